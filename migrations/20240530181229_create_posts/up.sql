@@ -1,4 +1,4 @@
-CREATE TABLE posts (
+CREATE TABLE IF NOT EXISTS posts (
     id         UUID         PRIMARY KEY NOT NULL,
     title      VARCHAR(127) NOT NULL,
     subtitle   VARCHAR(255) NOT NULL,
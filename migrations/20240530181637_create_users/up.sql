@@ -1,4 +1,4 @@
-CREATE TABLE users (
+CREATE TABLE IF NOT EXISTS users (
     id         UUID         PRIMARY KEY NOT NULL,
     name       VARCHAR(63)  NOT NULL,
     email      VARCHAR(127) NOT NULL    UNIQUE,
@@ -1,4 +1,4 @@
-CREATE TABLE comments (
+CREATE TABLE IF NOT EXISTS comments (
     id                 UUID         PRIMARY KEY NOT NULL,
     post_id            UUID         NOT NULL,
     user_id            UUID         NOT NULL,
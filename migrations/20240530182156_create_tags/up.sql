@@ -1,4 +1,4 @@
-CREATE TABLE tags (
+CREATE TABLE IF NOT EXISTS tags (
     id                 UUID         PRIMARY KEY,
     name               VARCHAR(63)  NOT NULL    UNIQUE,
     created_at         TIMESTAMPTZ  NOT NULL
@@ -1,4 +1,4 @@
-CREATE TABLE users_comments_likes (
+CREATE TABLE IF NOT EXISTS users_comments_likes (
     user_id       UUID         NOT NULL,
     comment_id    UUID         NOT NULL,
     PRIMARY KEY (user_id, comment_id),
@@ -1,4 +1,4 @@
-CREATE TABLE users_posts_likes (
+CREATE TABLE IF NOT EXISTS users_posts_likes (
     user_id    UUID         NOT NULL,
     post_id    UUID         NOT NULL,
     PRIMARY KEY (user_id, post_id),
@@ -1,4 +1,4 @@
-CREATE TABLE posts_tags (
+CREATE TABLE IF NOT EXISTS posts_tags (
     post_id       UUID         NOT NULL,
     tag_id        UUID         NOT NULL,
     PRIMARY KEY (post_id, tag_id),
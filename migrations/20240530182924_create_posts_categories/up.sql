@@ -1,4 +1,4 @@
-CREATE TABLE posts_categories (
+CREATE TABLE IF NOT EXISTS posts_categories (
     post_id        UUID         NOT NULL,
     category_id    UUID         NOT NULL,
     PRIMARY KEY (post_id, category_id),
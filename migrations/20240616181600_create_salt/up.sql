@@ -1,4 +1,4 @@
-CREATE TABLE salt (
+CREATE TABLE IF NOT EXISTS salt (
     salt           UUID         NOT NULL,
     user_id        UUID         NOT NULL,
     PRIMARY KEY (salt),
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS audit_logs (
+    id           UUID         PRIMARY KEY NOT NULL,
+    actor_id     UUID         NOT NULL,
+    action       VARCHAR(63)  NOT NULL,
+    target_id    UUID         NOT NULL,
+    reason       TEXT         DEFAULT     NULL,
+    created_at   TIMESTAMPTZ  NOT NULL
+);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS user_follows (
+    follower_id   UUID         NOT NULL,
+    followee_id   UUID         NOT NULL,
+    created_at    TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (follower_id, followee_id),
+    FOREIGN KEY (follower_id)           REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (followee_id)           REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS user_follows_followee_id_created_at_idx ON user_follows (followee_id, created_at);
@@ -0,0 +1,3 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS author_id UUID DEFAULT NULL REFERENCES users(id);
+
+CREATE INDEX IF NOT EXISTS posts_author_id_created_at_idx ON posts (author_id, created_at);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS post_views (
+    user_id       UUID         NOT NULL,
+    post_id       UUID         NOT NULL,
+    viewed_at     TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (user_id, post_id),
+    FOREIGN KEY (user_id)               REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (post_id)               REFERENCES posts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS post_views_user_id_viewed_at_idx ON post_views (user_id, viewed_at);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS drafts (
+    id            UUID         PRIMARY KEY NOT NULL,
+    post_id       UUID         DEFAULT     NULL,
+    author_id     UUID         NOT NULL,
+    title         TEXT         NOT NULL,
+    body          TEXT         NOT NULL,
+    saved_at      TIMESTAMPTZ  NOT NULL,
+    FOREIGN KEY (post_id)                   REFERENCES posts(id) ON DELETE SET NULL,
+    FOREIGN KEY (author_id)                 REFERENCES users(id) ON DELETE CASCADE
+);
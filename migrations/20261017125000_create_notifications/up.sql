@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS notifications (
+    id          UUID         PRIMARY KEY NOT NULL,
+    user_id     UUID         NOT NULL,
+    actor_id    UUID         NULL,
+    type        TEXT         NOT NULL,
+    payload     JSONB        NOT NULL    DEFAULT '{}',
+    read_at     TIMESTAMPTZ  DEFAULT     NULL,
+    created_at  TIMESTAMPTZ  NOT NULL,
+    FOREIGN KEY (user_id)    REFERENCES  users (id) ON DELETE CASCADE,
+    FOREIGN KEY (actor_id)   REFERENCES  users (id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS notifications_user_id_created_at_idx
+    ON notifications (user_id, created_at DESC);
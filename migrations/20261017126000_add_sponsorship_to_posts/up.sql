@@ -0,0 +1,2 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS sponsored_by TEXT DEFAULT NULL;
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS sponsor_url TEXT DEFAULT NULL;
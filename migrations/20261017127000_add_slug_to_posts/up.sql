@@ -0,0 +1,3 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS slug TEXT DEFAULT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS posts_slug_idx ON posts (slug);
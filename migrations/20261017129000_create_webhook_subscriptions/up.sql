@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS webhook_subscriptions (
+    id          UUID         PRIMARY KEY NOT NULL,
+    url         TEXT         NOT NULL,
+    secret      TEXT         NOT NULL,
+    events      TEXT[]       NOT NULL,
+    active      BOOLEAN      NOT NULL    DEFAULT TRUE,
+    created_at  TIMESTAMPTZ  NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS webhook_delivery_logs (
+    id               UUID         PRIMARY KEY NOT NULL,
+    subscription_id  UUID         NOT NULL,
+    event            TEXT         NOT NULL,
+    payload          JSONB        NOT NULL,
+    status_code      INT          NULL,
+    error            TEXT         NULL,
+    attempts         INT          NOT NULL,
+    created_at       TIMESTAMPTZ  NOT NULL,
+    FOREIGN KEY (subscription_id) REFERENCES webhook_subscriptions (id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS summary TEXT NULL;
+
+ALTER TABLE drafts ADD COLUMN IF NOT EXISTS summary TEXT NULL;
@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS reading_lists (
+    id          UUID          PRIMARY KEY NOT NULL,
+    user_id     UUID          NOT NULL,
+    name        VARCHAR(100)  NOT NULL,
+    public      BOOLEAN       NOT NULL    DEFAULT FALSE,
+    created_at  TIMESTAMPTZ   NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS reading_lists_user_id_idx ON reading_lists (user_id);
+
+CREATE TABLE IF NOT EXISTS reading_list_items (
+    list_id   UUID         NOT NULL,
+    post_id   UUID         NOT NULL,
+    added_at  TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (list_id, post_id),
+    FOREIGN KEY (list_id) REFERENCES reading_lists (id) ON DELETE CASCADE,
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE
+);
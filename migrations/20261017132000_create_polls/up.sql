@@ -0,0 +1,31 @@
+CREATE TABLE IF NOT EXISTS polls (
+    id          UUID          PRIMARY KEY NOT NULL,
+    post_id     UUID          NOT NULL    UNIQUE,
+    question    VARCHAR(255)  NOT NULL,
+    ends_at     TIMESTAMPTZ   NULL,
+    created_at  TIMESTAMPTZ   NOT NULL,
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS poll_options (
+    id        UUID          PRIMARY KEY NOT NULL,
+    poll_id   UUID          NOT NULL,
+    text      VARCHAR(127)  NOT NULL,
+    position  INT           NOT NULL,
+    FOREIGN KEY (poll_id) REFERENCES polls (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS poll_options_poll_id_idx ON poll_options (poll_id);
+
+CREATE TABLE IF NOT EXISTS poll_votes (
+    user_id    UUID         NOT NULL,
+    poll_id    UUID         NOT NULL,
+    option_id  UUID         NOT NULL,
+    voted_at   TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (user_id, poll_id),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    FOREIGN KEY (poll_id) REFERENCES polls (id) ON DELETE CASCADE,
+    FOREIGN KEY (option_id) REFERENCES poll_options (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS poll_votes_option_id_idx ON poll_votes (option_id);
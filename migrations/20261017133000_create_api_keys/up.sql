@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS api_keys (
+    id            UUID          PRIMARY KEY NOT NULL,
+    user_id       UUID          NOT NULL,
+    name          VARCHAR(100)  NOT NULL,
+    key_hash      TEXT          NOT NULL    UNIQUE,
+    scopes        TEXT[]        NOT NULL,
+    last_used_at  TIMESTAMPTZ   NULL,
+    created_at    TIMESTAMPTZ   NOT NULL,
+    expires_at    TIMESTAMPTZ   NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS api_keys_user_id_idx ON api_keys (user_id);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS post_attachments (
+    id          UUID          PRIMARY KEY NOT NULL,
+    post_id     UUID          NOT NULL,
+    filename    TEXT          NOT NULL,
+    url         TEXT          NOT NULL,
+    size_bytes  BIGINT        NOT NULL,
+    mime_type   TEXT          NOT NULL,
+    created_at  TIMESTAMPTZ   NOT NULL,
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS post_attachments_post_id_idx ON post_attachments (post_id);
@@ -0,0 +1 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS pinned_comment_id UUID NULL REFERENCES comments (id) ON DELETE SET NULL;
@@ -0,0 +1,3 @@
+ALTER TABLE users_comments_likes ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS users_comments_likes_comment_id_idx ON users_comments_likes (comment_id);
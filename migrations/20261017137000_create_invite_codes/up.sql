@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS invite_codes (
+    code        UUID         PRIMARY KEY NOT NULL,
+    created_by  UUID         NULL,
+    used_by     UUID         NULL,
+    used_at     TIMESTAMPTZ  NULL,
+    expires_at  TIMESTAMPTZ  NOT NULL,
+    FOREIGN KEY (created_by) REFERENCES users (id) ON DELETE SET NULL
+);
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS reading_progress (
+    user_id           UUID         NOT NULL,
+    post_id           UUID         NOT NULL,
+    progress_percent  INT          NOT NULL CHECK (0 <= progress_percent AND progress_percent <= 100),
+    updated_at        TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (user_id, post_id),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS reading_progress_user_id_updated_at_idx ON reading_progress (user_id, updated_at DESC);
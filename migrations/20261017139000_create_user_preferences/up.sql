@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS user_preferences (
+    user_id      UUID   PRIMARY KEY NOT NULL,
+    preferences  JSONB  NOT NULL DEFAULT '{}',
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
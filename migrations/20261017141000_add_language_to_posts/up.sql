@@ -0,0 +1,3 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS language CHAR(2) NOT NULL DEFAULT 'pt';
+
+CREATE INDEX IF NOT EXISTS posts_language_created_at_idx ON posts (language, created_at);
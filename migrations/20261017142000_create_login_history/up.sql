@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS login_history (
+    id              UUID         PRIMARY KEY NOT NULL,
+    user_id         UUID         NULL,
+    email           TEXT         NOT NULL,
+    success         BOOLEAN      NOT NULL,
+    ip              TEXT         NOT NULL,
+    user_agent      TEXT         NOT NULL,
+    failure_reason  TEXT         NULL,
+    created_at      TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS login_history_email_created_at_idx ON login_history (email, created_at);
+CREATE INDEX IF NOT EXISTS login_history_user_id_created_at_idx ON login_history (user_id, created_at);
@@ -0,0 +1,5 @@
+ALTER TABLE users ADD COLUMN IF NOT EXISTS username TEXT NOT NULL
+    DEFAULT ('user_' || SUBSTRING(REPLACE(gen_random_uuid()::TEXT, '-', ''), 1, 20));
+ALTER TABLE users ADD COLUMN IF NOT EXISTS username_changed_at TIMESTAMPTZ NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS users_username_idx ON users (username);
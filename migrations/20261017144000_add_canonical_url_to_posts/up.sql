@@ -0,0 +1,10 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS canonical_url TEXT NULL;
+
+CREATE TABLE IF NOT EXISTS post_slug_redirects (
+    old_slug    TEXT         PRIMARY KEY NOT NULL,
+    post_id     UUID         NOT NULL,
+    created_at  TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS post_slug_redirects_post_id_idx ON post_slug_redirects (post_id);
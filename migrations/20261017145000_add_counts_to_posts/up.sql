@@ -0,0 +1,6 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS word_count INT NOT NULL DEFAULT 0;
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS character_count INT NOT NULL DEFAULT 0;
+
+UPDATE posts SET
+    word_count = (SELECT COUNT(*) FROM REGEXP_SPLIT_TO_TABLE(body, '\s+') AS w WHERE w <> ''),
+    character_count = CHAR_LENGTH(body);
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS content_reports (
+    id           UUID         PRIMARY KEY NOT NULL,
+    reporter_id  UUID         NOT NULL,
+    entity_type  TEXT         NOT NULL,
+    entity_id    UUID         NOT NULL,
+    reason       TEXT         NOT NULL,
+    status       TEXT         NOT NULL DEFAULT 'pending',
+    created_at   TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    resolved_at  TIMESTAMPTZ  NULL,
+    resolved_by  UUID         NULL,
+    FOREIGN KEY (reporter_id) REFERENCES users (id) ON DELETE CASCADE,
+    UNIQUE (reporter_id, entity_type, entity_id)
+);
+
+CREATE INDEX IF NOT EXISTS content_reports_status_created_at_idx ON content_reports (status, created_at);
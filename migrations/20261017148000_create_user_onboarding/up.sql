@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS user_onboarding (
+    user_id               UUID         PRIMARY KEY NOT NULL,
+    email_verified        BOOLEAN      NOT NULL DEFAULT false,
+    profile_picture_set   BOOLEAN      NOT NULL DEFAULT false,
+    first_post_published  BOOLEAN      NOT NULL DEFAULT false,
+    biography_set         BOOLEAN      NOT NULL DEFAULT false,
+    skipped_at            TIMESTAMPTZ  NULL,
+    updated_at            TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+INSERT INTO user_onboarding (user_id, first_post_published)
+SELECT DISTINCT author_id, true FROM posts
+WHERE author_id IS NOT NULL AND published_at IS NOT NULL AND deleted_at IS NULL
+ON CONFLICT (user_id) DO NOTHING;
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS feature_flags (
+    name        TEXT         PRIMARY KEY NOT NULL,
+    enabled     BOOLEAN      NOT NULL DEFAULT true,
+    updated_at  TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO feature_flags (name, enabled) VALUES ('invite_codes', true)
+ON CONFLICT (name) DO NOTHING;
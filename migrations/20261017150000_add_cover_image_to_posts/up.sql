@@ -0,0 +1,2 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS cover_image_url TEXT NULL;
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS cover_image_blurhash TEXT NULL;
@@ -0,0 +1,8 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS code_languages TEXT[] NULL;
+
+UPDATE posts SET code_languages = ARRAY(
+    SELECT DISTINCT LOWER(m[1])
+    FROM REGEXP_MATCHES(body, '^[ \t]{0,3}(?:```|~~~)[ \t]*([A-Za-z0-9_+#.-]+)', 'gn') AS m
+);
+
+CREATE INDEX IF NOT EXISTS posts_code_languages_idx ON posts USING GIN (code_languages);
@@ -0,0 +1,5 @@
+ALTER TABLE content_reports
+    DROP CONSTRAINT IF EXISTS content_reports_reporter_id_entity_type_entity_id_key;
+
+CREATE INDEX IF NOT EXISTS content_reports_reporter_entity_idx
+    ON content_reports (reporter_id, entity_type, entity_id, created_at);
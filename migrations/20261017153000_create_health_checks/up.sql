@@ -0,0 +1,4 @@
+CREATE TABLE IF NOT EXISTS health_checks (
+    id INT PRIMARY KEY,
+    checked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
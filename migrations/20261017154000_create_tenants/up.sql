@@ -0,0 +1,25 @@
+CREATE TABLE IF NOT EXISTS tenants (
+    id         UUID         PRIMARY KEY NOT NULL,
+    name       VARCHAR(100) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO tenants (id, name)
+VALUES ('00000000-0000-0000-0000-000000000000', 'default')
+ON CONFLICT (id) DO NOTHING;
+
+ALTER TABLE users
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000000' REFERENCES tenants (id);
+
+ALTER TABLE posts
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000000' REFERENCES tenants (id);
+
+ALTER TABLE comments
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000000' REFERENCES tenants (id);
+
+CREATE INDEX IF NOT EXISTS users_tenant_id_idx ON users (tenant_id);
+CREATE INDEX IF NOT EXISTS posts_tenant_id_idx ON posts (tenant_id);
+CREATE INDEX IF NOT EXISTS comments_tenant_id_idx ON comments (tenant_id);
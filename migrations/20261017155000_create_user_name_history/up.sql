@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS user_name_history (
+    id          UUID         PRIMARY KEY NOT NULL,
+    user_id     UUID         NOT NULL,
+    old_name    TEXT         NOT NULL,
+    new_name    TEXT         NOT NULL,
+    changed_at  TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS user_name_history_user_id_changed_at_idx ON user_name_history (user_id, changed_at);
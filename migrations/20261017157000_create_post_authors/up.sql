@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS post_authors (
+    post_id   UUID         NOT NULL,
+    user_id   UUID         NOT NULL,
+    role      TEXT         NOT NULL DEFAULT 'co-author',
+    added_at  TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (post_id, user_id),
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS post_authors_user_id_idx ON post_authors (user_id);
+
+INSERT INTO post_authors (post_id, user_id, role, added_at)
+SELECT id, author_id, 'primary', created_at FROM posts WHERE author_id IS NOT NULL
+ON CONFLICT (post_id, user_id) DO NOTHING;
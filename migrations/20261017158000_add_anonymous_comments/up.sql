@@ -0,0 +1,6 @@
+ALTER TABLE comments
+    ALTER COLUMN user_id DROP NOT NULL,
+    ADD COLUMN IF NOT EXISTS guest_name TEXT NULL,
+    ADD COLUMN IF NOT EXISTS guest_email TEXT NULL;
+
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS allow_anonymous_comments BOOLEAN NOT NULL DEFAULT TRUE;
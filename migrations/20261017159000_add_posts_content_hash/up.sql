@@ -0,0 +1,10 @@
+-- `convert_to` is only stable because it depends on the server encoding, which never changes once
+-- the database is created, so it is safe to wrap it in an immutable function for the generated column.
+CREATE OR REPLACE FUNCTION post_content_hash(title TEXT, body TEXT) RETURNS TEXT
+    LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE
+    AS $$ SELECT encode(sha256(convert_to(title || body, 'UTF8')), 'hex') $$;
+
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS content_hash TEXT NOT NULL
+    GENERATED ALWAYS AS (post_content_hash(title, body)) STORED;
+
+CREATE INDEX IF NOT EXISTS posts_author_id_content_hash_idx ON posts (author_id, content_hash);
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS digest_deliveries (
+    id              UUID         PRIMARY KEY NOT NULL,
+    subscriber_id   UUID         NOT NULL,
+    sent_at         TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    post_ids        UUID[]       NOT NULL,
+    FOREIGN KEY (subscriber_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS digest_deliveries_subscriber_id_idx ON digest_deliveries (subscriber_id, sent_at DESC);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS failed_queue_items (
+    id                   UUID         PRIMARY KEY NOT NULL,
+    queue                TEXT         NOT NULL,
+    payload              JSONB        NOT NULL,
+    error                TEXT         NOT NULL,
+    attempts             INTEGER      NOT NULL DEFAULT 0,
+    permanently_failed   BOOLEAN      NOT NULL DEFAULT false,
+    created_at           TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    last_attempt_at      TIMESTAMPTZ  NULL
+);
+
+CREATE INDEX IF NOT EXISTS failed_queue_items_retry_idx ON failed_queue_items (queue, permanently_failed, created_at);
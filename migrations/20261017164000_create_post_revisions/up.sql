@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS post_revisions (
+    id          UUID         PRIMARY KEY NOT NULL,
+    post_id     UUID         NOT NULL,
+    version     INTEGER      NOT NULL,
+    title       VARCHAR(127) NOT NULL,
+    body        TEXT         NOT NULL,
+    created_at  TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE,
+    UNIQUE (post_id, version)
+);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS post_locks (
+    post_id     UUID         PRIMARY KEY NOT NULL,
+    locked_by   UUID         NOT NULL,
+    locked_at   TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    expires_at  TIMESTAMPTZ  NOT NULL,
+    FOREIGN KEY (post_id) REFERENCES posts (id) ON DELETE CASCADE,
+    FOREIGN KEY (locked_by) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS post_locks_expires_at_idx ON post_locks (expires_at);
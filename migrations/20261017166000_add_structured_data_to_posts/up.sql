@@ -0,0 +1,2 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS structured_data JSONB NULL;
+ALTER TABLE drafts ADD COLUMN IF NOT EXISTS structured_data JSONB NULL;
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS password_history (
+    id             UUID         PRIMARY KEY NOT NULL,
+    user_id        UUID         NOT NULL,
+    password_hash  TEXT         NOT NULL,
+    created_at     TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS password_history_user_id_created_at_idx ON password_history (user_id, created_at);
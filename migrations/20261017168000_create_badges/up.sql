@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS badges (
+    id           UUID  PRIMARY KEY NOT NULL,
+    slug         TEXT  NOT NULL UNIQUE,
+    name         TEXT  NOT NULL,
+    description  TEXT  NOT NULL,
+    icon_url     TEXT  NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS user_badges (
+    user_id     UUID         NOT NULL,
+    badge_id    UUID         NOT NULL,
+    awarded_at  TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, badge_id),
+    FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+    FOREIGN KEY (badge_id) REFERENCES badges (id) ON DELETE CASCADE
+);
+
+INSERT INTO badges (id, slug, name, description, icon_url) VALUES
+    ('7b0e3f1c-2a4d-4c6e-9f10-1a2b3c4d5e01', 'first_post', 'Primeiro post', 'Publicou o primeiro post.', '/badges/first_post.svg'),
+    ('7b0e3f1c-2a4d-4c6e-9f10-1a2b3c4d5e02', 'ten_posts', 'Dez posts', 'Publicou 10 posts.', '/badges/ten_posts.svg'),
+    ('7b0e3f1c-2a4d-4c6e-9f10-1a2b3c4d5e03', 'popular', 'Popular', 'Teve um post com 100 curtidas.', '/badges/popular.svg'),
+    ('7b0e3f1c-2a4d-4c6e-9f10-1a2b3c4d5e04', 'veteran', 'Veterano', 'Tem uma conta com mais de um ano.', '/badges/veteran.svg')
+ON CONFLICT (slug) DO NOTHING;
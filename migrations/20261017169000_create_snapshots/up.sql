@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS snapshots (
+    id             UUID         PRIMARY KEY NOT NULL,
+    snapshot_date  DATE         NOT NULL,
+    file_key       TEXT         NOT NULL,
+    post_count     BIGINT       NOT NULL,
+    size_bytes     BIGINT       NOT NULL,
+    created_at     TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS snapshots_created_at_idx ON snapshots (created_at);
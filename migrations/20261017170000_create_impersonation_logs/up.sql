@@ -0,0 +1,37 @@
+CREATE TABLE IF NOT EXISTS impersonation_logs (
+    id              UUID         PRIMARY KEY NOT NULL,
+    admin_id        UUID         NOT NULL,
+    target_user_id  UUID         NOT NULL,
+    token_jti       UUID         NOT NULL UNIQUE,
+    issued_at       TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+    used_from_ip    TEXT         NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS impersonation_logs_issued_at_idx ON impersonation_logs (issued_at);
+
+-- The application user may only append to the trail.
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'api_user') THEN
+        REVOKE UPDATE, DELETE, TRUNCATE ON impersonation_logs FROM api_user;
+        GRANT SELECT, INSERT ON impersonation_logs TO api_user;
+    END IF;
+END
+$$;
+
+-- Superusers and the table owner bypass the grants above, so the rows are also protected by
+-- triggers that reject any change for every role.
+CREATE OR REPLACE FUNCTION impersonation_logs_append_only() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'permission denied: impersonation_logs is append-only'
+        USING ERRCODE = 'insufficient_privilege';
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER impersonation_logs_no_update_delete
+    BEFORE UPDATE OR DELETE ON impersonation_logs
+    FOR EACH ROW EXECUTE FUNCTION impersonation_logs_append_only();
+
+CREATE TRIGGER impersonation_logs_no_truncate
+    BEFORE TRUNCATE ON impersonation_logs
+    FOR EACH STATEMENT EXECUTE FUNCTION impersonation_logs_append_only();
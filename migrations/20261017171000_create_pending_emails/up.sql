@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS pending_emails (
+    id             UUID         PRIMARY KEY NOT NULL,
+    "to"           TEXT         NOT NULL,
+    subject        TEXT         NOT NULL,
+    html_body      TEXT         NOT NULL,
+    scheduled_for  TIMESTAMPTZ  NOT NULL,
+    sent_at        TIMESTAMPTZ,
+    created_at     TIMESTAMPTZ  NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS pending_emails_scheduled_for_idx ON pending_emails (scheduled_for) WHERE sent_at IS NULL;
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS series (
+    id          UUID          PRIMARY KEY NOT NULL,
+    title       VARCHAR(150)  NOT NULL,
+    created_at  TIMESTAMPTZ   NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS series_posts (
+    series_id  UUID     NOT NULL,
+    post_id    UUID     NOT NULL,
+    position   INTEGER  NOT NULL CHECK (position >= 1),
+    PRIMARY KEY (series_id, position),
+    UNIQUE (series_id, post_id),
+    FOREIGN KEY (series_id) REFERENCES series (id) ON DELETE CASCADE,
+    FOREIGN KEY (post_id)   REFERENCES posts (id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS series_posts_post_id_idx ON series_posts (post_id);
@@ -0,0 +1,10 @@
+ALTER TABLE posts ADD COLUMN IF NOT EXISTS moderation_mode TEXT NOT NULL DEFAULT 'auto_approve'
+    CHECK (moderation_mode IN ('auto_approve', 'require_approval'));
+
+-- The existing comments were all visible, so they are backfilled as approved when they were made.
+ALTER TABLE comments ADD COLUMN IF NOT EXISTS approved_at TIMESTAMPTZ NULL;
+UPDATE comments SET approved_at = created_at WHERE approved_at IS NULL;
+ALTER TABLE comments ALTER COLUMN approved_at SET DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS comments_pending_post_id_idx ON comments (post_id, created_at)
+    WHERE approved_at IS NULL AND deleted_at IS NULL;
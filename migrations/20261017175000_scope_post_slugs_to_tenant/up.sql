@@ -0,0 +1,12 @@
+-- Slugs, current or old, only have to be unique inside the tenant that owns the post.
+DROP INDEX IF EXISTS posts_slug_idx;
+CREATE UNIQUE INDEX IF NOT EXISTS posts_tenant_id_slug_idx ON posts (tenant_id, slug);
+
+ALTER TABLE post_slug_redirects
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000000' REFERENCES tenants (id);
+
+UPDATE post_slug_redirects r SET tenant_id = p.tenant_id FROM posts p WHERE p.id = r.post_id;
+
+ALTER TABLE post_slug_redirects DROP CONSTRAINT IF EXISTS post_slug_redirects_pkey;
+ALTER TABLE post_slug_redirects ADD PRIMARY KEY (tenant_id, old_slug);
@@ -0,0 +1,372 @@
+use actix_web::web::{Data, Json};
+use fake::{
+    faker::{
+        internet::en::FreeEmailProvider,
+        lorem::en::{Paragraph, Paragraphs, Sentence},
+        name::en::{FirstName, LastName},
+    },
+    Fake,
+};
+use navarro_blog_api::{
+    infra::{postgres::Postgres, redis::Redis},
+    middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+    modules::{
+        comment::{comment_dto::InsertCommentDTO, comment_services::insert_comment_service},
+        draft::{
+            draft_dtos::PutDraftDTO,
+            draft_services::{promote_draft_service, put_draft_service},
+        },
+        user::{
+            user_dtos::InsertUserDTO,
+            user_queues::{drain_insert_user_queue, InsertUserAppQueue},
+            user_services::insert_user_service,
+        },
+    },
+    utils::graceful_shutdown::QUEUE_DRAIN_TIMEOUT,
+};
+use std::{env, process, sync::Arc};
+use validator::Validate;
+
+/// The password shared by every seeded user, so any of them can be used to log in.
+const SEED_PASSWORD: &str = "Senha@123";
+
+/// Tables kept by `--clear`: the migration history and the rows seeded by the migrations.
+const KEPT_TABLES: [&str; 3] = ["refinery_schema_history", "tenants", "feature_flags"];
+
+const USAGE: &str =
+    "Usage: cargo run --bin seed -- [--users N] [--posts N] [--comments N] [--clear]";
+
+struct SeedArgs {
+    users: usize,
+    posts: usize,
+    comments: usize,
+    clear: bool,
+}
+
+#[derive(Default)]
+struct SeedSummary {
+    users: (usize, usize),
+    posts: (usize, usize),
+    comments: (usize, usize),
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<SeedArgs, String> {
+    let mut seed_args = SeedArgs {
+        users: 10,
+        posts: 5,
+        comments: 3,
+        clear: false,
+    };
+
+    while let Some(arg) = args.next() {
+        let target = match arg.as_str() {
+            "--clear" => {
+                seed_args.clear = true;
+                continue;
+            }
+            "--users" => &mut seed_args.users,
+            "--posts" => &mut seed_args.posts,
+            "--comments" => &mut seed_args.comments,
+            _ => return Err(format!("Unknown argument `{}`", arg)),
+        };
+        *target = match args.next().and_then(|value| value.parse().ok()) {
+            Some(value) => value,
+            None => return Err(format!("`{}` expects a non negative number", arg)),
+        };
+    }
+    Ok(seed_args)
+}
+
+/// Truncates every table of the public schema but the ones in `KEPT_TABLES`.
+async fn clear_tables(pg_pool: &deadpool_postgres::Pool) -> Result<usize, String> {
+    let client = match pg_pool.get().await {
+        Ok(x) => x,
+        Err(e) => return Err(e.to_string()),
+    };
+    let rows = match client
+        .query(
+            "SELECT tablename FROM pg_tables WHERE schemaname = 'public' AND NOT (tablename = ANY($1))",
+            &[&KEPT_TABLES.to_vec()],
+        )
+        .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e.to_string()),
+    };
+    let tables: Vec<String> = rows
+        .iter()
+        .map(|row| format!("\"{}\"", row.get::<_, String>("tablename")))
+        .collect();
+    if tables.is_empty() {
+        return Ok(0);
+    }
+
+    match client
+        .batch_execute(&format!("TRUNCATE {} CASCADE", tables.join(", ")))
+        .await
+    {
+        Ok(_) => Ok(tables.len()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn fake_user(index: usize) -> InsertUserDTO {
+    let first_name: String = FirstName().fake();
+    let last_name: String = LastName().fake();
+    let name: String = format!("{} {}", first_name, last_name)
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == ' ')
+        .collect();
+    let handle: String = first_name
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .take(12)
+        .collect();
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let username = format!("{}_{}_{}", handle, index, &suffix[..6]);
+    let email_provider: String = FreeEmailProvider().fake();
+
+    InsertUserDTO {
+        name,
+        email: format!("{}@{}", username, email_provider),
+        username,
+        password: String::from(SEED_PASSWORD),
+        invite_code: None,
+    }
+}
+
+fn fake_post() -> PutDraftDTO {
+    let title: String = Sentence(3..8).fake();
+    let paragraphs: Vec<String> = Paragraphs(3..6).fake();
+
+    PutDraftDTO {
+        post_id: None,
+        title: title.trim_end_matches('.').to_string(),
+        body: paragraphs.join("\n\n"),
+        summary: None,
+        structured_data: None,
+    }
+}
+
+fn fake_comment() -> InsertCommentDTO {
+    InsertCommentDTO {
+        body: Paragraph(1..3).fake(),
+        parent_comment_id: None,
+        guest_name: None,
+        guest_email: None,
+        website: None,
+    }
+}
+
+/// Inserts the users through the insertion queue and flushes it, returning the created ids.
+async fn seed_users(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    total: usize,
+    summary: &mut SeedSummary,
+) -> Vec<String> {
+    let queue = Arc::new(InsertUserAppQueue::new());
+    let mut user_ids = Vec::with_capacity(total);
+
+    for index in 0..total {
+        let body = fake_user(index);
+        match body.validate() {
+            Ok(_) => (),
+            Err(e) => {
+                eprintln!("Skipping invalid user {}: {}", body.username, e);
+                summary.users.1 += 1;
+                continue;
+            }
+        };
+        match insert_user_service(
+            Data::new(queue.clone()),
+            pg_pool.clone(),
+            Json(body),
+            String::new(),
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await
+        {
+            Ok(user) => user_ids.push(user.id),
+            Err(e) => {
+                eprintln!("Failed to insert a user: status {}", e.status());
+                summary.users.1 += 1;
+            }
+        };
+    }
+
+    drain_insert_user_queue(pg_pool.get_ref().clone(), queue, *QUEUE_DRAIN_TIMEOUT).await;
+    summary.users.0 = user_ids.len();
+    user_ids
+}
+
+/// Publishes `total` posts per user through the draft workflow, returning the created ids.
+async fn seed_posts(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_ids: &[String],
+    total: usize,
+    summary: &mut SeedSummary,
+) -> Vec<String> {
+    let mut post_ids = Vec::with_capacity(user_ids.len() * total);
+
+    for user_id in user_ids {
+        for _ in 0..total {
+            let body = fake_post();
+            match body.validate() {
+                Ok(_) => (),
+                Err(e) => {
+                    eprintln!("Skipping invalid post: {}", e);
+                    summary.posts.1 += 1;
+                    continue;
+                }
+            };
+            let draft = match put_draft_service(
+                pg_pool.clone(),
+                uuid::Uuid::new_v4().to_string(),
+                user_id.clone(),
+                body,
+            )
+            .await
+            {
+                Ok(x) => x,
+                Err(e) => {
+                    eprintln!("Failed to save a draft: status {}", e.status());
+                    summary.posts.1 += 1;
+                    continue;
+                }
+            };
+            match promote_draft_service(pg_pool.clone(), draft.id, user_id.clone()).await {
+                Ok(promoted) => post_ids.push(promoted.id),
+                Err(e) => {
+                    eprintln!("Failed to publish a draft: status {}", e.status());
+                    summary.posts.1 += 1;
+                }
+            };
+        }
+    }
+
+    summary.posts.0 = post_ids.len();
+    post_ids
+}
+
+/// Inserts `total` comments per post, each one written by a random seeded user.
+async fn seed_comments(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    user_ids: &[String],
+    post_ids: &[String],
+    total: usize,
+    summary: &mut SeedSummary,
+) {
+    for post_id in post_ids {
+        for _ in 0..total {
+            let body = fake_comment();
+            match body.validate() {
+                Ok(_) => (),
+                Err(e) => {
+                    eprintln!("Skipping invalid comment: {}", e);
+                    summary.comments.1 += 1;
+                    continue;
+                }
+            };
+            let author_id = user_ids[(0..user_ids.len()).fake::<usize>()].clone();
+            match insert_comment_service(
+                pg_pool.clone(),
+                redis_pool.clone(),
+                post_id.clone(),
+                author_id,
+                body,
+            )
+            .await
+            {
+                Ok(_) => summary.comments.0 += 1,
+                Err(e) => {
+                    eprintln!("Failed to insert a comment: status {}", e.status());
+                    summary.comments.1 += 1;
+                }
+            };
+        }
+    }
+}
+
+fn print_summary(summary: &SeedSummary) {
+    println!();
+    println!("+----------+---------+--------+");
+    println!("| {:<8} | {:>7} | {:>6} |", "Entity", "Created", "Failed");
+    println!("+----------+---------+--------+");
+    for (entity, (created, failed)) in [
+        ("users", summary.users),
+        ("posts", summary.posts),
+        ("comments", summary.comments),
+    ] {
+        println!("| {:<8} | {:>7} | {:>6} |", entity, created, failed);
+    }
+    println!("+----------+---------+--------+");
+    println!(
+        "Every seeded user logs in with the password `{}`.",
+        SEED_PASSWORD
+    );
+}
+
+/// Fills a development database with fake users, posts and comments.
+///
+/// It reads the same environment variables as the API server, applies the pending migrations and
+/// creates the data through the real services, so every record goes through the same validation
+/// as the API. With `--clear` every table but the ones in `KEPT_TABLES` is truncated first.
+///
+/// # Flags
+///
+/// - `--users N`: The number of users to create (default 10).
+/// - `--posts N`: The number of posts per user (default 5).
+/// - `--comments N`: The number of comments per post (default 3).
+/// - `--clear`: Truncates the tables before seeding.
+///
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+    dotenv::dotenv().ok();
+
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(x) => x,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(1);
+        }
+    };
+
+    let postgres_pool = Postgres::pool();
+    let redis_pool = Redis::pool().await;
+
+    if let Err(e) = Postgres::run_migrations(&postgres_pool).await {
+        eprintln!("Failed to apply database migrations: {:#}", e);
+        process::exit(1);
+    }
+    if args.clear {
+        match clear_tables(&postgres_pool).await {
+            Ok(total) => println!("Truncated {} tables.", total),
+            Err(e) => {
+                eprintln!("Failed to truncate the tables: {}", e);
+                process::exit(1);
+            }
+        };
+    }
+
+    let pg_pool = Data::new(postgres_pool);
+    let mut summary = SeedSummary::default();
+
+    let user_ids = seed_users(pg_pool.clone(), args.users, &mut summary).await;
+    let post_ids = seed_posts(pg_pool.clone(), &user_ids, args.posts, &mut summary).await;
+    if !user_ids.is_empty() {
+        seed_comments(
+            pg_pool,
+            Data::new(redis_pool),
+            &user_ids,
+            &post_ids,
+            args.comments,
+            &mut summary,
+        )
+        .await;
+    }
+
+    print_summary(&summary);
+}
@@ -1,18 +1,188 @@
 use crate::{
-    modules::user::{
-        user_controllers::{
-            __path_delete_user, __path_detail_user, __path_insert_user, __path_list_users,
-            __path_login_user, __path_put_user, __path_user_id_options, __path_user_options,
+    modules::{
+        activitypub::activitypub_controllers::{__path_outbox, __path_webfinger},
+        admin::{
+            admin_controllers::{
+                __path_admin_statistics, __path_ban_user, __path_disable_maintenance_mode,
+                __path_enable_maintenance_mode, __path_impersonate_user, __path_impersonation_logs,
+                __path_merge_users, __path_unban_user,
+            },
+            admin_dtos::{
+                BanUserDTO, ImpersonationDTO, ImpersonationLogDTO, MergeUsersSummaryDTO,
+                PlatformStatsDTO,
+            },
+        },
+        api_key::{
+            api_key_controllers::__path_insert_api_key,
+            api_key_dtos::{ApiKeyDTO, InsertApiKeyDTO},
+        },
+        comment::{
+            comment_controllers::{
+                __path_approve_comment, __path_count_comments, __path_count_comments_by_slugs,
+                __path_insert_comment, __path_list_comments, __path_list_pending_comments,
+                __path_pin_comment, __path_reject_comment, __path_unpin_comment,
+            },
+            comment_dto::{
+                CommentCountDTO, CommentCountsDTO, CommentDTO, InsertCommentDTO, PinCommentDTO,
+            },
+        },
+        comment_like::comment_like_controllers::{__path_like_comment, __path_unlike_comment},
+        content_report::{
+            content_report_controllers::{
+                __path_content_report_stats, __path_list_content_reports, __path_report_comment,
+                __path_report_post, __path_resolve_content_report,
+            },
+            content_report_dtos::{
+                ContentReportDTO, ContentReportStatsDTO, InsertContentReportDTO, ReportedAuthorDTO,
+                ReportsByTypeDTO, ResolveContentReportDTO,
+            },
+        },
+        dead_letter::{
+            dead_letter_controllers::{
+                __path_delete_dead_letter_item, __path_list_dead_letter_items,
+            },
+            dead_letter_dtos::FailedQueueItemDTO,
+        },
+        draft::{
+            draft_controllers::{__path_detail_draft, __path_promote_draft, __path_put_draft},
+            draft_dtos::{DraftDTO, PromotedDraftDTO, PutDraftDTO},
+        },
+        feature_flag::{
+            feature_flag_controllers::{__path_list_feature_flags, __path_patch_feature_flag},
+            feature_flag_dtos::{FeatureFlagDTO, PatchFeatureFlagDTO},
+        },
+        feed::feed_controllers::{__path_rss_feed, __path_sitemap},
+        follower::{
+            follower_controllers::{
+                __path_activity_feed, __path_feed, __path_follow_user, __path_list_followers,
+                __path_list_following, __path_unfollow_user,
+            },
+            follower_dtos::{ActivityEventDTO, FeedPostDTO, FollowerDTO},
+        },
+        health::{health_controllers::__path_health, health_dtos::HealthDTO},
+        invite_code::{
+            invite_code_controllers::__path_insert_invite_codes,
+            invite_code_dtos::{InsertInviteCodesDTO, InviteCodeDTO},
+        },
+        login_history::{
+            login_history_controllers::{__path_email_login_history, __path_user_login_history},
+            login_history_dtos::LoginHistoryDTO,
+        },
+        metrics::metrics_controllers::__path_metrics,
+        newsletter::{
+            newsletter_controllers::{__path_list_pending_emails, __path_trigger_digest},
+            newsletter_dtos::PendingEmailDTO,
+        },
+        poll::{
+            poll_controllers::{__path_insert_poll, __path_vote_poll},
+            poll_dtos::{InsertPollDTO, PollDTO, PollOptionDTO, VotePollDTO},
+        },
+        post::{
+            post_controllers::{
+                __path_acquire_post_lock, __path_admin_list_posts, __path_archive,
+                __path_archive_month_posts, __path_copy_post, __path_delete_cover_image,
+                __path_detail_post, __path_embed_meta, __path_excerpt_post, __path_list_posts,
+                __path_patch_post_authors, __path_put_cover_image, __path_put_sponsorship,
+                __path_recompute_post_counts, __path_release_post_lock, __path_revision_diff,
+                __path_trending_posts, __path_update_post,
+            },
+            post_dto::{
+                ArchiveMonthDTO, AuthorDTO, CopiedPostDTO, CoverImageDTO, DiffChunkDTO,
+                EmbedMetaDTO, ExcerptPostDTO, LintWarningDTO, PatchPostAuthorsDTO, PostDTO,
+                PostFilterParams, PostLinkDTO, PostLockDTO, PostLockedDTO, SponsorshipDTO,
+                UpdatePostDTO,
+            },
+        },
+        post_attachment::{
+            post_attachment_controllers::{__path_delete_attachment, __path_insert_attachment},
+            post_attachment_dtos::{AttachmentDTO, InsertAttachmentDTO},
+        },
+        post_import::{
+            post_import_controllers::{
+                __path_bulk_import_posts, __path_import_markdown_posts, __path_import_rss_posts,
+            },
+            post_import_dtos::{
+                ImportErrorDTO, ImportPostsResultDTO, MarkdownImportResultDTO, RssImportDTO,
+            },
+        },
+        post_view::{post_view_controllers::__path_read_history, post_view_dtos::ReadPostDTO},
+        reading_list::{
+            reading_list_controllers::{
+                __path_delete_reading_list, __path_delete_reading_list_item,
+                __path_detail_reading_list, __path_insert_reading_list,
+                __path_insert_reading_list_item, __path_list_reading_lists,
+                __path_put_reading_list,
+            },
+            reading_list_dtos::{
+                DetailReadingListDTO, InsertReadingListDTO, InsertReadingListItemDTO,
+                PutReadingListDTO, ReadingListDTO, ReadingListItemDTO,
+            },
+        },
+        reading_progress::{
+            reading_progress_controllers::{
+                __path_detail_reading_progress, __path_list_in_progress,
+                __path_put_reading_progress,
+            },
+            reading_progress_dtos::{InProgressPostDTO, PutReadingProgressDTO, ReadingProgressDTO},
+        },
+        snapshot::{snapshot_controllers::__path_list_snapshots, snapshot_dtos::SnapshotDTO},
+        static_files::static_files_controllers::{__path_favicon, __path_robots_txt},
+        tenant::{
+            tenant_controllers::__path_insert_tenant,
+            tenant_dtos::{InsertTenantDTO, TenantDTO},
+        },
+        user::{
+            user_controllers::{
+                __path_admin_list_users, __path_batch_get_users, __path_deactivate_user,
+                __path_delete_user, __path_detail_user, __path_detail_user_by_username,
+                __path_insert_user, __path_list_users, __path_login_user,
+                __path_mention_autocomplete, __path_name_history, __path_put_user,
+                __path_reactivate_user, __path_refresh_token, __path_update_name,
+                __path_update_role, __path_update_username, __path_user_id_options,
+                __path_user_options,
+            },
+            user_dtos::{
+                BatchGetUsersDTO, DeactivatedUserDTO, DeleteUserDTO, DetailUserDTO,
+                DetailUserResponseDTO, InsertUserDTO, LoginUserDTO, MentionSuggestionDTO,
+                NameHistoryDTO, PutUserDTO, RefreshTokenDTO, UpdateNameDTO, UpdateRoleDTO,
+                UpdateUsernameDTO, UserFilterParams, UserSummaryDTO, UsernameDTO,
+            },
+        },
+        user_activity::{
+            user_activity_controllers::__path_user_activity, user_activity_dtos::ActivityDayDTO,
+        },
+        user_badge::{
+            user_badge_controllers::__path_list_user_badges,
+            user_badge_dtos::{BadgeSummaryDTO, UserBadgeDTO},
+        },
+        user_erasure::user_erasure_controllers::__path_delete_all_user_data,
+        user_export::user_export_controllers::__path_export_user_data,
+        user_onboarding::{
+            user_onboarding_controllers::{
+                __path_detail_user_onboarding, __path_skip_user_onboarding,
+            },
+            user_onboarding_dtos::UserOnboardingDTO,
+        },
+        user_preferences::{
+            user_preferences_controllers::{
+                __path_detail_user_preferences, __path_patch_user_preferences,
+            },
+            user_preferences_dtos::{PatchUserPreferencesDTO, UserPreferencesDTO},
+        },
+        user_recommendation::user_recommendation_controllers::__path_user_recommendations,
+        webhook::{
+            webhook_controllers::{__path_insert_webhook, __path_test_webhook},
+            webhook_dtos::{InsertWebhookDTO, WebhookDTO, WebhookDeliveryDTO},
         },
-        user_dtos::{DeleteUserDTO, DetailUserDTO, InsertUserDTO, LoginUserDTO, PutUserDTO},
     },
     shared::structs::{
         error_struct::{ErrorParams, ErrorStruct},
         query_params::QueryParams,
+        toc_entry::TocEntryDTO,
     },
 };
 use utoipa::{
-    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
     Modify, OpenApi,
 };
 use utoipa_swagger_ui::SwaggerUi;
@@ -20,7 +190,7 @@ use utoipa_swagger_ui::SwaggerUi;
 pub fn api_doc() -> SwaggerUi {
     #[derive(OpenApi)]
     #[openapi(
-		paths(user_options, insert_user, login_user, detail_user, list_users, delete_user, put_user, user_id_options),
+		paths(user_options, insert_user, login_user, detail_user, list_users, delete_user, put_user, user_id_options, admin_statistics, ban_user, unban_user, follow_user, unfollow_user, list_followers, list_following, feed, read_history, put_draft, detail_draft, promote_draft, insert_comment, list_posts, put_sponsorship, rss_feed, sitemap, export_user_data, delete_all_user_data, bulk_import_posts, insert_webhook, test_webhook, insert_reading_list, list_reading_lists, detail_reading_list, put_reading_list, delete_reading_list, insert_reading_list_item, delete_reading_list_item, detail_post, insert_poll, vote_poll, user_activity, trending_posts, user_recommendations, insert_api_key, insert_attachment, delete_attachment, list_comments, pin_comment, unpin_comment, like_comment, unlike_comment, insert_invite_codes, put_reading_progress, detail_reading_progress, list_in_progress, detail_user_preferences, patch_user_preferences, update_post, import_markdown_posts, favicon, robots_txt, user_login_history, email_login_history, detail_user_by_username, update_username, recompute_post_counts, report_post, report_comment, list_content_reports, resolve_content_report, detail_user_onboarding, skip_user_onboarding, list_feature_flags, patch_feature_flag, import_rss_posts, put_cover_image, delete_cover_image, health, insert_tenant, embed_meta, update_name, name_history, batch_get_users, archive, archive_month_posts, impersonate_user, copy_post, patch_post_authors, enable_maintenance_mode, disable_maintenance_mode, admin_list_users, refresh_token, update_role, excerpt_post, trigger_digest, list_dead_letter_items, delete_dead_letter_item, deactivate_user, reactivate_user, revision_diff, mention_autocomplete, admin_list_posts, acquire_post_lock, release_post_lock, merge_users, count_comments, count_comments_by_slugs, content_report_stats, metrics, list_user_badges, list_snapshots, outbox, webfinger, impersonation_logs, list_pending_emails, list_pending_comments, approve_comment, reject_comment, activity_feed),
 		components(
 			schemas(
 				InsertUserDTO,
@@ -31,6 +201,99 @@ pub fn api_doc() -> SwaggerUi {
                 ErrorStruct,
 				ErrorParams,
                 QueryParams,
+                PlatformStatsDTO,
+                BanUserDTO,
+                ImpersonationDTO,
+                ImpersonationLogDTO,
+                PendingEmailDTO,
+                DetailUserResponseDTO,
+                FollowerDTO,
+                FeedPostDTO,
+                ActivityEventDTO,
+                ReadPostDTO,
+                PutDraftDTO,
+                DraftDTO,
+                PromotedDraftDTO,
+                LintWarningDTO,
+                InsertCommentDTO,
+                CommentDTO,
+                CommentCountDTO,
+                CommentCountsDTO,
+                PostDTO,
+                SponsorshipDTO,
+                ImportPostsResultDTO,
+                ImportErrorDTO,
+                InsertWebhookDTO,
+                WebhookDTO,
+                WebhookDeliveryDTO,
+                InsertReadingListDTO,
+                PutReadingListDTO,
+                InsertReadingListItemDTO,
+                ReadingListDTO,
+                ReadingListItemDTO,
+                DetailReadingListDTO,
+                InsertPollDTO,
+                VotePollDTO,
+                PollOptionDTO,
+                PollDTO,
+                ActivityDayDTO,
+                UserBadgeDTO,
+                BadgeSummaryDTO,
+                SnapshotDTO,
+                InsertApiKeyDTO,
+                ApiKeyDTO,
+                InsertAttachmentDTO,
+                AttachmentDTO,
+                TocEntryDTO,
+                PinCommentDTO,
+                InsertInviteCodesDTO,
+                InviteCodeDTO,
+                PutReadingProgressDTO,
+                ReadingProgressDTO,
+                InProgressPostDTO,
+                UserPreferencesDTO,
+                PatchUserPreferencesDTO,
+                UpdatePostDTO,
+                MarkdownImportResultDTO,
+                LoginHistoryDTO,
+                UpdateUsernameDTO,
+                UsernameDTO,
+                UpdateNameDTO,
+                NameHistoryDTO,
+                UserFilterParams,
+                UpdateRoleDTO,
+                DeactivatedUserDTO,
+                MentionSuggestionDTO,
+                RefreshTokenDTO,
+                BatchGetUsersDTO,
+                UserSummaryDTO,
+                InsertContentReportDTO,
+                ContentReportDTO,
+                ResolveContentReportDTO,
+                ContentReportStatsDTO,
+                ReportsByTypeDTO,
+                ReportedAuthorDTO,
+                UserOnboardingDTO,
+                FeatureFlagDTO,
+                PatchFeatureFlagDTO,
+                RssImportDTO,
+                CoverImageDTO,
+                HealthDTO,
+                InsertTenantDTO,
+                TenantDTO,
+                EmbedMetaDTO,
+                ExcerptPostDTO,
+                DiffChunkDTO,
+                PostFilterParams,
+                PostLockDTO,
+                PostLockedDTO,
+                MergeUsersSummaryDTO,
+                FailedQueueItemDTO,
+                ArchiveMonthDTO,
+                CopiedPostDTO,
+                AuthorDTO,
+                PostLinkDTO,
+                PatchPostAuthorsDTO,
 			)
 		),
 		modifiers(& SecurityModifier),
@@ -46,6 +309,14 @@ pub fn api_doc() -> SwaggerUi {
         ),
 		tags((
 		    name = "user", description = "Controladores da entidade de usuário"
+		), (
+		    name = "admin", description = "Controladores de administração da plataforma"
+		), (
+		    name = "post", description = "Controladores da entidade de post"
+		), (
+		    name = "reading_list", description = "Controladores das listas de leitura"
+		), (
+		    name = "static", description = "Arquivos estáticos servidos pela API"
 		)),
 	)]
     pub struct ApiDoc;
@@ -66,6 +337,10 @@ pub fn api_doc() -> SwaggerUi {
                         .build(),
                 ),
             );
+            components.add_security_scheme(
+                "api_key",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-Api-Key"))),
+            );
         }
     }
 
@@ -1,3 +1,4 @@
+use crate::middlewares::idempotency_middleware::IDEMPOTENCY_KEY_HEADER;
 use actix_cors::Cors;
 use actix_web::http;
 use std::env;
@@ -12,5 +13,6 @@ pub fn cors() -> Cors {
         .allowed_methods(vec!["GET", "POST", "PATCH", "DELETE", "OPTIONS", "PUT"])
         .allowed_headers(vec![http::header::AUTHORIZATION, http::header::ACCEPT])
         .allowed_header(http::header::CONTENT_TYPE)
+        .allowed_header(IDEMPOTENCY_KEY_HEADER)
         .max_age(3600)
 }
@@ -0,0 +1,55 @@
+use crate::utils::error_construct::error_construct;
+use actix_web::{
+    error::{InternalError, JsonPayloadError, PathError},
+    web, HttpResponse,
+};
+
+fn bad_request(data: &str, message: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(error_construct(
+        String::from(data),
+        String::from("bad request"),
+        String::from(message),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Converts a `JsonPayloadError` into a 400 response with the default error shape.
+///
+/// The payload size errors are not handled here, since they are answered with a 413 by the
+/// `json_config` of the body size limit middleware.
+pub fn json_payload_error(err: JsonPayloadError) -> actix_web::Error {
+    let message = match &err {
+        JsonPayloadError::ContentType => {
+            "O cabeçalho Content-Type da requisição deve ser application/json."
+        }
+        JsonPayloadError::Deserialize(e) if e.is_data() => {
+            "O corpo da requisição não corresponde ao formato esperado."
+        }
+        JsonPayloadError::Deserialize(_) => "O corpo da requisição não é um JSON válido.",
+        _ => "Não foi possível ler o corpo da requisição.",
+    };
+    InternalError::from_response(err, bad_request("body", message)).into()
+}
+
+/// The global `PathConfig`, answering path extraction failures with a 400 in the default error
+/// shape.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::config::extractors::path_config;
+/// use actix_web::App;
+///
+/// let app = App::new().app_data(path_config());
+/// ```
+pub fn path_config() -> web::PathConfig {
+    web::PathConfig::default().error_handler(|err: PathError, _| {
+        InternalError::from_response(
+            err,
+            bad_request("path", "Os parâmetros da URL da requisição são inválidos."),
+        )
+        .into()
+    })
+}
@@ -1,2 +1,4 @@
 pub mod api_doc;
 pub mod cors;
+pub mod extractors;
+pub mod version_router;
@@ -0,0 +1,60 @@
+use crate::{
+    middlewares::deprecation_middleware::deprecation_middleware,
+    modules::{
+        admin::admin_controllers::admin_controllers_module,
+        comment_like::comment_like_controllers::comment_like_controllers_module,
+        poll::poll_controllers::poll_controllers_module,
+        post::post_controllers::post_controllers_module,
+        reading_list::reading_list_controllers::reading_list_controllers_module,
+        user::user_controllers::user_controllers_module,
+        webhook::webhook_controllers::webhook_controllers_module,
+    },
+};
+use actix_web::{middleware::from_fn, web};
+
+/// Registers the routes of the first version of the API.
+pub fn v1_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(user_controllers_module())
+        .service(admin_controllers_module())
+        .service(post_controllers_module())
+        .service(poll_controllers_module())
+        .service(comment_like_controllers_module())
+        .service(reading_list_controllers_module())
+        .service(webhook_controllers_module());
+}
+
+/// Registers the routes that break compatibility with `/v1`.
+pub fn v2_routes(_cfg: &mut web::ServiceConfig) {}
+
+/// Registers the unprefixed aliases of the `/v1` routes, answering with the deprecation headers.
+///
+/// Every module is wrapped on its own instead of under a `web::scope("")`, since an empty scope
+/// would match every path and hide the services registered after it, like the Swagger UI.
+fn deprecated_v1_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(user_controllers_module().wrap(from_fn(deprecation_middleware)))
+        .service(admin_controllers_module().wrap(from_fn(deprecation_middleware)))
+        .service(post_controllers_module().wrap(from_fn(deprecation_middleware)))
+        .service(poll_controllers_module().wrap(from_fn(deprecation_middleware)))
+        .service(comment_like_controllers_module().wrap(from_fn(deprecation_middleware)))
+        .service(reading_list_controllers_module().wrap(from_fn(deprecation_middleware)))
+        .service(webhook_controllers_module().wrap(from_fn(deprecation_middleware)));
+}
+
+/// Registers every version of the API under its own prefix.
+///
+/// The unprefixed routes are aliases of `/v1` kept for backward compatibility and answer with
+/// the `Deprecation` and `Sunset` headers.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::config::version_router::version_router;
+/// use actix_web::App;
+///
+/// let app = App::new().configure(version_router);
+/// ```
+pub fn version_router(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope("/v1").configure(v1_routes))
+        .service(web::scope("/v2").configure(v2_routes))
+        .configure(deprecated_v1_routes);
+}
@@ -1,7 +1,95 @@
 use deadpool_postgres::{Config, Pool, PoolConfig, Runtime};
+use refinery::{Migration, Runner};
 use std::env;
 use tokio_postgres::NoTls;
 
+/// Embeds a migration from the `migrations/` directory, keeping the `{version}_{name}/up.sql` layout.
+macro_rules! migration {
+    ($version:literal, $name:literal) => {
+        (
+            concat!("V", $version, "__", $name),
+            include_str!(concat!(
+                "../../migrations/",
+                $version,
+                "_",
+                $name,
+                "/up.sql"
+            )),
+        )
+    };
+}
+
+const MIGRATIONS: &[(&str, &str)] = &[
+    migration!("20240530181229", "create_posts"),
+    migration!("20240530181637", "create_users"),
+    migration!("20240530181926", "create_comments"),
+    migration!("20240530182156", "create_tags"),
+    migration!("20240530182301", "create_categories"),
+    migration!("20240530182415", "create_users_comments_likes"),
+    migration!("20240530182605", "create_users_posts_likes"),
+    migration!("20240530182744", "create_posts_tags"),
+    migration!("20240530182924", "create_posts_categories"),
+    migration!("20240616181600", "create_salt"),
+    migration!("20261017120000", "add_published_at_to_posts"),
+    migration!("20261017121000", "add_banned_at_to_users"),
+    migration!("20261017121100", "create_audit_logs"),
+    migration!("20261017122000", "create_user_follows"),
+    migration!("20261017122100", "add_author_id_to_posts"),
+    migration!("20261017123000", "create_post_views"),
+    migration!("20261017124000", "create_drafts"),
+    migration!("20261017125000", "create_notifications"),
+    migration!("20261017126000", "add_sponsorship_to_posts"),
+    migration!("20261017127000", "add_slug_to_posts"),
+    migration!("20261017128000", "add_deleted_at_to_users_posts_comments"),
+    migration!("20261017129000", "create_webhook_subscriptions"),
+    migration!("20261017130000", "add_summary_to_posts"),
+    migration!("20261017131000", "create_reading_lists"),
+    migration!("20261017132000", "create_polls"),
+    migration!("20261017133000", "create_api_keys"),
+    migration!("20261017134000", "create_post_attachments"),
+    migration!("20261017135000", "add_posts_pinned_comment_id"),
+    migration!("20261017136000", "add_users_comments_likes_created_at"),
+    migration!("20261017137000", "create_invite_codes"),
+    migration!("20261017138000", "create_reading_progress"),
+    migration!("20261017139000", "create_user_preferences"),
+    migration!("20261017140000", "add_version_to_posts"),
+    migration!("20261017141000", "add_language_to_posts"),
+    migration!("20261017142000", "create_login_history"),
+    migration!("20261017143000", "add_username_to_users"),
+    migration!("20261017144000", "add_canonical_url_to_posts"),
+    migration!("20261017145000", "add_counts_to_posts"),
+    migration!("20261017146000", "add_scheduled_at_to_posts"),
+    migration!("20261017147000", "create_content_reports"),
+    migration!("20261017148000", "create_user_onboarding"),
+    migration!("20261017149000", "create_feature_flags"),
+    migration!("20261017150000", "add_cover_image_to_posts"),
+    migration!("20261017151000", "add_code_languages_to_posts"),
+    migration!("20261017152000", "allow_repeated_content_reports"),
+    migration!("20261017153000", "create_health_checks"),
+    migration!("20261017154000", "create_tenants"),
+    migration!("20261017155000", "create_user_name_history"),
+    migration!("20261017156000", "add_user_preferences_timezone"),
+    migration!("20261017157000", "create_post_authors"),
+    migration!("20261017158000", "add_anonymous_comments"),
+    migration!("20261017159000", "add_posts_content_hash"),
+    migration!("20261017160000", "add_role_to_users"),
+    migration!("20261017161000", "create_digest_deliveries"),
+    migration!("20261017162000", "create_failed_queue_items"),
+    migration!("20261017163000", "add_deactivated_at_to_users"),
+    migration!("20261017164000", "create_post_revisions"),
+    migration!("20261017165000", "create_post_locks"),
+    migration!("20261017166000", "add_structured_data_to_posts"),
+    migration!("20261017167000", "create_password_history"),
+    migration!("20261017168000", "create_badges"),
+    migration!("20261017169000", "create_snapshots"),
+    migration!("20261017170000", "create_impersonation_logs"),
+    migration!("20261017171000", "create_pending_emails"),
+    migration!("20261017172000", "create_series"),
+    migration!("20261017173000", "add_comment_moderation"),
+    migration!("20261017174000", "check_users_role"),
+    migration!("20261017175000", "scope_post_slugs_to_tenant"),
+];
+
 pub struct Postgres {}
 
 impl Postgres {
@@ -16,4 +104,28 @@ impl Postgres {
             PoolConfig::new(env::var("DB_POOL_SIZE").unwrap().parse::<usize>().unwrap()).into();
         cfg.create_pool(Some(Runtime::Tokio1), NoTls).unwrap()
     }
+
+    /// Applies the pending SQL migrations of the `migrations/` directory.
+    ///
+    /// The applied versions are tracked in the `refinery_schema_history` table, so calling it on
+    /// an already migrated database is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// This function may return an error if:
+    ///
+    /// - It is not possible to obtain a connection from the pool.
+    /// - A migration name does not follow the `{version}_{name}` pattern.
+    /// - A migration fails to be applied.
+    pub async fn run_migrations(pool: &Pool) -> Result<(), anyhow::Error> {
+        let mut migrations = Vec::with_capacity(MIGRATIONS.len());
+        for (name, sql) in MIGRATIONS {
+            migrations.push(Migration::unapplied(name, sql)?);
+        }
+
+        let mut conn = pool.get().await?;
+        Runner::new(&migrations).run_async(&mut **conn).await?;
+
+        Ok(())
+    }
 }
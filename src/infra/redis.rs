@@ -1,10 +1,17 @@
 use deadpool_redis::{
-    redis::{cmd, RedisResult},
+    redis::{cmd, ErrorKind, RedisError, RedisResult},
     Config, ConnectionAddr, ConnectionInfo, Pool, PoolConfig, RedisConnectionInfo, Runtime,
     Timeouts,
 };
 use std::{env, time::Duration};
 
+const INCR_BY_IF_EXISTS_SCRIPT: &str = "
+    if redis.call('EXISTS', KEYS[1]) == 1 then
+        return redis.call('INCRBY', KEYS[1], ARGV[1])
+    end
+    return false
+";
+
 pub struct Redis {}
 
 impl Redis {
@@ -16,6 +23,34 @@ impl Redis {
             .await
     }
 
+    pub async fn set_ex(
+        redis_pool: &Pool,
+        key: &str,
+        value: &str,
+        seconds: u64,
+    ) -> RedisResult<()> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("SET")
+            .arg(&[key, value, "EX", &seconds.to_string()])
+            .query_async::<_, ()>(&mut redis_conn)
+            .await
+    }
+
+    /// Sets `key` only if it does not exist yet, returning whether it was set.
+    pub async fn set_nx_ex(
+        redis_pool: &Pool,
+        key: &str,
+        value: &str,
+        seconds: u64,
+    ) -> RedisResult<bool> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("SET")
+            .arg(&[key, value, "NX", "EX", &seconds.to_string()])
+            .query_async::<_, Option<String>>(&mut redis_conn)
+            .await
+            .map(|reply| reply.is_some())
+    }
+
     pub async fn get(redis_pool: &Pool, key: &str) -> RedisResult<String> {
         let mut redis_conn = redis_pool.get().await.unwrap();
         cmd("GET")
@@ -32,6 +67,144 @@ impl Redis {
             .await
     }
 
+    /// Deletes every key matching the glob `pattern`, iterating with `SCAN` to avoid blocking Redis.
+    pub async fn delete_by_pattern(redis_pool: &Pool, pattern: &str) -> RedisResult<i32> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        let mut cursor: u64 = 0;
+        let mut deleted = 0;
+        loop {
+            let (next_cursor, keys) = cmd("SCAN")
+                .arg(cursor)
+                .arg(&["MATCH", pattern, "COUNT", "100"])
+                .query_async::<_, (u64, Vec<String>)>(&mut redis_conn)
+                .await?;
+            if !keys.is_empty() {
+                deleted += cmd("DEL")
+                    .arg(&keys)
+                    .query_async::<_, i32>(&mut redis_conn)
+                    .await?;
+            }
+            if next_cursor == 0 {
+                return Ok(deleted);
+            }
+            cursor = next_cursor;
+        }
+    }
+
+    /// Returns every key matching the glob `pattern`, iterating with `SCAN` to avoid blocking Redis.
+    pub async fn scan_keys(redis_pool: &Pool, pattern: &str) -> RedisResult<Vec<String>> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        let mut cursor: u64 = 0;
+        let mut keys = Vec::new();
+        loop {
+            let (next_cursor, page) = cmd("SCAN")
+                .arg(cursor)
+                .arg(&["MATCH", pattern, "COUNT", "100"])
+                .query_async::<_, (u64, Vec<String>)>(&mut redis_conn)
+                .await?;
+            keys.extend(page);
+            if next_cursor == 0 {
+                return Ok(keys);
+            }
+            cursor = next_cursor;
+        }
+    }
+
+    pub async fn incr_by(redis_pool: &Pool, key: &str, increment: i64) -> RedisResult<i64> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("INCRBY")
+            .arg(key)
+            .arg(increment)
+            .query_async::<_, i64>(&mut redis_conn)
+            .await
+    }
+
+    /// Increments `key` by `increment` only if it exists, keeping its expiration, and returns the
+    /// new value or `None` when the key was not set.
+    pub async fn incr_by_if_exists(
+        redis_pool: &Pool,
+        key: &str,
+        increment: i64,
+    ) -> RedisResult<Option<i64>> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("EVAL")
+            .arg(INCR_BY_IF_EXISTS_SCRIPT)
+            .arg(1)
+            .arg(key)
+            .arg(increment)
+            .query_async::<_, Option<i64>>(&mut redis_conn)
+            .await
+    }
+
+    /// Increments `key` by one, setting it to expire in `seconds` when the increment creates it.
+    pub async fn incr_ex(redis_pool: &Pool, key: &str, seconds: u64) -> RedisResult<i64> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        let value = cmd("INCR")
+            .arg(key)
+            .query_async::<_, i64>(&mut redis_conn)
+            .await?;
+        if value == 1 {
+            cmd("EXPIRE")
+                .arg(key)
+                .arg(seconds)
+                .query_async::<_, i32>(&mut redis_conn)
+                .await?;
+        }
+        Ok(value)
+    }
+
+    /// Gets the value of `key` and deletes it atomically.
+    pub async fn get_del(redis_pool: &Pool, key: &str) -> RedisResult<Option<String>> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("GETDEL")
+            .arg(&[key])
+            .query_async::<_, Option<String>>(&mut redis_conn)
+            .await
+    }
+
+    pub async fn sadd(redis_pool: &Pool, key: &str, member: &str) -> RedisResult<i32> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("SADD")
+            .arg(&[key, member])
+            .query_async::<_, i32>(&mut redis_conn)
+            .await
+    }
+
+    pub async fn srem(redis_pool: &Pool, key: &str, member: &str) -> RedisResult<i32> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("SREM")
+            .arg(&[key, member])
+            .query_async::<_, i32>(&mut redis_conn)
+            .await
+    }
+
+    /// Unlike the other commands, a connection that can not be taken from the pool is returned as
+    /// an error, so the callers can fall back to another source when Redis is down.
+    pub async fn sismember(redis_pool: &Pool, key: &str, member: &str) -> RedisResult<bool> {
+        let mut redis_conn = match redis_pool.get().await {
+            Ok(redis_conn) => redis_conn,
+            Err(e) => {
+                return Err(RedisError::from((
+                    ErrorKind::IoError,
+                    "redis pool unavailable",
+                    e.to_string(),
+                )))
+            }
+        };
+        cmd("SISMEMBER")
+            .arg(&[key, member])
+            .query_async::<_, bool>(&mut redis_conn)
+            .await
+    }
+
+    pub async fn publish(redis_pool: &Pool, channel: &str, message: &str) -> RedisResult<i32> {
+        let mut redis_conn = redis_pool.get().await.unwrap();
+        cmd("PUBLISH")
+            .arg(&[channel, message])
+            .query_async::<_, i32>(&mut redis_conn)
+            .await
+    }
+
     pub async fn pool() -> Pool {
         let mut cfg = Config::default();
         cfg.connection = Some(ConnectionInfo {
@@ -1,14 +1,42 @@
-use actix_web::{http::KeepAlive, web, App, HttpServer};
-use config::{api_doc::api_doc, cors::cors};
+use actix_web::{http::KeepAlive, middleware::from_fn, web, App, HttpServer};
+use config::{
+    api_doc::api_doc, cors::cors, extractors::path_config, version_router::version_router,
+};
 use infra::{postgres::Postgres, redis::Redis};
-use modules::user::{
-    user_controllers::user_controllers_module,
-    user_queues::{
-        delete_user_flush_queue, insert_user_flush_queue, put_user_flush_queue, DeleteUserAppQueue,
-        InsertUserAppQueue, PutUserAppQueue,
+use middlewares::{
+    admin_middleware::admin_role_validation_middleware,
+    body_size_limit_middleware::{body_size_limit_middleware, json_config},
+    geo_restriction_middleware::geo_restriction_middleware,
+    gzip_compress_middleware::gzip_compress_middleware,
+    in_flight_middleware::in_flight_middleware,
+    json_charset_middleware::json_charset_middleware,
+    maintenance_mode_middleware::maintenance_mode_middleware,
+    security_headers_middleware::security_headers_middleware,
+    tenant_middleware::tenant_validation_middleware,
+};
+use modules::{
+    activitypub::activitypub_controllers::activitypub_controllers_module,
+    cache_warmup::cache_warmup_services::{warm_cache_service, CACHE_WARMUP_ENABLED},
+    comment_like::comment_like_queues::comment_likes_flush_queue,
+    dead_letter::dead_letter_queues::dead_letter_reprocess_job,
+    feature_flag::{
+        feature_flag_dtos::SharedFeatureFlags, feature_flag_queues::feature_flags_refresh_job,
+        feature_flag_services::load_feature_flags_service,
+    },
+    feed::feed_controllers::feed_controllers_module,
+    health::health_controllers::health_controllers_module,
+    metrics::metrics_controllers::metrics_controllers_module,
+    newsletter::newsletter_queues::{digest_job, pending_emails_job},
+    post::post_queues::{post_locks_expiration_job, post_scheduler_job},
+    snapshot::snapshot_queues::content_snapshot_job,
+    static_files::static_files_controllers::static_files_controllers_module,
+    user::user_queues::{
+        delete_user_flush_queue, drain_insert_user_queue, insert_user_flush_queue,
+        put_user_flush_queue, DeleteUserAppQueue, InsertUserAppQueue, PutUserAppQueue,
     },
 };
 use std::{env, net::Ipv4Addr, sync::Arc};
+use utils::graceful_shutdown::{graceful_shutdown, QUEUE_DRAIN_TIMEOUT, SHUTDOWN_TIMEOUT_SECS};
 
 mod config;
 mod infra;
@@ -19,8 +47,12 @@ mod utils;
 
 /// The main function that starts the server
 ///
-/// It creates the database connection pool (Postgres and Redis), and starts the server with the configured routes.
-/// It also creates the queues for the user module to handle the asynchronous operations.
+/// It creates the database connection pool (Postgres and Redis), applies the pending migrations, and starts the server with the configured routes.
+/// It also creates the queues for the user module to handle the asynchronous operations, and spawns the
+/// background jobs that retry the failed user insertions, flush the comment likes, publish the scheduled posts, expire the post
+/// locks, send the weekly digest and the e-mails held by the hourly limit, and refresh the feature flags. Once the server is running, the Redis cache is warmed in the
+/// background unless `CACHE_WARMUP_ENABLED` is `false`.
+/// On `SIGTERM` or `SIGINT` the server drains the in-flight requests and flushes the user insertion queue before exiting.
 ///
 /// # Internal Variables
 ///
@@ -29,6 +61,7 @@ mod utils;
 /// - `insert_user_queue`: A queue for inserting a new user.
 /// - `delete_user_queue`: A queue for deleting an existing user.
 /// - `put_user_queue`: A queue for updating users for completely.
+/// - `feature_flags`: The feature flags loaded at startup and refreshed every minute.
 ///
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -38,9 +71,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let redis_pool = Redis::pool().await;
     let postgres_pool = Postgres::pool();
 
+    if let Err(e) = Postgres::run_migrations(&postgres_pool).await {
+        log::error!("Failed to apply database migrations: {:#}", e);
+        std::process::exit(1);
+    }
+
+    let feature_flags: SharedFeatureFlags = Arc::default();
+    match load_feature_flags_service(web::Data::new(postgres_pool.clone())).await {
+        Ok(flags) => *feature_flags.write().unwrap() = flags,
+        Err(e) => log::warn!("Failed to load the feature flags: status {}", e.status()),
+    };
+
     let insert_pool_async = postgres_pool.clone();
     let insert_user_queue = Arc::new(InsertUserAppQueue::new());
     let insert_user_queue_async = insert_user_queue.clone();
+    let insert_pool_drain = postgres_pool.clone();
+    let insert_user_queue_drain = insert_user_queue.clone();
 
     let delete_pool_async = postgres_pool.clone();
     let delete_user_queue = Arc::new(DeleteUserAppQueue::new());
@@ -58,24 +104,111 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     });
     tokio::spawn(async move { put_user_flush_queue(put_pool_async, put_user_queue_async).await });
 
-    HttpServer::new(move || {
+    let dead_letter_pool_async = postgres_pool.clone();
+    let dead_letter_redis_pool_async = redis_pool.clone();
+    let dead_letter_queue_async = insert_user_queue.clone();
+    tokio::spawn(async move {
+        dead_letter_reprocess_job(
+            dead_letter_pool_async,
+            dead_letter_redis_pool_async,
+            dead_letter_queue_async,
+        )
+        .await
+    });
+
+    let comment_likes_pool_async = postgres_pool.clone();
+    let comment_likes_redis_pool_async = redis_pool.clone();
+    tokio::spawn(async move {
+        comment_likes_flush_queue(comment_likes_pool_async, comment_likes_redis_pool_async).await
+    });
+
+    let post_scheduler_pool_async = postgres_pool.clone();
+    let post_scheduler_redis_pool_async = redis_pool.clone();
+    tokio::spawn(async move {
+        post_scheduler_job(post_scheduler_pool_async, post_scheduler_redis_pool_async).await
+    });
+
+    let post_locks_pool_async = postgres_pool.clone();
+    tokio::spawn(async move { post_locks_expiration_job(post_locks_pool_async).await });
+
+    let digest_pool_async = postgres_pool.clone();
+    let digest_redis_pool_async = redis_pool.clone();
+    tokio::spawn(async move { digest_job(digest_pool_async, digest_redis_pool_async).await });
+
+    let pending_emails_pool_async = postgres_pool.clone();
+    let pending_emails_redis_pool_async = redis_pool.clone();
+    tokio::spawn(async move {
+        pending_emails_job(pending_emails_pool_async, pending_emails_redis_pool_async).await
+    });
+
+    let snapshot_pool_async = postgres_pool.clone();
+    tokio::spawn(async move { content_snapshot_job(snapshot_pool_async).await });
+
+    let feature_flags_pool_async = postgres_pool.clone();
+    let feature_flags_async = feature_flags.clone();
+    tokio::spawn(async move {
+        feature_flags_refresh_job(feature_flags_pool_async, feature_flags_async).await
+    });
+
+    let warmup_pool_async = postgres_pool.clone();
+    let warmup_redis_pool_async = redis_pool.clone();
+
+    let server = HttpServer::new(move || {
         App::new()
+            .wrap(from_fn(admin_role_validation_middleware))
+            .wrap(from_fn(tenant_validation_middleware))
+            .wrap(from_fn(maintenance_mode_middleware))
+            .wrap(from_fn(body_size_limit_middleware))
+            .wrap(from_fn(geo_restriction_middleware))
             .wrap(cors())
+            .wrap(from_fn(json_charset_middleware))
+            .wrap(from_fn(gzip_compress_middleware))
+            .wrap(from_fn(security_headers_middleware))
+            .wrap(from_fn(in_flight_middleware))
+            .app_data(json_config())
+            .app_data(path_config())
             .app_data(web::Data::new(postgres_pool.clone()))
             .app_data(web::Data::new(redis_pool.clone()))
             .app_data(web::Data::new(insert_user_queue.clone()))
             .app_data(web::Data::new(delete_user_queue.clone()))
             .app_data(web::Data::new(put_user_queue.clone()))
-            .service(user_controllers_module())
+            .app_data(web::Data::new(feature_flags.clone()))
+            .configure(version_router)
+            .configure(feed_controllers_module)
+            .configure(activitypub_controllers_module)
+            .configure(static_files_controllers_module)
+            .configure(health_controllers_module)
+            .configure(metrics_controllers_module)
             .service(api_doc())
     })
     .keep_alive(KeepAlive::Os)
+    .disable_signals()
+    .shutdown_timeout(*SHUTDOWN_TIMEOUT_SECS)
     .bind((
         Ipv4Addr::UNSPECIFIED,
         env::var("HTTP_PORT").unwrap().parse().unwrap(),
     ))?
-    .run()
-    .await?;
+    .run();
+
+    tokio::spawn(graceful_shutdown(server.handle()));
+    if *CACHE_WARMUP_ENABLED {
+        tokio::spawn(async move {
+            warm_cache_service(
+                web::Data::new(warmup_pool_async),
+                web::Data::new(warmup_redis_pool_async),
+            )
+            .await
+        });
+    }
+    server.await?;
+    log::info!("All in-flight requests completed.");
+
+    drain_insert_user_queue(
+        insert_pool_drain,
+        insert_user_queue_drain,
+        *QUEUE_DRAIN_TIMEOUT,
+    )
+    .await;
 
     Ok(())
 }
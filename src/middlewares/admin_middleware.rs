@@ -0,0 +1,114 @@
+use super::jwt_token_middleware::jwt_token_middleware;
+use crate::{
+    shared::structs::jwt_claims::Claims,
+    utils::{error_construct::error_construct, query_constructor_executor::query_executor},
+};
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderMap, AUTHORIZATION},
+    middleware::Next,
+    web, Error, HttpResponse,
+};
+use jsonwebtoken::TokenData;
+
+const ADMIN_ROLE_SQL: &str =
+    "SELECT id FROM users WHERE id = $1 AND role = 'admin' AND deleted_at IS NULL";
+
+fn admin_forbidden() -> HttpResponse {
+    HttpResponse::Forbidden().json(error_construct(
+        String::from("bearer token"),
+        String::from("forbidden"),
+        String::from("Acesso restrito a administradores."),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Middleware to restrict a route to administrators.
+///
+/// This function validates the bearer token of the request and checks if the `role` claim is `admin`.
+/// The claim is minted from `users.role`, and impersonation tokens are never accepted, even if
+/// their role claim were `admin`.
+///
+/// # Parameters
+///
+/// - `headers`: The headers of the request.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains the decoded token. On failure, returns an `HttpResponse` with the corresponding error.
+///
+/// # Errors
+///
+/// This function may return an error if:
+///
+/// - The `Authorization` header is missing or invalid.
+/// - The token does not belong to an administrator.
+/// - The token is an impersonation token.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::admin_middleware::admin_middleware;
+/// use actix_web::{HttpRequest, HttpResponse};
+///
+/// pub async fn example(req: HttpRequest) -> Result<(), HttpResponse> {
+///     match admin_middleware(req.headers()) {
+///         Ok(_) => Ok(()),
+///         Err(e) => return Err(e),
+///     }
+/// };
+/// ```
+pub fn admin_middleware(headers: &HeaderMap) -> Result<TokenData<Claims>, HttpResponse> {
+    let token = jwt_token_middleware(headers)?;
+
+    if token.claims.role != "admin" || token.claims.impersonated_by.is_some() {
+        return Err(admin_forbidden());
+    }
+    Ok(token)
+}
+
+/// Middleware to refuse the access tokens with the `admin` role claim whose user is not an
+/// administrator in `users.role`, answering `403 Forbidden`.
+///
+/// The role claim alone is not trusted by the admin routes: a token minted before the role was
+/// read from the database, or before the user was demoted, still carries `admin` until it expires.
+/// Tokens without the `admin` claim and invalid tokens are left for the route to handle.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::admin_middleware::admin_role_validation_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(admin_role_validation_middleware));
+/// ```
+pub async fn admin_role_validation_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let token = match req.headers().contains_key(AUTHORIZATION) {
+        true => jwt_token_middleware(req.headers()).ok(),
+        false => None,
+    };
+    let pg_pool = req
+        .app_data::<web::Data<deadpool_postgres::Pool>>()
+        .cloned();
+
+    if let (Some(token), Some(pg_pool)) = (token, pg_pool) {
+        if token.claims.role == "admin" {
+            let user_id = uuid::Uuid::parse_str(&token.claims.sub).unwrap_or_default();
+            let res = match query_executor(pg_pool, ADMIN_ROLE_SQL, &[&user_id]).await {
+                Ok(rows) if rows.is_empty() => Some(admin_forbidden()),
+                Ok(_) => None,
+                Err(e) => Some(e),
+            };
+            if let Some(res) = res {
+                return Ok(req.into_response(res).map_into_right_body());
+            }
+        }
+    }
+    Ok(next.call(req).await?.map_into_left_body())
+}
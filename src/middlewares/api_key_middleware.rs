@@ -0,0 +1,155 @@
+use super::jwt_token_middleware::jwt_token_middleware;
+use crate::{
+    modules::api_key::{
+        api_key_dtos::ApiKeyUserDTO,
+        api_key_services::{find_api_key_service, hash_api_key},
+    },
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpMessage, HttpRequest, HttpResponse};
+
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+
+fn api_key_unauthorized(message: &str) -> HttpResponse {
+    HttpResponse::Unauthorized().json(error_construct(
+        String::from("api key"),
+        String::from("unauthorized"),
+        String::from(message),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Checks that the key exists, has not expired and grants `scope`.
+///
+/// # Errors
+///
+/// This function may return an error if:
+///
+/// - The key was not found (401).
+/// - The key has expired (401).
+/// - The key does not grant `scope` (403).
+pub fn authorize_api_key(
+    api_key: Option<ApiKeyUserDTO>,
+    scope: &str,
+) -> Result<ApiKeyUserDTO, HttpResponse> {
+    let api_key = match api_key {
+        Some(api_key) => api_key,
+        None => return Err(api_key_unauthorized("Chave de API inválida.")),
+    };
+    if let Some(expires_at) = api_key.expires_at {
+        if expires_at <= chrono::Utc::now().timestamp() {
+            return Err(api_key_unauthorized("A chave de API expirou."));
+        }
+    }
+    if !api_key.scopes.iter().any(|granted| granted == scope) {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("api key"),
+            String::from("forbidden"),
+            format!("A chave de API não possui o escopo {}.", scope),
+            None,
+            None,
+            None,
+        )));
+    }
+    Ok(api_key)
+}
+
+/// Middleware to authenticate machine-to-machine requests by the `X-Api-Key` header.
+///
+/// The key is hashed with SHA-256 and looked up in Postgres (cached in Redis for 60 seconds). On
+/// success, the key's user is also inserted in the request extensions as an `ApiKeyUserDTO`.
+///
+/// # Parameters
+///
+/// - `req`: The request, with the Postgres and Redis pools in its app data.
+/// - `scope`: The scope the key must grant, such as `post:write`.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains the user and scopes of the key. On failure, returns an `HttpResponse` with the corresponding error.
+///
+/// # Errors
+///
+/// This function may return an error if:
+///
+/// - The `X-Api-Key` header is missing (400).
+/// - The key is invalid or has expired (401).
+/// - The key does not grant `scope` (403).
+/// - The lookup in Postgres fails.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::api_key_middleware::api_key_middleware;
+/// use actix_web::{HttpRequest, HttpResponse};
+///
+/// pub async fn example(req: HttpRequest) -> Result<String, HttpResponse> {
+///     match api_key_middleware(&req, "post:read").await {
+///         Ok(api_key) => Ok(api_key.user_id),
+///         Err(e) => return Err(e),
+///     }
+/// }
+/// ```
+pub async fn api_key_middleware(
+    req: &HttpRequest,
+    scope: &str,
+) -> Result<ApiKeyUserDTO, HttpResponse> {
+    let key = match req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|key| key.to_str().ok())
+    {
+        Some(key) => key.trim(),
+        None => {
+            return Err(HttpResponse::BadRequest().json(error_construct(
+                String::from("api key"),
+                String::from("bad request"),
+                String::from("O valor do cabeçalho 'X-Api-Key' deve ser informado."),
+                None,
+                None,
+                None,
+            )))
+        }
+    };
+    let (pg_pool, redis_pool) = match (
+        req.app_data::<Data<deadpool_postgres::Pool>>(),
+        req.app_data::<Data<deadpool_redis::Pool>>(),
+    ) {
+        (Some(pg_pool), Some(redis_pool)) => (pg_pool.clone(), redis_pool.clone()),
+        _ => return Err(HttpResponse::InternalServerError().finish()),
+    };
+
+    let api_key = match find_api_key_service(pg_pool, &redis_pool, &hash_api_key(key)).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let api_key = match authorize_api_key(api_key, scope) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    req.extensions_mut().insert(api_key.clone());
+    Ok(api_key)
+}
+
+/// Authenticates the request by the `X-Api-Key` header when it is sent, or else by the bearer token.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains the id of the authenticated user. On failure, returns an `HttpResponse` with the corresponding error.
+pub async fn jwt_or_api_key_middleware(
+    req: &HttpRequest,
+    scope: &str,
+) -> Result<String, HttpResponse> {
+    if req.headers().contains_key(API_KEY_HEADER) {
+        return match api_key_middleware(req, scope).await {
+            Ok(api_key) => Ok(api_key.user_id),
+            Err(e) => Err(e),
+        };
+    }
+    match jwt_token_middleware(req.headers()) {
+        Ok(token) => Ok(token.claims.sub),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{config::extractors::json_payload_error, utils::error_construct::error_construct};
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    error::{InternalError, JsonPayloadError},
+    http::header::CONTENT_LENGTH,
+    middleware::Next,
+    web, Error, HttpResponse,
+};
+use once_cell::sync::Lazy;
+use std::env;
+
+const UPLOAD_PATHS: [&str; 2] = ["/admin/post/import", "/admin/post/import/markdown"];
+
+pub static BODY_SIZE_LIMIT_BYTES: Lazy<usize> = Lazy::new(|| {
+    env::var("BODY_SIZE_LIMIT_BYTES")
+        .ok()
+        .and_then(|limit| limit.parse().ok())
+        .unwrap_or(1024 * 1024)
+});
+
+pub static UPLOAD_BODY_SIZE_LIMIT_BYTES: Lazy<usize> = Lazy::new(|| {
+    env::var("UPLOAD_BODY_SIZE_LIMIT_BYTES")
+        .ok()
+        .and_then(|limit| limit.parse().ok())
+        .unwrap_or(10 * 1024 * 1024)
+});
+
+/// Returns the body size limit of the path, where the upload endpoints get the larger one.
+pub fn body_size_limit(path: &str) -> usize {
+    if UPLOAD_PATHS.contains(&path) {
+        return *UPLOAD_BODY_SIZE_LIMIT_BYTES;
+    }
+    *BODY_SIZE_LIMIT_BYTES
+}
+
+pub fn payload_too_large(limit: usize) -> HttpResponse {
+    HttpResponse::PayloadTooLarge().json(error_construct(
+        String::from("body"),
+        String::from("payload too large"),
+        format!("O corpo da requisição deve ter no máximo {} bytes.", limit),
+        None,
+        None,
+        i32::try_from(limit).ok(),
+    ))
+}
+
+/// The global `JsonConfig`, limited to `BODY_SIZE_LIMIT_BYTES` and answering oversized bodies with
+/// a 413 error and any other malformed body with a 400, both in the default error shape.
+pub fn json_config() -> web::JsonConfig {
+    web::JsonConfig::default()
+        .limit(*BODY_SIZE_LIMIT_BYTES)
+        .error_handler(|err, _| match err {
+            JsonPayloadError::Overflow { limit }
+            | JsonPayloadError::OverflowKnownLength { limit, .. } => {
+                InternalError::from_response(err, payload_too_large(limit)).into()
+            }
+            err => json_payload_error(err),
+        })
+}
+
+/// Middleware to reject requests whose `Content-Length` exceeds the body size limit of the path.
+///
+/// Requests to the `/admin/post/import` endpoints are limited by the `UPLOAD_BODY_SIZE_LIMIT_BYTES` env var
+/// (default 10 MiB) and all the others by `BODY_SIZE_LIMIT_BYTES` (default 1 MiB).
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::body_size_limit_middleware::{body_size_limit_middleware, json_config};
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new()
+///     .app_data(json_config())
+///     .wrap(from_fn(body_size_limit_middleware));
+/// ```
+pub async fn body_size_limit_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let limit = body_size_limit(req.path());
+    let content_length = req
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if content_length.is_some_and(|length| length > limit) {
+        return Ok(req
+            .into_response(payload_too_large(limit))
+            .map_into_right_body());
+    }
+    Ok(next.call(req).await?.map_into_left_body())
+}
@@ -0,0 +1,47 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue},
+    middleware::Next,
+    Error,
+};
+use once_cell::sync::Lazy;
+use std::env;
+
+const DEFAULT_SUNSET: &str = "Thu, 31 Dec 2026 23:59:59 GMT";
+
+static SUNSET_VALUE: Lazy<HeaderValue> = Lazy::new(|| {
+    env::var("UNVERSIONED_ROUTES_SUNSET")
+        .ok()
+        .filter(|sunset| !sunset.is_empty())
+        .and_then(|sunset| HeaderValue::from_str(&sunset).ok())
+        .unwrap_or(HeaderValue::from_static(DEFAULT_SUNSET))
+});
+
+/// Middleware to flag the responses of a deprecated route.
+///
+/// It sets the `Deprecation` header and a `Sunset` header with the date the route stops being
+/// served, read as an HTTP-date from the `UNVERSIONED_ROUTES_SUNSET` env var.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::deprecation_middleware::deprecation_middleware;
+/// use actix_web::{middleware::from_fn, web};
+///
+/// let scope = web::scope("").wrap(from_fn(deprecation_middleware));
+/// ```
+pub async fn deprecation_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let mut res = next.call(req).await?;
+
+    let headers = res.headers_mut();
+    headers.insert(
+        HeaderName::from_static("deprecation"),
+        HeaderValue::from_static("true"),
+    );
+    headers.insert(HeaderName::from_static("sunset"), SUNSET_VALUE.clone());
+    Ok(res)
+}
@@ -0,0 +1,153 @@
+use crate::{infra::redis::Redis, utils::error_construct::error_construct};
+use actix_web::{
+    http::header::{HeaderMap, ETAG, IF_NONE_MATCH},
+    HttpRequest, HttpResponse,
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+const ETAG_EXPIRATION_SECONDS: u64 = 300;
+
+fn etag_key(route: &str, id: &str) -> String {
+    format!("etag:{}:{}", route, id)
+}
+
+fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    match headers
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) => value.split(',').any(|candidate| {
+            let candidate = candidate.trim();
+            candidate == "*" || candidate.trim_start_matches("W/") == etag
+        }),
+        None => false,
+    }
+}
+
+fn not_modified(etag: &str) -> HttpResponse {
+    HttpResponse::NotModified()
+        .append_header((ETAG, etag))
+        .finish()
+}
+
+/// Middleware to check if the client already has the current version of a resource.
+///
+/// It compares the `If-None-Match` header with the ETag stored in Redis under `etag:{route}:{id}`,
+/// so it must be called after the authentication middlewares of the route.
+///
+/// # Parameters
+///
+/// - `redis_pool`: A connection pool for the Redis database.
+/// - `route`: The name of the route of the resource.
+/// - `id`: The ID of the resource.
+/// - `req`: The request object.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, return an empty tuple. On failure, returns an `HttpResponse` with the `304 Not Modified` status.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::etag_middleware::etag_middleware;
+/// use actix_web::{HttpRequest, HttpResponse};
+///
+/// pub async fn example(redis_pool: deadpool_redis::Pool, id: String, req: HttpRequest) -> Result<(), HttpResponse> {
+///     match etag_middleware(&redis_pool, "user", &id, &req).await {
+///         Ok(_) => Ok(()),
+///         Err(e) => return Err(e),
+///     }
+/// };
+/// ```
+pub async fn etag_middleware(
+    redis_pool: &deadpool_redis::Pool,
+    route: &str,
+    id: &str,
+    req: &HttpRequest,
+) -> Result<(), HttpResponse> {
+    if req.headers().get(IF_NONE_MATCH).is_none() {
+        return Ok(());
+    }
+    match Redis::get(redis_pool, &etag_key(route, id)).await {
+        Ok(etag) if if_none_match(req.headers(), &etag) => Err(not_modified(&etag)),
+        _ => Ok(()),
+    }
+}
+
+/// Constructs the `200 OK` JSON response of a resource with its `ETag` header.
+///
+/// The ETag is the SHA-256 hash of the serialized body, and it is stored in Redis under
+/// `etag:{route}:{id}` for 300 seconds. If the `If-None-Match` header matches it, a
+/// `304 Not Modified` response without body is returned instead.
+///
+/// # Parameters
+///
+/// - `redis_pool`: A connection pool for the Redis database.
+/// - `route`: The name of the route of the resource.
+/// - `id`: The ID of the resource.
+/// - `req`: The request object.
+/// - `body`: The resource to be serialized.
+///
+/// # Returns
+///
+/// Returns an `HttpResponse` with the serialized resource or the `304 Not Modified` status.
+pub async fn etag_response<T: Serialize>(
+    redis_pool: &deadpool_redis::Pool,
+    route: &str,
+    id: &str,
+    req: &HttpRequest,
+    body: &T,
+) -> HttpResponse {
+    let body = match serde_json::to_string(body) {
+        Ok(body) => body,
+        Err(e) => {
+            return HttpResponse::InternalServerError().json(error_construct(
+                String::from("server"),
+                String::from("internal server error"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            ))
+        }
+    };
+    let etag = format!("\"{}\"", hex::encode(Sha256::digest(body.as_bytes())));
+    let _ = Redis::set_ex(
+        redis_pool,
+        &etag_key(route, id),
+        &etag,
+        ETAG_EXPIRATION_SECONDS,
+    )
+    .await;
+
+    if if_none_match(req.headers(), &etag) {
+        return not_modified(&etag);
+    }
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .append_header((ETAG, etag))
+        .body(body)
+}
+
+/// Removes the ETag stored for a resource, it must be called when the resource is updated or deleted.
+///
+/// # Parameters
+///
+/// - `redis_pool`: A connection pool for the Redis database.
+/// - `route`: The name of the route of the resource.
+/// - `id`: The ID of the resource.
+pub async fn etag_invalidate(redis_pool: &deadpool_redis::Pool, route: &str, id: &str) {
+    let _ = Redis::delete(redis_pool, &etag_key(route, id)).await;
+}
+
+/// Removes the ETags stored for every resource of a route, for resources cached under a key that
+/// is not known when they are updated, like the old slug of a renamed post.
+///
+/// # Parameters
+///
+/// - `redis_pool`: A connection pool for the Redis database.
+/// - `route`: The name of the route of the resources.
+pub async fn etag_invalidate_route(redis_pool: &deadpool_redis::Pool, route: &str) {
+    let _ = Redis::delete_by_pattern(redis_pool, &etag_key(route, "*")).await;
+}
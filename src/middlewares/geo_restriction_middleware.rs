@@ -0,0 +1,112 @@
+use crate::utils::{client_ip::client_ip, error_construct::error_construct};
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web, Error, HttpResponse,
+};
+use maxminddb::geoip2;
+use once_cell::sync::Lazy;
+use std::{env, net::IpAddr, sync::Arc};
+
+/// Resolves the ISO 3166-1 alpha-2 country code of an IP address.
+pub trait CountryLookup: Send + Sync {
+    fn country_code(&self, ip: IpAddr) -> Option<String>;
+}
+
+impl CountryLookup for maxminddb::Reader<Vec<u8>> {
+    fn country_code(&self, ip: IpAddr) -> Option<String> {
+        let country: geoip2::Country = self.lookup(ip).ok()?;
+        country.country?.iso_code.map(String::from)
+    }
+}
+
+/// The country lookup and the blocked countries used by [`geo_restriction_middleware`].
+pub struct GeoRestriction {
+    pub lookup: Option<Arc<dyn CountryLookup>>,
+    pub blocked_countries: Vec<String>,
+}
+
+impl GeoRestriction {
+    /// Loads the MaxMind GeoLite2-Country database from the `MAXMIND_DB_PATH` env var and the
+    /// comma separated `BLOCKED_COUNTRIES` codes.
+    pub fn from_env() -> Self {
+        let lookup = env::var("MAXMIND_DB_PATH")
+            .ok()
+            .filter(|path| !path.is_empty())
+            .and_then(|path| match maxminddb::Reader::open_readfile(&path) {
+                Ok(reader) => Some(Arc::new(reader) as Arc<dyn CountryLookup>),
+                Err(e) => {
+                    log::error!("Failed to open the MaxMind database at {}: {}", path, e);
+                    None
+                }
+            });
+        let blocked_countries = env::var("BLOCKED_COUNTRIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(|code| code.trim().to_uppercase())
+            .filter(|code| !code.is_empty())
+            .collect();
+        GeoRestriction {
+            lookup,
+            blocked_countries,
+        }
+    }
+
+    /// Returns the country code of the IP when it is blocked.
+    pub fn blocked_country(&self, ip: IpAddr) -> Option<String> {
+        let code = self.lookup.as_ref()?.country_code(ip)?;
+        self.blocked_countries
+            .iter()
+            .any(|blocked| blocked.eq_ignore_ascii_case(&code))
+            .then_some(code)
+    }
+}
+
+static GEO_RESTRICTION: Lazy<GeoRestriction> = Lazy::new(GeoRestriction::from_env);
+
+/// Middleware to answer `451 Unavailable For Legal Reasons` to clients whose IP is located in one
+/// of the `BLOCKED_COUNTRIES`.
+///
+/// The IP is the one of the connection, or the one in `X-Forwarded-For` when the connection comes
+/// from one of the `TRUSTED_PROXIES`.
+///
+/// It is a no-op when the `MAXMIND_DB_PATH` env var is not set. A `web::Data<GeoRestriction>` in the
+/// app data takes the place of the one loaded from the env vars.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::geo_restriction_middleware::geo_restriction_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(geo_restriction_middleware));
+/// ```
+pub async fn geo_restriction_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let blocked_country = {
+        let restriction = match req.app_data::<web::Data<GeoRestriction>>() {
+            Some(restriction) => restriction.get_ref(),
+            None => &*GEO_RESTRICTION,
+        };
+        match (restriction.lookup.is_some(), client_ip(req.request())) {
+            (true, Some(ip)) => restriction.blocked_country(ip),
+            _ => None,
+        }
+    };
+
+    if let Some(code) = blocked_country {
+        let res = HttpResponse::UnavailableForLegalReasons().json(error_construct(
+            String::from("country"),
+            String::from("unavailable for legal reasons"),
+            String::from("Este conteúdo não está disponível no seu país."),
+            Some(code),
+            None,
+            None,
+        ));
+        return Ok(req.into_response(res).map_into_right_body());
+    }
+    Ok(next.call(req).await?.map_into_left_body())
+}
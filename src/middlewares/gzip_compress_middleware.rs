@@ -0,0 +1,129 @@
+use actix_web::{
+    body::{to_bytes, BodySize, BoxBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    error::ErrorInternalServerError,
+    http::header::{
+        HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, VARY,
+    },
+    middleware::Next,
+    Error,
+};
+use flate2::{write::GzEncoder, Compression};
+use once_cell::sync::Lazy;
+use std::{env, io::Write};
+
+const MIN_COMPRESSION_SIZE: u64 = 1024;
+
+const COMPRESSED_CONTENT_TYPES: [&str; 5] = [
+    "application/zip",
+    "application/gzip",
+    "image/",
+    "video/",
+    "audio/",
+];
+
+static COMPRESSION_LEVEL: Lazy<u32> = Lazy::new(|| {
+    env::var("COMPRESSION_LEVEL")
+        .ok()
+        .and_then(|level| level.parse().ok())
+        .filter(|level| (1..=9).contains(level))
+        .unwrap_or(6)
+});
+
+fn accepts_gzip(req: &ServiceRequest) -> bool {
+    match req
+        .headers()
+        .get(ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) => value.split(',').any(|encoding| {
+            let mut params = encoding.trim().split(';');
+            let name = params.next().unwrap_or("").trim();
+            let disabled = params.any(|param| param.trim().replace(' ', "") == "q=0");
+            name.eq_ignore_ascii_case("gzip") && !disabled
+        }),
+        None => false,
+    }
+}
+
+fn already_compressed(content_type: Option<&HeaderValue>) -> bool {
+    match content_type.and_then(|value| value.to_str().ok()) {
+        Some(value) => {
+            let value = value.trim().to_ascii_lowercase();
+            COMPRESSED_CONTENT_TYPES
+                .iter()
+                .any(|content_type| value.starts_with(content_type))
+        }
+        None => false,
+    }
+}
+
+/// Middleware to compress the responses with gzip.
+///
+/// Only responses with a known size of at least 1024 bytes are compressed, and only when the client
+/// sends `Accept-Encoding: gzip`. Streamed responses and content types that are already compressed,
+/// like ZIP files and images, are passed through untouched. The compression level is read from
+/// `COMPRESSION_LEVEL` (1–9, default 6).
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::gzip_compress_middleware::gzip_compress_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(gzip_compress_middleware));
+/// ```
+pub async fn gzip_compress_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let accepts_gzip = accepts_gzip(&req);
+    let res = next.call(req).await?;
+
+    if !accepts_gzip
+        || res.headers().contains_key(CONTENT_ENCODING)
+        || already_compressed(res.headers().get(CONTENT_TYPE))
+    {
+        return Ok(res.map_into_boxed_body());
+    }
+    let size = match res.response().body().size() {
+        BodySize::Sized(size) => size,
+        BodySize::None | BodySize::Stream => return Ok(res.map_into_boxed_body()),
+    };
+
+    let (req, res) = res.into_parts();
+    let (mut res, body) = res.into_parts();
+    let varies_on_encoding = res.headers().get_all(VARY).any(|value| {
+        value.to_str().is_ok_and(|value| {
+            value
+                .split(',')
+                .any(|name| name.trim().eq_ignore_ascii_case("accept-encoding"))
+        })
+    });
+    if !varies_on_encoding {
+        res.headers_mut()
+            .append(VARY, HeaderValue::from_static("accept-encoding"));
+    }
+    if size < MIN_COMPRESSION_SIZE {
+        return Ok(ServiceResponse::new(req, res.set_body(body)).map_into_boxed_body());
+    }
+
+    let bytes = match to_bytes(body).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            let e: Box<dyn std::error::Error> = e.into();
+            return Err(ErrorInternalServerError(e.to_string()));
+        }
+    };
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::new(*COMPRESSION_LEVEL));
+    let compressed = match encoder.write_all(&bytes).and_then(|_| encoder.finish()) {
+        Ok(compressed) => compressed,
+        Err(e) => return Err(ErrorInternalServerError(e)),
+    };
+    res.headers_mut()
+        .insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+    res.headers_mut().remove(CONTENT_LENGTH);
+
+    Ok(ServiceResponse::new(req, res.set_body(compressed)).map_into_boxed_body())
+}
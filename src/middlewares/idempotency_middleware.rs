@@ -0,0 +1,226 @@
+use crate::{
+    infra::redis::Redis,
+    middlewares::{
+        api_key_middleware::API_KEY_HEADER, jwt_token_middleware::jwt_token_middleware,
+        tenant_middleware::tenant_middleware,
+    },
+    utils::error_construct::error_construct,
+};
+use actix_web::{
+    body::{to_bytes, BoxBody, MessageBody},
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    error::{ErrorInternalServerError, PayloadError},
+    http::StatusCode,
+    middleware::Next,
+    web, Error, HttpResponse,
+};
+use futures_util::{stream, Stream};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::pin::Pin;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+pub const IDEMPOTENCY_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+pub const IDEMPOTENCY_KEY: &str = "idempotent";
+const IDEMPOTENCY_EXPIRATION_SECONDS: u64 = 86400;
+const IDEMPOTENCY_LOCK_EXPIRATION_SECONDS: u64 = 60;
+
+/// The response stored under the idempotency key, replayed to the retries with the same key and
+/// the same body.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IdempotentResponse {
+    body_hash: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+fn replay(cached: IdempotentResponse) -> HttpResponse {
+    let mut res = HttpResponse::build(
+        StatusCode::from_u16(cached.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+    );
+    for header in cached.headers {
+        res.append_header(header);
+    }
+    res.insert_header((IDEMPOTENCY_REPLAYED_HEADER, "true"))
+        .body(cached.body)
+}
+
+/// Builds the Redis key of an idempotent request, scoped by who sends it, the tenant, the method
+/// and the path, so the same client key never replays the response of another user or route.
+///
+/// The subject is the `sub` of the bearer token, the hash of the `X-Api-Key` header or
+/// `anonymous`.
+pub fn idempotency_key(req: &ServiceRequest, key: &uuid::Uuid) -> String {
+    let subject = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token.claims.sub,
+        Err(_) => match req.headers().get(API_KEY_HEADER) {
+            Some(api_key) => hex::encode(Sha256::digest(api_key.as_bytes())),
+            None => String::from("anonymous"),
+        },
+    };
+    let tenant_id = tenant_middleware(req.headers()).unwrap_or_default();
+    format!(
+        "{}:{}:{}:{}:{}:{}",
+        IDEMPOTENCY_KEY,
+        subject,
+        tenant_id,
+        req.method(),
+        req.path(),
+        key
+    )
+}
+
+fn idempotency_error(status: StatusCode, code: &str, message: &str, key: &str) -> HttpResponse {
+    HttpResponse::build(status).json(error_construct(
+        String::from(IDEMPOTENCY_KEY_HEADER),
+        String::from(code),
+        String::from(message),
+        Some(key.to_string()),
+        None,
+        None,
+    ))
+}
+
+/// Middleware to answer the retries of a request that creates a resource with the response of
+/// the first attempt. It wraps single routes, never whole scopes, since responses like the tokens
+/// of a login must not be replayed.
+///
+/// When the request sends an `Idempotency-Key` header, which must be a UUID, the response is kept
+/// in Redis under `idempotency_key` for 24 hours with the hash of the request body. A later request
+/// with the same key and body gets the same status, headers and body, with the
+/// `Idempotent-Replayed: true` header, without reaching the handler.
+///
+/// Returns `422 Unprocessable Entity` when the key is reused with another body, and
+/// `409 Conflict` while the first request with the key is still running. Server errors are not
+/// kept, so the client can retry them. Requests without the header or when Redis is unreachable
+/// are handled as usual.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::idempotency_middleware::idempotency_middleware;
+/// use actix_web::{middleware::from_fn, post, HttpResponse, Responder};
+///
+/// #[post("/post", wrap = "from_fn(idempotency_middleware)")]
+/// async fn insert_post() -> impl Responder {
+///     HttpResponse::Created().finish()
+/// }
+/// ```
+pub async fn idempotency_middleware(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let client_key = match req.headers().get(IDEMPOTENCY_KEY_HEADER) {
+        Some(key) => key.to_str().unwrap_or_default().to_string(),
+        None => return Ok(next.call(req).await?.map_into_boxed_body()),
+    };
+    let key = match uuid::Uuid::parse_str(&client_key) {
+        Ok(uuid) => idempotency_key(&req, &uuid),
+        Err(_) => {
+            let res = idempotency_error(
+                StatusCode::BAD_REQUEST,
+                "bad request",
+                "A chave de idempotência deve ser um UUID válido.",
+                &client_key,
+            );
+            return Ok(req.into_response(res));
+        }
+    };
+    let redis_pool = match req.app_data::<web::Data<deadpool_redis::Pool>>().cloned() {
+        Some(redis_pool) => redis_pool,
+        None => return Ok(next.call(req).await?.map_into_boxed_body()),
+    };
+
+    let request_body = match req.extract::<web::Bytes>().await {
+        Ok(bytes) => bytes,
+        Err(e) => return Ok(req.error_response(e)),
+    };
+    let body_hash = hex::encode(Sha256::digest(&request_body));
+    let request_stream: Pin<Box<dyn Stream<Item = Result<web::Bytes, PayloadError>>>> =
+        Box::pin(stream::once(async move { Ok(request_body) }));
+    req.set_payload(Payload::from(request_stream));
+
+    if let Ok(cached) = Redis::get(&redis_pool, &key).await {
+        if let Ok(cached) = serde_json::from_str::<IdempotentResponse>(&cached) {
+            if cached.body_hash != body_hash {
+                let res = idempotency_error(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "unprocessable entity",
+                    "A chave de idempotência já foi usada com outro corpo de requisição.",
+                    &client_key,
+                );
+                return Ok(req.into_response(res));
+            }
+            return Ok(req.into_response(replay(cached)));
+        }
+    }
+
+    let lock_key = format!("{}:lock", key);
+    if let Ok(false) = Redis::set_nx_ex(
+        &redis_pool,
+        &lock_key,
+        "1",
+        IDEMPOTENCY_LOCK_EXPIRATION_SECONDS,
+    )
+    .await
+    {
+        let res = idempotency_error(
+            StatusCode::CONFLICT,
+            "conflict",
+            "Uma requisição com esta chave de idempotência ainda está em andamento.",
+            &client_key,
+        );
+        return Ok(req.into_response(res));
+    }
+
+    let res = match next.call(req).await {
+        Ok(res) => res,
+        Err(e) => {
+            let _ = Redis::delete(&redis_pool, &lock_key).await;
+            return Err(e);
+        }
+    };
+    if res.status().is_server_error() {
+        let _ = Redis::delete(&redis_pool, &lock_key).await;
+        return Ok(res.map_into_boxed_body());
+    }
+    let (req, res) = res.into_parts();
+    let (res, body) = res.into_parts();
+    let bytes = match to_bytes(body).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            let _ = Redis::delete(&redis_pool, &lock_key).await;
+            let e: Box<dyn std::error::Error> = e.into();
+            return Err(ErrorInternalServerError(e.to_string()));
+        }
+    };
+
+    if let Ok(body) = std::str::from_utf8(&bytes) {
+        let cached = IdempotentResponse {
+            body_hash,
+            status: res.status().as_u16(),
+            headers: res
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (name.to_string(), value.to_string()))
+                })
+                .collect(),
+            body: body.to_string(),
+        };
+        let _ = Redis::set_ex(
+            &redis_pool,
+            &key,
+            &serde_json::to_string(&cached).unwrap_or_default(),
+            IDEMPOTENCY_EXPIRATION_SECONDS,
+        )
+        .await;
+    }
+    let _ = Redis::delete(&redis_pool, &lock_key).await;
+    Ok(ServiceResponse::new(req, res.set_body(bytes)).map_into_boxed_body())
+}
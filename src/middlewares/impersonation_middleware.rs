@@ -0,0 +1,40 @@
+use super::jwt_token_middleware::jwt_token_middleware;
+use crate::utils::error_construct::error_construct;
+use actix_web::{http::header::HeaderMap, HttpResponse};
+
+/// Refuses the destructive actions, like deleting the account or changing the password or the
+/// e-mail, when the bearer token was issued by `POST /admin/impersonate/{user_id}`.
+///
+/// # Errors
+///
+/// Returns `403 Forbidden` if the token has the `impersonated_by` claim, or the error of
+/// `jwt_token_middleware` if the token is missing or invalid.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::impersonation_middleware::impersonation_middleware;
+/// use actix_web::{HttpRequest, HttpResponse};
+///
+/// pub async fn example(req: HttpRequest) -> Result<(), HttpResponse> {
+///     match impersonation_middleware(req.headers()) {
+///         Ok(_) => Ok(()),
+///         Err(e) => return Err(e),
+///     }
+/// };
+/// ```
+pub fn impersonation_middleware(headers: &HeaderMap) -> Result<(), HttpResponse> {
+    let token = jwt_token_middleware(headers)?;
+
+    if token.claims.impersonated_by.is_some() {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("bearer token"),
+            String::from("forbidden"),
+            String::from("Ações destrutivas não são permitidas em modo de representação."),
+            None,
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
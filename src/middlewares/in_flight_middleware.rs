@@ -0,0 +1,44 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    Error,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static IN_FLIGHT_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+struct InFlightGuard;
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        IN_FLIGHT_REQUESTS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Returns how many requests are being handled right now, across all the workers.
+pub fn in_flight_requests() -> usize {
+    IN_FLIGHT_REQUESTS.load(Ordering::SeqCst)
+}
+
+/// Middleware to count the requests being handled, so the graceful shutdown knows when to stop.
+///
+/// The request is counted until its response is produced, even if the handler fails or is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::in_flight_middleware::in_flight_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(in_flight_middleware));
+/// ```
+pub async fn in_flight_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    IN_FLIGHT_REQUESTS.fetch_add(1, Ordering::SeqCst);
+    let _guard = InFlightGuard;
+
+    next.call(req).await
+}
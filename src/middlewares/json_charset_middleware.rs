@@ -0,0 +1,39 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderValue, CONTENT_TYPE},
+    middleware::Next,
+    Error,
+};
+
+/// Middleware to declare the charset of the JSON responses.
+///
+/// It replaces the `Content-Type: application/json` header by `Content-Type: application/json; charset=utf-8`,
+/// keeping any other content type untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::json_charset_middleware::json_charset_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(json_charset_middleware));
+/// ```
+pub async fn json_charset_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let mut res = next.call(req).await?;
+
+    let is_json = match res.headers().get(CONTENT_TYPE) {
+        Some(content_type) => content_type == "application/json",
+        None => false,
+    };
+    if is_json {
+        res.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/json; charset=utf-8"),
+        );
+    }
+    Ok(res)
+}
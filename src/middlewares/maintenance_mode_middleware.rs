@@ -0,0 +1,85 @@
+use crate::{infra::redis::Redis, utils::error_construct::error_construct};
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::RETRY_AFTER,
+    middleware::Next,
+    web, Error, HttpResponse,
+};
+use once_cell::sync::Lazy;
+use std::env;
+
+/// The Redis key that overrides the `MAINTENANCE_MODE` env var at runtime.
+pub const MAINTENANCE_MODE_KEY: &str = "maintenance:mode";
+
+/// The paths answered during the maintenance, so the monitoring keeps working and the admins can
+/// turn it off.
+const EXEMPT_PATHS: [&str; 4] = [
+    "/health",
+    "/metrics",
+    "/admin/maintenance/enable",
+    "/v1/admin/maintenance/enable",
+];
+
+pub static MAINTENANCE_MODE: Lazy<bool> = Lazy::new(|| {
+    env::var("MAINTENANCE_MODE")
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+});
+
+pub static MAINTENANCE_RETRY_AFTER_SECS: Lazy<u64> = Lazy::new(|| {
+    env::var("MAINTENANCE_RETRY_AFTER_SECS")
+        .ok()
+        .and_then(|seconds| seconds.parse().ok())
+        .unwrap_or(300)
+});
+
+/// Returns whether the API is under maintenance, reading the `maintenance:mode` Redis key and
+/// falling back to the `MAINTENANCE_MODE` env var when it is not set or Redis is unreachable.
+pub async fn maintenance_mode_enabled(redis_pool: Option<&deadpool_redis::Pool>) -> bool {
+    let flag = match redis_pool {
+        Some(redis_pool) => Redis::get(redis_pool, MAINTENANCE_MODE_KEY).await.ok(),
+        None => None,
+    };
+    match flag {
+        Some(flag) => flag.eq_ignore_ascii_case("true"),
+        None => *MAINTENANCE_MODE,
+    }
+}
+
+/// Middleware to answer `503 Service Unavailable` with a `Retry-After` header while the API is
+/// under maintenance.
+///
+/// The flag is checked on every request, so the `POST /admin/maintenance/enable` route takes
+/// effect without a restart. `/health`, `/metrics` and the maintenance routes are never blocked.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::maintenance_mode_middleware::maintenance_mode_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(maintenance_mode_middleware));
+/// ```
+pub async fn maintenance_mode_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let exempt = EXEMPT_PATHS.contains(&req.path());
+    let redis_pool = req.app_data::<web::Data<deadpool_redis::Pool>>().cloned();
+
+    if !exempt && maintenance_mode_enabled(redis_pool.as_ref().map(|pool| pool.get_ref())).await {
+        let res = HttpResponse::ServiceUnavailable()
+            .insert_header((RETRY_AFTER, MAINTENANCE_RETRY_AFTER_SECS.to_string()))
+            .json(error_construct(
+                String::from("maintenance"),
+                String::from("service unavailable"),
+                String::from("Manutenção em andamento."),
+                None,
+                None,
+                None,
+            ));
+        return Ok(req.into_response(res).map_into_right_body());
+    }
+    Ok(next.call(req).await?.map_into_left_body())
+}
@@ -1,3 +1,18 @@
+pub mod admin_middleware;
+pub mod api_key_middleware;
 pub mod auth_middleware;
+pub mod body_size_limit_middleware;
+pub mod deprecation_middleware;
+pub mod etag_middleware;
+pub mod geo_restriction_middleware;
+pub mod gzip_compress_middleware;
+pub mod idempotency_middleware;
+pub mod impersonation_middleware;
+pub mod in_flight_middleware;
+pub mod json_charset_middleware;
 pub mod jwt_token_middleware;
+pub mod maintenance_mode_middleware;
+pub mod security_headers_middleware;
+pub mod super_admin_middleware;
+pub mod tenant_middleware;
 pub mod uuid_path_middleware;
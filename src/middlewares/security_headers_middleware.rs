@@ -0,0 +1,58 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{
+        HeaderName, HeaderValue, CONTENT_SECURITY_POLICY, REFERRER_POLICY,
+        STRICT_TRANSPORT_SECURITY, X_CONTENT_TYPE_OPTIONS, X_FRAME_OPTIONS,
+    },
+    middleware::Next,
+    Error,
+};
+use once_cell::sync::Lazy;
+use std::env;
+
+static CONTENT_SECURITY_POLICY_VALUE: Lazy<Option<HeaderValue>> = Lazy::new(|| {
+    env::var("CSP_REPORT_URI")
+        .ok()
+        .filter(|uri| !uri.is_empty())
+        .and_then(|uri| {
+            HeaderValue::from_str(&format!("default-src 'none'; report-uri {}", uri)).ok()
+        })
+});
+
+/// Middleware to set the standard security headers on every response.
+///
+/// When the `CSP_REPORT_URI` env var is set, a `Content-Security-Policy` header reporting to it is
+/// also added.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::security_headers_middleware::security_headers_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(security_headers_middleware));
+/// ```
+pub async fn security_headers_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let mut res = next.call(req).await?;
+
+    let headers = res.headers_mut();
+    headers.insert(X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    headers.insert(X_FRAME_OPTIONS, HeaderValue::from_static("DENY"));
+    headers.insert(
+        STRICT_TRANSPORT_SECURITY,
+        HeaderValue::from_static("max-age=31536000; includeSubDomains"),
+    );
+    headers.insert(REFERRER_POLICY, HeaderValue::from_static("no-referrer"));
+    headers.insert(
+        HeaderName::from_static("permissions-policy"),
+        HeaderValue::from_static("camera=(), microphone=()"),
+    );
+    if let Some(content_security_policy) = CONTENT_SECURITY_POLICY_VALUE.as_ref() {
+        headers.insert(CONTENT_SECURITY_POLICY, content_security_policy.clone());
+    }
+    Ok(res)
+}
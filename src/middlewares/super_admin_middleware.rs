@@ -0,0 +1,47 @@
+use super::admin_middleware::admin_middleware;
+use crate::{shared::structs::jwt_claims::Claims, utils::error_construct::error_construct};
+use actix_web::{http::header::HeaderMap, HttpResponse};
+use jsonwebtoken::TokenData;
+use once_cell::sync::Lazy;
+use std::env;
+
+pub const SUPER_ADMIN_SECRET_HEADER: &str = "X-Super-Admin-Secret";
+
+static SUPER_ADMIN_SECRET: Lazy<Option<String>> = Lazy::new(|| {
+    env::var("SUPER_ADMIN_SECRET")
+        .ok()
+        .filter(|secret| !secret.is_empty())
+});
+
+/// Restricts the route to the super-admins: admins that also send the `SUPER_ADMIN_SECRET` env var
+/// in the `X-Super-Admin-Secret` header. When the env var is not set, every request is refused.
+pub fn super_admin_middleware(headers: &HeaderMap) -> Result<TokenData<Claims>, HttpResponse> {
+    let token = admin_middleware(headers)?;
+
+    let secret = headers
+        .get(SUPER_ADMIN_SECRET_HEADER)
+        .and_then(|secret| secret.to_str().ok());
+    let is_super_admin = match (secret, SUPER_ADMIN_SECRET.as_deref()) {
+        (Some(secret), Some(expected)) => {
+            secret.len() == expected.len()
+                && secret
+                    .bytes()
+                    .zip(expected.bytes())
+                    .fold(0, |diff, (a, b)| diff | (a ^ b))
+                    == 0
+        }
+        _ => false,
+    };
+
+    if !is_super_admin {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("super admin"),
+            String::from("forbidden"),
+            String::from("Acesso restrito a super administradores."),
+            None,
+            None,
+            None,
+        )));
+    }
+    Ok(token)
+}
@@ -0,0 +1,161 @@
+use super::jwt_token_middleware::jwt_token_middleware;
+use crate::{
+    infra::redis::Redis,
+    utils::{error_construct::error_construct, query_constructor_executor::query_executor},
+};
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderMap, AUTHORIZATION},
+    middleware::Next,
+    web, Error, HttpResponse,
+};
+
+pub const TENANT_HEADER: &str = "X-Tenant-Id";
+
+/// The tenant of the deployments hosting a single blog, used when the request has no `X-Tenant-Id`.
+pub const DEFAULT_TENANT_ID: &str = "00000000-0000-0000-0000-000000000000";
+
+const KNOWN_TENANT_KEY: &str = "tenant:known";
+const KNOWN_TENANT_EXPIRATION_SECONDS: u64 = 60 * 60;
+
+/// Extracts the tenant of the request from the `X-Tenant-Id` header.
+///
+/// Requests without the header belong to the default tenant, so single blog deployments keep
+/// working unchanged. When the request carries a valid access token, it must have been minted for
+/// a user of the same tenant.
+///
+/// # Errors
+///
+/// Returns `400 Bad Request` if the header is not a valid UUID, and `403 Forbidden` if the access
+/// token belongs to a user of another tenant.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::tenant_middleware::{tenant_middleware, DEFAULT_TENANT_ID};
+/// use actix_web::http::header::HeaderMap;
+///
+/// let tenant_id = tenant_middleware(&HeaderMap::new()).unwrap();
+/// assert_eq!(tenant_id, DEFAULT_TENANT_ID);
+/// ```
+pub fn tenant_middleware(headers: &HeaderMap) -> Result<String, HttpResponse> {
+    let tenant_id =
+        match headers.get(TENANT_HEADER) {
+            Some(tenant_id) => {
+                let tenant_id = tenant_id.to_str().unwrap_or_default().trim();
+                match uuid::Uuid::parse_str(tenant_id) {
+                    Ok(uuid) => uuid.to_string(),
+                    Err(_) => return Err(HttpResponse::BadRequest().json(error_construct(
+                        String::from("tenant"),
+                        String::from("bad request"),
+                        String::from(
+                            "Por favor, envie um valor de UUID válido no cabeçalho X-Tenant-Id.",
+                        ),
+                        Some(tenant_id.to_string()),
+                        None,
+                        None,
+                    ))),
+                }
+            }
+            None => String::from(DEFAULT_TENANT_ID),
+        };
+
+    token_tenant_valid(headers, &tenant_id)?;
+    Ok(tenant_id)
+}
+
+/// Refuses the access tokens minted for a user of another tenant, or minted before the tenant was
+/// embedded in the tokens. Invalid tokens are left for the authentication of the route to refuse.
+fn token_tenant_valid(headers: &HeaderMap, tenant_id: &str) -> Result<(), HttpResponse> {
+    if !headers.contains_key(AUTHORIZATION) {
+        return Ok(());
+    }
+    let token = match jwt_token_middleware(headers) {
+        Ok(token) => token,
+        Err(_) => return Ok(()),
+    };
+    if token.claims.tenant_id.as_deref() == Some(tenant_id) {
+        return Ok(());
+    }
+    Err(HttpResponse::Forbidden().json(error_construct(
+        String::from("tenant"),
+        String::from("forbidden"),
+        String::from("O token de autorização não pertence a este tenant."),
+        Some(tenant_id.to_string()),
+        None,
+        None,
+    )))
+}
+
+async fn known_tenant(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: Option<web::Data<deadpool_redis::Pool>>,
+    tenant_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    let key = format!("{}:{}", KNOWN_TENANT_KEY, tenant_id);
+    if let Some(redis_pool) = &redis_pool {
+        if Redis::get(redis_pool, &key).await.is_ok() {
+            return Ok(true);
+        }
+    }
+    let rows = query_executor(
+        pg_pool,
+        "SELECT id FROM tenants WHERE id = $1",
+        &[&tenant_id],
+    )
+    .await?;
+    if rows.is_empty() {
+        return Ok(false);
+    }
+    if let Some(redis_pool) = &redis_pool {
+        let _ = Redis::set_ex(redis_pool, &key, "1", KNOWN_TENANT_EXPIRATION_SECONDS).await;
+    }
+    Ok(true)
+}
+
+/// Middleware to refuse the requests to tenants that do not exist, answering `404 Not Found`, on
+/// top of the checks of [`tenant_middleware`]. The tenants found are cached for an hour, since they
+/// are never removed.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::middlewares::tenant_middleware::tenant_validation_middleware;
+/// use actix_web::{middleware::from_fn, App};
+///
+/// let app = App::new().wrap(from_fn(tenant_validation_middleware));
+/// ```
+pub async fn tenant_validation_middleware(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(res) => return Ok(req.into_response(res).map_into_right_body()),
+    };
+    let pg_pool = req
+        .app_data::<web::Data<deadpool_postgres::Pool>>()
+        .cloned();
+    let redis_pool = req.app_data::<web::Data<deadpool_redis::Pool>>().cloned();
+
+    if let (false, Some(pg_pool)) = (tenant_id == DEFAULT_TENANT_ID, pg_pool) {
+        let tenant_uuid = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+        let res = match known_tenant(pg_pool, redis_pool, tenant_uuid).await {
+            Ok(true) => None,
+            Ok(false) => Some(HttpResponse::NotFound().json(error_construct(
+                String::from("tenant"),
+                String::from("not found"),
+                String::from("Não foi encontrado um tenant com este id."),
+                Some(tenant_id),
+                None,
+                None,
+            ))),
+            Err(e) => Some(e),
+        };
+        if let Some(res) = res {
+            return Ok(req.into_response(res).map_into_right_body());
+        }
+    }
+    Ok(next.call(req).await?.map_into_left_body())
+}
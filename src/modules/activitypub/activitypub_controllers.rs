@@ -0,0 +1,201 @@
+use super::{
+    activitypub_dtos::{
+        OutboxParams, WebFingerParams, ACTIVITY_JSON_CONTENT_TYPE, JRD_JSON_CONTENT_TYPE,
+    },
+    activitypub_services::{activitypub_outbox_service, webfinger_service},
+};
+use crate::middlewares::tenant_middleware::tenant_middleware;
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn activitypub_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(webfinger);
+}
+
+pub fn activitypub_user_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(outbox);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{username}/outbox",
+    params(
+        ("page" = Option<i64>, Query, description = "Página do outbox, a partir de 1. Sem ela, retorna a coleção com os links das páginas"),
+        ("per_page" = Option<i64>, Query, description = "Atividades por página, entre 1 e 50 (padrão: 20)"),
+    ),
+    responses((
+        status = 200, description = "Outbox ActivityPub do usuário, com uma atividade Create de um Article por post publicado (OK)",
+        content_type = "application/activity+json", example = json ! ({
+            "@context": "https://www.w3.org/ns/activitystreams",
+            "id": "http://localhost:8080/user/borrow_lightning/outbox?page=1",
+            "type": "OrderedCollectionPage",
+            "partOf": "http://localhost:8080/user/borrow_lightning/outbox",
+            "totalItems": 1,
+            "orderedItems": [{
+                "id": "https://navarro.blog/post/rust-async#create",
+                "type": "Create",
+                "actor": "http://localhost:8080/user/@borrow_lightning",
+                "published": "2024-06-18T22:03:54Z",
+                "to": ["https://www.w3.org/ns/activitystreams#Public"],
+                "object": {
+                    "id": "https://navarro.blog/post/rust-async",
+                    "type": "Article",
+                    "name": "Rust assíncrono",
+                    "summary": "Como funciona o async/await em Rust",
+                    "url": "https://navarro.blog/post/rust-async",
+                    "attributedTo": "http://localhost:8080/user/@borrow_lightning",
+                    "published": "2024-06-18T22:03:54Z",
+                    "to": ["https://www.w3.org/ns/activitystreams#Public"]
+                }
+            }]
+        })
+    ), (
+		status = 400, description = "Página ou quantidade de itens por página inválida (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "per_page": [{
+                "code": "range",
+                "message": "A quantidade de itens por página deve estar entre 1 e 50.",
+                "params": {
+                    "min": 1.0,
+                    "value": 100,
+                    "max": 50.0
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "username": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este nome de usuário.",
+                "params": {
+                    "min": null,
+                    "value": "borrow_lightning",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{username}/outbox")]
+async fn outbox(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    username: web::Path<String>,
+    query_params: web::Query<OutboxParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match query_params.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match activitypub_outbox_service(
+        pg_pool,
+        username.into_inner(),
+        tenant_id,
+        query_params.into_inner(),
+    )
+    .await
+    {
+        Ok(outbox) => HttpResponse::Ok()
+            .content_type(ACTIVITY_JSON_CONTENT_TYPE)
+            .body(outbox.to_string()),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/.well-known/webfinger",
+    params(
+        ("resource" = String, Query, description = "Conta no formato acct:{usuário}@{domínio}"),
+    ),
+    responses((
+        status = 200, description = "Documento WebFinger (JRD) com os links do ator e do outbox ActivityPub do usuário (OK)",
+        content_type = "application/jrd+json", example = json ! ({
+            "subject": "acct:borrow_lightning@localhost",
+            "aliases": ["http://localhost:8080/user/@borrow_lightning"],
+            "links": [{
+                "rel": "self",
+                "type": "application/activity+json",
+                "href": "http://localhost:8080/user/@borrow_lightning"
+            }, {
+                "rel": "https://www.w3.org/ns/activitystreams#outbox",
+                "type": "application/activity+json",
+                "href": "http://localhost:8080/user/borrow_lightning/outbox"
+            }]
+        })
+    ), (
+		status = 400, description = "Recurso fora do formato acct:{usuário}@{domínio} (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "resource": [{
+                "code": "bad request",
+                "message": "O recurso deve estar no formato acct:{usuário}@{domínio}.",
+                "params": {
+                    "min": null,
+                    "value": "borrow_lightning",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado ou conta de outro servidor (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "resource": [{
+                "code": "not found",
+                "message": "Esta conta não pertence a este servidor.",
+                "params": {
+                    "min": null,
+                    "value": "acct:borrow_lightning@mastodon.social",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/.well-known/webfinger")]
+async fn webfinger(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    query_params: web::Query<WebFingerParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match webfinger_service(pg_pool, query_params.into_inner().resource, tenant_id).await {
+        Ok(jrd) => HttpResponse::Ok()
+            .content_type(JRD_JSON_CONTENT_TYPE)
+            .body(jrd.to_string()),
+        Err(e) => e,
+    }
+}
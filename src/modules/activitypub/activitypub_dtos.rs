@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+pub const ACTIVITY_JSON_CONTENT_TYPE: &str = "application/activity+json";
+pub const JRD_JSON_CONTENT_TYPE: &str = "application/jrd+json";
+pub const ACTIVITY_STREAMS_CONTEXT: &str = "https://www.w3.org/ns/activitystreams";
+pub const PUBLIC_AUDIENCE: &str = "https://www.w3.org/ns/activitystreams#Public";
+
+#[derive(ToSchema, Serialize, Deserialize, Validate)]
+pub struct OutboxParams {
+    /// Page of the outbox. Without it, the collection is returned with links to its pages.
+    #[validate(range(min = 1, message = "A página deve ser maior ou igual a 1."))]
+    pub page: Option<i64>,
+    #[validate(range(
+        min = 1,
+        max = 50,
+        message = "A quantidade de itens por página deve estar entre 1 e 50."
+    ))]
+    pub per_page: Option<i64>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+pub struct WebFingerParams {
+    /// The account, as `acct:{username}@{domain}`.
+    #[serde(default)]
+    pub resource: String,
+}
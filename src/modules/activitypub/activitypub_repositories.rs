@@ -0,0 +1,78 @@
+use super::activitypub_dtos::PUBLIC_AUDIENCE;
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::env;
+
+const ACTIVITYPUB_OUTBOX_SQL: &str = "
+    WITH published AS (
+        SELECT p.id, p.title, p.subtitle, p.summary, COALESCE(p.slug, p.id::TEXT) AS slug,
+            p.published_at
+        FROM posts p
+        JOIN users u ON u.id = p.author_id
+        WHERE u.username = $1 AND u.tenant_id = $2 AND p.published_at IS NOT NULL
+            AND p.published_at <= NOW() AND p.deleted_at IS NULL
+    )
+    SELECT total.total, page.*
+    FROM (SELECT COUNT(*) AS total FROM published) total
+    LEFT JOIN (
+        SELECT * FROM published ORDER BY published_at DESC, id DESC LIMIT $3 OFFSET $4
+    ) page ON true
+";
+
+/// Returns how many posts the user published and the `page` of them (starting at 1), from the
+/// most recent, each as a `Create` activity of an `Article` attributed to `actor_url` and linking
+/// to `SITE_BASE_URL`.
+pub async fn activitypub_outbox_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    username: &str,
+    tenant_id: uuid::Uuid,
+    actor_url: &str,
+    page: i64,
+    per_page: i64,
+) -> Result<(i64, Vec<serde_json::Value>), HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        ACTIVITYPUB_OUTBOX_SQL,
+        &[&username, &tenant_id, &per_page, &((page - 1) * per_page)],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let site_url = env::var("SITE_BASE_URL").unwrap_or_default();
+    let total = rows.first().map(|row| row.get("total")).unwrap_or(0);
+    let activities = rows
+        .iter()
+        .filter_map(|row| {
+            let slug: Option<String> = row.get("slug");
+            let slug = slug?;
+            let title: String = row.get("title");
+            let subtitle: String = row.get("subtitle");
+            let summary: Option<String> = row.get("summary");
+            let published_at: DateTime<Utc> = row.get("published_at");
+            let published = published_at.to_rfc3339_opts(SecondsFormat::Secs, true);
+            let url = format!("{}/post/{}", site_url.trim_end_matches('/'), slug);
+            Some(serde_json::json!({
+                "id": format!("{}#create", url),
+                "type": "Create",
+                "actor": actor_url,
+                "published": published,
+                "to": [PUBLIC_AUDIENCE],
+                "object": {
+                    "id": url,
+                    "type": "Article",
+                    "name": title,
+                    "summary": summary.unwrap_or(subtitle),
+                    "url": url,
+                    "attributedTo": actor_url,
+                    "published": published,
+                    "to": [PUBLIC_AUDIENCE],
+                },
+            }))
+        })
+        .collect();
+    Ok((total, activities))
+}
@@ -0,0 +1,159 @@
+use super::{
+    activitypub_dtos::{OutboxParams, ACTIVITY_JSON_CONTENT_TYPE, ACTIVITY_STREAMS_CONTEXT},
+    activitypub_repositories::activitypub_outbox_repository,
+};
+use crate::{
+    modules::user::user_services::user_id_by_username_service,
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+use std::env;
+
+const DEFAULT_OUTBOX_PER_PAGE: i64 = 20;
+
+fn api_url() -> String {
+    format!(
+        "{}:{}",
+        env::var("BASE_URL").unwrap_or_default(),
+        env::var("HTTP_PORT").unwrap_or_default()
+    )
+}
+
+/// The ActivityPub id of the user, their profile in the API.
+pub fn actor_url(username: &str) -> String {
+    format!("{}/user/@{}", api_url(), username)
+}
+
+pub fn outbox_url(username: &str) -> String {
+    format!("{}/user/{}/outbox", api_url(), username)
+}
+
+/// The domain of the `acct:` URIs of the users, the host of `BASE_URL`. `None` when it is not set,
+/// accepting any domain.
+fn webfinger_domain() -> Option<String> {
+    reqwest::Url::parse(&env::var("BASE_URL").unwrap_or_default())
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+}
+
+/// Builds the outbox of the user as an ActivityStreams `OrderedCollection` linking to its pages,
+/// or as the `OrderedCollectionPage` of `params.page` with the `Create` activities of their posts.
+pub async fn activitypub_outbox_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    username: String,
+    tenant_id: String,
+    params: OutboxParams,
+) -> Result<serde_json::Value, HttpResponse> {
+    match user_id_by_username_service(pg_pool.clone(), username.clone(), tenant_id.clone()).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    let per_page = params.per_page.unwrap_or(DEFAULT_OUTBOX_PER_PAGE);
+    let (total, activities) = match activitypub_outbox_repository(
+        pg_pool,
+        &username,
+        uuid::Uuid::parse_str(&tenant_id).unwrap_or_default(),
+        &actor_url(&username),
+        params.page.unwrap_or(1),
+        per_page,
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let outbox = outbox_url(&username);
+    let page_url = |page: i64| match params.per_page {
+        Some(per_page) => format!("{}?page={}&per_page={}", outbox, page, per_page),
+        None => format!("{}?page={}", outbox, page),
+    };
+    let last_page = ((total + per_page - 1) / per_page).max(1);
+
+    let page = match params.page {
+        Some(page) => page,
+        None => {
+            return Ok(serde_json::json!({
+                "@context": ACTIVITY_STREAMS_CONTEXT,
+                "id": outbox,
+                "type": "OrderedCollection",
+                "totalItems": total,
+                "first": page_url(1),
+                "last": page_url(last_page),
+            }))
+        }
+    };
+    let mut collection_page = serde_json::json!({
+        "@context": ACTIVITY_STREAMS_CONTEXT,
+        "id": page_url(page),
+        "type": "OrderedCollectionPage",
+        "partOf": outbox,
+        "totalItems": total,
+        "orderedItems": activities,
+    });
+    if page < last_page {
+        collection_page["next"] = serde_json::json!(page_url(page + 1));
+    }
+    if page > 1 {
+        collection_page["prev"] = serde_json::json!(page_url((page - 1).min(last_page)));
+    }
+    Ok(collection_page)
+}
+
+/// Resolves `acct:{username}@{domain}` to a WebFinger JRD linking to the actor and outbox of the
+/// user.
+pub async fn webfinger_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    resource: String,
+    tenant_id: String,
+) -> Result<serde_json::Value, HttpResponse> {
+    let account = resource.strip_prefix("acct:").and_then(|account| {
+        account
+            .rsplit_once('@')
+            .filter(|(username, domain)| !username.is_empty() && !domain.is_empty())
+    });
+    let (username, domain) = match account {
+        Some(account) => account,
+        None => {
+            return Err(HttpResponse::BadRequest().json(error_construct(
+                String::from("resource"),
+                String::from("bad request"),
+                String::from("O recurso deve estar no formato acct:{usuário}@{domínio}."),
+                Some(resource),
+                None,
+                None,
+            )))
+        }
+    };
+    if webfinger_domain().is_some_and(|host| !host.eq_ignore_ascii_case(domain)) {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("resource"),
+            String::from("not found"),
+            String::from("Esta conta não pertence a este servidor."),
+            Some(resource),
+            None,
+            None,
+        )));
+    }
+
+    match user_id_by_username_service(pg_pool, username.to_string(), tenant_id).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    let actor = actor_url(username);
+    Ok(serde_json::json!({
+        "subject": resource,
+        "aliases": [actor],
+        "links": [{
+            "rel": "self",
+            "type": ACTIVITY_JSON_CONTENT_TYPE,
+            "href": actor,
+        }, {
+            "rel": "https://www.w3.org/ns/activitystreams#outbox",
+            "type": ACTIVITY_JSON_CONTENT_TYPE,
+            "href": outbox_url(username),
+        }],
+    }))
+}
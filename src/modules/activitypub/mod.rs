@@ -0,0 +1,4 @@
+pub mod activitypub_controllers;
+pub mod activitypub_dtos;
+pub mod activitypub_repositories;
+pub mod activitypub_services;
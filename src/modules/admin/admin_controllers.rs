@@ -0,0 +1,728 @@
+use super::{
+    admin_dtos::BanUserDTO,
+    admin_services::{
+        admin_statistics_service, ban_user_service, impersonate_user_service,
+        impersonation_logs_service, merge_users_service, set_maintenance_mode_service,
+        unban_user_service,
+    },
+};
+use crate::{
+    middlewares::{
+        admin_middleware::admin_middleware, super_admin_middleware::super_admin_middleware,
+        tenant_middleware::tenant_middleware, uuid_path_middleware::uuid_path_middleware,
+    },
+    modules::{
+        content_report::content_report_controllers::content_report_admin_controllers_module,
+        dead_letter::dead_letter_controllers::dead_letter_admin_controllers_module,
+        feature_flag::feature_flag_controllers::feature_flag_admin_controllers_module,
+        invite_code::invite_code_controllers::invite_code_admin_controllers_module,
+        login_history::login_history_controllers::login_history_admin_controllers_module,
+        newsletter::newsletter_controllers::newsletter_admin_controllers_module,
+        post::post_controllers::post_admin_controllers_module,
+        post_import::post_import_controllers::post_import_controllers_module,
+        snapshot::snapshot_controllers::snapshot_admin_controllers_module,
+        tenant::tenant_controllers::tenant_admin_controllers_module,
+        user::user_controllers::user_admin_controllers_module,
+        webhook::webhook_controllers::webhook_admin_controllers_module,
+    },
+    utils::client_ip::client_ip,
+};
+use actix_web::{delete, get, post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn admin_controllers_module() -> actix_web::Scope {
+    web::scope("/admin")
+        .service(admin_statistics)
+        .service(ban_user)
+        .service(unban_user)
+        .service(merge_users)
+        .service(impersonate_user)
+        .service(impersonation_logs)
+        .service(enable_maintenance_mode)
+        .service(disable_maintenance_mode)
+        .configure(post_import_controllers_module)
+        .configure(webhook_admin_controllers_module)
+        .configure(invite_code_admin_controllers_module)
+        .configure(login_history_admin_controllers_module)
+        .configure(content_report_admin_controllers_module)
+        .configure(feature_flag_admin_controllers_module)
+        .configure(tenant_admin_controllers_module)
+        .configure(user_admin_controllers_module)
+        .configure(newsletter_admin_controllers_module)
+        .configure(dead_letter_admin_controllers_module)
+        .configure(post_admin_controllers_module)
+        .configure(snapshot_admin_controllers_module)
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/stats",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Estatísticas gerais da plataforma (OK)", body = PlatformStatsDTO,
+        content_type = "application/json", example = json ! ({
+            "totalUsers": 120,
+            "activeUsersLast30Days": 42,
+            "totalPosts": 35,
+            "publishedPosts": 30,
+            "totalComments": 410,
+            "totalLikes": 1280
+        })
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/stats")]
+async fn admin_statistics(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match admin_statistics_service(pg_pool, redis_pool, tenant_id).await {
+        Ok(stats) => HttpResponse::Ok().json(stats),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/user/{user_id}/ban",
+    security(("bearer_auth" = [])),
+    request_body = BanUserDTO,
+    responses((
+        status = 204, description = "Usuário suspenso (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/user/{user_id}/ban")]
+async fn ban_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<BanUserDTO>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match admin_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match ban_user_service(
+        pg_pool,
+        redis_pool,
+        token.claims.sub,
+        user_id,
+        body.reason.clone(),
+    )
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/user/{user_id}/unban",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Suspensão do usuário removida (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/user/{user_id}/unban")]
+async fn unban_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match admin_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match unban_user_service(pg_pool, redis_pool, token.claims.sub, user_id).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/user/{source_id}/merge-into/{target_id}",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Conta duplicada mesclada na conta de destino, que recebe os seus registros (OK)",
+        body = MergeUsersSummaryDTO, content_type = "application/json", example = json ! ({
+            "postsMoved": 3,
+            "coAuthorshipsMoved": 3,
+            "commentsMoved": 12,
+            "postLikesMoved": 7,
+            "commentLikesMoved": 4,
+            "readingListsMoved": 1,
+            "draftsMoved": 0,
+            "loginHistoryMoved": 18
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "source_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "Usuário mesclado nele mesmo ou já excluído (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "conflict",
+                "message": "Não é possível mesclar um usuário excluído.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/user/{source_id}/merge-into/{target_id}")]
+async fn merge_users(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    path: web::Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match admin_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let (source_id, target_id) = path.into_inner();
+    let source_id = match uuid_path_middleware(source_id, "source_id") {
+        Ok(source_id) => source_id,
+        Err(e) => return e,
+    };
+    let target_id = match uuid_path_middleware(target_id, "target_id") {
+        Ok(target_id) => target_id,
+        Err(e) => return e,
+    };
+    match merge_users_service(pg_pool, redis_pool, token.claims.sub, source_id, target_id).await {
+        Ok(summary) => HttpResponse::Ok().json(summary),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/impersonate/{user_id}",
+    security(("bearer_auth" = [])),
+    params(
+        ("X-Super-Admin-Secret" = String, Header, description = "Segredo dos super administradores"),
+    ),
+    responses((
+        status = 200, description = "Token de acesso de 10 minutos para agir como o usuário; ações destrutivas são bloqueadas (OK)",
+        body = ImpersonationDTO, content_type = "application/json", example = json ! ({
+            "accessToken": "eyJ0eXAiOiJKV1QiLCJhbGciOiJIUzI1NiJ9.eyJzdWIiOiJmNWQ0NmIxYi02YWRiLTQwYWMtODJkNi1iMDAwNmNmNzgxYzAiLCJyb2xlIjoidXNlciIsImV4cCI6MTcxODc1NDIzNCwiaW1wZXJzb25hdGVkX2J5IjoiN2MxZDllMmYtM2I0YS00YzVkLThlNmYtOWEwYjFjMmQzZTRmIn0.Y3Vy",
+            "accessExpiresIn": 600,
+            "userId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "impersonatedBy": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a super administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "super admin": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a super administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/impersonate/{user_id}")]
+async fn impersonate_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match super_admin_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    let ip = client_ip(&req).map(|ip| ip.to_string()).unwrap_or_default();
+    match impersonate_user_service(pg_pool, token.claims.sub, user_id, ip).await {
+        Ok(impersonation) => HttpResponse::Ok().json(impersonation),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/impersonation-logs",
+    security(("bearer_auth" = [])),
+    params(
+        ("X-Super-Admin-Secret" = String, Header, description = "Segredo dos super administradores"),
+    ),
+    responses((
+        status = 200, description = "Registro imutável dos tokens de personificação emitidos, do mais recente ao mais antigo (OK)",
+        body = Vec<ImpersonationLogDTO>, content_type = "application/json", example = json ! ([{
+            "id": "0a7d8c1e-5f2b-4e6a-9c3d-1b2e3f4a5b6c",
+            "adminId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "targetUserId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "tokenJti": "3e9a1b2c-4d5e-4f6a-8b7c-9d0e1f2a3b4c",
+            "issuedAt": "2024-06-18 23:27:14.123456 UTC",
+            "usedFromIp": "203.0.113.7"
+        }])
+    ), (
+		status = 403, description = "Acesso restrito a super administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "super admin": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a super administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/impersonation-logs")]
+async fn impersonation_logs(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match super_admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match impersonation_logs_service(pg_pool).await {
+        Ok(logs) => HttpResponse::Ok().json(logs),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/maintenance/enable",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Modo de manutenção ativado, respondendo 503 às demais rotas (No Content)"
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "redis": [{
+                "code": "service unavailable",
+                "message": "Connection refused (os error 111)",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/maintenance/enable")]
+async fn enable_maintenance_mode(
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match set_maintenance_mode_service(redis_pool, true).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/maintenance/enable",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Modo de manutenção desativado (No Content)"
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "redis": [{
+                "code": "service unavailable",
+                "message": "Connection refused (os error 111)",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("/maintenance/enable")]
+async fn disable_maintenance_mode(
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match set_maintenance_mode_service(redis_pool, false).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
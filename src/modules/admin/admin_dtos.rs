@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlatformStatsDTO {
+    pub total_users: i64,
+    pub active_users_last_30_days: i64,
+    pub total_posts: i64,
+    pub published_posts: i64,
+    pub total_comments: i64,
+    pub total_likes: i64,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct BanUserDTO {
+    #[validate(length(
+        min = 3,
+        max = 500,
+        message = "O motivo deve ter entre 3 e 500 caracteres."
+    ))]
+    #[serde(default)]
+    pub reason: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ImpersonationDTO {
+    pub access_token: String,
+    pub access_expires_in: i64,
+    pub user_id: String,
+    pub impersonated_by: String,
+}
+
+/// An impersonation token issued by a super administrator. The entries can only be appended.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImpersonationLogDTO {
+    pub id: String,
+    pub admin_id: String,
+    pub target_user_id: String,
+    pub token_jti: String,
+    pub issued_at: String,
+    pub used_from_ip: String,
+}
+
+/// How many records of the source user were moved to the target user by a merge.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeUsersSummaryDTO {
+    pub posts_moved: u64,
+    pub co_authorships_moved: u64,
+    pub comments_moved: u64,
+    pub post_likes_moved: u64,
+    pub comment_likes_moved: u64,
+    pub reading_lists_moved: u64,
+    pub drafts_moved: u64,
+    pub login_history_moved: u64,
+}
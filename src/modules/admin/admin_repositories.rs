@@ -0,0 +1,421 @@
+use super::admin_dtos::{ImpersonationLogDTO, MergeUsersSummaryDTO, PlatformStatsDTO};
+use crate::{
+    shared::exceptions::custom_error_to_io_error_kind::{
+        custom_error_to_io_error_kind, CustomError,
+    },
+    utils::{
+        error_construct::error_construct, query_constructor_executor::query_executor,
+        query_timeout::with_query_timeout,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+const PLATFORM_STATS_SQL: &str = "
+    WITH users_stats AS (
+        SELECT COUNT(*) AS total_users FROM users WHERE tenant_id = $1
+    ), active_users_stats AS (
+        SELECT COUNT(*) AS active_users_last_30_days FROM users u
+        WHERE u.tenant_id = $1 AND (
+            u.created_at >= NOW() - INTERVAL '30 days'
+            OR u.updated_at >= NOW() - INTERVAL '30 days'
+            OR EXISTS (
+                SELECT 1 FROM comments c
+                WHERE c.user_id = u.id AND c.approved_at IS NOT NULL
+                    AND c.created_at >= NOW() - INTERVAL '30 days'
+            )
+        )
+    ), posts_stats AS (
+        SELECT
+            COUNT(*) AS total_posts,
+            COUNT(*) FILTER (WHERE published_at IS NOT NULL AND published_at <= NOW()) AS published_posts
+        FROM posts
+        WHERE tenant_id = $1
+    ), comments_stats AS (
+        SELECT COUNT(*) AS total_comments FROM comments c
+        INNER JOIN posts p ON p.id = c.post_id
+        WHERE c.approved_at IS NOT NULL AND p.tenant_id = $1
+    ), likes_stats AS (
+        SELECT
+            (SELECT COUNT(*) FROM users_posts_likes l
+                INNER JOIN posts p ON p.id = l.post_id WHERE p.tenant_id = $1)
+            + (SELECT COUNT(*) FROM users_comments_likes l
+                INNER JOIN comments c ON c.id = l.comment_id
+                INNER JOIN posts p ON p.id = c.post_id WHERE p.tenant_id = $1)
+            AS total_likes
+    )
+    SELECT * FROM users_stats, active_users_stats, posts_stats, comments_stats, likes_stats
+";
+
+/// Returns the statistics of the users, posts, comments and likes of the tenant.
+pub async fn admin_statistics_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    tenant_id: uuid::Uuid,
+) -> Result<PlatformStatsDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, PLATFORM_STATS_SQL, &[&tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(PlatformStatsDTO {
+        total_users: rows[0].get("total_users"),
+        active_users_last_30_days: rows[0].get("active_users_last_30_days"),
+        total_posts: rows[0].get("total_posts"),
+        published_posts: rows[0].get("published_posts"),
+        total_comments: rows[0].get("total_comments"),
+        total_likes: rows[0].get("total_likes"),
+    })
+}
+
+const BAN_USER_SQL: &str = "
+    WITH banned_user AS (
+        UPDATE users SET banned_at = COALESCE(banned_at, NOW()) WHERE id = $1 RETURNING id
+    )
+    INSERT INTO audit_logs (id, actor_id, action, target_id, reason, created_at)
+    SELECT $2, $3, 'ban_user', id, $4, NOW() FROM banned_user
+    RETURNING target_id
+";
+
+const INSERT_IMPERSONATION_LOG_SQL: &str = "
+    INSERT INTO impersonation_logs (id, admin_id, target_user_id, token_jti, issued_at, used_from_ip)
+    VALUES ($1, $2, $3, $4, NOW(), $5)
+";
+
+const IMPERSONATION_LOGS_SQL: &str = "
+    SELECT id, admin_id, target_user_id, token_jti, issued_at, used_from_ip
+    FROM impersonation_logs
+    ORDER BY issued_at DESC
+";
+
+const UNBAN_USER_SQL: &str = "
+    WITH unbanned_user AS (
+        UPDATE users SET banned_at = NULL WHERE id = $1 RETURNING id
+    )
+    INSERT INTO audit_logs (id, actor_id, action, target_id, created_at)
+    SELECT $2, $3, 'unban_user', id, NOW() FROM unbanned_user
+    RETURNING target_id
+";
+
+const IMPERSONATE_USER_SQL: &str = "
+    WITH impersonated_user AS (
+        SELECT id, tenant_id FROM users WHERE id = $1 AND deleted_at IS NULL
+    )
+    INSERT INTO audit_logs (id, actor_id, action, target_id, created_at)
+    SELECT $2, $3, 'impersonate_user', id, NOW() FROM impersonated_user
+    RETURNING target_id, (SELECT tenant_id FROM impersonated_user) AS tenant_id
+";
+
+const LOCK_MERGED_USERS_SQL: &str =
+    "SELECT id, email, deleted_at IS NOT NULL AS deleted FROM users WHERE id = ANY($1) FOR UPDATE";
+
+/// The statements moving the records of the user `$1` to the user `$2`, in the order of the
+/// fields of `MergeUsersSummaryDTO`. The likes and co-authorships both users already have are
+/// dropped first, since they can not be duplicated.
+const MERGE_USERS_SQL: [&str; 8] = [
+    "UPDATE posts SET author_id = $2 WHERE author_id = $1",
+    "WITH promoted AS (
+        UPDATE post_authors target SET role = 'primary'
+        FROM post_authors source
+        WHERE source.post_id = target.post_id AND source.user_id = $1 AND source.role = 'primary'
+            AND target.user_id = $2
+        RETURNING target.post_id
+    ), duplicated AS (
+        DELETE FROM post_authors source
+        WHERE source.user_id = $1 AND EXISTS (
+            SELECT 1 FROM post_authors target WHERE target.post_id = source.post_id AND target.user_id = $2
+        )
+        RETURNING source.post_id
+    )
+    UPDATE post_authors SET user_id = $2
+    WHERE user_id = $1 AND post_id NOT IN (SELECT post_id FROM duplicated)",
+    "UPDATE comments SET user_id = $2 WHERE user_id = $1",
+    "WITH duplicated AS (
+        DELETE FROM users_posts_likes source
+        WHERE source.user_id = $1 AND EXISTS (
+            SELECT 1 FROM users_posts_likes target WHERE target.post_id = source.post_id AND target.user_id = $2
+        )
+        RETURNING source.post_id
+    ), recounted AS (
+        UPDATE posts SET likes = GREATEST(likes - 1, 0) WHERE id IN (SELECT post_id FROM duplicated)
+    )
+    UPDATE users_posts_likes SET user_id = $2
+    WHERE user_id = $1 AND post_id NOT IN (SELECT post_id FROM duplicated)",
+    "WITH duplicated AS (
+        DELETE FROM users_comments_likes source
+        WHERE source.user_id = $1 AND EXISTS (
+            SELECT 1 FROM users_comments_likes target WHERE target.comment_id = source.comment_id AND target.user_id = $2
+        )
+        RETURNING source.comment_id
+    ), recounted AS (
+        UPDATE comments SET likes = GREATEST(likes - 1, 0) WHERE id IN (SELECT comment_id FROM duplicated)
+    )
+    UPDATE users_comments_likes SET user_id = $2
+    WHERE user_id = $1 AND comment_id NOT IN (SELECT comment_id FROM duplicated)",
+    "UPDATE reading_lists SET user_id = $2 WHERE user_id = $1",
+    "UPDATE drafts SET author_id = $2 WHERE author_id = $1",
+    "UPDATE login_history SET user_id = $2 WHERE user_id = $1",
+];
+
+const ANONYMIZE_MERGED_USER_SQL: &str = "
+    UPDATE users SET name = 'Deleted User', email = 'deleted_' || id || '@deleted.invalid',
+        username = 'deleted_' || SUBSTRING(REPLACE(id::TEXT, '-', ''), 1, 20), password = '',
+        updated_at = NOW(), deleted_at = NOW()
+    WHERE id = $1
+";
+
+const MERGE_USERS_AUDIT_LOG_SQL: &str = "
+    INSERT INTO audit_logs (id, actor_id, action, target_id, reason, created_at)
+    VALUES ($1, $2, 'merge_user', $3, $4, NOW())
+";
+
+fn user_not_found() -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("user"),
+        String::from("not found"),
+        String::from("Não foi encontrado um usuário com este id."),
+        None,
+        None,
+        None,
+    ))
+}
+
+pub async fn ban_user_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    admin_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+    reason: String,
+) -> Result<(), HttpResponse> {
+    let audit_log_id = uuid::Uuid::new_v4();
+    let rows = match query_executor(
+        pg_pool,
+        BAN_USER_SQL,
+        &[&user_id, &audit_log_id, &admin_id, &reason],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(user_not_found());
+    }
+    Ok(())
+}
+
+pub async fn unban_user_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    admin_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    let audit_log_id = uuid::Uuid::new_v4();
+    let rows = match query_executor(
+        pg_pool,
+        UNBAN_USER_SQL,
+        &[&user_id, &audit_log_id, &admin_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(user_not_found());
+    }
+    Ok(())
+}
+
+/// Records the impersonation of the user in `audit_logs`, returning the tenant of the user and
+/// failing if the user does not exist.
+pub async fn impersonate_user_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    admin_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+) -> Result<uuid::Uuid, HttpResponse> {
+    let audit_log_id = uuid::Uuid::new_v4();
+    let rows = match query_executor(
+        pg_pool,
+        IMPERSONATE_USER_SQL,
+        &[&user_id, &audit_log_id, &admin_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    match rows.first() {
+        Some(row) => Ok(row.get("tenant_id")),
+        None => Err(user_not_found()),
+    }
+}
+
+/// Appends an issued impersonation token to `impersonation_logs`. The table is append-only, so
+/// there is no way to change or remove an entry afterwards.
+pub async fn insert_impersonation_log_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    admin_id: uuid::Uuid,
+    target_user_id: uuid::Uuid,
+    token_jti: uuid::Uuid,
+    used_from_ip: String,
+) -> Result<(), HttpResponse> {
+    match query_executor(
+        pg_pool,
+        INSERT_IMPERSONATION_LOG_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &admin_id,
+            &target_user_id,
+            &token_jti,
+            &used_from_ip,
+        ],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+fn impersonation_log_dto_constructor(row: &Row) -> ImpersonationLogDTO {
+    let id: uuid::Uuid = row.get("id");
+    let admin_id: uuid::Uuid = row.get("admin_id");
+    let target_user_id: uuid::Uuid = row.get("target_user_id");
+    let token_jti: uuid::Uuid = row.get("token_jti");
+    let issued_at: DateTime<Utc> = row.get("issued_at");
+    ImpersonationLogDTO {
+        id: id.to_string(),
+        admin_id: admin_id.to_string(),
+        target_user_id: target_user_id.to_string(),
+        token_jti: token_jti.to_string(),
+        issued_at: issued_at.to_string(),
+        used_from_ip: row.get("used_from_ip"),
+    }
+}
+
+/// Returns every issued impersonation token, the most recent first.
+pub async fn impersonation_logs_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<ImpersonationLogDTO>, HttpResponse> {
+    match query_executor(pg_pool, IMPERSONATION_LOGS_SQL, &[]).await {
+        Ok(rows) => Ok(rows.iter().map(impersonation_log_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Moves the posts, comments, likes, reading lists, drafts and login history of `source_id` to
+/// `target_id`, then anonymizes and soft-deletes the source user and records the merge in
+/// `audit_logs`, all in a single transaction.
+///
+/// Returns what was moved along with the original e-mail of the source user. Fails with
+/// `404 Not Found` when a user does not exist and `409 Conflict` when one was already deleted.
+pub async fn merge_users_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    admin_id: uuid::Uuid,
+    source_id: uuid::Uuid,
+    target_id: uuid::Uuid,
+) -> Result<(MergeUsersSummaryDTO, String), HttpResponse> {
+    let mut conn = match pg_pool.get().await {
+        Ok(x) => x,
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::PoolError(e))),
+    };
+    let transaction = match conn.transaction().await {
+        Ok(x) => x,
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
+    };
+
+    let rows = match with_query_timeout(
+        transaction.query(LOCK_MERGED_USERS_SQL, &[&vec![source_id, target_id]]),
+        transaction.cancel_token(),
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let mut source_email = None;
+    for user_id in [source_id, target_id] {
+        let row = match rows
+            .iter()
+            .find(|row| row.get::<_, uuid::Uuid>("id") == user_id)
+        {
+            Some(row) => row,
+            None => {
+                return Err(HttpResponse::NotFound().json(error_construct(
+                    String::from("user"),
+                    String::from("not found"),
+                    String::from("Não foi encontrado um usuário com este id."),
+                    Some(user_id.to_string()),
+                    None,
+                    None,
+                )))
+            }
+        };
+        if row.get("deleted") {
+            return Err(HttpResponse::Conflict().json(error_construct(
+                String::from("user"),
+                String::from("conflict"),
+                String::from("Não é possível mesclar um usuário excluído."),
+                Some(user_id.to_string()),
+                None,
+                None,
+            )));
+        }
+        if user_id == source_id {
+            source_email = Some(row.get::<_, String>("email"));
+        }
+    }
+
+    let mut summary = MergeUsersSummaryDTO::default();
+    let counters = [
+        &mut summary.posts_moved,
+        &mut summary.co_authorships_moved,
+        &mut summary.comments_moved,
+        &mut summary.post_likes_moved,
+        &mut summary.comment_likes_moved,
+        &mut summary.reading_lists_moved,
+        &mut summary.drafts_moved,
+        &mut summary.login_history_moved,
+    ];
+    for (sql, counter) in MERGE_USERS_SQL.iter().zip(counters) {
+        *counter = match with_query_timeout(
+            transaction.execute(*sql, &[&source_id, &target_id]),
+            transaction.cancel_token(),
+        )
+        .await
+        {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+    }
+    match with_query_timeout(
+        transaction.execute(ANONYMIZE_MERGED_USER_SQL, &[&source_id]),
+        transaction.cancel_token(),
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let reason = format!("Merged into {}", target_id);
+    match with_query_timeout(
+        transaction.execute(
+            MERGE_USERS_AUDIT_LOG_SQL,
+            &[&uuid::Uuid::new_v4(), &admin_id, &source_id, &reason],
+        ),
+        transaction.cancel_token(),
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    match transaction.commit().await {
+        Ok(_) => (),
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
+    };
+
+    Ok((summary, source_email.unwrap_or_default()))
+}
@@ -0,0 +1,222 @@
+use super::{
+    admin_dtos::{ImpersonationDTO, ImpersonationLogDTO, MergeUsersSummaryDTO, PlatformStatsDTO},
+    admin_repositories::{
+        admin_statistics_repository, ban_user_repository, impersonate_user_repository,
+        impersonation_logs_repository, insert_impersonation_log_repository, merge_users_repository,
+        unban_user_repository,
+    },
+};
+use crate::{
+    infra::redis::Redis,
+    middlewares::maintenance_mode_middleware::MAINTENANCE_MODE_KEY,
+    shared::treaties::jwt_treated::{Jwt, IMPERSONATION_EXPIRATION_MINUTES},
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+
+pub const ADMIN_STATS_KEY: &str = "admin:stats";
+const ADMIN_STATS_EXPIRATION_SECONDS: u64 = 60;
+pub const BANNED_USERS_KEY: &str = "banned:users";
+
+pub async fn admin_statistics_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    tenant_id: String,
+) -> Result<PlatformStatsDTO, HttpResponse> {
+    let admin_stats_key = format!("{}:{}", ADMIN_STATS_KEY, tenant_id);
+    if let Ok(redis_stats) = Redis::get(&redis_pool, &admin_stats_key).await {
+        if let Ok(stats) = serde_json::from_str::<PlatformStatsDTO>(&redis_stats) {
+            return Ok(stats);
+        }
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let stats = match admin_statistics_repository(pg_pool, tenant_id).await {
+        Ok(stats) => stats,
+        Err(e) => return Err(e),
+    };
+    match serde_json::to_string(&stats) {
+        Ok(redis_stats) => {
+            let _ = Redis::set_ex(
+                &redis_pool,
+                &admin_stats_key,
+                &redis_stats,
+                ADMIN_STATS_EXPIRATION_SECONDS,
+            )
+            .await;
+            Ok(stats)
+        }
+        Err(e) => Err(HttpResponse::InternalServerError().json(error_construct(
+            String::from("server"),
+            String::from("internal server error"),
+            e.to_string(),
+            None,
+            None,
+            None,
+        ))),
+    }
+}
+
+fn parse_admin_id(admin_id: &str) -> Result<uuid::Uuid, HttpResponse> {
+    match uuid::Uuid::parse_str(admin_id) {
+        Ok(admin_id) => Ok(admin_id),
+        Err(_) => Err(HttpResponse::Unauthorized().json(error_construct(
+            String::from("bearer token"),
+            String::from("unauthorized"),
+            String::from("Acesso negado por token de autorização."),
+            None,
+            None,
+            None,
+        ))),
+    }
+}
+
+pub async fn ban_user_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    admin_id: String,
+    user_id: String,
+    reason: String,
+) -> Result<(), HttpResponse> {
+    let admin_id = match parse_admin_id(&admin_id) {
+        Ok(admin_id) => admin_id,
+        Err(e) => return Err(e),
+    };
+    let user_uuid = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    match ban_user_repository(pg_pool, admin_id, user_uuid, reason).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::sadd(&redis_pool, BANNED_USERS_KEY, &user_id).await;
+    Ok(())
+}
+
+pub async fn unban_user_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    admin_id: String,
+    user_id: String,
+) -> Result<(), HttpResponse> {
+    let admin_id = match parse_admin_id(&admin_id) {
+        Ok(admin_id) => admin_id,
+        Err(e) => return Err(e),
+    };
+    let user_uuid = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    match unban_user_repository(pg_pool, admin_id, user_uuid).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::srem(&redis_pool, BANNED_USERS_KEY, &user_id).await;
+    Ok(())
+}
+
+/// Issues an access token to act as the user for `IMPERSONATION_EXPIRATION_MINUTES` minutes,
+/// recording the impersonation in `audit_logs` and the token, with the IP it was requested from,
+/// in `impersonation_logs`.
+pub async fn impersonate_user_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    admin_id: String,
+    user_id: String,
+    used_from_ip: String,
+) -> Result<ImpersonationDTO, HttpResponse> {
+    let admin_uuid = match parse_admin_id(&admin_id) {
+        Ok(admin_id) => admin_id,
+        Err(e) => return Err(e),
+    };
+    let user_uuid = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let tenant_id = match impersonate_user_repository(pg_pool.clone(), admin_uuid, user_uuid).await
+    {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return Err(e),
+    };
+    let token_jti = uuid::Uuid::new_v4();
+    match insert_impersonation_log_repository(
+        pg_pool,
+        admin_uuid,
+        user_uuid,
+        token_jti,
+        used_from_ip,
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let access_token = match Jwt::impersonation_token_constructor(
+        user_id.clone(),
+        admin_id.clone(),
+        token_jti.to_string(),
+        tenant_id.to_string(),
+    ) {
+        Ok(token) => token,
+        Err(e) => return Err(e),
+    };
+    Ok(ImpersonationDTO {
+        access_token,
+        access_expires_in: IMPERSONATION_EXPIRATION_MINUTES * 60,
+        user_id,
+        impersonated_by: admin_id,
+    })
+}
+
+pub async fn impersonation_logs_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<ImpersonationLogDTO>, HttpResponse> {
+    impersonation_logs_repository(pg_pool).await
+}
+
+/// Merges the duplicated account `source_id` into `target_id`, moving everything the source owns
+/// to the target and soft-deleting the source, which is also dropped from the user cache.
+///
+/// A user can not be merged into itself, which answers `409 Conflict`.
+pub async fn merge_users_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    admin_id: String,
+    source_id: String,
+    target_id: String,
+) -> Result<MergeUsersSummaryDTO, HttpResponse> {
+    let admin_id = match parse_admin_id(&admin_id) {
+        Ok(admin_id) => admin_id,
+        Err(e) => return Err(e),
+    };
+    let source_uuid = uuid::Uuid::parse_str(&source_id).unwrap_or_default();
+    let target_uuid = uuid::Uuid::parse_str(&target_id).unwrap_or_default();
+    if source_uuid == target_uuid {
+        return Err(HttpResponse::Conflict().json(error_construct(
+            String::from("user"),
+            String::from("conflict"),
+            String::from("Não é possível mesclar um usuário nele mesmo."),
+            Some(source_id),
+            None,
+            None,
+        )));
+    }
+
+    let (summary, source_email) =
+        match merge_users_repository(pg_pool, admin_id, source_uuid, target_uuid).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+    let _ = Redis::delete(&redis_pool, &source_id).await;
+    let _ = Redis::delete(&redis_pool, &source_email).await;
+    Ok(summary)
+}
+
+/// Turns the maintenance mode on or off, overriding the `MAINTENANCE_MODE` env var.
+pub async fn set_maintenance_mode_service(
+    redis_pool: Data<deadpool_redis::Pool>,
+    enabled: bool,
+) -> Result<(), HttpResponse> {
+    match Redis::set(&redis_pool, MAINTENANCE_MODE_KEY, &enabled.to_string()).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(HttpResponse::ServiceUnavailable().json(error_construct(
+            String::from("redis"),
+            String::from("service unavailable"),
+            e.to_string(),
+            None,
+            None,
+            None,
+        ))),
+    }
+}
@@ -0,0 +1,4 @@
+pub mod admin_controllers;
+pub mod admin_dtos;
+pub mod admin_repositories;
+pub mod admin_services;
@@ -0,0 +1,102 @@
+use super::{api_key_dtos::InsertApiKeyDTO, api_key_services::insert_api_key_service};
+use crate::middlewares::{
+    auth_middleware::auth_middleware, impersonation_middleware::impersonation_middleware,
+};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn api_key_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(insert_api_key);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/api-key",
+    security(("bearer_auth" = [])),
+    request_body = InsertApiKeyDTO,
+    responses((
+        status = 201, description = "Chave de API criada; a chave em texto puro só é exibida nesta resposta (Created)",
+        body = ApiKeyDTO, content_type = "application/json", example = json ! ({
+            "id": "3f1e2d3c-4b5a-4c6d-8e7f-9a0b1c2d3e4f",
+            "name": "Deploy do blog",
+            "key": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+            "scopes": ["post:read", "post:write"],
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "expiresAt": null
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id, nome, escopos ou expiração inválidos (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "scopes": [{
+                "code": "scopes",
+                "message": "Informe ao menos um escopo válido: post:read, post:write.",
+                "params": {
+                    "value": ["admin"]
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Ação bloqueada em modo de representação (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Ações destrutivas não são permitidas em modo de representação.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{user_id}/api-key")]
+async fn insert_api_key(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    body: web::Json<InsertApiKeyDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req.clone(), "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match impersonation_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match insert_api_key_service(pg_pool, user_id.into_inner(), body.into_inner()).await {
+        Ok(api_key) => HttpResponse::Created().json(api_key),
+        Err(e) => e,
+    }
+}
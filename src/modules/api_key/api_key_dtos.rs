@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+pub const POST_READ_SCOPE: &str = "post:read";
+pub const POST_WRITE_SCOPE: &str = "post:write";
+const API_KEY_SCOPES: [&str; 2] = [POST_READ_SCOPE, POST_WRITE_SCOPE];
+
+fn api_key_scopes_valid(scopes: &[String]) -> Result<(), ValidationError> {
+    if !scopes.is_empty()
+        && scopes
+            .iter()
+            .all(|scope| API_KEY_SCOPES.contains(&scope.as_str()))
+    {
+        return Ok(());
+    }
+    Err(ValidationError::new("scopes").with_message(Cow::from(
+        "Informe ao menos um escopo válido: post:read, post:write.",
+    )))
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertApiKeyDTO {
+    #[validate(length(
+        min = 3,
+        max = 100,
+        message = "O nome da chave deve ter entre 3 e 100 caracteres."
+    ))]
+    pub name: String,
+
+    #[validate(custom(function = "api_key_scopes_valid"))]
+    pub scopes: Vec<String>,
+
+    #[serde(default, alias = "expiresAt")]
+    pub expires_at: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyDTO {
+    pub id: String,
+    pub name: String,
+    /// The plaintext key, only returned when the key is created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    pub scopes: Vec<String>,
+    pub created_at: String,
+    pub expires_at: Option<String>,
+}
+
+/// The user and scopes of a request authenticated by `X-Api-Key`, stored in the request extensions.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ApiKeyUserDTO {
+    pub api_key_id: String,
+    pub user_id: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<i64>,
+}
@@ -0,0 +1,80 @@
+use super::api_key_dtos::{ApiKeyDTO, ApiKeyUserDTO};
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const INSERT_API_KEY_SQL: &str = "
+    INSERT INTO api_keys (id, user_id, name, key_hash, scopes, created_at, expires_at)
+    VALUES ($1, $2, $3, $4, $5, NOW(), $6)
+    RETURNING id, name, scopes, created_at, expires_at
+";
+
+const FIND_API_KEY_SQL: &str = "
+    UPDATE api_keys k SET last_used_at = NOW()
+    FROM users u
+    WHERE k.key_hash = $1 AND u.id = k.user_id AND u.deleted_at IS NULL AND u.banned_at IS NULL
+        AND u.deactivated_at IS NULL
+    RETURNING k.id, k.user_id, k.scopes, k.expires_at
+";
+
+pub async fn insert_api_key_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    name: String,
+    key_hash: String,
+    scopes: Vec<String>,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<ApiKeyDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_API_KEY_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &user_id,
+            &name,
+            &key_hash,
+            &scopes,
+            &expires_at,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let id: uuid::Uuid = rows[0].get("id");
+    let created_at: DateTime<Utc> = rows[0].get("created_at");
+    let expires_at: Option<DateTime<Utc>> = rows[0].get("expires_at");
+    Ok(ApiKeyDTO {
+        id: id.to_string(),
+        name: rows[0].get("name"),
+        key: None,
+        scopes: rows[0].get("scopes"),
+        created_at: created_at.to_string(),
+        expires_at: expires_at.map(|dt| dt.to_string()),
+    })
+}
+
+/// Finds the key of an active user by its SHA-256 hash, recording its use in `last_used_at`.
+pub async fn find_api_key_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    key_hash: &str,
+) -> Result<Option<ApiKeyUserDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, FIND_API_KEY_SQL, &[&key_hash]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.first().map(|row| {
+        let id: uuid::Uuid = row.get("id");
+        let user_id: uuid::Uuid = row.get("user_id");
+        let expires_at: Option<DateTime<Utc>> = row.get("expires_at");
+        ApiKeyUserDTO {
+            api_key_id: id.to_string(),
+            user_id: user_id.to_string(),
+            scopes: row.get("scopes"),
+            expires_at: expires_at.map(|dt| dt.timestamp()),
+        }
+    }))
+}
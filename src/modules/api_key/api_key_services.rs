@@ -0,0 +1,97 @@
+use super::{
+    api_key_dtos::{ApiKeyDTO, ApiKeyUserDTO, InsertApiKeyDTO},
+    api_key_repositories::{find_api_key_repository, insert_api_key_repository},
+};
+use crate::{infra::redis::Redis, utils::error_construct::error_construct};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+const API_KEY_CACHE_EXPIRATION_SECONDS: u64 = 60;
+
+/// Generates a random 32-byte key, hex-encoded.
+pub fn generate_api_key() -> String {
+    hex::encode(rand::random::<[u8; 32]>())
+}
+
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+fn api_key_cache_key(key_hash: &str) -> String {
+    format!("api_key:{}", key_hash)
+}
+
+/// Creates a key for the user. Only its SHA-256 hash is stored, so the plaintext key is returned
+/// in this response and never again.
+pub async fn insert_api_key_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    body: InsertApiKeyDTO,
+) -> Result<ApiKeyDTO, HttpResponse> {
+    let expires_at = match body.expires_at {
+        Some(expires_at) => match DateTime::parse_from_rfc3339(expires_at.trim()) {
+            Ok(parsed) if parsed.with_timezone(&Utc) > Utc::now() => {
+                Some(parsed.with_timezone(&Utc))
+            }
+            _ => {
+                return Err(HttpResponse::BadRequest().json(error_construct(
+                    String::from("expires_at"),
+                    String::from("bad request"),
+                    String::from("A expiração da chave deve ser uma data RFC 3339 no futuro."),
+                    Some(expires_at),
+                    None,
+                    None,
+                )))
+            }
+        },
+        None => None,
+    };
+
+    let key = generate_api_key();
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let mut api_key = match insert_api_key_repository(
+        pg_pool,
+        user_id,
+        body.name,
+        hash_api_key(&key),
+        body.scopes,
+        expires_at,
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    api_key.key = Some(key);
+    Ok(api_key)
+}
+
+/// Finds a key by its hash, caching the keys found in Redis for 60 seconds.
+pub async fn find_api_key_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    key_hash: &str,
+) -> Result<Option<ApiKeyUserDTO>, HttpResponse> {
+    let cache_key = api_key_cache_key(key_hash);
+    if let Ok(cached) = Redis::get(redis_pool, &cache_key).await {
+        if let Ok(api_key) = serde_json::from_str(&cached) {
+            return Ok(Some(api_key));
+        }
+    }
+
+    let api_key = match find_api_key_repository(pg_pool, key_hash).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if let Some(api_key) = &api_key {
+        let _ = Redis::set_ex(
+            redis_pool,
+            &cache_key,
+            &serde_json::to_string(api_key).unwrap_or_default(),
+            API_KEY_CACHE_EXPIRATION_SECONDS,
+        )
+        .await;
+    }
+    Ok(api_key)
+}
@@ -0,0 +1,4 @@
+pub mod api_key_controllers;
+pub mod api_key_dtos;
+pub mod api_key_repositories;
+pub mod api_key_services;
@@ -0,0 +1,27 @@
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+
+const TOP_POST_SLUGS_SQL: &str = "
+    SELECT p.slug FROM posts p
+    WHERE p.published_at IS NOT NULL AND p.published_at <= NOW() AND p.deleted_at IS NULL
+        AND p.slug IS NOT NULL AND p.tenant_id = $2
+    ORDER BY (SELECT COUNT(*) FROM users_posts_likes l WHERE l.post_id = p.id) * 3
+            + (SELECT COUNT(*) FROM comments c WHERE c.post_id = p.id AND c.deleted_at IS NULL
+                AND c.approved_at IS NOT NULL) * 2
+            + (SELECT COUNT(*) FROM post_views v WHERE v.post_id = p.id) DESC,
+        p.published_at DESC, p.id DESC
+    LIMIT $1
+";
+
+/// Returns the slugs of the most engaged published posts of the tenant, weighting likes and
+/// comments like the trending posts but without the time decay.
+pub async fn top_post_slugs_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    limit: i64,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<String>, HttpResponse> {
+    match query_executor(pg_pool, TOP_POST_SLUGS_SQL, &[&limit, &tenant_id]).await {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get("slug")).collect()),
+        Err(e) => Err(e),
+    }
+}
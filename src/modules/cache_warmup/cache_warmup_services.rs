@@ -0,0 +1,101 @@
+use super::cache_warmup_repositories::top_post_slugs_repository;
+use crate::{
+    middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+    modules::{
+        admin::admin_services::admin_statistics_service,
+        post::post_services::{archive_service, excerpt_post_service, trending_posts_service},
+    },
+};
+use actix_web::web::Data;
+use once_cell::sync::Lazy;
+use std::{env, time::Instant};
+
+pub const CACHE_WARMUP_POSTS_LIMIT: i64 = 20;
+
+/// Whether the cache is warmed on startup, from the `CACHE_WARMUP_ENABLED` env var (default `true`).
+pub static CACHE_WARMUP_ENABLED: Lazy<bool> = Lazy::new(|| {
+    env::var("CACHE_WARMUP_ENABLED")
+        .map(|value| !value.eq_ignore_ascii_case("false"))
+        .unwrap_or(true)
+});
+
+/// Pre-loads in Redis what the first requests after a cache flush would otherwise compute: the
+/// excerpts of the `CACHE_WARMUP_POSTS_LIMIT` most engaged posts, the trending posts and the
+/// archive of the default tenant, and the admin stats.
+///
+/// Each entry goes through the service that reads it, so it is written with the same key and
+/// expiration. Failures are logged and skipped. Returns how many post excerpts were loaded.
+pub async fn warm_cache_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+) -> usize {
+    let started_at = Instant::now();
+    let tenant_id = String::from(DEFAULT_TENANT_ID);
+
+    let slugs = match top_post_slugs_repository(
+        pg_pool.clone(),
+        CACHE_WARMUP_POSTS_LIMIT,
+        uuid::Uuid::parse_str(&tenant_id).unwrap_or_default(),
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => {
+            log::warn!(
+                "Cache warmup: failed to list the top posts: status {}",
+                e.status()
+            );
+            Vec::new()
+        }
+    };
+    let mut loaded = 0;
+    for slug in &slugs {
+        match excerpt_post_service(
+            pg_pool.clone(),
+            redis_pool.clone(),
+            slug.clone(),
+            tenant_id.clone(),
+        )
+        .await
+        {
+            Ok(_) => loaded += 1,
+            Err(e) => log::warn!(
+                "Cache warmup: failed to load the post {}: status {}",
+                slug,
+                e.status()
+            ),
+        };
+    }
+    log::info!(
+        "Cache warmup: {}/{} posts loaded in {:.1}s",
+        loaded,
+        slugs.len(),
+        started_at.elapsed().as_secs_f64()
+    );
+
+    if let Err(e) =
+        trending_posts_service(pg_pool.clone(), redis_pool.clone(), tenant_id.clone()).await
+    {
+        log::warn!(
+            "Cache warmup: failed to load the trending posts: status {}",
+            e.status()
+        );
+    }
+    if let Err(e) = archive_service(pg_pool.clone(), redis_pool.clone(), tenant_id.clone()).await {
+        log::warn!(
+            "Cache warmup: failed to load the archive: status {}",
+            e.status()
+        );
+    }
+    if let Err(e) = admin_statistics_service(pg_pool, redis_pool, tenant_id).await {
+        log::warn!(
+            "Cache warmup: failed to load the admin stats: status {}",
+            e.status()
+        );
+    }
+    log::info!(
+        "Cache warmup: finished in {:.1}s",
+        started_at.elapsed().as_secs_f64()
+    );
+    loaded
+}
@@ -0,0 +1,2 @@
+pub mod cache_warmup_repositories;
+pub mod cache_warmup_services;
@@ -0,0 +1,901 @@
+use super::{
+    comment_dto::{CommentCountsDTO, InsertCommentDTO, ListCommentsParams, PinCommentDTO},
+    comment_services::{
+        approve_comment_service, count_comments_by_slugs_service, count_comments_service,
+        insert_anonymous_comment_service, insert_comment_service, list_comments_service,
+        list_pending_comments_service, pin_comment_service, reject_comment_service,
+        unpin_comment_service,
+    },
+};
+use crate::{
+    middlewares::{
+        jwt_token_middleware::jwt_token_middleware, tenant_middleware::tenant_middleware,
+        uuid_path_middleware::uuid_path_middleware,
+    },
+    utils::client_ip::client_ip,
+};
+use actix_web::{
+    delete, get, http::header::AUTHORIZATION, patch, post, web, HttpRequest, HttpResponse,
+    Responder,
+};
+use validator::Validate;
+
+pub fn comment_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(insert_comment)
+        .service(list_comments)
+        .service(count_comments)
+        .service(count_comments_by_slugs)
+        .service(pin_comment)
+        .service(unpin_comment)
+        .service(list_pending_comments);
+}
+
+pub fn comment_moderation_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(approve_comment).service(reject_comment);
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/comment",
+    security((), ("bearer_auth" = [])),
+    request_body = InsertCommentDTO,
+    responses((
+        status = 201, description = "Comentário criado; usuários mencionados com @nome são notificados. Em posts com moderation_mode require_approval, o comentário fica com approvedAt nulo até ser aprovado pelo autor. Sem o token de acesso, o comentário é anônimo e exige guest_name e guest_email; o campo website deve ficar vazio (Created)",
+        body = CommentDTO, content_type = "application/json", example = json ! ({
+            "id": "5b8f2c1e-7a3d-4e9b-9c1a-2f6d8e4b7a10",
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "userId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "parentCommentId": null,
+            "body": "Ótimo post, @navarro!",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "approvedAt": "2024-06-18 22:03:54.053147 UTC",
+            "isPinned": false,
+            "likeCount": 0
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id ou corpo inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O post não permite comentários anônimos (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "O autor deste post não permite comentários anônimos.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post ou comentário pai não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id ou o comentário pai não pertence a ele.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 429, description = "Mais de 5 comentários anônimos na última hora a partir do mesmo IP (Too Many Requests)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "too many requests",
+                "message": "Você pode enviar no máximo 5 comentários anônimos por hora.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{post_id}/comment")]
+async fn insert_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<InsertCommentDTO>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match req.headers().get(AUTHORIZATION) {
+        Some(_) => match jwt_token_middleware(req.headers()) {
+            Ok(token) => Some(token),
+            Err(e) => return e,
+        },
+        None => None,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    let token = match token {
+        Some(token) => token,
+        None => {
+            let ip = client_ip(&req).map(|ip| ip.to_string()).unwrap_or_default();
+            return match insert_anonymous_comment_service(
+                pg_pool,
+                &redis_pool,
+                post_id,
+                ip,
+                body.into_inner(),
+            )
+            .await
+            {
+                Ok(Some(comment)) => HttpResponse::Created().json(comment),
+                Ok(None) => HttpResponse::Created().finish(),
+                Err(e) => e,
+            };
+        }
+    };
+    match insert_comment_service(
+        pg_pool,
+        redis_pool,
+        post_id,
+        token.claims.sub,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(comment) => HttpResponse::Created().json(comment),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/comment",
+    security((), ("bearer_auth" = [])),
+    params(
+        ("sort" = Option<String>, Query, description = "Ordenação dos comentários: recent (padrão) ou top (mais curtidos)"),
+    ),
+    responses((
+        status = 200, description = "Comentários aprovados do post, com o fixado primeiro e os demais em ordem cronológica ou por curtidas; likedByMe só é enviado com o token de acesso (OK)",
+        body = Vec<CommentDTO>, content_type = "application/json", example = json ! ([{
+            "id": "5b8f2c1e-7a3d-4e9b-9c1a-2f6d8e4b7a10",
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "userId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "parentCommentId": null,
+            "body": "Nota do autor: o código está no repositório.",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "approvedAt": "2024-06-18 22:05:10.118342 UTC",
+            "isPinned": true,
+            "likeCount": 4,
+            "likedByMe": false
+        }])
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{post_id}/comment")]
+async fn list_comments(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    query_params: web::Query<ListCommentsParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match req.headers().contains_key(AUTHORIZATION) {
+        true => match jwt_token_middleware(req.headers()) {
+            Ok(token) => Some(token.claims.sub),
+            Err(e) => return e,
+        },
+        false => None,
+    };
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match list_comments_service(
+        pg_pool,
+        post_id.into_inner(),
+        user_id,
+        query_params.into_inner().sort,
+        tenant_id,
+    )
+    .await
+    {
+        Ok(comments) => HttpResponse::Ok().json(comments),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{slug}/comments/count",
+    responses((
+        status = 200, description = "Quantidade de comentários do post, em cache por 60 segundos (OK)",
+        body = CommentCountDTO, content_type = "application/json", example = json ! ({
+            "count": 123
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este slug.",
+                "params": {
+                    "min": null,
+                    "value": "criando-apis-com-rust-0a6e8ac0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{slug}/comments/count")]
+async fn count_comments(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    slug: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match count_comments_service(pg_pool, redis_pool, slug.into_inner(), tenant_id).await {
+        Ok(count) => HttpResponse::Ok().json(count),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/comments/count",
+    request_body = CommentCountsDTO,
+    responses((
+        status = 200, description = "Quantidade de comentários por slug; os slugs sem post publicado não são enviados (OK)",
+        body = HashMap<String, i64>, content_type = "application/json", example = json ! ({
+            "criando-apis-com-rust-0a6e8ac0": 123,
+            "testes-em-rust-5b8f2c1e": 0
+        })
+	), (
+		status = 400, description = "Nenhum ou mais de 50 slugs (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "slugs": [{
+                "code": "length",
+                "message": "Envie entre 1 e 50 slugs por requisição.",
+                "params": {
+                    "min": 1,
+                    "value": [],
+                    "max": 50
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("comments/count")]
+async fn count_comments_by_slugs(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    body: web::Json<CommentCountsDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match count_comments_by_slugs_service(pg_pool, body.into_inner().slugs, tenant_id).await {
+        Ok(counts) => HttpResponse::Ok().json(counts),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/pin-comment",
+    security(("bearer_auth" = [])),
+    request_body = PinCommentDTO,
+    responses((
+        status = 204, description = "Comentário fixado no post, substituindo o anterior (No Content)"
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post pode fixar comentários (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post pode fixar comentários.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado ou comentário de outro post (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "not found",
+                "message": "Não foi encontrado um comentário com este id no post.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{post_id}/pin-comment")]
+async fn pin_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    body: web::Json<PinCommentDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match pin_comment_service(
+        pg_pool,
+        post_id.into_inner(),
+        token.claims.sub,
+        body.into_inner().comment_id,
+    )
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/pin-comment",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Comentário desafixado do post (No Content)"
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post pode fixar comentários (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post pode fixar comentários.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("{post_id}/pin-comment")]
+async fn unpin_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match unpin_comment_service(pg_pool, post_id.into_inner(), token.claims.sub).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/comments/pending",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Comentários do post aguardando aprovação, dos mais antigos aos mais recentes; apenas para o autor do post (OK)",
+        body = Vec<CommentDTO>, content_type = "application/json", example = json ! ([{
+            "id": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "userId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "parentCommentId": null,
+            "body": "Excelente explicação!",
+            "preview": "Excelente explicação!",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "approvedAt": null,
+            "isPinned": false,
+            "likeCount": 0
+        }])
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post pode ver os comentários pendentes (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post pode ver os comentários pendentes.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{post_id}/comments/pending")]
+async fn list_pending_comments(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match list_pending_comments_service(pg_pool, post_id.into_inner(), token.claims.sub).await {
+        Ok(comments) => HttpResponse::Ok().json(comments),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/comment/{comment_id}/approve",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Comentário aprovado e exibido no post; o autor do comentário recebe uma notificação comment_approved (OK)",
+        body = CommentDTO, content_type = "application/json", example = json ! ({
+            "id": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "userId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "parentCommentId": null,
+            "body": "Excelente explicação!",
+            "preview": "Excelente explicação!",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "approvedAt": "2024-06-18 23:10:02.481920 UTC",
+            "isPinned": false,
+            "likeCount": 0
+        })
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post ou um administrador pode moderar comentários (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post ou um administrador pode moderar este comentário.",
+                "params": {
+                    "min": null,
+                    "value": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Comentário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "not found",
+                "message": "Não foi encontrado um comentário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O comentário já foi aprovado ou rejeitado (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "conflict",
+                "message": "Este comentário não está aguardando aprovação.",
+                "params": {
+                    "min": null,
+                    "value": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{comment_id}/approve")]
+async fn approve_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    comment_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match approve_comment_service(
+        pg_pool,
+        redis_pool,
+        comment_id.into_inner(),
+        token.claims.sub,
+        token.claims.role,
+    )
+    .await
+    {
+        Ok(comment) => HttpResponse::Ok().json(comment),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/comment/{comment_id}/reject",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Comentário pendente rejeitado e removido (No Content)"
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post ou um administrador pode moderar comentários (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post ou um administrador pode moderar este comentário.",
+                "params": {
+                    "min": null,
+                    "value": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Comentário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "not found",
+                "message": "Não foi encontrado um comentário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O comentário já foi aprovado ou rejeitado (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "conflict",
+                "message": "Este comentário não está aguardando aprovação.",
+                "params": {
+                    "min": null,
+                    "value": "9d2e4f6a-1b3c-4d5e-8f7a-6b5c4d3e2f10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{comment_id}/reject")]
+async fn reject_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    comment_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match reject_comment_service(
+        pg_pool,
+        comment_id.into_inner(),
+        token.claims.sub,
+        token.claims.role,
+    )
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
@@ -1,3 +1,7 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
 pub struct _Comment<'a> {
     pub id: &'a str,
     pub post_id: &'a str,
@@ -7,3 +11,86 @@ pub struct _Comment<'a> {
     pub likes: i32,
     pub created_at: &'a str,
 }
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertCommentDTO {
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "O comentário deve ter entre 1 e 5000 caracteres."
+    ))]
+    pub body: String,
+
+    #[serde(default, alias = "parentCommentId")]
+    pub parent_comment_id: Option<String>,
+
+    /// The name shown on an anonymous comment, required when commenting without a token.
+    #[validate(length(
+        min = 2,
+        max = 63,
+        message = "O nome deve ter entre 2 e 63 caracteres."
+    ))]
+    #[serde(default, alias = "guestName")]
+    pub guest_name: Option<String>,
+
+    /// The e-mail of an anonymous commenter, required when commenting without a token and never
+    /// shown in the comments.
+    #[validate(email(message = "O e-mail deve ser um endereço válido."))]
+    #[serde(default, alias = "guestEmail")]
+    pub guest_email: Option<String>,
+
+    /// Leave empty. A hidden field that only spam bots fill in.
+    #[serde(default)]
+    pub website: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentDTO {
+    pub id: String,
+    pub post_id: String,
+    /// The author of the comment, `None` for anonymous comments.
+    pub user_id: Option<String>,
+    /// The name given by the author of an anonymous comment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guest_name: Option<String>,
+    pub parent_comment_id: Option<String>,
+    pub body: String,
+    /// The first characters of the body, used by the notifications of the comment.
+    pub preview: String,
+    pub created_at: String,
+    /// When the comment was approved, `None` while it waits for the approval of the post author.
+    pub approved_at: Option<String>,
+    pub is_pinned: bool,
+    pub like_count: i64,
+    /// Whether the authenticated user liked the comment, omitted for anonymous requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub liked_by_me: Option<bool>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+pub struct ListCommentsParams {
+    /// `top` orders the comments by likes instead of chronologically.
+    pub sort: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct PinCommentDTO {
+    #[serde(alias = "commentId")]
+    pub comment_id: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct CommentCountDTO {
+    pub count: i64,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct CommentCountsDTO {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Envie entre 1 e 50 slugs por requisição."
+    ))]
+    pub slugs: Vec<String>,
+}
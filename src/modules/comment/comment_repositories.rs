@@ -0,0 +1,385 @@
+use super::comment_dto::CommentDTO;
+use crate::{
+    shared::parsers::preview_parser::{truncated_preview, PREVIEW_MAX_CHARS},
+    utils::{error_construct::error_construct, query_constructor_executor::query_executor},
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tokio_postgres::Row;
+
+const INSERT_COMMENT_SQL: &str = "
+    INSERT INTO comments (
+        id, post_id, user_id, parent_comment_id, body, created_at, approved_at, tenant_id
+    )
+    SELECT $1, p.id, $3, $4, $5, NOW(), CASE WHEN p.moderation_mode = 'require_approval'
+        THEN NULL ELSE NOW() END, p.tenant_id
+    FROM posts p
+    WHERE p.id = $2 AND p.deleted_at IS NULL
+        AND ($4::UUID IS NULL OR EXISTS (
+            SELECT 1 FROM comments c WHERE c.id = $4 AND c.post_id = p.id
+        ))
+    RETURNING created_at, approved_at
+";
+
+const INSERT_ANONYMOUS_COMMENT_SQL: &str = "
+    INSERT INTO comments (
+        id, post_id, user_id, guest_name, guest_email, parent_comment_id, body, created_at,
+        approved_at, tenant_id
+    )
+    SELECT $1, p.id, NULL, $3, $4, $5, $6, NOW(), CASE WHEN p.moderation_mode = 'require_approval'
+        THEN NULL ELSE NOW() END, p.tenant_id
+    FROM posts p
+    WHERE p.id = $2 AND p.deleted_at IS NULL
+        AND ($5::UUID IS NULL OR EXISTS (
+            SELECT 1 FROM comments c WHERE c.id = $5 AND c.post_id = p.id
+        ))
+    RETURNING created_at, approved_at
+";
+
+const POST_ALLOWS_ANONYMOUS_COMMENTS_SQL: &str =
+    "SELECT allow_anonymous_comments FROM posts WHERE id = $1 AND deleted_at IS NULL";
+
+const LIST_COMMENTS_SQL: &str = "
+    SELECT c.id, c.post_id, c.user_id, c.guest_name, c.parent_comment_id, c.body, c.created_at,
+        c.approved_at, COALESCE(c.id = p.pinned_comment_id, FALSE) AS is_pinned,
+        COALESCE(c.likes, 0)::BIGINT AS like_count,
+        CASE WHEN $2::UUID IS NULL THEN NULL ELSE EXISTS (
+            SELECT 1 FROM users_comments_likes l WHERE l.comment_id = c.id AND l.user_id = $2
+        ) END AS liked_by_me
+    FROM comments c
+    JOIN posts p ON p.id = c.post_id
+    WHERE c.post_id = $1 AND c.deleted_at IS NULL AND c.approved_at IS NOT NULL
+        AND c.tenant_id = $4
+    ORDER BY is_pinned DESC, CASE WHEN $3 THEN COALESCE(c.likes, 0) ELSE 0 END DESC,
+        c.created_at, c.id
+";
+
+const PIN_COMMENT_SQL: &str = "
+    UPDATE posts SET pinned_comment_id = $2
+    WHERE id = $1 AND EXISTS (
+        SELECT 1 FROM comments WHERE id = $2 AND post_id = $1 AND deleted_at IS NULL
+    )
+    RETURNING id
+";
+
+const UNPIN_COMMENT_SQL: &str = "UPDATE posts SET pinned_comment_id = NULL WHERE id = $1";
+
+const COMMENT_POST_ID_BY_SLUG_SQL: &str = "
+    SELECT id FROM posts
+    WHERE slug = $1 AND published_at IS NOT NULL AND published_at <= NOW()
+        AND deleted_at IS NULL AND tenant_id = $2
+";
+
+const COUNT_COMMENTS_SQL: &str = "
+    SELECT COUNT(*) AS count FROM comments
+    WHERE post_id = $1 AND deleted_at IS NULL AND approved_at IS NOT NULL
+";
+
+const COUNT_COMMENTS_BY_SLUGS_SQL: &str = "
+    SELECT p.slug, COUNT(c.id) AS count
+    FROM posts p
+    LEFT JOIN comments c
+        ON c.post_id = p.id AND c.deleted_at IS NULL AND c.approved_at IS NOT NULL
+    WHERE p.slug = ANY($1) AND p.published_at IS NOT NULL AND p.published_at <= NOW()
+        AND p.deleted_at IS NULL AND p.tenant_id = $2
+    GROUP BY p.slug
+";
+
+const LIST_PENDING_COMMENTS_SQL: &str = "
+    SELECT id, post_id, user_id, guest_name, parent_comment_id, body, created_at, approved_at,
+        FALSE AS is_pinned, COALESCE(likes, 0)::BIGINT AS like_count, NULL::BOOLEAN AS liked_by_me
+    FROM comments
+    WHERE post_id = $1 AND approved_at IS NULL AND deleted_at IS NULL
+    ORDER BY created_at, id
+";
+
+const COMMENT_MODERATION_SQL: &str = "
+    SELECT p.author_id, c.approved_at IS NULL AS pending
+    FROM comments c
+    JOIN posts p ON p.id = c.post_id
+    WHERE c.id = $1 AND c.deleted_at IS NULL AND p.deleted_at IS NULL
+";
+
+const APPROVE_COMMENT_SQL: &str = "
+    UPDATE comments SET approved_at = NOW()
+    WHERE id = $1 AND approved_at IS NULL AND deleted_at IS NULL
+    RETURNING id, post_id, user_id, guest_name, parent_comment_id, body, created_at, approved_at,
+        FALSE AS is_pinned, COALESCE(likes, 0)::BIGINT AS like_count, NULL::BOOLEAN AS liked_by_me
+";
+
+const REJECT_COMMENT_SQL: &str = "
+    UPDATE comments SET deleted_at = NOW()
+    WHERE id = $1 AND approved_at IS NULL AND deleted_at IS NULL
+    RETURNING id
+";
+
+fn comment_dto_constructor(row: &Row) -> CommentDTO {
+    let id: uuid::Uuid = row.get("id");
+    let post_id: uuid::Uuid = row.get("post_id");
+    let user_id: Option<uuid::Uuid> = row.get("user_id");
+    let parent_comment_id: Option<uuid::Uuid> = row.get("parent_comment_id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    let approved_at: Option<DateTime<Utc>> = row.get("approved_at");
+    let body: String = row.get("body");
+    CommentDTO {
+        id: id.to_string(),
+        post_id: post_id.to_string(),
+        user_id: user_id.map(|id| id.to_string()),
+        guest_name: row.get("guest_name"),
+        parent_comment_id: parent_comment_id.map(|id| id.to_string()),
+        preview: truncated_preview(&body, PREVIEW_MAX_CHARS),
+        body,
+        created_at: created_at.to_string(),
+        approved_at: approved_at.map(|approved_at| approved_at.to_string()),
+        is_pinned: row.get("is_pinned"),
+        like_count: row.get("like_count"),
+        liked_by_me: row.get("liked_by_me"),
+    }
+}
+
+fn comment_post_not_found(post_id: uuid::Uuid) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("post"),
+        String::from("not found"),
+        String::from(
+            "Não foi encontrado um post com este id ou o comentário pai não pertence a ele.",
+        ),
+        Some(post_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+pub async fn insert_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+    parent_comment_id: Option<uuid::Uuid>,
+    body: String,
+) -> Result<CommentDTO, HttpResponse> {
+    let id = uuid::Uuid::new_v4();
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_COMMENT_SQL,
+        &[&id, &post_id, &user_id, &parent_comment_id, &body],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(comment_post_not_found(post_id));
+    }
+
+    let created_at: DateTime<Utc> = rows[0].get("created_at");
+    let approved_at: Option<DateTime<Utc>> = rows[0].get("approved_at");
+    Ok(CommentDTO {
+        id: id.to_string(),
+        post_id: post_id.to_string(),
+        user_id: Some(user_id.to_string()),
+        guest_name: None,
+        parent_comment_id: parent_comment_id.map(|id| id.to_string()),
+        preview: truncated_preview(&body, PREVIEW_MAX_CHARS),
+        body,
+        created_at: created_at.to_string(),
+        approved_at: approved_at.map(|approved_at| approved_at.to_string()),
+        is_pinned: false,
+        like_count: 0,
+        liked_by_me: None,
+    })
+}
+
+/// Inserts a comment without an author, identified only by the name and e-mail of the guest.
+pub async fn insert_anonymous_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    guest_name: String,
+    guest_email: String,
+    parent_comment_id: Option<uuid::Uuid>,
+    body: String,
+) -> Result<CommentDTO, HttpResponse> {
+    let id = uuid::Uuid::new_v4();
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_ANONYMOUS_COMMENT_SQL,
+        &[
+            &id,
+            &post_id,
+            &guest_name,
+            &guest_email,
+            &parent_comment_id,
+            &body,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(comment_post_not_found(post_id));
+    }
+
+    let created_at: DateTime<Utc> = rows[0].get("created_at");
+    let approved_at: Option<DateTime<Utc>> = rows[0].get("approved_at");
+    Ok(CommentDTO {
+        id: id.to_string(),
+        post_id: post_id.to_string(),
+        user_id: None,
+        guest_name: Some(guest_name),
+        parent_comment_id: parent_comment_id.map(|id| id.to_string()),
+        preview: truncated_preview(&body, PREVIEW_MAX_CHARS),
+        body,
+        created_at: created_at.to_string(),
+        approved_at: approved_at.map(|approved_at| approved_at.to_string()),
+        is_pinned: false,
+        like_count: 0,
+        liked_by_me: None,
+    })
+}
+
+/// Returns whether the post accepts anonymous comments, or `None` if the post does not exist.
+pub async fn post_allows_anonymous_comments_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Option<bool>, HttpResponse> {
+    match query_executor(pg_pool, POST_ALLOWS_ANONYMOUS_COMMENTS_SQL, &[&post_id]).await {
+        Ok(rows) => Ok(rows.first().map(|row| row.get("allow_anonymous_comments"))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Lists the comments of the post, with the pinned one first and the rest in chronological order,
+/// or by the most liked when `top` is set.
+///
+/// `liked_by_me` is only filled when `user_id` is given.
+pub async fn list_comments_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    user_id: Option<uuid::Uuid>,
+    top: bool,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<CommentDTO>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        LIST_COMMENTS_SQL,
+        &[&post_id, &user_id, &top, &tenant_id],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.iter().map(comment_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Pins the comment on the post, replacing any previous pin, and returns `false` if the comment
+/// does not belong to the post.
+pub async fn pin_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    comment_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(pg_pool, PIN_COMMENT_SQL, &[&post_id, &comment_id]).await {
+        Ok(rows) => Ok(!rows.is_empty()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn unpin_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, UNPIN_COMMENT_SQL, &[&post_id]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the id of the published post with the given slug, or `None` if there is none.
+pub async fn comment_post_id_by_slug_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slug: String,
+    tenant_id: uuid::Uuid,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    match query_executor(pg_pool, COMMENT_POST_ID_BY_SLUG_SQL, &[&slug, &tenant_id]).await {
+        Ok(rows) => Ok(rows.first().map(|row| row.get("id"))),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn count_comments_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<i64, HttpResponse> {
+    match query_executor(pg_pool, COUNT_COMMENTS_SQL, &[&post_id]).await {
+        Ok(rows) => Ok(rows[0].get("count")),
+        Err(e) => Err(e),
+    }
+}
+
+/// Counts the comments of the published posts with the given slugs in a single query. The slugs
+/// without a published post are left out of the result.
+pub async fn count_comments_by_slugs_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slugs: Vec<String>,
+    tenant_id: uuid::Uuid,
+) -> Result<HashMap<String, i64>, HttpResponse> {
+    match query_executor(pg_pool, COUNT_COMMENTS_BY_SLUGS_SQL, &[&slugs, &tenant_id]).await {
+        Ok(rows) => Ok(rows
+            .iter()
+            .map(|row| (row.get("slug"), row.get("count")))
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Lists the comments of the post waiting for the approval of its author, oldest first.
+pub async fn list_pending_comments_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Vec<CommentDTO>, HttpResponse> {
+    match query_executor(pg_pool, LIST_PENDING_COMMENTS_SQL, &[&post_id]).await {
+        Ok(rows) => Ok(rows.iter().map(comment_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the author of the post of the comment and whether the comment still waits for
+/// approval, or `None` if the comment does not exist.
+pub async fn comment_moderation_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    comment_id: uuid::Uuid,
+) -> Result<Option<(Option<uuid::Uuid>, bool)>, HttpResponse> {
+    match query_executor(pg_pool, COMMENT_MODERATION_SQL, &[&comment_id]).await {
+        Ok(rows) => Ok(rows
+            .first()
+            .map(|row| (row.get("author_id"), row.get("pending")))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Approves a pending comment, returning `None` if it is no longer pending.
+pub async fn approve_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    comment_id: uuid::Uuid,
+) -> Result<Option<CommentDTO>, HttpResponse> {
+    match query_executor(pg_pool, APPROVE_COMMENT_SQL, &[&comment_id]).await {
+        Ok(rows) => Ok(rows.first().map(comment_dto_constructor)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Soft deletes a pending comment, returning `false` if it is no longer pending.
+pub async fn reject_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    comment_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(pg_pool, REJECT_COMMENT_SQL, &[&comment_id]).await {
+        Ok(rows) => Ok(!rows.is_empty()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,591 @@
+use super::{
+    comment_dto::{CommentCountDTO, CommentDTO, InsertCommentDTO},
+    comment_repositories::*,
+};
+use crate::{
+    infra::redis::Redis,
+    middlewares::uuid_path_middleware::uuid_path_middleware,
+    modules::{
+        notification::notification_services::insert_notification_service,
+        poll::poll_repositories::post_author_repository,
+        user::user_repositories::find_mentioned_user_repository,
+    },
+    shared::parsers::mentions_parser::extract_mentions,
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+use std::collections::HashMap;
+
+const MAX_ANONYMOUS_COMMENTS_PER_HOUR: i64 = 5;
+const ANONYMOUS_COMMENT_RATE_EXPIRATION_SECONDS: u64 = 60 * 60;
+const COMMENT_COUNT_KEY: &str = "comments:count";
+const COMMENT_COUNT_EXPIRATION_SECONDS: u64 = 60;
+const PIN_FORBIDDEN_MESSAGE: &str = "Apenas o autor do post pode fixar comentários.";
+
+pub fn anonymous_comment_rate_key(ip: &str) -> String {
+    format!("comment:anonymous:rate:{}", ip)
+}
+
+pub fn comment_count_key(post_id: &str) -> String {
+    format!("{}:{}", COMMENT_COUNT_KEY, post_id)
+}
+
+/// Adds the new comment to the cached count of its post, when the count is cached.
+async fn increment_comment_count(redis_pool: &deadpool_redis::Pool, post_id: &str) {
+    let _ = Redis::incr_by_if_exists(redis_pool, &comment_count_key(post_id), 1).await;
+}
+
+/// Drops the cached comment counts of every post, called when comments are deleted in bulk or
+/// without knowing their post.
+pub async fn invalidate_comment_counts_service(redis_pool: &deadpool_redis::Pool) {
+    let _ = Redis::delete_by_pattern(redis_pool, &format!("{}:*", COMMENT_COUNT_KEY)).await;
+}
+
+async fn notify_mentions(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    comment: &CommentDTO,
+    author_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    let post_id = uuid::Uuid::parse_str(&comment.post_id).unwrap_or_default();
+    for username in extract_mentions(&comment.body) {
+        let user_id =
+            match find_mentioned_user_repository(pg_pool.clone(), &username, post_id).await {
+                Ok(Some(user_id)) => user_id,
+                Ok(None) => continue,
+                Err(e) => return Err(e),
+            };
+        if user_id == author_id {
+            continue;
+        }
+
+        let payload = serde_json::json!({
+            "postId": comment.post_id,
+            "commentId": comment.id,
+            "preview": comment.preview,
+        });
+        match insert_notification_service(
+            pg_pool.clone(),
+            redis_pool,
+            user_id,
+            Some(author_id),
+            "mention",
+            payload,
+        )
+        .await
+        {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+    }
+    Ok(())
+}
+
+pub async fn insert_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    post_id: String,
+    author_id: String,
+    body: InsertCommentDTO,
+) -> Result<CommentDTO, HttpResponse> {
+    let parent_comment_id = match body.parent_comment_id {
+        Some(parent_comment_id) => {
+            match uuid_path_middleware(parent_comment_id, "parent_comment_id") {
+                Ok(id) => Some(uuid::Uuid::parse_str(&id).unwrap_or_default()),
+                Err(e) => return Err(e),
+            }
+        }
+        None => None,
+    };
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    let author_id = uuid::Uuid::parse_str(&author_id).unwrap_or_default();
+
+    let comment = match insert_comment_repository(
+        pg_pool.clone(),
+        post_id,
+        author_id,
+        parent_comment_id,
+        body.body,
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    // A comment waiting for moderation is counted and notifies its mentions only when approved.
+    if comment.approved_at.is_none() {
+        return Ok(comment);
+    }
+    increment_comment_count(&redis_pool, &comment.post_id).await;
+
+    if let Err(e) = notify_mentions(pg_pool, &redis_pool, &comment, author_id).await {
+        log::warn!(
+            "Failed to notify the mentions of comment {}: {}",
+            comment.id,
+            e.status()
+        );
+    }
+    Ok(comment)
+}
+
+/// Inserts a comment without an account, returning `None` without inserting it when the
+/// `website` honeypot was filled in, so the bots that fill every field are accepted silently.
+///
+/// The guest must send a name and an e-mail, each IP can send up to
+/// `MAX_ANONYMOUS_COMMENTS_PER_HOUR` anonymous comments per hour, counted in Redis, and the post
+/// must allow anonymous comments.
+pub async fn insert_anonymous_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    post_id: String,
+    ip: String,
+    body: InsertCommentDTO,
+) -> Result<Option<CommentDTO>, HttpResponse> {
+    if body
+        .website
+        .as_deref()
+        .is_some_and(|website| !website.is_empty())
+    {
+        log::info!(
+            "Discarding an anonymous comment on post {} from {}",
+            post_id,
+            ip
+        );
+        return Ok(None);
+    }
+    let (guest_name, guest_email) = match (body.guest_name, body.guest_email) {
+        (Some(guest_name), Some(guest_email)) => (guest_name, guest_email),
+        _ => {
+            return Err(HttpResponse::BadRequest().json(error_construct(
+                String::from("guest_name"),
+                String::from("bad request"),
+                String::from("Informe o nome e o e-mail para comentar sem uma conta."),
+                None,
+                None,
+                None,
+            )))
+        }
+    };
+    let parent_comment_id = match body.parent_comment_id {
+        Some(parent_comment_id) => match parse_uuid(parent_comment_id, "parent_comment_id") {
+            Ok(id) => Some(id),
+            Err(e) => return Err(e),
+        },
+        None => None,
+    };
+
+    let key = anonymous_comment_rate_key(&ip);
+    match Redis::incr_ex(redis_pool, &key, ANONYMOUS_COMMENT_RATE_EXPIRATION_SECONDS).await {
+        Ok(comments) if comments > MAX_ANONYMOUS_COMMENTS_PER_HOUR => {
+            return Err(HttpResponse::TooManyRequests().json(error_construct(
+                String::from("comment"),
+                String::from("too many requests"),
+                format!(
+                    "Você pode enviar no máximo {} comentários anônimos por hora.",
+                    MAX_ANONYMOUS_COMMENTS_PER_HOUR
+                ),
+                None,
+                None,
+                None,
+            )))
+        }
+        Ok(_) => (),
+        Err(e) => {
+            return Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                String::from("redis"),
+                String::from("service unavailable"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            )))
+        }
+    };
+
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    match post_allows_anonymous_comments_repository(pg_pool.clone(), post_id).await {
+        Ok(Some(true)) => (),
+        Ok(Some(false)) => {
+            return Err(HttpResponse::Forbidden().json(error_construct(
+                String::from("post"),
+                String::from("forbidden"),
+                String::from("O autor deste post não permite comentários anônimos."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(error_construct(
+                String::from("post"),
+                String::from("not found"),
+                String::from("Não foi encontrado um post com este id."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Err(e) => return Err(e),
+    };
+
+    match insert_anonymous_comment_repository(
+        pg_pool,
+        post_id,
+        guest_name,
+        guest_email,
+        parent_comment_id,
+        body.body,
+    )
+    .await
+    {
+        Ok(comment) => {
+            if comment.approved_at.is_some() {
+                increment_comment_count(redis_pool, &comment.post_id).await;
+            }
+            Ok(Some(comment))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn parse_uuid(id: String, field: &str) -> Result<uuid::Uuid, HttpResponse> {
+    match uuid_path_middleware(id, field) {
+        Ok(id) => Ok(uuid::Uuid::parse_str(&id).unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Checks that the post exists and was written by `author_id`, answering with `forbidden_message`
+/// otherwise.
+async fn post_author_valid(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    author_id: String,
+    forbidden_message: &str,
+) -> Result<(), HttpResponse> {
+    let post_author = match post_author_repository(pg_pool, post_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author {
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("post"),
+            String::from("not found"),
+            String::from("Não foi encontrado um post com este id."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        ))),
+        Some(author) if author.map(|id| id.to_string()) != Some(author_id) => {
+            Err(HttpResponse::Forbidden().json(error_construct(
+                String::from("post"),
+                String::from("forbidden"),
+                String::from(forbidden_message),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+pub async fn list_comments_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    user_id: Option<String>,
+    sort: Option<String>,
+    tenant_id: String,
+) -> Result<Vec<CommentDTO>, HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let top = match sort.as_deref() {
+        None | Some("recent") => false,
+        Some("top") => true,
+        Some(_) => {
+            return Err(HttpResponse::BadRequest().json(error_construct(
+                String::from("sort"),
+                String::from("bad request"),
+                String::from("A ordenação deve ser 'recent' ou 'top'."),
+                sort,
+                None,
+                None,
+            )))
+        }
+    };
+    let user_id = user_id.map(|id| uuid::Uuid::parse_str(&id).unwrap_or_default());
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    list_comments_repository(pg_pool, post_id, user_id, top, tenant_id).await
+}
+
+/// Pins the comment on the post of `author_id`, replacing the previous pin.
+pub async fn pin_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    author_id: String,
+    comment_id: String,
+) -> Result<(), HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let comment_id = match parse_uuid(comment_id, "comment_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author_valid(pg_pool.clone(), post_id, author_id, PIN_FORBIDDEN_MESSAGE).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    match pin_comment_repository(pg_pool, post_id, comment_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("comment"),
+            String::from("not found"),
+            String::from("Não foi encontrado um comentário com este id no post."),
+            Some(comment_id.to_string()),
+            None,
+            None,
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn unpin_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    author_id: String,
+) -> Result<(), HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author_valid(pg_pool.clone(), post_id, author_id, PIN_FORBIDDEN_MESSAGE).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    unpin_comment_repository(pg_pool, post_id).await
+}
+
+/// Returns the number of comments of the published post with the given slug, cached for
+/// `COMMENT_COUNT_EXPIRATION_SECONDS` and kept up to date by the comment insertions.
+pub async fn count_comments_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    slug: String,
+    tenant_id: String,
+) -> Result<CommentCountDTO, HttpResponse> {
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let post_id =
+        match comment_post_id_by_slug_repository(pg_pool.clone(), slug.clone(), tenant_id).await {
+            Ok(Some(x)) => x,
+            Ok(None) => {
+                return Err(HttpResponse::NotFound().json(error_construct(
+                    String::from("post"),
+                    String::from("not found"),
+                    String::from("Não foi encontrado um post com este slug."),
+                    Some(slug),
+                    None,
+                    None,
+                )))
+            }
+            Err(e) => return Err(e),
+        };
+
+    let key = comment_count_key(&post_id.to_string());
+    if let Ok(count) = Redis::get(&redis_pool, &key).await {
+        if let Ok(count) = count.parse() {
+            return Ok(CommentCountDTO { count });
+        }
+    }
+
+    let count = match count_comments_repository(pg_pool, post_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &key,
+        &count.to_string(),
+        COMMENT_COUNT_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(CommentCountDTO { count })
+}
+
+pub async fn count_comments_by_slugs_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slugs: Vec<String>,
+    tenant_id: String,
+) -> Result<HashMap<String, i64>, HttpResponse> {
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    count_comments_by_slugs_repository(pg_pool, slugs, tenant_id).await
+}
+
+/// Lists the comments of the post of `author_id` waiting for approval.
+pub async fn list_pending_comments_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    author_id: String,
+) -> Result<Vec<CommentDTO>, HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author_valid(
+        pg_pool.clone(),
+        post_id,
+        author_id,
+        "Apenas o autor do post pode ver os comentários pendentes.",
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    list_pending_comments_repository(pg_pool, post_id).await
+}
+
+fn comment_already_moderated(comment_id: uuid::Uuid) -> HttpResponse {
+    HttpResponse::Conflict().json(error_construct(
+        String::from("comment"),
+        String::from("conflict"),
+        String::from("Este comentário não está aguardando aprovação."),
+        Some(comment_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+/// Checks that the comment exists and waits for approval, and that `user_id` is the author of its
+/// post or an admin.
+async fn comment_moderator_valid(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    comment_id: uuid::Uuid,
+    user_id: String,
+    role: &str,
+) -> Result<(), HttpResponse> {
+    let (post_author, pending) = match comment_moderation_repository(pg_pool, comment_id).await {
+        Ok(Some(x)) => x,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(error_construct(
+                String::from("comment"),
+                String::from("not found"),
+                String::from("Não foi encontrado um comentário com este id."),
+                Some(comment_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Err(e) => return Err(e),
+    };
+    if role != "admin" && post_author.map(|id| id.to_string()) != Some(user_id) {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("comment"),
+            String::from("forbidden"),
+            String::from(
+                "Apenas o autor do post ou um administrador pode moderar este comentário.",
+            ),
+            Some(comment_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    if !pending {
+        return Err(comment_already_moderated(comment_id));
+    }
+    Ok(())
+}
+
+/// Approves a pending comment, adding it to the cached count of its post, notifying the users it
+/// mentions and notifying its author with a `comment_approved` notification. Anonymous comments
+/// have no one to notify.
+pub async fn approve_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    comment_id: String,
+    user_id: String,
+    role: String,
+) -> Result<CommentDTO, HttpResponse> {
+    let comment_id = match parse_uuid(comment_id, "comment_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match comment_moderator_valid(pg_pool.clone(), comment_id, user_id.clone(), &role).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let comment = match approve_comment_repository(pg_pool.clone(), comment_id).await {
+        Ok(Some(x)) => x,
+        Ok(None) => return Err(comment_already_moderated(comment_id)),
+        Err(e) => return Err(e),
+    };
+    increment_comment_count(&redis_pool, &comment.post_id).await;
+
+    let commenter_id = match comment
+        .user_id
+        .as_deref()
+        .and_then(|commenter_id| uuid::Uuid::parse_str(commenter_id).ok())
+    {
+        Some(commenter_id) => commenter_id,
+        None => return Ok(comment),
+    };
+    if let Err(e) = notify_mentions(pg_pool.clone(), &redis_pool, &comment, commenter_id).await {
+        log::warn!(
+            "Failed to notify the mentions of comment {}: {}",
+            comment.id,
+            e.status()
+        );
+    }
+    if commenter_id.to_string() == user_id {
+        return Ok(comment);
+    }
+    let payload = serde_json::json!({
+        "postId": comment.post_id,
+        "commentId": comment.id,
+        "preview": comment.preview,
+    });
+    let notified = insert_notification_service(
+        pg_pool,
+        &redis_pool,
+        commenter_id,
+        uuid::Uuid::parse_str(&user_id).ok(),
+        "comment_approved",
+        payload,
+    )
+    .await
+    .map_err(|e| e.status());
+    if let Err(status) = notified {
+        log::warn!(
+            "Failed to notify the approval of comment {}: {}",
+            comment.id,
+            status
+        );
+    }
+    Ok(comment)
+}
+
+/// Rejects a pending comment, soft deleting it so it never shows up on the post.
+pub async fn reject_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    comment_id: String,
+    user_id: String,
+    role: String,
+) -> Result<(), HttpResponse> {
+    let comment_id = match parse_uuid(comment_id, "comment_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match comment_moderator_valid(pg_pool.clone(), comment_id, user_id, &role).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    match reject_comment_repository(pg_pool, comment_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(comment_already_moderated(comment_id)),
+        Err(e) => Err(e),
+    }
+}
@@ -1 +1,4 @@
+pub mod comment_controllers;
 pub mod comment_dto;
+pub mod comment_repositories;
+pub mod comment_services;
@@ -0,0 +1,185 @@
+use super::comment_like_services::{like_comment_service, unlike_comment_service};
+use crate::{
+    middlewares::jwt_token_middleware::jwt_token_middleware,
+    modules::{
+        comment::comment_controllers::comment_moderation_controllers_module,
+        content_report::content_report_controllers::content_report_comment_controllers_module,
+    },
+};
+use actix_web::{delete, post, web, HttpRequest, HttpResponse, Responder};
+
+pub fn comment_like_controllers_module() -> actix_web::Scope {
+    web::scope("/comment")
+        .service(like_comment)
+        .service(unlike_comment)
+        .configure(content_report_comment_controllers_module)
+        .configure(comment_moderation_controllers_module)
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/comment/{comment_id}/like",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Curtida registrada no comentário; curtir novamente não tem efeito (No Content)"
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Comentário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "not found",
+                "message": "Não foi encontrado um comentário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/{comment_id}/like")]
+async fn like_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    comment_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match like_comment_service(
+        pg_pool,
+        redis_pool,
+        comment_id.into_inner(),
+        token.claims.sub,
+    )
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/comment/{comment_id}/like",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Curtida removida do comentário (No Content)"
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Comentário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "not found",
+                "message": "Não foi encontrado um comentário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("/{comment_id}/like")]
+async fn unlike_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    comment_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match unlike_comment_service(
+        pg_pool,
+        redis_pool,
+        comment_id.into_inner(),
+        token.claims.sub,
+    )
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,63 @@
+use super::{
+    comment_like_repositories::reconcile_comment_likes_repository,
+    comment_like_services::COMMENT_LIKES_KEY_PREFIX,
+};
+use crate::infra::redis::Redis;
+use actix_web::web::Data;
+use deadpool_postgres::Pool;
+use std::time::Duration;
+
+const FLUSH_INTERVAL_SECS: u64 = 300;
+
+/// Takes the comments liked or unliked since the last flush from their Redis counters and
+/// recounts their likes in Postgres.
+///
+/// If Postgres fails, the counters are restored so the comments are retried in the next flush.
+pub async fn flush_comment_likes(
+    pg_pool: Pool,
+    redis_pool: &deadpool_redis::Pool,
+) -> Result<usize, String> {
+    let keys = match Redis::scan_keys(redis_pool, &format!("{}*", COMMENT_LIKES_KEY_PREFIX)).await {
+        Ok(x) => x,
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut comment_ids = Vec::new();
+    for key in keys {
+        match Redis::get_del(redis_pool, &key).await {
+            Ok(Some(_)) => (),
+            Ok(None) => continue,
+            Err(e) => return Err(e.to_string()),
+        };
+        if let Some(Ok(comment_id)) = key
+            .strip_prefix(COMMENT_LIKES_KEY_PREFIX)
+            .map(uuid::Uuid::parse_str)
+        {
+            comment_ids.push(comment_id);
+        }
+    }
+    if comment_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let flushed = comment_ids.len();
+    let status =
+        match reconcile_comment_likes_repository(Data::new(pg_pool), comment_ids.clone()).await {
+            Ok(_) => return Ok(flushed),
+            Err(e) => e.status(),
+        };
+    for comment_id in comment_ids {
+        let key = format!("{}{}", COMMENT_LIKES_KEY_PREFIX, comment_id);
+        let _ = Redis::incr_by(redis_pool, &key, 0).await;
+    }
+    Err(format!("status {}", status))
+}
+
+/// Reconciles the Redis like counters of the comments into Postgres every 5 minutes.
+pub async fn comment_likes_flush_queue(pool_async: Pool, redis_pool: deadpool_redis::Pool) {
+    loop {
+        tokio::time::sleep(Duration::from_secs(FLUSH_INTERVAL_SECS)).await;
+        if let Err(e) = flush_comment_likes(pool_async.clone(), &redis_pool).await {
+            log::warn!("Failed to reconcile the comment likes: {}", e);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+
+const LIKE_COMMENT_SQL: &str = "
+    WITH comment AS (
+        SELECT id FROM comments WHERE id = $2 AND deleted_at IS NULL
+    ), inserted AS (
+        INSERT INTO users_comments_likes (user_id, comment_id, created_at)
+        SELECT $1, id, NOW() FROM comment
+        ON CONFLICT (user_id, comment_id) DO NOTHING
+        RETURNING comment_id
+    )
+    SELECT EXISTS (SELECT 1 FROM comment) AS comment_exists,
+        EXISTS (SELECT 1 FROM inserted) AS changed
+";
+
+const UNLIKE_COMMENT_SQL: &str = "
+    WITH comment AS (
+        SELECT id FROM comments WHERE id = $2 AND deleted_at IS NULL
+    ), deleted AS (
+        DELETE FROM users_comments_likes
+        WHERE user_id = $1 AND comment_id IN (SELECT id FROM comment)
+        RETURNING comment_id
+    )
+    SELECT EXISTS (SELECT 1 FROM comment) AS comment_exists,
+        EXISTS (SELECT 1 FROM deleted) AS changed
+";
+
+const RECONCILE_COMMENT_LIKES_SQL: &str = "
+    UPDATE comments c SET likes = (
+        SELECT COUNT(*) FROM users_comments_likes l WHERE l.comment_id = c.id
+    )
+    WHERE c.id = ANY($1)
+";
+
+/// Likes the comment, returning `None` if it does not exist and whether a like was added.
+pub async fn like_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    comment_id: uuid::Uuid,
+) -> Result<Option<bool>, HttpResponse> {
+    let rows = match query_executor(pg_pool, LIKE_COMMENT_SQL, &[&user_id, &comment_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if !rows[0].get::<_, bool>("comment_exists") {
+        return Ok(None);
+    }
+    Ok(Some(rows[0].get("changed")))
+}
+
+/// Removes the like of the comment, returning `None` if it does not exist and whether a like was
+/// removed.
+pub async fn unlike_comment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    comment_id: uuid::Uuid,
+) -> Result<Option<bool>, HttpResponse> {
+    let rows = match query_executor(pg_pool, UNLIKE_COMMENT_SQL, &[&user_id, &comment_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if !rows[0].get::<_, bool>("comment_exists") {
+        return Ok(None);
+    }
+    Ok(Some(rows[0].get("changed")))
+}
+
+/// Recounts the likes of the comments into `comments.likes`.
+pub async fn reconcile_comment_likes_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    comment_ids: Vec<uuid::Uuid>,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, RECONCILE_COMMENT_LIKES_SQL, &[&comment_ids]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
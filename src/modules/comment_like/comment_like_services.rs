@@ -0,0 +1,74 @@
+use super::comment_like_repositories::{like_comment_repository, unlike_comment_repository};
+use crate::{
+    infra::redis::Redis, middlewares::uuid_path_middleware::uuid_path_middleware,
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+
+pub const COMMENT_LIKES_KEY_PREFIX: &str = "comment:likes:";
+
+fn comment_not_found(comment_id: uuid::Uuid) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("comment"),
+        String::from("not found"),
+        String::from("Não foi encontrado um comentário com este id."),
+        Some(comment_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+/// Adds `increment` to the Redis counter of the comment, later reconciled into Postgres by
+/// `comment_likes_flush_queue`.
+async fn count_comment_like(
+    redis_pool: &deadpool_redis::Pool,
+    comment_id: uuid::Uuid,
+    increment: i64,
+) {
+    let key = format!("{}{}", COMMENT_LIKES_KEY_PREFIX, comment_id);
+    if let Err(e) = Redis::incr_by(redis_pool, &key, increment).await {
+        log::warn!("Failed to count the like of comment {}: {}", comment_id, e);
+    }
+}
+
+pub async fn like_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    comment_id: String,
+    user_id: String,
+) -> Result<(), HttpResponse> {
+    let comment_id = match uuid_path_middleware(comment_id, "comment_id") {
+        Ok(id) => uuid::Uuid::parse_str(&id).unwrap_or_default(),
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+
+    match like_comment_repository(pg_pool, user_id, comment_id).await {
+        Ok(Some(true)) => count_comment_like(&redis_pool, comment_id, 1).await,
+        Ok(Some(false)) => (),
+        Ok(None) => return Err(comment_not_found(comment_id)),
+        Err(e) => return Err(e),
+    };
+    Ok(())
+}
+
+pub async fn unlike_comment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    comment_id: String,
+    user_id: String,
+) -> Result<(), HttpResponse> {
+    let comment_id = match uuid_path_middleware(comment_id, "comment_id") {
+        Ok(id) => uuid::Uuid::parse_str(&id).unwrap_or_default(),
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+
+    match unlike_comment_repository(pg_pool, user_id, comment_id).await {
+        Ok(Some(true)) => count_comment_like(&redis_pool, comment_id, -1).await,
+        Ok(Some(false)) => (),
+        Ok(None) => return Err(comment_not_found(comment_id)),
+        Err(e) => return Err(e),
+    };
+    Ok(())
+}
@@ -0,0 +1,4 @@
+pub mod comment_like_controllers;
+pub mod comment_like_queues;
+pub mod comment_like_repositories;
+pub mod comment_like_services;
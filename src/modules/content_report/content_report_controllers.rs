@@ -0,0 +1,593 @@
+use super::{
+    content_report_dtos::{
+        ContentReportParams, InsertContentReportDTO, ResolveContentReportDTO, COMMENT_ENTITY,
+        POST_ENTITY,
+    },
+    content_report_services::{
+        content_report_stats_service, list_content_reports_service, report_content_service,
+        resolve_content_report_service,
+    },
+};
+use crate::middlewares::{
+    admin_middleware::admin_middleware, jwt_token_middleware::jwt_token_middleware,
+    uuid_path_middleware::uuid_path_middleware,
+};
+use actix_web::{get, patch, post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn content_report_post_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(report_post);
+}
+
+pub fn content_report_comment_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(report_comment);
+}
+
+pub fn content_report_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_content_reports)
+        .service(content_report_stats)
+        .service(resolve_content_report);
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/report",
+    security(("bearer_auth" = [])),
+    request_body = InsertContentReportDTO,
+    responses((
+        status = 201, description = "Denúncia registrada para análise dos administradores (Created)",
+        body = ContentReportDTO, content_type = "application/json", example = json ! ({
+            "id": "3c4d5e6f-7a8b-4c9d-8e0f-1a2b3c4d5e6f",
+            "reporterId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "entityType": "post",
+            "entityId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "reason": "Conteúdo ofensivo contra outros leitores.",
+            "status": "pending",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "resolvedAt": null,
+            "resolvedBy": null
+        })
+	), (
+		status = 400, description = "Erro do usuário por id ou corpo inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "reason": [{
+                "code": "length",
+                "message": "O motivo da denúncia deve ter entre 10 e 500 caracteres.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Conteúdo não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O usuário tem uma denúncia pendente deste conteúdo feita nos últimos 7 dias (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "report": [{
+                "code": "conflict",
+                "message": "Você já denunciou este conteúdo nos últimos 7 dias.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 429, description = "O usuário atingiu o limite diário de denúncias (Too Many Requests)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "report": [{
+                "code": "too many requests",
+                "message": "Você pode enviar no máximo 10 denúncias a cada 24 horas.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{post_id}/report")]
+async fn report_post(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<InsertContentReportDTO>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match report_content_service(
+        pg_pool,
+        &redis_pool,
+        token.claims.sub,
+        POST_ENTITY,
+        post_id,
+        body.into_inner().reason,
+    )
+    .await
+    {
+        Ok(report) => HttpResponse::Created().json(report),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/comment/{comment_id}/report",
+    security(("bearer_auth" = [])),
+    request_body = InsertContentReportDTO,
+    responses((
+        status = 201, description = "Denúncia registrada para análise dos administradores (Created)",
+        body = ContentReportDTO, content_type = "application/json", example = json ! ({
+            "id": "3c4d5e6f-7a8b-4c9d-8e0f-1a2b3c4d5e6f",
+            "reporterId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "entityType": "comment",
+            "entityId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "reason": "Conteúdo ofensivo contra outros leitores.",
+            "status": "pending",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "resolvedAt": null,
+            "resolvedBy": null
+        })
+	), (
+		status = 400, description = "Erro do usuário por id ou corpo inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "reason": [{
+                "code": "length",
+                "message": "O motivo da denúncia deve ter entre 10 e 500 caracteres.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Conteúdo não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "comment": [{
+                "code": "not found",
+                "message": "Não foi encontrado um comentário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O usuário tem uma denúncia pendente deste conteúdo feita nos últimos 7 dias (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "report": [{
+                "code": "conflict",
+                "message": "Você já denunciou este conteúdo nos últimos 7 dias.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 429, description = "O usuário atingiu o limite diário de denúncias (Too Many Requests)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "report": [{
+                "code": "too many requests",
+                "message": "Você pode enviar no máximo 10 denúncias a cada 24 horas.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/{comment_id}/report")]
+async fn report_comment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<InsertContentReportDTO>,
+    comment_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let comment_id = match uuid_path_middleware(comment_id.into_inner(), "comment_id") {
+        Ok(comment_id) => comment_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match report_content_service(
+        pg_pool,
+        &redis_pool,
+        token.claims.sub,
+        COMMENT_ENTITY,
+        comment_id,
+        body.into_inner().reason,
+    )
+    .await
+    {
+        Ok(report) => HttpResponse::Created().json(report),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/reports",
+    security(("bearer_auth" = [])),
+    params(
+        ("status" = Option<String>, Query, description = "Filtra as denúncias pelo status (pending ou resolved)"),
+    ),
+    responses((
+        status = 200, description = "As últimas 100 denúncias, das mais recentes para as mais antigas (OK)",
+        body = Vec<ContentReportDTO>, content_type = "application/json", example = json ! ([{
+            "id": "3c4d5e6f-7a8b-4c9d-8e0f-1a2b3c4d5e6f",
+            "reporterId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "entityType": "comment",
+            "entityId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "reason": "Conteúdo ofensivo contra outros leitores.",
+            "status": "pending",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "resolvedAt": null,
+            "resolvedBy": null
+        }])
+	), (
+		status = 400, description = "Erro do usuário por status inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "status": [{
+                "code": "regex",
+                "message": "O status da denúncia deve ser pending ou resolved.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/reports")]
+async fn list_content_reports(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    query_params: web::Query<ContentReportParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match query_params.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match list_content_reports_service(pg_pool, query_params.into_inner().status).await {
+        Ok(reports) => HttpResponse::Ok().json(reports),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/reports/stats",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Carga de trabalho da moderação, em cache por 5 minutos (OK)",
+        body = ContentReportStatsDTO, content_type = "application/json", example = json ! ({
+            "totalPending": 12,
+            "avgResolutionTimeHours": 5.25,
+            "reportsByType": {
+                "post": 20,
+                "comment": 34
+            },
+            "mostReportedAuthors": [{
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "reportCount": 9
+            }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/reports/stats")]
+async fn content_report_stats(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match content_report_stats_service(pg_pool, &redis_pool).await {
+        Ok(stats) => HttpResponse::Ok().json(stats),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/report/{report_id}/resolve",
+    security(("bearer_auth" = [])),
+    request_body = ResolveContentReportDTO,
+    responses((
+        status = 200, description = "Denúncia resolvida, com o conteúdo removido quando deleteContent é true; o autor da denúncia é notificado (OK)",
+        body = ContentReportDTO, content_type = "application/json", example = json ! ({
+            "id": "3c4d5e6f-7a8b-4c9d-8e0f-1a2b3c4d5e6f",
+            "reporterId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "entityType": "post",
+            "entityId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "reason": "Conteúdo ofensivo contra outros leitores.",
+            "status": "resolved",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "resolvedAt": "2024-06-19 10:12:03.481516 UTC",
+            "resolvedBy": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f"
+        })
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "report_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Denúncia não encontrada (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "report": [{
+                "code": "not found",
+                "message": "Não foi encontrada uma denúncia com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "A denúncia já foi resolvida (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "report": [{
+                "code": "conflict",
+                "message": "Esta denúncia já foi resolvida.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("/report/{report_id}/resolve")]
+async fn resolve_content_report(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<ResolveContentReportDTO>,
+    report_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match admin_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let report_id = match uuid_path_middleware(report_id.into_inner(), "report_id") {
+        Ok(report_id) => report_id,
+        Err(e) => return e,
+    };
+    match resolve_content_report_service(
+        pg_pool,
+        &redis_pool,
+        report_id,
+        token.claims.sub,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(e) => e,
+    }
+}
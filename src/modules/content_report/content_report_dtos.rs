@@ -0,0 +1,75 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+pub const POST_ENTITY: &str = "post";
+pub const COMMENT_ENTITY: &str = "comment";
+
+static RE_REPORT_STATUS: Lazy<Regex> = Lazy::new(|| Regex::new("^(pending|resolved)$").unwrap());
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentReportDTO {
+    pub id: String,
+    pub reporter_id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub reason: String,
+    pub status: String,
+    pub created_at: String,
+    pub resolved_at: Option<String>,
+    pub resolved_by: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate)]
+pub struct InsertContentReportDTO {
+    #[validate(length(
+        min = 10,
+        max = 500,
+        message = "O motivo da denúncia deve ter entre 10 e 500 caracteres."
+    ))]
+    pub reason: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate)]
+pub struct ContentReportParams {
+    #[validate(regex(
+        path = *RE_REPORT_STATUS,
+        message = "O status da denúncia deve ser pending ou resolved."
+    ))]
+    pub status: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+pub struct ResolveContentReportDTO {
+    /// Whether the reported post or comment is soft-deleted along with the resolution.
+    #[serde(default, alias = "deleteContent")]
+    pub delete_content: bool,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ReportsByTypeDTO {
+    pub post: i64,
+    pub comment: i64,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportedAuthorDTO {
+    pub author_id: String,
+    pub report_count: i64,
+}
+
+/// The moderation workload, counted over every report.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentReportStatsDTO {
+    pub total_pending: i64,
+    /// The average time between a report and its resolution, `0` when none was resolved.
+    pub avg_resolution_time_hours: f64,
+    pub reports_by_type: ReportsByTypeDTO,
+    /// The authors of the most reported posts and comments, most reported first.
+    pub most_reported_authors: Vec<ReportedAuthorDTO>,
+}
@@ -0,0 +1,251 @@
+use super::content_report_dtos::{
+    ContentReportDTO, ContentReportStatsDTO, ReportsByTypeDTO, COMMENT_ENTITY,
+};
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+const INSERT_CONTENT_REPORT_SQL: &str = "
+    WITH entity AS (
+        SELECT id FROM posts WHERE $3::TEXT = 'post' AND id = $4 AND deleted_at IS NULL
+        UNION ALL
+        SELECT id FROM comments WHERE $3::TEXT = 'comment' AND id = $4 AND deleted_at IS NULL
+    ), inserted AS (
+        INSERT INTO content_reports (id, reporter_id, entity_type, entity_id, reason, status, created_at)
+        SELECT $1, $2, $3, id, $5, 'pending', NOW() FROM entity
+        WHERE NOT EXISTS (
+            SELECT 1 FROM content_reports
+            WHERE reporter_id = $2 AND entity_type = $3 AND entity_id = $4 AND status = 'pending'
+                AND created_at > NOW() - INTERVAL '7 days'
+        )
+        RETURNING *
+    )
+    SELECT EXISTS (SELECT 1 FROM entity) AS entity_exists, inserted.*
+    FROM (SELECT 1) AS one LEFT JOIN inserted ON TRUE
+";
+
+const LIST_CONTENT_REPORTS_SQL: &str = "
+    SELECT * FROM content_reports
+    WHERE $1::TEXT IS NULL OR status = $1
+    ORDER BY created_at DESC
+    LIMIT $2
+";
+
+const RESOLVE_CONTENT_REPORT_SQL: &str = "
+    WITH resolved AS (
+        UPDATE content_reports SET status = 'resolved', resolved_at = NOW(), resolved_by = $2
+        WHERE id = $1 AND status = 'pending'
+        RETURNING *
+    ), deleted_post AS (
+        UPDATE posts SET deleted_at = NOW() FROM resolved
+        WHERE $3 AND resolved.entity_type = 'post' AND posts.id = resolved.entity_id
+            AND posts.deleted_at IS NULL
+    ), deleted_comment AS (
+        UPDATE comments SET deleted_at = NOW() FROM resolved
+        WHERE $3 AND resolved.entity_type = 'comment' AND comments.id = resolved.entity_id
+            AND comments.deleted_at IS NULL
+    )
+    SELECT EXISTS (SELECT 1 FROM content_reports WHERE id = $1) AS report_exists, resolved.*
+    FROM (SELECT 1) AS one LEFT JOIN resolved ON TRUE
+";
+
+const CONTENT_REPORT_STATS_SQL: &str = "
+    WITH reports AS (
+        SELECT r.entity_type, r.status, r.created_at, r.resolved_at,
+            COALESCE(p.author_id, c.user_id) AS author_id
+        FROM content_reports r
+        LEFT JOIN posts p ON r.entity_type = 'post' AND p.id = r.entity_id
+        LEFT JOIN comments c ON r.entity_type = 'comment' AND c.id = r.entity_id
+    )
+    SELECT COUNT(*) FILTER (WHERE status = 'pending') AS total_pending,
+        COALESCE(
+            AVG(EXTRACT(EPOCH FROM resolved_at - created_at) / 3600)
+                FILTER (WHERE resolved_at IS NOT NULL),
+            0
+        )::FLOAT8 AS avg_resolution_time_hours,
+        COUNT(*) FILTER (WHERE entity_type = 'post') AS post_reports,
+        COUNT(*) FILTER (WHERE entity_type = 'comment') AS comment_reports,
+        (
+            SELECT COALESCE(json_agg(json_build_object(
+                'authorId', author_id, 'reportCount', report_count
+            ))::TEXT, '[]')
+            FROM (
+                SELECT author_id, COUNT(*) AS report_count FROM reports
+                WHERE author_id IS NOT NULL
+                GROUP BY author_id
+                ORDER BY report_count DESC, author_id
+                LIMIT $1
+            ) AS authors
+        ) AS most_reported_authors
+    FROM reports
+";
+
+pub const CONTENT_REPORTS_LIMIT: i64 = 100;
+const MOST_REPORTED_AUTHORS_LIMIT: i64 = 10;
+
+fn content_report_dto_constructor(row: &Row) -> ContentReportDTO {
+    let id: uuid::Uuid = row.get("id");
+    let reporter_id: uuid::Uuid = row.get("reporter_id");
+    let entity_id: uuid::Uuid = row.get("entity_id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    let resolved_at: Option<DateTime<Utc>> = row.get("resolved_at");
+    let resolved_by: Option<uuid::Uuid> = row.get("resolved_by");
+    ContentReportDTO {
+        id: id.to_string(),
+        reporter_id: reporter_id.to_string(),
+        entity_type: row.get("entity_type"),
+        entity_id: entity_id.to_string(),
+        reason: row.get("reason"),
+        status: row.get("status"),
+        created_at: created_at.to_string(),
+        resolved_at: resolved_at.map(|dt| dt.to_string()),
+        resolved_by: resolved_by.map(|id| id.to_string()),
+    }
+}
+
+fn entity_not_found(entity_type: &str, entity_id: uuid::Uuid) -> HttpResponse {
+    let message = match entity_type {
+        COMMENT_ENTITY => "Não foi encontrado um comentário com este id.",
+        _ => "Não foi encontrado um post com este id.",
+    };
+    HttpResponse::NotFound().json(error_construct(
+        String::from(entity_type),
+        String::from("not found"),
+        String::from(message),
+        Some(entity_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+/// Reports the post or comment, unless the user already has a pending report of it from the last
+/// 7 days.
+pub async fn insert_content_report_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    reporter_id: uuid::Uuid,
+    entity_type: &str,
+    entity_id: uuid::Uuid,
+    reason: String,
+) -> Result<ContentReportDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_CONTENT_REPORT_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &reporter_id,
+            &entity_type,
+            &entity_id,
+            &reason,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if !rows[0].get::<_, bool>("entity_exists") {
+        return Err(entity_not_found(entity_type, entity_id));
+    }
+    let inserted_id: Option<uuid::Uuid> = rows[0].get("id");
+    if inserted_id.is_none() {
+        return Err(HttpResponse::Conflict().json(error_construct(
+            String::from("report"),
+            String::from("conflict"),
+            String::from("Você já denunciou este conteúdo nos últimos 7 dias."),
+            Some(entity_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(content_report_dto_constructor(&rows[0]))
+}
+
+/// Returns the latest reports, only the ones with the status when it is given.
+pub async fn list_content_reports_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    status: Option<String>,
+) -> Result<Vec<ContentReportDTO>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        LIST_CONTENT_REPORTS_SQL,
+        &[&status, &CONTENT_REPORTS_LIMIT],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.iter().map(content_report_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Resolves the pending report, soft-deleting the reported content when `delete_content` is set.
+pub async fn resolve_content_report_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    report_id: uuid::Uuid,
+    admin_id: uuid::Uuid,
+    delete_content: bool,
+) -> Result<ContentReportDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        RESOLVE_CONTENT_REPORT_SQL,
+        &[&report_id, &admin_id, &delete_content],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if !rows[0].get::<_, bool>("report_exists") {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("report"),
+            String::from("not found"),
+            String::from("Não foi encontrada uma denúncia com este id."),
+            Some(report_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    let resolved_id: Option<uuid::Uuid> = rows[0].get("id");
+    if resolved_id.is_none() {
+        return Err(HttpResponse::Conflict().json(error_construct(
+            String::from("report"),
+            String::from("conflict"),
+            String::from("Esta denúncia já foi resolvida."),
+            Some(report_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(content_report_dto_constructor(&rows[0]))
+}
+
+/// Aggregates the moderation workload in a single query, with up to `MOST_REPORTED_AUTHORS_LIMIT`
+/// of the most reported authors.
+pub async fn content_report_stats_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<ContentReportStatsDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        CONTENT_REPORT_STATS_SQL,
+        &[&MOST_REPORTED_AUTHORS_LIMIT],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let row = &rows[0];
+    let most_reported_authors: String = row.get("most_reported_authors");
+    Ok(ContentReportStatsDTO {
+        total_pending: row.get("total_pending"),
+        avg_resolution_time_hours: row.get("avg_resolution_time_hours"),
+        reports_by_type: ReportsByTypeDTO {
+            post: row.get("post_reports"),
+            comment: row.get("comment_reports"),
+        },
+        most_reported_authors: serde_json::from_str(&most_reported_authors).unwrap_or_default(),
+    })
+}
@@ -0,0 +1,184 @@
+use super::{
+    content_report_dtos::{
+        ContentReportDTO, ContentReportStatsDTO, ResolveContentReportDTO, COMMENT_ENTITY,
+        POST_ENTITY,
+    },
+    content_report_repositories::*,
+};
+use crate::{
+    infra::redis::Redis,
+    middlewares::etag_middleware::etag_invalidate_route,
+    modules::{
+        comment::comment_services::invalidate_comment_counts_service,
+        metrics::metrics_services::MODERATION_PENDING_REPORTS,
+        notification::notification_services::insert_notification_service,
+        post::post_services::{invalidate_archive_service, invalidate_excerpts_service},
+    },
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+use once_cell::sync::Lazy;
+use std::{env, sync::atomic::Ordering};
+
+pub const REPORT_RESOLVED_NOTIFICATION: &str = "report_resolved";
+pub const CONTENT_REPORT_STATS_KEY: &str = "reports:stats";
+const REPORT_RATE_EXPIRATION_SECONDS: u64 = 60 * 60 * 24;
+const CONTENT_REPORT_STATS_EXPIRATION_SECONDS: u64 = 300;
+
+/// How many reports a user can send in 24 hours, from the `MAX_REPORTS_PER_DAY` env var
+/// (default 10).
+pub static MAX_REPORTS_PER_DAY: Lazy<i64> = Lazy::new(|| {
+    env::var("MAX_REPORTS_PER_DAY")
+        .ok()
+        .and_then(|limit| limit.parse().ok())
+        .unwrap_or(10)
+});
+
+pub fn report_rate_key(reporter_id: &str) -> String {
+    format!("report:rate:{}", reporter_id)
+}
+
+/// Reports the content for the administrators.
+///
+/// Each user can send up to `MAX_REPORTS_PER_DAY` reports in 24 hours, counted in Redis from the
+/// first one, and cannot report the same content again while their report of the last 7 days is
+/// still pending.
+pub async fn report_content_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    reporter_id: String,
+    entity_type: &str,
+    entity_id: String,
+    reason: String,
+) -> Result<ContentReportDTO, HttpResponse> {
+    let key = report_rate_key(&reporter_id);
+    match Redis::incr_ex(redis_pool, &key, REPORT_RATE_EXPIRATION_SECONDS).await {
+        Ok(reports) if reports > *MAX_REPORTS_PER_DAY => {
+            return Err(HttpResponse::TooManyRequests().json(error_construct(
+                String::from("report"),
+                String::from("too many requests"),
+                format!(
+                    "Você pode enviar no máximo {} denúncias a cada 24 horas.",
+                    *MAX_REPORTS_PER_DAY
+                ),
+                Some(reporter_id),
+                None,
+                None,
+            )))
+        }
+        Ok(_) => (),
+        Err(e) => {
+            return Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                String::from("redis"),
+                String::from("service unavailable"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            )))
+        }
+    };
+
+    insert_content_report_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&reporter_id).unwrap_or_default(),
+        entity_type,
+        uuid::Uuid::parse_str(&entity_id).unwrap_or_default(),
+        reason,
+    )
+    .await
+}
+
+pub async fn list_content_reports_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    status: Option<String>,
+) -> Result<Vec<ContentReportDTO>, HttpResponse> {
+    list_content_reports_repository(pg_pool, status).await
+}
+
+/// Returns the moderation workload, cached in Redis for `CONTENT_REPORT_STATS_EXPIRATION_SECONDS`,
+/// and mirrors its `total_pending` on the `moderation_pending_reports` gauge.
+pub async fn content_report_stats_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+) -> Result<ContentReportStatsDTO, HttpResponse> {
+    let cached = match Redis::get(redis_pool, CONTENT_REPORT_STATS_KEY).await {
+        Ok(cached) => serde_json::from_str::<ContentReportStatsDTO>(&cached).ok(),
+        Err(_) => None,
+    };
+    let stats = match cached {
+        Some(stats) => stats,
+        None => {
+            let stats = match content_report_stats_repository(pg_pool).await {
+                Ok(x) => x,
+                Err(e) => return Err(e),
+            };
+            let _ = Redis::set_ex(
+                redis_pool,
+                CONTENT_REPORT_STATS_KEY,
+                &serde_json::to_string(&stats).unwrap_or_default(),
+                CONTENT_REPORT_STATS_EXPIRATION_SECONDS,
+            )
+            .await;
+            stats
+        }
+    };
+    MODERATION_PENDING_REPORTS.store(stats.total_pending, Ordering::Relaxed);
+    Ok(stats)
+}
+
+/// Resolves the report and notifies its reporter, without revealing which administrator resolved it.
+///
+/// The report is already resolved when the notification is sent, so a failure to send it is only
+/// logged.
+pub async fn resolve_content_report_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    report_id: String,
+    admin_id: String,
+    body: ResolveContentReportDTO,
+) -> Result<ContentReportDTO, HttpResponse> {
+    let report = match resolve_content_report_repository(
+        pg_pool.clone(),
+        uuid::Uuid::parse_str(&report_id).unwrap_or_default(),
+        uuid::Uuid::parse_str(&admin_id).unwrap_or_default(),
+        body.delete_content,
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if body.delete_content && report.entity_type == POST_ENTITY {
+        invalidate_archive_service(redis_pool).await;
+        invalidate_excerpts_service(redis_pool).await;
+        etag_invalidate_route(redis_pool, "post").await;
+    }
+    if body.delete_content && report.entity_type == COMMENT_ENTITY {
+        invalidate_comment_counts_service(redis_pool).await;
+    }
+
+    let payload = serde_json::json!({
+        "reportId": report.id,
+        "entityType": report.entity_type,
+        "entityId": report.entity_id,
+        "contentDeleted": body.delete_content,
+    });
+    if let Err(e) = insert_notification_service(
+        pg_pool,
+        redis_pool,
+        uuid::Uuid::parse_str(&report.reporter_id).unwrap_or_default(),
+        None,
+        REPORT_RESOLVED_NOTIFICATION,
+        payload,
+    )
+    .await
+    {
+        log::warn!(
+            "Failed to notify the resolution of report {}: {}",
+            report.id,
+            e.status()
+        );
+    }
+    Ok(report)
+}
@@ -0,0 +1,4 @@
+pub mod content_report_controllers;
+pub mod content_report_dtos;
+pub mod content_report_repositories;
+pub mod content_report_services;
@@ -0,0 +1,176 @@
+use super::dead_letter_services::{
+    delete_dead_letter_item_service, list_dead_letter_items_service,
+};
+use crate::middlewares::{
+    admin_middleware::admin_middleware, uuid_path_middleware::uuid_path_middleware,
+};
+use actix_web::{delete, get, web, HttpRequest, HttpResponse, Responder};
+
+pub fn dead_letter_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_dead_letter_items)
+        .service(delete_dead_letter_item);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/queue/dead-letter",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Itens da fila que falharam 5 vezes e não serão mais reprocessados (OK)",
+        body = Vec<FailedQueueItemDTO>, content_type = "application/json", example = json ! ([{
+            "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "queue": "insert_user",
+            "error": "db error: ERROR: duplicate key value violates unique constraint \"users_email_key\"",
+            "attempts": 5,
+            "permanentlyFailed": true,
+            "createdAt": "2024-06-18 22:03:54.053147 UTC",
+            "lastAttemptAt": "2024-06-19 00:03:54.053147 UTC"
+        }])
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/queue/dead-letter")]
+async fn list_dead_letter_items(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match list_dead_letter_items_service(pg_pool).await {
+        Ok(items) => HttpResponse::Ok().json(items),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/queue/dead-letter/{item_id}",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Item da fila removido (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "item_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "123",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Item da fila não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "item_id": [{
+                "code": "not found",
+                "message": "Não foi encontrado um item da fila com este id.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("/queue/dead-letter/{item_id}")]
+async fn delete_dead_letter_item(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    item_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let item_id = match uuid_path_middleware(item_id.into_inner(), "item_id") {
+        Ok(item_id) => item_id,
+        Err(e) => return e,
+    };
+    match delete_dead_letter_item_service(pg_pool, item_id).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
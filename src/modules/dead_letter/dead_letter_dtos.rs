@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The queue of the users waiting to be inserted by `insert_user_flush_queue`.
+pub const INSERT_USER_QUEUE: &str = "insert_user";
+
+/// A queue item that could not be processed. The payload is not exposed, since it carries the
+/// password hash and the salt of the user.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedQueueItemDTO {
+    pub id: String,
+    pub queue: String,
+    pub error: String,
+    pub attempts: i32,
+    pub permanently_failed: bool,
+    pub created_at: String,
+    pub last_attempt_at: Option<String>,
+}
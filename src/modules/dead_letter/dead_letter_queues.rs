@@ -0,0 +1,34 @@
+use super::dead_letter_services::reprocess_dead_letter_items;
+use crate::modules::user::user_queues::InsertUserAppQueue;
+use actix_web::web::Data;
+use deadpool_postgres::Pool;
+use std::{sync::Arc, time::Duration};
+
+const REPROCESS_INTERVAL_SECS: u64 = 900;
+
+/// Retries the failed user insertions every 15 minutes.
+pub async fn dead_letter_reprocess_job(
+    pool_async: Pool,
+    redis_pool: deadpool_redis::Pool,
+    queue: Arc<InsertUserAppQueue>,
+) {
+    let mut interval = tokio::time::interval(Duration::from_secs(REPROCESS_INTERVAL_SECS));
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        match reprocess_dead_letter_items(
+            Data::new(pool_async.clone()),
+            Data::new(redis_pool.clone()),
+            queue.clone(),
+        )
+        .await
+        {
+            Ok(0) => (),
+            Ok(requeued) => log::info!("Re-enqueued {} failed user insertions.", requeued),
+            Err(e) => log::warn!(
+                "Failed to reprocess the dead letter items: status {}",
+                e.status()
+            ),
+        };
+    }
+}
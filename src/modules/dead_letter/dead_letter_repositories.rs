@@ -0,0 +1,132 @@
+use super::dead_letter_dtos::FailedQueueItemDTO;
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+const INSERT_FAILED_QUEUE_ITEMS_SQL: &str = "
+    INSERT INTO failed_queue_items (id, queue, payload, error)
+    SELECT item.id, $2, item.payload::JSONB, $4
+    FROM UNNEST($1::UUID[], $3::TEXT[]) AS item (id, payload)
+    ON CONFLICT (id) DO UPDATE SET error = EXCLUDED.error
+";
+
+const MARK_PERMANENTLY_FAILED_SQL: &str = "
+    UPDATE failed_queue_items SET permanently_failed = true
+    WHERE queue = $1 AND attempts >= $2 AND NOT permanently_failed
+";
+
+const CLAIM_RETRYABLE_ITEMS_SQL: &str = "
+    UPDATE failed_queue_items SET attempts = attempts + 1, last_attempt_at = NOW()
+    WHERE queue = $1 AND attempts < $2 AND NOT permanently_failed
+        AND created_at > NOW() - INTERVAL '24 hours'
+    RETURNING id, payload::TEXT AS payload
+";
+
+const LIST_PERMANENTLY_FAILED_SQL: &str = "
+    SELECT id, queue, error, attempts, permanently_failed, created_at, last_attempt_at
+    FROM failed_queue_items WHERE permanently_failed
+    ORDER BY created_at DESC
+";
+
+const DELETE_FAILED_QUEUE_ITEM_SQL: &str =
+    "DELETE FROM failed_queue_items WHERE id = $1 RETURNING id";
+
+fn failed_queue_item_dto_constructor(row: &Row) -> FailedQueueItemDTO {
+    let id: uuid::Uuid = row.get("id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    let last_attempt_at: Option<DateTime<Utc>> = row.get("last_attempt_at");
+    FailedQueueItemDTO {
+        id: id.to_string(),
+        queue: row.get("queue"),
+        error: row.get("error"),
+        attempts: row.get("attempts"),
+        permanently_failed: row.get("permanently_failed"),
+        created_at: created_at.to_string(),
+        last_attempt_at: last_attempt_at.map(|x| x.to_string()),
+    }
+}
+
+/// Stores the items of a failed queue flush, keeping the attempts of the ones already stored.
+pub async fn insert_failed_queue_items_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    queue: &str,
+    items: Vec<(uuid::Uuid, String)>,
+    error: String,
+) -> Result<(), HttpResponse> {
+    let (ids, payloads): (Vec<uuid::Uuid>, Vec<String>) = items.into_iter().unzip();
+    match query_executor(
+        pg_pool,
+        INSERT_FAILED_QUEUE_ITEMS_SQL,
+        &[&ids, &queue, &payloads, &error],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn mark_permanently_failed_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    queue: &str,
+    max_attempts: i32,
+) -> Result<(), HttpResponse> {
+    match query_executor(
+        pg_pool,
+        MARK_PERMANENTLY_FAILED_SQL,
+        &[&queue, &max_attempts],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Counts one more attempt for the items of the last 24 hours still under `max_attempts`,
+/// returning their payloads.
+pub async fn claim_retryable_items_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    queue: &str,
+    max_attempts: i32,
+) -> Result<Vec<(uuid::Uuid, String)>, HttpResponse> {
+    match query_executor(pg_pool, CLAIM_RETRYABLE_ITEMS_SQL, &[&queue, &max_attempts]).await {
+        Ok(rows) => Ok(rows
+            .iter()
+            .map(|row| (row.get("id"), row.get("payload")))
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn list_permanently_failed_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<FailedQueueItemDTO>, HttpResponse> {
+    match query_executor(pg_pool, LIST_PERMANENTLY_FAILED_SQL, &[]).await {
+        Ok(rows) => Ok(rows.iter().map(failed_queue_item_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn delete_failed_queue_item_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    item_id: String,
+) -> Result<(), HttpResponse> {
+    let id = uuid::Uuid::parse_str(&item_id).unwrap_or_default();
+    let rows = match query_executor(pg_pool, DELETE_FAILED_QUEUE_ITEM_SQL, &[&id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("item_id"),
+            String::from("not found"),
+            String::from("Não foi encontrado um item da fila com este id."),
+            Some(item_id),
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
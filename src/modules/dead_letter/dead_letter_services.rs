@@ -0,0 +1,80 @@
+use super::{
+    dead_letter_dtos::{FailedQueueItemDTO, INSERT_USER_QUEUE},
+    dead_letter_repositories::{
+        claim_retryable_items_repository, delete_failed_queue_item_repository,
+        list_permanently_failed_repository, mark_permanently_failed_repository,
+    },
+};
+use crate::{
+    infra::redis::Redis,
+    modules::user::user_queues::{requeue_insert_user, InsertUserAppQueue, InsertUserQueuePayload},
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+use std::sync::Arc;
+
+const MAX_ATTEMPTS: i32 = 5;
+const REPROCESS_LOCK_KEY: &str = "queue:dead-letter:lock";
+const REPROCESS_LOCK_SECONDS: u64 = 60;
+
+/// Re-enqueues the users whose insertion failed in the last 24 hours, counting one more attempt
+/// for each. Items with 5 attempts are marked as permanently failed and no longer retried.
+///
+/// A Redis lock keeps two instances from re-enqueueing the same items, returning `0` when another
+/// instance holds it.
+pub async fn reprocess_dead_letter_items(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    queue: Arc<InsertUserAppQueue>,
+) -> Result<usize, HttpResponse> {
+    match Redis::set_nx_ex(&redis_pool, REPROCESS_LOCK_KEY, "1", REPROCESS_LOCK_SECONDS).await {
+        Ok(true) => (),
+        Ok(false) => return Ok(0),
+        Err(e) => {
+            return Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                String::from("redis"),
+                String::from("service unavailable"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            )))
+        }
+    };
+
+    match mark_permanently_failed_repository(pg_pool.clone(), INSERT_USER_QUEUE, MAX_ATTEMPTS).await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let items =
+        match claim_retryable_items_repository(pg_pool, INSERT_USER_QUEUE, MAX_ATTEMPTS).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+
+    let mut requeued = 0;
+    for (id, payload) in items {
+        match serde_json::from_str::<InsertUserQueuePayload>(&payload) {
+            Ok(payload) => {
+                requeue_insert_user(&queue, payload);
+                requeued += 1;
+            }
+            Err(e) => log::warn!("Failed to read the dead letter item {}: {}", id, e),
+        };
+    }
+    Ok(requeued)
+}
+
+pub async fn list_dead_letter_items_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<FailedQueueItemDTO>, HttpResponse> {
+    list_permanently_failed_repository(pg_pool).await
+}
+
+pub async fn delete_dead_letter_item_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    item_id: String,
+) -> Result<(), HttpResponse> {
+    delete_failed_queue_item_repository(pg_pool, item_id).await
+}
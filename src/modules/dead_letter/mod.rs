@@ -0,0 +1,5 @@
+pub mod dead_letter_controllers;
+pub mod dead_letter_dtos;
+pub mod dead_letter_queues;
+pub mod dead_letter_repositories;
+pub mod dead_letter_services;
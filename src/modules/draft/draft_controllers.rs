@@ -0,0 +1,369 @@
+use super::{
+    draft_dtos::PutDraftDTO,
+    draft_services::{detail_draft_service, promote_draft_service, put_draft_service},
+};
+use crate::{
+    middlewares::{
+        api_key_middleware::jwt_or_api_key_middleware,
+        idempotency_middleware::idempotency_middleware,
+    },
+    modules::{
+        api_key::api_key_dtos::{POST_READ_SCOPE, POST_WRITE_SCOPE},
+        feed::feed_services::invalidate_sitemap_service,
+        follower::follower_services::invalidate_activity_feeds_service,
+        post::post_services::invalidate_archive_service,
+        user_badge::user_badge_services::evaluate_badges_service,
+        user_onboarding::{
+            user_onboarding_dtos::OnboardingStep,
+            user_onboarding_services::complete_onboarding_step_service,
+        },
+        webhook::{webhook_dtos::POST_PUBLISHED_EVENT, webhook_services::fire_webhook_service},
+    },
+};
+use actix_web::{get, middleware::from_fn, post, put, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn draft_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_draft)
+        .service(detail_draft)
+        .service(promote_draft);
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/draft/{draft_id}",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    request_body = PutDraftDTO,
+    responses((
+        status = 200, description = "Rascunho salvo (OK)", body = DraftDTO,
+        content_type = "application/json", example = json ! ({
+            "id": "5b8f2c1e-7a3d-4e9b-9c1a-2f6d8e4b7a10",
+            "postId": null,
+            "authorId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "title": "Rust e Actix Web",
+            "body": "Rascunho do post",
+            "savedAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O rascunho pertence a outro autor (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft": [{
+                "code": "forbidden",
+                "message": "Apenas o autor pode acessar este rascunho.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post do rascunho não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post deste autor com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[put("draft/{draft_id}")]
+async fn put_draft(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    body: web::Json<PutDraftDTO>,
+    draft_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match jwt_or_api_key_middleware(&req, POST_WRITE_SCOPE).await {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match put_draft_service(pg_pool, draft_id.into_inner(), user_id, body.into_inner()).await {
+        Ok(draft) => HttpResponse::Ok().json(draft),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/draft/{draft_id}",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses((
+        status = 200, description = "Rascunho encontrado (OK)", body = DraftDTO,
+        content_type = "application/json", example = json ! ({
+            "id": "5b8f2c1e-7a3d-4e9b-9c1a-2f6d8e4b7a10",
+            "postId": null,
+            "authorId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "title": "Rust e Actix Web",
+            "body": "Rascunho do post",
+            "savedAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O rascunho pertence a outro autor (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft": [{
+                "code": "forbidden",
+                "message": "Apenas o autor pode acessar este rascunho.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Rascunho não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft": [{
+                "code": "not found",
+                "message": "Não foi encontrado um rascunho com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("draft/{draft_id}")]
+async fn detail_draft(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    draft_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match jwt_or_api_key_middleware(&req, POST_READ_SCOPE).await {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match detail_draft_service(pg_pool, draft_id.into_inner(), user_id).await {
+        Ok(draft) => HttpResponse::Ok().json(draft),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/draft/{draft_id}/promote",
+    security(("bearer_auth" = []), ("api_key" = [])),
+    responses((
+        status = 201, description = "Rascunho publicado como post, com os avisos de markdown do corpo, que não impedem a publicação (Created)",
+        headers(("Location" = String, description = "URL do post publicado")),
+        body = PromotedDraftDTO, content_type = "application/json", example = json ! ({
+            "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "warnings": [{
+                "line": 12,
+                "column": 1,
+                "rule": "image_missing_alt",
+                "message": "A imagem não tem texto alternativo."
+            }]
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O rascunho pertence a outro autor (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft": [{
+                "code": "forbidden",
+                "message": "Apenas o autor pode acessar este rascunho.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Rascunho não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "draft": [{
+                "code": "not found",
+                "message": "Não foi encontrado um rascunho com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O autor já tem um post com o mesmo título e conteúdo (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "conflict",
+                "message": "Já existe um post deste autor com o mesmo título e conteúdo.",
+                "params": {
+                    "min": null,
+                    "value": "0191d2e4-6a3b-7c2f-9e1d-4b5a6c7d8e9f",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("draft/{draft_id}/promote", wrap = "from_fn(idempotency_middleware)")]
+async fn promote_draft(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    draft_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match jwt_or_api_key_middleware(&req, POST_WRITE_SCOPE).await {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match promote_draft_service(pg_pool.clone(), draft_id.into_inner(), user_id.clone()).await {
+        Ok(promoted) => {
+            let post_id = promoted.id.clone();
+            invalidate_sitemap_service(&redis_pool).await;
+            invalidate_archive_service(&redis_pool).await;
+            let author_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+            complete_onboarding_step_service(
+                pg_pool.clone(),
+                &redis_pool,
+                author_id,
+                OnboardingStep::FirstPostPublished,
+            )
+            .await;
+            evaluate_badges_service(pg_pool.clone(), &redis_pool, author_id).await;
+            invalidate_activity_feeds_service(
+                pg_pool.clone(),
+                &redis_pool,
+                uuid::Uuid::parse_str(&post_id).unwrap_or_default(),
+            )
+            .await;
+            let webhook_data = serde_json::json!({ "postId": post_id });
+            tokio::spawn(async move {
+                let _ = fire_webhook_service(pg_pool, POST_PUBLISHED_EVENT, webhook_data).await;
+            });
+            HttpResponse::Created()
+                .insert_header(("Location", format!("/post/{}", post_id)))
+                .json(promoted)
+        }
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,65 @@
+use crate::modules::post::{
+    post_dto::LintWarningDTO, post_providers::validate_structured_data_provider,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct PutDraftDTO {
+    #[serde(default, alias = "postId")]
+    pub post_id: Option<String>,
+
+    #[validate(length(max = 1000, message = "O título deve ter no máximo 1000 caracteres."))]
+    #[serde(default)]
+    pub title: String,
+
+    #[validate(length(
+        max = 500000,
+        message = "O corpo deve ter no máximo 500000 caracteres."
+    ))]
+    #[serde(default)]
+    pub body: String,
+
+    #[validate(length(max = 500, message = "O resumo deve ter no máximo 500 caracteres."))]
+    #[serde(default)]
+    pub summary: Option<String>,
+
+    #[validate(custom(function = "validate_structured_data_provider"))]
+    #[serde(default, alias = "structuredData")]
+    pub structured_data: Option<serde_json::Value>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftDTO {
+    pub id: String,
+    pub post_id: Option<String>,
+    pub author_id: String,
+    pub title: String,
+    pub body: String,
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structured_data: Option<serde_json::Value>,
+    pub saved_at: String,
+}
+
+/// The post published from a draft, with the markdown warnings of its body.
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct PromotedDraftDTO {
+    pub id: String,
+    pub warnings: Vec<LintWarningDTO>,
+}
+
+#[derive(Validate)]
+pub struct PublishDraftDTO {
+    #[validate(length(
+        min = 3,
+        max = 127,
+        message = "O título deve ter entre 3 e 127 caracteres."
+    ))]
+    pub title: String,
+
+    #[validate(length(min = 10, message = "O corpo deve ter pelo menos 10 caracteres."))]
+    pub body: String,
+}
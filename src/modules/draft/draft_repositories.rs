@@ -0,0 +1,244 @@
+use super::draft_dtos::{DraftDTO, PutDraftDTO};
+use crate::{
+    modules::post::post_providers::detect_language_provider,
+    shared::parsers::{
+        code_languages_parser::extract_code_languages, slug_parser::slugify,
+        summary_parser::generate_summary, text_counts_parser::count_words_and_characters,
+    },
+    utils::{error_construct::error_construct, query_constructor_executor::query_executor},
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const PUT_DRAFT_SQL: &str = "
+    INSERT INTO drafts (id, post_id, author_id, title, body, summary, structured_data, saved_at)
+    VALUES ($1, $2, $3, $4, $5, $6, $7::TEXT::JSONB, NOW())
+    ON CONFLICT (id) DO UPDATE SET
+        post_id = EXCLUDED.post_id, title = EXCLUDED.title, body = EXCLUDED.body,
+        summary = EXCLUDED.summary, structured_data = EXCLUDED.structured_data, saved_at = NOW()
+    WHERE drafts.author_id = EXCLUDED.author_id
+    RETURNING id, post_id, author_id, title, body, summary,
+        structured_data::TEXT AS structured_data, saved_at
+";
+
+const DETAIL_DRAFT_SQL: &str = "
+    SELECT id, post_id, author_id, title, body, summary,
+        structured_data::TEXT AS structured_data, saved_at
+    FROM drafts WHERE id = $1
+";
+
+const PUBLISH_NEW_DRAFT_SQL: &str = "
+    WITH deleted_draft AS (
+        DELETE FROM drafts WHERE id = $1
+    ),
+    post AS (
+        INSERT INTO posts (
+            id, title, subtitle, body, created_at, published_at, author_id, slug, summary,
+            language, word_count, character_count, code_languages, structured_data, tenant_id
+        )
+        SELECT $2, $3, '', $4, NOW(), NOW(), $5, $6, $7, $8, $9, $10, $11, $12::TEXT::JSONB,
+            u.tenant_id
+        FROM users u WHERE u.id = $5
+        RETURNING id
+    ),
+    primary_author AS (
+        INSERT INTO post_authors (post_id, user_id, role, added_at)
+        SELECT id, $5, 'primary', NOW() FROM post
+    )
+    SELECT id FROM post
+";
+
+const PUBLISH_EXISTING_DRAFT_SQL: &str = "
+    WITH deleted_draft AS (
+        DELETE FROM drafts WHERE id = $1
+            AND EXISTS (SELECT 1 FROM posts WHERE id = $2 AND author_id = $5)
+    ), revision AS (
+        INSERT INTO post_revisions (id, post_id, version, title, body, created_at)
+        SELECT gen_random_uuid(), id, version, title, body, NOW() FROM posts
+        WHERE id = $2 AND author_id = $5
+    )
+    UPDATE posts SET title = $3, body = $4, summary = $7, updated_at = NOW(),
+        language = $8, word_count = $9, character_count = $10, code_languages = $11,
+        structured_data = $12::TEXT::JSONB, version = version + 1,
+        published_at = COALESCE(published_at, NOW()), slug = COALESCE(slug, $6)
+    WHERE id = $2 AND author_id = $5
+    RETURNING id
+";
+
+const AUTHOR_POST_EXISTS_SQL: &str = "SELECT 1 FROM posts WHERE id = $1 AND author_id = $2";
+
+fn post_not_found(post_id: uuid::Uuid) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("post"),
+        String::from("not found"),
+        String::from("Não foi encontrado um post deste autor com este id."),
+        Some(post_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+fn draft_dto_constructor(row: &postgres::Row) -> DraftDTO {
+    let id: uuid::Uuid = row.get("id");
+    let post_id: Option<uuid::Uuid> = row.get("post_id");
+    let author_id: uuid::Uuid = row.get("author_id");
+    let saved_at: DateTime<Utc> = row.get("saved_at");
+
+    DraftDTO {
+        id: id.to_string(),
+        post_id: post_id.map(|id| id.to_string()),
+        author_id: author_id.to_string(),
+        title: row.get("title"),
+        body: row.get("body"),
+        summary: row.get("summary"),
+        structured_data: row
+            .get::<_, Option<String>>("structured_data")
+            .and_then(|data| serde_json::from_str(&data).ok()),
+        saved_at: saved_at.to_string(),
+    }
+}
+
+pub fn draft_forbidden() -> HttpResponse {
+    HttpResponse::Forbidden().json(error_construct(
+        String::from("draft"),
+        String::from("forbidden"),
+        String::from("Apenas o autor pode acessar este rascunho."),
+        None,
+        None,
+        None,
+    ))
+}
+
+pub async fn put_draft_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    draft_id: uuid::Uuid,
+    post_id: Option<uuid::Uuid>,
+    author_id: uuid::Uuid,
+    body: PutDraftDTO,
+) -> Result<DraftDTO, HttpResponse> {
+    let structured_data = body
+        .structured_data
+        .filter(|data| !data.is_null())
+        .map(|data| data.to_string());
+    let rows = match query_executor(
+        pg_pool,
+        PUT_DRAFT_SQL,
+        &[
+            &draft_id,
+            &post_id,
+            &author_id,
+            &body.title,
+            &body.body,
+            &body.summary,
+            &structured_data,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(draft_forbidden());
+    }
+    Ok(draft_dto_constructor(&rows[0]))
+}
+
+pub async fn detail_draft_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    draft_id: uuid::Uuid,
+) -> Result<DraftDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, DETAIL_DRAFT_SQL, &[&draft_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("draft"),
+            String::from("not found"),
+            String::from("Não foi encontrado um rascunho com este id."),
+            Some(draft_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(draft_dto_constructor(&rows[0]))
+}
+
+/// Publishes the draft as a new post or over its existing post, with the language detected from
+/// its title and body. The replaced title and body of an existing post are kept in
+/// `post_revisions`.
+pub async fn publish_draft_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    draft: DraftDTO,
+    author_id: uuid::Uuid,
+) -> Result<String, HttpResponse> {
+    let draft_id = uuid::Uuid::parse_str(&draft.id).unwrap_or_default();
+    let (sql, post_id) = match draft.post_id {
+        Some(post_id) => (
+            PUBLISH_EXISTING_DRAFT_SQL,
+            uuid::Uuid::parse_str(&post_id).unwrap_or_default(),
+        ),
+        None => (PUBLISH_NEW_DRAFT_SQL, uuid::Uuid::new_v4()),
+    };
+    let slug = format!(
+        "{}-{}",
+        slugify(&draft.title),
+        &post_id.simple().to_string()[..8]
+    );
+    let summary = match draft.summary {
+        Some(summary) => summary,
+        None => generate_summary(&draft.body),
+    };
+    let language = detect_language_provider(&format!("{} {}", draft.title, draft.body));
+    let (word_count, character_count) = count_words_and_characters(&draft.body);
+    let code_languages = extract_code_languages(&draft.body);
+    let structured_data = draft.structured_data.map(|data| data.to_string());
+    let rows = match query_executor(
+        pg_pool,
+        sql,
+        &[
+            &draft_id,
+            &post_id,
+            &draft.title,
+            &draft.body,
+            &author_id,
+            &slug,
+            &summary,
+            &language,
+            &word_count,
+            &character_count,
+            &code_languages,
+            &structured_data,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(post_not_found(post_id));
+    }
+    Ok(post_id.to_string())
+}
+
+pub async fn author_post_exists_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    author_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    let rows = match query_executor(pg_pool, AUTHOR_POST_EXISTS_SQL, &[&post_id, &author_id]).await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(post_not_found(post_id));
+    }
+    Ok(())
+}
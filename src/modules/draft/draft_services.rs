@@ -0,0 +1,112 @@
+use super::{
+    draft_dtos::{DraftDTO, PromotedDraftDTO, PublishDraftDTO, PutDraftDTO},
+    draft_repositories::*,
+};
+use crate::{
+    middlewares::uuid_path_middleware::uuid_path_middleware,
+    modules::post::{
+        post_providers::lint_markdown_provider, post_services::duplicate_post_service,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use validator::Validate;
+
+fn parse_uuid(id: String, field: &str) -> Result<uuid::Uuid, HttpResponse> {
+    match uuid_path_middleware(id, field) {
+        Ok(id) => Ok(uuid::Uuid::parse_str(&id).unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn put_draft_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    draft_id: String,
+    author_id: String,
+    body: PutDraftDTO,
+) -> Result<DraftDTO, HttpResponse> {
+    let draft_id = match parse_uuid(draft_id, "draft_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let author_id = match parse_uuid(author_id, "bearer token") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let post_id = match body.post_id.clone() {
+        Some(post_id) => match parse_uuid(post_id, "post_id") {
+            Ok(post_id) => Some(post_id),
+            Err(e) => return Err(e),
+        },
+        None => None,
+    };
+    if let Some(post_id) = post_id {
+        match author_post_exists_repository(pg_pool.clone(), post_id, author_id).await {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+    }
+
+    put_draft_repository(pg_pool, draft_id, post_id, author_id, body).await
+}
+
+pub async fn detail_draft_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    draft_id: String,
+    author_id: String,
+) -> Result<DraftDTO, HttpResponse> {
+    let draft_id = match parse_uuid(draft_id, "draft_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let draft = match detail_draft_repository(pg_pool, draft_id).await {
+        Ok(draft) => draft,
+        Err(e) => return Err(e),
+    };
+
+    if draft.author_id != author_id {
+        return Err(draft_forbidden());
+    }
+    Ok(draft)
+}
+
+pub async fn promote_draft_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    draft_id: String,
+    author_id: String,
+) -> Result<PromotedDraftDTO, HttpResponse> {
+    let draft = match detail_draft_service(pg_pool.clone(), draft_id, author_id.clone()).await {
+        Ok(draft) => draft,
+        Err(e) => return Err(e),
+    };
+    let publish = PublishDraftDTO {
+        title: draft.title.clone(),
+        body: draft.body.clone(),
+    };
+    match publish.validate() {
+        Ok(_) => (),
+        Err(e) => return Err(HttpResponse::BadRequest().json(e)),
+    };
+
+    let author_id = uuid::Uuid::parse_str(&author_id).unwrap_or_default();
+    let post_id = draft
+        .post_id
+        .as_deref()
+        .map(|id| uuid::Uuid::parse_str(id).unwrap_or_default());
+    match duplicate_post_service(
+        pg_pool.clone(),
+        author_id,
+        &draft.title,
+        &draft.body,
+        post_id,
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let warnings = lint_markdown_provider(&draft.body);
+    match publish_draft_repository(pg_pool, draft, author_id).await {
+        Ok(id) => Ok(PromotedDraftDTO { id, warnings }),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,4 @@
+pub mod draft_controllers;
+pub mod draft_dtos;
+pub mod draft_repositories;
+pub mod draft_services;
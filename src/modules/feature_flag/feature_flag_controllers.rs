@@ -0,0 +1,168 @@
+use super::{
+    feature_flag_dtos::{PatchFeatureFlagDTO, SharedFeatureFlags},
+    feature_flag_services::{list_feature_flags_service, patch_feature_flag_service},
+};
+use crate::middlewares::admin_middleware::admin_middleware;
+use actix_web::{get, patch, web, HttpRequest, HttpResponse, Responder};
+
+pub fn feature_flag_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_feature_flags).service(patch_feature_flag);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/flags",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Todas as feature flags cadastradas (OK)",
+        body = Vec<FeatureFlagDTO>, content_type = "application/json", example = json ! ([{
+            "name": "invite_codes",
+            "enabled": true,
+            "updatedAt": "2024-06-18 22:03:54.053147 UTC"
+        }])
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/flags")]
+async fn list_feature_flags(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match list_feature_flags_service(pg_pool).await {
+        Ok(flags) => HttpResponse::Ok().json(flags),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/flags/{name}",
+    security(("bearer_auth" = [])),
+    request_body = PatchFeatureFlagDTO,
+    responses((
+        status = 200, description = "Feature flag atualizada, aplicada pelas outras instâncias em até um minuto (OK)",
+        body = FeatureFlagDTO, content_type = "application/json", example = json ! ({
+            "name": "invite_codes",
+            "enabled": false,
+            "updatedAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Feature flag não encontrada (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "feature": [{
+                "code": "not found",
+                "message": "Não foi encontrada uma feature flag com este nome.",
+                "params": {
+                    "min": null,
+                    "value": "newsletter",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("/flags/{name}")]
+async fn patch_feature_flag(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    feature_flags: web::Data<SharedFeatureFlags>,
+    body: web::Json<PatchFeatureFlagDTO>,
+    name: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match patch_feature_flag_service(
+        pg_pool,
+        &feature_flags,
+        name.into_inner(),
+        body.into_inner().enabled,
+    )
+    .await
+    {
+        Ok(flag) => HttpResponse::Ok().json(flag),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use utoipa::ToSchema;
+
+pub const INVITE_CODES_FLAG: &str = "invite_codes";
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureFlagDTO {
+    pub name: String,
+    pub enabled: bool,
+    pub updated_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+pub struct PatchFeatureFlagDTO {
+    pub enabled: bool,
+}
+
+/// The in-memory copy of the `feature_flags` table, read by the `feature_enabled!` macro.
+#[derive(Clone, Debug, Default)]
+pub struct FeatureFlags {
+    flags: HashMap<String, bool>,
+}
+
+/// The feature flags shared by the workers and refreshed by `feature_flags_refresh_job`.
+pub type SharedFeatureFlags = Arc<RwLock<FeatureFlags>>;
+
+impl FeatureFlags {
+    pub fn from_flags(flags: &[FeatureFlagDTO]) -> Self {
+        FeatureFlags {
+            flags: flags
+                .iter()
+                .map(|flag| (flag.name.clone(), flag.enabled))
+                .collect(),
+        }
+    }
+
+    /// Whether the feature is enabled; features without a flag are always enabled.
+    pub fn enabled(&self, name: &str) -> bool {
+        self.flags.get(name).copied().unwrap_or(true)
+    }
+
+    pub fn set(&mut self, name: &str, enabled: bool) {
+        self.flags.insert(String::from(name), enabled);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::utils::error_construct::error_construct;
+use actix_web::HttpResponse;
+
+pub fn feature_disabled(name: &str) -> HttpResponse {
+    HttpResponse::NotImplemented().json(error_construct(
+        String::from("feature"),
+        String::from("not implemented"),
+        String::from("Este recurso está desativado no momento."),
+        Some(String::from(name)),
+        None,
+        None,
+    ))
+}
+
+/// Returns a `501 Not Implemented` from the handler when the feature is disabled.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::{
+///     feature_enabled,
+///     modules::feature_flag::feature_flag_dtos::SharedFeatureFlags,
+/// };
+/// use actix_web::{web, HttpResponse};
+///
+/// async fn example(flags: web::Data<SharedFeatureFlags>) -> HttpResponse {
+///     feature_enabled!(flags, "invite_codes");
+///     HttpResponse::Ok().finish()
+/// }
+/// ```
+#[macro_export]
+macro_rules! feature_enabled {
+    ($flags:expr, $name:expr) => {
+        let enabled = match $flags.read() {
+            Ok(flags) => flags.enabled($name),
+            Err(_) => true,
+        };
+        if !enabled {
+            return $crate::modules::feature_flag::feature_flag_providers::feature_disabled($name);
+        }
+    };
+}
@@ -0,0 +1,35 @@
+use super::{
+    feature_flag_dtos::SharedFeatureFlags, feature_flag_services::load_feature_flags_service,
+};
+use actix_web::{web::Data, HttpResponse};
+use deadpool_postgres::Pool;
+use std::time::Duration;
+
+const REFRESH_INTERVAL_SECS: u64 = 60;
+
+/// Replaces the shared flags with the ones currently stored in Postgres.
+pub async fn refresh_feature_flags(
+    pg_pool: Pool,
+    flags: &SharedFeatureFlags,
+) -> Result<(), HttpResponse> {
+    let loaded = match load_feature_flags_service(Data::new(pg_pool)).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if let Ok(mut flags) = flags.write() {
+        *flags = loaded;
+    }
+    Ok(())
+}
+
+/// Re-reads the feature flags every minute, so the toggles made on other instances reach this one.
+pub async fn feature_flags_refresh_job(pool_async: Pool, flags: SharedFeatureFlags) {
+    let mut interval = tokio::time::interval(Duration::from_secs(REFRESH_INTERVAL_SECS));
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        if let Err(e) = refresh_feature_flags(pool_async.clone(), &flags).await {
+            log::warn!("Failed to refresh the feature flags: status {}", e.status());
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use super::feature_flag_dtos::FeatureFlagDTO;
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+const LIST_FEATURE_FLAGS_SQL: &str =
+    "SELECT name, enabled, updated_at FROM feature_flags ORDER BY name";
+
+const PATCH_FEATURE_FLAG_SQL: &str = "
+    UPDATE feature_flags SET enabled = $2, updated_at = NOW()
+    WHERE name = $1
+    RETURNING name, enabled, updated_at
+";
+
+fn feature_flag_dto_constructor(row: &Row) -> FeatureFlagDTO {
+    let updated_at: DateTime<Utc> = row.get("updated_at");
+    FeatureFlagDTO {
+        name: row.get("name"),
+        enabled: row.get("enabled"),
+        updated_at: updated_at.to_string(),
+    }
+}
+
+pub async fn list_feature_flags_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<FeatureFlagDTO>, HttpResponse> {
+    match query_executor(pg_pool, LIST_FEATURE_FLAGS_SQL, &[]).await {
+        Ok(rows) => Ok(rows.iter().map(feature_flag_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn patch_feature_flag_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    name: String,
+    enabled: bool,
+) -> Result<FeatureFlagDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, PATCH_FEATURE_FLAG_SQL, &[&name, &enabled]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    match rows.first() {
+        Some(row) => Ok(feature_flag_dto_constructor(row)),
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("feature"),
+            String::from("not found"),
+            String::from("Não foi encontrada uma feature flag com este nome."),
+            Some(name),
+            None,
+            None,
+        ))),
+    }
+}
@@ -0,0 +1,39 @@
+use super::{
+    feature_flag_dtos::{FeatureFlagDTO, FeatureFlags, SharedFeatureFlags},
+    feature_flag_repositories::*,
+};
+use actix_web::{web::Data, HttpResponse};
+
+/// Reads all the flags from Postgres into a new `FeatureFlags`.
+pub async fn load_feature_flags_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<FeatureFlags, HttpResponse> {
+    match list_feature_flags_repository(pg_pool).await {
+        Ok(flags) => Ok(FeatureFlags::from_flags(&flags)),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn list_feature_flags_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<FeatureFlagDTO>, HttpResponse> {
+    list_feature_flags_repository(pg_pool).await
+}
+
+/// Toggles the flag in Postgres and in the flags of this worker; the other instances pick it up on
+/// their next refresh.
+pub async fn patch_feature_flag_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    flags: &SharedFeatureFlags,
+    name: String,
+    enabled: bool,
+) -> Result<FeatureFlagDTO, HttpResponse> {
+    let flag = match patch_feature_flag_repository(pg_pool, name, enabled).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if let Ok(mut flags) = flags.write() {
+        flags.set(&flag.name, flag.enabled);
+    }
+    Ok(flag)
+}
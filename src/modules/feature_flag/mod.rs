@@ -0,0 +1,6 @@
+pub mod feature_flag_controllers;
+pub mod feature_flag_dtos;
+pub mod feature_flag_providers;
+pub mod feature_flag_queues;
+pub mod feature_flag_repositories;
+pub mod feature_flag_services;
@@ -0,0 +1,85 @@
+use super::feed_services::{rss_feed_service, sitemap_service};
+use crate::middlewares::tenant_middleware::tenant_middleware;
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+
+pub fn feed_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(rss_feed).service(sitemap);
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/feed.rss",
+    responses((
+        status = 200, description = "Feed RSS 2.0 com os últimos 20 posts publicados (OK)",
+        content_type = "application/rss+xml; charset=utf-8"
+    ), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/feed.rss")]
+async fn rss_feed(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match rss_feed_service(pg_pool, redis_pool, tenant_id).await {
+        Ok(xml) => HttpResponse::Ok()
+            .content_type("application/rss+xml; charset=utf-8")
+            .body(xml),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/sitemap.xml",
+    responses((
+        status = 200, description = "Sitemap com a página inicial, a listagem e os posts publicados (OK)",
+        content_type = "application/xml; charset=utf-8"
+    ), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/sitemap.xml")]
+async fn sitemap(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match sitemap_service(pg_pool, redis_pool, tenant_id).await {
+        Ok(xml) => HttpResponse::Ok()
+            .content_type("application/xml; charset=utf-8")
+            .body(xml),
+        Err(e) => e,
+    }
+}
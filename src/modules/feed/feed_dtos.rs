@@ -0,0 +1,13 @@
+#[derive(Clone)]
+pub struct RssItemDTO {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub published_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Clone)]
+pub struct SitemapUrlDTO {
+    pub slug: String,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,62 @@
+use super::feed_dtos::{RssItemDTO, SitemapUrlDTO};
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+
+const RSS_FEED_SQL: &str = "
+    SELECT id, title, subtitle, published_at FROM posts
+    WHERE published_at IS NOT NULL AND published_at <= NOW() AND deleted_at IS NULL
+        AND tenant_id = $2
+    ORDER BY published_at DESC, id DESC
+    LIMIT $1
+";
+
+const SITEMAP_SQL: &str = "
+    SELECT COALESCE(slug, id::TEXT) AS slug, COALESCE(updated_at, published_at) AS updated_at
+    FROM posts
+    WHERE published_at IS NOT NULL AND published_at <= NOW() AND deleted_at IS NULL
+        AND tenant_id = $1
+    ORDER BY published_at DESC
+    LIMIT 50000
+";
+
+pub async fn rss_feed_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    limit: i64,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<RssItemDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, RSS_FEED_SQL, &[&limit, &tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let id: uuid::Uuid = row.get("id");
+            RssItemDTO {
+                id: id.to_string(),
+                title: row.get("title"),
+                description: row.get("subtitle"),
+                published_at: row.get("published_at"),
+            }
+        })
+        .collect())
+}
+
+pub async fn sitemap_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<SitemapUrlDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, SITEMAP_SQL, &[&tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| SitemapUrlDTO {
+            slug: row.get("slug"),
+            updated_at: row.get("updated_at"),
+        })
+        .collect())
+}
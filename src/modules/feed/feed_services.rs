@@ -0,0 +1,129 @@
+use super::{
+    feed_dtos::{RssItemDTO, SitemapUrlDTO},
+    feed_repositories::{rss_feed_repository, sitemap_repository},
+};
+use crate::infra::redis::Redis;
+use actix_web::{web::Data, HttpResponse};
+use std::env;
+
+const RSS_FEED_KEY: &str = "feed:rss";
+const RSS_FEED_EXPIRATION_SECONDS: u64 = 300;
+const RSS_FEED_LIMIT: i64 = 20;
+const SITEMAP_KEY: &str = "sitemap:xml";
+const SITEMAP_EXPIRATION_SECONDS: u64 = 3600;
+
+/// Escapes the characters with a special meaning in XML and HTML text or attribute values.
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Builds an RSS 2.0 document with one `<item>` per post, linking to `{BASE_URL}:{HTTP_PORT}`.
+pub fn rss_feed_xml(items: &[RssItemDTO]) -> String {
+    let site_url = format!(
+        "{}:{}",
+        env::var("BASE_URL").unwrap_or_default(),
+        env::var("HTTP_PORT").unwrap_or_default()
+    );
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>\n",
+    );
+    xml.push_str("<title>Navarro Blog</title>\n");
+    xml.push_str(&format!("<link>{}</link>\n", escape_xml(&site_url)));
+    xml.push_str("<description>Últimos posts do Navarro Blog</description>\n");
+    for item in items {
+        let link = escape_xml(&format!("{}/post/{}", site_url, item.id));
+        xml.push_str(&format!(
+            "<item>\n<title>{}</title>\n<link>{}</link>\n<description>{}</description>\n<pubDate>{}</pubDate>\n<guid isPermaLink=\"false\">{}</guid>\n</item>\n",
+            escape_xml(&item.title),
+            link,
+            escape_xml(&item.description),
+            item.published_at.to_rfc2822(),
+            item.id
+        ));
+    }
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+pub async fn rss_feed_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    tenant_id: String,
+) -> Result<String, HttpResponse> {
+    let rss_feed_key = format!("{}:{}", RSS_FEED_KEY, tenant_id);
+    if let Ok(xml) = Redis::get(&redis_pool, &rss_feed_key).await {
+        return Ok(xml);
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let items = match rss_feed_repository(pg_pool, RSS_FEED_LIMIT, tenant_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let xml = rss_feed_xml(&items);
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &rss_feed_key,
+        &xml,
+        RSS_FEED_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(xml)
+}
+
+/// Builds a sitemaps.org `<urlset>` with the homepage, the post listing and one `<url>` per post,
+/// using absolute URLs under `SITE_BASE_URL`.
+pub fn sitemap_xml(urls: &[SitemapUrlDTO]) -> String {
+    let site_url = env::var("SITE_BASE_URL").unwrap_or_default();
+    let site_url = site_url.trim_end_matches('/');
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for path in ["/", "/post"] {
+        xml.push_str(&format!(
+            "<url>\n<loc>{}{}</loc>\n</url>\n",
+            escape_xml(site_url),
+            path
+        ));
+    }
+    for url in urls {
+        xml.push_str(&format!(
+            "<url>\n<loc>{}/post/{}</loc>\n<lastmod>{}</lastmod>\n</url>\n",
+            escape_xml(site_url),
+            escape_xml(&url.slug),
+            url.updated_at
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        ));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+pub async fn sitemap_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    tenant_id: String,
+) -> Result<String, HttpResponse> {
+    let sitemap_key = format!("{}:{}", SITEMAP_KEY, tenant_id);
+    if let Ok(xml) = Redis::get(&redis_pool, &sitemap_key).await {
+        return Ok(xml);
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let urls = match sitemap_repository(pg_pool, tenant_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let xml = sitemap_xml(&urls);
+    let _ = Redis::set_ex(&redis_pool, &sitemap_key, &xml, SITEMAP_EXPIRATION_SECONDS).await;
+    Ok(xml)
+}
+
+/// Drops the cached sitemap of every tenant, called whenever a post is published or removed.
+pub async fn invalidate_sitemap_service(redis_pool: &deadpool_redis::Pool) {
+    let _ = Redis::delete_by_pattern(redis_pool, &format!("{}:*", SITEMAP_KEY)).await;
+}
@@ -0,0 +1,4 @@
+pub mod feed_controllers;
+pub mod feed_dtos;
+pub mod feed_repositories;
+pub mod feed_services;
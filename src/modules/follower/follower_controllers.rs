@@ -0,0 +1,503 @@
+use super::follower_services::*;
+use crate::{
+    middlewares::{
+        auth_middleware::auth_middleware, jwt_token_middleware::jwt_token_middleware,
+        uuid_path_middleware::uuid_path_middleware,
+    },
+    shared::structs::cursor_params::CursorParams,
+};
+use actix_web::{delete, get, post, web, HttpRequest, HttpResponse, Responder};
+
+pub fn follower_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(follow_user)
+        .service(unfollow_user)
+        .service(list_followers)
+        .service(list_following)
+        .service(feed)
+        .service(activity_feed);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/follow",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Usuário seguido (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido ou ao seguir a si mesmo (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Não é possível seguir a si mesmo.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "06b0ff3f-637d-4c15-a02c-c8247ffb9400",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{user_id}/follow")]
+async fn follow_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match follow_user_service(pg_pool, redis_pool, token.claims.sub, user_id).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/follow",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Usuário deixou de ser seguido (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido ou ao seguir a si mesmo (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Não é possível seguir a si mesmo.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("{user_id}/follow")]
+async fn unfollow_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match unfollow_user_service(pg_pool, redis_pool, token.claims.sub, user_id).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/followers",
+    security(("bearer_auth" = [])),
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Seguidores do usuário (OK)", content_type = "application/json",
+        example = json ! ({
+            "data": [{
+                "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                "name": "borrow lightning",
+                "followedAt": "2024-06-18 22:03:54.053147 UTC"
+            }],
+            "nextCursor": "MTcxODc1OTAzNDA1MzE0N19mNWQ0NmIxYi02YWRiLTQwYWMtODJkNi1iMDAwNmNmNzgxYzB8OWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZQ"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id ou cursor inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/followers")]
+async fn list_followers(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match jwt_token_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match list_followers_service(pg_pool, user_id, query_params.into_inner()).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/following",
+    security(("bearer_auth" = [])),
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Usuários seguidos pelo usuário (OK)", content_type = "application/json",
+        example = json ! ({
+            "data": [{
+                "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                "name": "borrow lightning",
+                "followedAt": "2024-06-18 22:03:54.053147 UTC"
+            }],
+            "nextCursor": "MTcxODc1OTAzNDA1MzE0N19mNWQ0NmIxYi02YWRiLTQwYWMtODJkNi1iMDAwNmNmNzgxYzB8OWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZTljMWU5YzFlOWMxZQ"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id ou cursor inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/following")]
+async fn list_following(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match jwt_token_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match list_following_service(pg_pool, user_id, query_params.into_inner()).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/feed",
+    security(("bearer_auth" = [])),
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Posts publicados pelos usuários seguidos (OK)", content_type = "application/json",
+        example = json ! ({
+            "data": [{
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "authorId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC"
+            }],
+            "nextCursor": null
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id ou cursor inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/feed")]
+async fn feed(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match feed_service(pg_pool, user_id.into_inner(), query_params.into_inner()).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/activity-feed",
+    security(("bearer_auth" = [])),
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Atividade dos usuários seguidos, da mais recente à mais antiga: posts publicados nos últimos 30 dias (type post) e comentários dos últimos 7 dias (type comment); a primeira página fica em cache por 120 segundos (OK)",
+        content_type = "application/json", example = json ! ({
+            "data": [{
+                "type": "comment",
+                "id": "5b8f2c1e-7a3d-4e9b-9c1a-2f6d8e4b7a10",
+                "actorId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "postTitle": "Rust e Actix Web",
+                "postSlug": "rust-e-actix-web-0a6e8ac0",
+                "preview": "Ótimo post, vale a leitura!",
+                "eventTime": "2024-06-19 10:12:31.274019 UTC"
+            }, {
+                "type": "post",
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "actorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "postTitle": "Rust e Actix Web",
+                "postSlug": "rust-e-actix-web-0a6e8ac0",
+                "preview": "Criando APIs performáticas",
+                "eventTime": "2024-06-18 22:03:54.053147 UTC"
+            }],
+            "nextCursor": null
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id ou cursor inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/activity-feed")]
+async fn activity_feed(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match activity_feed_service(
+        pg_pool,
+        &redis_pool,
+        user_id.into_inner(),
+        query_params.into_inner(),
+    )
+    .await
+    {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowerDTO {
+    pub id: String,
+    pub name: String,
+    pub followed_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedPostDTO {
+    pub id: String,
+    pub title: String,
+    pub subtitle: String,
+    pub author_id: String,
+    pub created_at: String,
+}
+
+/// An entry of the activity feed: a post published or a comment written by a followed user.
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityEventDTO {
+    /// `post` or `comment`.
+    #[serde(rename = "type")]
+    pub event_type: String,
+    /// The id of the post or of the comment.
+    pub id: String,
+    /// The followed user who published the post or wrote the comment.
+    pub actor_id: String,
+    pub post_id: String,
+    pub post_title: String,
+    pub post_slug: Option<String>,
+    /// The subtitle of the post or the first characters of the comment.
+    pub preview: String,
+    /// When the post was published or the comment was written.
+    pub event_time: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowCountsDTO {
+    pub follower_count: i64,
+    pub following_count: i64,
+}
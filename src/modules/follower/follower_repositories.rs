@@ -0,0 +1,302 @@
+use super::follower_dtos::{ActivityEventDTO, FeedPostDTO, FollowCountsDTO, FollowerDTO};
+use crate::{
+    shared::{
+        parsers::preview_parser::{truncated_preview, PREVIEW_MAX_CHARS},
+        structs::cursor_params::CursorPage,
+    },
+    utils::{
+        cursor::{encode_cursor, CursorPosition},
+        error_construct::error_construct,
+        query_constructor_executor::query_executor,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const FOLLOW_USER_SQL: &str = "
+    WITH followee AS (
+        SELECT id FROM users
+        WHERE id = $2 AND tenant_id = (SELECT tenant_id FROM users WHERE id = $1)
+    ), inserted AS (
+        INSERT INTO user_follows (follower_id, followee_id, created_at)
+        SELECT $1, id, NOW() FROM followee
+        ON CONFLICT DO NOTHING
+    )
+    SELECT id FROM followee
+";
+
+const UNFOLLOW_USER_SQL: &str =
+    "DELETE FROM user_follows WHERE follower_id = $1 AND followee_id = $2";
+
+const FOLLOWERS_SQL: &str = "
+    SELECT u.id, u.name, uf.created_at FROM user_follows uf
+    INNER JOIN users u ON u.id = uf.follower_id
+    WHERE uf.followee_id = $1
+        AND ($2::TIMESTAMPTZ IS NULL OR (uf.created_at, u.id) < ($2, $3))
+    ORDER BY uf.created_at DESC, u.id DESC
+    LIMIT $4
+";
+
+const FOLLOWING_SQL: &str = "
+    SELECT u.id, u.name, uf.created_at FROM user_follows uf
+    INNER JOIN users u ON u.id = uf.followee_id
+    WHERE uf.follower_id = $1
+        AND ($2::TIMESTAMPTZ IS NULL OR (uf.created_at, u.id) < ($2, $3))
+    ORDER BY uf.created_at DESC, u.id DESC
+    LIMIT $4
+";
+
+const FEED_SQL: &str = "
+    SELECT p.id, p.title, p.subtitle, p.author_id, p.created_at FROM posts p
+    INNER JOIN user_follows uf ON uf.followee_id = p.author_id
+    WHERE uf.follower_id = $1
+        AND p.published_at IS NOT NULL AND p.published_at <= NOW() AND p.deleted_at IS NULL
+        AND p.tenant_id = (SELECT tenant_id FROM users WHERE id = $1)
+        AND ($2::TIMESTAMPTZ IS NULL OR (p.created_at, p.id) < ($2, $3))
+    ORDER BY p.created_at DESC, p.id DESC
+    LIMIT $4
+";
+
+const ACTIVITY_FEED_SQL: &str = "
+    SELECT * FROM (
+        SELECT 'post' AS event_type, p.id, p.author_id AS actor_id, p.id AS post_id,
+            p.title AS post_title, p.slug AS post_slug, p.subtitle AS preview,
+            p.published_at AS event_time
+        FROM posts p
+        INNER JOIN user_follows uf ON uf.followee_id = p.author_id
+        WHERE uf.follower_id = $1
+            AND p.published_at > NOW() - make_interval(days => $2) AND p.published_at <= NOW()
+            AND p.deleted_at IS NULL AND p.tenant_id = (SELECT tenant_id FROM users WHERE id = $1)
+        UNION ALL
+        SELECT 'comment', c.id, c.user_id, p.id, p.title, p.slug, c.body, c.created_at
+        FROM comments c
+        INNER JOIN user_follows uf ON uf.followee_id = c.user_id
+        INNER JOIN posts p ON p.id = c.post_id
+        WHERE uf.follower_id = $1 AND c.created_at > NOW() - make_interval(days => $3)
+            AND c.deleted_at IS NULL AND c.approved_at IS NOT NULL
+            AND p.published_at IS NOT NULL AND p.published_at <= NOW() AND p.deleted_at IS NULL
+            AND p.tenant_id = (SELECT tenant_id FROM users WHERE id = $1)
+    ) events
+    WHERE $4::TIMESTAMPTZ IS NULL OR (event_time, id) < ($4, $5)
+    ORDER BY event_time DESC, id DESC
+    LIMIT $6
+";
+
+const POST_AUTHOR_FOLLOWERS_SQL: &str = "
+    SELECT uf.follower_id FROM user_follows uf
+    INNER JOIN posts p ON p.author_id = uf.followee_id
+    WHERE p.id = $1
+";
+
+const FOLLOW_COUNTS_SQL: &str = "
+    SELECT
+        (SELECT COUNT(*) FROM user_follows WHERE followee_id = $1) AS follower_count,
+        (SELECT COUNT(*) FROM user_follows WHERE follower_id = $1) AS following_count
+";
+
+pub async fn follow_user_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    follower_id: uuid::Uuid,
+    followee_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    let rows = match query_executor(pg_pool, FOLLOW_USER_SQL, &[&follower_id, &followee_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("user"),
+            String::from("not found"),
+            String::from("Não foi encontrado um usuário com este id."),
+            Some(followee_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
+
+pub async fn unfollow_user_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    follower_id: uuid::Uuid,
+    followee_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, UNFOLLOW_USER_SQL, &[&follower_id, &followee_id]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn follows_page_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    sql: &str,
+    user_id: uuid::Uuid,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+) -> Result<CursorPage<FollowerDTO>, HttpResponse> {
+    let (cursor_created_at, cursor_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        sql,
+        &[&user_id, &cursor_created_at, &cursor_id, &(limit + 1)],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<FollowerDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        let id: uuid::Uuid = row.get("id");
+        let created_at: DateTime<Utc> = row.get("created_at");
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(created_at, id));
+        }
+        data.push(FollowerDTO {
+            id: id.to_string(),
+            name: row.get("name"),
+            followed_at: created_at.to_string(),
+        });
+    }
+    Ok(CursorPage { data, next_cursor })
+}
+
+pub async fn list_followers_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+) -> Result<CursorPage<FollowerDTO>, HttpResponse> {
+    follows_page_repository(pg_pool, FOLLOWERS_SQL, user_id, cursor, limit).await
+}
+
+pub async fn list_following_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+) -> Result<CursorPage<FollowerDTO>, HttpResponse> {
+    follows_page_repository(pg_pool, FOLLOWING_SQL, user_id, cursor, limit).await
+}
+
+pub async fn feed_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+) -> Result<CursorPage<FeedPostDTO>, HttpResponse> {
+    let (cursor_created_at, cursor_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        FEED_SQL,
+        &[&user_id, &cursor_created_at, &cursor_id, &(limit + 1)],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<FeedPostDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        let id: uuid::Uuid = row.get("id");
+        let author_id: uuid::Uuid = row.get("author_id");
+        let created_at: DateTime<Utc> = row.get("created_at");
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(created_at, id));
+        }
+        data.push(FeedPostDTO {
+            id: id.to_string(),
+            title: row.get("title"),
+            subtitle: row.get("subtitle"),
+            author_id: author_id.to_string(),
+            created_at: created_at.to_string(),
+        });
+    }
+    Ok(CursorPage { data, next_cursor })
+}
+
+/// Lists the posts published by the users followed by `user_id` in the last `post_days` days and
+/// the comments they wrote in the last `comment_days` days, most recent first, only on the posts
+/// of the tenant of the user.
+pub async fn activity_feed_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+    post_days: i32,
+    comment_days: i32,
+) -> Result<CursorPage<ActivityEventDTO>, HttpResponse> {
+    let (cursor_event_time, cursor_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        ACTIVITY_FEED_SQL,
+        &[
+            &user_id,
+            &post_days,
+            &comment_days,
+            &cursor_event_time,
+            &cursor_id,
+            &(limit + 1),
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<ActivityEventDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        let id: uuid::Uuid = row.get("id");
+        let actor_id: uuid::Uuid = row.get("actor_id");
+        let post_id: uuid::Uuid = row.get("post_id");
+        let event_time: DateTime<Utc> = row.get("event_time");
+        let preview: String = row.get("preview");
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(event_time, id));
+        }
+        data.push(ActivityEventDTO {
+            event_type: row.get("event_type"),
+            id: id.to_string(),
+            actor_id: actor_id.to_string(),
+            post_id: post_id.to_string(),
+            post_title: row.get("post_title"),
+            post_slug: row.get("post_slug"),
+            preview: truncated_preview(&preview, PREVIEW_MAX_CHARS),
+            event_time: event_time.to_string(),
+        });
+    }
+    Ok(CursorPage { data, next_cursor })
+}
+
+/// Returns the ids of the followers of the author of the post.
+pub async fn post_author_followers_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Vec<uuid::Uuid>, HttpResponse> {
+    match query_executor(pg_pool, POST_AUTHOR_FOLLOWERS_SQL, &[&post_id]).await {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get("follower_id")).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn follow_counts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<FollowCountsDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, FOLLOW_COUNTS_SQL, &[&user_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(FollowCountsDTO {
+        follower_count: rows[0].get("follower_count"),
+        following_count: rows[0].get("following_count"),
+    })
+}
@@ -0,0 +1,246 @@
+use super::{
+    follower_dtos::{ActivityEventDTO, FeedPostDTO, FollowCountsDTO, FollowerDTO},
+    follower_repositories::*,
+};
+use crate::{
+    infra::redis::Redis,
+    middlewares::etag_middleware::etag_invalidate,
+    shared::structs::cursor_params::{CursorPage, CursorParams},
+    utils::{cursor::cursor_page_params, error_construct::error_construct},
+};
+use actix_web::{web::Data, HttpResponse};
+
+const FOLLOW_COUNTS_EXPIRATION_SECONDS: u64 = 3600;
+const ACTIVITY_FEED_EXPIRATION_SECONDS: u64 = 120;
+const ACTIVITY_FEED_POST_DAYS: i32 = 30;
+const ACTIVITY_FEED_COMMENT_DAYS: i32 = 7;
+
+fn follow_counts_key(user_id: &str) -> String {
+    format!("follow:counts:{}", user_id)
+}
+
+/// The key of the cached first page of the activity feed of the user, one per page size.
+fn activity_feed_key(user_id: &str, limit: i64) -> String {
+    format!("activity_feed:{}:{}", user_id, limit)
+}
+
+fn activity_feed_pattern(user_id: &str) -> String {
+    format!("activity_feed:{}:*", user_id)
+}
+
+fn self_follow_error(user_id: String) -> HttpResponse {
+    HttpResponse::BadRequest().json(error_construct(
+        String::from("user_id"),
+        String::from("bad request"),
+        String::from("Não é possível seguir a si mesmo."),
+        Some(user_id),
+        None,
+        None,
+    ))
+}
+
+async fn follow_cache_invalidate(
+    redis_pool: &deadpool_redis::Pool,
+    follower_id: &str,
+    followee_id: &str,
+) {
+    for user_id in [follower_id, followee_id] {
+        let _ = Redis::delete(redis_pool, &follow_counts_key(user_id)).await;
+        etag_invalidate(redis_pool, "user", user_id).await;
+    }
+    let _ = Redis::delete_by_pattern(redis_pool, &activity_feed_pattern(follower_id)).await;
+}
+
+pub async fn follow_user_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    follower_id: String,
+    followee_id: String,
+) -> Result<(), HttpResponse> {
+    if follower_id == followee_id {
+        return Err(self_follow_error(followee_id));
+    }
+    match follow_user_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&follower_id).unwrap_or_default(),
+        uuid::Uuid::parse_str(&followee_id).unwrap_or_default(),
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    follow_cache_invalidate(&redis_pool, &follower_id, &followee_id).await;
+    Ok(())
+}
+
+pub async fn unfollow_user_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    follower_id: String,
+    followee_id: String,
+) -> Result<(), HttpResponse> {
+    if follower_id == followee_id {
+        return Err(self_follow_error(followee_id));
+    }
+    match unfollow_user_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&follower_id).unwrap_or_default(),
+        uuid::Uuid::parse_str(&followee_id).unwrap_or_default(),
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    follow_cache_invalidate(&redis_pool, &follower_id, &followee_id).await;
+    Ok(())
+}
+
+pub async fn list_followers_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    params: CursorParams,
+) -> Result<CursorPage<FollowerDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    list_followers_repository(pg_pool, user_id, cursor, limit).await
+}
+
+pub async fn list_following_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    params: CursorParams,
+) -> Result<CursorPage<FollowerDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    list_following_repository(pg_pool, user_id, cursor, limit).await
+}
+
+pub async fn feed_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    params: CursorParams,
+) -> Result<CursorPage<FeedPostDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    feed_repository(pg_pool, user_id, cursor, limit).await
+}
+
+/// Returns a page of the activity of the users followed by `user_id`: the posts they published in
+/// the last `ACTIVITY_FEED_POST_DAYS` days and the comments they wrote in the last
+/// `ACTIVITY_FEED_COMMENT_DAYS` days.
+///
+/// The first page is cached for `ACTIVITY_FEED_EXPIRATION_SECONDS`, and dropped when a followed
+/// user publishes a post or the user follows or unfollows someone.
+pub async fn activity_feed_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    user_id: String,
+    params: CursorParams,
+) -> Result<CursorPage<ActivityEventDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let key = cursor.is_none().then(|| activity_feed_key(&user_id, limit));
+    if let Some(key) = &key {
+        if let Ok(redis_page) = Redis::get(redis_pool, key).await {
+            if let Ok(page) = serde_json::from_str::<CursorPage<ActivityEventDTO>>(&redis_page) {
+                return Ok(page);
+            }
+        }
+    }
+
+    let page = match activity_feed_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&user_id).unwrap_or_default(),
+        cursor,
+        limit,
+        ACTIVITY_FEED_POST_DAYS,
+        ACTIVITY_FEED_COMMENT_DAYS,
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if let (Some(key), Ok(redis_page)) = (key, serde_json::to_string(&page)) {
+        let _ = Redis::set_ex(
+            redis_pool,
+            &key,
+            &redis_page,
+            ACTIVITY_FEED_EXPIRATION_SECONDS,
+        )
+        .await;
+    }
+    Ok(page)
+}
+
+/// Drops the cached activity feeds of the followers of the author of a post that was just
+/// published.
+pub async fn invalidate_activity_feeds_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    post_id: uuid::Uuid,
+) {
+    let follower_ids = match post_author_followers_repository(pg_pool, post_id)
+        .await
+        .map_err(|e| e.status())
+    {
+        Ok(x) => x,
+        Err(status) => {
+            log::warn!(
+                "Failed to invalidate the activity feeds of the followers of post {}: {}",
+                post_id,
+                status
+            );
+            return;
+        }
+    };
+    for follower_id in follower_ids {
+        let _ =
+            Redis::delete_by_pattern(redis_pool, &activity_feed_pattern(&follower_id.to_string()))
+                .await;
+    }
+}
+
+pub async fn follow_counts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    user_id: &str,
+) -> Result<FollowCountsDTO, HttpResponse> {
+    let key = follow_counts_key(user_id);
+    if let Ok(redis_counts) = Redis::get(redis_pool, &key).await {
+        if let Ok(counts) = serde_json::from_str::<FollowCountsDTO>(&redis_counts) {
+            return Ok(counts);
+        }
+    }
+
+    let counts =
+        match follow_counts_repository(pg_pool, uuid::Uuid::parse_str(user_id).unwrap_or_default())
+            .await
+        {
+            Ok(counts) => counts,
+            Err(e) => return Err(e),
+        };
+    if let Ok(redis_counts) = serde_json::to_string(&counts) {
+        let _ = Redis::set_ex(
+            redis_pool,
+            &key,
+            &redis_counts,
+            FOLLOW_COUNTS_EXPIRATION_SECONDS,
+        )
+        .await;
+    }
+    Ok(counts)
+}
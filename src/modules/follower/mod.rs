@@ -0,0 +1,4 @@
+pub mod follower_controllers;
+pub mod follower_dtos;
+pub mod follower_repositories;
+pub mod follower_services;
@@ -0,0 +1,39 @@
+use super::health_services::health_service;
+use actix_web::{get, web, HttpResponse, Responder};
+
+pub fn health_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(health);
+}
+
+#[utoipa::path(
+    tag = "static",
+    path = "/health",
+    responses((
+        status = 200, description = "Postgres (leitura e escrita) e Redis (escrita) disponíveis (OK)", body = HealthDTO,
+        content_type = "application/json", example = json ! ({
+            "postgres_read": "ok",
+            "postgres_write": "ok",
+            "redis_write": "ok"
+        })
+    ), (
+		status = 503, description = "Alguma das verificações falhou (Service Unavailable)", body = HealthDTO,
+		content_type = "application/json", example = json ! ({
+            "postgres_read": "ok",
+            "postgres_write": "fail",
+            "redis_write": "ok"
+        })
+	))
+)]
+#[get("/health")]
+async fn health(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> impl Responder {
+    let health = health_service(pg_pool, redis_pool).await;
+
+    if health.is_healthy() {
+        HttpResponse::Ok().json(health)
+    } else {
+        HttpResponse::ServiceUnavailable().json(health)
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub const HEALTH_OK: &str = "ok";
+pub const HEALTH_FAIL: &str = "fail";
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+pub struct HealthDTO {
+    pub postgres_read: String,
+    pub postgres_write: String,
+    pub redis_write: String,
+}
+
+impl HealthDTO {
+    pub fn is_healthy(&self) -> bool {
+        [&self.postgres_read, &self.postgres_write, &self.redis_write]
+            .iter()
+            .all(|status| *status == HEALTH_OK)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+
+const HEALTH_CHECK_ID: i32 = 1;
+
+const HEALTH_CHECK_READ_SQL: &str = "SELECT 1";
+
+const HEALTH_CHECK_WRITE_SQL: &str = "
+    INSERT INTO health_checks (id, checked_at) VALUES ($1, NOW())
+    ON CONFLICT DO NOTHING
+";
+
+pub async fn health_check_postgres_read(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, HEALTH_CHECK_READ_SQL, &[]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// A read replica answers `SELECT 1` just fine, so the write capability is checked on its own.
+pub async fn health_check_postgres_write(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, HEALTH_CHECK_WRITE_SQL, &[&HEALTH_CHECK_ID]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
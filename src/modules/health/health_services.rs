@@ -0,0 +1,48 @@
+use super::{
+    health_dtos::{HealthDTO, HEALTH_FAIL, HEALTH_OK},
+    health_repositories::{health_check_postgres_read, health_check_postgres_write},
+};
+use actix_web::web::Data;
+use deadpool_redis::redis::cmd;
+
+const HEALTH_CHECK_KEY: &str = "health:check";
+const HEALTH_CHECK_EXPIRATION_SECONDS: u64 = 5;
+
+/// Writes a short-lived key, without panicking when no connection can be taken from the pool.
+pub async fn health_check_redis_write(redis_pool: &deadpool_redis::Pool) -> Result<(), String> {
+    let mut redis_conn = match redis_pool.get().await {
+        Ok(x) => x,
+        Err(e) => return Err(e.to_string()),
+    };
+    cmd("SET")
+        .arg(&[
+            HEALTH_CHECK_KEY,
+            HEALTH_OK,
+            "EX",
+            &HEALTH_CHECK_EXPIRATION_SECONDS.to_string(),
+        ])
+        .query_async::<_, ()>(&mut redis_conn)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+fn health_status(healthy: bool) -> String {
+    if healthy { HEALTH_OK } else { HEALTH_FAIL }.to_string()
+}
+
+pub async fn health_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+) -> HealthDTO {
+    let (postgres_read, postgres_write, redis_write) = tokio::join!(
+        health_check_postgres_read(pg_pool.clone()),
+        health_check_postgres_write(pg_pool),
+        health_check_redis_write(&redis_pool)
+    );
+
+    HealthDTO {
+        postgres_read: health_status(postgres_read.is_ok()),
+        postgres_write: health_status(postgres_write.is_ok()),
+        redis_write: health_status(redis_write.is_ok()),
+    }
+}
@@ -0,0 +1,4 @@
+pub mod health_controllers;
+pub mod health_dtos;
+pub mod health_repositories;
+pub mod health_services;
@@ -0,0 +1,114 @@
+use super::{
+    invite_code_dtos::InsertInviteCodesDTO, invite_code_services::insert_invite_codes_service,
+};
+use crate::{
+    feature_enabled,
+    middlewares::admin_middleware::admin_middleware,
+    modules::feature_flag::feature_flag_dtos::{SharedFeatureFlags, INVITE_CODES_FLAG},
+};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn invite_code_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(insert_invite_codes);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/invite",
+    security(("bearer_auth" = [])),
+    request_body = InsertInviteCodesDTO,
+    responses((
+        status = 201, description = "Códigos de convite gerados para o cadastro no beta fechado (Created)",
+        body = Vec<InviteCodeDTO>, content_type = "application/json", example = json ! ([{
+            "code": "c4a1f7e2-9b3d-4e8a-b6c5-1d2e3f4a5b6c",
+            "expiresAt": "2024-06-25 22:03:54.053147 UTC"
+        }])
+    ), (
+		status = 400, description = "Erro do usuário por quantidade ou validade inválida (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "count": [{
+                "code": "range",
+                "message": "A quantidade de convites deve estar entre 1 e 100.",
+                "params": {
+                    "min": 1.0,
+                    "value": 500,
+                    "max": 100.0
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 501, description = "Os códigos de convite estão desativados (Not Implemented)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "feature": [{
+                "code": "not implemented",
+                "message": "Este recurso está desativado no momento.",
+                "params": {
+                    "min": null,
+                    "value": "invite_codes",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/invite")]
+async fn insert_invite_codes(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    feature_flags: web::Data<SharedFeatureFlags>,
+    body: web::Json<InsertInviteCodesDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match admin_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    feature_enabled!(feature_flags, INVITE_CODES_FLAG);
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match insert_invite_codes_service(pg_pool, token.claims.sub, body.into_inner()).await {
+        Ok(codes) => HttpResponse::Created().json(codes),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertInviteCodesDTO {
+    #[validate(range(
+        min = 1,
+        max = 100,
+        message = "A quantidade de convites deve estar entre 1 e 100."
+    ))]
+    pub count: i64,
+
+    #[validate(range(
+        min = 1,
+        max = 365,
+        message = "A validade do convite deve estar entre 1 e 365 dias."
+    ))]
+    #[serde(default, alias = "expiresInDays")]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteCodeDTO {
+    pub code: String,
+    pub expires_at: String,
+}
@@ -0,0 +1,58 @@
+use super::invite_code_repositories::find_invite_code_repository;
+use crate::utils::error_construct::error_construct;
+use actix_web::{web::Data, HttpResponse};
+use once_cell::sync::Lazy;
+use std::env;
+
+/// Whether new users must register with an invite code, set by the `REQUIRE_INVITE_CODE` env var.
+pub static REQUIRE_INVITE_CODE: Lazy<bool> = Lazy::new(|| {
+    env::var("REQUIRE_INVITE_CODE")
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+});
+
+pub fn invalid_invite_code(code: Option<String>) -> HttpResponse {
+    HttpResponse::BadRequest().json(error_construct(
+        String::from("invite_code"),
+        String::from("bad request"),
+        String::from("O código de convite é inválido ou já foi utilizado."),
+        code,
+        None,
+        None,
+    ))
+}
+
+/// Checks that the invite code exists, was not used and has not expired, returning it parsed.
+///
+/// # Errors
+///
+/// This function may return an error if:
+///
+/// - The code is missing, does not exist or was already used (400).
+/// - The code has expired (410).
+pub async fn validate_invite_code_provider(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    code: Option<String>,
+) -> Result<uuid::Uuid, HttpResponse> {
+    let parsed = match code
+        .as_deref()
+        .map(|code| uuid::Uuid::parse_str(code.trim()))
+    {
+        Some(Ok(parsed)) => parsed,
+        _ => return Err(invalid_invite_code(code)),
+    };
+    match find_invite_code_repository(pg_pool, parsed).await {
+        Ok(Some(state)) if state.used => Err(invalid_invite_code(code)),
+        Ok(Some(state)) if state.expired => Err(HttpResponse::Gone().json(error_construct(
+            String::from("invite_code"),
+            String::from("gone"),
+            String::from("O código de convite expirou."),
+            code,
+            None,
+            None,
+        ))),
+        Ok(Some(_)) => Ok(parsed),
+        Ok(None) => Err(invalid_invite_code(code)),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,87 @@
+use super::invite_code_dtos::InviteCodeDTO;
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const INSERT_INVITE_CODES_SQL: &str = "
+    INSERT INTO invite_codes (code, created_by, expires_at)
+    SELECT code, $2, NOW() + make_interval(days => $3)
+    FROM UNNEST($1::UUID[]) AS code
+    RETURNING code, expires_at
+";
+
+const FIND_INVITE_CODE_SQL: &str = "
+    SELECT used_by IS NOT NULL AS used, expires_at <= NOW() AS expired
+    FROM invite_codes WHERE code = $1
+";
+
+const USE_INVITE_CODE_SQL: &str = "
+    UPDATE invite_codes SET used_by = $2, used_at = NOW()
+    WHERE code = $1 AND used_by IS NULL AND expires_at > NOW()
+    RETURNING code
+";
+
+pub struct InviteCodeState {
+    pub used: bool,
+    pub expired: bool,
+}
+
+pub async fn insert_invite_codes_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    created_by: uuid::Uuid,
+    count: i64,
+    expires_in_days: i32,
+) -> Result<Vec<InviteCodeDTO>, HttpResponse> {
+    let codes: Vec<uuid::Uuid> = (0..count).map(|_| uuid::Uuid::new_v4()).collect();
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_INVITE_CODES_SQL,
+        &[&codes, &created_by, &expires_in_days],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let code: uuid::Uuid = row.get("code");
+            let expires_at: DateTime<Utc> = row.get("expires_at");
+            InviteCodeDTO {
+                code: code.to_string(),
+                expires_at: expires_at.to_string(),
+            }
+        })
+        .collect())
+}
+
+/// Returns whether the invite code was used or expired, or `None` if it does not exist.
+pub async fn find_invite_code_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    code: uuid::Uuid,
+) -> Result<Option<InviteCodeState>, HttpResponse> {
+    let rows = match query_executor(pg_pool, FIND_INVITE_CODE_SQL, &[&code]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.first().map(|row| InviteCodeState {
+        used: row.get("used"),
+        expired: row.get("expired"),
+    }))
+}
+
+/// Marks the invite code as used by `user_id`, returning `false` if it was already used or has
+/// expired in the meantime.
+pub async fn use_invite_code_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    code: uuid::Uuid,
+    user_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(pg_pool, USE_INVITE_CODE_SQL, &[&code, &user_id]).await {
+        Ok(rows) => Ok(!rows.is_empty()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,22 @@
+use super::{
+    invite_code_dtos::{InsertInviteCodesDTO, InviteCodeDTO},
+    invite_code_repositories::insert_invite_codes_repository,
+};
+use actix_web::{web::Data, HttpResponse};
+
+const DEFAULT_EXPIRES_IN_DAYS: i64 = 7;
+
+pub async fn insert_invite_codes_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    admin_id: String,
+    body: InsertInviteCodesDTO,
+) -> Result<Vec<InviteCodeDTO>, HttpResponse> {
+    let expires_in_days = body.expires_in_days.unwrap_or(DEFAULT_EXPIRES_IN_DAYS) as i32;
+    insert_invite_codes_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&admin_id).unwrap_or_default(),
+        body.count,
+        expires_in_days,
+    )
+    .await
+}
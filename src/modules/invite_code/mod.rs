@@ -0,0 +1,5 @@
+pub mod invite_code_controllers;
+pub mod invite_code_dtos;
+pub mod invite_code_providers;
+pub mod invite_code_repositories;
+pub mod invite_code_services;
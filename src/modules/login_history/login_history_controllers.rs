@@ -0,0 +1,179 @@
+use super::{
+    login_history_dtos::LoginHistoryParams,
+    login_history_services::{email_login_history_service, user_login_history_service},
+};
+use crate::middlewares::{admin_middleware::admin_middleware, auth_middleware::auth_middleware};
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn login_history_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(user_login_history);
+}
+
+pub fn login_history_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(email_login_history);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/login-history",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "As últimas 50 tentativas de login do usuário, incluindo as que falharam com o seu e-mail, das mais recentes para as mais antigas (OK)",
+        body = Vec<LoginHistoryDTO>, content_type = "application/json", example = json ! ([{
+            "id": "0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+            "userId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "email": "lightning@gmail.com",
+            "success": true,
+            "ip": "203.0.113.7",
+            "userAgent": "Mozilla/5.0",
+            "failureReason": null,
+            "createdAt": "2024-06-18 22:03:54.053147 UTC"
+        }, {
+            "id": "1f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+            "userId": null,
+            "email": "lightning@gmail.com",
+            "success": false,
+            "ip": "203.0.113.7",
+            "userAgent": "Mozilla/5.0",
+            "failureReason": "invalid credentials",
+            "createdAt": "2024-06-18 22:03:41.053147 UTC"
+        }])
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/login-history")]
+async fn user_login_history(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match user_login_history_service(pg_pool, user_id.into_inner()).await {
+        Ok(history) => HttpResponse::Ok().json(history),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/login-history",
+    security(("bearer_auth" = [])),
+    params(
+        ("email" = String, Query, description = "E-mail usado nas tentativas de login"),
+    ),
+    responses((
+        status = 200, description = "As últimas 50 tentativas de login com o e-mail, das mais recentes para as mais antigas (OK)",
+        body = Vec<LoginHistoryDTO>
+	), (
+		status = 400, description = "Erro do usuário por e-mail inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "email": [{
+                "code": "email",
+                "message": "O e-mail deve ser um endereço válido.",
+                "params": {
+                    "value": "lightning"
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/login-history")]
+async fn email_login_history(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    query_params: web::Query<LoginHistoryParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match query_params.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match email_login_history_service(pg_pool, query_params.into_inner().email).await {
+        Ok(history) => HttpResponse::Ok().json(history),
+        Err(e) => e,
+    }
+}
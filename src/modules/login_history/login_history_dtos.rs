@@ -0,0 +1,46 @@
+use crate::utils::client_ip::client_ip;
+use actix_web::{http::header::USER_AGENT, HttpRequest};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginHistoryDTO {
+    pub id: String,
+    pub user_id: Option<String>,
+    pub email: String,
+    pub success: bool,
+    pub ip: String,
+    pub user_agent: String,
+    pub failure_reason: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate)]
+pub struct LoginHistoryParams {
+    #[validate(email(message = "O e-mail deve ser um endereço válido."))]
+    #[serde(default)]
+    pub email: String,
+}
+
+/// The client that made a login attempt, recorded in its history entry.
+#[derive(Clone, Default)]
+pub struct LoginClient {
+    pub ip: String,
+    pub user_agent: String,
+}
+
+impl LoginClient {
+    pub fn from_request(req: &HttpRequest) -> Self {
+        LoginClient {
+            ip: client_ip(req).map(|ip| ip.to_string()).unwrap_or_default(),
+            user_agent: req
+                .headers()
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
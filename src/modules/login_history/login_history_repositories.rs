@@ -0,0 +1,106 @@
+use super::login_history_dtos::{LoginClient, LoginHistoryDTO};
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+const INSERT_LOGIN_HISTORY_SQL: &str = "
+    INSERT INTO login_history (id, user_id, email, success, ip, user_agent, failure_reason, created_at)
+    VALUES ($1, $2, $3, $4, $5, $6, $7, NOW())
+";
+
+const USER_LOGIN_HISTORY_SQL: &str = "
+    SELECT id, user_id, email, success, ip, user_agent, failure_reason, created_at
+    FROM login_history
+    WHERE user_id = $1 OR email = (SELECT email FROM users WHERE id = $1)
+    ORDER BY created_at DESC
+    LIMIT $2
+";
+
+const EMAIL_LOGIN_HISTORY_SQL: &str = "
+    SELECT id, user_id, email, success, ip, user_agent, failure_reason, created_at
+    FROM login_history
+    WHERE email = $1
+    ORDER BY created_at DESC
+    LIMIT $2
+";
+
+pub const LOGIN_HISTORY_LIMIT: i64 = 50;
+
+fn login_history_dto_constructor(row: &Row) -> LoginHistoryDTO {
+    let id: uuid::Uuid = row.get("id");
+    let user_id: Option<uuid::Uuid> = row.get("user_id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    LoginHistoryDTO {
+        id: id.to_string(),
+        user_id: user_id.map(|id| id.to_string()),
+        email: row.get("email"),
+        success: row.get("success"),
+        ip: row.get("ip"),
+        user_agent: row.get("user_agent"),
+        failure_reason: row.get("failure_reason"),
+        created_at: created_at.to_string(),
+    }
+}
+
+/// Records a login attempt, successful when there is no `failure_reason`.
+pub async fn insert_login_history_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: Option<uuid::Uuid>,
+    email: String,
+    client: &LoginClient,
+    failure_reason: Option<String>,
+) -> Result<(), HttpResponse> {
+    match query_executor(
+        pg_pool,
+        INSERT_LOGIN_HISTORY_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &user_id,
+            &email,
+            &failure_reason.is_none(),
+            &client.ip,
+            &client.user_agent,
+            &failure_reason,
+        ],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the latest login attempts of the user, including the failed ones made with their e-mail.
+pub async fn user_login_history_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Vec<LoginHistoryDTO>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        USER_LOGIN_HISTORY_SQL,
+        &[&user_id, &LOGIN_HISTORY_LIMIT],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.iter().map(login_history_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the latest login attempts made with the e-mail.
+pub async fn email_login_history_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    email: String,
+) -> Result<Vec<LoginHistoryDTO>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        EMAIL_LOGIN_HISTORY_SQL,
+        &[&email, &LOGIN_HISTORY_LIMIT],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.iter().map(login_history_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
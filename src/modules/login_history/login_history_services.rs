@@ -0,0 +1,58 @@
+use super::{
+    login_history_dtos::{LoginClient, LoginHistoryDTO},
+    login_history_repositories::*,
+};
+use actix_web::{http::StatusCode, web::Data, HttpResponse};
+
+/// Describes why a login attempt failed, from the error response of the login.
+pub fn login_failure_reason(error: &HttpResponse) -> String {
+    match error.status() {
+        StatusCode::UNAUTHORIZED => String::from("invalid credentials"),
+        StatusCode::FORBIDDEN => String::from("account suspended"),
+        StatusCode::NOT_FOUND => String::from("user not found"),
+        status => status
+            .canonical_reason()
+            .unwrap_or("unknown")
+            .to_lowercase(),
+    }
+}
+
+/// Records the result of a login attempt, where a successful one carries the id of the user.
+///
+/// A failure to record it is only logged, never failing the login itself.
+pub async fn record_login_attempt_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    email: String,
+    client: &LoginClient,
+    attempt: Result<&str, &HttpResponse>,
+) {
+    let (user_id, failure_reason) = match attempt {
+        Ok(user_id) => (uuid::Uuid::parse_str(user_id).ok(), None),
+        Err(e) => (None, Some(login_failure_reason(e))),
+    };
+    if let Err(e) =
+        insert_login_history_repository(pg_pool, user_id, email.clone(), client, failure_reason)
+            .await
+    {
+        log::warn!(
+            "Failed to record the login attempt of {}: {}",
+            email,
+            e.status()
+        );
+    }
+}
+
+pub async fn user_login_history_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+) -> Result<Vec<LoginHistoryDTO>, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    user_login_history_repository(pg_pool, user_id).await
+}
+
+pub async fn email_login_history_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    email: String,
+) -> Result<Vec<LoginHistoryDTO>, HttpResponse> {
+    email_login_history_repository(pg_pool, email).await
+}
@@ -0,0 +1,4 @@
+pub mod login_history_controllers;
+pub mod login_history_dtos;
+pub mod login_history_repositories;
+pub mod login_history_services;
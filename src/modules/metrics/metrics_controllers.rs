@@ -0,0 +1,113 @@
+use super::metrics_services::render_metrics;
+use crate::{
+    middlewares::admin_middleware::admin_middleware,
+    modules::content_report::content_report_services::content_report_stats_service,
+};
+use actix_web::{
+    get,
+    http::header::{HeaderMap, AUTHORIZATION},
+    web, HttpRequest, HttpResponse, Responder,
+};
+use once_cell::sync::Lazy;
+use std::env;
+
+static METRICS_TOKEN: Lazy<Option<String>> = Lazy::new(|| {
+    env::var("METRICS_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+});
+
+/// Whether the request sends the `METRICS_TOKEN` env var as its bearer token, the credential of
+/// the Prometheus scraper. Always `false` when the env var is not set.
+fn metrics_token_valid(headers: &HeaderMap) -> bool {
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match (token, METRICS_TOKEN.as_deref()) {
+        (Some(token), Some(expected)) => {
+            token.len() == expected.len()
+                && token
+                    .bytes()
+                    .zip(expected.bytes())
+                    .fold(0, |diff, (a, b)| diff | (a ^ b))
+                    == 0
+        }
+        _ => false,
+    }
+}
+
+pub fn metrics_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(metrics);
+}
+
+#[utoipa::path(
+    tag = "static",
+    path = "/metrics",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Métricas no formato de texto do Prometheus, como o gauge moderation_pending_reports (OK)",
+        content_type = "text/plain; version=0.0.4"
+    ), (
+		status = 400, description = "Cabeçalho Authorization não informado (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "bad request",
+                "message": "O valor do cabeçalho 'Authorization' deve ser informado.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "O token não é o da env var METRICS_TOKEN nem um token de autorização válido (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores ou ao token da env var METRICS_TOKEN (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	))
+)]
+#[get("/metrics")]
+async fn metrics(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    if !metrics_token_valid(req.headers()) {
+        match admin_middleware(req.headers()) {
+            Ok(_) => (),
+            Err(e) => return e,
+        };
+    }
+    if let Err(e) = content_report_stats_service(pg_pool, &redis_pool).await {
+        log::warn!(
+            "Failed to refresh the moderation metrics: status {}",
+            e.status()
+        );
+    }
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(render_metrics())
+}
@@ -0,0 +1,15 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+
+/// The number of pending content reports, mirroring the last computed
+/// `ContentReportStatsDTO::total_pending`.
+pub static MODERATION_PENDING_REPORTS: AtomicI64 = AtomicI64::new(0);
+
+/// Renders the gauges in the Prometheus text exposition format.
+pub fn render_metrics() -> String {
+    format!(
+        "# HELP moderation_pending_reports Content reports waiting for moderation.\n\
+         # TYPE moderation_pending_reports gauge\n\
+         moderation_pending_reports {}\n",
+        MODERATION_PENDING_REPORTS.load(Ordering::Relaxed)
+    )
+}
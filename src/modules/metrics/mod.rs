@@ -0,0 +1,2 @@
+pub mod metrics_controllers;
+pub mod metrics_services;
@@ -1,5 +1,39 @@
+pub mod activitypub;
+pub mod admin;
+pub mod api_key;
+pub mod cache_warmup;
 pub mod category;
 pub mod comment;
+pub mod comment_like;
+pub mod content_report;
+pub mod dead_letter;
+pub mod draft;
+pub mod feature_flag;
+pub mod feed;
+pub mod follower;
+pub mod health;
+pub mod invite_code;
+pub mod login_history;
+pub mod metrics;
+pub mod newsletter;
+pub mod notification;
+pub mod poll;
 pub mod post;
+pub mod post_attachment;
+pub mod post_import;
+pub mod post_view;
+pub mod reading_list;
+pub mod reading_progress;
+pub mod snapshot;
+pub mod static_files;
 pub mod tag;
+pub mod tenant;
 pub mod user;
+pub mod user_activity;
+pub mod user_badge;
+pub mod user_erasure;
+pub mod user_export;
+pub mod user_onboarding;
+pub mod user_preferences;
+pub mod user_recommendation;
+pub mod webhook;
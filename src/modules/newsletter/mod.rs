@@ -0,0 +1,6 @@
+pub mod newsletter_controllers;
+pub mod newsletter_dtos;
+pub mod newsletter_providers;
+pub mod newsletter_queues;
+pub mod newsletter_repositories;
+pub mod newsletter_services;
@@ -0,0 +1,134 @@
+use super::newsletter_services::{list_pending_emails_service, send_digest_service};
+use crate::middlewares::admin_middleware::admin_middleware;
+use actix_web::{get, post, web, HttpRequest, HttpResponse, Responder};
+
+pub fn newsletter_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(trigger_digest).service(list_pending_emails);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/digest/trigger",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 202, description = "Envio do resumo semanal iniciado em segundo plano, limitado a 100 e-mails por minuto (Accepted)"
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	))
+)]
+#[post("/digest/trigger")]
+async fn trigger_digest(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    tokio::spawn(async move {
+        match send_digest_service(pg_pool, redis_pool).await {
+            Ok(summary) => log::info!(
+                "Weekly digest sent to {} of {} subscribers ({} failed).",
+                summary.sent,
+                summary.subscribers,
+                summary.failed
+            ),
+            Err(e) => log::warn!("Failed to send the weekly digest: status {}", e.status()),
+        };
+    });
+    HttpResponse::Accepted().finish()
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/emails/pending",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "E-mails ainda não entregues, retidos pelo limite por hora do domínio ou recusados pelo serviço de e-mail (OK)",
+        body = Vec<PendingEmailDTO>, content_type = "application/json", example = json ! ([{
+            "id": "5b1f0c2a-7d3e-4a8b-9c6d-2e1f0a9b8c7d",
+            "to": "victor@gmail.com",
+            "subject": "Os destaques da semana no Navarro Blog",
+            "scheduledFor": "2024-06-18 08:00:00.000000 UTC",
+            "createdAt": "2024-06-18 08:00:00.000000 UTC"
+        }])
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/emails/pending")]
+async fn list_pending_emails(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match list_pending_emails_service(pg_pool).await {
+        Ok(emails) => HttpResponse::Ok().json(emails),
+        Err(e) => e,
+    }
+}
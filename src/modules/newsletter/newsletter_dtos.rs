@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A user who opted into the newsletter in the preferences and verified the e-mail.
+#[derive(Clone, Debug)]
+pub struct DigestSubscriberDTO {
+    pub id: uuid::Uuid,
+    pub email: String,
+    pub tenant_id: uuid::Uuid,
+}
+
+/// One of the most liked posts of the week, as listed in the digest e-mail.
+#[derive(Clone, Debug)]
+pub struct DigestPostDTO {
+    pub id: uuid::Uuid,
+    pub title: String,
+    pub summary: Option<String>,
+    pub url: String,
+}
+
+/// The result of a digest run.
+#[derive(Clone, Debug, Default)]
+pub struct DigestSummaryDTO {
+    pub subscribers: usize,
+    pub sent: usize,
+    pub failed: usize,
+}
+
+/// An e-mail held back by the hourly limit of its recipient domain, waiting for
+/// `pending_emails_job`. The body is not exposed.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingEmailDTO {
+    pub id: String,
+    pub to: String,
+    pub subject: String,
+    pub scheduled_for: String,
+    pub created_at: String,
+}
+
+/// A due pending e-mail, with what is needed to send it.
+#[derive(Clone, Debug)]
+pub struct QueuedEmailDTO {
+    pub id: uuid::Uuid,
+    pub to: String,
+    pub subject: String,
+    pub html_body: String,
+}
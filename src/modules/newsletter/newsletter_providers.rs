@@ -0,0 +1,65 @@
+use crate::utils::error_construct::error_construct;
+use actix_web::HttpResponse;
+use once_cell::sync::Lazy;
+use std::{env, time::Duration};
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap()
+});
+
+/// How many e-mails each recipient domain may receive per hour, from the `EMAIL_MAX_PER_HOUR` env
+/// var (default `500`).
+pub static EMAIL_MAX_PER_HOUR: Lazy<u32> = Lazy::new(|| {
+    env::var("EMAIL_MAX_PER_HOUR")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(500)
+});
+
+fn email_error(message: String) -> HttpResponse {
+    HttpResponse::ServiceUnavailable().json(error_construct(
+        String::from("email"),
+        String::from("service unavailable"),
+        message,
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Sends an HTML e-mail through the transactional e-mail API at `EMAIL_API_URL`, authenticated
+/// with `EMAIL_API_KEY` and sent from `EMAIL_FROM`. Any 2xx response counts as sent.
+pub async fn send_email_provider(to: &str, subject: &str, html: &str) -> Result<(), HttpResponse> {
+    let url = match env::var("EMAIL_API_URL") {
+        Ok(url) if !url.is_empty() => url,
+        _ => {
+            return Err(email_error(String::from(
+                "O envio de e-mails não está configurado.",
+            )))
+        }
+    };
+    let payload = serde_json::json!({
+        "from": env::var("EMAIL_FROM").unwrap_or_default(),
+        "to": to,
+        "subject": subject,
+        "html": html,
+    });
+
+    match HTTP_CLIENT
+        .post(url)
+        .bearer_auth(env::var("EMAIL_API_KEY").unwrap_or_default())
+        .json(&payload)
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => Ok(()),
+        Ok(resp) => Err(email_error(format!(
+            "O serviço de e-mail respondeu com o status {}.",
+            resp.status().as_u16()
+        ))),
+        Err(e) => Err(email_error(e.to_string())),
+    }
+}
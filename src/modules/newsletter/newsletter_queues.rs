@@ -0,0 +1,47 @@
+use super::newsletter_services::{
+    flush_pending_emails_service, next_digest_run, send_digest_service,
+};
+use actix_web::web::Data;
+use deadpool_postgres::Pool;
+use std::time::Duration;
+
+const PENDING_EMAILS_INTERVAL_SECS: u64 = 60;
+
+/// Sends the weekly digest every Monday at 08:00 UTC.
+pub async fn digest_job(pool_async: Pool, redis_pool: deadpool_redis::Pool) {
+    loop {
+        let now = chrono::Utc::now();
+        let wait = (next_digest_run(now) - now).to_std().unwrap_or_default();
+        tokio::time::sleep(wait).await;
+
+        match send_digest_service(Data::new(pool_async.clone()), Data::new(redis_pool.clone()))
+            .await
+        {
+            Ok(summary) => log::info!(
+                "Weekly digest sent to {} of {} subscribers ({} failed).",
+                summary.sent,
+                summary.subscribers,
+                summary.failed
+            ),
+            Err(e) => log::warn!("Failed to send the weekly digest: status {}", e.status()),
+        };
+    }
+}
+
+/// Sends the e-mails queued by the hourly limit every minute, as the limit of each domain allows.
+pub async fn pending_emails_job(pool_async: Pool, redis_pool: deadpool_redis::Pool) {
+    let mut interval = tokio::time::interval(Duration::from_secs(PENDING_EMAILS_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        match flush_pending_emails_service(
+            Data::new(pool_async.clone()),
+            Data::new(redis_pool.clone()),
+        )
+        .await
+        {
+            Ok(0) => (),
+            Ok(sent) => log::info!("{} pending e-mails sent.", sent),
+            Err(e) => log::warn!("Failed to send the pending e-mails: status {}", e.status()),
+        };
+    }
+}
@@ -0,0 +1,200 @@
+use super::newsletter_dtos::{DigestPostDTO, DigestSubscriberDTO, PendingEmailDTO, QueuedEmailDTO};
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use std::env;
+
+const DIGEST_SUBSCRIBERS_SQL: &str = "
+    SELECT u.id, u.email, u.tenant_id FROM users u
+    JOIN user_preferences p ON p.user_id = u.id
+    JOIN user_onboarding o ON o.user_id = u.id
+    WHERE COALESCE((p.preferences->>'receiveNewsletter')::BOOLEAN, false)
+        AND o.email_verified AND u.deleted_at IS NULL AND u.banned_at IS NULL
+    ORDER BY u.tenant_id, u.created_at
+";
+
+const DIGEST_TOP_POSTS_SQL: &str = "
+    SELECT p.id, p.title, p.summary, p.slug,
+        (SELECT COUNT(*) FROM users_posts_likes l WHERE l.post_id = p.id) AS like_count
+    FROM posts p
+    WHERE p.published_at >= NOW() - INTERVAL '7 days' AND p.published_at <= NOW()
+        AND p.deleted_at IS NULL AND p.tenant_id = $1
+    ORDER BY like_count DESC, p.published_at DESC
+    LIMIT $2
+";
+
+const INSERT_DIGEST_DELIVERY_SQL: &str = "
+    INSERT INTO digest_deliveries (id, subscriber_id, sent_at, post_ids) VALUES ($1, $2, NOW(), $3)
+";
+
+const INSERT_PENDING_EMAIL_SQL: &str = "
+    INSERT INTO pending_emails (id, \"to\", subject, html_body, scheduled_for, created_at)
+    VALUES ($1, $2, $3, $4, NOW(), NOW())
+";
+
+const DUE_PENDING_EMAILS_SQL: &str = "
+    SELECT id, \"to\", subject, html_body FROM pending_emails
+    WHERE sent_at IS NULL AND scheduled_for <= NOW()
+    ORDER BY scheduled_for, created_at
+    LIMIT $1
+";
+
+const MARK_PENDING_EMAIL_SENT_SQL: &str = "
+    UPDATE pending_emails SET sent_at = NOW() WHERE id = $1
+";
+
+const RESCHEDULE_PENDING_EMAIL_SQL: &str = "
+    UPDATE pending_emails SET scheduled_for = NOW() + $2::TEXT::INTERVAL WHERE id = $1
+";
+
+const LIST_PENDING_EMAILS_SQL: &str = "
+    SELECT id, \"to\", subject, scheduled_for, created_at FROM pending_emails
+    WHERE sent_at IS NULL
+    ORDER BY scheduled_for, created_at
+";
+
+pub async fn digest_subscribers_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<DigestSubscriberDTO>, HttpResponse> {
+    match query_executor(pg_pool, DIGEST_SUBSCRIBERS_SQL, &[]).await {
+        Ok(rows) => Ok(rows
+            .iter()
+            .map(|row| DigestSubscriberDTO {
+                id: row.get("id"),
+                email: row.get("email"),
+                tenant_id: row.get("tenant_id"),
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the most liked posts published in the last 7 days, linking to `SITE_BASE_URL`.
+pub async fn digest_top_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    tenant_id: uuid::Uuid,
+    limit: i64,
+) -> Result<Vec<DigestPostDTO>, HttpResponse> {
+    let site_url = env::var("SITE_BASE_URL").unwrap_or_default();
+    match query_executor(pg_pool, DIGEST_TOP_POSTS_SQL, &[&tenant_id, &limit]).await {
+        Ok(rows) => Ok(rows
+            .iter()
+            .map(|row| {
+                let id: uuid::Uuid = row.get("id");
+                let slug: Option<String> = row.get("slug");
+                DigestPostDTO {
+                    id,
+                    title: row.get("title"),
+                    summary: row.get("summary"),
+                    url: format!(
+                        "{}/post/{}",
+                        site_url.trim_end_matches('/'),
+                        slug.unwrap_or_else(|| id.to_string())
+                    ),
+                }
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_digest_delivery_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    subscriber_id: uuid::Uuid,
+    post_ids: Vec<uuid::Uuid>,
+) -> Result<(), HttpResponse> {
+    match query_executor(
+        pg_pool,
+        INSERT_DIGEST_DELIVERY_SQL,
+        &[&uuid::Uuid::new_v4(), &subscriber_id, &post_ids],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Queues an e-mail to be sent by `pending_emails_job` as soon as the hourly limit allows.
+pub async fn insert_pending_email_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    to: &str,
+    subject: &str,
+    html_body: &str,
+) -> Result<(), HttpResponse> {
+    match query_executor(
+        pg_pool,
+        INSERT_PENDING_EMAIL_SQL,
+        &[&uuid::Uuid::new_v4(), &to, &subject, &html_body],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns up to `limit` unsent e-mails whose time has come, the oldest first.
+pub async fn due_pending_emails_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    limit: i64,
+) -> Result<Vec<QueuedEmailDTO>, HttpResponse> {
+    match query_executor(pg_pool, DUE_PENDING_EMAILS_SQL, &[&limit]).await {
+        Ok(rows) => Ok(rows
+            .iter()
+            .map(|row| QueuedEmailDTO {
+                id: row.get("id"),
+                to: row.get("to"),
+                subject: row.get("subject"),
+                html_body: row.get("html_body"),
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn mark_pending_email_sent_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    email_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, MARK_PENDING_EMAIL_SENT_SQL, &[&email_id]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Postpones a pending e-mail by `delay`, a Postgres interval like `1 hour`.
+pub async fn reschedule_pending_email_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    email_id: uuid::Uuid,
+    delay: &str,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, RESCHEDULE_PENDING_EMAIL_SQL, &[&email_id, &delay]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns every e-mail not delivered yet, the next to be sent first.
+pub async fn list_pending_emails_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<PendingEmailDTO>, HttpResponse> {
+    match query_executor(pg_pool, LIST_PENDING_EMAILS_SQL, &[]).await {
+        Ok(rows) => Ok(rows
+            .iter()
+            .map(|row| {
+                let id: uuid::Uuid = row.get("id");
+                let scheduled_for: DateTime<Utc> = row.get("scheduled_for");
+                let created_at: DateTime<Utc> = row.get("created_at");
+                PendingEmailDTO {
+                    id: id.to_string(),
+                    to: row.get("to"),
+                    subject: row.get("subject"),
+                    scheduled_for: scheduled_for.to_string(),
+                    created_at: created_at.to_string(),
+                }
+            })
+            .collect()),
+        Err(e) => Err(e),
+    }
+}
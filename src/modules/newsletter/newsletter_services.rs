@@ -0,0 +1,246 @@
+use super::{
+    newsletter_dtos::{DigestPostDTO, DigestSummaryDTO, PendingEmailDTO},
+    newsletter_providers::{send_email_provider, EMAIL_MAX_PER_HOUR},
+    newsletter_repositories::{
+        digest_subscribers_repository, digest_top_posts_repository, due_pending_emails_repository,
+        insert_digest_delivery_repository, insert_pending_email_repository,
+        list_pending_emails_repository, mark_pending_email_sent_repository,
+        reschedule_pending_email_repository,
+    },
+};
+use crate::{
+    infra::redis::Redis, modules::feed::feed_services::escape_xml,
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc};
+use std::collections::{hash_map::Entry, HashMap};
+
+const DIGEST_POSTS_LIMIT: i64 = 5;
+const DIGEST_SUBJECT: &str = "Os destaques da semana no Navarro Blog";
+const DIGEST_RATE_KEY: &str = "newsletter:digest:rate";
+const MAX_DIGEST_EMAILS_PER_MINUTE: i64 = 100;
+const EMAIL_RATE_KEY: &str = "email:rate";
+const EMAIL_RATE_WINDOW_SECONDS: u64 = 60 * 60;
+const PENDING_EMAILS_BATCH: i64 = 100;
+const PENDING_EMAIL_RETRY_DELAY: &str = "1 hour";
+
+/// Sends e-mails through [`send_email_provider`] up to `max_per_hour` per recipient domain, counted
+/// in Redis under `email:rate:{domain}` so every instance shares the limit. The e-mails over the
+/// limit are stored in `pending_emails` and sent later by `pending_emails_job`.
+pub struct RateLimitedEmailSender {
+    pub max_per_hour: u32,
+    pub pg_pool: Data<deadpool_postgres::Pool>,
+    pub redis_pool: Data<deadpool_redis::Pool>,
+}
+
+impl RateLimitedEmailSender {
+    /// Creates a sender limited to `EMAIL_MAX_PER_HOUR` e-mails per domain.
+    pub fn new(
+        pg_pool: Data<deadpool_postgres::Pool>,
+        redis_pool: Data<deadpool_redis::Pool>,
+    ) -> Self {
+        RateLimitedEmailSender {
+            max_per_hour: *EMAIL_MAX_PER_HOUR,
+            pg_pool,
+            redis_pool,
+        }
+    }
+
+    /// Takes one e-mail from the hourly quota of the domain of `to`, returning `false` when the
+    /// quota is exhausted.
+    async fn acquire(&self, to: &str) -> Result<bool, HttpResponse> {
+        let domain = to.rsplit('@').next().unwrap_or_default().to_lowercase();
+        let key = format!("{}:{}", EMAIL_RATE_KEY, domain);
+        match Redis::incr_ex(&self.redis_pool, &key, EMAIL_RATE_WINDOW_SECONDS).await {
+            Ok(sent) => Ok(sent <= self.max_per_hour as i64),
+            Err(e) => Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                String::from("redis"),
+                String::from("service unavailable"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            ))),
+        }
+    }
+
+    /// Sends the e-mail now or, when the domain of `to` is over its hourly limit, queues it and
+    /// returns `Ok` all the same.
+    pub async fn send(&self, to: &str, subject: &str, html: &str) -> Result<(), HttpResponse> {
+        let within_limit = match self.acquire(to).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+        match within_limit {
+            true => send_email_provider(to, subject, html).await,
+            false => insert_pending_email_repository(self.pg_pool.clone(), to, subject, html).await,
+        }
+    }
+}
+
+/// Returns the next Monday at 08:00 UTC strictly after `now`.
+pub fn next_digest_run(now: DateTime<Utc>) -> DateTime<Utc> {
+    let days_until_monday = (7 - now.weekday().num_days_from_monday()) % 7;
+    let run = (now.date_naive() + Duration::days(days_until_monday as i64))
+        .and_time(NaiveTime::from_hms_opt(8, 0, 0).unwrap())
+        .and_utc();
+    match run > now {
+        true => run,
+        false => run + Duration::days(7),
+    }
+}
+
+/// Builds the HTML body of the digest, with the title, summary and link of each post.
+pub fn digest_html(posts: &[DigestPostDTO]) -> String {
+    let mut html = String::from("<h1>Os destaques da semana</h1>\n<ul>\n");
+    for post in posts {
+        html.push_str(&format!(
+            "<li><a href=\"{}\">{}</a>",
+            escape_xml(&post.url),
+            escape_xml(&post.title)
+        ));
+        if let Some(summary) = &post.summary {
+            html.push_str(&format!("<p>{}</p>", escape_xml(summary)));
+        }
+        html.push_str("</li>\n");
+    }
+    html.push_str("</ul>\n");
+    html
+}
+
+/// Waits until one more e-mail fits in the current minute, counted in Redis so every instance
+/// shares the SMTP quota of 100 e-mails per minute.
+async fn digest_send_slot(redis_pool: &deadpool_redis::Pool) -> Result<(), HttpResponse> {
+    loop {
+        let minute = Utc::now().timestamp() / 60;
+        let key = format!("{}:{}", DIGEST_RATE_KEY, minute);
+        match Redis::incr_ex(redis_pool, &key, 60).await {
+            Ok(sent) if sent <= MAX_DIGEST_EMAILS_PER_MINUTE => return Ok(()),
+            Ok(_) => {
+                let next_minute_ms = ((minute + 1) * 60_000 - Utc::now().timestamp_millis()).max(0);
+                tokio::time::sleep(std::time::Duration::from_millis(next_minute_ms as u64)).await;
+            }
+            Err(e) => {
+                return Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                    String::from("redis"),
+                    String::from("service unavailable"),
+                    e.to_string(),
+                    None,
+                    None,
+                    None,
+                )))
+            }
+        };
+    }
+}
+
+/// Sends the weekly digest with the most liked posts of the last 7 days of each tenant to every
+/// newsletter subscriber, logging each e-mail sent in `digest_deliveries`.
+///
+/// Subscribers of a tenant without posts in the week are skipped.
+pub async fn send_digest_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+) -> Result<DigestSummaryDTO, HttpResponse> {
+    let subscribers = match digest_subscribers_repository(pg_pool.clone()).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let sender = RateLimitedEmailSender::new(pg_pool.clone(), redis_pool.clone());
+    let mut summary = DigestSummaryDTO {
+        subscribers: subscribers.len(),
+        ..Default::default()
+    };
+    let mut digests: HashMap<uuid::Uuid, (Vec<uuid::Uuid>, String)> = HashMap::new();
+    for subscriber in subscribers {
+        if let Entry::Vacant(entry) = digests.entry(subscriber.tenant_id) {
+            let posts = match digest_top_posts_repository(
+                pg_pool.clone(),
+                subscriber.tenant_id,
+                DIGEST_POSTS_LIMIT,
+            )
+            .await
+            {
+                Ok(x) => x,
+                Err(e) => return Err(e),
+            };
+            let post_ids = posts.iter().map(|post| post.id).collect();
+            entry.insert((post_ids, digest_html(&posts)));
+        }
+        let (post_ids, html) = &digests[&subscriber.tenant_id];
+        if post_ids.is_empty() {
+            continue;
+        }
+
+        match digest_send_slot(&redis_pool).await {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+        if let Err(e) = sender.send(&subscriber.email, DIGEST_SUBJECT, html).await {
+            log::warn!(
+                "Failed to send the digest to {}: status {}",
+                subscriber.id,
+                e.status()
+            );
+            summary.failed += 1;
+            continue;
+        }
+        summary.sent += 1;
+        let _ = insert_digest_delivery_repository(pg_pool.clone(), subscriber.id, post_ids.clone())
+            .await;
+    }
+    Ok(summary)
+}
+
+/// Sends the due pending e-mails whose domain is back under the hourly limit, returning how many
+/// were sent. The others stay queued, and an e-mail the provider refuses is retried in an hour.
+pub async fn flush_pending_emails_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+) -> Result<usize, HttpResponse> {
+    let emails = match due_pending_emails_repository(pg_pool.clone(), PENDING_EMAILS_BATCH).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let sender = RateLimitedEmailSender::new(pg_pool.clone(), redis_pool);
+    let mut sent = 0;
+    for email in emails {
+        let within_limit = match sender.acquire(&email.to).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+        if !within_limit {
+            continue;
+        }
+        let failed = match send_email_provider(&email.to, &email.subject, &email.html_body).await {
+            Ok(_) => None,
+            Err(e) => Some(e.status()),
+        };
+        if let Some(status) = failed {
+            log::warn!(
+                "Failed to send the pending e-mail {}: status {}",
+                email.id,
+                status
+            );
+            let _ = reschedule_pending_email_repository(
+                pg_pool.clone(),
+                email.id,
+                PENDING_EMAIL_RETRY_DELAY,
+            )
+            .await;
+            continue;
+        }
+        sent += 1;
+        let _ = mark_pending_email_sent_repository(pg_pool.clone(), email.id).await;
+    }
+    Ok(sent)
+}
+
+pub async fn list_pending_emails_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<PendingEmailDTO>, HttpResponse> {
+    list_pending_emails_repository(pg_pool).await
+}
@@ -0,0 +1,4 @@
+pub mod notification_dtos;
+pub mod notification_providers;
+pub mod notification_repositories;
+pub mod notification_services;
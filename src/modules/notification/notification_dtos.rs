@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// The notification types a user can opt out of in `UserPreferencesDTO::notification_opt_outs`.
+pub const NOTIFICATION_TYPES: [&str; 6] = [
+    "comment_on_post",
+    "reply_to_comment",
+    "like_on_post",
+    "mention",
+    "follow",
+    "comment_approved",
+];
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDTO {
+    pub id: String,
+    pub user_id: String,
+    pub actor_id: Option<String>,
+    #[serde(rename = "type")]
+    pub notification_type: String,
+    pub payload: serde_json::Value,
+    pub created_at: String,
+}
@@ -0,0 +1,40 @@
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+
+const NOTIFICATION_OPTED_OUT_SQL: &str = "
+    SELECT EXISTS (
+        SELECT 1 FROM user_preferences
+        WHERE user_id = $1 AND preferences->'notificationOptOuts' ? $2
+    ) AS opted_out
+";
+
+/// Returns a `403 Forbidden` when the user opted out of the notifications of `notification_type`
+/// in their preferences.
+pub async fn check_notification_opted_out_provider(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    notification_type: &str,
+) -> Result<(), HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        NOTIFICATION_OPTED_OUT_SQL,
+        &[&user_id, &notification_type],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows[0].get("opted_out") {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("notification"),
+            String::from("forbidden"),
+            String::from("O usuário optou por não receber notificações deste tipo."),
+            Some(String::from(notification_type)),
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use super::notification_dtos::NotificationDTO;
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+
+const INSERT_NOTIFICATION_SQL: &str = "
+    INSERT INTO notifications (id, user_id, actor_id, type, payload, created_at)
+    VALUES ($1, $2, $3, $4, $5::TEXT::JSONB, NOW())
+    RETURNING created_at
+";
+
+pub async fn insert_notification_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    actor_id: Option<uuid::Uuid>,
+    notification_type: &str,
+    payload: serde_json::Value,
+) -> Result<NotificationDTO, HttpResponse> {
+    let id = uuid::Uuid::new_v4();
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_NOTIFICATION_SQL,
+        &[
+            &id,
+            &user_id,
+            &actor_id,
+            &notification_type,
+            &payload.to_string(),
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let created_at: chrono::DateTime<chrono::Utc> = rows[0].get("created_at");
+    Ok(NotificationDTO {
+        id: id.to_string(),
+        user_id: user_id.to_string(),
+        actor_id: actor_id.map(|id| id.to_string()),
+        notification_type: String::from(notification_type),
+        payload,
+        created_at: created_at.to_string(),
+    })
+}
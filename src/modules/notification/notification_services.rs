@@ -0,0 +1,53 @@
+use super::{
+    notification_dtos::NotificationDTO,
+    notification_providers::check_notification_opted_out_provider,
+    notification_repositories::insert_notification_repository,
+};
+use crate::infra::redis::Redis;
+use actix_web::{web::Data, HttpResponse};
+
+pub fn notification_channel(user_id: &str) -> String {
+    format!("notifications:{}", user_id)
+}
+
+/// Stores a notification for `user_id` and publishes it on the user's Redis pub/sub channel,
+/// returning `None` without storing it when the user opted out of `notification_type`.
+///
+/// Publishing is best-effort: the notification is already persisted when it happens, so a Redis
+/// failure does not lose it.
+pub async fn insert_notification_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    user_id: uuid::Uuid,
+    actor_id: Option<uuid::Uuid>,
+    notification_type: &str,
+    payload: serde_json::Value,
+) -> Result<Option<NotificationDTO>, HttpResponse> {
+    match check_notification_opted_out_provider(pg_pool.clone(), user_id, notification_type).await {
+        Ok(_) => (),
+        Err(e) if e.status() == 403 => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let notification = match insert_notification_repository(
+        pg_pool,
+        user_id,
+        actor_id,
+        notification_type,
+        payload,
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let message = serde_json::to_string(&notification).unwrap_or_default();
+    let _ = Redis::publish(
+        redis_pool,
+        &notification_channel(&notification.user_id),
+        &message,
+    )
+    .await;
+    Ok(Some(notification))
+}
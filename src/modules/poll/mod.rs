@@ -0,0 +1,4 @@
+pub mod poll_controllers;
+pub mod poll_dtos;
+pub mod poll_repositories;
+pub mod poll_services;
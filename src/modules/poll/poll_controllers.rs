@@ -0,0 +1,241 @@
+use super::{
+    poll_dtos::{InsertPollDTO, VotePollDTO},
+    poll_services::{insert_poll_service, vote_poll_service},
+};
+use crate::middlewares::jwt_token_middleware::jwt_token_middleware;
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn poll_controllers_module() -> actix_web::Scope {
+    web::scope("/poll").service(vote_poll)
+}
+
+pub fn poll_post_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(insert_poll);
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/poll",
+    security(("bearer_auth" = [])),
+    request_body = InsertPollDTO,
+    responses((
+        status = 201, description = "Enquete criada no post (Created)", body = PollDTO
+    ), (
+		status = 400, description = "Erro do usuário por id, pergunta, opções ou data de fim inválidos (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "options": [{
+                "code": "length",
+                "message": "A enquete deve ter entre 2 e 10 opções.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post pode criar a enquete (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post pode criar uma enquete.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O post já possui uma enquete (Conflict)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "poll": [{
+                "code": "conflict",
+                "message": "Este post já possui uma enquete.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{post_id}/poll")]
+async fn insert_poll(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    body: web::Json<InsertPollDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match insert_poll_service(
+        pg_pool,
+        post_id.into_inner(),
+        token.claims.sub,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(poll) => HttpResponse::Created().json(poll),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/poll/{poll_id}/vote",
+    security(("bearer_auth" = [])),
+    request_body = VotePollDTO,
+    responses((
+        status = 200, description = "Voto registrado; retorna o resultado atualizado da enquete (OK)", body = PollDTO
+    ), (
+		status = 400, description = "Erro do usuário por id inválido ou opção de outra enquete (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "option_id": [{
+                "code": "bad request",
+                "message": "A opção informada não pertence a esta enquete.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Enquete não encontrada (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "poll": [{
+                "code": "not found",
+                "message": "Não foi encontrada uma enquete com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O usuário já votou nesta enquete (Conflict)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "poll": [{
+                "code": "conflict",
+                "message": "Você já votou nesta enquete.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 410, description = "A enquete foi encerrada (Gone)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "poll": [{
+                "code": "gone",
+                "message": "Esta enquete foi encerrada e não aceita novos votos.",
+                "params": {
+                    "min": null,
+                    "value": "2024-07-18 22:03:54 UTC",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/{poll_id}/vote")]
+async fn vote_poll(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    poll_id: web::Path<String>,
+    body: web::Json<VotePollDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match vote_poll_service(
+        pg_pool,
+        poll_id.into_inner(),
+        token.claims.sub,
+        body.into_inner().option_id,
+    )
+    .await
+    {
+        Ok(poll) => HttpResponse::Ok().json(poll),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+fn poll_options_valid(options: &[String]) -> Result<(), ValidationError> {
+    if options
+        .iter()
+        .all(|option| !option.trim().is_empty() && option.chars().count() <= 127)
+    {
+        return Ok(());
+    }
+    Err(ValidationError::new("options").with_message(Cow::from(
+        "Cada opção da enquete deve ter entre 1 e 127 caracteres.",
+    )))
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertPollDTO {
+    #[validate(length(
+        min = 3,
+        max = 255,
+        message = "A pergunta da enquete deve ter entre 3 e 255 caracteres."
+    ))]
+    pub question: String,
+
+    #[validate(
+        length(min = 2, max = 10, message = "A enquete deve ter entre 2 e 10 opções."),
+        custom(function = "poll_options_valid")
+    )]
+    pub options: Vec<String>,
+
+    #[serde(default, alias = "endsAt")]
+    pub ends_at: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct VotePollDTO {
+    #[serde(alias = "optionId")]
+    pub option_id: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PollOptionDTO {
+    pub id: String,
+    pub text: String,
+    pub position: i32,
+    pub vote_count: i64,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PollDTO {
+    pub id: String,
+    pub post_id: String,
+    pub question: String,
+    pub ends_at: Option<String>,
+    pub closed: bool,
+    pub options: Vec<PollOptionDTO>,
+}
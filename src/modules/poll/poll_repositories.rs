@@ -0,0 +1,176 @@
+use super::poll_dtos::{PollDTO, PollOptionDTO};
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const POST_AUTHOR_SQL: &str = "SELECT author_id FROM posts WHERE id = $1 AND deleted_at IS NULL";
+
+const INSERT_POLL_SQL: &str = "
+    WITH poll AS (
+        INSERT INTO polls (id, post_id, question, ends_at, created_at)
+        VALUES ($1, $2, $3, $4, NOW())
+        ON CONFLICT (post_id) DO NOTHING
+        RETURNING id
+    ), options AS (
+        INSERT INTO poll_options (id, poll_id, text, position)
+        SELECT o.id, poll.id, o.text, o.position::INT
+        FROM poll, UNNEST($5::UUID[], $6::TEXT[]) WITH ORDINALITY AS o (id, text, position)
+    )
+    SELECT id FROM poll
+";
+
+const DETAIL_POLL_SQL: &str = "
+    SELECT p.id, p.post_id, p.question, p.ends_at, COALESCE(p.ends_at <= NOW(), FALSE) AS closed,
+        o.id AS option_id, o.text, o.position, COUNT(v.user_id) AS vote_count
+    FROM polls p
+    JOIN poll_options o ON o.poll_id = p.id
+    LEFT JOIN poll_votes v ON v.option_id = o.id
+    WHERE p.id = $1
+    GROUP BY p.id, o.id
+    ORDER BY o.position
+";
+
+const POST_POLL_ID_SQL: &str = "SELECT id FROM polls WHERE post_id = $1";
+
+const INSERT_VOTE_SQL: &str = "
+    WITH option AS (
+        SELECT id FROM poll_options WHERE id = $3 AND poll_id = $2
+    ), inserted AS (
+        INSERT INTO poll_votes (user_id, poll_id, option_id, voted_at)
+        SELECT $1, $2, id, NOW() FROM option
+        ON CONFLICT (user_id, poll_id) DO NOTHING
+        RETURNING option_id
+    )
+    SELECT EXISTS (SELECT 1 FROM option) AS option_exists,
+        EXISTS (SELECT 1 FROM inserted) AS voted
+";
+
+/// Returns the author of the post, or `None` if the post does not exist.
+pub async fn post_author_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Option<Option<uuid::Uuid>>, HttpResponse> {
+    let rows = match query_executor(pg_pool, POST_AUTHOR_SQL, &[&post_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.first().map(|row| row.get("author_id")))
+}
+
+/// Creates the poll with its options, returning `None` if the post already has a poll.
+pub async fn insert_poll_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    question: String,
+    options: Vec<String>,
+    ends_at: Option<DateTime<Utc>>,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    let option_ids: Vec<uuid::Uuid> = options.iter().map(|_| uuid::Uuid::new_v4()).collect();
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_POLL_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &post_id,
+            &question,
+            &ends_at,
+            &option_ids,
+            &options,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.first().map(|row| row.get("id")))
+}
+
+pub async fn post_poll_id_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    let rows = match query_executor(pg_pool, POST_POLL_ID_SQL, &[&post_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.first().map(|row| row.get("id")))
+}
+
+/// Finds a poll with the vote count of each option.
+pub async fn detail_poll_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    poll_id: uuid::Uuid,
+) -> Result<Option<PollDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, DETAIL_POLL_SQL, &[&poll_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let first = match rows.first() {
+        Some(row) => row,
+        None => return Ok(None),
+    };
+    let post_id: uuid::Uuid = first.get("post_id");
+    let ends_at: Option<DateTime<Utc>> = first.get("ends_at");
+
+    Ok(Some(PollDTO {
+        id: poll_id.to_string(),
+        post_id: post_id.to_string(),
+        question: first.get("question"),
+        ends_at: ends_at.map(|dt| dt.to_string()),
+        closed: first.get("closed"),
+        options: rows
+            .iter()
+            .map(|row| {
+                let option_id: uuid::Uuid = row.get("option_id");
+                PollOptionDTO {
+                    id: option_id.to_string(),
+                    text: row.get("text"),
+                    position: row.get("position"),
+                    vote_count: row.get("vote_count"),
+                }
+            })
+            .collect(),
+    }))
+}
+
+pub async fn insert_vote_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    poll_id: uuid::Uuid,
+    option_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    let rows =
+        match query_executor(pg_pool, INSERT_VOTE_SQL, &[&user_id, &poll_id, &option_id]).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+
+    let option_exists: bool = rows[0].get("option_exists");
+    if !option_exists {
+        return Err(HttpResponse::BadRequest().json(error_construct(
+            String::from("option_id"),
+            String::from("bad request"),
+            String::from("A opção informada não pertence a esta enquete."),
+            Some(option_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    let voted: bool = rows[0].get("voted");
+    if !voted {
+        return Err(HttpResponse::Conflict().json(error_construct(
+            String::from("poll"),
+            String::from("conflict"),
+            String::from("Você já votou nesta enquete."),
+            Some(poll_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
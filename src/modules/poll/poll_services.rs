@@ -0,0 +1,181 @@
+use super::{
+    poll_dtos::{InsertPollDTO, PollDTO},
+    poll_repositories::*,
+};
+use crate::{
+    middlewares::uuid_path_middleware::uuid_path_middleware,
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+fn parse_uuid(id: String, field: &str) -> Result<uuid::Uuid, HttpResponse> {
+    match uuid_path_middleware(id, field) {
+        Ok(id) => Ok(uuid::Uuid::parse_str(&id).unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+fn poll_not_found(id: uuid::Uuid) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("poll"),
+        String::from("not found"),
+        String::from("Não foi encontrada uma enquete com este id."),
+        Some(id.to_string()),
+        None,
+        None,
+    ))
+}
+
+/// Parses the optional RFC 3339 end of the poll, which must be in the future.
+fn parse_ends_at(ends_at: Option<String>) -> Result<Option<DateTime<Utc>>, HttpResponse> {
+    let ends_at = match ends_at {
+        Some(ends_at) => ends_at,
+        None => return Ok(None),
+    };
+    match DateTime::parse_from_rfc3339(ends_at.trim()) {
+        Ok(parsed) if parsed.with_timezone(&Utc) > Utc::now() => {
+            Ok(Some(parsed.with_timezone(&Utc)))
+        }
+        _ => Err(HttpResponse::BadRequest().json(error_construct(
+            String::from("ends_at"),
+            String::from("bad request"),
+            String::from("O fim da enquete deve ser uma data RFC 3339 no futuro."),
+            Some(ends_at),
+            None,
+            None,
+        ))),
+    }
+}
+
+pub async fn insert_poll_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    author_id: String,
+    body: InsertPollDTO,
+) -> Result<PollDTO, HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let ends_at = match parse_ends_at(body.ends_at) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let post_author = match post_author_repository(pg_pool.clone(), post_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author {
+        None => {
+            return Err(HttpResponse::NotFound().json(error_construct(
+                String::from("post"),
+                String::from("not found"),
+                String::from("Não foi encontrado um post com este id."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Some(author) if author.map(|id| id.to_string()) != Some(author_id) => {
+            return Err(HttpResponse::Forbidden().json(error_construct(
+                String::from("post"),
+                String::from("forbidden"),
+                String::from("Apenas o autor do post pode criar uma enquete."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Some(_) => (),
+    };
+
+    let options = body
+        .options
+        .iter()
+        .map(|option| option.trim().to_string())
+        .collect();
+    let poll_id =
+        match insert_poll_repository(pg_pool.clone(), post_id, body.question, options, ends_at)
+            .await
+        {
+            Ok(Some(poll_id)) => poll_id,
+            Ok(None) => {
+                return Err(HttpResponse::Conflict().json(error_construct(
+                    String::from("poll"),
+                    String::from("conflict"),
+                    String::from("Este post já possui uma enquete."),
+                    Some(post_id.to_string()),
+                    None,
+                    None,
+                )))
+            }
+            Err(e) => return Err(e),
+        };
+
+    match detail_poll_repository(pg_pool, poll_id).await {
+        Ok(Some(poll)) => Ok(poll),
+        Ok(None) => Err(poll_not_found(poll_id)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Casts the vote of `user_id`, returning the updated results of the poll.
+///
+/// Each user votes once per poll, and closed polls reject new votes with 410.
+pub async fn vote_poll_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    poll_id: String,
+    user_id: String,
+    option_id: String,
+) -> Result<PollDTO, HttpResponse> {
+    let poll_id = match parse_uuid(poll_id, "poll_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let option_id = match parse_uuid(option_id, "option_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let poll = match detail_poll_repository(pg_pool.clone(), poll_id).await {
+        Ok(Some(poll)) => poll,
+        Ok(None) => return Err(poll_not_found(poll_id)),
+        Err(e) => return Err(e),
+    };
+    if poll.closed {
+        return Err(HttpResponse::Gone().json(error_construct(
+            String::from("poll"),
+            String::from("gone"),
+            String::from("Esta enquete foi encerrada e não aceita novos votos."),
+            poll.ends_at,
+            None,
+            None,
+        )));
+    }
+
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    match insert_vote_repository(pg_pool.clone(), user_id, poll_id, option_id).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    match detail_poll_repository(pg_pool, poll_id).await {
+        Ok(Some(poll)) => Ok(poll),
+        Ok(None) => Err(poll_not_found(poll_id)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the poll of the post, if it has one.
+pub async fn detail_post_poll_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Option<PollDTO>, HttpResponse> {
+    let poll_id = match post_poll_id_repository(pg_pool.clone(), post_id).await {
+        Ok(Some(poll_id)) => poll_id,
+        Ok(None) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    detail_poll_repository(pg_pool, poll_id).await
+}
@@ -1 +1,6 @@
+pub mod post_controllers;
 pub mod post_dto;
+pub mod post_providers;
+pub mod post_queues;
+pub mod post_repositories;
+pub mod post_services;
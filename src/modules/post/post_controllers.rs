@@ -0,0 +1,1838 @@
+use super::{
+    post_dto::{
+        CopiedPostDTO, CoverImageDTO, ListPostsParams, PatchPostAuthorsDTO, PostFilterParams,
+        RevisionDiffParams, SponsorshipDTO, UpdatePostDTO,
+    },
+    post_services::{
+        acquire_post_lock_service, admin_list_posts_service, archive_month_posts_service,
+        archive_service, copy_post_service, delete_cover_image_service, detail_post_service,
+        embed_meta_html, embed_meta_service, excerpt_post_service, invalidate_excerpts_service,
+        list_posts_service, patch_post_authors_service, put_cover_image_service,
+        put_sponsorship_service, recompute_post_counts_service, release_post_lock_service,
+        revision_diff_service, trending_posts_service, update_post_service,
+    },
+};
+use crate::{
+    middlewares::{
+        admin_middleware::admin_middleware,
+        etag_middleware::{etag_invalidate_route, etag_middleware, etag_response},
+        idempotency_middleware::idempotency_middleware,
+        jwt_token_middleware::jwt_token_middleware,
+        tenant_middleware::tenant_middleware,
+        uuid_path_middleware::uuid_path_middleware,
+    },
+    modules::{
+        comment::comment_controllers::comment_controllers_module,
+        content_report::content_report_controllers::content_report_post_controllers_module,
+        draft::draft_controllers::draft_controllers_module,
+        poll::poll_controllers::poll_post_controllers_module,
+        post_attachment::post_attachment_controllers::post_attachment_controllers_module,
+        reading_progress::reading_progress_controllers::reading_progress_post_controllers_module,
+    },
+    shared::structs::{cursor_params::CursorParams, page_params::PageParams},
+};
+use actix_web::{
+    delete, get,
+    http::header::{ACCEPT, CACHE_CONTROL, LOCATION},
+    middleware::from_fn,
+    patch, post, put, web, HttpRequest, HttpResponse, Responder,
+};
+use validator::Validate;
+
+pub fn post_controllers_module() -> actix_web::Scope {
+    web::scope("/post")
+        .service(list_posts)
+        .service(trending_posts)
+        .service(archive)
+        .service(archive_month_posts)
+        .service(put_sponsorship)
+        .service(put_cover_image)
+        .service(delete_cover_image)
+        .service(update_post)
+        .service(recompute_post_counts)
+        .service(copy_post)
+        .service(patch_post_authors)
+        .service(embed_meta)
+        .service(excerpt_post)
+        .service(revision_diff)
+        .service(acquire_post_lock)
+        .service(release_post_lock)
+        .configure(draft_controllers_module)
+        .configure(comment_controllers_module)
+        .configure(poll_post_controllers_module)
+        .configure(post_attachment_controllers_module)
+        .configure(reading_progress_post_controllers_module)
+        .configure(content_report_post_controllers_module)
+        .service(detail_post)
+}
+
+pub fn post_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(admin_list_posts);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/post",
+    security(("bearer_auth" = [])),
+    params(
+        ("page" = Option<i64>, Query, description = "Página, a partir de 1 (padrão 1)"),
+        ("per_page" = Option<i64>, Query, description = "Quantidade de posts por página (1 a 100, padrão 20)"),
+        ("status" = Option<String>, Query, description = "Status do post (draft, published ou deleted); sem ele, lista todos os posts não excluídos"),
+        ("author_id" = Option<String>, Query, description = "Posts em que o usuário é autor ou coautor"),
+        ("published_after" = Option<String>, Query, description = "Publicados a partir desta data (RFC 3339)"),
+        ("published_before" = Option<String>, Query, description = "Publicados antes desta data (RFC 3339)"),
+        ("has_cover" = Option<bool>, Query, description = "Se o post tem imagem de capa")
+    ),
+    responses((
+        status = 200, description = "Listagem filtrada de posts, do mais recente ao mais antigo (OK)",
+        content_type = "application/json", example = json ! ({
+            "data": [{
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "summary": "Um resumo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                "publishedAt": null,
+                "isSponsored": false,
+                "version": 0,
+                "coverImageUrl": null
+            }],
+            "page": 1,
+            "perPage": 20,
+            "total": 1,
+            "totalPages": 1
+        })
+    ), (
+		status = 400, description = "Erro do usuário por filtro inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "status": [{
+                "code": "regex",
+                "message": "O status do post deve ser draft, published ou deleted.",
+                "params": {
+                    "value": "archived"
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/post")]
+async fn admin_list_posts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    page_params: web::Query<PageParams>,
+    filter: web::Query<PostFilterParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match filter.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match admin_list_posts_service(
+        pg_pool,
+        page_params.into_inner(),
+        filter.into_inner(),
+        tenant_id,
+    )
+    .await
+    {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post",
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+        ("sponsored" = Option<bool>, Query, description = "Filtra apenas posts patrocinados (true) ou não patrocinados (false)"),
+        ("lang" = Option<String>, Query, description = "Filtra os posts pelo idioma (código de duas letras, como en)"),
+        ("code_language" = Option<String>, Query, description = "Filtra os posts com blocos de código na linguagem, como rust"),
+    ),
+    responses((
+        status = 200, description = "Posts publicados, do mais recente ao mais antigo (OK)",
+        content_type = "application/json", example = json ! ({
+            "data": [{
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "summary": "Um resumo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                "publishedAt": "2024-06-18 22:03:54.053147 UTC",
+                "sponsoredBy": "Navarro Tech",
+                "sponsorUrl": "https://navarro.tech",
+                "isSponsored": true,
+                "version": 0,
+                "language": "pt",
+                "canonicalUrl": null,
+                "wordCount": 412,
+                "characterCount": 2518,
+                "coverImageUrl": null,
+                "coverImageBlurhash": null,
+                "codeLanguages": ["rust", "toml"]
+            }],
+            "nextCursor": null
+        })
+    ), (
+		status = 400, description = "Erro do usuário por cursor ou idioma inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "cursor": [{
+                "code": "bad request",
+                "message": "Cursor inválido.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("")]
+async fn list_posts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    query_params: web::Query<ListPostsParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match list_posts_service(pg_pool, query_params.into_inner(), tenant_id).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/trending",
+    responses((
+        status = 200, description = "Os 10 posts dos últimos 30 dias com mais engajamento recente, por curtidas, comentários e visualizações com decaimento pelo tempo de publicação (OK)",
+        body = Vec<PostDTO>
+    ), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("trending")]
+async fn trending_posts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match trending_posts_service(pg_pool, redis_pool, tenant_id).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/archive",
+    responses((
+        status = 200, description = "Quantidade de posts publicados por mês, do mês mais recente ao mais antigo (OK)",
+        body = Vec<ArchiveMonthDTO>, content_type = "application/json", example = json ! ([{
+            "year": 2024,
+            "month": 12,
+            "count": 5
+        }, {
+            "year": 2024,
+            "month": 11,
+            "count": 2
+        }])
+    ), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("archive")]
+async fn archive(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match archive_service(pg_pool, redis_pool, tenant_id).await {
+        Ok(months) => HttpResponse::Ok().json(months),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/archive/{year}/{month}",
+    params(
+        ("year" = i32, Path, description = "Ano da publicação, como 2024"),
+        ("month" = u32, Path, description = "Mês da publicação, de 1 a 12"),
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Posts publicados no mês, do mais recente ao mais antigo (OK)",
+        content_type = "application/json", example = json ! ({
+            "data": [{
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "summary": "Um resumo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-12-18 22:03:54.053147 UTC",
+                "publishedAt": "2024-12-18 22:03:54.053147 UTC",
+                "isSponsored": false,
+                "version": 0,
+                "language": "pt",
+                "wordCount": 412,
+                "characterCount": 2518,
+                "codeLanguages": []
+            }],
+            "nextCursor": null
+        })
+    ), (
+		status = 400, description = "Erro do usuário por mês ou cursor inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "month": [{
+                "code": "bad request",
+                "message": "Informe um ano válido e um mês entre 1 e 12.",
+                "params": {
+                    "min": null,
+                    "value": "2024/13",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("archive/{year}/{month}")]
+async fn archive_month_posts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    path: web::Path<(i32, u32)>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    let (year, month) = path.into_inner();
+    match archive_month_posts_service(pg_pool, year, month, query_params.into_inner(), tenant_id)
+        .await
+    {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/sponsorship",
+    security(("bearer_auth" = [])),
+    request_body = SponsorshipDTO,
+    responses((
+        status = 200, description = "Patrocínio do post atualizado; envie os campos nulos para removê-lo (OK)",
+        body = PostDTO, content_type = "application/json", example = json ! ({
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "body": "Conteúdo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                "publishedAt": "2024-06-18 22:03:54.053147 UTC",
+                "sponsoredBy": "Navarro Tech",
+                "sponsorUrl": "https://navarro.tech",
+                "isSponsored": true,
+                "version": 0,
+                "language": "pt",
+                "canonicalUrl": null,
+                "wordCount": 412,
+                "characterCount": 2518
+            })
+    ), (
+		status = 400, description = "Erro do usuário por id ou corpo inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "sponsor_url": [{
+                "code": "url",
+                "message": "A URL do patrocinador deve ser um endereço HTTPS válido.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é o autor do post nem administrador (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post ou um administrador pode alterar o patrocínio.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{post_id}/sponsorship")]
+async fn put_sponsorship(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<SponsorshipDTO>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    let is_admin = token.claims.role == "admin";
+    match put_sponsorship_service(
+        pg_pool,
+        post_id,
+        token.claims.sub,
+        is_admin,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(post) => {
+            etag_invalidate_route(&redis_pool, "post").await;
+            HttpResponse::Ok().json(post)
+        }
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/cover",
+    security(("bearer_auth" = [])),
+    request_body = CoverImageDTO,
+    responses((
+        status = 200, description = "Capa do post definida ou atualizada (OK)",
+        body = PostDTO, content_type = "application/json", example = json ! ({
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "body": "Conteúdo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                "publishedAt": "2024-06-18 22:03:54.053147 UTC",
+                "isSponsored": false,
+                "version": 0,
+                "language": "pt",
+                "canonicalUrl": null,
+                "wordCount": 412,
+                "characterCount": 2518,
+                "coverImageUrl": "https://cdn.navarro.tech/rust-e-actix-web.webp",
+                "coverImageBlurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj"
+            })
+    ), (
+		status = 400, description = "Erro do usuário por id, corpo inválido ou URL que não aponta para uma imagem (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "cover_image_url": [{
+                "code": "url",
+                "message": "A URL da capa deve ser um endereço HTTPS válido.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é o autor do post nem administrador (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas os autores do post ou um administrador podem alterar a capa.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{post_id}/cover")]
+async fn put_cover_image(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<CoverImageDTO>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    let is_admin = token.claims.role == "admin";
+    match put_cover_image_service(
+        pg_pool,
+        post_id,
+        token.claims.sub,
+        is_admin,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(post) => {
+            invalidate_excerpts_service(&redis_pool).await;
+            etag_invalidate_route(&redis_pool, "post").await;
+            HttpResponse::Ok().json(post)
+        }
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/cover",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Capa do post removida (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "abc",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é o autor do post nem administrador (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas os autores do post ou um administrador podem alterar a capa.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("{post_id}/cover")]
+async fn delete_cover_image(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    let is_admin = token.claims.role == "admin";
+    match delete_cover_image_service(pg_pool, post_id, token.claims.sub, is_admin).await {
+        Ok(_) => {
+            invalidate_excerpts_service(&redis_pool).await;
+            etag_invalidate_route(&redis_pool, "post").await;
+            HttpResponse::NoContent().finish()
+        }
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}",
+    security(("bearer_auth" = [])),
+    request_body = UpdatePostDTO,
+    responses((
+        status = 200, description = "Post atualizado, com a versão incrementada e os avisos de markdown do corpo, que não impedem a atualização (OK)",
+        body = PostDTO, content_type = "application/json", example = json ! ({
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "body": "Conteúdo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                "publishedAt": "2024-06-18 22:03:54.053147 UTC",
+                "summary": "Um resumo do post",
+                "isSponsored": false,
+                "version": 1,
+                "language": "pt",
+                "canonicalUrl": "https://dev.to/bush1d3v/rust-e-actix-web",
+                "wordCount": 412,
+                "characterCount": 2518,
+                "warnings": [{
+                    "line": 3,
+                    "column": 1,
+                    "rule": "unclosed_code_fence",
+                    "message": "O bloco de código aberto nesta linha não foi fechado."
+                }]
+            })
+    ), (
+		status = 400, description = "Erro do usuário por id ou corpo inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "title": [{
+                "code": "length",
+                "message": "O título deve ter entre 3 e 127 caracteres.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é autor nem coautor do post (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas os autores do post podem editá-lo.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "O post foi alterado desde a versão enviada, o slug já pertence a outro post ou o autor já tem um post com o mesmo título e conteúdo (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "version": [{
+                "code": "conflict",
+                "message": "O post foi modificado por outra sessão. Por favor, recarregue e tente novamente.",
+                "params": {
+                    "min": null,
+                    "value": "2",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 423, description = "Outro usuário está editando o post (Locked)", body = PostLockedDTO,
+		content_type = "application/json", example = json ! ({
+            "lockedBy": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "lockedByName": "Victor Navarro",
+            "expiresAt": "2024-06-18 22:08:54.053147 UTC"
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[put("{post_id}")]
+async fn update_post(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<UpdatePostDTO>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match update_post_service(pg_pool, post_id, token.claims.sub, body.into_inner()).await {
+        Ok(post) => {
+            invalidate_excerpts_service(&redis_pool).await;
+            etag_invalidate_route(&redis_pool, "post").await;
+            HttpResponse::Ok().json(post)
+        }
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/recompute-counts",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Contagem de palavras e caracteres do post recalculada a partir do corpo atual (OK)",
+        body = PostDTO, content_type = "application/json", example = json ! ({
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "body": "Conteúdo do post",
+                "summary": "Um resumo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                "publishedAt": "2024-06-18 22:03:54.053147 UTC",
+                "isSponsored": false,
+                "version": 3,
+                "language": "pt",
+                "canonicalUrl": null,
+                "wordCount": 3,
+                "characterCount": 16
+            })
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "123",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{post_id}/recompute-counts")]
+async fn recompute_post_counts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    match recompute_post_counts_service(pg_pool, post_id).await {
+        Ok(post) => {
+            invalidate_excerpts_service(&redis_pool).await;
+            etag_invalidate_route(&redis_pool, "post").await;
+            HttpResponse::Ok().json(post)
+        }
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/copy",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 201, description = "Cópia criada como rascunho com o slug {slug}-copy, numerado quando já existe (Created)",
+        body = CopiedPostDTO, content_type = "application/json", example = json ! ({
+            "postId": "5b1f0c2e-8d3a-4e6f-9a7b-1c2d3e4f5a6b"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post ou um administrador pode copiá-lo (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post ou um administrador pode copiá-lo.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{post_id}/copy", wrap = "from_fn(idempotency_middleware)")]
+async fn copy_post(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    let is_admin = token.claims.role == "admin";
+    match copy_post_service(pg_pool, post_id, token.claims.sub, is_admin, tenant_id).await {
+        Ok(post_id) => HttpResponse::Created()
+            .insert_header(("Location", format!("/post/{}", post_id)))
+            .json(CopiedPostDTO { post_id }),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/authors",
+    security(("bearer_auth" = [])),
+    request_body = PatchPostAuthorsDTO,
+    responses((
+        status = 200, description = "Autores do post, o principal primeiro e os coautores pela data de inclusão (OK)",
+        body = Vec<AuthorDTO>, content_type = "application/json", example = json ! ([{
+            "userId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "name": "Victor Navarro",
+            "username": "victor_navarro",
+            "role": "primary",
+            "addedAt": "2024-06-19 01:03:54.053147 UTC"
+        }, {
+            "userId": "5b1f0c2e-8d3a-4e6f-9a7b-1c2d3e4f5a6b",
+            "name": "Maria Silva",
+            "username": "maria_silva",
+            "role": "co-author",
+            "addedAt": "2024-06-20 10:15:00.000000 UTC"
+        }])
+    ), (
+		status = 400, description = "Erro do usuário por id inválido, usuário inexistente ou mais de 5 coautores (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "add": [{
+                "code": "bad request",
+                "message": "Um post pode ter no máximo 5 coautores.",
+                "params": {
+                    "min": null,
+                    "value": "6",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é o autor principal do post (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor principal do post pode gerenciar os coautores.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{post_id}/authors")]
+async fn patch_post_authors(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    post_id: web::Path<String>,
+    body: web::Json<PatchPostAuthorsDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match patch_post_authors_service(
+        pg_pool,
+        post_id,
+        token.claims.sub,
+        body.into_inner(),
+        tenant_id,
+    )
+    .await
+    {
+        Ok(authors) => {
+            invalidate_excerpts_service(&redis_pool).await;
+            etag_invalidate_route(&redis_pool, "post").await;
+            HttpResponse::Ok().json(authors)
+        }
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{slug}/embed-meta",
+    responses((
+        status = 200, description = "Metadados Open Graph e Twitter Card do post, em JSON ou como tags <meta> quando o header Accept pede text/html (OK)",
+        body = EmbedMetaDTO, content_type = "application/json", example = json ! ({
+            "ogTitle": "Rust e Actix Web",
+            "ogDescription": "Um resumo do post",
+            "ogImage": "https://cdn.navarro.blog/capa.png",
+            "ogUrl": "https://navarro.blog/post/rust-e-actix-web-0a6e8ac0",
+            "twitterCard": "summary_large_image",
+            "twitterSite": "@navarroblog"
+        })
+    ), (
+		status = 301, description = "Slug antigo do post, com o header Location apontando para os metadados do slug atual (Moved Permanently)",
+		headers((
+			"location" = String, description = "URL dos metadados do post com o slug atual"
+		))
+	), (
+		status = 404, description = "Post não encontrado (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post publicado com este slug.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{slug}/embed-meta")]
+async fn embed_meta(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    slug: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    let meta = match embed_meta_service(pg_pool, redis_pool, slug.into_inner(), tenant_id).await {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+
+    let wants_html = req
+        .headers()
+        .get(ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/html"));
+    match wants_html {
+        true => HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .body(embed_meta_html(&meta)),
+        false => HttpResponse::Ok().json(meta),
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{slug}/excerpt",
+    responses((
+        status = 200, description = "Resumo do post publicado, sem o corpo, para cards sociais e indexadores (OK)",
+        headers((
+			"cache-control" = String, description = "public, max-age=3600"
+		)),
+        body = ExcerptPostDTO, content_type = "application/json", example = json ! ({
+            "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "title": "Rust e Actix Web",
+            "slug": "rust-e-actix-web-0a6e8ac0",
+            "summary": "Um resumo do post",
+            "coverImageUrl": "https://cdn.navarro.blog/capa.png",
+            "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "publishedAt": "2024-06-18 22:03:54.053147 UTC",
+            "tagNames": ["rust"],
+            "categoryNames": ["backend"],
+            "likeCount": 12,
+            "viewCount": 340,
+            "readingTime": 4
+        })
+    ), (
+		status = 404, description = "Post não encontrado (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post publicado com este slug.",
+                "params": {
+                    "min": null,
+                    "value": "rust-e-actix-web",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{slug}/excerpt")]
+async fn excerpt_post(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    slug: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match excerpt_post_service(pg_pool, redis_pool, slug.into_inner(), tenant_id).await {
+        Ok(excerpt) => HttpResponse::Ok()
+            .insert_header((CACHE_CONTROL, "public, max-age=3600"))
+            .json(excerpt),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/revisions/{revision_id}/diff",
+    security(("bearer_auth" = [])),
+    params(
+        ("compare_to" = Option<String>, Query, description = "Compara a revisão com o corpo atual do post (current, padrão) ou com a revisão anterior (prev)"),
+        ("granularity" = Option<String>, Query, description = "Compara por palavras (word, padrão) ou por caracteres (character)"),
+    ),
+    responses((
+        status = 200, description = "Trechos mantidos, inseridos e removidos entre as duas versões do corpo (OK)",
+        body = Vec<DiffChunkDTO>, content_type = "application/json", example = json ! ([
+            { "op": "equal", "text": "Criando APIs " },
+            { "op": "delete", "text": "rápidas" },
+            { "op": "insert", "text": "performáticas" },
+            { "op": "equal", "text": " com Rust" }
+        ])
+    ), (
+		status = 400, description = "Erro do usuário por id ou parâmetro inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "granularity": [{
+                "code": "regex",
+                "message": "A granularidade deve ser word ou character.",
+                "params": {
+                    "min": null,
+                    "value": "line",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é autor nem coautor do post (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas os autores do post podem ver suas revisões.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post ou revisão não encontrados (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "revision": [{
+                "code": "not found",
+                "message": "Não foi encontrada uma revisão deste post com este id.",
+                "params": {
+                    "min": null,
+                    "value": "4d5e6f7a-8b9c-4d0e-9f1a-2b3c4d5e6f7a",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{post_id}/revisions/{revision_id}/diff")]
+async fn revision_diff(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    path: web::Path<(String, String)>,
+    query_params: web::Query<RevisionDiffParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let (post_id, revision_id) = path.into_inner();
+    let post_id = match uuid_path_middleware(post_id, "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    let revision_id = match uuid_path_middleware(revision_id, "revision_id") {
+        Ok(revision_id) => revision_id,
+        Err(e) => return e,
+    };
+    match query_params.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match revision_diff_service(
+        pg_pool,
+        post_id,
+        revision_id,
+        token.claims.sub,
+        query_params.into_inner(),
+    )
+    .await
+    {
+        Ok(chunks) => HttpResponse::Ok().json(chunks),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/lock",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Post bloqueado para edição por 5 minutos, ou bloqueio renovado (OK)",
+        body = PostLockDTO, content_type = "application/json", example = json ! ({
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "lockedBy": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "lockedAt": "2024-06-18 22:03:54.053147 UTC",
+            "expiresAt": "2024-06-18 22:08:54.053147 UTC"
+        })
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "abc",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é autor do post nem administrador (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas os autores do post ou um administrador podem bloqueá-lo.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 423, description = "Outro usuário está editando o post (Locked)", body = PostLockedDTO,
+		content_type = "application/json", example = json ! ({
+            "lockedBy": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "lockedByName": "Victor Navarro",
+            "expiresAt": "2024-06-18 22:08:54.053147 UTC"
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{post_id}/lock")]
+async fn acquire_post_lock(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    let is_admin = token.claims.role == "admin";
+    match acquire_post_lock_service(pg_pool, post_id, token.claims.sub, is_admin).await {
+        Ok(lock) => HttpResponse::Ok().json(lock),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/lock",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Bloqueio do post liberado (No Content)"
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "abc",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "O usuário não é autor do post nem administrador (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas os autores do post ou um administrador podem bloqueá-lo.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 423, description = "Outro usuário está editando o post (Locked)", body = PostLockedDTO,
+		content_type = "application/json", example = json ! ({
+            "lockedBy": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+            "lockedByName": "Victor Navarro",
+            "expiresAt": "2024-06-18 22:08:54.053147 UTC"
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("{post_id}/lock")]
+async fn release_post_lock(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let post_id = match uuid_path_middleware(post_id.into_inner(), "post_id") {
+        Ok(post_id) => post_id,
+        Err(e) => return e,
+    };
+    let is_admin = token.claims.role == "admin";
+    match release_post_lock_service(pg_pool, post_id, token.claims.sub, is_admin).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{slug}",
+    responses((
+        status = 200, description = "Post publicado com este slug, incluindo a enquete e o sumário quando existirem e os posts anterior e seguinte da série (OK)",
+        body = PostDTO, content_type = "application/json", example = json ! ({
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web-0a6e8ac0",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "body": "Conteúdo do post",
+                "summary": "Um resumo do post",
+                "authorId": "7c1d9e2f-3b4a-4c5d-8e6f-9a0b1c2d3e4f",
+                "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                "publishedAt": "2024-06-18 22:03:54.053147 UTC",
+                "isSponsored": false,
+                "version": 3,
+                "language": "pt",
+                "canonicalUrl": null,
+                "wordCount": 412,
+                "characterCount": 2518,
+                "poll": {
+                    "id": "5b2f1c3d-7e8a-4b9c-8d0e-1f2a3b4c5d6e",
+                    "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                    "question": "Qual framework você prefere?",
+                    "endsAt": "2024-07-18 22:03:54 UTC",
+                    "closed": false,
+                    "options": [{
+                        "id": "9d8c7b6a-5f4e-4d3c-8b2a-1f0e9d8c7b6a",
+                        "text": "Actix Web",
+                        "position": 1,
+                        "voteCount": 12
+                    }]
+                },
+                "toc": [{
+                    "level": 2,
+                    "text": "Instalação",
+                    "anchor": "instalação"
+                }],
+                "prevInSeries": null,
+                "nextInSeries": {
+                    "title": "Rust e Actix Web: middlewares",
+                    "slug": "rust-e-actix-web-middlewares-5c3d2e1f"
+                }
+        }), headers((
+			"etag" = String, description = "Hash da versão atual do post, para ser enviado no header If-None-Match"
+		))
+    ), (
+		status = 304, description = "Post não modificado desde a versão do header If-None-Match (Not Modified)"
+	), (
+		status = 301, description = "Slug antigo do post, com o header Location apontando para o slug atual (Moved Permanently)",
+		headers((
+			"location" = String, description = "URL do post com o slug atual"
+		))
+	), (
+		status = 404, description = "Post não encontrado (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post publicado com este slug.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{slug}")]
+async fn detail_post(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    slug: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    let slug = slug.into_inner();
+    let etag_id = format!("{}:{}", tenant_id, slug);
+    match etag_middleware(&redis_pool, "post", &etag_id, &req).await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match detail_post_service(pg_pool, slug, tenant_id).await {
+        Ok(Ok(post)) => etag_response(&redis_pool, "post", &etag_id, &req, &post).await,
+        Ok(Err(redirect)) => HttpResponse::MovedPermanently()
+            .insert_header((LOCATION, format!("/post/{}", redirect.new_slug)))
+            .finish(),
+        Err(e) => e,
+    }
+}
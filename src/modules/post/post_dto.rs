@@ -1,3 +1,18 @@
+use super::post_providers::{
+    author_id_valid_provider, author_ids_valid_provider, cover_image_url_valid_provider,
+    filter_date_valid_provider, language_valid_provider, moderation_mode_valid_provider,
+    scheduled_at_valid_provider, sponsorship_url_valid_provider, validate_structured_data_provider,
+};
+use crate::modules::{
+    poll::poll_dtos::PollDTO, post_attachment::post_attachment_dtos::AttachmentDTO,
+};
+use crate::shared::structs::toc_entry::TocEntryDTO;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
 pub struct _Post<'a> {
     pub id: &'a str,
     pub title: &'a str,
@@ -6,3 +21,332 @@ pub struct _Post<'a> {
     pub created_at: &'a str,
     pub likes: i32,
 }
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostDTO {
+    pub id: String,
+    pub slug: Option<String>,
+    pub title: String,
+    pub subtitle: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub summary: Option<String>,
+    pub author_id: Option<String>,
+    pub created_at: String,
+    pub published_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sponsored_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sponsor_url: Option<String>,
+    pub is_sponsored: bool,
+    pub version: i32,
+    pub language: String,
+    pub canonical_url: Option<String>,
+    pub word_count: i32,
+    pub character_count: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<String>,
+    pub cover_image_url: Option<String>,
+    pub cover_image_blurhash: Option<String>,
+    pub code_languages: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollDTO>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<AttachmentDTO>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toc: Option<Vec<TocEntryDTO>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authors: Option<Vec<AuthorDTO>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structured_data: Option<serde_json::Value>,
+    /// The previous post of the series, filled only in the post detail and `null` for the first.
+    #[serde(default)]
+    pub prev_in_series: Option<PostLinkDTO>,
+    /// The next post of the series, filled only in the post detail and `null` for the last.
+    #[serde(default)]
+    pub next_in_series: Option<PostLinkDTO>,
+    /// The markdown mistakes found in the body, filled only when the post is updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<LintWarningDTO>>,
+}
+
+/// A likely mistake in the markdown of a post body. Warnings never block the post from being saved.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LintWarningDTO {
+    pub line: usize,
+    pub column: usize,
+    /// `unclosed_code_fence`, `empty_link_text`, `image_missing_alt` or `heading_level_skip`.
+    pub rule: String,
+    pub message: String,
+}
+
+/// A link to another post, as shown in the series navigation.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PostLinkDTO {
+    pub title: String,
+    pub slug: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct SponsorshipDTO {
+    #[validate(length(
+        min = 2,
+        max = 127,
+        message = "O nome do patrocinador deve ter entre 2 e 127 caracteres."
+    ))]
+    #[serde(default, alias = "sponsoredBy")]
+    pub sponsored_by: Option<String>,
+
+    #[validate(custom(function = "sponsorship_url_valid_provider"))]
+    #[serde(default, alias = "sponsorUrl")]
+    pub sponsor_url: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct CoverImageDTO {
+    #[validate(custom(function = "cover_image_url_valid_provider"))]
+    #[serde(alias = "coverImageUrl")]
+    pub cover_image_url: String,
+
+    /// The BlurHash of the image, computed by the client, shown while the cover loads.
+    #[validate(length(
+        min = 6,
+        max = 50,
+        message = "O blurhash da capa deve ter entre 6 e 50 caracteres."
+    ))]
+    #[serde(default, alias = "coverImageBlurhash")]
+    pub cover_image_blurhash: Option<String>,
+}
+
+static RE_SLUG: Lazy<Regex> = Lazy::new(|| Regex::new("^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct UpdatePostDTO {
+    #[validate(length(
+        min = 3,
+        max = 127,
+        message = "O título deve ter entre 3 e 127 caracteres."
+    ))]
+    pub title: String,
+
+    #[validate(length(max = 255, message = "O subtítulo deve ter no máximo 255 caracteres."))]
+    #[serde(default)]
+    pub subtitle: String,
+
+    #[validate(length(min = 10, message = "O corpo deve ter pelo menos 10 caracteres."))]
+    pub body: String,
+
+    #[validate(length(max = 500, message = "O resumo deve ter no máximo 500 caracteres."))]
+    #[serde(default)]
+    pub summary: Option<String>,
+
+    /// The `version` of the post read by the client before editing it.
+    pub version: i32,
+
+    #[validate(custom(function = "language_valid_provider"))]
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// The new slug of the post; the current one keeps redirecting to it.
+    #[validate(
+        length(min = 3, max = 127, message = "O slug deve ter entre 3 e 127 caracteres."),
+        regex(
+            path = *RE_SLUG,
+            message = "O slug deve conter apenas letras minúsculas, dígitos e hífens entre eles."
+        )
+    )]
+    #[serde(default)]
+    pub slug: Option<String>,
+
+    /// The original URL of the post when it was first published elsewhere.
+    #[validate(url(message = "A URL canônica deve ser um endereço válido."))]
+    #[serde(default, alias = "canonicalUrl")]
+    pub canonical_url: Option<String>,
+
+    /// When an unpublished post goes live, as an RFC 3339 timestamp in the future.
+    #[validate(custom(function = "scheduled_at_valid_provider"))]
+    #[serde(default, alias = "scheduledAt")]
+    pub scheduled_at: Option<String>,
+
+    /// Whether visitors without an account can comment; unchanged when omitted.
+    #[serde(default, alias = "allowAnonymousComments")]
+    pub allow_anonymous_comments: Option<bool>,
+
+    /// `auto_approve` to show new comments right away or `require_approval` to hold them until the
+    /// author approves them; unchanged when omitted.
+    #[validate(custom(function = "moderation_mode_valid_provider"))]
+    #[serde(default, alias = "moderationMode")]
+    pub moderation_mode: Option<String>,
+
+    /// A data table embedded in the post, as `{ "headers": [...], "rows": [[...], ...] }`.
+    #[validate(custom(function = "validate_structured_data_provider"))]
+    #[serde(default, alias = "structuredData")]
+    pub structured_data: Option<serde_json::Value>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+pub struct ListPostsParams {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+    pub sponsored: Option<bool>,
+    pub lang: Option<String>,
+    pub code_language: Option<String>,
+}
+
+/// The current slug of a post looked up by one of its old slugs.
+#[derive(Clone, Debug)]
+pub struct PostRedirectDTO {
+    pub new_slug: String,
+}
+
+/// The Open Graph and Twitter Card metadata of a post, used when sharing it.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedMetaDTO {
+    pub og_title: String,
+    pub og_description: String,
+    pub og_image: String,
+    pub og_url: String,
+    pub twitter_card: String,
+    pub twitter_site: String,
+}
+
+/// The lightweight view of a published post, without the body, used by social cards and external
+/// search indexes.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExcerptPostDTO {
+    pub id: String,
+    pub title: String,
+    pub slug: String,
+    pub summary: Option<String>,
+    pub cover_image_url: Option<String>,
+    pub author_id: Option<String>,
+    pub published_at: String,
+    pub tag_names: Vec<String>,
+    pub category_names: Vec<String>,
+    pub like_count: i64,
+    pub view_count: i64,
+    /// The estimated reading time in minutes.
+    pub reading_time: i32,
+}
+
+/// The number of posts published in a month, listed by the blog archive.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+pub struct ArchiveMonthDTO {
+    pub year: i32,
+    pub month: u8,
+    pub count: i64,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CopiedPostDTO {
+    pub post_id: String,
+}
+
+/// An author credited on a post: the `primary` one, who created it, or a `co-author`.
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorDTO {
+    pub user_id: String,
+    pub name: String,
+    pub username: Option<String>,
+    pub role: String,
+    pub added_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone, Default)]
+pub struct PatchPostAuthorsDTO {
+    #[validate(custom(function = "author_ids_valid_provider"))]
+    #[serde(default)]
+    pub add: Vec<String>,
+
+    #[validate(custom(function = "author_ids_valid_provider"))]
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+static RE_COMPARE_TO: Lazy<Regex> = Lazy::new(|| Regex::new("^(current|prev)$").unwrap());
+static RE_GRANULARITY: Lazy<Regex> = Lazy::new(|| Regex::new("^(word|character)$").unwrap());
+
+#[derive(ToSchema, Serialize, Deserialize, Validate)]
+pub struct RevisionDiffParams {
+    #[validate(regex(
+        path = *RE_COMPARE_TO,
+        message = "A comparação deve ser com a versão current ou prev."
+    ))]
+    pub compare_to: Option<String>,
+
+    #[validate(regex(
+        path = *RE_GRANULARITY,
+        message = "A granularidade deve ser word ou character."
+    ))]
+    pub granularity: Option<String>,
+}
+
+/// A run of text kept (`equal`), added (`insert`) or removed (`delete`) between two versions of
+/// a post body.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DiffChunkDTO {
+    pub op: String,
+    pub text: String,
+}
+
+/// The body of a post revision along with the bodies it can be compared to.
+#[derive(Clone, Debug)]
+pub struct RevisionBodiesDTO {
+    pub revision_body: String,
+    pub post_body: String,
+    /// The body of the revision right before it, `None` for the first one.
+    pub previous_body: Option<String>,
+}
+
+/// The editing lock of a post, held by `locked_by` until `expires_at`.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostLockDTO {
+    pub post_id: String,
+    pub locked_by: String,
+    pub locked_at: String,
+    pub expires_at: String,
+}
+
+/// The body of the `423 Locked` answered while another user holds the lock of the post.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostLockedDTO {
+    pub locked_by: String,
+    pub locked_by_name: String,
+    pub expires_at: String,
+}
+
+static RE_POST_STATUS: Lazy<Regex> =
+    Lazy::new(|| Regex::new("^(draft|published|deleted)$").unwrap());
+
+/// The filters of the admin listing of posts. Every filter is optional and they are combined with
+/// `AND`.
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone, Default)]
+pub struct PostFilterParams {
+    /// `draft` for the posts never published, `published` or `deleted`. Without it, every post
+    /// but the deleted ones is listed.
+    #[validate(regex(
+        path = *RE_POST_STATUS,
+        message = "O status do post deve ser draft, published ou deleted."
+    ))]
+    pub status: Option<String>,
+
+    #[validate(custom(function = "author_id_valid_provider"))]
+    pub author_id: Option<String>,
+
+    /// Only posts published at or after this RFC 3339 timestamp.
+    #[validate(custom(function = "filter_date_valid_provider"))]
+    pub published_after: Option<String>,
+
+    /// Only posts published before this RFC 3339 timestamp.
+    #[validate(custom(function = "filter_date_valid_provider"))]
+    pub published_before: Option<String>,
+
+    pub has_cover: Option<bool>,
+}
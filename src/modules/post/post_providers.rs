@@ -0,0 +1,367 @@
+use super::post_dto::LintWarningDTO;
+use crate::utils::error_construct::error_construct;
+use actix_web::HttpResponse;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+use std::{borrow::Cow, env, time::Duration};
+use validator::{ValidateUrl, ValidationError};
+
+const STOP_WORDS: [(&str, &[&str]); 5] = [
+    (
+        "pt",
+        &[
+            "de", "que", "não", "uma", "um", "para", "com", "os", "as", "é", "do", "da", "em",
+            "se", "mais", "como", "mas", "você",
+        ],
+    ),
+    (
+        "en",
+        &[
+            "the", "and", "is", "of", "to", "in", "that", "it", "with", "for", "this", "are",
+            "was", "not", "you", "be", "on", "have",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "las", "que", "de", "y", "es", "en", "un", "una", "por", "con",
+            "para", "del", "no", "pero", "muy",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "et", "est", "de", "des", "un", "une", "que", "pour", "dans", "pas",
+            "avec", "du", "sur", "vous", "ce",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "das", "und", "ist", "nicht", "ein", "eine", "zu", "mit", "den", "von",
+            "auf", "für", "sich", "auch", "ich", "es",
+        ],
+    ),
+];
+
+/// Two-letter codes accepted as the language of a post, from the comma separated
+/// `SUPPORTED_LANGUAGES` env var (default `pt,en,es,fr,de`). The first one is the fallback of
+/// [`detect_language_provider`].
+pub static SUPPORTED_LANGUAGES: Lazy<Vec<String>> = Lazy::new(|| {
+    let languages: Vec<String> = env::var("SUPPORTED_LANGUAGES")
+        .unwrap_or_default()
+        .split(',')
+        .map(|language| language.trim().to_lowercase())
+        .filter(|language| language.len() == 2)
+        .collect();
+    match languages.is_empty() {
+        true => ["pt", "en", "es", "fr", "de"].map(String::from).to_vec(),
+        false => languages,
+    }
+});
+
+/// Whether the cover image URLs are checked with [`image_url_provider`] before being set.
+pub static VALIDATE_IMAGE_URLS: Lazy<bool> = Lazy::new(|| {
+    env::var("VALIDATE_IMAGE_URLS")
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+});
+
+/// The `og:image` of the posts without a cover image, from the `DEFAULT_OG_IMAGE_URL` env var.
+pub static DEFAULT_OG_IMAGE_URL: Lazy<String> =
+    Lazy::new(|| env::var("DEFAULT_OG_IMAGE_URL").unwrap_or_default());
+
+/// The `twitter:site` handle of the blog, like `@navarroblog`, from the `TWITTER_SITE` env var.
+pub static TWITTER_SITE: Lazy<String> = Lazy::new(|| env::var("TWITTER_SITE").unwrap_or_default());
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap()
+});
+
+/// Checks that a sponsor URL is an absolute `https://` URL.
+pub fn sponsorship_url_valid_provider(url: &str) -> Result<(), ValidationError> {
+    if url.starts_with("https://") && url.validate_url() {
+        return Ok(());
+    }
+    Err(ValidationError::new("url").with_message(Cow::from(
+        "A URL do patrocinador deve ser um endereço HTTPS válido.",
+    )))
+}
+
+const MAX_STRUCTURED_DATA_HEADERS: usize = 10;
+const MAX_STRUCTURED_DATA_ROWS: usize = 100;
+
+fn structured_data_error(message: &'static str) -> ValidationError {
+    ValidationError::new("structured_data").with_message(Cow::from(message))
+}
+
+/// Checks that the structured data of a post is `null` or a table like
+/// `{ "headers": ["Linguagem", "Ano"], "rows": [["Rust", "2015"]] }`, with up to 10 headers, up
+/// to 100 rows and one cell per header in every row.
+pub fn validate_structured_data_provider(data: &Value) -> Result<(), ValidationError> {
+    let table = match data {
+        Value::Null => return Ok(()),
+        Value::Object(table) => table,
+        _ => {
+            return Err(structured_data_error(
+                "Os dados estruturados devem ser um objeto com headers e rows.",
+            ))
+        }
+    };
+    if table.keys().any(|key| key != "headers" && key != "rows") {
+        return Err(structured_data_error(
+            "Os dados estruturados devem conter apenas headers e rows.",
+        ));
+    }
+
+    let headers = match table.get("headers").and_then(Value::as_array) {
+        Some(headers) if !headers.is_empty() && headers.iter().all(Value::is_string) => headers,
+        _ => {
+            return Err(structured_data_error(
+                "Os dados estruturados devem ter uma lista de headers com textos.",
+            ))
+        }
+    };
+    if headers.len() > MAX_STRUCTURED_DATA_HEADERS {
+        return Err(structured_data_error(
+            "Os dados estruturados devem ter no máximo 10 headers.",
+        ));
+    }
+
+    let rows = match table.get("rows").and_then(Value::as_array) {
+        Some(rows) => rows,
+        _ => {
+            return Err(structured_data_error(
+                "Os dados estruturados devem ter uma lista de rows.",
+            ))
+        }
+    };
+    if rows.len() > MAX_STRUCTURED_DATA_ROWS {
+        return Err(structured_data_error(
+            "Os dados estruturados devem ter no máximo 100 rows.",
+        ));
+    }
+    let valid_row = |row: &Value| {
+        row.as_array()
+            .is_some_and(|cells| cells.len() == headers.len() && cells.iter().all(Value::is_string))
+    };
+    if !rows.iter().all(valid_row) {
+        return Err(structured_data_error(
+            "Cada row dos dados estruturados deve ter um texto para cada header.",
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that the scheduled publication date is an RFC 3339 timestamp in the future.
+pub fn scheduled_at_valid_provider(scheduled_at: &str) -> Result<(), ValidationError> {
+    match DateTime::parse_from_rfc3339(scheduled_at) {
+        Ok(scheduled_at) if scheduled_at > Utc::now() => Ok(()),
+        Ok(_) => Err(ValidationError::new("scheduled_at").with_message(Cow::from(
+            "A data de publicação agendada deve estar no futuro.",
+        ))),
+        Err(_) => Err(ValidationError::new("scheduled_at").with_message(Cow::from(
+            "A data de publicação agendada deve estar no formato RFC 3339.",
+        ))),
+    }
+}
+
+/// Checks that a date filter of the admin listing of posts is an RFC 3339 timestamp.
+pub fn filter_date_valid_provider(date: &str) -> Result<(), ValidationError> {
+    if DateTime::parse_from_rfc3339(date).is_ok() {
+        return Ok(());
+    }
+    Err(ValidationError::new("date")
+        .with_message(Cow::from("A data deve estar no formato RFC 3339.")))
+}
+
+/// Checks that the author filter of the admin listing of posts is a valid UUID.
+pub fn author_id_valid_provider(author_id: &str) -> Result<(), ValidationError> {
+    if uuid::Uuid::parse_str(author_id).is_ok() {
+        return Ok(());
+    }
+    Err(ValidationError::new("author_id")
+        .with_message(Cow::from("O id do autor deve ser um UUID válido.")))
+}
+
+/// Checks that every user id sent to add or remove a co-author is a valid UUID.
+pub fn author_ids_valid_provider(ids: &[String]) -> Result<(), ValidationError> {
+    if ids.iter().all(|id| uuid::Uuid::parse_str(id).is_ok()) {
+        return Ok(());
+    }
+    Err(ValidationError::new("authors").with_message(Cow::from(
+        "Todos os ids de usuário devem ser UUIDs válidos.",
+    )))
+}
+
+/// Checks that the language is one of the `SUPPORTED_LANGUAGES`.
+pub fn language_valid_provider(language: &str) -> Result<(), ValidationError> {
+    if SUPPORTED_LANGUAGES
+        .iter()
+        .any(|supported| supported == language)
+    {
+        return Ok(());
+    }
+    Err(
+        ValidationError::new("language").with_message(Cow::from(format!(
+            "O idioma deve ser um destes: {}.",
+            SUPPORTED_LANGUAGES.join(", ")
+        ))),
+    )
+}
+
+const MODERATION_MODES: [&str; 2] = ["auto_approve", "require_approval"];
+
+pub fn moderation_mode_valid_provider(moderation_mode: &str) -> Result<(), ValidationError> {
+    if MODERATION_MODES.contains(&moderation_mode) {
+        return Ok(());
+    }
+    Err(
+        ValidationError::new("moderation_mode").with_message(Cow::from(
+            "O modo de moderação deve ser auto_approve ou require_approval.",
+        )),
+    )
+}
+
+static RE_LINT_CODE_FENCE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[ ]{0,3}```").unwrap());
+static RE_LINT_HEADING: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[ ]{0,3}(#{1,6})(?:\s|$)").unwrap());
+static RE_LINT_EMPTY_LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"(!?)\[\s*\]\([^)]*\)").unwrap());
+
+fn lint_warning(line: usize, column: usize, rule: &str, message: String) -> LintWarningDTO {
+    LintWarningDTO {
+        line,
+        column,
+        rule: rule.to_string(),
+        message,
+    }
+}
+
+/// Looks for common mistakes in the markdown of a post body, ordered by their position, with
+/// 1-based lines and columns counted in characters.
+///
+/// The rules are a ` ``` ` code fence that is never closed, links and images with an empty text,
+/// and headings that skip a level after the previous heading, like `###` right after `#`. Nothing
+/// inside fenced code blocks is checked.
+pub fn lint_markdown_provider(body: &str) -> Vec<LintWarningDTO> {
+    let mut warnings = Vec::new();
+    let mut open_fence: Option<(usize, usize)> = None;
+    let mut previous_level: Option<usize> = None;
+    for (index, line) in body.lines().enumerate() {
+        let line_number = index + 1;
+        if RE_LINT_CODE_FENCE.is_match(line) {
+            open_fence = match open_fence {
+                Some(_) => None,
+                None => Some((line_number, line.find("```").unwrap_or_default() + 1)),
+            };
+            continue;
+        }
+        if open_fence.is_some() {
+            continue;
+        }
+
+        if let Some(capture) = RE_LINT_HEADING.captures(line) {
+            let hashes = capture.get(1).unwrap();
+            let level = hashes.len();
+            if let Some(previous) = previous_level.filter(|previous| level > previous + 1) {
+                warnings.push(lint_warning(
+                    line_number,
+                    hashes.start() + 1,
+                    "heading_level_skip",
+                    format!(
+                        "O título de nível {} vem depois de um título de nível {}, pulando níveis.",
+                        level, previous
+                    ),
+                ));
+            }
+            previous_level = Some(level);
+        }
+
+        for capture in RE_LINT_EMPTY_LINK.captures_iter(line) {
+            let column = line[..capture.get(0).unwrap().start()].chars().count() + 1;
+            let (rule, message) = match &capture[1] {
+                "!" => ("image_missing_alt", "A imagem não tem texto alternativo."),
+                _ => ("empty_link_text", "O link não tem texto."),
+            };
+            warnings.push(lint_warning(line_number, column, rule, message.to_string()));
+        }
+    }
+
+    if let Some((line, column)) = open_fence {
+        warnings.push(lint_warning(
+            line,
+            column,
+            "unclosed_code_fence",
+            String::from("O bloco de código aberto nesta linha não foi fechado."),
+        ));
+    }
+    warnings.sort_by_key(|warning| (warning.line, warning.column));
+    warnings
+}
+
+/// Guesses the language of a text by counting the stop-words of each supported language.
+///
+/// Ties go to the language listed first in `SUPPORTED_LANGUAGES`, which is also returned when no
+/// stop-word is found.
+pub fn detect_language_provider(body: &str) -> String {
+    let words: Vec<String> = body
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect();
+
+    let mut best: (&str, usize) = (SUPPORTED_LANGUAGES[0].as_str(), 0);
+    for language in SUPPORTED_LANGUAGES.iter() {
+        let stop_words = match STOP_WORDS.iter().find(|(code, _)| code == language) {
+            Some((_, stop_words)) => stop_words,
+            None => continue,
+        };
+        let count = words
+            .iter()
+            .filter(|word| stop_words.contains(&word.as_str()))
+            .count();
+        if count > best.1 {
+            best = (language, count);
+        }
+    }
+    String::from(best.0)
+}
+
+/// Checks that a cover image URL is an absolute `https://` URL.
+pub fn cover_image_url_valid_provider(url: &str) -> Result<(), ValidationError> {
+    if url.starts_with("https://") && url.validate_url() {
+        return Ok(());
+    }
+    Err(ValidationError::new("url").with_message(Cow::from(
+        "A URL da capa deve ser um endereço HTTPS válido.",
+    )))
+}
+
+/// Makes a `HEAD` request to the URL, which must answer with a 2xx status and an `image/*`
+/// `Content-Type`.
+pub async fn image_url_provider(url: &str) -> Result<(), HttpResponse> {
+    let is_image = match HTTP_CLIENT.head(url).send().await {
+        Ok(resp) if resp.status().is_success() => resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .is_some_and(|content_type| content_type.trim().to_lowercase().starts_with("image/")),
+        _ => false,
+    };
+    if is_image {
+        return Ok(());
+    }
+    Err(HttpResponse::BadRequest().json(error_construct(
+        String::from("cover_image_url"),
+        String::from("bad request"),
+        String::from("A URL da capa deve apontar para uma imagem acessível."),
+        Some(String::from(url)),
+        None,
+        None,
+    )))
+}
@@ -0,0 +1,85 @@
+use super::{
+    post_repositories::{
+        expire_post_locks_repository, publish_post_repository, scheduled_posts_repository,
+    },
+    post_services::invalidate_archive_service,
+};
+use crate::modules::{
+    feed::feed_services::invalidate_sitemap_service,
+    follower::follower_services::invalidate_activity_feeds_service,
+    webhook::{webhook_dtos::POST_PUBLISHED_EVENT, webhook_services::fire_webhook_service},
+};
+use actix_web::{web::Data, HttpResponse};
+use deadpool_postgres::Pool;
+use std::time::Duration;
+
+const SCHEDULER_INTERVAL_SECS: u64 = 60;
+const POST_LOCKS_EXPIRATION_INTERVAL_SECS: u64 = 60;
+
+/// Publishes the posts whose `scheduled_at` has arrived, returning the ids of the published ones.
+pub async fn publish_scheduled_posts(pg_pool: Pool) -> Result<Vec<uuid::Uuid>, HttpResponse> {
+    let pg_pool = Data::new(pg_pool);
+    let post_ids = match scheduled_posts_repository(pg_pool.clone()).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut published = Vec::with_capacity(post_ids.len());
+    for post_id in post_ids {
+        match publish_post_repository(pg_pool.clone(), post_id).await {
+            Ok(true) => published.push(post_id),
+            Ok(false) => (),
+            Err(e) => return Err(e),
+        };
+    }
+    Ok(published)
+}
+
+/// Publishes the scheduled posts every minute, refreshing the sitemap and firing the
+/// `post.published` webhooks of each one.
+pub async fn post_scheduler_job(pool_async: Pool, redis_pool: deadpool_redis::Pool) {
+    let mut interval = tokio::time::interval(Duration::from_secs(SCHEDULER_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        let post_ids = match publish_scheduled_posts(pool_async.clone()).await {
+            Ok(x) => x,
+            Err(e) => {
+                log::warn!(
+                    "Failed to publish the scheduled posts: status {}",
+                    e.status()
+                );
+                continue;
+            }
+        };
+        if post_ids.is_empty() {
+            continue;
+        }
+
+        invalidate_sitemap_service(&redis_pool).await;
+        invalidate_archive_service(&redis_pool).await;
+        for post_id in post_ids {
+            invalidate_activity_feeds_service(Data::new(pool_async.clone()), &redis_pool, post_id)
+                .await;
+            let webhook_data = serde_json::json!({ "postId": post_id.to_string() });
+            let _ = fire_webhook_service(
+                Data::new(pool_async.clone()),
+                POST_PUBLISHED_EVENT,
+                webhook_data,
+            )
+            .await;
+        }
+    }
+}
+
+/// Deletes the expired post locks every minute. The locks already stop being enforced when they
+/// expire, this only keeps the table small.
+pub async fn post_locks_expiration_job(pool_async: Pool) {
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(POST_LOCKS_EXPIRATION_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+        if let Err(e) = expire_post_locks_repository(Data::new(pool_async.clone())).await {
+            log::warn!("Failed to expire the post locks: status {}", e.status());
+        }
+    }
+}
@@ -0,0 +1,1186 @@
+use super::post_dto::{
+    ArchiveMonthDTO, AuthorDTO, ExcerptPostDTO, PostDTO, PostFilterParams, PostLinkDTO,
+    PostLockDTO, PostLockedDTO, PostRedirectDTO, RevisionBodiesDTO, UpdatePostDTO,
+};
+use crate::{
+    shared::{
+        exceptions::custom_error_to_io_error_kind::{custom_error_to_io_error_kind, CustomError},
+        parsers::{
+            code_languages_parser::extract_code_languages,
+            text_counts_parser::count_words_and_characters,
+        },
+        structs::{cursor_params::CursorPage, page_params::Page},
+    },
+    utils::{
+        cursor::{encode_cursor, CursorPosition},
+        error_construct::error_construct,
+        query_constructor_executor::query_executor,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
+use tokio_postgres::types::ToSql;
+
+const LIST_POSTS_SQL: &str = "
+    SELECT id, slug, title, subtitle, NULL::TEXT AS body, summary, author_id, created_at,
+        published_at, sponsored_by, sponsor_url, version, language, canonical_url,
+        word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+        code_languages
+    FROM posts
+    WHERE published_at IS NOT NULL AND published_at <= NOW() AND deleted_at IS NULL
+        AND ($1::BOOL IS NULL OR (sponsored_by IS NOT NULL) = $1)
+        AND ($5::TEXT IS NULL OR language = $5)
+        AND ($6::TEXT IS NULL OR code_languages @> ARRAY[$6::TEXT])
+        AND ($2::TIMESTAMPTZ IS NULL OR (created_at, id) < ($2, $3))
+        AND tenant_id = $7
+    ORDER BY created_at DESC, id DESC
+    LIMIT $4
+";
+
+const TRENDING_POSTS_SQL: &str = "
+    SELECT id, slug, title, subtitle, NULL::TEXT AS body, summary, author_id, created_at,
+        published_at, sponsored_by, sponsor_url, version, language, canonical_url,
+        word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+        code_languages
+    FROM (
+        SELECT p.*,
+            ((SELECT COUNT(*) FROM users_posts_likes l WHERE l.post_id = p.id) * 3
+                + (SELECT COUNT(*) FROM comments c WHERE c.post_id = p.id AND c.deleted_at IS NULL
+                    AND c.approved_at IS NOT NULL) * 2
+                + (SELECT COUNT(*) FROM post_views v WHERE v.post_id = p.id))::FLOAT8
+            / POWER(EXTRACT(EPOCH FROM NOW() - p.published_at)::FLOAT8 / 3600 + 2, 1.8) AS score
+        FROM posts p
+        WHERE p.published_at IS NOT NULL AND p.published_at <= NOW()
+            AND p.published_at > NOW() - INTERVAL '30 days' AND p.deleted_at IS NULL
+            AND p.tenant_id = $2
+    ) ranked
+    ORDER BY score DESC, published_at DESC, id DESC
+    LIMIT $1
+";
+
+const ARCHIVE_SQL: &str = "
+    SELECT date_trunc('month', published_at AT TIME ZONE 'UTC') AS month, COUNT(*) AS count
+    FROM posts
+    WHERE published_at IS NOT NULL AND published_at <= NOW() AND deleted_at IS NULL
+        AND tenant_id = $1
+    GROUP BY 1
+    ORDER BY 1 DESC
+";
+
+const ARCHIVE_MONTH_POSTS_SQL: &str = "
+    SELECT id, slug, title, subtitle, NULL::TEXT AS body, summary, author_id, created_at,
+        published_at, sponsored_by, sponsor_url, version, language, canonical_url,
+        word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+        code_languages
+    FROM posts
+    WHERE published_at >= $1 AND published_at < $2 AND published_at <= NOW()
+        AND deleted_at IS NULL
+        AND ($3::TIMESTAMPTZ IS NULL OR (published_at, id) < ($3, $4))
+        AND tenant_id = $6
+    ORDER BY published_at DESC, id DESC
+    LIMIT $5
+";
+
+const UPDATE_POST_SQL: &str = "
+    WITH old AS (
+        SELECT slug, title, body, version, tenant_id FROM posts
+        WHERE id = $1 AND version = $2 AND deleted_at IS NULL
+    ), updated AS (
+        UPDATE posts SET title = $3, subtitle = $4, body = $5, summary = $6, updated_at = NOW(),
+            language = COALESCE($7, language), slug = COALESCE($8, slug), canonical_url = $9,
+            word_count = $10, character_count = $11, scheduled_at = $12, code_languages = $13,
+            allow_anonymous_comments = COALESCE($14, allow_anonymous_comments),
+            structured_data = $15::TEXT::JSONB,
+            moderation_mode = COALESCE($16, moderation_mode), version = version + 1
+        WHERE id = $1 AND version = $2 AND deleted_at IS NULL
+        RETURNING id, slug, title, subtitle, body, summary, author_id, created_at, published_at,
+            sponsored_by, sponsor_url, version, language, canonical_url,
+            word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+            code_languages, structured_data::TEXT AS structured_data
+    ), claimed AS (
+        DELETE FROM post_slug_redirects r USING old
+        WHERE r.old_slug = $8 AND r.tenant_id = old.tenant_id AND EXISTS (SELECT 1 FROM updated)
+    ), redirected AS (
+        INSERT INTO post_slug_redirects (old_slug, post_id, tenant_id, created_at)
+        SELECT old.slug, $1, old.tenant_id, NOW() FROM old, updated
+        WHERE old.slug IS NOT NULL AND old.slug <> updated.slug
+        ON CONFLICT (tenant_id, old_slug) DO UPDATE SET post_id = EXCLUDED.post_id, created_at = NOW()
+    ), revision AS (
+        INSERT INTO post_revisions (id, post_id, version, title, body, created_at)
+        SELECT gen_random_uuid(), $1, old.version, old.title, old.body, NOW() FROM old, updated
+    )
+    SELECT * FROM updated
+";
+
+const REVISION_BODIES_SQL: &str = "
+    SELECT revision.body AS revision_body, posts.body AS post_body,
+        (SELECT previous.body FROM post_revisions previous
+        WHERE previous.post_id = revision.post_id AND previous.version < revision.version
+        ORDER BY previous.version DESC LIMIT 1) AS previous_body
+    FROM post_revisions revision
+    JOIN posts ON posts.id = revision.post_id AND posts.deleted_at IS NULL
+    WHERE revision.id = $2 AND revision.post_id = $1
+";
+
+const ACQUIRE_POST_LOCK_SQL: &str = "
+    INSERT INTO post_locks (post_id, locked_by, locked_at, expires_at)
+    VALUES ($1, $2, NOW(), NOW() + make_interval(secs => $3))
+    ON CONFLICT (post_id) DO UPDATE
+    SET locked_by = EXCLUDED.locked_by, locked_at = EXCLUDED.locked_at,
+        expires_at = EXCLUDED.expires_at
+    WHERE post_locks.locked_by = EXCLUDED.locked_by OR post_locks.expires_at < NOW()
+    RETURNING post_id, locked_by, locked_at, expires_at
+";
+
+const ACTIVE_POST_LOCK_SQL: &str = "
+    SELECT post_locks.locked_by, users.name AS locked_by_name, post_locks.expires_at
+    FROM post_locks
+    JOIN users ON users.id = post_locks.locked_by
+    WHERE post_locks.post_id = $1 AND post_locks.expires_at >= NOW()
+";
+
+const RELEASE_POST_LOCK_SQL: &str = "
+    DELETE FROM post_locks
+    WHERE post_id = $1 AND (locked_by = $2 OR $3 OR expires_at < NOW())
+    RETURNING post_id
+";
+
+const EXPIRE_POST_LOCKS_SQL: &str =
+    "DELETE FROM post_locks WHERE expires_at < NOW() RETURNING post_id";
+
+const DETAIL_POST_BY_SLUG_SQL: &str = "
+    SELECT id, slug, title, subtitle, body, summary, author_id, created_at, published_at,
+        sponsored_by, sponsor_url, version, language, canonical_url,
+        word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+        code_languages, structured_data::TEXT AS structured_data
+    FROM posts
+    WHERE slug = $1 AND published_at IS NOT NULL AND published_at <= NOW() AND deleted_at IS NULL
+        AND tenant_id = $2
+";
+
+/// The reading time assumes 200 words per minute, rounded up to at least one minute.
+const EXCERPT_POST_SQL: &str = "
+    SELECT p.id, p.title, p.slug, p.summary, p.cover_image_url, p.author_id, p.published_at,
+        ARRAY(
+            SELECT t.name::TEXT FROM posts_tags pt JOIN tags t ON t.id = pt.tag_id
+            WHERE pt.post_id = p.id ORDER BY t.name
+        ) AS tag_names,
+        ARRAY(
+            SELECT c.name::TEXT FROM posts_categories pc JOIN categories c ON c.id = pc.category_id
+            WHERE pc.post_id = p.id ORDER BY c.name
+        ) AS category_names,
+        (SELECT COUNT(*) FROM users_posts_likes l WHERE l.post_id = p.id) AS like_count,
+        (SELECT COUNT(*) FROM post_views v WHERE v.post_id = p.id) AS view_count,
+        GREATEST(1, CEIL(p.word_count / 200.0))::INT AS reading_time
+    FROM posts p
+    WHERE p.slug = $1 AND p.published_at IS NOT NULL AND p.published_at <= NOW()
+        AND p.deleted_at IS NULL AND p.tenant_id = $2
+";
+
+const POST_SLUG_REDIRECT_SQL: &str = "
+    SELECT p.slug FROM post_slug_redirects r
+    JOIN posts p ON p.id = r.post_id
+    WHERE r.old_slug = $1 AND p.slug IS NOT NULL AND p.deleted_at IS NULL AND p.tenant_id = $2
+";
+
+const POST_SLUG_TAKEN_SQL: &str = "
+    WITH post AS (SELECT tenant_id FROM posts WHERE id = $2)
+    SELECT EXISTS (
+        SELECT 1 FROM posts p, post WHERE p.slug = $1 AND p.id <> $2 AND p.tenant_id = post.tenant_id
+    ) OR EXISTS (
+        SELECT 1 FROM post_slug_redirects r, post
+        WHERE r.old_slug = $1 AND r.post_id <> $2 AND r.tenant_id = post.tenant_id
+    ) AS taken
+";
+
+const COPY_SOURCE_SQL: &str = "
+    SELECT author_id, slug, title FROM posts WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL
+";
+
+const COPY_SLUGS_TAKEN_SQL: &str = "
+    SELECT slug FROM posts WHERE tenant_id = $2 AND (slug = $1 OR slug LIKE $1 || '-%')
+    UNION
+    SELECT old_slug FROM post_slug_redirects
+    WHERE tenant_id = $2 AND (old_slug = $1 OR old_slug LIKE $1 || '-%')
+";
+
+const COPY_POST_SQL: &str = "
+    WITH copied AS (
+        INSERT INTO posts (
+            id, title, subtitle, body, summary, created_at, published_at, author_id, slug,
+            language, word_count, character_count, code_languages, cover_image_url,
+            cover_image_blurhash, tenant_id
+        )
+        SELECT $2, title, subtitle, body, summary, NOW(), NULL, $3, $4,
+            language, word_count, character_count, code_languages, cover_image_url,
+            cover_image_blurhash, tenant_id
+        FROM posts WHERE id = $1 AND tenant_id = $5 AND deleted_at IS NULL
+        RETURNING id
+    ), primary_author AS (
+        INSERT INTO post_authors (post_id, user_id, role, added_at)
+        SELECT id, $3, 'primary', NOW() FROM copied
+    ), categories AS (
+        INSERT INTO posts_categories (post_id, category_id)
+        SELECT copied.id, pc.category_id FROM copied, posts_categories pc WHERE pc.post_id = $1
+    ), tags AS (
+        INSERT INTO posts_tags (post_id, tag_id)
+        SELECT copied.id, pt.tag_id FROM copied, posts_tags pt WHERE pt.post_id = $1
+    )
+    SELECT id FROM copied
+";
+
+const LIST_POST_AUTHORS_SQL: &str = "
+    SELECT pa.user_id, u.name, u.username, pa.role, pa.added_at
+    FROM post_authors pa JOIN users u ON u.id = pa.user_id
+    WHERE pa.post_id = $1
+    ORDER BY pa.role = 'primary' DESC, pa.added_at, pa.user_id
+";
+
+const IS_POST_AUTHOR_SQL: &str =
+    "SELECT EXISTS (SELECT 1 FROM post_authors WHERE post_id = $1 AND user_id = $2) AS is_author";
+
+const EXISTING_USER_IDS_SQL: &str =
+    "SELECT id FROM users WHERE id = ANY($1) AND tenant_id = $2 AND deleted_at IS NULL";
+
+const PATCH_POST_AUTHORS_SQL: &str = "
+    WITH post AS (
+        SELECT id FROM posts WHERE id = $1 AND tenant_id = $4
+    ), removed AS (
+        DELETE FROM post_authors
+        WHERE post_id IN (SELECT id FROM post) AND role <> 'primary' AND user_id = ANY($3)
+    )
+    INSERT INTO post_authors (post_id, user_id, role, added_at)
+    SELECT post.id, u.id, 'co-author', NOW()
+    FROM post, users u
+    WHERE u.id = ANY($2) AND u.tenant_id = $4 AND u.deleted_at IS NULL
+    ON CONFLICT (post_id, user_id) DO NOTHING
+";
+
+const DUPLICATE_POST_SQL: &str = "
+    SELECT id FROM posts
+    WHERE author_id = $1 AND content_hash = $2 AND deleted_at IS NULL
+        AND ($3::UUID IS NULL OR id <> $3)
+    LIMIT 1
+";
+
+const POST_BODY_SQL: &str = "SELECT body FROM posts WHERE id = $1 AND deleted_at IS NULL";
+
+const UPDATE_POST_COUNTS_SQL: &str = "
+    UPDATE posts SET word_count = $2, character_count = $3
+    WHERE id = $1 AND deleted_at IS NULL
+    RETURNING id, slug, title, subtitle, body, summary, author_id, created_at, published_at,
+        sponsored_by, sponsor_url, version, language, canonical_url,
+        word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+        code_languages
+";
+
+const SCHEDULED_POSTS_SQL: &str = "
+    SELECT id FROM posts
+    WHERE scheduled_at <= NOW() AND published_at IS NULL AND deleted_at IS NULL
+";
+
+const PUBLISH_POST_SQL: &str = "
+    UPDATE posts SET published_at = NOW()
+    WHERE id = $1 AND published_at IS NULL AND deleted_at IS NULL
+    RETURNING id
+";
+
+const PUT_SPONSORSHIP_SQL: &str = "
+    WITH updated AS (
+        UPDATE posts SET sponsored_by = $2, sponsor_url = $3, updated_at = NOW()
+        WHERE id = $1 AND ($4 OR author_id = $5)
+        RETURNING id, slug, title, subtitle, body, summary, author_id, created_at,
+            published_at, sponsored_by, sponsor_url, version, language, canonical_url,
+            word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+            code_languages
+    )
+    SELECT EXISTS (SELECT 1 FROM posts WHERE id = $1) AS post_exists, updated.*
+    FROM (SELECT 1) AS one LEFT JOIN updated ON TRUE
+";
+
+const PUT_COVER_IMAGE_SQL: &str = "
+    WITH updated AS (
+        UPDATE posts SET cover_image_url = $2, cover_image_blurhash = $3, updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NULL AND (
+            $4 OR author_id = $5
+            OR EXISTS (SELECT 1 FROM post_authors WHERE post_id = $1 AND user_id = $5)
+        )
+        RETURNING id, slug, title, subtitle, body, summary, author_id, created_at,
+            published_at, sponsored_by, sponsor_url, version, language, canonical_url,
+            word_count, character_count, scheduled_at, cover_image_url, cover_image_blurhash,
+            code_languages
+    )
+    SELECT EXISTS (SELECT 1 FROM posts WHERE id = $1 AND deleted_at IS NULL) AS post_exists,
+        updated.*
+    FROM (SELECT 1) AS one LEFT JOIN updated ON TRUE
+";
+
+const SERIES_POSITION_SQL: &str = "
+    SELECT series_id, position FROM series_posts WHERE post_id = $1
+    ORDER BY series_id
+    LIMIT 1
+";
+
+const SERIES_POST_LINK_SQL: &str = "
+    SELECT p.title, COALESCE(p.slug, p.id::TEXT) AS slug
+    FROM series_posts sp JOIN posts p ON p.id = sp.post_id
+    WHERE sp.series_id = $1 AND sp.position = $2
+        AND p.published_at IS NOT NULL AND p.published_at <= NOW() AND p.deleted_at IS NULL
+";
+
+fn post_not_found(post_id: uuid::Uuid) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("post"),
+        String::from("not found"),
+        String::from("Não foi encontrado um post com este id."),
+        Some(post_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+pub fn post_dto_constructor(row: &postgres::Row) -> PostDTO {
+    let id: uuid::Uuid = row.get("id");
+    let author_id: Option<uuid::Uuid> = row.get("author_id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    let published_at: Option<DateTime<Utc>> = row.get("published_at");
+    let scheduled_at: Option<DateTime<Utc>> = row.get("scheduled_at");
+    let sponsored_by: Option<String> = row.get("sponsored_by");
+
+    PostDTO {
+        id: id.to_string(),
+        slug: row.get("slug"),
+        title: row.get("title"),
+        subtitle: row.get("subtitle"),
+        body: row.get("body"),
+        summary: row.get("summary"),
+        author_id: author_id.map(|id| id.to_string()),
+        created_at: created_at.to_string(),
+        published_at: published_at.map(|dt| dt.to_string()),
+        is_sponsored: sponsored_by.is_some(),
+        sponsored_by,
+        sponsor_url: row.get("sponsor_url"),
+        version: row.get("version"),
+        language: row.get("language"),
+        canonical_url: row.get("canonical_url"),
+        word_count: row.get("word_count"),
+        character_count: row.get("character_count"),
+        scheduled_at: scheduled_at.map(|dt| dt.to_string()),
+        cover_image_url: row.get("cover_image_url"),
+        cover_image_blurhash: row.get("cover_image_blurhash"),
+        code_languages: row
+            .get::<_, Option<Vec<String>>>("code_languages")
+            .unwrap_or_default(),
+        poll: None,
+        attachments: None,
+        toc: None,
+        authors: None,
+        prev_in_series: None,
+        next_in_series: None,
+        warnings: None,
+        structured_data: row
+            .try_get::<_, Option<String>>("structured_data")
+            .ok()
+            .flatten()
+            .and_then(|data| serde_json::from_str(&data).ok()),
+    }
+}
+
+pub async fn list_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    sponsored: Option<bool>,
+    language: Option<String>,
+    code_language: Option<String>,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+    tenant_id: uuid::Uuid,
+) -> Result<CursorPage<PostDTO>, HttpResponse> {
+    let (cursor_created_at, cursor_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        LIST_POSTS_SQL,
+        &[
+            &sponsored,
+            &cursor_created_at,
+            &cursor_id,
+            &(limit + 1),
+            &language,
+            &code_language,
+            &tenant_id,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<PostDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        let post = post_dto_constructor(row);
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(row.get("created_at"), row.get("id")));
+        }
+        data.push(post);
+    }
+    Ok(CursorPage { data, next_cursor })
+}
+
+/// Returns the posts published in the last 30 days with the highest time-decayed engagement score:
+/// `(likes * 3 + comments * 2 + views) / (hours since published + 2) ^ 1.8`.
+/// Lists the posts of the tenant for the admin dashboard, narrowed by the filters and paginated
+/// by page number. The query is built from the filters sent, each one bound as a parameter.
+pub async fn admin_list_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    filter: PostFilterParams,
+    page: i64,
+    per_page: i64,
+    tenant_id: uuid::Uuid,
+) -> Result<Page<PostDTO>, HttpResponse> {
+    let mut params: Vec<Box<dyn ToSql + Sync>> = vec![Box::new(tenant_id)];
+    let mut conditions = vec![String::from("tenant_id = $1")];
+
+    conditions.push(String::from(match filter.status.as_deref() {
+        Some("draft") => "published_at IS NULL AND deleted_at IS NULL",
+        Some("published") => "published_at IS NOT NULL AND deleted_at IS NULL",
+        Some("deleted") => "deleted_at IS NOT NULL",
+        _ => "deleted_at IS NULL",
+    }));
+    if let Some(author_id) = filter.author_id {
+        params.push(Box::new(
+            uuid::Uuid::parse_str(&author_id).unwrap_or_default(),
+        ));
+        conditions.push(format!(
+            "(author_id = ${0} OR EXISTS (SELECT 1 FROM post_authors WHERE post_id = posts.id AND user_id = ${0}))",
+            params.len()
+        ));
+    }
+    for (published_at, operator) in [
+        (filter.published_after, ">="),
+        (filter.published_before, "<"),
+    ] {
+        let published_at = published_at
+            .as_deref()
+            .and_then(|published_at| DateTime::parse_from_rfc3339(published_at).ok());
+        if let Some(published_at) = published_at {
+            params.push(Box::new(published_at.with_timezone(&Utc)));
+            conditions.push(format!("published_at {} ${}", operator, params.len()));
+        }
+    }
+    if let Some(has_cover) = filter.has_cover {
+        params.push(Box::new(has_cover));
+        conditions.push(format!("(cover_image_url IS NOT NULL) = ${}", params.len()));
+    }
+
+    let mut count_builder = sql_builder::SqlBuilder::select_from("posts");
+    count_builder.field("COUNT(*) AS total");
+    let mut sql_builder = sql_builder::SqlBuilder::select_from("posts");
+    sql_builder.fields(&[
+        "id",
+        "slug",
+        "title",
+        "subtitle",
+        "NULL::TEXT AS body",
+        "summary",
+        "author_id",
+        "created_at",
+        "published_at",
+        "sponsored_by",
+        "sponsor_url",
+        "version",
+        "language",
+        "canonical_url",
+        "word_count",
+        "character_count",
+        "scheduled_at",
+        "cover_image_url",
+        "cover_image_blurhash",
+        "code_languages",
+    ]);
+    for condition in &conditions {
+        count_builder.and_where(condition);
+        sql_builder.and_where(condition);
+    }
+    sql_builder.order_by("created_at", true);
+    sql_builder.order_by("id", true);
+    sql_builder.limit(per_page);
+    sql_builder.offset((page - 1) * per_page);
+
+    let (count_sql, sql) = match (count_builder.sql(), sql_builder.sql()) {
+        (Ok(count_sql), Ok(sql)) => (count_sql, sql),
+        (Err(e), _) | (_, Err(e)) => {
+            return Err(custom_error_to_io_error_kind(CustomError::AnyhowError(e)))
+        }
+    };
+    let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|param| param.as_ref()).collect();
+    let total: i64 = match query_executor(pg_pool.clone(), &count_sql, &params).await {
+        Ok(rows) => rows[0].get("total"),
+        Err(e) => return Err(e),
+    };
+    let rows = match query_executor(pg_pool, &sql, &params).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(Page {
+        data: rows.iter().map(post_dto_constructor).collect(),
+        page,
+        per_page,
+        total,
+        total_pages: (total + per_page - 1) / per_page,
+    })
+}
+
+pub async fn trending_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    limit: i64,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<PostDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, TRENDING_POSTS_SQL, &[&limit, &tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.iter().map(post_dto_constructor).collect())
+}
+
+/// Returns the number of published posts per month, from the most recent month to the oldest.
+pub async fn archive_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<ArchiveMonthDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, ARCHIVE_SQL, &[&tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let month: NaiveDateTime = row.get("month");
+            ArchiveMonthDTO {
+                year: month.year(),
+                month: month.month() as u8,
+                count: row.get("count"),
+            }
+        })
+        .collect())
+}
+
+/// Returns a page of the posts published between `start` and `end`, the most recent first.
+pub async fn archive_month_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+    tenant_id: uuid::Uuid,
+) -> Result<CursorPage<PostDTO>, HttpResponse> {
+    let (cursor_published_at, cursor_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        ARCHIVE_MONTH_POSTS_SQL,
+        &[
+            &start,
+            &end,
+            &cursor_published_at,
+            &cursor_id,
+            &(limit + 1),
+            &tenant_id,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<PostDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(row.get("published_at"), row.get("id")));
+        }
+        data.push(post_dto_constructor(row));
+    }
+    Ok(CursorPage { data, next_cursor })
+}
+
+/// Returns the published post with the slug. When no post has it, the old slugs kept in
+/// `post_slug_redirects` are checked and the current slug of the post is returned instead.
+pub async fn detail_post_by_slug_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slug: String,
+    tenant_id: uuid::Uuid,
+) -> Result<Result<PostDTO, PostRedirectDTO>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool.clone(),
+        DETAIL_POST_BY_SLUG_SQL,
+        &[&slug, &tenant_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if let Some(row) = rows.first() {
+        return Ok(Ok(post_dto_constructor(row)));
+    }
+
+    let rows = match query_executor(pg_pool, POST_SLUG_REDIRECT_SQL, &[&slug, &tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    match rows.first() {
+        Some(row) => Ok(Err(PostRedirectDTO {
+            new_slug: row.get("slug"),
+        })),
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("post"),
+            String::from("not found"),
+            String::from("Não foi encontrado um post publicado com este slug."),
+            Some(slug),
+            None,
+            None,
+        ))),
+    }
+}
+
+/// Returns the excerpt of the published post with the slug, selecting everything but the body.
+pub async fn excerpt_post_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slug: String,
+    tenant_id: uuid::Uuid,
+) -> Result<ExcerptPostDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, EXCERPT_POST_SQL, &[&slug, &tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    match rows.first() {
+        Some(row) => {
+            let id: uuid::Uuid = row.get("id");
+            let author_id: Option<uuid::Uuid> = row.get("author_id");
+            let published_at: DateTime<Utc> = row.get("published_at");
+            Ok(ExcerptPostDTO {
+                id: id.to_string(),
+                title: row.get("title"),
+                slug: row.get("slug"),
+                summary: row.get("summary"),
+                cover_image_url: row.get("cover_image_url"),
+                author_id: author_id.map(|id| id.to_string()),
+                published_at: published_at.to_string(),
+                tag_names: row.get("tag_names"),
+                category_names: row.get("category_names"),
+                like_count: row.get("like_count"),
+                view_count: row.get("view_count"),
+                reading_time: row.get("reading_time"),
+            })
+        }
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("post"),
+            String::from("not found"),
+            String::from("Não foi encontrado um post publicado com este slug."),
+            Some(slug),
+            None,
+            None,
+        ))),
+    }
+}
+
+pub async fn put_sponsorship_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    sponsored_by: Option<String>,
+    sponsor_url: Option<String>,
+    actor_id: uuid::Uuid,
+    is_admin: bool,
+) -> Result<PostDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        PUT_SPONSORSHIP_SQL,
+        &[&post_id, &sponsored_by, &sponsor_url, &is_admin, &actor_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let post_exists: bool = rows[0].get("post_exists");
+    if !post_exists {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("post"),
+            String::from("not found"),
+            String::from("Não foi encontrado um post com este id."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    let updated_id: Option<uuid::Uuid> = rows[0].get("id");
+    if updated_id.is_none() {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("post"),
+            String::from("forbidden"),
+            String::from("Apenas o autor do post ou um administrador pode alterar o patrocínio."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        )));
+    }
+
+    Ok(post_dto_constructor(&rows[0]))
+}
+
+/// Returns whether the slug belongs, currently or as an old slug, to a post other than `post_id`.
+pub async fn post_slug_taken_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slug: &str,
+    post_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(pg_pool, POST_SLUG_TAKEN_SQL, &[&slug, &post_id]).await {
+        Ok(rows) => Ok(rows[0].get("taken")),
+        Err(e) => Err(e),
+    }
+}
+
+/// Updates the post only if it is still at `version`, returning `None` when another session
+/// changed it first.
+///
+/// When the slug changes, the old one is kept in `post_slug_redirects` so its links keep working,
+/// and the replaced title and body are kept in `post_revisions`.
+pub async fn update_post_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    version: i32,
+    body: UpdatePostDTO,
+) -> Result<Option<PostDTO>, HttpResponse> {
+    let (word_count, character_count) = count_words_and_characters(&body.body);
+    let code_languages = extract_code_languages(&body.body);
+    let scheduled_at = body
+        .scheduled_at
+        .as_deref()
+        .and_then(|scheduled_at| DateTime::parse_from_rfc3339(scheduled_at).ok())
+        .map(|scheduled_at| scheduled_at.with_timezone(&Utc));
+    let structured_data = body
+        .structured_data
+        .as_ref()
+        .filter(|data| !data.is_null())
+        .map(|data| data.to_string());
+    match query_executor(
+        pg_pool,
+        UPDATE_POST_SQL,
+        &[
+            &post_id,
+            &version,
+            &body.title,
+            &body.subtitle,
+            &body.body,
+            &body.summary,
+            &body.language,
+            &body.slug,
+            &body.canonical_url,
+            &word_count,
+            &character_count,
+            &scheduled_at,
+            &code_languages,
+            &body.allow_anonymous_comments,
+            &structured_data,
+            &body.moderation_mode,
+        ],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.first().map(post_dto_constructor)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the body of the revision, the current body of the post and the body of the previous
+/// revision, or `None` if the post has no revision with this id.
+pub async fn revision_bodies_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    revision_id: uuid::Uuid,
+) -> Result<Option<RevisionBodiesDTO>, HttpResponse> {
+    match query_executor(pg_pool, REVISION_BODIES_SQL, &[&post_id, &revision_id]).await {
+        Ok(rows) => Ok(rows.first().map(|row| RevisionBodiesDTO {
+            revision_body: row.get("revision_body"),
+            post_body: row.get("post_body"),
+            previous_body: row.get("previous_body"),
+        })),
+        Err(e) => Err(e),
+    }
+}
+
+/// Recomputes the word and character counts of the post from its current body.
+pub async fn recompute_post_counts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<PostDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool.clone(), POST_BODY_SQL, &[&post_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let body: String = match rows.first() {
+        Some(row) => row.get("body"),
+        None => return Err(post_not_found(post_id)),
+    };
+
+    let (word_count, character_count) = count_words_and_characters(&body);
+    let rows = match query_executor(
+        pg_pool,
+        UPDATE_POST_COUNTS_SQL,
+        &[&post_id, &word_count, &character_count],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match rows.first() {
+        Some(row) => Ok(post_dto_constructor(row)),
+        None => Err(post_not_found(post_id)),
+    }
+}
+
+/// Returns the ids of the unpublished posts whose `scheduled_at` has arrived.
+pub async fn scheduled_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<uuid::Uuid>, HttpResponse> {
+    match query_executor(pg_pool, SCHEDULED_POSTS_SQL, &[]).await {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get("id")).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Publishes the post now, returning `false` when it was already published or deleted.
+pub async fn publish_post_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(pg_pool, PUBLISH_POST_SQL, &[&post_id]).await {
+        Ok(rows) => Ok(!rows.is_empty()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Sets the cover image of the post, or removes it when both fields are `None`.
+///
+/// Only the author of the post or an admin can change it.
+pub async fn put_cover_image_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    cover_image_url: Option<String>,
+    cover_image_blurhash: Option<String>,
+    actor_id: uuid::Uuid,
+    is_admin: bool,
+) -> Result<PostDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        PUT_COVER_IMAGE_SQL,
+        &[
+            &post_id,
+            &cover_image_url,
+            &cover_image_blurhash,
+            &is_admin,
+            &actor_id,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let post_exists: bool = rows[0].get("post_exists");
+    if !post_exists {
+        return Err(post_not_found(post_id));
+    }
+    let updated_id: Option<uuid::Uuid> = rows[0].get("id");
+    if updated_id.is_none() {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("post"),
+            String::from("forbidden"),
+            String::from("Apenas os autores do post ou um administrador podem alterar a capa."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        )));
+    }
+
+    Ok(post_dto_constructor(&rows[0]))
+}
+
+/// Returns the author, the slug and the title of the post, or `None` if it does not exist in the
+/// tenant.
+pub async fn copy_source_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    tenant_id: uuid::Uuid,
+) -> Result<Option<(Option<uuid::Uuid>, Option<String>, String)>, HttpResponse> {
+    let rows = match query_executor(pg_pool, COPY_SOURCE_SQL, &[&post_id, &tenant_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .first()
+        .map(|row| (row.get("author_id"), row.get("slug"), row.get("title"))))
+}
+
+/// Returns the slugs of the tenant, current or old, that are `slug` or start with `{slug}-`.
+pub async fn copy_slugs_taken_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slug: &str,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<String>, HttpResponse> {
+    match query_executor(pg_pool, COPY_SLUGS_TAKEN_SQL, &[&slug, &tenant_id]).await {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get("slug")).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Duplicates the post as an unpublished one owned by `author_id`, with its categories and tags.
+pub async fn copy_post_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    author_id: uuid::Uuid,
+    slug: String,
+    tenant_id: uuid::Uuid,
+) -> Result<uuid::Uuid, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        COPY_POST_SQL,
+        &[
+            &post_id,
+            &uuid::Uuid::new_v4(),
+            &author_id,
+            &slug,
+            &tenant_id,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match rows.first() {
+        Some(row) => Ok(row.get("id")),
+        None => Err(post_not_found(post_id)),
+    }
+}
+
+/// Returns the authors of the post, the primary one first and then the co-authors by `added_at`.
+pub async fn list_post_authors_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Vec<AuthorDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, LIST_POST_AUTHORS_SQL, &[&post_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let user_id: uuid::Uuid = row.get("user_id");
+            let added_at: DateTime<Utc> = row.get("added_at");
+            AuthorDTO {
+                user_id: user_id.to_string(),
+                name: row.get("name"),
+                username: row.get("username"),
+                role: row.get("role"),
+                added_at: added_at.to_string(),
+            }
+        })
+        .collect())
+}
+
+async fn series_post_link(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    series_id: uuid::Uuid,
+    position: i32,
+) -> Result<Option<PostLinkDTO>, HttpResponse> {
+    match query_executor(pg_pool, SERIES_POST_LINK_SQL, &[&series_id, &position]).await {
+        Ok(rows) => Ok(rows.first().map(|row| PostLinkDTO {
+            title: row.get("title"),
+            slug: row.get("slug"),
+        })),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the published posts right before and after the post in its series, both `None` when
+/// the post is not part of a series.
+pub async fn series_navigation_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<(Option<PostLinkDTO>, Option<PostLinkDTO>), HttpResponse> {
+    let rows = match query_executor(pg_pool.clone(), SERIES_POSITION_SQL, &[&post_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let (series_id, position): (uuid::Uuid, i32) = match rows.first() {
+        Some(row) => (row.get("series_id"), row.get("position")),
+        None => return Ok((None, None)),
+    };
+
+    let prev = match series_post_link(pg_pool.clone(), series_id, position - 1).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let next = match series_post_link(pg_pool, series_id, position + 1).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    Ok((prev, next))
+}
+
+/// Returns whether the user is the primary author or a co-author of the post.
+pub async fn is_post_author_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    user_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(pg_pool, IS_POST_AUTHOR_SQL, &[&post_id, &user_id]).await {
+        Ok(rows) => Ok(rows[0].get("is_author")),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns which of the ids belong to users of the tenant that were not deleted.
+pub async fn existing_user_ids_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_ids: &[uuid::Uuid],
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<uuid::Uuid>, HttpResponse> {
+    match query_executor(pg_pool, EXISTING_USER_IDS_SQL, &[&user_ids, &tenant_id]).await {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get("id")).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the id of a post of the author with the same content hash, ignoring `exclude_post_id`.
+pub async fn duplicate_post_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    author_id: uuid::Uuid,
+    content_hash: String,
+    exclude_post_id: Option<uuid::Uuid>,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        DUPLICATE_POST_SQL,
+        &[&author_id, &content_hash, &exclude_post_id],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.first().map(|row| row.get("id"))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Adds the users in `add` as co-authors and removes the co-authors in `remove`. The primary
+/// author is never removed, and nothing changes unless the post and the added users belong to
+/// the tenant.
+pub async fn patch_post_authors_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    add: Vec<uuid::Uuid>,
+    remove: Vec<uuid::Uuid>,
+    tenant_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    match query_executor(
+        pg_pool,
+        PATCH_POST_AUTHORS_SQL,
+        &[&post_id, &add, &remove, &tenant_id],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Takes the lock of the post for `lock_secs`, or renews it when `locked_by` already holds it.
+/// Returns `None` when another user holds a lock that did not expire yet.
+pub async fn acquire_post_lock_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    locked_by: uuid::Uuid,
+    lock_secs: f64,
+) -> Result<Option<PostLockDTO>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        ACQUIRE_POST_LOCK_SQL,
+        &[&post_id, &locked_by, &lock_secs],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.first().map(|row| {
+            let post_id: uuid::Uuid = row.get("post_id");
+            let locked_by: uuid::Uuid = row.get("locked_by");
+            let locked_at: DateTime<Utc> = row.get("locked_at");
+            let expires_at: DateTime<Utc> = row.get("expires_at");
+            PostLockDTO {
+                post_id: post_id.to_string(),
+                locked_by: locked_by.to_string(),
+                locked_at: locked_at.to_string(),
+                expires_at: expires_at.to_string(),
+            }
+        })),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the holder of the lock of the post, `None` when it is not locked or the lock expired.
+pub async fn active_post_lock_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Option<PostLockedDTO>, HttpResponse> {
+    match query_executor(pg_pool, ACTIVE_POST_LOCK_SQL, &[&post_id]).await {
+        Ok(rows) => Ok(rows.first().map(|row| {
+            let locked_by: uuid::Uuid = row.get("locked_by");
+            let expires_at: DateTime<Utc> = row.get("expires_at");
+            PostLockedDTO {
+                locked_by: locked_by.to_string(),
+                locked_by_name: row.get("locked_by_name"),
+                expires_at: expires_at.to_string(),
+            }
+        })),
+        Err(e) => Err(e),
+    }
+}
+
+/// Releases the lock of the post held by `locked_by`, or any lock when `force` is set. Expired
+/// locks are always released. Returns whether a lock was removed.
+pub async fn release_post_lock_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    locked_by: uuid::Uuid,
+    force: bool,
+) -> Result<bool, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        RELEASE_POST_LOCK_SQL,
+        &[&post_id, &locked_by, &force],
+    )
+    .await
+    {
+        Ok(rows) => Ok(!rows.is_empty()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Deletes the expired post locks, returning how many were removed.
+pub async fn expire_post_locks_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<usize, HttpResponse> {
+    match query_executor(pg_pool, EXPIRE_POST_LOCKS_SQL, &[]).await {
+        Ok(rows) => Ok(rows.len()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,996 @@
+use super::{
+    post_dto::{
+        ArchiveMonthDTO, AuthorDTO, CoverImageDTO, DiffChunkDTO, EmbedMetaDTO, ExcerptPostDTO,
+        ListPostsParams, PatchPostAuthorsDTO, PostDTO, PostFilterParams, PostLockDTO,
+        PostRedirectDTO, RevisionDiffParams, SponsorshipDTO, UpdatePostDTO,
+    },
+    post_providers::{
+        image_url_provider, language_valid_provider, lint_markdown_provider, DEFAULT_OG_IMAGE_URL,
+        SUPPORTED_LANGUAGES, TWITTER_SITE, VALIDATE_IMAGE_URLS,
+    },
+    post_repositories::{
+        acquire_post_lock_repository, active_post_lock_repository, admin_list_posts_repository,
+        archive_month_posts_repository, archive_repository, copy_post_repository,
+        copy_slugs_taken_repository, copy_source_repository, detail_post_by_slug_repository,
+        duplicate_post_repository, excerpt_post_repository, existing_user_ids_repository,
+        is_post_author_repository, list_post_authors_repository, list_posts_repository,
+        patch_post_authors_repository, post_slug_taken_repository, put_cover_image_repository,
+        put_sponsorship_repository, recompute_post_counts_repository, release_post_lock_repository,
+        revision_bodies_repository, series_navigation_repository, trending_posts_repository,
+        update_post_repository,
+    },
+};
+use crate::{
+    infra::redis::Redis,
+    modules::{
+        feed::feed_services::escape_xml,
+        poll::{
+            poll_repositories::post_author_repository, poll_services::detail_post_poll_service,
+        },
+        post_attachment::post_attachment_repositories::list_post_attachments_repository,
+    },
+    shared::{
+        parsers::{
+            content_hash_parser::content_hash, preview_parser::truncated_preview,
+            slug_parser::slugify, summary_parser::generate_summary, toc_parser::extract_toc,
+        },
+        structs::{
+            cursor_params::{CursorPage, CursorParams},
+            page_params::{Page, PageParams},
+        },
+    },
+    utils::{cursor::cursor_page_params, error_construct::error_construct},
+};
+use actix_web::{http::header::LOCATION, web::Data, HttpResponse};
+use similar::{ChangeTag, TextDiff};
+use std::env;
+
+pub const TRENDING_POSTS_KEY: &str = "posts:trending";
+const TRENDING_POSTS_LIMIT: i64 = 10;
+const TRENDING_POSTS_EXPIRATION_SECONDS: u64 = 300;
+pub const EMBED_META_KEY: &str = "posts:embed-meta";
+const EMBED_META_EXPIRATION_SECONDS: u64 = 600;
+const EMBED_META_DESCRIPTION_CHARS: usize = 160;
+pub const ARCHIVE_KEY: &str = "posts:archive";
+const ARCHIVE_EXPIRATION_SECONDS: u64 = 3600;
+const MAX_CO_AUTHORS: usize = 5;
+pub const EXCERPT_KEY: &str = "post:excerpt";
+const EXCERPT_EXPIRATION_SECONDS: u64 = 3600;
+const POST_LOCK_SECONDS: f64 = 300.0;
+
+pub async fn list_posts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    params: ListPostsParams,
+    tenant_id: String,
+) -> Result<CursorPage<PostDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&CursorParams {
+        cursor: params.cursor,
+        limit: params.limit,
+    }) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if let Some(lang) = &params.lang {
+        if language_valid_provider(lang).is_err() {
+            return Err(HttpResponse::BadRequest().json(error_construct(
+                String::from("lang"),
+                String::from("bad request"),
+                format!(
+                    "O idioma deve ser um destes: {}.",
+                    SUPPORTED_LANGUAGES.join(", ")
+                ),
+                Some(lang.clone()),
+                None,
+                None,
+            )));
+        }
+    }
+    let code_language = params
+        .code_language
+        .map(|code_language| code_language.trim().to_lowercase());
+    list_posts_repository(
+        pg_pool,
+        params.sponsored,
+        params.lang,
+        code_language,
+        cursor,
+        limit,
+        uuid::Uuid::parse_str(&tenant_id).unwrap_or_default(),
+    )
+    .await
+}
+
+/// Lists the posts of the tenant for the admin dashboard, including the drafts and, with
+/// `status=deleted`, the soft-deleted posts.
+pub async fn admin_list_posts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    page_params: PageParams,
+    filter: PostFilterParams,
+    tenant_id: String,
+) -> Result<Page<PostDTO>, HttpResponse> {
+    let (page, per_page) = page_params.page_and_size();
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    admin_list_posts_repository(pg_pool, filter, page, per_page, tenant_id).await
+}
+
+pub async fn trending_posts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    tenant_id: String,
+) -> Result<Vec<PostDTO>, HttpResponse> {
+    let trending_posts_key = format!("{}:{}", TRENDING_POSTS_KEY, tenant_id);
+    if let Ok(cached) = Redis::get(&redis_pool, &trending_posts_key).await {
+        if let Ok(posts) = serde_json::from_str(&cached) {
+            return Ok(posts);
+        }
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let posts = match trending_posts_repository(pg_pool, TRENDING_POSTS_LIMIT, tenant_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &trending_posts_key,
+        &serde_json::to_string(&posts).unwrap_or_default(),
+        TRENDING_POSTS_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(posts)
+}
+
+/// Returns the number of published posts per month, cached for an hour.
+pub async fn archive_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    tenant_id: String,
+) -> Result<Vec<ArchiveMonthDTO>, HttpResponse> {
+    let archive_key = format!("{}:{}", ARCHIVE_KEY, tenant_id);
+    if let Ok(cached) = Redis::get(&redis_pool, &archive_key).await {
+        if let Ok(months) = serde_json::from_str(&cached) {
+            return Ok(months);
+        }
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let months = match archive_repository(pg_pool, tenant_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &archive_key,
+        &serde_json::to_string(&months).unwrap_or_default(),
+        ARCHIVE_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(months)
+}
+
+/// Drops the cached archive of every tenant, called whenever a post is published or removed.
+pub async fn invalidate_archive_service(redis_pool: &deadpool_redis::Pool) {
+    let _ = Redis::delete_by_pattern(redis_pool, &format!("{}:*", ARCHIVE_KEY)).await;
+}
+
+/// Returns a page of the posts published in the month, the most recent first.
+pub async fn archive_month_posts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    year: i32,
+    month: u32,
+    params: CursorParams,
+    tenant_id: String,
+) -> Result<CursorPage<PostDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let start = chrono::NaiveDate::from_ymd_opt(year, month, 1);
+    let end = start.and_then(|start| start.checked_add_months(chrono::Months::new(1)));
+    let (start, end) = match start.zip(end) {
+        Some(x) => x,
+        None => {
+            return Err(HttpResponse::BadRequest().json(error_construct(
+                String::from("month"),
+                String::from("bad request"),
+                String::from("Informe um ano válido e um mês entre 1 e 12."),
+                Some(format!("{}/{}", year, month)),
+                None,
+                None,
+            )))
+        }
+    };
+
+    archive_month_posts_repository(
+        pg_pool,
+        start.and_time(chrono::NaiveTime::MIN).and_utc(),
+        end.and_time(chrono::NaiveTime::MIN).and_utc(),
+        cursor,
+        limit,
+        uuid::Uuid::parse_str(&tenant_id).unwrap_or_default(),
+    )
+    .await
+}
+
+/// Builds the Open Graph and Twitter Card metadata of a post.
+///
+/// The description is the summary of the post, or its body when it has none, as plain text cut
+/// at 160 characters. The image is the cover of the post, falling back to `DEFAULT_OG_IMAGE_URL`.
+pub fn embed_meta_constructor(post: &PostDTO) -> EmbedMetaDTO {
+    let site_url = env::var("SITE_BASE_URL").unwrap_or_default();
+    let text = match &post.summary {
+        Some(summary) if !summary.trim().is_empty() => summary.as_str(),
+        _ => post.body.as_deref().unwrap_or_default(),
+    };
+    let og_image = post
+        .cover_image_url
+        .clone()
+        .unwrap_or_else(|| DEFAULT_OG_IMAGE_URL.clone());
+    let twitter_card = match og_image.is_empty() {
+        true => "summary",
+        false => "summary_large_image",
+    };
+
+    EmbedMetaDTO {
+        og_title: post.title.clone(),
+        og_description: truncated_preview(&generate_summary(text), EMBED_META_DESCRIPTION_CHARS),
+        og_url: format!(
+            "{}/post/{}",
+            site_url.trim_end_matches('/'),
+            post.slug.clone().unwrap_or_default()
+        ),
+        og_image,
+        twitter_card: String::from(twitter_card),
+        twitter_site: TWITTER_SITE.clone(),
+    }
+}
+
+/// Renders the metadata as the `<meta>` tags of an HTML `<head>`.
+pub fn embed_meta_html(meta: &EmbedMetaDTO) -> String {
+    let mut html = String::new();
+    for (property, content) in [
+        ("og:title", &meta.og_title),
+        ("og:description", &meta.og_description),
+        ("og:image", &meta.og_image),
+        ("og:url", &meta.og_url),
+    ] {
+        html.push_str(&format!(
+            "<meta property=\"{}\" content=\"{}\">\n",
+            property,
+            escape_xml(content)
+        ));
+    }
+    for (name, content) in [
+        ("twitter:card", &meta.twitter_card),
+        ("twitter:site", &meta.twitter_site),
+    ] {
+        html.push_str(&format!(
+            "<meta name=\"{}\" content=\"{}\">\n",
+            name,
+            escape_xml(content)
+        ));
+    }
+    html
+}
+
+/// Returns the sharing metadata of the published post with the given slug, cached for 600 s.
+pub async fn embed_meta_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    slug: String,
+    tenant_id: String,
+) -> Result<EmbedMetaDTO, HttpResponse> {
+    let embed_meta_key = format!("{}:{}:{}", EMBED_META_KEY, tenant_id, slug);
+    if let Ok(cached) = Redis::get(&redis_pool, &embed_meta_key).await {
+        if let Ok(meta) = serde_json::from_str(&cached) {
+            return Ok(meta);
+        }
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let post = match detail_post_by_slug_repository(pg_pool, slug, tenant_id).await {
+        Ok(Ok(x)) => x,
+        Ok(Err(redirect)) => {
+            return Err(HttpResponse::MovedPermanently()
+                .insert_header((LOCATION, format!("/post/{}/embed-meta", redirect.new_slug)))
+                .finish())
+        }
+        Err(e) => return Err(e),
+    };
+    let meta = embed_meta_constructor(&post);
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &embed_meta_key,
+        &serde_json::to_string(&meta).unwrap_or_default(),
+        EMBED_META_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(meta)
+}
+
+/// Returns the excerpt of the published post with the given slug, cached for 3600 s.
+pub async fn excerpt_post_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    slug: String,
+    tenant_id: String,
+) -> Result<ExcerptPostDTO, HttpResponse> {
+    let excerpt_key = format!("{}:{}:{}", EXCERPT_KEY, tenant_id, slug);
+    if let Ok(cached) = Redis::get(&redis_pool, &excerpt_key).await {
+        if let Ok(excerpt) = serde_json::from_str(&cached) {
+            return Ok(excerpt);
+        }
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let excerpt = match excerpt_post_repository(pg_pool, slug, tenant_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &excerpt_key,
+        &serde_json::to_string(&excerpt).unwrap_or_default(),
+        EXCERPT_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(excerpt)
+}
+
+/// Drops the cached excerpts of every post, called whenever a post is updated, since the old slug
+/// of a renamed post is not known after the update.
+pub async fn invalidate_excerpts_service(redis_pool: &deadpool_redis::Pool) {
+    let _ = Redis::delete_by_pattern(redis_pool, &format!("{}:*", EXCERPT_KEY)).await;
+}
+
+/// Returns the published post with the given slug, including its authors, its poll when it has
+/// one and the previous and next posts of its series, or the current slug of the post when the
+/// given one is an old slug.
+pub async fn detail_post_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    slug: String,
+    tenant_id: String,
+) -> Result<Result<PostDTO, PostRedirectDTO>, HttpResponse> {
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let mut post = match detail_post_by_slug_repository(pg_pool.clone(), slug, tenant_id).await {
+        Ok(Ok(x)) => x,
+        Ok(Err(redirect)) => return Ok(Err(redirect)),
+        Err(e) => return Err(e),
+    };
+
+    let toc = extract_toc(post.body.as_deref().unwrap_or_default());
+    if !toc.is_empty() {
+        post.toc = Some(toc);
+    }
+
+    let post_id = uuid::Uuid::parse_str(&post.id).unwrap_or_default();
+    post.poll = match detail_post_poll_service(pg_pool.clone(), post_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    post.attachments = match list_post_attachments_repository(pg_pool.clone(), post_id).await {
+        Ok(x) => Some(x),
+        Err(e) => return Err(e),
+    };
+    post.authors = match list_post_authors_repository(pg_pool.clone(), post_id).await {
+        Ok(x) => Some(x),
+        Err(e) => return Err(e),
+    };
+    (post.prev_in_series, post.next_in_series) =
+        match series_navigation_repository(pg_pool, post_id).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+    Ok(Ok(post))
+}
+
+pub async fn put_sponsorship_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    actor_id: String,
+    is_admin: bool,
+    body: SponsorshipDTO,
+) -> Result<PostDTO, HttpResponse> {
+    if body.sponsored_by.is_none() && body.sponsor_url.is_some() {
+        return Err(HttpResponse::BadRequest().json(error_construct(
+            String::from("sponsored_by"),
+            String::from("bad request"),
+            String::from("Informe o patrocinador ao enviar a URL do patrocínio."),
+            None,
+            None,
+            None,
+        )));
+    }
+
+    put_sponsorship_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&post_id).unwrap_or_default(),
+        body.sponsored_by,
+        body.sponsor_url,
+        uuid::Uuid::parse_str(&actor_id).unwrap_or_default(),
+        is_admin,
+    )
+    .await
+}
+
+/// Sets the cover image of the post.
+///
+/// When the `VALIDATE_IMAGE_URLS` env var is `true`, the URL must answer a `HEAD` request with an
+/// `image/*` content type.
+pub async fn put_cover_image_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    actor_id: String,
+    is_admin: bool,
+    body: CoverImageDTO,
+) -> Result<PostDTO, HttpResponse> {
+    if *VALIDATE_IMAGE_URLS {
+        match image_url_provider(&body.cover_image_url).await {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+    }
+
+    put_cover_image_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&post_id).unwrap_or_default(),
+        Some(body.cover_image_url),
+        body.cover_image_blurhash,
+        uuid::Uuid::parse_str(&actor_id).unwrap_or_default(),
+        is_admin,
+    )
+    .await
+}
+
+pub async fn delete_cover_image_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    actor_id: String,
+    is_admin: bool,
+) -> Result<PostDTO, HttpResponse> {
+    put_cover_image_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&post_id).unwrap_or_default(),
+        None,
+        None,
+        uuid::Uuid::parse_str(&actor_id).unwrap_or_default(),
+        is_admin,
+    )
+    .await
+}
+
+/// Returns a `409 Conflict` with the id of the existing post when the author already has a post
+/// with the same title and body.
+pub async fn duplicate_post_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    author_id: uuid::Uuid,
+    title: &str,
+    body: &str,
+    exclude_post_id: Option<uuid::Uuid>,
+) -> Result<(), HttpResponse> {
+    let hash = content_hash(title, body);
+    match duplicate_post_repository(pg_pool, author_id, hash, exclude_post_id).await {
+        Ok(None) => Ok(()),
+        Ok(Some(post_id)) => Err(HttpResponse::Conflict().json(error_construct(
+            String::from("post"),
+            String::from("conflict"),
+            String::from("Já existe um post deste autor com o mesmo título e conteúdo."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the primary author of the post when `actor_id` is one of its authors, answering
+/// `404 Not Found` if the post does not exist and `403 Forbidden` with `message` otherwise.
+async fn post_author_guard(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    actor_id: &str,
+    message: &str,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    let post_author = match post_author_repository(pg_pool.clone(), post_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author {
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("post"),
+            String::from("not found"),
+            String::from("Não foi encontrado um post com este id."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        ))),
+        Some(author) if author.map(|id| id.to_string()).as_deref() == Some(actor_id) => Ok(author),
+        Some(author) => {
+            let actor_id = uuid::Uuid::parse_str(actor_id).unwrap_or_default();
+            match is_post_author_repository(pg_pool, post_id, actor_id).await {
+                Ok(true) => Ok(author),
+                Ok(false) => Err(HttpResponse::Forbidden().json(error_construct(
+                    String::from("post"),
+                    String::from("forbidden"),
+                    String::from(message),
+                    Some(post_id.to_string()),
+                    None,
+                    None,
+                ))),
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
+
+/// Answers `404 Not Found` when the post does not exist and `403 Forbidden` when `actor_id` is
+/// neither one of its authors nor an admin.
+async fn post_lock_guard(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    actor_id: &str,
+    is_admin: bool,
+) -> Result<(), HttpResponse> {
+    if !is_admin {
+        return match post_author_guard(
+            pg_pool,
+            post_id,
+            actor_id,
+            "Apenas os autores do post ou um administrador podem bloqueá-lo.",
+        )
+        .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        };
+    }
+    match post_author_repository(pg_pool, post_id).await {
+        Ok(Some(_)) => Ok(()),
+        Ok(None) => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("post"),
+            String::from("not found"),
+            String::from("Não foi encontrado um post com este id."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Locks the post for editing by `actor_id` during 5 minutes, renewing the lock when they already
+/// hold it.
+///
+/// While another user holds a lock that did not expire, it answers `423 Locked` with the name of
+/// the holder and when the lock expires.
+pub async fn acquire_post_lock_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    actor_id: String,
+    is_admin: bool,
+) -> Result<PostLockDTO, HttpResponse> {
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    match post_lock_guard(pg_pool.clone(), post_id, &actor_id, is_admin).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    let locked_by = uuid::Uuid::parse_str(&actor_id).unwrap_or_default();
+    match acquire_post_lock_repository(pg_pool.clone(), post_id, locked_by, POST_LOCK_SECONDS).await
+    {
+        Ok(Some(lock)) => return Ok(lock),
+        Ok(None) => (),
+        Err(e) => return Err(e),
+    };
+    match active_post_lock_repository(pg_pool, post_id).await {
+        Ok(Some(lock)) => Err(HttpResponse::Locked().json(lock)),
+        Ok(None) => Err(HttpResponse::Conflict().json(error_construct(
+            String::from("post"),
+            String::from("conflict"),
+            String::from("O bloqueio do post mudou durante a requisição, tente novamente."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Releases the lock of the post held by `actor_id`. Admins can release the lock of any user.
+///
+/// Releasing a post that is not locked does nothing, and a lock held by another user answers
+/// `423 Locked`.
+pub async fn release_post_lock_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    actor_id: String,
+    is_admin: bool,
+) -> Result<(), HttpResponse> {
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    match post_lock_guard(pg_pool.clone(), post_id, &actor_id, is_admin).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    let locked_by = uuid::Uuid::parse_str(&actor_id).unwrap_or_default();
+    match release_post_lock_repository(pg_pool.clone(), post_id, locked_by, is_admin).await {
+        Ok(true) => return Ok(()),
+        Ok(false) => (),
+        Err(e) => return Err(e),
+    };
+    match active_post_lock_repository(pg_pool, post_id).await {
+        Ok(Some(lock)) => Err(HttpResponse::Locked().json(lock)),
+        Ok(None) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Updates the post of `author_id`, its primary author or a co-author, with optimistic locking: the update only applies if the post is
+/// still at the `version` sent by the client, otherwise it returns `409 Conflict`.
+///
+/// Changing the slug keeps the old one redirecting to the post.
+/// Returns a `423 Locked` while another user holds the lock of the post.
+pub async fn update_post_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    author_id: String,
+    body: UpdatePostDTO,
+) -> Result<PostDTO, HttpResponse> {
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    let primary_author = match post_author_guard(
+        pg_pool.clone(),
+        post_id,
+        &author_id,
+        "Apenas os autores do post podem editá-lo.",
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match active_post_lock_repository(pg_pool.clone(), post_id).await {
+        Ok(Some(lock)) if lock.locked_by != author_id => {
+            return Err(HttpResponse::Locked().json(lock))
+        }
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    if let Some(primary_author_id) = primary_author {
+        match duplicate_post_service(
+            pg_pool.clone(),
+            primary_author_id,
+            &body.title,
+            &body.body,
+            Some(post_id),
+        )
+        .await
+        {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+    }
+
+    if let Some(slug) = &body.slug {
+        match post_slug_taken_repository(pg_pool.clone(), slug, post_id).await {
+            Ok(false) => (),
+            Ok(true) => {
+                return Err(HttpResponse::Conflict().json(error_construct(
+                    String::from("slug"),
+                    String::from("conflict"),
+                    String::from("Este slug já está sendo utilizado por outro post."),
+                    Some(slug.clone()),
+                    None,
+                    None,
+                )))
+            }
+            Err(e) => return Err(e),
+        };
+    }
+
+    let version = body.version;
+    let warnings = lint_markdown_provider(&body.body);
+    match update_post_repository(pg_pool.clone(), post_id, version, body).await {
+        Ok(Some(mut post)) => {
+            post.authors = match list_post_authors_repository(pg_pool, post_id).await {
+                Ok(x) => Some(x),
+                Err(e) => return Err(e),
+            };
+            post.warnings = Some(warnings);
+            Ok(post)
+        }
+        Ok(None) => Err(HttpResponse::Conflict().json(error_construct(
+            String::from("version"),
+            String::from("conflict"),
+            String::from(
+                "O post foi modificado por outra sessão. Por favor, recarregue e tente novamente.",
+            ),
+            Some(version.to_string()),
+            None,
+            None,
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn recompute_post_counts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+) -> Result<PostDTO, HttpResponse> {
+    recompute_post_counts_repository(pg_pool, uuid::Uuid::parse_str(&post_id).unwrap_or_default())
+        .await
+}
+
+/// Returns `{slug}-copy`, or `{slug}-copy-2`, `{slug}-copy-3` and so on when it is taken.
+pub fn copy_slug(slug: &str, taken: &[String]) -> String {
+    let base = format!("{}-copy", slug);
+    if !taken.contains(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or_default()
+}
+
+/// Duplicates the post as a draft of the actor, returning the id of the copy.
+///
+/// Only the author of the post or an admin can copy it.
+pub async fn copy_post_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    actor_id: String,
+    is_admin: bool,
+    tenant_id: String,
+) -> Result<String, HttpResponse> {
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    let actor_id = uuid::Uuid::parse_str(&actor_id).unwrap_or_default();
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let (author_id, slug, title) =
+        match copy_source_repository(pg_pool.clone(), post_id, tenant_id).await {
+            Ok(Some(source)) => source,
+            Ok(None) => {
+                return Err(HttpResponse::NotFound().json(error_construct(
+                    String::from("post"),
+                    String::from("not found"),
+                    String::from("Não foi encontrado um post com este id."),
+                    Some(post_id.to_string()),
+                    None,
+                    None,
+                )))
+            }
+            Err(e) => return Err(e),
+        };
+    if !is_admin && author_id != Some(actor_id) {
+        return Err(HttpResponse::Forbidden().json(error_construct(
+            String::from("post"),
+            String::from("forbidden"),
+            String::from("Apenas o autor do post ou um administrador pode copiá-lo."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        )));
+    }
+
+    let slug = slug.unwrap_or_else(|| slugify(&title));
+    let taken =
+        match copy_slugs_taken_repository(pg_pool.clone(), &format!("{}-copy", slug), tenant_id)
+            .await
+        {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+    match copy_post_repository(
+        pg_pool,
+        post_id,
+        actor_id,
+        copy_slug(&slug, &taken),
+        tenant_id,
+    )
+    .await
+    {
+        Ok(copy_id) => Ok(copy_id.to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Adds and removes the co-authors of the post, returning its authors afterwards.
+///
+/// Only the primary author can manage the co-authors, every added user must exist and the post
+/// can have at most `MAX_CO_AUTHORS` co-authors. The primary author can not be added or removed.
+pub async fn patch_post_authors_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    actor_id: String,
+    body: PatchPostAuthorsDTO,
+    tenant_id: String,
+) -> Result<Vec<AuthorDTO>, HttpResponse> {
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    let actor_id = uuid::Uuid::parse_str(&actor_id).unwrap_or_default();
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    match post_author_repository(pg_pool.clone(), post_id).await {
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(error_construct(
+                String::from("post"),
+                String::from("not found"),
+                String::from("Não foi encontrado um post com este id."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Ok(Some(author_id)) if author_id == Some(actor_id) => (),
+        Ok(Some(_)) => {
+            return Err(HttpResponse::Forbidden().json(error_construct(
+                String::from("post"),
+                String::from("forbidden"),
+                String::from("Apenas o autor principal do post pode gerenciar os coautores."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Err(e) => return Err(e),
+    };
+
+    let parse_ids = |ids: Vec<String>| -> Vec<uuid::Uuid> {
+        let mut ids: Vec<uuid::Uuid> = ids
+            .iter()
+            .map(|id| uuid::Uuid::parse_str(id).unwrap_or_default())
+            .filter(|id| *id != actor_id)
+            .collect();
+        ids.sort();
+        ids.dedup();
+        ids
+    };
+    let remove = parse_ids(body.remove);
+    let add: Vec<uuid::Uuid> = parse_ids(body.add)
+        .into_iter()
+        .filter(|id| !remove.contains(id))
+        .collect();
+
+    let existing = match existing_user_ids_repository(pg_pool.clone(), &add, tenant_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let missing: Vec<String> = add
+        .iter()
+        .filter(|id| !existing.contains(id))
+        .map(|id| id.to_string())
+        .collect();
+    if !missing.is_empty() {
+        return Err(HttpResponse::BadRequest().json(error_construct(
+            String::from("add"),
+            String::from("bad request"),
+            String::from("Não foram encontrados usuários com estes ids."),
+            Some(missing.join(",")),
+            None,
+            None,
+        )));
+    }
+
+    let authors = match list_post_authors_repository(pg_pool.clone(), post_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let mut co_authors: Vec<uuid::Uuid> = authors
+        .iter()
+        .filter(|author| author.role != "primary")
+        .map(|author| uuid::Uuid::parse_str(&author.user_id).unwrap_or_default())
+        .filter(|id| !remove.contains(id))
+        .collect();
+    for id in &add {
+        if !co_authors.contains(id) {
+            co_authors.push(*id);
+        }
+    }
+    if co_authors.len() > MAX_CO_AUTHORS {
+        return Err(HttpResponse::BadRequest().json(error_construct(
+            String::from("add"),
+            String::from("bad request"),
+            format!("Um post pode ter no máximo {} coautores.", MAX_CO_AUTHORS),
+            Some(co_authors.len().to_string()),
+            None,
+            None,
+        )));
+    }
+
+    match patch_post_authors_repository(pg_pool.clone(), post_id, add, remove, tenant_id).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    list_post_authors_repository(pg_pool, post_id).await
+}
+
+/// Joins the consecutive changes with the same operation into a single chunk.
+fn diff_chunks<'a>(diff: TextDiff<'a, 'a, '_, str>) -> Vec<DiffChunkDTO> {
+    let mut chunks: Vec<DiffChunkDTO> = Vec::new();
+    for change in diff.iter_all_changes() {
+        let op = match change.tag() {
+            ChangeTag::Equal => "equal",
+            ChangeTag::Insert => "insert",
+            ChangeTag::Delete => "delete",
+        };
+        match chunks.last_mut() {
+            Some(chunk) if chunk.op == op => chunk.text.push_str(change.value()),
+            _ => chunks.push(DiffChunkDTO {
+                op: String::from(op),
+                text: change.value().to_string(),
+            }),
+        };
+    }
+    chunks
+}
+
+/// Computes the word level diff from `old` to `new`, as chunks of kept, inserted and deleted text.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::modules::post::post_services::compute_diff;
+///
+/// let chunks = compute_diff("Olá mundo", "Olá Rust");
+/// assert_eq!(chunks[0].op, "equal");
+/// assert_eq!(chunks[0].text, "Olá ");
+/// ```
+pub fn compute_diff(old: &str, new: &str) -> Vec<DiffChunkDTO> {
+    diff_chunks(TextDiff::from_words(old, new))
+}
+
+/// Computes the character level diff from `old` to `new`, as chunks of kept, inserted and deleted
+/// text.
+pub fn compute_character_diff(old: &str, new: &str) -> Vec<DiffChunkDTO> {
+    diff_chunks(TextDiff::from_chars(old, new))
+}
+
+/// Compares the body of the revision with the current body of the post or, with
+/// `compare_to=prev`, the body of the previous revision with the one of the revision.
+///
+/// Only the authors of the post can see its revisions.
+pub async fn revision_diff_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    revision_id: String,
+    actor_id: String,
+    params: RevisionDiffParams,
+) -> Result<Vec<DiffChunkDTO>, HttpResponse> {
+    let post_id = uuid::Uuid::parse_str(&post_id).unwrap_or_default();
+    let revision_id = uuid::Uuid::parse_str(&revision_id).unwrap_or_default();
+    match post_author_guard(
+        pg_pool.clone(),
+        post_id,
+        &actor_id,
+        "Apenas os autores do post podem ver suas revisões.",
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    let bodies = match revision_bodies_repository(pg_pool, post_id, revision_id).await {
+        Ok(Some(x)) => x,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(error_construct(
+                String::from("revision"),
+                String::from("not found"),
+                String::from("Não foi encontrada uma revisão deste post com este id."),
+                Some(revision_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Err(e) => return Err(e),
+    };
+    let (old, new) = match params.compare_to.as_deref() {
+        Some("prev") => (
+            bodies.previous_body.unwrap_or_default(),
+            bodies.revision_body,
+        ),
+        _ => (bodies.revision_body, bodies.post_body),
+    };
+    match params.granularity.as_deref() {
+        Some("character") => Ok(compute_character_diff(&old, &new)),
+        _ => Ok(compute_diff(&old, &new)),
+    }
+}
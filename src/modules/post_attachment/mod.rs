@@ -0,0 +1,5 @@
+pub mod post_attachment_controllers;
+pub mod post_attachment_dtos;
+pub mod post_attachment_providers;
+pub mod post_attachment_repositories;
+pub mod post_attachment_services;
@@ -0,0 +1,210 @@
+use super::{
+    post_attachment_dtos::InsertAttachmentDTO,
+    post_attachment_services::{delete_attachment_service, insert_attachment_service},
+};
+use crate::middlewares::jwt_token_middleware::jwt_token_middleware;
+use actix_web::{delete, post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn post_attachment_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(insert_attachment).service(delete_attachment);
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/attachment",
+    security(("bearer_auth" = [])),
+    request_body = InsertAttachmentDTO,
+    responses((
+        status = 201, description = "Anexo vinculado ao post (Created)",
+        body = AttachmentDTO, content_type = "application/json", example = json ! ({
+            "id": "0b6f3c1e-8f4d-4a2b-9c5e-7d1a2b3c4d5e",
+            "postId": "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d",
+            "filename": "exemplos.zip",
+            "url": "https://cdn.navarro.tech/exemplos.zip",
+            "sizeBytes": 20480,
+            "mimeType": "application/zip",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+	), (
+		status = 400, description = "Erro do usuário por id, URL, nome, tamanho ou tipo do arquivo inválidos (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "mime_type": [{
+                "code": "mime_type",
+                "message": "O tipo do anexo deve ser application/pdf, application/zip, text/plain ou image/*.",
+                "params": {
+                    "value": "application/x-msdownload"
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post pode gerenciar os anexos (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post pode gerenciar seus anexos.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{post_id}/attachment")]
+async fn insert_attachment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    post_id: web::Path<String>,
+    body: web::Json<InsertAttachmentDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match insert_attachment_service(
+        pg_pool,
+        post_id.into_inner(),
+        token.claims.sub,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(attachment) => HttpResponse::Created().json(attachment),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/attachment/{attachment_id}",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Anexo removido do post (No Content)"
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "attachment_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Apenas o autor do post pode gerenciar os anexos (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "forbidden",
+                "message": "Apenas o autor do post pode gerenciar seus anexos.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post ou anexo não encontrado (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "attachment": [{
+                "code": "not found",
+                "message": "Não foi encontrado um anexo com este id no post.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("{post_id}/attachment/{attachment_id}")]
+async fn delete_attachment(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    path: web::Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let (post_id, attachment_id) = path.into_inner();
+    match delete_attachment_service(pg_pool, post_id, attachment_id, token.claims.sub).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
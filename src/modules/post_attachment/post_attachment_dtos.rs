@@ -0,0 +1,37 @@
+use super::post_attachment_providers::attachment_mime_type_valid_provider;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertAttachmentDTO {
+    #[validate(url(message = "A URL do anexo deve ser um endereço válido."))]
+    pub url: String,
+
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "O nome do arquivo deve ter entre 1 e 255 caracteres."
+    ))]
+    pub filename: String,
+
+    #[validate(range(min = 0, message = "O tamanho do arquivo não pode ser negativo."))]
+    #[serde(alias = "sizeBytes")]
+    pub size_bytes: i64,
+
+    #[validate(custom(function = "attachment_mime_type_valid_provider"))]
+    #[serde(alias = "mimeType")]
+    pub mime_type: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentDTO {
+    pub id: String,
+    pub post_id: String,
+    pub filename: String,
+    pub url: String,
+    pub size_bytes: i64,
+    pub mime_type: String,
+    pub created_at: String,
+}
@@ -0,0 +1,50 @@
+use crate::utils::error_construct::error_construct;
+use actix_web::HttpResponse;
+use once_cell::sync::Lazy;
+use std::{borrow::Cow, env, time::Duration};
+use validator::ValidationError;
+
+const ATTACHMENT_MIME_TYPES: [&str; 3] = ["application/pdf", "application/zip", "text/plain"];
+
+/// Whether the attachment URLs are checked with [`url_reachable_provider`] before being inserted.
+pub static VALIDATE_ATTACHMENT_URLS: Lazy<bool> = Lazy::new(|| {
+    env::var("VALIDATE_ATTACHMENT_URLS")
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+});
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap()
+});
+
+/// Checks that the MIME type is `application/pdf`, `application/zip`, `text/plain` or `image/*`.
+pub fn attachment_mime_type_valid_provider(mime_type: &str) -> Result<(), ValidationError> {
+    let mime_type = mime_type.trim().to_lowercase();
+    let is_image = mime_type
+        .strip_prefix("image/")
+        .is_some_and(|subtype| !subtype.is_empty());
+    if is_image || ATTACHMENT_MIME_TYPES.contains(&mime_type.as_str()) {
+        return Ok(());
+    }
+    Err(ValidationError::new("mime_type").with_message(Cow::from(
+        "O tipo do anexo deve ser application/pdf, application/zip, text/plain ou image/*.",
+    )))
+}
+
+/// Makes a `HEAD` request to the URL, which must answer with a 2xx status.
+pub async fn url_reachable_provider(url: &str) -> Result<(), HttpResponse> {
+    match HTTP_CLIENT.head(url).send().await {
+        Ok(resp) if resp.status().is_success() => Ok(()),
+        _ => Err(HttpResponse::BadRequest().json(error_construct(
+            String::from("url"),
+            String::from("bad request"),
+            String::from("A URL do anexo não está acessível."),
+            Some(String::from(url)),
+            None,
+            None,
+        ))),
+    }
+}
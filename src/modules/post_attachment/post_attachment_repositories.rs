@@ -0,0 +1,84 @@
+use super::post_attachment_dtos::{AttachmentDTO, InsertAttachmentDTO};
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+const INSERT_ATTACHMENT_SQL: &str = "
+    INSERT INTO post_attachments (id, post_id, filename, url, size_bytes, mime_type, created_at)
+    VALUES ($1, $2, $3, $4, $5, $6, NOW())
+    RETURNING id, post_id, filename, url, size_bytes, mime_type, created_at
+";
+
+const DELETE_ATTACHMENT_SQL: &str =
+    "DELETE FROM post_attachments WHERE id = $1 AND post_id = $2 RETURNING id";
+
+const LIST_POST_ATTACHMENTS_SQL: &str = "
+    SELECT id, post_id, filename, url, size_bytes, mime_type, created_at
+    FROM post_attachments
+    WHERE post_id = $1
+    ORDER BY created_at, id
+";
+
+fn attachment_dto_constructor(row: &Row) -> AttachmentDTO {
+    let id: uuid::Uuid = row.get("id");
+    let post_id: uuid::Uuid = row.get("post_id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    AttachmentDTO {
+        id: id.to_string(),
+        post_id: post_id.to_string(),
+        filename: row.get("filename"),
+        url: row.get("url"),
+        size_bytes: row.get("size_bytes"),
+        mime_type: row.get("mime_type"),
+        created_at: created_at.to_string(),
+    }
+}
+
+pub async fn insert_attachment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    body: InsertAttachmentDTO,
+) -> Result<AttachmentDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_ATTACHMENT_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &post_id,
+            &body.filename.trim(),
+            &body.url.trim(),
+            &body.size_bytes,
+            &body.mime_type.trim().to_lowercase(),
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(attachment_dto_constructor(&rows[0]))
+}
+
+/// Deletes the attachment of the post, returning `false` if it was not found.
+pub async fn delete_attachment_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    attachment_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(pg_pool, DELETE_ATTACHMENT_SQL, &[&attachment_id, &post_id]).await {
+        Ok(rows) => Ok(!rows.is_empty()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn list_post_attachments_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+) -> Result<Vec<AttachmentDTO>, HttpResponse> {
+    match query_executor(pg_pool, LIST_POST_ATTACHMENTS_SQL, &[&post_id]).await {
+        Ok(rows) => Ok(rows.iter().map(attachment_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
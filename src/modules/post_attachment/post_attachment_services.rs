@@ -0,0 +1,112 @@
+use super::{
+    post_attachment_dtos::{AttachmentDTO, InsertAttachmentDTO},
+    post_attachment_providers::{url_reachable_provider, VALIDATE_ATTACHMENT_URLS},
+    post_attachment_repositories::*,
+};
+use crate::{
+    middlewares::uuid_path_middleware::uuid_path_middleware,
+    modules::poll::poll_repositories::post_author_repository,
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+
+fn parse_uuid(id: String, field: &str) -> Result<uuid::Uuid, HttpResponse> {
+    match uuid_path_middleware(id, field) {
+        Ok(id) => Ok(uuid::Uuid::parse_str(&id).unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Checks that the post exists and was written by `author_id`.
+async fn post_author_valid(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: uuid::Uuid,
+    author_id: String,
+) -> Result<(), HttpResponse> {
+    let post_author = match post_author_repository(pg_pool, post_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author {
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("post"),
+            String::from("not found"),
+            String::from("Não foi encontrado um post com este id."),
+            Some(post_id.to_string()),
+            None,
+            None,
+        ))),
+        Some(author) if author.map(|id| id.to_string()) != Some(author_id) => {
+            Err(HttpResponse::Forbidden().json(error_construct(
+                String::from("post"),
+                String::from("forbidden"),
+                String::from("Apenas o autor do post pode gerenciar seus anexos."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/// Links the file to the post of `author_id`.
+///
+/// When the `VALIDATE_ATTACHMENT_URLS` env var is `true`, the URL must answer a `HEAD` request
+/// with a 2xx status.
+pub async fn insert_attachment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    author_id: String,
+    body: InsertAttachmentDTO,
+) -> Result<AttachmentDTO, HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author_valid(pg_pool.clone(), post_id, author_id).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    if *VALIDATE_ATTACHMENT_URLS {
+        match url_reachable_provider(body.url.trim()).await {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+    }
+
+    insert_attachment_repository(pg_pool, post_id, body).await
+}
+
+pub async fn delete_attachment_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post_id: String,
+    attachment_id: String,
+    author_id: String,
+) -> Result<(), HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let attachment_id = match parse_uuid(attachment_id, "attachment_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    match post_author_valid(pg_pool.clone(), post_id, author_id).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    match delete_attachment_repository(pg_pool, post_id, attachment_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("attachment"),
+            String::from("not found"),
+            String::from("Não foi encontrado um anexo com este id no post."),
+            Some(attachment_id.to_string()),
+            None,
+            None,
+        ))),
+        Err(e) => Err(e),
+    }
+}
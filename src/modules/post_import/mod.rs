@@ -0,0 +1,4 @@
+pub mod post_import_controllers;
+pub mod post_import_dtos;
+pub mod post_import_repositories;
+pub mod post_import_services;
@@ -0,0 +1,530 @@
+use super::{
+    post_import_dtos::{MarkdownImportResultDTO, RssImportDTO},
+    post_import_services::{
+        bulk_import_posts_service, import_markdown_post_service, import_rss_posts_service,
+        parse_import_file, MAX_MARKDOWN_FILES, MAX_MARKDOWN_FILE_BYTES,
+    },
+};
+use crate::{
+    middlewares::{
+        admin_middleware::admin_middleware,
+        body_size_limit_middleware::UPLOAD_BODY_SIZE_LIMIT_BYTES,
+    },
+    modules::{
+        feed::feed_services::invalidate_sitemap_service,
+        post::post_services::invalidate_archive_service,
+    },
+    utils::error_construct::error_construct,
+};
+use actix_multipart::Multipart;
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use futures_util::StreamExt;
+use validator::Validate;
+
+pub fn post_import_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(bulk_import_posts)
+        .service(import_markdown_posts)
+        .service(import_rss_posts);
+}
+
+fn import_file_error(status: u16, code: &str, message: &str) -> HttpResponse {
+    let mut resp = match status {
+        413 => HttpResponse::PayloadTooLarge(),
+        _ => HttpResponse::BadRequest(),
+    };
+    resp.json(error_construct(
+        String::from("file"),
+        String::from(code),
+        String::from(message),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Reads the first file of the multipart body, returning its content type and bytes.
+///
+/// The content type falls back to the file extension (`.csv`, `.jsonl` or `.ndjson`) when the
+/// part does not declare a specific one.
+async fn read_import_file(mut payload: Multipart) -> Result<(String, Vec<u8>), HttpResponse> {
+    let mut field = match payload.next().await {
+        Some(Ok(field)) => field,
+        _ => {
+            return Err(import_file_error(
+                400,
+                "bad request",
+                "Envie o arquivo de importação em um corpo multipart.",
+            ))
+        }
+    };
+
+    let file_name = field
+        .content_disposition()
+        .and_then(|content_disposition| content_disposition.get_filename())
+        .unwrap_or_default()
+        .to_lowercase();
+    let content_type = match field.content_type().map(|mime| mime.essence_str()) {
+        Some(content_type) if content_type != "application/octet-stream" => {
+            String::from(content_type)
+        }
+        _ if file_name.ends_with(".csv") => String::from("text/csv"),
+        _ if file_name.ends_with(".jsonl") || file_name.ends_with(".ndjson") => {
+            String::from("application/x-ndjson")
+        }
+        _ => String::from("application/octet-stream"),
+    };
+
+    let mut data: Vec<u8> = Vec::new();
+    while let Some(chunk) = field.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(_) => {
+                return Err(import_file_error(
+                    400,
+                    "bad request",
+                    "Não foi possível ler o arquivo de importação.",
+                ))
+            }
+        };
+        if data.len() + chunk.len() > *UPLOAD_BODY_SIZE_LIMIT_BYTES {
+            return Err(import_file_error(
+                413,
+                "payload too large",
+                &format!(
+                    "O arquivo de importação deve ter no máximo {} bytes.",
+                    *UPLOAD_BODY_SIZE_LIMIT_BYTES
+                ),
+            ));
+        }
+        data.extend_from_slice(&chunk);
+    }
+    Ok((content_type, data))
+}
+
+/// A Markdown part of the multipart body: its file name and either its bytes or the result
+/// rejecting it before parsing.
+type MarkdownFile = (String, Result<Vec<u8>, MarkdownImportResultDTO>);
+
+/// Reads up to `MAX_MARKDOWN_FILES` parts of the multipart body, rejecting the files that are not
+/// `.md` or exceed `MAX_MARKDOWN_FILE_BYTES` without failing the others.
+async fn read_markdown_files(mut payload: Multipart) -> Result<Vec<MarkdownFile>, HttpResponse> {
+    let mut files: Vec<MarkdownFile> = Vec::new();
+    while let Some(field) = payload.next().await {
+        let mut field = match field {
+            Ok(field) => field,
+            Err(_) => {
+                return Err(import_file_error(
+                    400,
+                    "bad request",
+                    "Não foi possível ler o corpo multipart.",
+                ))
+            }
+        };
+        if files.len() == MAX_MARKDOWN_FILES {
+            return Err(import_file_error(
+                400,
+                "bad request",
+                &format!(
+                    "Envie no máximo {} arquivos por requisição.",
+                    MAX_MARKDOWN_FILES
+                ),
+            ));
+        }
+
+        let file_name = field
+            .content_disposition()
+            .and_then(|content_disposition| content_disposition.get_filename())
+            .unwrap_or_default()
+            .to_string();
+        let rejected = |status: u16, reason: String| MarkdownImportResultDTO {
+            file: file_name.clone(),
+            status,
+            post_id: None,
+            reason: Some(reason),
+        };
+
+        let mut data: Vec<u8> = Vec::new();
+        let mut too_large = false;
+        while let Some(chunk) = field.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(_) => {
+                    return Err(import_file_error(
+                        400,
+                        "bad request",
+                        "Não foi possível ler o arquivo enviado.",
+                    ))
+                }
+            };
+            too_large = too_large || data.len() + chunk.len() > MAX_MARKDOWN_FILE_BYTES;
+            if !too_large {
+                data.extend_from_slice(&chunk);
+            }
+        }
+
+        let file = if !file_name.to_lowercase().ends_with(".md") {
+            Err(rejected(
+                415,
+                String::from("O arquivo deve ter a extensão .md."),
+            ))
+        } else if too_large {
+            Err(rejected(
+                413,
+                format!(
+                    "O arquivo deve ter no máximo {} bytes.",
+                    MAX_MARKDOWN_FILE_BYTES
+                ),
+            ))
+        } else {
+            Ok(data)
+        };
+        files.push((file_name, file));
+    }
+
+    if files.is_empty() {
+        return Err(import_file_error(
+            400,
+            "bad request",
+            "Envie ao menos um arquivo .md em um corpo multipart.",
+        ));
+    }
+    Ok(files)
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/post/import",
+    security(("bearer_auth" = [])),
+    request_body(
+        content = String, content_type = "multipart/form-data",
+        description = "Arquivo text/csv (colunas title,slug,body,author_email,published_at) ou application/x-ndjson com até 1000 linhas"
+    ),
+    responses((
+        status = 200, description = "Resultado da importação, com as linhas inválidas em errors (OK)",
+        body = ImportPostsResultDTO, content_type = "application/json", example = json ! ({
+            "imported": 42,
+            "updated": 3,
+            "errors": [{ "row": 7, "reason": "O slug deve conter apenas letras minúsculas, números e hífens." }]
+        })
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 413, description = "Arquivo com mais de 1000 linhas ou 10 MB (Payload Too Large)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "file": [{
+                "code": "payload too large",
+                "message": "O arquivo deve ter no máximo 1000 linhas.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 415, description = "Tipo de arquivo não suportado (Unsupported Media Type)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "file": [{
+                "code": "unsupported media type",
+                "message": "O arquivo deve ser do tipo text/csv ou application/x-ndjson.",
+                "params": {
+                    "min": null,
+                    "value": "application/pdf",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/post/import")]
+async fn bulk_import_posts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    payload: Multipart,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let (content_type, data) = match read_import_file(payload).await {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    let rows = match parse_import_file(&content_type, &data) {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    match bulk_import_posts_service(pg_pool, rows).await {
+        Ok(result) => {
+            if result.imported + result.updated > 0 {
+                invalidate_sitemap_service(&redis_pool).await;
+                invalidate_archive_service(&redis_pool).await;
+            }
+            HttpResponse::Ok().json(result)
+        }
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/post/import/markdown",
+    security(("bearer_auth" = [])),
+    request_body(
+        content = String, content_type = "multipart/form-data",
+        description = "Até 10 arquivos .md de até 1 MB, cada um com um front-matter YAML (title, slug, author_email, published_at, summary, language, tags e categories) seguido do corpo do post"
+    ),
+    responses((
+        status = 200, description = "Resultado de cada arquivo, com o status 201 e o id do post criado ou o status e o motivo da rejeição (400, 409, 413 ou 415) (OK)",
+        body = [MarkdownImportResultDTO], content_type = "application/json", example = json ! ([{
+            "file": "rust-e-actix-web.md",
+            "status": 201,
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10"
+        }, {
+            "file": "rascunho.md",
+            "status": 400,
+            "reason": "O arquivo deve começar com um front-matter YAML delimitado por linhas ---."
+        }, {
+            "file": "livro.md",
+            "status": 413,
+            "reason": "O arquivo deve ter no máximo 1048576 bytes."
+        }])
+    ), (
+		status = 400, description = "Corpo multipart sem arquivos ou com mais de 10 arquivos (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "file": [{
+                "code": "bad request",
+                "message": "Envie no máximo 10 arquivos por requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/post/import/markdown")]
+async fn import_markdown_posts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    payload: Multipart,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let files = match read_markdown_files(payload).await {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+
+    let mut results: Vec<MarkdownImportResultDTO> = Vec::with_capacity(files.len());
+    for (file_name, file) in files {
+        let result = match file {
+            Ok(data) => match import_markdown_post_service(pg_pool.clone(), file_name, &data).await
+            {
+                Ok(x) => x,
+                Err(e) => return e,
+            },
+            Err(result) => result,
+        };
+        results.push(result);
+    }
+    if results.iter().any(|result| result.status == 201) {
+        invalidate_sitemap_service(&redis_pool).await;
+        invalidate_archive_service(&redis_pool).await;
+    }
+    HttpResponse::Ok().json(results)
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/import/rss",
+    security(("bearer_auth" = [])),
+    request_body = RssImportDTO,
+    responses((
+        status = 200, description = "Resultado da importação dos itens do feed como posts do administrador, com os itens inválidos em errors (OK)",
+        body = ImportPostsResultDTO, content_type = "application/json", example = json ! ({
+            "imported": 12,
+            "updated": 1,
+            "errors": [{ "row": 4, "reason": "O item do feed não tem conteúdo." }]
+        })
+    ), (
+		status = 400, description = "URL do feed inválida, inacessível ou sem um RSS válido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "feed_url": [{
+                "code": "regex",
+                "message": "A URL do feed deve começar com http:// ou https://.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 429, description = "Já existe uma importação de RSS em andamento (Too Many Requests)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "import": [{
+                "code": "too many requests",
+                "message": "Já existe uma importação de RSS em andamento.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/import/rss")]
+async fn import_rss_posts(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<RssImportDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match admin_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match import_rss_posts_service(
+        pg_pool,
+        redis_pool.clone(),
+        token.claims.sub,
+        body.into_inner().feed_url,
+    )
+    .await
+    {
+        Ok(result) => {
+            if result.imported + result.updated > 0 {
+                invalidate_sitemap_service(&redis_pool).await;
+                invalidate_archive_service(&redis_pool).await;
+            }
+            HttpResponse::Ok().json(result)
+        }
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,123 @@
+use crate::modules::post::post_providers::language_valid_provider;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+static RE_SLUG: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+static RE_FEED_URL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^https?://\S+$").unwrap());
+
+fn names_valid(names: &[String]) -> Result<(), ValidationError> {
+    if names
+        .iter()
+        .all(|name| !name.trim().is_empty() && name.chars().count() <= 63)
+    {
+        return Ok(());
+    }
+    Err(ValidationError::new("length").with_message(Cow::from(
+        "As tags e categorias devem ter entre 1 e 63 caracteres.",
+    )))
+}
+
+#[derive(Serialize, Deserialize, Validate, Clone)]
+pub struct ImportPostDTO {
+    #[validate(length(
+        min = 3,
+        max = 127,
+        message = "O título deve ter entre 3 e 127 caracteres."
+    ))]
+    pub title: String,
+
+    #[validate(
+        length(
+            min = 3,
+            max = 255,
+            message = "O slug deve ter entre 3 e 255 caracteres."
+        ),
+        regex(
+            path = *RE_SLUG,
+            message = "O slug deve conter apenas letras minúsculas, números e hífens."
+        )
+    )]
+    pub slug: String,
+
+    #[validate(length(min = 10, message = "O corpo deve ter pelo menos 10 caracteres."))]
+    pub body: String,
+
+    #[validate(email(message = "O e-mail do autor deve ser um endereço válido."))]
+    pub author_email: String,
+
+    #[serde(default)]
+    pub published_at: Option<String>,
+
+    #[validate(length(max = 500, message = "O resumo deve ter no máximo 500 caracteres."))]
+    #[serde(default)]
+    pub summary: Option<String>,
+
+    #[validate(custom(function = "language_valid_provider"))]
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ImportErrorDTO {
+    pub row: usize,
+    pub reason: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ImportPostsResultDTO {
+    pub imported: i64,
+    pub updated: i64,
+    pub errors: Vec<ImportErrorDTO>,
+}
+
+/// The YAML front-matter of a Markdown import file.
+#[derive(Serialize, Deserialize, Validate, Clone)]
+pub struct MarkdownFrontMatterDTO {
+    pub title: String,
+    pub slug: String,
+    pub author_email: String,
+
+    #[serde(default)]
+    pub published_at: Option<String>,
+
+    #[serde(default)]
+    pub summary: Option<String>,
+
+    #[serde(default)]
+    pub language: Option<String>,
+
+    #[validate(custom(function = "names_valid"))]
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[validate(custom(function = "names_valid"))]
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownImportResultDTO {
+    pub file: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate)]
+pub struct RssImportDTO {
+    #[validate(
+        length(max = 2048, message = "A URL do feed deve ter no máximo 2048 caracteres."),
+        regex(
+            path = *RE_FEED_URL,
+            message = "A URL do feed deve começar com http:// ou https://."
+        )
+    )]
+    pub feed_url: String,
+}
@@ -0,0 +1,235 @@
+use crate::{
+    shared::parsers::{
+        code_languages_parser::extract_code_languages,
+        text_counts_parser::count_words_and_characters,
+    },
+    utils::query_constructor_executor::query_executor,
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+const AUTHORS_BY_EMAIL_SQL: &str =
+    "SELECT id, email FROM users WHERE email = ANY($1) AND deleted_at IS NULL";
+
+const AUTHOR_EMAIL_SQL: &str = "SELECT email FROM users WHERE id = $1 AND deleted_at IS NULL";
+
+const UPSERT_POSTS_SQL: &str = "
+    WITH upserted AS (
+        INSERT INTO posts (
+            id, title, subtitle, body, summary, author_id, slug, published_at, language,
+            word_count, character_count, code_languages, created_at, tenant_id
+        )
+        SELECT t.id, t.title, '', t.body, t.summary, t.author_id, t.slug, t.published_at,
+            t.language, t.word_count, t.character_count, STRING_TO_ARRAY(t.code_languages, ','),
+            NOW(), u.tenant_id
+        FROM UNNEST(
+            $1::UUID[], $2::TEXT[], $3::TEXT[], $4::TEXT[], $5::UUID[], $6::TEXT[],
+            $7::TIMESTAMPTZ[], $8::TEXT[], $9::INT[], $10::INT[], $11::TEXT[]
+        ) AS t (
+            id, title, body, summary, author_id, slug, published_at, language, word_count,
+            character_count, code_languages
+        )
+        JOIN users u ON u.id = t.author_id
+        ON CONFLICT (tenant_id, slug) DO UPDATE SET
+            title = EXCLUDED.title, body = EXCLUDED.body, summary = EXCLUDED.summary,
+            author_id = EXCLUDED.author_id, language = EXCLUDED.language,
+            published_at = EXCLUDED.published_at, word_count = EXCLUDED.word_count,
+            character_count = EXCLUDED.character_count, code_languages = EXCLUDED.code_languages,
+            updated_at = NOW()
+        RETURNING id, author_id, (xmax = 0) AS inserted
+    ),
+    old_primary_authors AS (
+        DELETE FROM post_authors pa USING upserted
+        WHERE pa.post_id = upserted.id AND pa.role = 'primary' AND pa.user_id <> upserted.author_id
+    ),
+    primary_authors AS (
+        INSERT INTO post_authors (post_id, user_id, role, added_at)
+        SELECT id, author_id, 'primary', NOW() FROM upserted
+        ON CONFLICT (post_id, user_id) DO UPDATE SET role = 'primary'
+    )
+    SELECT inserted FROM upserted
+";
+
+const INSERT_POST_SQL: &str = "
+    WITH post AS (
+        INSERT INTO posts (
+            id, title, subtitle, body, summary, author_id, slug, published_at, language,
+            word_count, character_count, code_languages, created_at, tenant_id
+        )
+        SELECT $1, $2, '', $3, $4, $5, $6, $7, $8, $13, $14, $15, NOW(), u.tenant_id
+        FROM users u WHERE u.id = $5
+        ON CONFLICT (tenant_id, slug) DO NOTHING
+        RETURNING id, author_id
+    ),
+    primary_author AS (
+        INSERT INTO post_authors (post_id, user_id, role, added_at)
+        SELECT id, author_id, 'primary', NOW() FROM post
+    ),
+    new_tags AS (
+        INSERT INTO tags (id, name, created_at)
+        SELECT id, name, NOW() FROM UNNEST($9::UUID[], $10::TEXT[]) AS t (id, name)
+        WHERE EXISTS (SELECT 1 FROM post)
+        ON CONFLICT (name) DO NOTHING
+        RETURNING id
+    ),
+    post_tags AS (
+        INSERT INTO posts_tags (post_id, tag_id)
+        SELECT post.id, t.id
+        FROM post, (SELECT id FROM new_tags UNION SELECT id FROM tags WHERE name = ANY($10)) t
+    ),
+    new_categories AS (
+        INSERT INTO categories (id, name, created_at)
+        SELECT id, name, NOW() FROM UNNEST($11::UUID[], $12::TEXT[]) AS c (id, name)
+        WHERE EXISTS (SELECT 1 FROM post)
+        ON CONFLICT (name) DO NOTHING
+        RETURNING id
+    ),
+    post_categories AS (
+        INSERT INTO posts_categories (post_id, category_id)
+        SELECT post.id, c.id
+        FROM post, (
+            SELECT id FROM new_categories UNION SELECT id FROM categories WHERE name = ANY($12)
+        ) c
+    )
+    SELECT id FROM post
+";
+
+/// A validated import row, with its author already resolved.
+pub struct ImportPostRow {
+    pub title: String,
+    pub slug: String,
+    pub body: String,
+    pub summary: String,
+    pub author_id: uuid::Uuid,
+    pub published_at: Option<DateTime<Utc>>,
+    pub language: String,
+}
+
+pub async fn authors_by_email_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    emails: Vec<String>,
+) -> Result<HashMap<String, uuid::Uuid>, HttpResponse> {
+    let rows = match query_executor(pg_pool, AUTHORS_BY_EMAIL_SQL, &[&emails]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| (row.get("email"), row.get("id")))
+        .collect())
+}
+
+pub async fn author_email_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    author_id: uuid::Uuid,
+) -> Result<Option<String>, HttpResponse> {
+    match query_executor(pg_pool, AUTHOR_EMAIL_SQL, &[&author_id]).await {
+        Ok(rows) => Ok(rows.first().map(|row| row.get("email"))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Inserts the new posts and updates the existing ones (matched by slug) in a single statement.
+///
+/// Returns the number of inserted and updated posts.
+pub async fn upsert_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    posts: Vec<ImportPostRow>,
+) -> Result<(i64, i64), HttpResponse> {
+    if posts.is_empty() {
+        return Ok((0, 0));
+    }
+
+    let ids: Vec<uuid::Uuid> = posts.iter().map(|_| uuid::Uuid::new_v4()).collect();
+    let titles: Vec<&str> = posts.iter().map(|post| post.title.as_str()).collect();
+    let bodies: Vec<&str> = posts.iter().map(|post| post.body.as_str()).collect();
+    let summaries: Vec<&str> = posts.iter().map(|post| post.summary.as_str()).collect();
+    let author_ids: Vec<uuid::Uuid> = posts.iter().map(|post| post.author_id).collect();
+    let slugs: Vec<&str> = posts.iter().map(|post| post.slug.as_str()).collect();
+    let published_ats: Vec<Option<DateTime<Utc>>> =
+        posts.iter().map(|post| post.published_at).collect();
+    let languages: Vec<&str> = posts.iter().map(|post| post.language.as_str()).collect();
+    let (word_counts, character_counts): (Vec<i32>, Vec<i32>) = posts
+        .iter()
+        .map(|post| count_words_and_characters(&post.body))
+        .unzip();
+    // Each post has its own list of languages, so they are sent comma separated (the identifiers
+    // never contain commas) and split back in the statement.
+    let code_languages: Vec<String> = posts
+        .iter()
+        .map(|post| extract_code_languages(&post.body).join(","))
+        .collect();
+
+    let rows = match query_executor(
+        pg_pool,
+        UPSERT_POSTS_SQL,
+        &[
+            &ids,
+            &titles,
+            &bodies,
+            &summaries,
+            &author_ids,
+            &slugs,
+            &published_ats,
+            &languages,
+            &word_counts,
+            &character_counts,
+            &code_languages,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let imported = rows
+        .iter()
+        .filter(|row| row.get::<_, bool>("inserted"))
+        .count() as i64;
+    Ok((imported, rows.len() as i64 - imported))
+}
+
+/// Inserts a single post linked to its tags and categories, creating the missing ones by name,
+/// with the word and character counts and the code languages of its body.
+///
+/// Returns `None` if there is already a post with the slug.
+pub async fn insert_post_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    post: ImportPostRow,
+    tags: Vec<String>,
+    categories: Vec<String>,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    let tag_ids: Vec<uuid::Uuid> = tags.iter().map(|_| uuid::Uuid::new_v4()).collect();
+    let category_ids: Vec<uuid::Uuid> = categories.iter().map(|_| uuid::Uuid::new_v4()).collect();
+    let (word_count, character_count) = count_words_and_characters(&post.body);
+    let code_languages = extract_code_languages(&post.body);
+    match query_executor(
+        pg_pool,
+        INSERT_POST_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &post.title,
+            &post.body,
+            &post.summary,
+            &post.author_id,
+            &post.slug,
+            &post.published_at,
+            &post.language,
+            &tag_ids,
+            &tags,
+            &category_ids,
+            &categories,
+            &word_count,
+            &character_count,
+            &code_languages,
+        ],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.first().map(|row| row.get("id"))),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,538 @@
+use super::{
+    post_import_dtos::{
+        ImportErrorDTO, ImportPostDTO, ImportPostsResultDTO, MarkdownFrontMatterDTO,
+        MarkdownImportResultDTO,
+    },
+    post_import_repositories::{
+        author_email_repository, authors_by_email_repository, insert_post_repository,
+        upsert_posts_repository, ImportPostRow,
+    },
+};
+use crate::{
+    infra::redis::Redis,
+    middlewares::body_size_limit_middleware::UPLOAD_BODY_SIZE_LIMIT_BYTES,
+    modules::post::post_providers::detect_language_provider,
+    shared::parsers::{slug_parser::slugify, summary_parser::generate_summary},
+    utils::error_construct::error_construct,
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use std::{collections::HashSet, time::Duration};
+use validator::{Validate, ValidationErrors};
+
+pub const MAX_IMPORT_ROWS: usize = 1000;
+pub const MAX_MARKDOWN_FILES: usize = 10;
+pub const MAX_MARKDOWN_FILE_BYTES: usize = 1024 * 1024;
+pub const RSS_IMPORT_LOCK_KEY: &str = "import:rss:lock";
+const RSS_IMPORT_LOCK_SECONDS: u64 = 300;
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .unwrap()
+});
+
+/// A parsed row of the import file: its 1-based number and the row or the reason it is invalid.
+pub type ImportFileRow = (usize, Result<ImportPostDTO, String>);
+
+fn validation_reason(errors: ValidationErrors) -> String {
+    let mut reasons: Vec<String> = errors
+        .field_errors()
+        .into_iter()
+        .flat_map(|(field, errors)| {
+            errors.iter().map(move |error| match &error.message {
+                Some(message) => message.to_string(),
+                None => format!("O campo {} é inválido.", field),
+            })
+        })
+        .collect();
+    reasons.sort();
+    reasons.join(" ")
+}
+
+fn too_many_rows() -> HttpResponse {
+    HttpResponse::PayloadTooLarge().json(error_construct(
+        String::from("file"),
+        String::from("payload too large"),
+        format!("O arquivo deve ter no máximo {} linhas.", MAX_IMPORT_ROWS),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Parses a `text/csv` (with the `title,slug,body,author_email,published_at` header and the
+/// optional `summary` and `language` columns) or an `application/x-ndjson` import file.
+pub fn parse_import_file(
+    content_type: &str,
+    data: &[u8],
+) -> Result<Vec<ImportFileRow>, HttpResponse> {
+    let mut rows: Vec<ImportFileRow> = Vec::new();
+    match content_type {
+        "text/csv" => {
+            let mut reader = csv::Reader::from_reader(data);
+            for (index, record) in reader.deserialize::<ImportPostDTO>().enumerate() {
+                if index == MAX_IMPORT_ROWS {
+                    return Err(too_many_rows());
+                }
+                rows.push((index + 1, record.map_err(|e| e.to_string())));
+            }
+        }
+        "application/x-ndjson" => {
+            let lines = String::from_utf8_lossy(data);
+            for (index, line) in lines
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .enumerate()
+            {
+                if index == MAX_IMPORT_ROWS {
+                    return Err(too_many_rows());
+                }
+                rows.push((
+                    index + 1,
+                    serde_json::from_str::<ImportPostDTO>(line).map_err(|e| e.to_string()),
+                ));
+            }
+        }
+        _ => {
+            return Err(HttpResponse::UnsupportedMediaType().json(error_construct(
+                String::from("file"),
+                String::from("unsupported media type"),
+                String::from("O arquivo deve ser do tipo text/csv ou application/x-ndjson."),
+                Some(String::from(content_type)),
+                None,
+                None,
+            )))
+        }
+    }
+    Ok(rows)
+}
+
+fn parse_published_at(published_at: Option<String>) -> Result<Option<DateTime<Utc>>, String> {
+    match published_at.filter(|published_at| !published_at.trim().is_empty()) {
+        Some(published_at) => match DateTime::parse_from_rfc3339(published_at.trim()) {
+            Ok(published_at) => Ok(Some(published_at.with_timezone(&Utc))),
+            Err(_) => Err(String::from(
+                "A data de publicação deve estar no formato RFC 3339.",
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Validates the rows, resolves their authors by e-mail and upserts the valid ones by slug.
+///
+/// Rows without a `language` get the one detected from their body.
+/// Invalid rows do not stop the import; they are reported in `errors` with their row number.
+pub async fn bulk_import_posts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    rows: Vec<ImportFileRow>,
+) -> Result<ImportPostsResultDTO, HttpResponse> {
+    let mut result = ImportPostsResultDTO::default();
+    let mut slugs: HashSet<String> = HashSet::new();
+    let mut valid_rows: Vec<(usize, ImportPostDTO, Option<DateTime<Utc>>)> = Vec::new();
+
+    for (row, post) in rows {
+        let post = match post {
+            Ok(post) => post,
+            Err(reason) => {
+                result.errors.push(ImportErrorDTO { row, reason });
+                continue;
+            }
+        };
+        if let Err(e) = post.validate() {
+            result.errors.push(ImportErrorDTO {
+                row,
+                reason: validation_reason(e),
+            });
+            continue;
+        }
+        let published_at = match parse_published_at(post.published_at.clone()) {
+            Ok(published_at) => published_at,
+            Err(reason) => {
+                result.errors.push(ImportErrorDTO { row, reason });
+                continue;
+            }
+        };
+        if !slugs.insert(post.slug.clone()) {
+            result.errors.push(ImportErrorDTO {
+                row,
+                reason: String::from("Este slug já foi informado em outra linha do arquivo."),
+            });
+            continue;
+        }
+        valid_rows.push((row, post, published_at));
+    }
+
+    let emails: Vec<String> = valid_rows
+        .iter()
+        .map(|(_, post, _)| post.author_email.clone())
+        .collect();
+    let authors = match authors_by_email_repository(pg_pool.clone(), emails).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut posts: Vec<ImportPostRow> = Vec::with_capacity(valid_rows.len());
+    for (row, post, published_at) in valid_rows {
+        match authors.get(&post.author_email) {
+            Some(author_id) => posts.push(ImportPostRow {
+                title: post.title,
+                slug: post.slug,
+                summary: match post.summary {
+                    Some(summary) => summary,
+                    None => generate_summary(&post.body),
+                },
+                language: match post.language {
+                    Some(language) => language,
+                    None => detect_language_provider(&post.body),
+                },
+                body: post.body,
+                author_id: *author_id,
+                published_at,
+            }),
+            None => result.errors.push(ImportErrorDTO {
+                row,
+                reason: String::from("Não foi encontrado um usuário com o e-mail do autor."),
+            }),
+        }
+    }
+
+    let (imported, updated) = match upsert_posts_repository(pg_pool, posts).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    result.imported = imported;
+    result.updated = updated;
+    result.errors.sort_by_key(|error| error.row);
+    Ok(result)
+}
+
+/// Splits a Markdown file into its YAML front-matter, delimited by `---` lines at the top of the
+/// file, and its body.
+pub fn parse_markdown_file(data: &[u8]) -> Result<(MarkdownFrontMatterDTO, String), String> {
+    let invalid_front_matter = || {
+        String::from("O arquivo deve começar com um front-matter YAML delimitado por linhas ---.")
+    };
+    let content = match std::str::from_utf8(data) {
+        Ok(content) => content.trim_start_matches('\u{feff}'),
+        Err(_) => return Err(String::from("O arquivo deve estar codificado em UTF-8.")),
+    };
+    let rest = match content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    {
+        Some(rest) => rest,
+        None => return Err(invalid_front_matter()),
+    };
+
+    let mut front_matter_end = None;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            front_matter_end = Some((offset, offset + line.len()));
+            break;
+        }
+        offset += line.len();
+    }
+    let (yaml_end, body_start) = match front_matter_end {
+        Some(x) => x,
+        None => return Err(invalid_front_matter()),
+    };
+
+    match serde_yaml::from_str::<MarkdownFrontMatterDTO>(&rest[..yaml_end]) {
+        Ok(front_matter) => Ok((front_matter, rest[body_start..].trim().to_string())),
+        Err(e) => Err(format!("O front-matter YAML é inválido: {}", e)),
+    }
+}
+
+fn markdown_result(file: String, status: u16, reason: String) -> MarkdownImportResultDTO {
+    MarkdownImportResultDTO {
+        file,
+        status,
+        post_id: None,
+        reason: Some(reason),
+    }
+}
+
+fn unique_names(names: Vec<String>) -> Vec<String> {
+    let mut seen: HashSet<String> = HashSet::new();
+    names
+        .into_iter()
+        .map(|name| name.trim().to_string())
+        .filter(|name| seen.insert(name.clone()))
+        .collect()
+}
+
+/// Validates a Markdown file with the same rules of the bulk import and inserts it as a new post.
+///
+/// Invalid files do not fail the request: the result carries the status of the file (`201`,
+/// `400` or `409` when the slug already exists) and the reason it was rejected.
+pub async fn import_markdown_post_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    file: String,
+    data: &[u8],
+) -> Result<MarkdownImportResultDTO, HttpResponse> {
+    let (front_matter, body) = match parse_markdown_file(data) {
+        Ok(x) => x,
+        Err(reason) => return Ok(markdown_result(file, 400, reason)),
+    };
+    if let Err(e) = front_matter.validate() {
+        return Ok(markdown_result(file, 400, validation_reason(e)));
+    }
+    let post = ImportPostDTO {
+        title: front_matter.title,
+        slug: front_matter.slug,
+        body,
+        author_email: front_matter.author_email,
+        published_at: front_matter.published_at,
+        summary: front_matter.summary,
+        language: front_matter.language,
+    };
+    if let Err(e) = post.validate() {
+        return Ok(markdown_result(file, 400, validation_reason(e)));
+    }
+    let published_at = match parse_published_at(post.published_at.clone()) {
+        Ok(published_at) => published_at,
+        Err(reason) => return Ok(markdown_result(file, 400, reason)),
+    };
+
+    let authors =
+        match authors_by_email_repository(pg_pool.clone(), vec![post.author_email.clone()]).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+    let author_id = match authors.get(&post.author_email) {
+        Some(author_id) => *author_id,
+        None => {
+            return Ok(markdown_result(
+                file,
+                400,
+                String::from("Não foi encontrado um usuário com o e-mail do autor."),
+            ))
+        }
+    };
+
+    let row = ImportPostRow {
+        title: post.title,
+        slug: post.slug,
+        summary: match post.summary {
+            Some(summary) => summary,
+            None => generate_summary(&post.body),
+        },
+        language: match post.language {
+            Some(language) => language,
+            None => detect_language_provider(&post.body),
+        },
+        body: post.body,
+        author_id,
+        published_at,
+    };
+    match insert_post_repository(
+        pg_pool,
+        row,
+        unique_names(front_matter.tags),
+        unique_names(front_matter.categories),
+    )
+    .await
+    {
+        Ok(Some(post_id)) => Ok(MarkdownImportResultDTO {
+            file,
+            status: 201,
+            post_id: Some(post_id.to_string()),
+            reason: None,
+        }),
+        Ok(None) => Ok(markdown_result(
+            file,
+            409,
+            String::from("Já existe um post com este slug."),
+        )),
+        Err(e) => Err(e),
+    }
+}
+
+fn feed_error(message: &str, feed_url: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(error_construct(
+        String::from("feed_url"),
+        String::from("bad request"),
+        String::from(message),
+        Some(String::from(feed_url)),
+        None,
+        None,
+    ))
+}
+
+/// The slug of an RSS item: the last segment of the path of its link, without the extension,
+/// falling back to the title when the link has no path.
+fn rss_item_slug(link: Option<&str>, title: &str) -> String {
+    let segment = link
+        .and_then(|link| reqwest::Url::parse(link).ok())
+        .and_then(|url| {
+            url.path_segments()
+                .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+                .map(|segment| segment.split('.').next().unwrap_or_default().to_string())
+        })
+        .unwrap_or_default();
+    match slugify(&segment) {
+        slug if slug.is_empty() => slugify(title),
+        slug => slug,
+    }
+}
+
+/// Parses an RSS 2.0 feed into import rows authored by `author_email`, one per item and limited
+/// to the first `MAX_IMPORT_ROWS` items.
+///
+/// The body is the `<content:encoded>` of the item, or its `<description>` when it has none, and
+/// the `<pubDate>` becomes the publication date.
+pub fn parse_rss_feed(data: &[u8], author_email: &str) -> Result<Vec<ImportFileRow>, String> {
+    let channel = match rss::Channel::read_from(data) {
+        Ok(channel) => channel,
+        Err(_) => return Err(String::from("O conteúdo da URL não é um feed RSS válido.")),
+    };
+
+    Ok(channel
+        .items()
+        .iter()
+        .take(MAX_IMPORT_ROWS)
+        .enumerate()
+        .map(|(index, item)| {
+            let title = match item.title() {
+                Some(title) => title.trim().to_string(),
+                None => {
+                    return (
+                        index + 1,
+                        Err(String::from("O item do feed não tem título.")),
+                    )
+                }
+            };
+            let body = match item.content().or(item.description()) {
+                Some(body) => body.trim().to_string(),
+                None => {
+                    return (
+                        index + 1,
+                        Err(String::from("O item do feed não tem conteúdo.")),
+                    )
+                }
+            };
+            let published_at = match item.pub_date() {
+                Some(pub_date) => match DateTime::parse_from_rfc2822(pub_date.trim()) {
+                    Ok(published_at) => Some(published_at.with_timezone(&Utc).to_rfc3339()),
+                    Err(_) => {
+                        return (
+                            index + 1,
+                            Err(String::from("A data de publicação do item é inválida.")),
+                        )
+                    }
+                },
+                None => None,
+            };
+            (
+                index + 1,
+                Ok(ImportPostDTO {
+                    slug: rss_item_slug(item.link(), &title),
+                    title,
+                    body,
+                    author_email: String::from(author_email),
+                    published_at,
+                    summary: None,
+                    language: None,
+                }),
+            )
+        })
+        .collect())
+}
+
+async fn fetch_rss_feed(feed_url: &str) -> Result<Vec<u8>, HttpResponse> {
+    let resp = match HTTP_CLIENT.get(feed_url).send().await {
+        Ok(resp) if resp.status().is_success() => resp,
+        _ => return Err(feed_error("A URL do feed não está acessível.", feed_url)),
+    };
+    match resp.bytes().await {
+        Ok(data) if data.len() <= *UPLOAD_BODY_SIZE_LIMIT_BYTES => Ok(data.to_vec()),
+        Ok(_) => Err(feed_error(
+            "O feed excede o tamanho máximo de importação.",
+            feed_url,
+        )),
+        Err(_) => Err(feed_error("Não foi possível ler o feed.", feed_url)),
+    }
+}
+
+async fn rss_import(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    author_id: uuid::Uuid,
+    feed_url: &str,
+) -> Result<ImportPostsResultDTO, HttpResponse> {
+    let author_email = match author_email_repository(pg_pool.clone(), author_id).await {
+        Ok(Some(email)) => email,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(error_construct(
+                String::from("user"),
+                String::from("not found"),
+                String::from("Não foi encontrado um usuário com este id."),
+                Some(author_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Err(e) => return Err(e),
+    };
+    let data = match fetch_rss_feed(feed_url).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let rows = match parse_rss_feed(&data, &author_email) {
+        Ok(x) => x,
+        Err(reason) => return Err(feed_error(&reason, feed_url)),
+    };
+    bulk_import_posts_service(pg_pool, rows).await
+}
+
+/// Imports the items of an RSS feed as posts of the admin, upserting them by slug with the same
+/// validation of the bulk import.
+///
+/// Only one RSS import runs at a time, guarded by the `import:rss:lock` Redis key, which expires
+/// after 5 minutes if the import never releases it.
+pub async fn import_rss_posts_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    admin_id: String,
+    feed_url: String,
+) -> Result<ImportPostsResultDTO, HttpResponse> {
+    match Redis::set_nx_ex(
+        &redis_pool,
+        RSS_IMPORT_LOCK_KEY,
+        "1",
+        RSS_IMPORT_LOCK_SECONDS,
+    )
+    .await
+    {
+        Ok(true) => (),
+        Ok(false) => {
+            return Err(HttpResponse::TooManyRequests().json(error_construct(
+                String::from("import"),
+                String::from("too many requests"),
+                String::from("Já existe uma importação de RSS em andamento."),
+                None,
+                None,
+                None,
+            )))
+        }
+        Err(e) => {
+            return Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                String::from("redis"),
+                String::from("service unavailable"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            )))
+        }
+    };
+
+    let author_id = uuid::Uuid::parse_str(&admin_id).unwrap_or_default();
+    let result = rss_import(pg_pool, author_id, &feed_url).await;
+    let _ = Redis::delete(&redis_pool, RSS_IMPORT_LOCK_KEY).await;
+    result
+}
@@ -0,0 +1,4 @@
+pub mod post_view_controllers;
+pub mod post_view_dtos;
+pub mod post_view_repositories;
+pub mod post_view_services;
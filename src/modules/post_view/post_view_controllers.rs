@@ -0,0 +1,86 @@
+use super::post_view_services::read_history_service;
+use crate::{
+    middlewares::auth_middleware::auth_middleware, shared::structs::cursor_params::CursorParams,
+};
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+
+pub fn post_view_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(read_history);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/history",
+    security(("bearer_auth" = [])),
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Posts lidos pelo usuário, do mais recente ao mais antigo (OK)",
+        content_type = "application/json", example = json ! ({
+            "data": [{
+                "id": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "title": "Rust e Actix Web",
+                "subtitle": "Criando APIs performáticas",
+                "viewedAt": "2024-06-18 22:03:54.053147 UTC"
+            }],
+            "nextCursor": null
+        })
+    ), (
+		status = 400, description = "Erro do usuário por id ou cursor inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "cursor": [{
+                "code": "bad request",
+                "message": "Cursor inválido.",
+                "params": {
+                    "min": null,
+                    "value": "abc",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/history")]
+async fn read_history(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match read_history_service(pg_pool, user_id.into_inner(), query_params.into_inner()).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadPostDTO {
+    pub id: String,
+    pub title: String,
+    pub subtitle: String,
+    pub viewed_at: String,
+}
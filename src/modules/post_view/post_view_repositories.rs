@@ -0,0 +1,86 @@
+use super::post_view_dtos::ReadPostDTO;
+use crate::{
+    shared::structs::cursor_params::CursorPage,
+    utils::{
+        cursor::{encode_cursor, CursorPosition},
+        query_constructor_executor::query_executor,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const READ_HISTORY_SQL: &str = "
+    SELECT p.id, p.title, p.subtitle, pv.viewed_at FROM post_views pv
+    INNER JOIN posts p ON p.id = pv.post_id
+    WHERE pv.user_id = $1
+        AND ($2::TIMESTAMPTZ IS NULL OR (pv.viewed_at, p.id) < ($2, $3))
+    ORDER BY pv.viewed_at DESC, p.id DESC
+    LIMIT $4
+";
+
+pub async fn _record_post_view_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    post_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    const RECORD_POST_VIEW_SQL: &str = "
+        INSERT INTO post_views (user_id, post_id, viewed_at) VALUES ($1, $2, NOW())
+        ON CONFLICT (user_id, post_id) DO UPDATE SET viewed_at = EXCLUDED.viewed_at
+    ";
+
+    match query_executor(pg_pool, RECORD_POST_VIEW_SQL, &[&user_id, &post_id]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn _has_read_post_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    post_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    const HAS_READ_POST_SQL: &str = "
+        SELECT EXISTS (SELECT 1 FROM post_views WHERE user_id = $1 AND post_id = $2) AS has_read
+    ";
+
+    match query_executor(pg_pool, HAS_READ_POST_SQL, &[&user_id, &post_id]).await {
+        Ok(rows) => Ok(rows[0].get("has_read")),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn read_history_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+) -> Result<CursorPage<ReadPostDTO>, HttpResponse> {
+    let (cursor_viewed_at, cursor_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        READ_HISTORY_SQL,
+        &[&user_id, &cursor_viewed_at, &cursor_id, &(limit + 1)],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<ReadPostDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        let id: uuid::Uuid = row.get("id");
+        let viewed_at: DateTime<Utc> = row.get("viewed_at");
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(viewed_at, id));
+        }
+        data.push(ReadPostDTO {
+            id: id.to_string(),
+            title: row.get("title"),
+            subtitle: row.get("subtitle"),
+            viewed_at: viewed_at.to_string(),
+        });
+    }
+    Ok(CursorPage { data, next_cursor })
+}
@@ -0,0 +1,52 @@
+use super::{post_view_dtos::ReadPostDTO, post_view_repositories::*};
+use crate::{
+    middlewares::jwt_token_middleware::jwt_token_middleware,
+    shared::structs::cursor_params::{CursorPage, CursorParams},
+    utils::cursor::cursor_page_params,
+};
+use actix_web::{http::header::HeaderMap, web::Data, HttpResponse};
+
+/// Records the view of a post by the authenticated user of the request.
+///
+/// Anonymous requests (or requests with an invalid token) are not recorded. It must be called by the
+/// post detail service, and the result fills its `has_read` field.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains whether the user had already read the post before
+/// this view, or `None` for anonymous requests. On failure, returns an `HttpResponse` with the corresponding error.
+pub async fn _record_post_view_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    headers: &HeaderMap,
+    post_id: uuid::Uuid,
+) -> Result<Option<bool>, HttpResponse> {
+    let user_id = match jwt_token_middleware(headers) {
+        Ok(token) => match uuid::Uuid::parse_str(&token.claims.sub) {
+            Ok(user_id) => user_id,
+            Err(_) => return Ok(None),
+        },
+        Err(_) => return Ok(None),
+    };
+
+    let has_read = match _has_read_post_repository(pg_pool.clone(), user_id, post_id).await {
+        Ok(has_read) => has_read,
+        Err(e) => return Err(e),
+    };
+    match _record_post_view_repository(pg_pool, user_id, post_id).await {
+        Ok(_) => Ok(Some(has_read)),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn read_history_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    params: CursorParams,
+) -> Result<CursorPage<ReadPostDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    read_history_repository(pg_pool, user_id, cursor, limit).await
+}
@@ -0,0 +1,4 @@
+pub mod reading_list_controllers;
+pub mod reading_list_dtos;
+pub mod reading_list_repositories;
+pub mod reading_list_services;
@@ -0,0 +1,621 @@
+use super::{
+    reading_list_dtos::{InsertReadingListDTO, InsertReadingListItemDTO, PutReadingListDTO},
+    reading_list_services::*,
+};
+use crate::{
+    middlewares::jwt_token_middleware::jwt_token_middleware,
+    shared::structs::cursor_params::CursorParams,
+};
+use actix_web::{delete, get, patch, post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn reading_list_controllers_module() -> actix_web::Scope {
+    web::scope("/reading-list")
+        .service(insert_reading_list)
+        .service(list_reading_lists)
+        .service(detail_reading_list)
+        .service(put_reading_list)
+        .service(delete_reading_list)
+        .service(insert_reading_list_item)
+        .service(delete_reading_list_item)
+}
+
+#[utoipa::path(
+    tag = "reading_list",
+    path = "/reading-list",
+    security(("bearer_auth" = [])),
+    request_body = InsertReadingListDTO,
+    responses((
+        status = 201, description = "Lista de leitura criada (Created)",
+        body = ReadingListDTO
+    ), (
+		status = 400, description = "Erro do usuário por nome inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "name": [{
+                "code": "length",
+                "message": "O nome da lista deve ter entre 3 e 100 caracteres.",
+                "params": {
+                    "min": null,
+                    "value": "ab",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("")]
+async fn insert_reading_list(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    body: web::Json<InsertReadingListDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match insert_reading_list_service(pg_pool, token.claims.sub, body.into_inner()).await {
+        Ok(list) => HttpResponse::Created().json(list),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "reading_list",
+    path = "/reading-list",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Listas de leitura do usuário autenticado com a quantidade de itens (OK)",
+        body = Vec<ReadingListDTO>
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("")]
+async fn list_reading_lists(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match list_user_reading_lists_service(pg_pool, token.claims.sub).await {
+        Ok(lists) => HttpResponse::Ok().json(lists),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "reading_list",
+    path = "/reading-list/{reading_list_id}",
+    security(("bearer_auth" = [])),
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Lista de leitura com uma página de seus posts; listas privadas exigem o token do dono (OK)",
+        body = DetailReadingListDTO
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "A lista de leitura pertence a outro usuário (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "forbidden",
+                "message": "Apenas o dono pode acessar esta lista de leitura.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Lista de leitura não encontrada (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "not found",
+                "message": "Não foi encontrada uma lista de leitura com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/{reading_list_id}")]
+async fn detail_reading_list(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    reading_list_id: web::Path<String>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match detail_reading_list_service(
+        pg_pool,
+        req.headers(),
+        reading_list_id.into_inner(),
+        query_params.into_inner(),
+    )
+    .await
+    {
+        Ok(list) => HttpResponse::Ok().json(list),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "reading_list",
+    path = "/reading-list/{reading_list_id}",
+    security(("bearer_auth" = [])),
+    request_body = PutReadingListDTO,
+    responses((
+        status = 200, description = "Lista de leitura atualizada (OK)",
+        body = ReadingListDTO
+    ), (
+		status = 400, description = "Erro do usuário por nome inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "name": [{
+                "code": "length",
+                "message": "O nome da lista deve ter entre 3 e 100 caracteres.",
+                "params": {
+                    "min": null,
+                    "value": "ab",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "A lista de leitura pertence a outro usuário (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "forbidden",
+                "message": "Apenas o dono pode acessar esta lista de leitura.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Lista de leitura não encontrada (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "not found",
+                "message": "Não foi encontrada uma lista de leitura com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("/{reading_list_id}")]
+async fn put_reading_list(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    reading_list_id: web::Path<String>,
+    body: web::Json<PutReadingListDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match put_reading_list_service(
+        pg_pool,
+        reading_list_id.into_inner(),
+        token.claims.sub,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(list) => HttpResponse::Ok().json(list),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "reading_list",
+    path = "/reading-list/{reading_list_id}",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Lista de leitura removida (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "A lista de leitura pertence a outro usuário (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "forbidden",
+                "message": "Apenas o dono pode acessar esta lista de leitura.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Lista de leitura não encontrada (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "not found",
+                "message": "Não foi encontrada uma lista de leitura com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("/{reading_list_id}")]
+async fn delete_reading_list(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    reading_list_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match delete_reading_list_service(pg_pool, reading_list_id.into_inner(), token.claims.sub).await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "reading_list",
+    path = "/reading-list/{reading_list_id}/item",
+    security(("bearer_auth" = [])),
+    request_body = InsertReadingListItemDTO,
+    responses((
+        status = 204, description = "Post adicionado à lista de leitura (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "A lista de leitura pertence a outro usuário (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "forbidden",
+                "message": "Apenas o dono pode acessar esta lista de leitura.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Lista de leitura ou post não encontrado (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/{reading_list_id}/item")]
+async fn insert_reading_list_item(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    reading_list_id: web::Path<String>,
+    body: web::Json<InsertReadingListItemDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match insert_reading_list_item_service(
+        pg_pool,
+        reading_list_id.into_inner(),
+        token.claims.sub,
+        body.into_inner().post_id,
+    )
+    .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "reading_list",
+    path = "/reading-list/{reading_list_id}/item/{post_id}",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Post removido da lista de leitura (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "A lista de leitura pertence a outro usuário (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "reading_list": [{
+                "code": "forbidden",
+                "message": "Apenas o dono pode acessar esta lista de leitura.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Lista de leitura não encontrada ou post fora da lista (Not Found)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Este post não está na lista de leitura.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("/{reading_list_id}/item/{post_id}")]
+async fn delete_reading_list_item(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    path: web::Path<(String, String)>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    let (reading_list_id, post_id) = path.into_inner();
+    match delete_reading_list_item_service(pg_pool, reading_list_id, token.claims.sub, post_id)
+        .await
+    {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertReadingListDTO {
+    #[validate(length(
+        min = 3,
+        max = 100,
+        message = "O nome da lista deve ter entre 3 e 100 caracteres."
+    ))]
+    pub name: String,
+
+    #[serde(default)]
+    pub public: bool,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct PutReadingListDTO {
+    #[validate(length(
+        min = 3,
+        max = 100,
+        message = "O nome da lista deve ter entre 3 e 100 caracteres."
+    ))]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub public: Option<bool>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct InsertReadingListItemDTO {
+    #[serde(alias = "postId")]
+    pub post_id: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingListDTO {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub public: bool,
+    pub item_count: i64,
+    pub created_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingListItemDTO {
+    pub post_id: String,
+    pub slug: Option<String>,
+    pub title: String,
+    pub summary: Option<String>,
+    pub added_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_percent: Option<i32>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailReadingListDTO {
+    pub list: ReadingListDTO,
+    pub items: Vec<ReadingListItemDTO>,
+    pub next_cursor: Option<String>,
+}
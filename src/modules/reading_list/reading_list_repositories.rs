@@ -0,0 +1,257 @@
+use super::reading_list_dtos::{ReadingListDTO, ReadingListItemDTO};
+use crate::{
+    shared::structs::cursor_params::CursorPage,
+    utils::{
+        cursor::{encode_cursor, CursorPosition},
+        error_construct::error_construct,
+        query_constructor_executor::query_executor,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const INSERT_READING_LIST_SQL: &str = "
+    INSERT INTO reading_lists (id, user_id, name, public, created_at)
+    VALUES ($1, $2, $3, $4, NOW())
+    RETURNING id, user_id, name, public, created_at, 0::BIGINT AS item_count
+";
+
+const DETAIL_READING_LIST_SQL: &str = "
+    SELECT id, user_id, name, public, created_at,
+        (SELECT COUNT(*) FROM reading_list_items WHERE list_id = reading_lists.id) AS item_count
+    FROM reading_lists WHERE id = $1
+";
+
+const LIST_USER_READING_LISTS_SQL: &str = "
+    SELECT id, user_id, name, public, created_at,
+        (SELECT COUNT(*) FROM reading_list_items WHERE list_id = reading_lists.id) AS item_count
+    FROM reading_lists WHERE user_id = $1
+    ORDER BY created_at DESC, id DESC
+";
+
+const PUT_READING_LIST_SQL: &str = "
+    UPDATE reading_lists SET name = COALESCE($2, name), public = COALESCE($3, public)
+    WHERE id = $1
+    RETURNING id, user_id, name, public, created_at,
+        (SELECT COUNT(*) FROM reading_list_items WHERE list_id = $1) AS item_count
+";
+
+const DELETE_READING_LIST_SQL: &str = "DELETE FROM reading_lists WHERE id = $1";
+
+const INSERT_READING_LIST_ITEM_SQL: &str = "
+    WITH post AS (
+        SELECT id FROM posts WHERE id = $2 AND deleted_at IS NULL
+    ), inserted AS (
+        INSERT INTO reading_list_items (list_id, post_id, added_at)
+        SELECT $1, id, NOW() FROM post
+        ON CONFLICT (list_id, post_id) DO NOTHING
+    )
+    SELECT EXISTS (SELECT 1 FROM post) AS post_exists
+";
+
+const DELETE_READING_LIST_ITEM_SQL: &str =
+    "DELETE FROM reading_list_items WHERE list_id = $1 AND post_id = $2 RETURNING post_id";
+
+const LIST_READING_LIST_ITEMS_SQL: &str = "
+    SELECT i.post_id, i.added_at, p.slug, p.title, p.summary, r.progress_percent
+    FROM reading_list_items i
+    JOIN posts p ON p.id = i.post_id AND p.deleted_at IS NULL
+    LEFT JOIN reading_progress r ON r.post_id = i.post_id AND r.user_id = $5
+    WHERE i.list_id = $1
+        AND ($2::TIMESTAMPTZ IS NULL OR (i.added_at, i.post_id) < ($2, $3))
+    ORDER BY i.added_at DESC, i.post_id DESC
+    LIMIT $4
+";
+
+fn reading_list_dto_constructor(row: &postgres::Row) -> ReadingListDTO {
+    let id: uuid::Uuid = row.get("id");
+    let user_id: uuid::Uuid = row.get("user_id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+
+    ReadingListDTO {
+        id: id.to_string(),
+        user_id: user_id.to_string(),
+        name: row.get("name"),
+        public: row.get("public"),
+        item_count: row.get("item_count"),
+        created_at: created_at.to_string(),
+    }
+}
+
+fn post_not_found(post_id: uuid::Uuid, message: &str) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("post"),
+        String::from("not found"),
+        String::from(message),
+        Some(post_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+pub async fn insert_reading_list_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    name: String,
+    public: bool,
+) -> Result<ReadingListDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_READING_LIST_SQL,
+        &[&uuid::Uuid::new_v4(), &user_id, &name, &public],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(reading_list_dto_constructor(&rows[0]))
+}
+
+pub async fn detail_reading_list_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: uuid::Uuid,
+) -> Result<ReadingListDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, DETAIL_READING_LIST_SQL, &[&list_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    match rows.first() {
+        Some(row) => Ok(reading_list_dto_constructor(row)),
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("reading_list"),
+            String::from("not found"),
+            String::from("Não foi encontrada uma lista de leitura com este id."),
+            Some(list_id.to_string()),
+            None,
+            None,
+        ))),
+    }
+}
+
+pub async fn list_user_reading_lists_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Vec<ReadingListDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, LIST_USER_READING_LISTS_SQL, &[&user_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.iter().map(reading_list_dto_constructor).collect())
+}
+
+pub async fn put_reading_list_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: uuid::Uuid,
+    name: Option<String>,
+    public: Option<bool>,
+) -> Result<ReadingListDTO, HttpResponse> {
+    let rows =
+        match query_executor(pg_pool, PUT_READING_LIST_SQL, &[&list_id, &name, &public]).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+
+    Ok(reading_list_dto_constructor(&rows[0]))
+}
+
+pub async fn delete_reading_list_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    match query_executor(pg_pool, DELETE_READING_LIST_SQL, &[&list_id]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Adds a post to the list. Adding a post that is already in the list is a no-op.
+pub async fn insert_reading_list_item_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: uuid::Uuid,
+    post_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    let rows =
+        match query_executor(pg_pool, INSERT_READING_LIST_ITEM_SQL, &[&list_id, &post_id]).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+
+    let post_exists: bool = rows[0].get("post_exists");
+    if !post_exists {
+        return Err(post_not_found(
+            post_id,
+            "Não foi encontrado um post com este id.",
+        ));
+    }
+    Ok(())
+}
+
+pub async fn delete_reading_list_item_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: uuid::Uuid,
+    post_id: uuid::Uuid,
+) -> Result<(), HttpResponse> {
+    let rows =
+        match query_executor(pg_pool, DELETE_READING_LIST_ITEM_SQL, &[&list_id, &post_id]).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+
+    if rows.is_empty() {
+        return Err(post_not_found(
+            post_id,
+            "Este post não está na lista de leitura.",
+        ));
+    }
+    Ok(())
+}
+
+/// Lists the items of the list, with the reading progress of `viewer_id` when there is one.
+pub async fn list_reading_list_items_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: uuid::Uuid,
+    viewer_id: Option<uuid::Uuid>,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+) -> Result<CursorPage<ReadingListItemDTO>, HttpResponse> {
+    let (cursor_added_at, cursor_post_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        LIST_READING_LIST_ITEMS_SQL,
+        &[
+            &list_id,
+            &cursor_added_at,
+            &cursor_post_id,
+            &(limit + 1),
+            &viewer_id,
+        ],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<ReadingListItemDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        let post_id: uuid::Uuid = row.get("post_id");
+        let added_at: DateTime<Utc> = row.get("added_at");
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(added_at, post_id));
+        }
+        data.push(ReadingListItemDTO {
+            post_id: post_id.to_string(),
+            slug: row.get("slug"),
+            title: row.get("title"),
+            summary: row.get("summary"),
+            added_at: added_at.to_string(),
+            progress_percent: row.get("progress_percent"),
+        });
+    }
+    Ok(CursorPage { data, next_cursor })
+}
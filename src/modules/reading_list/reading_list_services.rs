@@ -0,0 +1,185 @@
+use super::{
+    reading_list_dtos::{
+        DetailReadingListDTO, InsertReadingListDTO, PutReadingListDTO, ReadingListDTO,
+    },
+    reading_list_repositories::*,
+};
+use crate::{
+    middlewares::{
+        jwt_token_middleware::jwt_token_middleware, uuid_path_middleware::uuid_path_middleware,
+    },
+    shared::structs::cursor_params::CursorParams,
+    utils::{cursor::cursor_page_params, error_construct::error_construct},
+};
+use actix_web::{http::header::HeaderMap, web::Data, HttpResponse};
+
+fn parse_uuid(id: String, field: &str) -> Result<uuid::Uuid, HttpResponse> {
+    match uuid_path_middleware(id, field) {
+        Ok(id) => Ok(uuid::Uuid::parse_str(&id).unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+fn reading_list_forbidden() -> HttpResponse {
+    HttpResponse::Forbidden().json(error_construct(
+        String::from("reading_list"),
+        String::from("forbidden"),
+        String::from("Apenas o dono pode acessar esta lista de leitura."),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Fetches the list, ensuring that it belongs to `user_id`.
+async fn owned_reading_list(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: String,
+    user_id: &str,
+) -> Result<ReadingListDTO, HttpResponse> {
+    let list_id = match parse_uuid(list_id, "reading_list_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let list = match detail_reading_list_repository(pg_pool, list_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if list.user_id != user_id {
+        return Err(reading_list_forbidden());
+    }
+    Ok(list)
+}
+
+pub async fn insert_reading_list_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    body: InsertReadingListDTO,
+) -> Result<ReadingListDTO, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    insert_reading_list_repository(pg_pool, user_id, body.name, body.public).await
+}
+
+pub async fn list_user_reading_lists_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+) -> Result<Vec<ReadingListDTO>, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    list_user_reading_lists_repository(pg_pool, user_id).await
+}
+
+pub async fn put_reading_list_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: String,
+    user_id: String,
+    body: PutReadingListDTO,
+) -> Result<ReadingListDTO, HttpResponse> {
+    let list = match owned_reading_list(pg_pool.clone(), list_id, &user_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let list_id = uuid::Uuid::parse_str(&list.id).unwrap_or_default();
+    put_reading_list_repository(pg_pool, list_id, body.name, body.public).await
+}
+
+pub async fn delete_reading_list_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: String,
+    user_id: String,
+) -> Result<(), HttpResponse> {
+    let list = match owned_reading_list(pg_pool.clone(), list_id, &user_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let list_id = uuid::Uuid::parse_str(&list.id).unwrap_or_default();
+    delete_reading_list_repository(pg_pool, list_id).await
+}
+
+pub async fn insert_reading_list_item_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: String,
+    user_id: String,
+    post_id: String,
+) -> Result<(), HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let list = match owned_reading_list(pg_pool.clone(), list_id, &user_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let list_id = uuid::Uuid::parse_str(&list.id).unwrap_or_default();
+    insert_reading_list_item_repository(pg_pool, list_id, post_id).await
+}
+
+pub async fn delete_reading_list_item_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    list_id: String,
+    user_id: String,
+    post_id: String,
+) -> Result<(), HttpResponse> {
+    let post_id = match parse_uuid(post_id, "post_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let list = match owned_reading_list(pg_pool.clone(), list_id, &user_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let list_id = uuid::Uuid::parse_str(&list.id).unwrap_or_default();
+    delete_reading_list_item_repository(pg_pool, list_id, post_id).await
+}
+
+/// Returns the list with a page of its items.
+///
+/// Public lists are visible to anyone. Private lists require the bearer token of their owner.
+pub async fn detail_reading_list_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    headers: &HeaderMap,
+    list_id: String,
+    params: CursorParams,
+) -> Result<DetailReadingListDTO, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let list_id = match parse_uuid(list_id, "reading_list_id") {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let list = match detail_reading_list_repository(pg_pool.clone(), list_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let viewer_id = jwt_token_middleware(headers)
+        .ok()
+        .and_then(|token| uuid::Uuid::parse_str(&token.claims.sub).ok());
+    if !list.public {
+        let token = match jwt_token_middleware(headers) {
+            Ok(token) => token,
+            Err(e) => return Err(e),
+        };
+        if token.claims.sub != list.user_id {
+            return Err(reading_list_forbidden());
+        }
+    }
+
+    let page = match list_reading_list_items_repository(pg_pool, list_id, viewer_id, cursor, limit)
+        .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    Ok(DetailReadingListDTO {
+        list,
+        items: page.data,
+        next_cursor: page.next_cursor,
+    })
+}
@@ -0,0 +1,4 @@
+pub mod reading_progress_controllers;
+pub mod reading_progress_dtos;
+pub mod reading_progress_repositories;
+pub mod reading_progress_services;
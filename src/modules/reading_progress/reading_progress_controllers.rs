@@ -0,0 +1,285 @@
+use super::{
+    reading_progress_dtos::PutReadingProgressDTO,
+    reading_progress_services::{
+        detail_reading_progress_service, list_in_progress_service, put_reading_progress_service,
+    },
+};
+use crate::{
+    middlewares::{auth_middleware::auth_middleware, jwt_token_middleware::jwt_token_middleware},
+    shared::structs::cursor_params::CursorParams,
+};
+use actix_web::{get, put, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn reading_progress_post_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_reading_progress)
+        .service(detail_reading_progress);
+}
+
+pub fn reading_progress_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_in_progress);
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/progress",
+    security(("bearer_auth" = [])),
+    request_body = PutReadingProgressDTO,
+    responses((
+        status = 200, description = "Progresso de leitura salvo (OK)",
+        content_type = "application/json", example = json ! ({
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "progressPercent": 42,
+            "updatedAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+	), (
+		status = 400, description = "Erro do usuário por id ou progresso inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "progress_percent": [{
+                "code": "range",
+                "message": "O progresso de leitura deve estar entre 0 e 100.",
+                "params": {
+                    "min": 0.0,
+                    "value": 120,
+                    "max": 100.0
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Post não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post": [{
+                "code": "not found",
+                "message": "Não foi encontrado um post com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[put("{post_id}/progress")]
+async fn put_reading_progress(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    post_id: web::Path<String>,
+    body: web::Json<PutReadingProgressDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match put_reading_progress_service(
+        pg_pool,
+        redis_pool,
+        post_id.into_inner(),
+        token.claims.sub,
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(progress) => HttpResponse::Ok().json(progress),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "post",
+    path = "/post/{post_id}/progress",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Progresso de leitura do usuário no post (OK)",
+        content_type = "application/json", example = json ! ({
+            "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+            "progressPercent": 42,
+            "updatedAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "post_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Nenhum progresso registrado para o post (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "progress": [{
+                "code": "not found",
+                "message": "Não há progresso de leitura registrado para este post.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{post_id}/progress")]
+async fn detail_reading_progress(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    post_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match detail_reading_progress_service(
+        pg_pool,
+        redis_pool,
+        post_id.into_inner(),
+        token.claims.sub,
+    )
+    .await
+    {
+        Ok(progress) => HttpResponse::Ok().json(progress),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/in-progress",
+    security(("bearer_auth" = [])),
+    params(
+        ("cursor" = Option<String>, Query, description = "Cursor retornado em nextCursor pela página anterior"),
+        ("limit" = Option<i64>, Query, description = "Quantidade de itens por página (1 a 100, padrão 20)"),
+    ),
+    responses((
+        status = 200, description = "Posts com leitura não concluída, do progresso mais recente ao mais antigo (OK)",
+        content_type = "application/json", example = json ! ({
+            "data": [{
+                "postId": "0a6e8ac0-4e0a-4d5f-8f0e-4f4b0b4c2d10",
+                "slug": "rust-e-actix-web",
+                "title": "Rust e Actix Web",
+                "summary": "Criando APIs performáticas",
+                "progressPercent": 42,
+                "updatedAt": "2024-06-18 22:03:54.053147 UTC"
+            }],
+            "nextCursor": null
+        })
+	), (
+		status = 400, description = "Erro do usuário por id ou cursor inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "cursor": [{
+                "code": "bad request",
+                "message": "Cursor inválido.",
+                "params": {
+                    "min": null,
+                    "value": "abc",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/in-progress")]
+async fn list_in_progress(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    query_params: web::Query<CursorParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match list_in_progress_service(pg_pool, user_id.into_inner(), query_params.into_inner()).await {
+        Ok(page) => HttpResponse::Ok().json(page),
+        Err(e) => e,
+    }
+}
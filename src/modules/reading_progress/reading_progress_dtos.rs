@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct PutReadingProgressDTO {
+    #[validate(range(
+        min = 0,
+        max = 100,
+        message = "O progresso de leitura deve estar entre 0 e 100."
+    ))]
+    #[serde(alias = "progressPercent")]
+    pub progress_percent: i32,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingProgressDTO {
+    pub post_id: String,
+    pub progress_percent: i32,
+    pub updated_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InProgressPostDTO {
+    pub post_id: String,
+    pub slug: Option<String>,
+    pub title: String,
+    pub summary: Option<String>,
+    pub progress_percent: i32,
+    pub updated_at: String,
+}
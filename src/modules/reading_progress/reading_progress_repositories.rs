@@ -0,0 +1,112 @@
+use super::reading_progress_dtos::{InProgressPostDTO, ReadingProgressDTO};
+use crate::{
+    shared::structs::cursor_params::CursorPage,
+    utils::{
+        cursor::{encode_cursor, CursorPosition},
+        query_constructor_executor::query_executor,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+
+const PUT_READING_PROGRESS_SQL: &str = "
+    INSERT INTO reading_progress (user_id, post_id, progress_percent, updated_at)
+    SELECT $1, id, $3, NOW() FROM posts WHERE id = $2 AND deleted_at IS NULL
+    ON CONFLICT (user_id, post_id) DO UPDATE
+        SET progress_percent = EXCLUDED.progress_percent, updated_at = EXCLUDED.updated_at
+    RETURNING post_id, progress_percent, updated_at
+";
+
+const DETAIL_READING_PROGRESS_SQL: &str = "
+    SELECT post_id, progress_percent, updated_at FROM reading_progress
+    WHERE user_id = $1 AND post_id = $2
+";
+
+const LIST_IN_PROGRESS_SQL: &str = "
+    SELECT r.post_id, r.progress_percent, r.updated_at, p.slug, p.title, p.summary
+    FROM reading_progress r
+    JOIN posts p ON p.id = r.post_id AND p.deleted_at IS NULL
+    WHERE r.user_id = $1 AND r.progress_percent < 100
+        AND ($2::TIMESTAMPTZ IS NULL OR (r.updated_at, r.post_id) < ($2, $3))
+    ORDER BY r.updated_at DESC, r.post_id DESC
+    LIMIT $4
+";
+
+fn reading_progress_dto_constructor(row: &Row) -> ReadingProgressDTO {
+    let post_id: uuid::Uuid = row.get("post_id");
+    let updated_at: DateTime<Utc> = row.get("updated_at");
+    ReadingProgressDTO {
+        post_id: post_id.to_string(),
+        progress_percent: row.get("progress_percent"),
+        updated_at: updated_at.to_string(),
+    }
+}
+
+/// Saves the progress of the user on the post, returning `None` if the post does not exist.
+pub async fn put_reading_progress_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    post_id: uuid::Uuid,
+    progress_percent: i32,
+) -> Result<Option<ReadingProgressDTO>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        PUT_READING_PROGRESS_SQL,
+        &[&user_id, &post_id, &progress_percent],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.first().map(reading_progress_dto_constructor)),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn detail_reading_progress_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    post_id: uuid::Uuid,
+) -> Result<Option<ReadingProgressDTO>, HttpResponse> {
+    match query_executor(pg_pool, DETAIL_READING_PROGRESS_SQL, &[&user_id, &post_id]).await {
+        Ok(rows) => Ok(rows.first().map(reading_progress_dto_constructor)),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn list_in_progress_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    cursor: Option<CursorPosition>,
+    limit: i64,
+) -> Result<CursorPage<InProgressPostDTO>, HttpResponse> {
+    let (cursor_updated_at, cursor_post_id) = cursor.unzip();
+    let rows = match query_executor(
+        pg_pool,
+        LIST_IN_PROGRESS_SQL,
+        &[&user_id, &cursor_updated_at, &cursor_post_id, &(limit + 1)],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let mut next_cursor = None;
+    let mut data: Vec<InProgressPostDTO> = Vec::with_capacity(limit as usize);
+    for row in rows.iter().take(limit as usize) {
+        let post_id: uuid::Uuid = row.get("post_id");
+        let updated_at: DateTime<Utc> = row.get("updated_at");
+        if rows.len() as i64 > limit {
+            next_cursor = Some(encode_cursor(updated_at, post_id));
+        }
+        data.push(InProgressPostDTO {
+            post_id: post_id.to_string(),
+            slug: row.get("slug"),
+            title: row.get("title"),
+            summary: row.get("summary"),
+            progress_percent: row.get("progress_percent"),
+            updated_at: updated_at.to_string(),
+        });
+    }
+    Ok(CursorPage { data, next_cursor })
+}
@@ -0,0 +1,127 @@
+use super::{
+    reading_progress_dtos::{InProgressPostDTO, PutReadingProgressDTO, ReadingProgressDTO},
+    reading_progress_repositories::*,
+};
+use crate::{
+    infra::redis::Redis,
+    middlewares::uuid_path_middleware::uuid_path_middleware,
+    shared::structs::cursor_params::{CursorPage, CursorParams},
+    utils::{cursor::cursor_page_params, error_construct::error_construct},
+};
+use actix_web::{web::Data, HttpResponse};
+
+const READING_PROGRESS_TTL_SECONDS: u64 = 86400;
+
+fn reading_progress_key(user_id: uuid::Uuid, post_id: uuid::Uuid) -> String {
+    format!("progress:{}:{}", user_id, post_id)
+}
+
+fn post_not_found(post_id: uuid::Uuid) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("post"),
+        String::from("not found"),
+        String::from("Não foi encontrado um post com este id."),
+        Some(post_id.to_string()),
+        None,
+        None,
+    ))
+}
+
+/// Saves the reading progress of the user in Postgres and writes it through to the Redis cache.
+///
+/// A cache failure is only logged, since Postgres remains the source of truth.
+pub async fn put_reading_progress_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    post_id: String,
+    user_id: String,
+    body: PutReadingProgressDTO,
+) -> Result<ReadingProgressDTO, HttpResponse> {
+    let post_id = match uuid_path_middleware(post_id, "post_id") {
+        Ok(id) => uuid::Uuid::parse_str(&id).unwrap_or_default(),
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+
+    let progress =
+        match put_reading_progress_repository(pg_pool, user_id, post_id, body.progress_percent)
+            .await
+        {
+            Ok(Some(x)) => x,
+            Ok(None) => return Err(post_not_found(post_id)),
+            Err(e) => return Err(e),
+        };
+
+    if let Err(e) = Redis::set_ex(
+        &redis_pool,
+        &reading_progress_key(user_id, post_id),
+        &serde_json::to_string(&progress).unwrap_or_default(),
+        READING_PROGRESS_TTL_SECONDS,
+    )
+    .await
+    {
+        log::warn!(
+            "Failed to cache the reading progress of post {}: {}",
+            post_id,
+            e
+        );
+    }
+    Ok(progress)
+}
+
+/// Returns the reading progress of the user, reading the Redis cache before Postgres.
+pub async fn detail_reading_progress_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    post_id: String,
+    user_id: String,
+) -> Result<ReadingProgressDTO, HttpResponse> {
+    let post_id = match uuid_path_middleware(post_id, "post_id") {
+        Ok(id) => uuid::Uuid::parse_str(&id).unwrap_or_default(),
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let key = reading_progress_key(user_id, post_id);
+
+    if let Ok(cached) = Redis::get(&redis_pool, &key).await {
+        if let Ok(progress) = serde_json::from_str::<ReadingProgressDTO>(&cached) {
+            return Ok(progress);
+        }
+    }
+
+    let progress = match detail_reading_progress_repository(pg_pool, user_id, post_id).await {
+        Ok(Some(x)) => x,
+        Ok(None) => {
+            return Err(HttpResponse::NotFound().json(error_construct(
+                String::from("progress"),
+                String::from("not found"),
+                String::from("Não há progresso de leitura registrado para este post."),
+                Some(post_id.to_string()),
+                None,
+                None,
+            )))
+        }
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &key,
+        &serde_json::to_string(&progress).unwrap_or_default(),
+        READING_PROGRESS_TTL_SECONDS,
+    )
+    .await;
+    Ok(progress)
+}
+
+pub async fn list_in_progress_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    params: CursorParams,
+) -> Result<CursorPage<InProgressPostDTO>, HttpResponse> {
+    let (cursor, limit) = match cursor_page_params(&params) {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    list_in_progress_repository(pg_pool, user_id, cursor, limit).await
+}
@@ -0,0 +1,6 @@
+pub mod snapshot_controllers;
+pub mod snapshot_dtos;
+pub mod snapshot_providers;
+pub mod snapshot_queues;
+pub mod snapshot_repositories;
+pub mod snapshot_services;
@@ -0,0 +1,77 @@
+use super::snapshot_services::list_snapshots_service;
+use crate::middlewares::admin_middleware::admin_middleware;
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+
+pub fn snapshot_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_snapshots);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/snapshots",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Os 30 snapshots diários mais recentes dos posts publicados (OK)",
+        body = Vec<SnapshotDTO>, content_type = "application/json", example = json ! ([{
+            "id": "3c1f9b0e-8d7a-4b6e-9c2d-1e0f3a4b5c6d",
+            "snapshotDate": "2024-06-18",
+            "fileKey": "snapshots/2024-06-18/posts.jsonl",
+            "postCount": 42,
+            "sizeBytes": 183204,
+            "createdAt": "2024-06-18 03:00:00.123456 UTC"
+        }])
+    ), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/snapshots")]
+async fn list_snapshots(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match list_snapshots_service(pg_pool).await {
+        Ok(snapshots) => HttpResponse::Ok().json(snapshots),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDTO {
+    pub id: String,
+    pub snapshot_date: String,
+    /// Key of the JSON Lines file in the bucket.
+    pub file_key: String,
+    pub post_count: i64,
+    pub size_bytes: i64,
+    pub created_at: String,
+}
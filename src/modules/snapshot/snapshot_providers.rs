@@ -0,0 +1,139 @@
+use crate::utils::error_construct::error_construct;
+use actix_web::HttpResponse;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use std::{env, time::Duration};
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(60))
+        .build()
+        .unwrap()
+});
+
+const SIGNED_HEADERS: &str = "host;x-amz-content-sha256;x-amz-date";
+
+fn storage_error(message: String) -> HttpResponse {
+    HttpResponse::ServiceUnavailable().json(error_construct(
+        String::from("storage"),
+        String::from("service unavailable"),
+        message,
+        None,
+        None,
+        None,
+    ))
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Builds the AWS Signature Version 4 `Authorization` header of a `PUT` of an object, signing the
+/// `host`, `x-amz-content-sha256` and `x-amz-date` headers.
+///
+/// `path` must already be URI-encoded, the snapshot keys only use unreserved characters.
+pub fn s3_authorization(
+    access_key_id: &str,
+    secret_access_key: &str,
+    region: &str,
+    host: &str,
+    path: &str,
+    payload_hash: &str,
+    now: DateTime<Utc>,
+) -> String {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+    let scope = format!("{}/{}/s3/aws4_request", date, region);
+
+    let canonical_request = format!(
+        "PUT\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+        path, host, payload_hash, amz_date, SIGNED_HEADERS, payload_hash
+    );
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let signing_key = [region, "s3", "aws4_request"].iter().fold(
+        hmac_sha256(format!("AWS4{}", secret_access_key).as_bytes(), &date),
+        |key, part| hmac_sha256(&key, part),
+    );
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        access_key_id,
+        scope,
+        SIGNED_HEADERS,
+        hex::encode(hmac_sha256(&signing_key, &string_to_sign))
+    )
+}
+
+/// Uploads an object to the S3-compatible bucket `AWS_S3_BUCKET` in `AWS_S3_REGION`, signed with
+/// `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`.
+///
+/// `AWS_S3_ENDPOINT` points to another S3-compatible service, addressed in path style
+/// (`{endpoint}/{bucket}/{key}`). Without it, the AWS virtual-hosted endpoint of the bucket is used.
+pub async fn upload_object_provider(
+    key: &str,
+    body: Vec<u8>,
+    content_type: &str,
+) -> Result<(), HttpResponse> {
+    let bucket = match env::var("AWS_S3_BUCKET") {
+        Ok(bucket) if !bucket.is_empty() => bucket,
+        _ => {
+            return Err(storage_error(String::from(
+                "O armazenamento de snapshots não está configurado.",
+            )))
+        }
+    };
+    let region = env::var("AWS_S3_REGION").unwrap_or_else(|_| String::from("us-east-1"));
+    let url = match env::var("AWS_S3_ENDPOINT") {
+        Ok(endpoint) if !endpoint.is_empty() => {
+            format!("{}/{}/{}", endpoint.trim_end_matches('/'), bucket, key)
+        }
+        _ => format!("https://{}.s3.{}.amazonaws.com/{}", bucket, region, key),
+    };
+    let url = match reqwest::Url::parse(&url) {
+        Ok(url) => url,
+        Err(e) => return Err(storage_error(e.to_string())),
+    };
+    let host = match url.port() {
+        Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+        None => url.host_str().unwrap_or_default().to_string(),
+    };
+
+    let now = Utc::now();
+    let payload_hash = hex::encode(Sha256::digest(&body));
+    let authorization = s3_authorization(
+        &env::var("AWS_ACCESS_KEY_ID").unwrap_or_default(),
+        &env::var("AWS_SECRET_ACCESS_KEY").unwrap_or_default(),
+        &region,
+        &host,
+        url.path(),
+        &payload_hash,
+        now,
+    );
+
+    match HTTP_CLIENT
+        .put(url)
+        .header("Authorization", authorization)
+        .header("Content-Type", content_type)
+        .header("x-amz-content-sha256", payload_hash)
+        .header("x-amz-date", now.format("%Y%m%dT%H%M%SZ").to_string())
+        .body(body)
+        .send()
+        .await
+    {
+        Ok(resp) if resp.status().is_success() => Ok(()),
+        Ok(resp) => Err(storage_error(format!(
+            "O armazenamento respondeu com o status {}.",
+            resp.status().as_u16()
+        ))),
+        Err(e) => Err(storage_error(e.to_string())),
+    }
+}
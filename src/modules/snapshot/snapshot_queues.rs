@@ -0,0 +1,32 @@
+use super::snapshot_services::content_snapshot_service;
+use actix_web::web::Data;
+use deadpool_postgres::Pool;
+use std::time::Duration;
+
+const SNAPSHOT_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// Archives the published posts once a day, starting one day after the server starts.
+pub async fn content_snapshot_job(pool_async: Pool) {
+    let period = Duration::from_secs(SNAPSHOT_INTERVAL_SECS);
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+    loop {
+        interval.tick().await;
+        match content_snapshot_service(
+            Data::new(pool_async.clone()),
+            chrono::Utc::now().date_naive(),
+        )
+        .await
+        {
+            Ok(snapshot) => log::info!(
+                "Content snapshot {} stored with {} posts ({} bytes).",
+                snapshot.file_key,
+                snapshot.post_count,
+                snapshot.size_bytes
+            ),
+            Err(e) => log::warn!(
+                "Failed to store the content snapshot: status {}",
+                e.status()
+            ),
+        };
+    }
+}
@@ -0,0 +1,79 @@
+use super::snapshot_dtos::SnapshotDTO;
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, NaiveDate, Utc};
+use tokio_postgres::Row;
+
+const PUBLISHED_POSTS_SQL: &str = "
+    SELECT row_to_json(p)::TEXT AS post FROM posts p
+    WHERE p.published_at IS NOT NULL AND p.deleted_at IS NULL
+    ORDER BY p.published_at, p.id
+";
+
+const INSERT_SNAPSHOT_SQL: &str = "
+    INSERT INTO snapshots (id, snapshot_date, file_key, post_count, size_bytes, created_at)
+    VALUES ($1, $2, $3, $4, $5, NOW())
+    RETURNING *
+";
+
+const LIST_SNAPSHOTS_SQL: &str = "SELECT * FROM snapshots ORDER BY created_at DESC LIMIT $1";
+
+fn snapshot_dto_constructor(row: &Row) -> SnapshotDTO {
+    let id: uuid::Uuid = row.get("id");
+    let snapshot_date: NaiveDate = row.get("snapshot_date");
+    let created_at: DateTime<Utc> = row.get("created_at");
+    SnapshotDTO {
+        id: id.to_string(),
+        snapshot_date: snapshot_date.to_string(),
+        file_key: row.get("file_key"),
+        post_count: row.get("post_count"),
+        size_bytes: row.get("size_bytes"),
+        created_at: created_at.to_string(),
+    }
+}
+
+/// Returns every published post as a JSON object with all of its columns, in publication order.
+pub async fn published_posts_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<String>, HttpResponse> {
+    match query_executor(pg_pool, PUBLISHED_POSTS_SQL, &[]).await {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get("post")).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn insert_snapshot_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    snapshot_date: NaiveDate,
+    file_key: &str,
+    post_count: i64,
+    size_bytes: i64,
+) -> Result<SnapshotDTO, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        INSERT_SNAPSHOT_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &snapshot_date,
+            &file_key,
+            &post_count,
+            &size_bytes,
+        ],
+    )
+    .await
+    {
+        Ok(rows) => Ok(snapshot_dto_constructor(&rows[0])),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the `limit` most recent snapshots.
+pub async fn list_snapshots_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    limit: i64,
+) -> Result<Vec<SnapshotDTO>, HttpResponse> {
+    match query_executor(pg_pool, LIST_SNAPSHOTS_SQL, &[&limit]).await {
+        Ok(rows) => Ok(rows.iter().map(snapshot_dto_constructor).collect()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,53 @@
+use super::{
+    snapshot_dtos::SnapshotDTO,
+    snapshot_providers::upload_object_provider,
+    snapshot_repositories::{
+        insert_snapshot_repository, list_snapshots_repository, published_posts_repository,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use chrono::NaiveDate;
+
+const RECENT_SNAPSHOTS_LIMIT: i64 = 30;
+
+/// Key of the snapshot file of `date` in the bucket.
+pub fn snapshot_file_key(date: NaiveDate) -> String {
+    format!("snapshots/{}/posts.jsonl", date.format("%Y-%m-%d"))
+}
+
+/// Uploads every published post as a JSON Lines file, one post per line, and records the snapshot.
+pub async fn content_snapshot_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    snapshot_date: NaiveDate,
+) -> Result<SnapshotDTO, HttpResponse> {
+    let posts = match published_posts_repository(pg_pool.clone()).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let mut body = posts.join("\n");
+    if !body.is_empty() {
+        body.push('\n');
+    }
+
+    let file_key = snapshot_file_key(snapshot_date);
+    let size_bytes = body.len() as i64;
+    match upload_object_provider(&file_key, body.into_bytes(), "application/x-ndjson").await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    insert_snapshot_repository(
+        pg_pool,
+        snapshot_date,
+        &file_key,
+        posts.len() as i64,
+        size_bytes,
+    )
+    .await
+}
+
+pub async fn list_snapshots_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+) -> Result<Vec<SnapshotDTO>, HttpResponse> {
+    list_snapshots_repository(pg_pool, RECENT_SNAPSHOTS_LIMIT).await
+}
@@ -0,0 +1 @@
+pub mod static_files_controllers;
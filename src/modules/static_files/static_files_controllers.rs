@@ -0,0 +1,52 @@
+use actix_web::{
+    get,
+    http::header::{CacheControl, CacheDirective},
+    web, HttpResponse, Responder,
+};
+
+const FAVICON: &[u8] = include_bytes!("favicon.ico");
+
+const ROBOTS_TXT: &str = "User-agent: *
+Disallow: /admin
+Disallow: /user
+Allow: /post
+Allow: /feed.rss
+";
+
+const STATIC_MAX_AGE: u32 = 86400;
+
+pub fn static_files_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(favicon).service(robots_txt);
+}
+
+#[utoipa::path(
+    tag = "static",
+    path = "/favicon.ico",
+    responses((
+        status = 200, description = "Ícone 16x16 da API (OK)",
+        content_type = "image/x-icon"
+    ))
+)]
+#[get("/favicon.ico")]
+async fn favicon() -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("image/x-icon")
+        .insert_header(CacheControl(vec![CacheDirective::MaxAge(STATIC_MAX_AGE)]))
+        .body(FAVICON)
+}
+
+#[utoipa::path(
+    tag = "static",
+    path = "/robots.txt",
+    responses((
+        status = 200, description = "Regras para os crawlers, bloqueando as rotas de administração e de usuário (OK)",
+        content_type = "text/plain; charset=utf-8"
+    ))
+)]
+#[get("/robots.txt")]
+async fn robots_txt() -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .insert_header(CacheControl(vec![CacheDirective::MaxAge(STATIC_MAX_AGE)]))
+        .body(ROBOTS_TXT)
+}
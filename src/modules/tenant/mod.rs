@@ -0,0 +1,4 @@
+pub mod tenant_controllers;
+pub mod tenant_dtos;
+pub mod tenant_repositories;
+pub mod tenant_services;
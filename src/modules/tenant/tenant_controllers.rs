@@ -0,0 +1,110 @@
+use super::{tenant_dtos::InsertTenantDTO, tenant_services::insert_tenant_service};
+use crate::middlewares::super_admin_middleware::super_admin_middleware;
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn tenant_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(insert_tenant);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/tenant",
+    security(("bearer_auth" = [])),
+    request_body = InsertTenantDTO,
+    params(
+        ("X-Super-Admin-Secret" = String, Header, description = "Segredo de super administrador, definido na env var SUPER_ADMIN_SECRET"),
+    ),
+    responses((
+        status = 201, description = "Tenant criado, usado pelas requisições com o cabeçalho X-Tenant-Id (Created)",
+        body = TenantDTO, content_type = "application/json", example = json ! ({
+            "id": "8d1ad9ab-f8a5-4ba9-9b37-6a1f7d0a1a57",
+            "name": "Blog da Ana",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+	), (
+		status = 400, description = "Erro do usuário por nome inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "name": [{
+                "code": "length",
+                "message": "O nome do tenant deve ter entre 1 e 100 caracteres.",
+                "params": {
+                    "min": 1,
+                    "value": "",
+                    "max": 100
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a super administradores (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "super admin": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a super administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "Já existe um tenant com este nome (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "name": [{
+                "code": "conflict",
+                "message": "Já existe um tenant com este nome.",
+                "params": {
+                    "min": null,
+                    "value": "Blog da Ana",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/tenant")]
+async fn insert_tenant(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    body: web::Json<InsertTenantDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    match super_admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match insert_tenant_service(pg_pool, body.into_inner()).await {
+        Ok(tenant) => HttpResponse::Created().json(tenant),
+        Err(e) => e,
+    }
+}
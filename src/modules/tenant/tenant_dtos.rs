@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertTenantDTO {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "O nome do tenant deve ter entre 1 e 100 caracteres."
+    ))]
+    pub name: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantDTO {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+}
@@ -0,0 +1,41 @@
+use super::tenant_dtos::TenantDTO;
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const INSERT_TENANT_SQL: &str = "
+    INSERT INTO tenants (id, name, created_at) VALUES ($1, $2, NOW())
+    ON CONFLICT (name) DO NOTHING
+    RETURNING id, name, created_at
+";
+
+pub async fn insert_tenant_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    tenant_id: uuid::Uuid,
+    name: String,
+) -> Result<TenantDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, INSERT_TENANT_SQL, &[&tenant_id, &name]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    match rows.first() {
+        Some(row) => {
+            let id: uuid::Uuid = row.get("id");
+            let created_at: DateTime<Utc> = row.get("created_at");
+            Ok(TenantDTO {
+                id: id.to_string(),
+                name: row.get("name"),
+                created_at: created_at.to_string(),
+            })
+        }
+        None => Err(HttpResponse::Conflict().json(error_construct(
+            String::from("name"),
+            String::from("conflict"),
+            String::from("Já existe um tenant com este nome."),
+            Some(name),
+            None,
+            None,
+        ))),
+    }
+}
@@ -0,0 +1,12 @@
+use super::{
+    tenant_dtos::{InsertTenantDTO, TenantDTO},
+    tenant_repositories::insert_tenant_repository,
+};
+use actix_web::{web::Data, HttpResponse};
+
+pub async fn insert_tenant_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    body: InsertTenantDTO,
+) -> Result<TenantDTO, HttpResponse> {
+    insert_tenant_repository(pg_pool, uuid::Uuid::new_v4(), body.name.trim().to_string()).await
+}
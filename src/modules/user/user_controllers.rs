@@ -6,15 +6,55 @@ use super::{
 };
 use crate::{
     infra::redis::Redis,
-    middlewares::{auth_middleware::auth_middleware, jwt_token_middleware::jwt_token_middleware},
-    modules::user::{
-        user_queues::DeleteUserAppQueue,
-        user_services::{delete_user_service, login_user_service},
+    middlewares::{
+        admin_middleware::admin_middleware,
+        auth_middleware::auth_middleware,
+        etag_middleware::{etag_invalidate, etag_middleware, etag_response},
+        idempotency_middleware::idempotency_middleware,
+        impersonation_middleware::impersonation_middleware,
+        jwt_token_middleware::jwt_token_middleware,
+        tenant_middleware::tenant_middleware,
+        uuid_path_middleware::uuid_path_middleware,
+    },
+    modules::{
+        activitypub::activitypub_controllers::activitypub_user_controllers_module,
+        api_key::api_key_controllers::api_key_controllers_module,
+        follower::{
+            follower_controllers::follower_controllers_module,
+            follower_services::follow_counts_service,
+        },
+        login_history::{
+            login_history_controllers::login_history_controllers_module,
+            login_history_dtos::LoginClient,
+        },
+        post_view::post_view_controllers::post_view_controllers_module,
+        reading_progress::reading_progress_controllers::reading_progress_controllers_module,
+        user::{
+            user_queues::DeleteUserAppQueue,
+            user_services::{delete_user_service, login_user_service},
+        },
+        user_activity::user_activity_controllers::user_activity_controllers_module,
+        user_badge::{
+            user_badge_controllers::user_badge_controllers_module,
+            user_badge_dtos::BadgeSummaryDTO, user_badge_services::list_user_badges_service,
+        },
+        user_erasure::user_erasure_controllers::user_erasure_controllers_module,
+        user_export::user_export_controllers::user_export_controllers_module,
+        user_onboarding::{
+            user_onboarding_controllers::user_onboarding_controllers_module,
+            user_onboarding_services::detail_user_onboarding_service,
+        },
+        user_preferences::{
+            user_preferences_controllers::user_preferences_controllers_module,
+            user_preferences_services::{detail_user_preferences_service, local_time},
+        },
+        user_recommendation::user_recommendation_controllers::user_recommendation_controllers_module,
     },
     shared::structs::query_params::QueryParams,
 };
 use actix_web::{
-    body::BoxBody, delete, get, options, post, put, web, HttpRequest, HttpResponse, Responder,
+    body::BoxBody, delete, get, middleware::from_fn, options, patch, post, put, web, HttpRequest,
+    HttpResponse, Responder,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -25,11 +65,37 @@ pub fn user_controllers_module() -> actix_web::Scope {
         .service(user_options)
         .service(insert_user)
         .service(login_user)
+        .service(refresh_token)
+        .service(batch_get_users)
+        .service(mention_autocomplete)
         .service(list_users)
         .service(user_id_options)
+        .service(detail_user_by_username)
         .service(detail_user)
+        .service(update_username)
+        .service(update_name)
+        .service(update_role)
+        .service(deactivate_user)
+        .service(reactivate_user)
         .service(put_user)
         .service(delete_user)
+        .configure(follower_controllers_module)
+        .configure(post_view_controllers_module)
+        .configure(user_export_controllers_module)
+        .configure(user_erasure_controllers_module)
+        .configure(user_activity_controllers_module)
+        .configure(user_badge_controllers_module)
+        .configure(activitypub_user_controllers_module)
+        .configure(user_recommendation_controllers_module)
+        .configure(api_key_controllers_module)
+        .configure(reading_progress_controllers_module)
+        .configure(user_preferences_controllers_module)
+        .configure(login_history_controllers_module)
+        .configure(user_onboarding_controllers_module)
+}
+
+pub fn user_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(admin_list_users).service(name_history);
 }
 
 #[utoipa::path(
@@ -50,6 +116,12 @@ pub fn user_controllers_module() -> actix_web::Scope {
                     "min": 8,
                     "value": "senha12",
                     "max": 255
+                }
+		    }, {
+                "code": "password_strength",
+                "message": "A senha é muito fraca.",
+                "params": {
+                    "password_strength": 0
                 }
 		    }]
         })
@@ -66,6 +138,19 @@ pub fn user_controllers_module() -> actix_web::Scope {
                 }
 		    }]
         })
+	), (
+		status = 410, description = "Código de convite expirado, quando REQUIRE_INVITE_CODE=true (Gone)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "invite_code": [{
+                "code": "gone",
+                "message": "O código de convite expirou.",
+                "params": {
+                    "min": null,
+                    "value": "c4a1f7e2-9b3d-4e8a-b6c5-1d2e3f4a5b6c",
+                    "max": null,
+                }
+		    }]
+        })
 	), (
 		status = 500, description = "Erro Interno do Servidor (Internal Server Error)", body = ErrorStruct,
 		content_type = "application/json", example = json ! ({
@@ -94,13 +179,18 @@ pub fn user_controllers_module() -> actix_web::Scope {
         })
 	))
 )]
-#[post("")]
+#[post("", wrap = "from_fn(idempotency_middleware)")]
 async fn insert_user(
     body: web::Json<InsertUserDTO>,
     queue: web::Data<Arc<InsertUserAppQueue>>,
     redis_pool: web::Data<deadpool_redis::Pool>,
     pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
 ) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
     match body.validate() {
         Ok(_) => (),
         Err(e) => return HttpResponse::BadRequest().json(e),
@@ -109,7 +199,7 @@ async fn insert_user(
         Ok(redis_user) => redis_user,
         Err(_) => String::from(""),
     };
-    match insert_user_service(queue.clone(), pg_pool, body, redis_user).await {
+    match insert_user_service(queue.clone(), pg_pool, body, redis_user, tenant_id).await {
         Ok(resp) => match UserSerdes::serde_json_to_string(&resp) {
             Ok(redis_user) => {
                 let _ = Redis::set(&redis_pool, &resp.id, &redis_user).await;
@@ -131,10 +221,10 @@ async fn insert_user(
 	responses((
 		status = 200, description = "Usuário logado com sucesso (OK)", body = LoginResponse,
 		content_type = "application/json", example = json ! ({
-			"access_token": "string",
-            "access_expires_in": "i64",
-			"refresh_token": "string",
-            "refresh_expires_in": "i64"
+			"accessToken": "string",
+            "accessExpiresIn": "i64",
+			"refreshToken": "string",
+            "refreshExpiresIn": "i64"
         })
 	), (
 		status = 400, description = "Erro do usuário por campo inválido e/ou falta de preenchimento (Bad Request)",
@@ -162,6 +252,19 @@ async fn insert_user(
                 }
 		    }]
         })
+	), (
+		status = 403, description = "Conta suspensa por um administrador ou desativada pelo usuário (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "forbidden",
+                "message": "Sua conta está desativada. Use /user/f5d46b1b-6adb-40ac-82d6-b0006cf781c0/reactivate para reativá-la.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
 	), (
 		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
 		content_type = "application/json", example = json ! ({
@@ -208,7 +311,12 @@ async fn login_user(
     body: web::Json<LoginUserDTO>,
     pg_pool: web::Data<deadpool_postgres::Pool>,
     redis_pool: web::Data<deadpool_redis::Pool>,
+    req: HttpRequest,
 ) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
     match body.validate() {
         Ok(_) => (),
         Err(e) => return HttpResponse::BadRequest().json(e),
@@ -217,7 +325,16 @@ async fn login_user(
         Ok(redis_user) => redis_user,
         Err(_) => String::from(""),
     };
-    match login_user_service(body.clone(), pg_pool, redis_user.clone()).await {
+    match login_user_service(
+        body.clone(),
+        pg_pool,
+        redis_pool.clone(),
+        redis_user.clone(),
+        LoginClient::from_request(&req),
+        tenant_id,
+    )
+    .await
+    {
         Ok(service_resp) => {
             login_user_response_constructor(service_resp, &redis_pool, &redis_user, &body.email)
                 .await
@@ -227,6 +344,7 @@ async fn login_user(
 }
 
 #[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct LoginUserControllerResponse {
     pub access_token: String,
     pub access_expires_in: i64,
@@ -250,6 +368,216 @@ async fn login_user_response_constructor(
     })
 }
 
+#[utoipa::path(
+	tag = "user",
+    path = "/user/refresh",
+	request_body = RefreshTokenDTO,
+	responses((
+		status = 200, description = "Novos tokens com o papel e a verificação de e-mail atuais do usuário (OK)",
+		body = LoginResponse, content_type = "application/json", example = json ! ({
+			"accessToken": "string",
+            "accessExpiresIn": "i64",
+			"refreshToken": "string",
+            "refreshExpiresIn": "i64"
+        })
+	), (
+		status = 400, description = "Erro do usuário por falta do token (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "refresh_token": [{
+                "code": "length",
+                "message": "Informe o token de atualização.",
+                "params": {
+                    "min": 1,
+                    "value": ""
+                }
+            }]
+        })
+	), (
+		status = 401, description = "Token de atualização inválido ou expirado (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "ExpiredSignature",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Conta suspensa por um administrador ou desativada pelo usuário (Forbidden)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "forbidden",
+                "message": "Esta conta foi suspensa.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("refresh")]
+async fn refresh_token(
+    body: web::Json<RefreshTokenDTO>,
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+) -> impl Responder {
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match refresh_token_service(pg_pool, redis_pool, body.into_inner().refresh_token).await {
+        Ok(tokens) => HttpResponse::Ok().json(LoginUserControllerResponse {
+            access_token: tokens.access_token,
+            access_expires_in: tokens.access_expires_in,
+            refresh_token: tokens.refresh_token,
+            refresh_expires_in: tokens.refresh_expires_in,
+        }),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/batch",
+    security(("bearer_auth" = [])),
+    request_body = BatchGetUsersDTO,
+    responses((
+        status = 200, description = "Usuários encontrados indexados pelo id, ids inexistentes são omitidos (OK)",
+        body = HashMap<String, UserSummaryDTO>, content_type = "application/json", example = json ! ({
+            "f5d46b1b-6adb-40ac-82d6-b0006cf781c0": {
+                "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                "name": "Borrow Lightning",
+                "username": "borrow_lightning"
+            }
+        })
+    ), (
+		status = 400, description = "Erro do usuário por ids inválidos ou mais de 100 ids (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "ids": [{
+                "code": "length",
+                "message": "Envie no máximo 100 ids por requisição.",
+                "params": {
+                    "max": 100,
+                    "value": []
+                }
+            }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("batch")]
+async fn batch_get_users(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<BatchGetUsersDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match batch_get_users_service(pg_pool, redis_pool, body.into_inner(), tenant_id).await {
+        Ok(users) => HttpResponse::Ok().json(users),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/autocomplete",
+    params(
+        ("q" = Option<String>, Query, description = "Início do nome de usuário digitado após o @, com pelo menos 2 caracteres"),
+    ),
+    responses((
+        status = 200, description = "Até 10 usuários ativos cujo nome de usuário começa com o prefixo, sem diferenciar maiúsculas; vazio para prefixos com menos de 2 caracteres (OK)",
+        body = Vec<MentionSuggestionDTO>, content_type = "application/json", example = json ! ([
+            { "username": "victor_navarro", "name": "Victor Navarro" },
+            { "username": "victoria", "name": "Victoria Lima" }
+        ])
+    ), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("autocomplete")]
+async fn mention_autocomplete(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    query_params: web::Query<AutocompleteParams>,
+    req: HttpRequest,
+) -> impl Responder {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    let prefix = query_params.into_inner().q.unwrap_or_default();
+    match mention_autocomplete_service(pg_pool, redis_pool, prefix, tenant_id).await {
+        Ok(suggestions) => HttpResponse::Ok().json(suggestions),
+        Err(e) => e,
+    }
+}
+
 #[utoipa::path(
     tag = "user",
     path = "/user",
@@ -269,13 +597,13 @@ async fn login_user_response_constructor(
                     "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
                     "name": "borrow lightning",
                     "email": "lightning@gmail.com",
-                    "created_at": "2024-06-18 22:03:54.053147-03",
+                    "createdAt": "2024-06-18 22:03:54.053147-03",
                 },
                 {
                     "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c1",
                     "name": "borrow lightning2",
                     "email": "lightning2@gmail.com",
-                    "created_at": "2024-06-18 22:03:54.053147-02",
+                    "createdAt": "2024-06-18 22:03:54.053147-02",
                 }
         ])
     ), (
@@ -342,7 +670,11 @@ async fn list_users(
         Ok(_) => (),
         Err(e) => return e,
     };
-    match list_users_service(pg_pool, query_params).await {
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match list_users_service(pg_pool, query_params, tenant_id).await {
         Ok(users) => HttpResponse::Ok().json(users),
         Err(e) => e,
     }
@@ -353,14 +685,27 @@ async fn list_users(
     path = "/user/{user_id}",
     security(("bearer_auth" = [])),
     responses((
-        status = 200, description = "Detalhamento de usuário com sucesso (OK)", body = DetailUserControllerResponse,
+        status = 200, description = "Detalhamento de usuário com sucesso (OK)", body = DetailUserResponseDTO,
         content_type = "application/json", example = json ! ({
             "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
             "name": "borrow lightning",
+            "username": "borrow_lightning",
             "email": "lightning@gmail.com",
-            "created_at": "2024-06-18 22:03:54.053147-03",
-        })
+            "createdAt": "2024-06-18 22:03:54.053147-03",
+            "followerCount": 12,
+            "followingCount": 3,
+            "onboardingComplete": false,
+            "badges": [{
+                "slug": "first_post",
+                "name": "Primeiro post",
+                "iconUrl": "/badges/first_post.svg"
+            }],
+        }), headers((
+			"etag" = String, description = "Hash da versão atual do usuário, para ser enviado no header If-None-Match"
+		))
     ), (
+		status = 304, description = "Usuário não modificado desde a versão do header If-None-Match (Not Modified)"
+	), (
 		status = 400, description = "Erro do usuário por id inválido e/ou falta de preenchimento (Bad Request)",
 		body = ErrorStruct, content_type = "application/json", example = json ! ({
             "user_id": [{
@@ -399,6 +744,12 @@ async fn list_users(
                 }
 		    }]
         })
+	), (
+		status = 410, description = "O usuário desativou a conta (Gone)", body = DeactivatedUserDTO,
+		content_type = "application/json", example = json ! ({
+            "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "deactivated": true
+        })
 	), (
 		status = 500, description = "Erro Interno do Servidor (Internal Server Error)", body = ErrorStruct,
 		content_type = "application/json", example = json ! ({
@@ -434,53 +785,17 @@ async fn detail_user(
     user_id: web::Path<String>,
     req: HttpRequest,
 ) -> impl Responder {
-    match auth_middleware(user_id.clone(), req, "user_id").await {
-        Ok(_) => (),
-        Err(e) => return e,
-    };
-    let redis_user = match Redis::get(&redis_pool, &user_id).await {
-        Ok(redis_user) => redis_user,
-        Err(_) => String::from(""),
-    };
-    match detail_user_service(pg_pool, user_id.clone(), redis_user).await {
-        Ok(user_dto) => match UserSerdes::serde_json_to_string(&user_dto) {
-            Ok(redis_user) => {
-                let _ = Redis::set(&redis_pool, &user_id, &redis_user).await;
-                let _ = Redis::set(&redis_pool, &user_dto.email, &redis_user).await;
-                let user = DetailUserDTO {
-                    id: user_dto.id,
-                    name: user_dto.name,
-                    email: user_dto.email,
-                    created_at: user_dto.created_at,
-                };
-                HttpResponse::Ok().json(user)
-            }
-            Err(e) => e,
-        },
-        Err(e) => e,
-    }
+    detail_user_response_constructor(pg_pool, redis_pool, user_id.into_inner(), req).await
 }
 
 #[utoipa::path(
     tag = "user",
-    path = "/user/{user_id}",
+    path = "/user/@{username}",
     security(("bearer_auth" = [])),
     responses((
-        status = 202, description = "Requisição aceita (Accepted)"
+        status = 200, description = "Detalhamento de usuário pelo nome de usuário, igual ao de /user/{user_id} (OK)",
+        body = DetailUserResponseDTO
     ), (
-		status = 400, description = "Erro do usuário por id inválido e/ou falta de preenchimento (Bad Request)",
-		body = ErrorStruct, content_type = "application/json", example = json ! ({
-            "user_id": [{
-                "code": "bad request",
-                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
-                "params": {
-                    "min": null,
-                    "value": null,
-                    "max": null
-                }
-		    }]
-        })
-	), (
 		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
 		body = ErrorStruct, content_type = "application/json", example = json ! ({
             "bearer token": [{
@@ -493,31 +808,24 @@ async fn detail_user(
                 }
 		    }]
         })
-	) , (
+	), (
 		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
 		content_type = "application/json", example = json ! ({
-            "id": [{
+            "username": [{
                 "code": "not found",
-                "message": "Não foi encontrado um usuário com este id.",
+                "message": "Não foi encontrado um usuário com este nome de usuário.",
                 "params": {
                     "min": null,
-                    "value": "06buff3f-637d-4c15-a02c-c8247ffb9400",
+                    "value": "borrow_lightning",
                     "max": null
                 }
 		    }]
         })
 	), (
-		status = 500, description = "Erro Interno do Servidor (Internal Server Error)", body = ErrorStruct,
+		status = 410, description = "O usuário desativou a conta (Gone)", body = DeactivatedUserDTO,
 		content_type = "application/json", example = json ! ({
-            "jsonwebtoken": [{
-                "code": "internal server error",
-                "message": "failed to decode token",
-                "params": {
-                    "min": null,
-                    "value": null,
-                    "max": null,
-                }
-		    }]
+            "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "deactivated": true
         })
 	), (
 		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
@@ -534,21 +842,213 @@ async fn detail_user(
         })
 	))
 )]
-#[delete("{user_id}")]
-async fn delete_user(
+#[get("@{username}")]
+async fn detail_user_by_username(
     pg_pool: web::Data<deadpool_postgres::Pool>,
     redis_pool: web::Data<deadpool_redis::Pool>,
-    queue: web::Data<Arc<DeleteUserAppQueue>>,
-    body: web::Json<DeleteUserDTO>,
-    user_id: web::Path<String>,
+    username: web::Path<String>,
     req: HttpRequest,
 ) -> impl Responder {
-    match auth_middleware(user_id.clone(), req, "user_id").await {
-        Ok(_) => (),
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
         Err(e) => return e,
     };
-    match body.validate() {
-        Ok(_) => (),
+    let user_id = match user_id_by_username_service(
+        pg_pool.clone(),
+        username.into_inner(),
+        tenant_id,
+    )
+    .await
+    {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    detail_user_response_constructor(pg_pool, redis_pool, user_id, req).await
+}
+
+async fn detail_user_response_constructor(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: String,
+    req: HttpRequest,
+) -> HttpResponse {
+    match auth_middleware(user_id.clone(), req.clone(), "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match user_deactivated_service(pg_pool.clone(), &user_id).await {
+        Ok(true) => {
+            return HttpResponse::Gone().json(DeactivatedUserDTO {
+                id: user_id,
+                deactivated: true,
+            })
+        }
+        Ok(false) => (),
+        Err(e) => return e,
+    };
+    match etag_middleware(&redis_pool, "user", &user_id, &req).await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let redis_user = match Redis::get(&redis_pool, &user_id).await {
+        Ok(redis_user) => redis_user,
+        Err(_) => String::from(""),
+    };
+    match detail_user_service(pg_pool.clone(), user_id.clone(), redis_user).await {
+        Ok(user_dto) => match UserSerdes::serde_json_to_string(&user_dto) {
+            Ok(redis_user) => {
+                let _ = Redis::set(&redis_pool, &user_id, &redis_user).await;
+                let _ = Redis::set(&redis_pool, &user_dto.email, &redis_user).await;
+                let counts =
+                    match follow_counts_service(pg_pool.clone(), &redis_pool, &user_id).await {
+                        Ok(counts) => counts,
+                        Err(e) => return e,
+                    };
+                let onboarding =
+                    match detail_user_onboarding_service(pg_pool.clone(), user_id.clone()).await {
+                        Ok(onboarding) => onboarding,
+                        Err(e) => return e,
+                    };
+                let preferences = match detail_user_preferences_service(
+                    pg_pool.clone(),
+                    redis_pool.clone(),
+                    user_id.clone(),
+                )
+                .await
+                {
+                    Ok(preferences) => preferences,
+                    Err(e) => return e,
+                };
+                let badges = match list_user_badges_service(pg_pool.clone(), user_id.clone()).await
+                {
+                    Ok(badges) => badges,
+                    Err(e) => return e,
+                };
+                let user = DetailUserResponseDTO {
+                    id: user_dto.id,
+                    name: user_dto.name,
+                    username: user_dto.username,
+                    email: user_dto.email,
+                    created_at_local: local_time(&user_dto.created_at, &preferences.timezone),
+                    created_at: user_dto.created_at,
+                    follower_count: counts.follower_count,
+                    following_count: counts.following_count,
+                    onboarding_complete: onboarding.onboarding_complete,
+                    badges: badges.into_iter().map(BadgeSummaryDTO::from).collect(),
+                };
+                etag_response(&redis_pool, "user", &user_id, &req, &user).await
+            }
+            Err(e) => e,
+        },
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 202, description = "Requisição aceita (Accepted)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido e/ou falta de preenchimento (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Ação destrutiva bloqueada em modo de representação (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Ações destrutivas não são permitidas em modo de representação.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	) , (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "id": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "06buff3f-637d-4c15-a02c-c8247ffb9400",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 500, description = "Erro Interno do Servidor (Internal Server Error)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "jsonwebtoken": [{
+                "code": "internal server error",
+                "message": "failed to decode token",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("{user_id}")]
+async fn delete_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    queue: web::Data<Arc<DeleteUserAppQueue>>,
+    body: web::Json<DeleteUserDTO>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req.clone(), "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match impersonation_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
         Err(e) => return HttpResponse::BadRequest().json(e),
     };
     let redis_user = match Redis::get(&redis_pool, &user_id).await {
@@ -576,6 +1076,7 @@ async fn delete_user_response_constructor(
 ) -> HttpResponse {
     let _ = Redis::delete(redis_pool, user_id).await;
     let _ = Redis::delete(redis_pool, email).await;
+    etag_invalidate(redis_pool, "user", user_id).await;
     HttpResponse::Accepted().finish()
 }
 
@@ -588,7 +1089,7 @@ async fn delete_user_response_constructor(
 			"location" = String, description = "Link para realizar get de dados do usuário atualizado"
 		))
     ), (
-		status = 400, description = "Erro do usuário por id inválido e/ou falta de preenchimento (Bad Request)",
+		status = 400, description = "Erro do usuário por id inválido, senha reutilizada e/ou falta de preenchimento (Bad Request)",
 		body = ErrorStruct, content_type = "application/json", example = json ! ({
             "user_id": [{
                 "code": "bad request",
@@ -614,7 +1115,7 @@ async fn delete_user_response_constructor(
 		    }]
         })
 	), (
-		status = 403, description = "Acesso negado (Forbidden)",
+		status = 403, description = "Acesso negado ou ação bloqueada em modo de representação (Forbidden)",
 		body = ErrorStruct, content_type = "application/json", example = json ! ({
             "user": [{
                 "code": "forbidden",
@@ -689,7 +1190,11 @@ async fn put_user(
     user_id: web::Path<String>,
     req: HttpRequest,
 ) -> impl Responder {
-    match auth_middleware(user_id.clone(), req, "user_id").await {
+    match auth_middleware(user_id.clone(), req.clone(), "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match impersonation_middleware(req.headers()) {
         Ok(_) => (),
         Err(e) => return e,
     };
@@ -731,6 +1236,7 @@ async fn put_user_response_constructor(
     let _ = Redis::delete(redis_pool, excluded_email).await;
     let _ = Redis::set(redis_pool, user_id, redis_user).await;
     let _ = Redis::set(redis_pool, new_email, redis_user).await;
+    etag_invalidate(redis_pool, "user", user_id).await;
     HttpResponse::Accepted()
         .append_header(("Location", format!("/user/{}", user_id)))
         .finish()
@@ -806,3 +1312,728 @@ async fn user_id_options(req: HttpRequest) -> impl Responder {
         Err(e) => e,
     }
 }
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/username",
+    security(("bearer_auth" = [])),
+    request_body = UpdateUsernameDTO,
+    responses((
+        status = 200, description = "Nome de usuário alterado com sucesso (OK)", body = UsernameDTO,
+        content_type = "application/json", example = json ! ({
+            "username": "borrow_lightning",
+            "usernameChangedAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por campo inválido e/ou falta de preenchimento (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "username": [{
+                "code": "regex",
+                "message": "O nome de usuário deve conter apenas letras minúsculas, dígitos e sublinhados.",
+                "params": {
+                    "value": "Borrow Lightning"
+                }
+            }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Ação bloqueada em modo de representação (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Ações destrutivas não são permitidas em modo de representação.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 409, description = "Nome de usuário já utilizado (Conflict)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "username": [{
+                "code": "conflict",
+                "message": "Este nome de usuário já está sendo utilizado por outro usuário.",
+                "params": {
+                    "min": null,
+                    "value": "borrow_lightning",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 429, description = "Nome de usuário alterado há menos de 30 dias (Too Many Requests)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "username": [{
+                "code": "too many requests",
+                "message": "O nome de usuário só pode ser alterado uma vez a cada 30 dias. Tente novamente a partir de 18/07/2024.",
+                "params": {
+                    "min": null,
+                    "value": "borrow_lightning",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{user_id}/username")]
+async fn update_username(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<UpdateUsernameDTO>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req.clone(), "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match impersonation_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    let user = match update_username_service(pg_pool, user_id.clone(), body.into_inner()).await {
+        Ok(user) => user,
+        Err(e) => return e,
+    };
+    if let Ok(redis_user) = UserSerdes::serde_json_to_string(&user) {
+        let _ = Redis::set(&redis_pool, &user.id, &redis_user).await;
+        let _ = Redis::set(&redis_pool, &user.email, &redis_user).await;
+    }
+    etag_invalidate(&redis_pool, "user", &user.id).await;
+    HttpResponse::Ok().json(UsernameDTO {
+        username: user.username,
+        username_changed_at: user.updated_at.unwrap_or_default(),
+    })
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/name",
+    security(("bearer_auth" = [])),
+    request_body = UpdateNameDTO,
+    responses((
+        status = 200, description = "Nome alterado com sucesso (OK)", body = DetailUserDTO,
+        content_type = "application/json", example = json ! ({
+            "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "name": "Borrow Lightning",
+            "username": "borrow_lightning",
+            "email": "lightning@gmail.com",
+            "createdAt": "2024-06-18 22:03:54.053147 UTC"
+        })
+    ), (
+		status = 400, description = "Erro do usuário por campo inválido e/ou falta de preenchimento (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "name": [{
+                "code": "length",
+                "message": "O nome deve ter entre 3 e 63 caracteres.",
+                "params": {
+                    "min": 3,
+                    "value": "Bo",
+                    "max": 63
+                }
+            }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Ação bloqueada em modo de representação (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Ações destrutivas não são permitidas em modo de representação.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 429, description = "Nome alterado 3 vezes nos últimos 30 dias (Too Many Requests)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "name": [{
+                "code": "too many requests",
+                "message": "O nome só pode ser alterado 3 vezes a cada 30 dias.",
+                "params": {
+                    "min": null,
+                    "value": "Borrow Lightning",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{user_id}/name")]
+async fn update_name(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    body: web::Json<UpdateNameDTO>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req.clone(), "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match impersonation_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    let user = match update_name_service(pg_pool, user_id.clone(), body.into_inner()).await {
+        Ok(user) => user,
+        Err(e) => return e,
+    };
+    if let Ok(redis_user) = UserSerdes::serde_json_to_string(&user) {
+        let _ = Redis::set(&redis_pool, &user.id, &redis_user).await;
+        let _ = Redis::set(&redis_pool, &user.email, &redis_user).await;
+    }
+    etag_invalidate(&redis_pool, "user", &user.id).await;
+    HttpResponse::Ok().json(DetailUserDTO {
+        id: user.id,
+        name: user.name,
+        username: user.username,
+        email: user.email,
+        created_at: user.created_at,
+    })
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/user/{user_id}/role",
+    security(("bearer_auth" = [])),
+    request_body = UpdateRoleDTO,
+    responses((
+        status = 204, description = "Papel do usuário alterado, aplicado aos tokens a partir do próximo login ou refresh (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id ou papel inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "role": [{
+                "code": "role",
+                "message": "O papel deve ser user ou admin.",
+                "params": {
+                    "value": "owner"
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{user_id}/role")]
+async fn update_role(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    body: web::Json<UpdateRoleDTO>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match update_role_service(pg_pool, user_id, body.into_inner()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/deactivate",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Conta desativada pelo próprio usuário ou por um administrador, bloqueando o login até a reativação (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "123",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Token ausente, inválido ou de outro usuário que não é administrador (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Ação bloqueada em modo de representação (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Ações destrutivas não são permitidas em modo de representação.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{user_id}/deactivate")]
+async fn deactivate_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    deactivation_response_constructor(pg_pool, user_id.into_inner(), req, true).await
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/reactivate",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Conta reativada, usando a sessão aberta antes da desativação ou o token de um administrador (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "123",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Token ausente, inválido ou de outro usuário que não é administrador (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Ação bloqueada em modo de representação (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Ações destrutivas não são permitidas em modo de representação.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{user_id}/reactivate")]
+async fn reactivate_user(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    deactivation_response_constructor(pg_pool, user_id.into_inner(), req, false).await
+}
+
+/// Lets the owner of the account or an admin deactivate and reactivate it.
+async fn deactivation_response_constructor(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: String,
+    req: HttpRequest,
+    deactivated: bool,
+) -> HttpResponse {
+    let token = match jwt_token_middleware(req.headers()) {
+        Ok(token) => token,
+        Err(e) => return e,
+    };
+    match impersonation_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let user_id = match uuid_path_middleware(user_id, "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    if token.claims.role != "admin" {
+        match auth_middleware(user_id.clone(), req, "user_id").await {
+            Ok(_) => (),
+            Err(e) => return e,
+        };
+    }
+    match set_deactivated_service(pg_pool, user_id, deactivated).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/user/{user_id}/name-history",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Todas as alterações de nome do usuário, das mais recentes para as mais antigas (OK)",
+        body = Vec<NameHistoryDTO>, content_type = "application/json", example = json ! ([{
+            "id": "0f1e2d3c-4b5a-6978-8796-a5b4c3d2e1f0",
+            "userId": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+            "oldName": "Borrow",
+            "newName": "Borrow Lightning",
+            "changedAt": "2024-06-18 22:03:54.053147 UTC"
+        }])
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/user/{user_id}/name-history")]
+async fn name_history(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match list_name_history_service(pg_pool, user_id).await {
+        Ok(history) => HttpResponse::Ok().json(history),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/user",
+    security(("bearer_auth" = [])),
+    params(
+        ("offset" = Option<i8>, Query, description = "Paginação (offset)"),
+        ("limit" = Option<i8>, Query, description = "Paginação (limit)"),
+        ("order_by" = Option<String>, Query, description = "Coluna de ordenação"),
+        ("order_direction" = Option<String>, Query, description = "Direção da ordenação"),
+        ("role" = Option<String>, Query, description = "Papel do usuário (user ou admin)"),
+        ("email_verified" = Option<bool>, Query, description = "Se o e-mail do usuário foi verificado"),
+        ("created_after" = Option<String>, Query, description = "Criados a partir desta data (RFC 3339)"),
+        ("created_before" = Option<String>, Query, description = "Criados antes desta data (RFC 3339)"),
+        ("banned" = Option<bool>, Query, description = "Se o usuário está suspenso")
+    ),
+    responses((
+        status = 200, description = "Listagem filtrada de usuários (OK)", body = ListUserControllerResponse,
+        content_type = "application/json", example = json ! ([
+                {
+                    "id": "f5d46b1b-6adb-40ac-82d6-b0006cf781c0",
+                    "name": "borrow lightning",
+                    "username": "borrow",
+                    "email": "lightning@gmail.com",
+                    "createdAt": "2024-06-18 22:03:54.053147 UTC",
+                }
+        ])
+    ), (
+		status = 400, description = "Erro do usuário por filtro inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "created_after": [{
+                "code": "date",
+                "message": "A data deve estar no formato RFC 3339.",
+                "params": {
+                    "value": "ontem"
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Nenhum usuário atende aos filtros (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "users": [{
+                "code": "not found",
+                "message": "Não foram encontrados usuários.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("/user")]
+async fn admin_list_users(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    req: HttpRequest,
+    query_params: web::Query<QueryParams>,
+    filter: web::Query<UserFilterParams>,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let tenant_id = match tenant_middleware(req.headers()) {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => return e,
+    };
+    match filter.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match admin_list_users_service(pg_pool, query_params, filter.into_inner(), tenant_id).await {
+        Ok(users) => HttpResponse::Ok().json(users),
+        Err(e) => e,
+    }
+}
@@ -1,25 +1,49 @@
+use crate::{
+    modules::user_badge::user_badge_dtos::BadgeSummaryDTO,
+    utils::password_strength::{score_password, MIN_PASSWORD_STRENGTH},
+};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use utoipa::ToSchema;
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 #[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct UserDTO {
     pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub username: String,
     pub email: String,
     pub password: String,
+    #[serde(alias = "created_at")]
     pub created_at: String,
+    #[serde(alias = "updated_at")]
     pub updated_at: Option<String>,
 }
 
 static RE_NAME: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(^[a-zA-ZÀ-ÿ0-9\s]+$)|(^.*?[@$!%*?&].*$)").unwrap());
+static RE_USERNAME: Lazy<Regex> = Lazy::new(|| Regex::new("^[a-z0-9_]+$").unwrap());
 static RE_PASSWORD: Lazy<Regex> = Lazy::new(|| Regex::new("^.*?[@$!%*?&].*$").unwrap());
 static RE_EMAIL: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$").unwrap());
 
+/// Refuses the passwords scoring below `MIN_PASSWORD_STRENGTH`, returning the score in the
+/// `password_strength` param.
+fn password_strength_valid(password: &str) -> Result<(), ValidationError> {
+    let score = score_password(password);
+    if score >= MIN_PASSWORD_STRENGTH {
+        return Ok(());
+    }
+    let mut error =
+        ValidationError::new("password_strength").with_message(Cow::from("A senha é muito fraca."));
+    error.add_param(Cow::from("password_strength"), &score);
+    Err(error)
+}
+
 #[derive(Serialize, Deserialize, ToSchema, Validate, Clone)]
 pub struct InsertUserDTO {
     #[validate(
@@ -36,6 +60,20 @@ pub struct InsertUserDTO {
     #[serde(default)]
     pub name: String,
 
+    #[validate(
+		length(
+			min = 3,
+			max = 30,
+			message = "O nome de usuário deve ter entre 3 e 30 caracteres."
+		),
+		regex(
+			path = * RE_USERNAME,
+			message = "O nome de usuário deve conter apenas letras minúsculas, dígitos e sublinhados."
+		)
+	)]
+    #[serde(default)]
+    pub username: String,
+
     #[validate(
 		email(message = "O e-mail deve ser um endereço válido."),
 		length(
@@ -57,10 +95,15 @@ pub struct InsertUserDTO {
 		regex(
 			path = * RE_PASSWORD,
 			message = "A senha deve ter pelo menos 1 caractere especial."
-		)
+		),
+		custom(function = "password_strength_valid")
 	)]
     #[serde(default)]
     pub password: String,
+
+    /// Required when the `REQUIRE_INVITE_CODE` env var is `true`.
+    #[serde(default, alias = "inviteCode")]
+    pub invite_code: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema, Validate, Clone)]
@@ -92,12 +135,64 @@ pub struct LoginUserDTO {
     pub password: String,
 }
 
+fn user_role_valid(role: &str) -> Result<(), ValidationError> {
+    if ["user", "admin"].contains(&role) {
+        return Ok(());
+    }
+    Err(ValidationError::new("role").with_message(Cow::from("O papel deve ser user ou admin.")))
+}
+
+fn filter_date_valid(date: &str) -> Result<(), ValidationError> {
+    if chrono::DateTime::parse_from_rfc3339(date).is_ok() {
+        return Ok(());
+    }
+    Err(ValidationError::new("date")
+        .with_message(Cow::from("A data deve estar no formato RFC 3339.")))
+}
+
+/// The filters of the admin listing of users. Every filter is optional and they are combined with
+/// `AND`.
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone, Default)]
+pub struct UserFilterParams {
+    #[validate(custom(function = "user_role_valid"))]
+    pub role: Option<String>,
+    pub email_verified: Option<bool>,
+    /// Only users created at or after this RFC 3339 timestamp.
+    #[validate(custom(function = "filter_date_valid"))]
+    pub created_after: Option<String>,
+    /// Only users created before this RFC 3339 timestamp.
+    #[validate(custom(function = "filter_date_valid"))]
+    pub created_before: Option<String>,
+    pub banned: Option<bool>,
+}
+
 #[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct DetailUserDTO {
     pub id: String,
     pub name: String,
+    pub username: String,
+    pub email: String,
+    #[serde(alias = "created_at")]
+    pub created_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailUserResponseDTO {
+    pub id: String,
+    pub name: String,
+    pub username: String,
     pub email: String,
     pub created_at: String,
+    /// `created_at` in the time zone set in the preferences of the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at_local: Option<String>,
+    pub follower_count: i64,
+    pub following_count: i64,
+    pub onboarding_complete: bool,
+    #[serde(default)]
+    pub badges: Vec<BadgeSummaryDTO>,
 }
 
 #[derive(ToSchema, Serialize, Deserialize, Clone, Validate)]
@@ -144,7 +239,7 @@ pub struct PutUserDTO {
 			message = "A senha deve ter pelo menos 1 caractere especial."
 		)
 	)]
-    #[serde(default)]
+    #[serde(default, alias = "newPassword")]
     pub new_password: String,
 
     #[validate(
@@ -168,6 +263,116 @@ pub struct PutUserDTO {
 		),
 		regex(path = * RE_EMAIL, message = "O e-mail deve ser um endereço válido.")
 	)]
-    #[serde(default)]
+    #[serde(default, alias = "newEmail")]
     pub new_email: String,
 }
+
+#[derive(Serialize, Deserialize, ToSchema, Validate, Clone)]
+pub struct UpdateUsernameDTO {
+    #[validate(
+		length(
+			min = 3,
+			max = 30,
+			message = "O nome de usuário deve ter entre 3 e 30 caracteres."
+		),
+		regex(
+			path = * RE_USERNAME,
+			message = "O nome de usuário deve conter apenas letras minúsculas, dígitos e sublinhados."
+		)
+	)]
+    #[serde(default)]
+    pub username: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsernameDTO {
+    pub username: String,
+    pub username_changed_at: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Validate, Clone)]
+pub struct UpdateRoleDTO {
+    #[validate(custom(function = "user_role_valid"))]
+    pub role: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Validate, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshTokenDTO {
+    #[validate(length(min = 1, message = "Informe o token de atualização."))]
+    #[serde(default, alias = "refresh_token")]
+    pub refresh_token: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Validate, Clone)]
+pub struct UpdateNameDTO {
+    #[validate(
+		length(
+			min = 3,
+			max = 63,
+			message = "O nome deve ter entre 3 e 63 caracteres."
+		),
+		regex(
+			path = * RE_NAME,
+			message = "O nome deve conter apenas dígitos validos."
+		)
+	)]
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NameHistoryDTO {
+    pub id: String,
+    pub user_id: String,
+    pub old_name: String,
+    pub new_name: String,
+    pub changed_at: String,
+}
+
+fn batch_ids_valid(ids: &[String]) -> Result<(), ValidationError> {
+    if ids.iter().all(|id| uuid::Uuid::parse_str(id).is_ok()) {
+        return Ok(());
+    }
+    Err(ValidationError::new("ids")
+        .with_message(Cow::from("Todos os ids devem ser UUIDs válidos.")))
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct BatchGetUsersDTO {
+    #[validate(
+        length(max = 100, message = "Envie no máximo 100 ids por requisição."),
+        custom(function = "batch_ids_valid")
+    )]
+    #[serde(default)]
+    pub ids: Vec<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSummaryDTO {
+    pub id: String,
+    pub name: String,
+    pub username: String,
+}
+
+/// The body of the `410 Gone` answered for a user who deactivated the account.
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct DeactivatedUserDTO {
+    pub id: String,
+    pub deactivated: bool,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+pub struct AutocompleteParams {
+    pub q: Option<String>,
+}
+
+/// A user suggested while typing an `@` mention.
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+pub struct MentionSuggestionDTO {
+    pub username: String,
+    pub name: String,
+}
@@ -1,5 +1,9 @@
-use crate::utils::{
-    error_construct::error_construct, query_constructor_executor::query_constructor_executor,
+use super::user_repositories::{password_history_repository, PASSWORD_HISTORY_LIMIT};
+use crate::{
+    shared::treaties::strip_suffix_treated::StripSuffix,
+    utils::{
+        error_construct::error_construct, query_constructor_executor::query_constructor_executor,
+    },
 };
 use actix_web::{web::Data, HttpResponse};
 use deadpool_postgres::Pool;
@@ -50,3 +54,94 @@ pub async fn email_not_exists(pg_pool: Data<Pool>, email: String) -> Result<(),
     }
     Ok(())
 }
+
+pub async fn username_exists(pg_pool: Data<Pool>, username: String) -> Result<(), HttpResponse> {
+    let mut sql_builder = SqlBuilder::select_from("users");
+    sql_builder.field("id");
+    sql_builder.or_where_eq("username", quote(username.clone()));
+
+    let rows = match query_constructor_executor(pg_pool, sql_builder).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if !rows.is_empty() {
+        return Err(HttpResponse::Conflict().json(error_construct(
+            String::from("username"),
+            String::from("conflict"),
+            String::from("Este nome de usuário já está sendo utilizado por outro usuário."),
+            Some(username),
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
+
+pub async fn username_not_exists(
+    pg_pool: Data<Pool>,
+    username: String,
+) -> Result<(), HttpResponse> {
+    let mut sql_builder = SqlBuilder::select_from("users");
+    sql_builder.field("id");
+    sql_builder.or_where_eq("username", quote(username.clone()));
+
+    let rows = match query_constructor_executor(pg_pool, sql_builder).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(username_not_found(username));
+    }
+    Ok(())
+}
+
+pub fn username_not_found(username: String) -> HttpResponse {
+    HttpResponse::NotFound().json(error_construct(
+        String::from("username"),
+        String::from("not found"),
+        String::from("Não foi encontrado um usuário com este nome de usuário."),
+        Some(username),
+        None,
+        None,
+    ))
+}
+
+/// Fails with 400 when the new password matches the current password of the user or one of the
+/// last `PASSWORD_HISTORY_LIMIT` ones. Every stored hash carries the user salt as a suffix, which
+/// is stripped before verifying.
+pub async fn password_not_reused(
+    pg_pool: Data<Pool>,
+    user_id: uuid::Uuid,
+    current_hash: String,
+    salt: &str,
+    new_password: &str,
+) -> Result<(), HttpResponse> {
+    let mut hashes = match password_history_repository(pg_pool, user_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    hashes.insert(0, current_hash);
+
+    for hash in hashes {
+        let hash = match StripSuffix::strip_suffix(hash, salt) {
+            Ok(hash) => hash,
+            Err(e) => return Err(e),
+        };
+        if bcrypt::verify(new_password, &hash).unwrap_or(false) {
+            return Err(HttpResponse::BadRequest().json(error_construct(
+                String::from("new_password"),
+                String::from("bad request"),
+                format!(
+                    "A nova senha não pode ser igual a uma das últimas {} senhas utilizadas.",
+                    PASSWORD_HISTORY_LIMIT
+                ),
+                None,
+                None,
+                None,
+            )));
+        }
+    }
+    Ok(())
+}
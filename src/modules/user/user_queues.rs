@@ -1,78 +1,174 @@
-use super::user_dtos::{InsertUserDTO, PutUserDTO};
-use crate::shared::exceptions::custom_error_to_io_error_kind::{
-    custom_error_to_io_error_kind, CustomError,
+use super::{
+    user_dtos::{InsertUserDTO, PutUserDTO},
+    user_repositories::PASSWORD_HISTORY_LIMIT,
+};
+use crate::{
+    modules::dead_letter::{
+        dead_letter_dtos::INSERT_USER_QUEUE,
+        dead_letter_repositories::insert_failed_queue_items_repository,
+    },
+    shared::exceptions::custom_error_to_io_error_kind::{
+        custom_error_to_io_error_kind, CustomError,
+    },
+};
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
 };
-use actix_web::{web::Json, HttpResponse};
 use deadpool_postgres::Pool;
+use serde::{Deserialize, Serialize};
 use sql_builder::{quote, SqlBuilder};
 use std::{io::ErrorKind, sync::Arc, time::Duration};
 
-type InsertUserQueueEvent = (String, Json<InsertUserDTO>, String, String);
+type InsertUserQueueEvent = (String, Json<InsertUserDTO>, String, String, String);
 pub type InsertUserAppQueue = deadqueue::unlimited::Queue<InsertUserQueueEvent>;
 
+const DELETE_FAILED_QUEUE_ITEMS_SQL: &str = "DELETE FROM failed_queue_items WHERE id = ANY($1)";
+
+/// A user of the insertion queue as stored in `failed_queue_items` when its flush fails.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsertUserQueuePayload {
+    pub id: String,
+    pub user: InsertUserDTO,
+    pub created_at: String,
+    pub salt: String,
+    pub tenant_id: String,
+}
+
+/// Pushes a user read back from `failed_queue_items` into the insertion queue.
+pub fn requeue_insert_user(queue: &InsertUserAppQueue, payload: InsertUserQueuePayload) {
+    queue.push((
+        payload.id,
+        Json(payload.user),
+        payload.created_at,
+        payload.salt,
+        payload.tenant_id,
+    ));
+}
+
+/// Stores the users of a failed flush in `failed_queue_items`, to be retried by
+/// `dead_letter_reprocess_job`.
+async fn dead_letter_insert_users(pool: Pool, events: Vec<InsertUserQueueEvent>, error: String) {
+    let items = events
+        .into_iter()
+        .map(|(id, body, created_at, salt, tenant_id)| {
+            let payload = InsertUserQueuePayload {
+                id: id.clone(),
+                user: body.into_inner(),
+                created_at,
+                salt,
+                tenant_id,
+            };
+            (
+                uuid::Uuid::parse_str(&id).unwrap_or_default(),
+                serde_json::to_string(&payload).unwrap_or_default(),
+            )
+        })
+        .collect();
+    if let Err(e) =
+        insert_failed_queue_items_repository(Data::new(pool), INSERT_USER_QUEUE, items, error).await
+    {
+        log::warn!(
+            "Failed to store the users of a failed flush: status {}",
+            e.status()
+        );
+    }
+}
+
+async fn insert_user_batch(
+    pool: Pool,
+    user_sql: &str,
+    user_salt_sql: &str,
+    user_ids: &[uuid::Uuid],
+) -> Result<(), CustomError> {
+    let mut conn = match pool.get().await {
+        Ok(x) => x,
+        Err(e) => return Err(CustomError::PoolError(e)),
+    };
+    let transaction = match conn.transaction().await {
+        Ok(x) => x,
+        Err(e) => return Err(CustomError::TokioPostgres(e)),
+    };
+    match transaction.batch_execute(user_sql).await {
+        Ok(_) => (),
+        Err(e) => return Err(CustomError::TokioPostgres(e)),
+    };
+    match transaction.batch_execute(user_salt_sql).await {
+        Ok(_) => (),
+        Err(e) => return Err(CustomError::TokioPostgres(e)),
+    };
+    match transaction
+        .execute(DELETE_FAILED_QUEUE_ITEMS_SQL, &[&user_ids])
+        .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(CustomError::TokioPostgres(e)),
+    };
+    match transaction.commit().await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(CustomError::TokioPostgres(e)),
+    }
+}
+
+/// Inserts every user of the queue in one transaction. When it fails, the users are moved to
+/// `failed_queue_items` instead of being lost.
 async fn insert_user_queue(pool: Pool, queue: Arc<InsertUserAppQueue>) -> Result<(), HttpResponse> {
     let mut user_sql = String::new();
     let mut user_salt_sql = String::new();
+    let mut events = Vec::with_capacity(queue.len());
 
     while queue.len() > 0 {
-        let (id, body, created_at, salt) = queue.pop().await;
+        let (id, body, created_at, salt, tenant_id) = queue.pop().await;
 
         let mut sql_builder = SqlBuilder::insert_into("users");
         sql_builder
             .field("id")
             .field("name")
+            .field("username")
             .field("email")
             .field("password")
-            .field("created_at");
+            .field("created_at")
+            .field("tenant_id");
         sql_builder.values(&[
             &quote(&id),
             &quote(&body.name),
+            &quote(&body.username),
             &quote(&body.email),
             &quote(&body.password),
             &quote(&created_at),
+            &quote(&tenant_id),
         ]);
 
-        let mut this_sql = match sql_builder.sql() {
+        let this_sql = match sql_builder.sql() {
             Ok(x) => x,
             Err(_) => continue,
         };
-        this_sql.pop();
         user_sql.push_str(&this_sql);
 
         let mut sql_builder = SqlBuilder::insert_into("salt");
         sql_builder.field("user_id").field("salt");
         sql_builder.values(&[&quote(&id), &quote(&salt)]);
 
-        let mut this_sql = match sql_builder.sql() {
+        let this_sql = match sql_builder.sql() {
             Ok(x) => x,
             Err(_) => continue,
         };
-        this_sql.pop();
         user_salt_sql.push_str(&this_sql);
+        events.push((id, body, created_at, salt, tenant_id));
     }
 
-    let mut conn = match pool.get().await {
-        Ok(x) => x,
-        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::PoolError(e))),
-    };
-    let transaction = match conn.transaction().await {
-        Ok(x) => x,
-        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
-    };
-    match transaction.batch_execute(&user_sql).await {
-        Ok(_) => (),
-        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
-    };
-    match transaction.batch_execute(&user_salt_sql).await {
-        Ok(_) => (),
-        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
-    };
-    match transaction.commit().await {
-        Ok(_) => (),
-        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
-    };
-
-    Ok(())
+    let user_ids: Vec<uuid::Uuid> = events
+        .iter()
+        .map(|(id, ..)| uuid::Uuid::parse_str(id).unwrap_or_default())
+        .collect();
+    match insert_user_batch(pool.clone(), &user_sql, &user_salt_sql, &user_ids).await {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            dead_letter_insert_users(pool, events, e.to_string()).await;
+            Err(custom_error_to_io_error_kind(e))
+        }
+    }
 }
 
 pub async fn insert_user_flush_queue(pool_async: Pool, queue_async: Arc<InsertUserAppQueue>) {
@@ -96,15 +192,51 @@ pub async fn insert_user_flush_queue(pool_async: Pool, queue_async: Arc<InsertUs
     }
 }
 
+/// Flushes the users left in the queue on shutdown, giving up after `timeout`.
+pub async fn drain_insert_user_queue(
+    pool: Pool,
+    queue: Arc<InsertUserAppQueue>,
+    timeout: Duration,
+) {
+    match tokio::time::timeout(timeout, insert_user_queue(pool, queue.clone())).await {
+        Ok(Ok(_)) => (),
+        Ok(Err(e)) => log::warn!(
+            "Failed to flush the user queue on shutdown: status {}",
+            e.status()
+        ),
+        Err(_) => log::warn!(
+            "The user queue was not drained in {}s, {} users left",
+            timeout.as_secs(),
+            queue.len()
+        ),
+    }
+}
+
 type PutUserQueueEvent = (String, Json<PutUserDTO>, String);
 pub type PutUserAppQueue = deadqueue::unlimited::Queue<PutUserQueueEvent>;
 
+/// The replaced password of every user is recorded in `password_history` in the same transaction
+/// of the update, so it is only kept when the update is committed.
 async fn put_user_queue(pool: Pool, queue: Arc<PutUserAppQueue>) -> Result<(), HttpResponse> {
     let mut user_sql = String::new();
 
     while queue.len() > 0 {
         let (user_id, user, updated_at) = queue.pop().await;
 
+        user_sql.push_str(&format!(
+            "INSERT INTO password_history (id, user_id, password_hash, created_at)
+            SELECT {}, id, password, NOW() FROM users WHERE id = {};
+            DELETE FROM password_history WHERE user_id = {} AND id NOT IN (
+                SELECT id FROM password_history WHERE user_id = {}
+                ORDER BY created_at DESC LIMIT {}
+            );",
+            quote(uuid::Uuid::new_v4().to_string()),
+            quote(user_id.clone()),
+            quote(user_id.clone()),
+            quote(user_id.clone()),
+            PASSWORD_HISTORY_LIMIT
+        ));
+
         let mut sql_builder = sql_builder::SqlBuilder::update_table("users");
 
         sql_builder.set("password", &quote(user.new_password.clone()));
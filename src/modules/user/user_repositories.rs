@@ -1,30 +1,47 @@
 use super::{
-    user_dtos::{DetailUserDTO, InsertUserDTO, PutUserDTO, UserDTO},
+    user_dtos::{
+        DetailUserDTO, InsertUserDTO, MentionSuggestionDTO, NameHistoryDTO, PutUserDTO, UserDTO,
+        UserFilterParams,
+    },
     user_queues::{DeleteUserAppQueue, InsertUserAppQueue, PutUserAppQueue},
 };
 use crate::{
-    shared::structs::query_params::QueryParams,
+    shared::{
+        exceptions::custom_error_to_io_error_kind::{custom_error_to_io_error_kind, CustomError},
+        structs::query_params::QueryParams,
+    },
     utils::{
-        error_construct::error_construct, query_constructor_executor::query_constructor_executor,
+        error_construct::error_construct,
+        query_constructor_executor::{query_constructor_executor, query_executor},
     },
 };
 use actix_web::{
     web::{Data, Json, Query},
     HttpResponse,
 };
+use chrono::{DateTime, Utc};
 use sql_builder::quote;
 use std::sync::Arc;
+use tokio_postgres::types::ToSql;
+
+/// How many previous passwords of a user are kept, and refused as a new password.
+pub const PASSWORD_HISTORY_LIMIT: i64 = 5;
 
 fn user_dto_constructor(rows: Vec<postgres::Row>) -> UserDTO {
-    let user_id: uuid::Uuid = rows[0].get("id");
-    let created_at: chrono::DateTime<chrono::Utc> = rows[0].get("created_at");
-    let updated_at: Option<chrono::DateTime<chrono::Utc>> = rows[0].get("updated_at");
+    user_row_constructor(&rows[0])
+}
+
+fn user_row_constructor(row: &postgres::Row) -> UserDTO {
+    let user_id: uuid::Uuid = row.get("id");
+    let created_at: chrono::DateTime<chrono::Utc> = row.get("created_at");
+    let updated_at: Option<chrono::DateTime<chrono::Utc>> = row.get("updated_at");
 
     UserDTO {
         id: user_id.to_string(),
-        name: rows[0].get("name"),
-        email: rows[0].get("email"),
-        password: rows[0].get("password"),
+        name: row.get("name"),
+        username: row.get("username"),
+        email: row.get("email"),
+        password: row.get("password"),
         created_at: created_at.to_string(),
         updated_at: updated_at.map(|dt| dt.to_string()),
     }
@@ -63,30 +80,35 @@ pub async fn insert_user_repository(
     body: Json<InsertUserDTO>,
     user_id: String,
     user_salt: String,
+    tenant_id: String,
 ) -> Result<UserDTO, HttpResponse> {
     let name = body.name.clone();
+    let username = body.username.clone();
     let email = body.email.clone();
     let password = body.password.clone();
     let created_at = chrono::Utc::now().to_string();
     let dto = UserDTO {
         id: user_id.clone(),
         name,
+        username,
         email,
         password,
         created_at: created_at.clone(),
         updated_at: None,
     };
-    queue.push((user_id.clone(), body, created_at, user_salt));
+    queue.push((user_id.clone(), body, created_at, user_salt, tenant_id));
 
     Ok(dto)
 }
 
 pub async fn login_user_repository(
     email: String,
+    tenant_id: String,
     pg_pool: Data<deadpool_postgres::Pool>,
 ) -> Result<UserDTO, HttpResponse> {
     let mut sql_builder = sql_builder::SqlBuilder::select_from("users");
-    sql_builder.or_where_eq("email", &quote(email.clone()));
+    sql_builder.and_where_eq("email", &quote(email.clone()));
+    sql_builder.and_where_eq("tenant_id", quote(tenant_id));
 
     let rows = match query_constructor_executor(pg_pool, sql_builder).await {
         Ok(x) => x,
@@ -133,9 +155,418 @@ pub async fn detail_user_repository(
     Ok(user_dto_constructor(rows))
 }
 
+/// Returns which of the ids belong to users of the tenant.
+pub async fn tenant_user_ids_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_ids: &[uuid::Uuid],
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<uuid::Uuid>, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        "SELECT id FROM users WHERE id = ANY($1) AND tenant_id = $2",
+        &[&user_ids, &tenant_id],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get("id")).collect()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the users with the ids, leaving out the ids that do not exist.
+pub async fn batch_get_users_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_ids: &[uuid::Uuid],
+) -> Result<Vec<UserDTO>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT * FROM users WHERE id = ANY($1)",
+        &[&user_ids],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.iter().map(user_row_constructor).collect())
+}
+
+/// Returns the user mentioned as `@username` in a comment of the post, looked up in the tenant of
+/// the post, the same users suggested by the mention autocomplete.
+pub async fn find_mentioned_user_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    username: &str,
+    post_id: uuid::Uuid,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT u.id FROM users u
+        JOIN posts p ON p.id = $2 AND p.tenant_id = u.tenant_id
+        WHERE LOWER(u.username) = LOWER($1)
+            AND u.banned_at IS NULL AND u.deleted_at IS NULL AND u.deactivated_at IS NULL",
+        &[&username, &post_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.first().map(|row| row.get("id")))
+}
+
+pub async fn find_user_by_username_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    username: &str,
+    tenant_id: uuid::Uuid,
+) -> Result<Option<uuid::Uuid>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT id FROM users WHERE username = $1 AND tenant_id = $2",
+        &[&username, &tenant_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.first().map(|row| row.get("id")))
+}
+
+/// Returns when the user last changed their username, or `None` if they never did.
+pub async fn username_changed_at_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Option<chrono::DateTime<chrono::Utc>>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT username_changed_at FROM users WHERE id = $1",
+        &[&user_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("user"),
+            String::from("not found"),
+            String::from("Não foi encontrado um usuário com este id."),
+            Some(user_id.to_string()),
+            None,
+            None,
+        )));
+    }
+
+    Ok(rows[0].get("username_changed_at"))
+}
+
+/// Updates the username of the user, returning the updated user.
+pub async fn update_username_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    username: String,
+) -> Result<UserDTO, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        "UPDATE users SET username = $2, username_changed_at = NOW(), updated_at = NOW()
+        WHERE id = $1 RETURNING *",
+        &[&user_id, &username],
+    )
+    .await
+    {
+        Ok(rows) => Ok(user_dto_constructor(rows)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Counts the name changes of the user in the last `days` days.
+pub async fn recent_name_changes_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    days: i32,
+) -> Result<i64, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT COUNT(*) AS total FROM user_name_history
+        WHERE user_id = $1 AND changed_at > NOW() - make_interval(days => $2)",
+        &[&user_id, &days],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows[0].get("total"))
+}
+
+/// Updates the name of the user, returning the updated user.
+///
+/// When the name changes, the old and the new one are recorded in `user_name_history` in the same
+/// statement, before the update is applied.
+pub async fn update_user_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    name: String,
+) -> Result<UserDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "WITH old_user AS (
+            SELECT id, name FROM users WHERE id = $1 FOR UPDATE
+        ), history AS (
+            INSERT INTO user_name_history (id, user_id, old_name, new_name, changed_at)
+            SELECT $3, id, name, $2, NOW() FROM old_user WHERE name <> $2
+        )
+        UPDATE users SET name = $2, updated_at = NOW()
+        FROM old_user WHERE users.id = old_user.id RETURNING users.*",
+        &[&user_id, &name, &uuid::Uuid::new_v4()],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("user"),
+            String::from("not found"),
+            String::from("Não foi encontrado um usuário com este id."),
+            Some(user_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(user_dto_constructor(rows))
+}
+
+/// Returns every name change of the user, from the most recent to the oldest.
+pub async fn list_name_history_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Vec<NameHistoryDTO>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT id, user_id, old_name, new_name, changed_at FROM user_name_history
+        WHERE user_id = $1 ORDER BY changed_at DESC",
+        &[&user_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let id: uuid::Uuid = row.get("id");
+            let user_id: uuid::Uuid = row.get("user_id");
+            let changed_at: chrono::DateTime<chrono::Utc> = row.get("changed_at");
+            NameHistoryDTO {
+                id: id.to_string(),
+                user_id: user_id.to_string(),
+                old_name: row.get("old_name"),
+                new_name: row.get("new_name"),
+                changed_at: changed_at.to_string(),
+            }
+        })
+        .collect())
+}
+
+/// Returns the last `PASSWORD_HISTORY_LIMIT` password hashes of the user, from the most recent to
+/// the oldest. The hashes keep the salt suffix, as stored in `users`.
+pub async fn password_history_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Vec<String>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT password_hash FROM password_history
+        WHERE user_id = $1 ORDER BY created_at DESC LIMIT $2",
+        &[&user_id, &PASSWORD_HISTORY_LIMIT],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.iter().map(|row| row.get("password_hash")).collect())
+}
+
+/// Returns the role of the user, whether the e-mail was verified and the tenant of the user, the
+/// claims embedded in the tokens. `None` when the user does not exist or was deleted.
+pub async fn user_claims_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Option<(String, bool, uuid::Uuid)>, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT u.role, COALESCE(o.email_verified, false) AS email_verified, u.tenant_id
+        FROM users u
+        LEFT JOIN user_onboarding o ON o.user_id = u.id
+        WHERE u.id = $1 AND u.deleted_at IS NULL",
+        &[&user_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    Ok(rows.first().map(|row| {
+        (
+            row.get("role"),
+            row.get("email_verified"),
+            row.get("tenant_id"),
+        )
+    }))
+}
+
+pub async fn update_role_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    role: String,
+) -> Result<(), HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "UPDATE users SET role = $2, updated_at = NOW() WHERE id = $1 AND deleted_at IS NULL RETURNING id",
+        &[&user_id, &role],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("user"),
+            String::from("not found"),
+            String::from("Não foi encontrado um usuário com este id."),
+            Some(user_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
+
+/// Sets `deactivated_at` to now when `deactivated` is true, clearing it otherwise.
+pub async fn set_deactivated_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    deactivated: bool,
+) -> Result<(), HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        "UPDATE users SET deactivated_at = CASE WHEN $2 THEN NOW() END, updated_at = NOW() WHERE id = $1 AND deleted_at IS NULL RETURNING id",
+        &[&user_id, &deactivated],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("user"),
+            String::from("not found"),
+            String::from("Não foi encontrado um usuário com este id."),
+            Some(user_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    Ok(())
+}
+
+/// Returns whether the user was banned, `false` when the user does not exist.
+pub async fn user_banned_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        "SELECT banned_at IS NOT NULL AS banned FROM users WHERE id = $1",
+        &[&user_id],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.first().is_some_and(|row| row.get("banned"))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns whether the user deactivated the account, `false` when the user does not exist.
+pub async fn user_deactivated_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<bool, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        "SELECT deactivated_at IS NOT NULL AS deactivated FROM users WHERE id = $1",
+        &[&user_id],
+    )
+    .await
+    {
+        Ok(rows) => Ok(rows.first().is_some_and(|row| row.get("deactivated"))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns up to `limit` active users of the tenant whose username starts with `prefix`,
+/// ignoring the case. The `%`, `_` and `\` of the prefix are matched literally.
+pub async fn mention_autocomplete_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    prefix: &str,
+    limit: u8,
+    tenant_id: uuid::Uuid,
+) -> Result<Vec<MentionSuggestionDTO>, HttpResponse> {
+    let pattern = format!(
+        "{}%",
+        prefix
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    );
+    let rows = match query_executor(
+        pg_pool,
+        "SELECT username, name FROM users
+        WHERE username ILIKE $1 AND tenant_id = $3
+            AND banned_at IS NULL AND deleted_at IS NULL AND deactivated_at IS NULL
+        ORDER BY username
+        LIMIT $2",
+        &[&pattern, &i64::from(limit), &tenant_id],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| MentionSuggestionDTO {
+            username: row.get("username"),
+            name: row.get("name"),
+        })
+        .collect())
+}
+
+/// Lists the users of the tenant. The admin listing sends a `UserFilterParams`, whose filters are
+/// bound as query parameters.
 pub async fn list_users_repository(
     pg_pool: Data<deadpool_postgres::Pool>,
     query_params: Query<QueryParams>,
+    tenant_id: String,
+    filter: Option<UserFilterParams>,
 ) -> Result<Vec<DetailUserDTO>, HttpResponse> {
     let order_by = query_params
         .order_by
@@ -146,8 +577,40 @@ pub async fn list_users_repository(
         .clone()
         .unwrap_or(String::from("desc"));
 
+    let mut params: Vec<Box<dyn ToSql + Sync>> = Vec::new();
     let mut sql_builder = sql_builder::SqlBuilder::select_from("users");
-    sql_builder.fields(&["id", "name", "email", "created_at"]);
+    sql_builder.fields(&["id", "name", "username", "email", "created_at"]);
+    params.push(Box::new(
+        uuid::Uuid::parse_str(&tenant_id).unwrap_or_default(),
+    ));
+    sql_builder.and_where("tenant_id = $1");
+
+    let filter = filter.unwrap_or_default();
+    if let Some(role) = filter.role {
+        params.push(Box::new(role));
+        sql_builder.and_where(format!("role = ${}", params.len()));
+    }
+    if let Some(email_verified) = filter.email_verified {
+        params.push(Box::new(email_verified));
+        sql_builder.and_where(format!(
+            "EXISTS (SELECT 1 FROM user_onboarding WHERE user_id = users.id AND email_verified) = ${}",
+            params.len()
+        ));
+    }
+    for (created_at, operator) in [(filter.created_after, ">="), (filter.created_before, "<")] {
+        let created_at = created_at
+            .as_deref()
+            .and_then(|created_at| DateTime::parse_from_rfc3339(created_at).ok());
+        if let Some(created_at) = created_at {
+            params.push(Box::new(created_at.with_timezone(&Utc)));
+            sql_builder.and_where(format!("created_at {} ${}", operator, params.len()));
+        }
+    }
+    if let Some(banned) = filter.banned {
+        params.push(Box::new(banned));
+        sql_builder.and_where(format!("(banned_at IS NOT NULL) = ${}", params.len()));
+    }
+
     sql_builder.order_by(
         order_by,
         match order_direction.as_str() {
@@ -160,7 +623,12 @@ pub async fn list_users_repository(
     sql_builder.limit(limit);
     sql_builder.offset(query_params.offset.unwrap_or(0));
 
-    let rows = match query_constructor_executor(pg_pool, sql_builder).await {
+    let sql = match sql_builder.sql() {
+        Ok(x) => x,
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::AnyhowError(e))),
+    };
+    let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|param| param.as_ref()).collect();
+    let rows = match query_executor(pg_pool, &sql, &params).await {
         Ok(x) => x,
         Err(e) => return Err(e),
     };
@@ -183,6 +651,7 @@ pub async fn list_users_repository(
         let user = DetailUserDTO {
             id: user_id.to_string(),
             name: row.get("name"),
+            username: row.get("username"),
             email: row.get("email"),
             created_at: created_at.to_string(),
         };
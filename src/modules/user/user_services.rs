@@ -1,11 +1,31 @@
 use super::{
-    user_dtos::{DetailUserDTO, InsertUserDTO, LoginUserDTO, PutUserDTO, UserDTO},
-    user_providers::{email_exists, email_not_exists},
+    user_dtos::{
+        BatchGetUsersDTO, DetailUserDTO, InsertUserDTO, LoginUserDTO, MentionSuggestionDTO,
+        NameHistoryDTO, PutUserDTO, UpdateNameDTO, UpdateRoleDTO, UpdateUsernameDTO, UserDTO,
+        UserFilterParams, UserSummaryDTO,
+    },
+    user_providers::{
+        email_exists, email_not_exists, password_not_reused, username_exists, username_not_exists,
+        username_not_found,
+    },
     user_queues::{DeleteUserAppQueue, InsertUserAppQueue, PutUserAppQueue},
     user_repositories::*,
     user_serdes::UserSerdes,
 };
 use crate::{
+    infra::redis::Redis,
+    modules::{
+        admin::admin_services::BANNED_USERS_KEY,
+        invite_code::{
+            invite_code_providers::{
+                invalid_invite_code, validate_invite_code_provider, REQUIRE_INVITE_CODE,
+            },
+            invite_code_repositories::use_invite_code_repository,
+        },
+        login_history::{
+            login_history_dtos::LoginClient, login_history_services::record_login_attempt_service,
+        },
+    },
     shared::{
         structs::query_params::QueryParams,
         treaties::{
@@ -20,13 +40,22 @@ use actix_web::{
     web::{Data, Json, Query},
     HttpResponse,
 };
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
+
+pub const USERNAME_CHANGE_INTERVAL_DAYS: i64 = 30;
+pub const NAME_CHANGES_LIMIT: i64 = 3;
+pub const NAME_CHANGES_INTERVAL_DAYS: i32 = 30;
+pub const AUTOCOMPLETE_KEY: &str = "autocomplete:users";
+const AUTOCOMPLETE_EXPIRATION_SECONDS: u64 = 30;
+const AUTOCOMPLETE_MIN_PREFIX_CHARS: usize = 2;
+const AUTOCOMPLETE_LIMIT: u8 = 10;
 
 pub async fn insert_user_service(
     queue: Data<Arc<InsertUserAppQueue>>,
     pg_pool: Data<deadpool_postgres::Pool>,
     mut body: Json<InsertUserDTO>,
     redis_user: String,
+    tenant_id: String,
 ) -> Result<UserDTO, HttpResponse> {
     if redis_user != String::from("") {
         return Err(HttpResponse::Conflict().json(error_construct(
@@ -38,10 +67,23 @@ pub async fn insert_user_service(
             None,
         )));
     }
-    match email_exists(pg_pool, body.email.clone()).await {
+    match email_exists(pg_pool.clone(), body.email.clone()).await {
         Ok(_) => (),
         Err(e) => return Err(e),
     };
+    match username_exists(pg_pool.clone(), body.username.clone()).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let invite_code = match *REQUIRE_INVITE_CODE {
+        true => {
+            match validate_invite_code_provider(pg_pool.clone(), body.invite_code.clone()).await {
+                Ok(code) => Some(code),
+                Err(e) => return Err(e),
+            }
+        }
+        false => None,
+    };
 
     let user_id = uuid::Uuid::new_v4().to_string();
     let hash = match Bcrypt::hash(&body.password) {
@@ -51,7 +93,16 @@ pub async fn insert_user_service(
     let user_salt = uuid::Uuid::new_v4().to_string();
     body.password = format!("{}{}", hash, user_salt);
 
-    match insert_user_repository(queue.clone(), body, user_id.clone(), user_salt).await {
+    if let Some(code) = invite_code {
+        let new_user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+        match use_invite_code_repository(pg_pool, code, new_user_id).await {
+            Ok(true) => (),
+            Ok(false) => return Err(invalid_invite_code(body.invite_code.clone())),
+            Err(e) => return Err(e),
+        };
+    }
+
+    match insert_user_repository(queue.clone(), body, user_id.clone(), user_salt, tenant_id).await {
         Ok(user) => Ok(user),
         Err(e) => Err(e),
     }
@@ -65,10 +116,158 @@ pub struct LoginUserServiceResponse {
     pub access_expires_in: i64,
 }
 
+pub struct RefreshTokenServiceResponse {
+    pub refresh_token: String,
+    pub refresh_expires_in: i64,
+    pub access_token: String,
+    pub access_expires_in: i64,
+}
+
+/// Mints a new pair of tokens carrying the current role, e-mail verification and tenant of the user.
+async fn issue_tokens(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: &str,
+) -> Result<RefreshTokenServiceResponse, HttpResponse> {
+    let user_uuid = uuid::Uuid::parse_str(user_id).unwrap_or_default();
+    let (role, email_verified, tenant_id) = match user_claims_repository(pg_pool, user_uuid).await {
+        Ok(Some(claims)) => claims,
+        Ok(None) => {
+            return Err(HttpResponse::Unauthorized().json(error_construct(
+                String::from("bearer token"),
+                String::from("unauthorized"),
+                String::from("Acesso negado por token de autorização."),
+                None,
+                None,
+                None,
+            )))
+        }
+        Err(e) => return Err(e),
+    };
+
+    let refresh_token = match Jwt::refresh_token_constructor(
+        user_id.to_string(),
+        role.clone(),
+        email_verified,
+        tenant_id.to_string(),
+    ) {
+        Ok(refresh_token) => refresh_token,
+        Err(e) => return Err(e),
+    };
+    let access_token = match Jwt::access_token_constructor(
+        user_id.to_string(),
+        role,
+        email_verified,
+        tenant_id.to_string(),
+    ) {
+        Ok(access_token) => access_token,
+        Err(e) => return Err(e),
+    };
+    Ok(RefreshTokenServiceResponse {
+        refresh_token,
+        refresh_expires_in: 7 * 60 * 60 * 24,
+        access_token,
+        access_expires_in: 30 * 60,
+    })
+}
+
+fn banned_user() -> HttpResponse {
+    HttpResponse::Forbidden().json(error_construct(
+        String::from("user"),
+        String::from("forbidden"),
+        String::from("Esta conta foi suspensa."),
+        None,
+        None,
+        None,
+    ))
+}
+
+fn deactivated_user(user_id: &str) -> HttpResponse {
+    HttpResponse::Forbidden().json(error_construct(
+        String::from("user"),
+        String::from("forbidden"),
+        format!(
+            "Sua conta está desativada. Use /user/{}/reactivate para reativá-la.",
+            user_id
+        ),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Refuses the banned and the deactivated users. The bans are read from the Redis set and, when
+/// Redis is unavailable, from `users.banned_at`.
+async fn user_access_allowed(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    user_id: &str,
+) -> Result<(), HttpResponse> {
+    let user_uuid = uuid::Uuid::parse_str(user_id).unwrap_or_default();
+    let banned = match Redis::sismember(redis_pool, BANNED_USERS_KEY, user_id).await {
+        Ok(banned) => banned,
+        Err(e) => {
+            log::warn!("Failed to read the banned users from Redis: {}", e);
+            match user_banned_repository(pg_pool.clone(), user_uuid).await {
+                Ok(banned) => banned,
+                Err(e) => return Err(e),
+            }
+        }
+    };
+    if banned {
+        return Err(banned_user());
+    }
+    match user_deactivated_repository(pg_pool, user_uuid).await {
+        Ok(true) => Err(deactivated_user(user_id)),
+        Ok(false) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Exchanges a refresh token for a new pair of tokens, reading the role and the e-mail
+/// verification again, since they may have changed since the login.
+pub async fn refresh_token_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    refresh_token: String,
+) -> Result<RefreshTokenServiceResponse, HttpResponse> {
+    let token = match Jwt::refresh_token_decode(&refresh_token) {
+        Ok(token) => token,
+        Err(e) => return Err(e),
+    };
+    match user_access_allowed(pg_pool.clone(), &redis_pool, &token.claims.sub).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    issue_tokens(pg_pool, &token.claims.sub).await
+}
+
+/// Logs the user in and records the attempt, successful or not, in the login history.
 pub async fn login_user_service(
     body: LoginUserDTO,
     pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
     redis_user: String,
+    client: LoginClient,
+    tenant_id: String,
+) -> Result<LoginUserServiceResponse, HttpResponse> {
+    let email = body.email.clone();
+    let resp = login_user_attempt(body, pg_pool.clone(), redis_pool, redis_user, tenant_id).await;
+    record_login_attempt_service(
+        pg_pool,
+        email,
+        &client,
+        resp.as_ref().map(|resp| resp.user.id.as_str()),
+    )
+    .await;
+    resp
+}
+
+async fn login_user_attempt(
+    body: LoginUserDTO,
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    redis_user: String,
+    tenant_id: String,
 ) -> Result<LoginUserServiceResponse, HttpResponse> {
     if redis_user == String::from("") {
         match email_not_exists(pg_pool.clone(), body.email.clone()).await {
@@ -77,7 +276,8 @@ pub async fn login_user_service(
         }
     }
 
-    let user_dto = match login_user_repository(body.email.clone(), pg_pool.clone()).await {
+    let user_dto = match login_user_repository(body.email.clone(), tenant_id, pg_pool.clone()).await
+    {
         Ok(user) => user,
         Err(e) => return Err(e),
     };
@@ -102,20 +302,21 @@ pub async fn login_user_service(
         Err(e) => return Err(e),
     };
 
-    let refresh_token = match Jwt::refresh_token_constructor(user_dto.id.clone()) {
-        Ok(refresh_token) => refresh_token,
+    match user_access_allowed(pg_pool.clone(), &redis_pool, &user_dto.id).await {
+        Ok(_) => (),
         Err(e) => return Err(e),
     };
-    let access_token = match Jwt::access_token_constructor(user_dto.id.clone()) {
-        Ok(access_token) => access_token,
+
+    let tokens = match issue_tokens(pg_pool, &user_dto.id).await {
+        Ok(tokens) => tokens,
         Err(e) => return Err(e),
     };
     Ok(LoginUserServiceResponse {
         user: user_dto,
-        refresh_token,
-        refresh_expires_in: 7 * 60 * 60 * 24,
-        access_token,
-        access_expires_in: 30 * 60,
+        refresh_token: tokens.refresh_token,
+        refresh_expires_in: tokens.refresh_expires_in,
+        access_token: tokens.access_token,
+        access_expires_in: tokens.access_expires_in,
     })
 }
 
@@ -137,16 +338,262 @@ pub async fn detail_user_service(
     }
 }
 
+/// Deactivates or reactivates the account. Posts and comments of a deactivated user stay visible,
+/// but the login and the refresh are refused until the user reactivates it with an access token
+/// issued before, or an admin reactivates it.
+pub async fn set_deactivated_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    deactivated: bool,
+) -> Result<(), HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    set_deactivated_repository(pg_pool, user_id, deactivated).await
+}
+
+pub async fn user_deactivated_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: &str,
+) -> Result<bool, HttpResponse> {
+    user_deactivated_repository(pg_pool, uuid::Uuid::parse_str(user_id).unwrap_or_default()).await
+}
+
+/// Returns the users of the tenant with the ids keyed by id, reading the cached ones from Redis and
+/// querying Postgres only for the cache misses, which are cached afterwards. Unknown ids and the
+/// users of other tenants are left out.
+pub async fn batch_get_users_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    body: BatchGetUsersDTO,
+    tenant_id: String,
+) -> Result<HashMap<String, UserSummaryDTO>, HttpResponse> {
+    let mut users = HashMap::with_capacity(body.ids.len());
+    let mut missing_ids = Vec::new();
+
+    let user_ids: Vec<uuid::Uuid> = body
+        .ids
+        .iter()
+        .map(|user_id| uuid::Uuid::parse_str(user_id).unwrap_or_default())
+        .collect();
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let tenant_user_ids =
+        match tenant_user_ids_repository(pg_pool.clone(), &user_ids, tenant_id).await {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+
+    for user_id in tenant_user_ids {
+        if users.contains_key(&user_id.to_string()) || missing_ids.contains(&user_id) {
+            continue;
+        }
+        let cached = match Redis::get(&redis_pool, &user_id.to_string()).await {
+            Ok(redis_user) => UserSerdes::serde_string_to_json(&redis_user).ok(),
+            Err(_) => None,
+        };
+        match cached {
+            Some(user) => {
+                users.insert(user.id.clone(), user_summary_constructor(user));
+            }
+            None => missing_ids.push(user_id),
+        };
+    }
+    if missing_ids.is_empty() {
+        return Ok(users);
+    }
+
+    let db_users = match batch_get_users_repository(pg_pool, &missing_ids).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    for user in db_users {
+        if let Ok(redis_user) = UserSerdes::serde_json_to_string(&user) {
+            let _ = Redis::set(&redis_pool, &user.id, &redis_user).await;
+            let _ = Redis::set(&redis_pool, &user.email, &redis_user).await;
+        }
+        users.insert(user.id.clone(), user_summary_constructor(user));
+    }
+    Ok(users)
+}
+
+fn user_summary_constructor(user: UserDTO) -> UserSummaryDTO {
+    UserSummaryDTO {
+        id: user.id,
+        name: user.name,
+        username: user.username,
+    }
+}
+
+/// Returns the id of the user with the username.
+/// Suggests up to `AUTOCOMPLETE_LIMIT` usernames starting with the prefix typed after the `@`,
+/// cached for `AUTOCOMPLETE_EXPIRATION_SECONDS`. Prefixes shorter than
+/// `AUTOCOMPLETE_MIN_PREFIX_CHARS` get no suggestions.
+pub async fn mention_autocomplete_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    prefix: String,
+    tenant_id: String,
+) -> Result<Vec<MentionSuggestionDTO>, HttpResponse> {
+    let prefix = prefix.trim().trim_start_matches('@').to_lowercase();
+    if prefix.chars().count() < AUTOCOMPLETE_MIN_PREFIX_CHARS {
+        return Ok(Vec::new());
+    }
+
+    let autocomplete_key = format!("{}:{}:{}", AUTOCOMPLETE_KEY, tenant_id, prefix);
+    if let Ok(cached) = Redis::get(&redis_pool, &autocomplete_key).await {
+        if let Ok(suggestions) = serde_json::from_str(&cached) {
+            return Ok(suggestions);
+        }
+    }
+
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    let suggestions = match mention_autocomplete_repository(
+        pg_pool,
+        &prefix,
+        AUTOCOMPLETE_LIMIT,
+        tenant_id,
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &autocomplete_key,
+        &serde_json::to_string(&suggestions).unwrap_or_default(),
+        AUTOCOMPLETE_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(suggestions)
+}
+
+pub async fn user_id_by_username_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    username: String,
+    tenant_id: String,
+) -> Result<String, HttpResponse> {
+    match username_not_exists(pg_pool.clone(), username.clone()).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+    let tenant_id = uuid::Uuid::parse_str(&tenant_id).unwrap_or_default();
+    match find_user_by_username_repository(pg_pool, &username, tenant_id).await {
+        Ok(Some(user_id)) => Ok(user_id.to_string()),
+        Ok(None) => Err(username_not_found(username)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Changes the username of the user, allowed once every `USERNAME_CHANGE_INTERVAL_DAYS` days.
+pub async fn update_username_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    body: UpdateUsernameDTO,
+) -> Result<UserDTO, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let changed_at = match username_changed_at_repository(pg_pool.clone(), user_id).await {
+        Ok(changed_at) => changed_at,
+        Err(e) => return Err(e),
+    };
+    if let Some(changed_at) = changed_at {
+        let available_at = changed_at + chrono::Duration::days(USERNAME_CHANGE_INTERVAL_DAYS);
+        if available_at > chrono::Utc::now() {
+            return Err(HttpResponse::TooManyRequests().json(error_construct(
+                String::from("username"),
+                String::from("too many requests"),
+                format!(
+                    "O nome de usuário só pode ser alterado uma vez a cada {} dias. Tente novamente a partir de {}.",
+                    USERNAME_CHANGE_INTERVAL_DAYS,
+                    available_at.format("%d/%m/%Y")
+                ),
+                Some(body.username),
+                None,
+                None,
+            )));
+        }
+    }
+    match username_exists(pg_pool.clone(), body.username.clone()).await {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
+    match update_username_repository(pg_pool, user_id, body.username).await {
+        Ok(user) => Ok(user),
+        Err(e) => Err(e),
+    }
+}
+
+/// Changes the name of the user, allowed `NAME_CHANGES_LIMIT` times every
+/// `NAME_CHANGES_INTERVAL_DAYS` days.
+pub async fn update_name_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    body: UpdateNameDTO,
+) -> Result<UserDTO, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let recent_changes =
+        match recent_name_changes_repository(pg_pool.clone(), user_id, NAME_CHANGES_INTERVAL_DAYS)
+            .await
+        {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+    if recent_changes >= NAME_CHANGES_LIMIT {
+        return Err(HttpResponse::TooManyRequests().json(error_construct(
+            String::from("name"),
+            String::from("too many requests"),
+            format!(
+                "O nome só pode ser alterado {} vezes a cada {} dias.",
+                NAME_CHANGES_LIMIT, NAME_CHANGES_INTERVAL_DAYS
+            ),
+            Some(body.name),
+            None,
+            None,
+        )));
+    }
+
+    update_user_repository(pg_pool, user_id, body.name.trim().to_string()).await
+}
+
+/// Changes the role of the user. It is embedded in the tokens minted from the next login or
+/// refresh on.
+pub async fn update_role_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+    body: UpdateRoleDTO,
+) -> Result<(), HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    update_role_repository(pg_pool, user_id, body.role).await
+}
+
+pub async fn list_name_history_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+) -> Result<Vec<NameHistoryDTO>, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    list_name_history_repository(pg_pool, user_id).await
+}
+
 pub async fn list_users_service(
     pg_pool: Data<deadpool_postgres::Pool>,
     query_params: Query<QueryParams>,
+    tenant_id: String,
 ) -> Result<Vec<DetailUserDTO>, HttpResponse> {
-    match list_users_repository(pg_pool, query_params).await {
+    match list_users_repository(pg_pool, query_params, tenant_id, None).await {
         Ok(user) => Ok(user),
         Err(e) => Err(e),
     }
 }
 
+/// Lists the users of the tenant for the admin dashboard, narrowed by the filters.
+pub async fn admin_list_users_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    query_params: Query<QueryParams>,
+    filter: UserFilterParams,
+    tenant_id: String,
+) -> Result<Vec<DetailUserDTO>, HttpResponse> {
+    list_users_repository(pg_pool, query_params, tenant_id, Some(filter)).await
+}
+
 pub async fn delete_user_service(
     pg_pool: Data<deadpool_postgres::Pool>,
     queue: Data<Arc<DeleteUserAppQueue>>,
@@ -238,6 +685,20 @@ pub async fn put_user_service(
         Err(e) => return Err(e),
     };
 
+    let user_uuid = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    match password_not_reused(
+        pg_pool.clone(),
+        user_uuid,
+        db_user.password.clone(),
+        &salt,
+        &body.new_password,
+    )
+    .await
+    {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+    };
+
     body.new_password = match Bcrypt::hash(&body.new_password) {
         Ok(hash) => format!("{}{}", hash, salt),
         Err(e) => return Err(e),
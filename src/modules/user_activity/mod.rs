@@ -0,0 +1,4 @@
+pub mod user_activity_controllers;
+pub mod user_activity_dtos;
+pub mod user_activity_repositories;
+pub mod user_activity_services;
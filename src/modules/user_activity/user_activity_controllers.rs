@@ -0,0 +1,69 @@
+use super::{
+    user_activity_dtos::ActivityParams, user_activity_services::user_activity_heatmap_service,
+};
+use crate::middlewares::uuid_path_middleware::uuid_path_middleware;
+use actix_web::{get, web, HttpResponse, Responder};
+
+pub fn user_activity_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(user_activity);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/activity",
+    params(
+        ("year" = Option<i32>, Query, description = "Ano da atividade (padrão: ano atual)"),
+    ),
+    responses((
+        status = 200, description = "Quantidade de posts e comentários do usuário por dia do ano, apenas nos dias com atividade (OK)",
+        body = Vec<ActivityDayDTO>, content_type = "application/json", example = json ! ([{
+            "date": "2024-06-18",
+            "count": 3
+        }, {
+            "date": "2024-06-20",
+            "count": 1
+        }])
+    ), (
+		status = 400, description = "Erro do usuário por id ou ano inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "year": [{
+                "code": "bad request",
+                "message": "O ano deve estar entre 1970 e 2024.",
+                "params": {
+                    "min": null,
+                    "value": "1800",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/activity")]
+async fn user_activity(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    query_params: web::Query<ActivityParams>,
+) -> impl Responder {
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match user_activity_heatmap_service(pg_pool, redis_pool, user_id, query_params.year).await {
+        Ok(activity) => HttpResponse::Ok().json(activity),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ActivityDayDTO {
+    pub date: String,
+    pub count: i64,
+}
+
+#[derive(ToSchema, Serialize, Deserialize)]
+pub struct ActivityParams {
+    pub year: Option<i32>,
+}
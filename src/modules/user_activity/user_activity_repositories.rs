@@ -0,0 +1,43 @@
+use super::user_activity_dtos::ActivityDayDTO;
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+
+const USER_ACTIVITY_HEATMAP_SQL: &str = "
+    SELECT to_char(day, 'YYYY-MM-DD') AS date, SUM(count)::BIGINT AS count
+    FROM (
+        SELECT date_trunc('day', created_at AT TIME ZONE 'UTC') AS day, COUNT(*) AS count
+        FROM posts
+        WHERE author_id = $1 AND deleted_at IS NULL AND EXTRACT(YEAR FROM created_at AT TIME ZONE 'UTC') = $2::INT
+        GROUP BY 1
+        UNION ALL
+        SELECT date_trunc('day', created_at AT TIME ZONE 'UTC') AS day, COUNT(*) AS count
+        FROM comments
+        WHERE user_id = $1 AND deleted_at IS NULL AND approved_at IS NOT NULL
+            AND EXTRACT(YEAR FROM created_at AT TIME ZONE 'UTC') = $2::INT
+        GROUP BY 1
+    ) activity
+    GROUP BY day
+    ORDER BY day
+";
+
+/// Counts the posts and comments created by the user on each day (in UTC) of `year`.
+///
+/// Only days with activity are returned, in chronological order.
+pub async fn user_activity_heatmap_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    year: i32,
+) -> Result<Vec<ActivityDayDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, USER_ACTIVITY_HEATMAP_SQL, &[&user_id, &year]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| ActivityDayDTO {
+            date: row.get("date"),
+            count: row.get("count"),
+        })
+        .collect())
+}
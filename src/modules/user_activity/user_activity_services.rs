@@ -0,0 +1,54 @@
+use super::{
+    user_activity_dtos::ActivityDayDTO,
+    user_activity_repositories::user_activity_heatmap_repository,
+};
+use crate::{infra::redis::Redis, utils::error_construct::error_construct};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{Datelike, Utc};
+
+const USER_ACTIVITY_EXPIRATION_SECONDS: u64 = 3600;
+
+pub fn user_activity_key(user_id: &str, year: i32) -> String {
+    format!("user:activity:{}:{}", user_id, year)
+}
+
+pub async fn user_activity_heatmap_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    user_id: String,
+    year: Option<i32>,
+) -> Result<Vec<ActivityDayDTO>, HttpResponse> {
+    let current_year = Utc::now().year();
+    let year = year.unwrap_or(current_year);
+    if !(1970..=current_year).contains(&year) {
+        return Err(HttpResponse::BadRequest().json(error_construct(
+            String::from("year"),
+            String::from("bad request"),
+            format!("O ano deve estar entre 1970 e {}.", current_year),
+            Some(year.to_string()),
+            None,
+            None,
+        )));
+    }
+
+    let key = user_activity_key(&user_id, year);
+    if let Ok(cached) = Redis::get(&redis_pool, &key).await {
+        if let Ok(activity) = serde_json::from_str(&cached) {
+            return Ok(activity);
+        }
+    }
+
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let activity = match user_activity_heatmap_repository(pg_pool, user_id, year).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &key,
+        &serde_json::to_string(&activity).unwrap_or_default(),
+        USER_ACTIVITY_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(activity)
+}
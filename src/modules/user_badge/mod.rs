@@ -0,0 +1,4 @@
+pub mod user_badge_controllers;
+pub mod user_badge_dtos;
+pub mod user_badge_repositories;
+pub mod user_badge_services;
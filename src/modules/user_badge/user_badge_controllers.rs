@@ -0,0 +1,62 @@
+use super::user_badge_services::list_user_badges_service;
+use crate::middlewares::uuid_path_middleware::uuid_path_middleware;
+use actix_web::{get, web, HttpResponse, Responder};
+
+pub fn user_badge_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_user_badges);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/badges",
+    responses((
+        status = 200, description = "Conquistas do usuário, na ordem em que foram obtidas (OK)",
+        body = Vec<UserBadgeDTO>, content_type = "application/json", example = json ! ([{
+            "slug": "first_post",
+            "name": "Primeiro post",
+            "description": "Publicou o primeiro post.",
+            "iconUrl": "/badges/first_post.svg",
+            "awardedAt": "2024-06-18 14:32:10.123456 UTC"
+        }])
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": "1",
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/badges")]
+async fn list_user_badges(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+) -> impl Responder {
+    let user_id = match uuid_path_middleware(user_id.into_inner(), "user_id") {
+        Ok(user_id) => user_id,
+        Err(e) => return e,
+    };
+    match list_user_badges_service(pg_pool, user_id).await {
+        Ok(badges) => HttpResponse::Ok().json(badges),
+        Err(e) => e,
+    }
+}
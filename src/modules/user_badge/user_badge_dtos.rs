@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserBadgeDTO {
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub icon_url: String,
+    pub awarded_at: String,
+}
+
+/// The badge as shown in the detail of the user.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BadgeSummaryDTO {
+    pub slug: String,
+    pub name: String,
+    pub icon_url: String,
+}
+
+impl From<UserBadgeDTO> for BadgeSummaryDTO {
+    fn from(badge: UserBadgeDTO) -> Self {
+        BadgeSummaryDTO {
+            slug: badge.slug,
+            name: badge.name,
+            icon_url: badge.icon_url,
+        }
+    }
+}
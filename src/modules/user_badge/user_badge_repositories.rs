@@ -0,0 +1,81 @@
+use super::user_badge_dtos::UserBadgeDTO;
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+
+const LIST_USER_BADGES_SQL: &str = "
+    SELECT b.slug, b.name, b.description, b.icon_url, ub.awarded_at
+    FROM user_badges ub
+    JOIN badges b ON b.id = ub.badge_id
+    WHERE ub.user_id = $1
+    ORDER BY ub.awarded_at, b.slug
+";
+
+const EVALUATE_BADGES_SQL: &str = "
+    WITH stats AS (
+        SELECT
+            (
+                SELECT COUNT(*) FROM posts
+                WHERE author_id = $1 AND published_at IS NOT NULL AND deleted_at IS NULL
+            ) AS published_posts,
+            (
+                SELECT COALESCE(MAX(likes), 0) FROM (
+                    SELECT COUNT(*) AS likes FROM users_posts_likes l
+                    JOIN posts p ON p.id = l.post_id
+                    WHERE p.author_id = $1 AND p.deleted_at IS NULL
+                    GROUP BY l.post_id
+                ) post_likes
+            ) AS top_post_likes,
+            (
+                SELECT created_at < NOW() - INTERVAL '1 year' FROM users WHERE id = $1
+            ) AS veteran
+    )
+    INSERT INTO user_badges (user_id, badge_id, awarded_at)
+    SELECT $1, b.id, NOW() FROM badges b, stats s
+    WHERE (b.slug = 'first_post' AND s.published_posts >= 1)
+        OR (b.slug = 'ten_posts' AND s.published_posts >= 10)
+        OR (b.slug = 'popular' AND s.top_post_likes >= 100)
+        OR (b.slug = 'veteran' AND s.veteran)
+    ON CONFLICT (user_id, badge_id) DO NOTHING
+    RETURNING badge_id
+";
+
+/// Returns the badges earned by the user, in the order they were awarded.
+pub async fn list_user_badges_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Vec<UserBadgeDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, LIST_USER_BADGES_SQL, &[&user_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let awarded_at: chrono::DateTime<chrono::Utc> = row.get("awarded_at");
+            UserBadgeDTO {
+                slug: row.get("slug"),
+                name: row.get("name"),
+                description: row.get("description"),
+                icon_url: row.get("icon_url"),
+                awarded_at: awarded_at.to_string(),
+            }
+        })
+        .collect())
+}
+
+/// Awards the user every badge whose condition they meet and did not earn yet, returning how many
+/// were awarded:
+///
+/// - `first_post` and `ten_posts`: 1 and 10 published posts;
+/// - `popular`: a post with 100 likes;
+/// - `veteran`: an account older than one year.
+pub async fn evaluate_badges_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<usize, HttpResponse> {
+    match query_executor(pg_pool, EVALUATE_BADGES_SQL, &[&user_id]).await {
+        Ok(rows) => Ok(rows.len()),
+        Err(e) => Err(e),
+    }
+}
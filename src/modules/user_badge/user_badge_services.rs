@@ -0,0 +1,34 @@
+use super::{
+    user_badge_dtos::UserBadgeDTO,
+    user_badge_repositories::{evaluate_badges_repository, list_user_badges_repository},
+};
+use crate::middlewares::etag_middleware::etag_invalidate;
+use actix_web::{web::Data, HttpResponse};
+
+pub async fn list_user_badges_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+) -> Result<Vec<UserBadgeDTO>, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    list_user_badges_repository(pg_pool, user_id).await
+}
+
+/// Awards the user the badges they earned, invalidating the ETag of the user when any was awarded
+/// so its detail lists them.
+///
+/// A failure is only logged, never failing the action that triggered the evaluation.
+pub async fn evaluate_badges_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    user_id: uuid::Uuid,
+) {
+    match evaluate_badges_repository(pg_pool, user_id).await {
+        Ok(0) => (),
+        Ok(_) => etag_invalidate(redis_pool, "user", &user_id.to_string()).await,
+        Err(e) => log::warn!(
+            "Failed to evaluate the badges of user {}: {}",
+            user_id,
+            e.status()
+        ),
+    };
+}
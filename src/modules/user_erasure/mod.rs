@@ -0,0 +1,3 @@
+pub mod user_erasure_controllers;
+pub mod user_erasure_repositories;
+pub mod user_erasure_services;
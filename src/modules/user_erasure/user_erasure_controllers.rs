@@ -0,0 +1,103 @@
+use super::user_erasure_services::delete_all_user_data_service;
+use crate::middlewares::{
+    auth_middleware::auth_middleware, impersonation_middleware::impersonation_middleware,
+};
+use actix_web::{delete, web, HttpRequest, HttpResponse, Responder};
+
+pub fn user_erasure_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(delete_all_user_data);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/account",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 204, description = "Dados pessoais do usuário apagados e conta anonimizada (No Content)"
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Ação destrutiva bloqueada em modo de representação (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Ações destrutivas não são permitidas em modo de representação.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado ou já apagado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[delete("{user_id}/account")]
+async fn delete_all_user_data(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req.clone(), "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match impersonation_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match delete_all_user_data_service(pg_pool, redis_pool, user_id.into_inner()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    shared::exceptions::custom_error_to_io_error_kind::{
+        custom_error_to_io_error_kind, CustomError,
+    },
+    utils::{error_construct::error_construct, query_timeout::with_query_timeout},
+};
+use actix_web::{web::Data, HttpResponse};
+
+const LOCK_USER_SQL: &str =
+    "SELECT email FROM users WHERE id = $1 AND deleted_at IS NULL FOR UPDATE";
+
+const ANONYMIZE_USER_SQL: &str = "
+    UPDATE users SET name = 'Deleted User', email = 'deleted_' || id || '@deleted.invalid',
+        password = '', updated_at = NOW(), deleted_at = NOW()
+    WHERE id = $1
+";
+
+const ERASE_USER_DATA_SQL: [&str; 16] = [
+    "DELETE FROM notifications WHERE user_id = $1",
+    "DELETE FROM reading_lists WHERE user_id = $1",
+    "DELETE FROM drafts WHERE author_id = $1",
+    "DELETE FROM post_views WHERE user_id = $1",
+    "DELETE FROM reading_progress WHERE user_id = $1",
+    "DELETE FROM poll_votes WHERE user_id = $1",
+    "DELETE FROM user_follows WHERE follower_id = $1 OR followee_id = $1",
+    "DELETE FROM salt WHERE user_id = $1",
+    "DELETE FROM api_keys WHERE user_id = $1",
+    "DELETE FROM password_history WHERE user_id = $1",
+    "DELETE FROM user_badges WHERE user_id = $1",
+    "DELETE FROM user_preferences WHERE user_id = $1",
+    "DELETE FROM content_reports WHERE reporter_id = $1",
+    "DELETE FROM login_history WHERE user_id = $1 OR email = (SELECT email FROM users WHERE id = $1)",
+    "UPDATE posts SET deleted_at = NOW() WHERE author_id = $1 AND deleted_at IS NULL",
+    "UPDATE comments SET deleted_at = NOW() WHERE user_id = $1 AND deleted_at IS NULL",
+];
+
+/// Anonymizes the user and erases or soft-deletes all their data in a single transaction.
+///
+/// Returns the original e-mail of the user, which no longer exists in the database after this.
+pub async fn delete_all_user_data_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<String, HttpResponse> {
+    let mut conn = match pg_pool.get().await {
+        Ok(x) => x,
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::PoolError(e))),
+    };
+    let transaction = match conn.transaction().await {
+        Ok(x) => x,
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
+    };
+
+    let rows = match with_query_timeout(
+        transaction.query(LOCK_USER_SQL, &[&user_id]),
+        transaction.cancel_token(),
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    if rows.is_empty() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("user"),
+            String::from("not found"),
+            String::from("Não foi encontrado um usuário com este id."),
+            Some(user_id.to_string()),
+            None,
+            None,
+        )));
+    }
+    let email: String = rows[0].get("email");
+
+    for sql in ERASE_USER_DATA_SQL.iter().chain([&ANONYMIZE_USER_SQL]) {
+        match with_query_timeout(
+            transaction.execute(*sql, &[&user_id]),
+            transaction.cancel_token(),
+        )
+        .await
+        {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+    }
+    match transaction.commit().await {
+        Ok(_) => (),
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
+    };
+
+    Ok(email)
+}
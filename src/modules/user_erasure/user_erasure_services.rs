@@ -0,0 +1,38 @@
+use super::user_erasure_repositories::delete_all_user_data_repository;
+use crate::{
+    infra::redis::Redis, modules::comment::comment_services::invalidate_comment_counts_service,
+};
+use actix_web::{web::Data, HttpResponse};
+
+/// Erases all personal data of the user (GDPR right to erasure) and flushes their Redis keys.
+///
+/// Besides the cached user (keyed by id and e-mail), every key ending in `:{user_id}` is removed,
+/// which covers the ETag, follow counts and rate limit entries. The cached comment counts are
+/// dropped too, since the comments of the user are deleted.
+pub async fn delete_all_user_data_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    user_id: String,
+) -> Result<(), HttpResponse> {
+    let email = match delete_all_user_data_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&user_id).unwrap_or_default(),
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let _ = Redis::delete(&redis_pool, &user_id).await;
+    let _ = Redis::delete(&redis_pool, &email).await;
+    for pattern in [
+        format!("user:{}*", user_id),
+        format!("rate:*:{}", user_id),
+        format!("*:{}", user_id),
+    ] {
+        let _ = Redis::delete_by_pattern(&redis_pool, &pattern).await;
+    }
+    invalidate_comment_counts_service(&redis_pool).await;
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod user_export_controllers;
+pub mod user_export_repositories;
+pub mod user_export_services;
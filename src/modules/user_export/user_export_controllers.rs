@@ -0,0 +1,110 @@
+use super::user_export_services::export_user_data_service;
+use crate::middlewares::auth_middleware::auth_middleware;
+use actix_web::{
+    get, http::header::CONTENT_DISPOSITION, web, HttpRequest, HttpResponse, Responder,
+};
+
+pub fn user_export_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(export_user_data);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/export",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Arquivo ZIP com um JSON por tipo de dado do usuário (OK)",
+        content_type = "application/zip",
+        headers(("Content-Disposition" = String, description = "attachment; filename=\"export_{user_id}.zip\""))
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Usuário não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "not found",
+                "message": "Não foi encontrado um usuário com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 429, description = "Exportação já realizada nas últimas 24 horas (Too Many Requests)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user": [{
+                "code": "too many requests",
+                "message": "Você já exportou seus dados nas últimas 24 horas.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/export")]
+async fn export_user_data(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let user_id = user_id.into_inner();
+    let archive = match export_user_data_service(pg_pool, redis_pool, user_id.clone()).await {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+
+    HttpResponse::Ok()
+        .content_type("application/zip")
+        .insert_header((
+            CONTENT_DISPOSITION,
+            format!("attachment; filename=\"export_{}.zip\"", user_id),
+        ))
+        .body(archive)
+}
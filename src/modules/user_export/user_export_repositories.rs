@@ -0,0 +1,67 @@
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+
+const EXPORT_USER_DATA_SQL: &str = "
+    SELECT
+        (SELECT row_to_json(u)::TEXT FROM (
+            SELECT id, name, email, created_at, updated_at, banned_at FROM users WHERE id = $1
+        ) u) AS profile,
+        (SELECT COALESCE(json_agg(p ORDER BY p.created_at), '[]')::TEXT FROM (
+            SELECT id, slug, title, subtitle, body, created_at, updated_at, published_at,
+                sponsored_by, sponsor_url
+            FROM posts WHERE author_id = $1
+        ) p) AS posts,
+        (SELECT COALESCE(json_agg(d ORDER BY d.saved_at), '[]')::TEXT FROM (
+            SELECT id, post_id, title, body, saved_at FROM drafts WHERE author_id = $1
+        ) d) AS drafts,
+        (SELECT COALESCE(json_agg(c ORDER BY c.created_at), '[]')::TEXT FROM (
+            SELECT id, post_id, parent_comment_id, body, created_at, updated_at
+            FROM comments WHERE user_id = $1
+        ) c) AS comments,
+        (SELECT COALESCE(json_agg(f ORDER BY f.created_at), '[]')::TEXT FROM (
+            SELECT followee_id, created_at FROM user_follows WHERE follower_id = $1
+        ) f) AS following,
+        (SELECT COALESCE(json_agg(v ORDER BY v.viewed_at), '[]')::TEXT FROM (
+            SELECT post_id, viewed_at FROM post_views WHERE user_id = $1
+        ) v) AS read_history
+";
+
+/// Entity types of the export, each one becoming a `{name}.json` file.
+pub const EXPORT_FILES: [&str; 6] = [
+    "profile",
+    "posts",
+    "drafts",
+    "comments",
+    "following",
+    "read_history",
+];
+
+pub async fn export_user_data_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<Vec<(String, String)>, HttpResponse> {
+    let rows = match query_executor(pg_pool, EXPORT_USER_DATA_SQL, &[&user_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    let profile: Option<String> = rows[0].get("profile");
+    if profile.is_none() {
+        return Err(HttpResponse::NotFound().json(error_construct(
+            String::from("user"),
+            String::from("not found"),
+            String::from("Não foi encontrado um usuário com este id."),
+            Some(user_id.to_string()),
+            None,
+            None,
+        )));
+    }
+
+    Ok(EXPORT_FILES
+        .iter()
+        .map(|name| {
+            let data: Option<String> = rows[0].get(name);
+            (format!("{}.json", name), data.unwrap_or_default())
+        })
+        .collect())
+}
@@ -0,0 +1,93 @@
+use super::user_export_repositories::export_user_data_repository;
+use crate::{infra::redis::Redis, utils::error_construct::error_construct};
+use actix_web::{web::Data, HttpResponse};
+use std::io::{Cursor, Write};
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+const EXPORT_RATE_EXPIRATION_SECONDS: u64 = 86400;
+
+fn export_rate_key(user_id: &str) -> String {
+    format!("export:rate:{}", user_id)
+}
+
+/// Packs the `(file name, content)` pairs into a ZIP archive.
+pub fn export_zip(files: &[(String, String)]) -> Result<Vec<u8>, HttpResponse> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in files {
+        let written = zip
+            .start_file(name.as_str(), SimpleFileOptions::default())
+            .and_then(|_| zip.write_all(content.as_bytes()).map_err(Into::into));
+        if written.is_err() {
+            return Err(export_error());
+        }
+    }
+    match zip.finish() {
+        Ok(cursor) => Ok(cursor.into_inner()),
+        Err(_) => Err(export_error()),
+    }
+}
+
+fn export_error() -> HttpResponse {
+    HttpResponse::InternalServerError().json(error_construct(
+        String::from("server"),
+        String::from("internal server error"),
+        String::from("Erro ao gerar o arquivo de exportação. Tente novamente mais tarde."),
+        None,
+        None,
+        None,
+    ))
+}
+
+/// Collects every data of the user into a ZIP archive with one JSON file per entity type.
+///
+/// Only one export per user is allowed every 24 hours; a failed export releases the limit.
+pub async fn export_user_data_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    user_id: String,
+) -> Result<Vec<u8>, HttpResponse> {
+    let key = export_rate_key(&user_id);
+    match Redis::set_nx_ex(&redis_pool, &key, "1", EXPORT_RATE_EXPIRATION_SECONDS).await {
+        Ok(true) => (),
+        Ok(false) => {
+            return Err(HttpResponse::TooManyRequests().json(error_construct(
+                String::from("user"),
+                String::from("too many requests"),
+                String::from("Você já exportou seus dados nas últimas 24 horas."),
+                Some(user_id),
+                None,
+                None,
+            )))
+        }
+        Err(e) => {
+            return Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                String::from("redis"),
+                String::from("service unavailable"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            )))
+        }
+    };
+
+    let files = match export_user_data_repository(
+        pg_pool,
+        uuid::Uuid::parse_str(&user_id).unwrap_or_default(),
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => {
+            let _ = Redis::delete(&redis_pool, &key).await;
+            return Err(e);
+        }
+    };
+    match export_zip(&files) {
+        Ok(x) => Ok(x),
+        Err(e) => {
+            let _ = Redis::delete(&redis_pool, &key).await;
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod user_onboarding_controllers;
+pub mod user_onboarding_dtos;
+pub mod user_onboarding_repositories;
+pub mod user_onboarding_services;
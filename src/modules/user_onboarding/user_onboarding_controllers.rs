@@ -0,0 +1,152 @@
+use super::user_onboarding_services::{
+    detail_user_onboarding_service, skip_user_onboarding_service,
+};
+use crate::middlewares::auth_middleware::auth_middleware;
+use actix_web::{get, post, web, HttpRequest, HttpResponse, Responder};
+
+pub fn user_onboarding_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(detail_user_onboarding)
+        .service(skip_user_onboarding);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/onboarding",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Checklist de primeiros passos do usuário: e-mail verificado, foto de perfil, primeiro post publicado e biografia (OK)",
+        body = UserOnboardingDTO, content_type = "application/json", example = json ! ({
+            "emailVerified": false,
+            "profilePictureSet": false,
+            "firstPostPublished": true,
+            "biographySet": false,
+            "skipped": false,
+            "onboardingComplete": false
+        })
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/onboarding")]
+async fn detail_user_onboarding(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match detail_user_onboarding_service(pg_pool, user_id.into_inner()).await {
+        Ok(onboarding) => HttpResponse::Ok().json(onboarding),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/onboarding/skip",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Checklist de primeiros passos dispensado; os passos já concluídos são mantidos (OK)",
+        body = UserOnboardingDTO, content_type = "application/json", example = json ! ({
+            "emailVerified": false,
+            "profilePictureSet": false,
+            "firstPostPublished": true,
+            "biographySet": false,
+            "skipped": true,
+            "onboardingComplete": false
+        })
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("{user_id}/onboarding/skip")]
+async fn skip_user_onboarding(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match skip_user_onboarding_service(pg_pool, user_id.into_inner()).await {
+        Ok(onboarding) => HttpResponse::Ok().json(onboarding),
+        Err(e) => e,
+    }
+}
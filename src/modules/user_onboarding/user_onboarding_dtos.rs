@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOnboardingDTO {
+    pub email_verified: bool,
+    pub profile_picture_set: bool,
+    pub first_post_published: bool,
+    pub biography_set: bool,
+    /// Whether the user dismissed the checklist.
+    pub skipped: bool,
+    /// Whether the four steps are done.
+    pub onboarding_complete: bool,
+}
+
+/// A step of the onboarding checklist, marked as done when the user performs its action.
+#[derive(Clone, Copy, Debug)]
+pub enum OnboardingStep {
+    EmailVerified,
+    ProfilePictureSet,
+    FirstPostPublished,
+    BiographySet,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 4] = [
+        OnboardingStep::EmailVerified,
+        OnboardingStep::ProfilePictureSet,
+        OnboardingStep::FirstPostPublished,
+        OnboardingStep::BiographySet,
+    ];
+
+    pub fn column(&self) -> &'static str {
+        match self {
+            OnboardingStep::EmailVerified => "email_verified",
+            OnboardingStep::ProfilePictureSet => "profile_picture_set",
+            OnboardingStep::FirstPostPublished => "first_post_published",
+            OnboardingStep::BiographySet => "biography_set",
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use super::user_onboarding_dtos::{OnboardingStep, UserOnboardingDTO};
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use tokio_postgres::Row;
+
+const DETAIL_USER_ONBOARDING_SQL: &str = "SELECT * FROM user_onboarding WHERE user_id = $1";
+
+const SKIP_USER_ONBOARDING_SQL: &str = "
+    INSERT INTO user_onboarding (user_id, skipped_at, updated_at) VALUES ($1, NOW(), NOW())
+    ON CONFLICT (user_id) DO UPDATE
+        SET skipped_at = COALESCE(user_onboarding.skipped_at, NOW()), updated_at = NOW()
+    RETURNING *
+";
+
+fn user_onboarding_dto_constructor(row: &Row) -> UserOnboardingDTO {
+    let skipped_at: Option<chrono::DateTime<chrono::Utc>> = row.get("skipped_at");
+    UserOnboardingDTO {
+        email_verified: row.get("email_verified"),
+        profile_picture_set: row.get("profile_picture_set"),
+        first_post_published: row.get("first_post_published"),
+        biography_set: row.get("biography_set"),
+        skipped: skipped_at.is_some(),
+        onboarding_complete: OnboardingStep::ALL
+            .iter()
+            .all(|step| row.get::<_, bool>(step.column())),
+    }
+}
+
+/// Returns the onboarding checklist of the user, with no step done if they never started it.
+pub async fn detail_user_onboarding_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<UserOnboardingDTO, HttpResponse> {
+    match query_executor(pg_pool, DETAIL_USER_ONBOARDING_SQL, &[&user_id]).await {
+        Ok(rows) => Ok(rows
+            .first()
+            .map(user_onboarding_dto_constructor)
+            .unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Marks the step of the onboarding checklist of the user as done.
+pub async fn complete_onboarding_step_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    step: OnboardingStep,
+) -> Result<UserOnboardingDTO, HttpResponse> {
+    let sql = format!(
+        "
+        INSERT INTO user_onboarding (user_id, {0}, updated_at) VALUES ($1, true, NOW())
+        ON CONFLICT (user_id) DO UPDATE SET {0} = true, updated_at = NOW()
+        RETURNING *
+        ",
+        step.column()
+    );
+    match query_executor(pg_pool, &sql, &[&user_id]).await {
+        Ok(rows) => Ok(user_onboarding_dto_constructor(&rows[0])),
+        Err(e) => Err(e),
+    }
+}
+
+/// Dismisses the onboarding checklist of the user, keeping the steps already done.
+pub async fn skip_user_onboarding_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<UserOnboardingDTO, HttpResponse> {
+    match query_executor(pg_pool, SKIP_USER_ONBOARDING_SQL, &[&user_id]).await {
+        Ok(rows) => Ok(user_onboarding_dto_constructor(&rows[0])),
+        Err(e) => Err(e),
+    }
+}
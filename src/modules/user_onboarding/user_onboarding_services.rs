@@ -0,0 +1,46 @@
+use super::{
+    user_onboarding_dtos::{OnboardingStep, UserOnboardingDTO},
+    user_onboarding_repositories::*,
+};
+use crate::middlewares::etag_middleware::etag_invalidate;
+use actix_web::{web::Data, HttpResponse};
+
+pub async fn detail_user_onboarding_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+) -> Result<UserOnboardingDTO, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    detail_user_onboarding_repository(pg_pool, user_id).await
+}
+
+pub async fn skip_user_onboarding_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: String,
+) -> Result<UserOnboardingDTO, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    skip_user_onboarding_repository(pg_pool, user_id).await
+}
+
+/// Marks the step of the onboarding checklist of the user as done, invalidating the ETag of the
+/// user when the checklist is complete so its detail reflects `onboardingComplete`.
+///
+/// A failure is only logged, never failing the action that completed the step.
+pub async fn complete_onboarding_step_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: &deadpool_redis::Pool,
+    user_id: uuid::Uuid,
+    step: OnboardingStep,
+) {
+    match complete_onboarding_step_repository(pg_pool, user_id, step).await {
+        Ok(onboarding) if onboarding.onboarding_complete => {
+            etag_invalidate(redis_pool, "user", &user_id.to_string()).await
+        }
+        Ok(_) => (),
+        Err(e) => log::warn!(
+            "Failed to complete the onboarding step {} of user {}: {}",
+            step.column(),
+            user_id,
+            e.status()
+        ),
+    };
+}
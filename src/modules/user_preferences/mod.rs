@@ -0,0 +1,4 @@
+pub mod user_preferences_controllers;
+pub mod user_preferences_dtos;
+pub mod user_preferences_repositories;
+pub mod user_preferences_services;
@@ -0,0 +1,169 @@
+use super::{
+    user_preferences_dtos::PatchUserPreferencesDTO,
+    user_preferences_services::{detail_user_preferences_service, patch_user_preferences_service},
+};
+use crate::middlewares::auth_middleware::auth_middleware;
+use actix_web::{get, patch, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn user_preferences_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(detail_user_preferences)
+        .service(patch_user_preferences);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/preferences",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Preferências do usuário; campos nunca alterados vêm com o valor padrão (OK)",
+        content_type = "application/json", example = json ! ({
+            "receiveCommentNotifications": true,
+            "receiveLikeNotifications": true,
+            "receiveNewsletter": false,
+            "language": "pt",
+            "theme": "dark",
+            "notificationOptOuts": ["like_on_post"],
+            "timezone": "America/Sao_Paulo"
+        })
+	), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/preferences")]
+async fn detail_user_preferences(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match detail_user_preferences_service(pg_pool, redis_pool, user_id.into_inner()).await {
+        Ok(preferences) => HttpResponse::Ok().json(preferences),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/preferences",
+    security(("bearer_auth" = [])),
+    request_body = PatchUserPreferencesDTO,
+    responses((
+        status = 200, description = "Preferências atualizadas; campos não enviados mantêm o valor atual (OK)",
+        content_type = "application/json", example = json ! ({
+            "receiveCommentNotifications": true,
+            "receiveLikeNotifications": true,
+            "receiveNewsletter": false,
+            "language": "pt",
+            "theme": "dark",
+            "notificationOptOuts": ["like_on_post"],
+            "timezone": "America/Sao_Paulo"
+        })
+	), (
+		status = 400, description = "Erro do usuário por id, idioma, tema ou fuso horário inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "language": [{
+                "code": "language",
+                "message": "O idioma deve ser pt, en ou es.",
+                "params": {
+                    "value": "fr"
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[patch("{user_id}/preferences")]
+async fn patch_user_preferences(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    body: web::Json<PatchUserPreferencesDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match patch_user_preferences_service(
+        pg_pool,
+        redis_pool,
+        user_id.into_inner(),
+        body.into_inner(),
+    )
+    .await
+    {
+        Ok(preferences) => HttpResponse::Ok().json(preferences),
+        Err(e) => e,
+    }
+}
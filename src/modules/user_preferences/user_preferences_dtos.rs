@@ -0,0 +1,130 @@
+use crate::modules::notification::notification_dtos::NOTIFICATION_TYPES;
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, str::FromStr};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+const LANGUAGES: [&str; 3] = ["pt", "en", "es"];
+const THEMES: [&str; 3] = ["light", "dark", "system"];
+
+fn language_valid(language: &str) -> Result<(), ValidationError> {
+    if LANGUAGES.contains(&language) {
+        return Ok(());
+    }
+    Err(ValidationError::new("language").with_message(Cow::from("O idioma deve ser pt, en ou es.")))
+}
+
+fn theme_valid(theme: &str) -> Result<(), ValidationError> {
+    if THEMES.contains(&theme) {
+        return Ok(());
+    }
+    Err(ValidationError::new("theme")
+        .with_message(Cow::from("O tema deve ser light, dark ou system.")))
+}
+
+fn timezone_valid(timezone: &str) -> Result<(), ValidationError> {
+    if Tz::from_str(timezone).is_ok() {
+        return Ok(());
+    }
+    Err(ValidationError::new("timezone").with_message(Cow::from(
+        "O fuso horário deve ser um nome IANA válido, como America/Sao_Paulo.",
+    )))
+}
+
+fn notification_opt_outs_valid(opt_outs: &[String]) -> Result<(), ValidationError> {
+    if opt_outs
+        .iter()
+        .all(|notification_type| NOTIFICATION_TYPES.contains(&notification_type.as_str()))
+    {
+        return Ok(());
+    }
+    Err(ValidationError::new("notification_opt_outs").with_message(Cow::from(
+        "Os tipos de notificação devem ser comment_on_post, reply_to_comment, like_on_post, mention, follow ou comment_approved.",
+    )))
+}
+
+/// The settings of a user, stored as JSONB. Fields missing from the stored document take their default value.
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UserPreferencesDTO {
+    pub receive_comment_notifications: bool,
+    pub receive_like_notifications: bool,
+    pub receive_newsletter: bool,
+    pub language: String,
+    pub theme: String,
+    /// The notification types the user does not want to receive, out of `NOTIFICATION_TYPES`.
+    pub notification_opt_outs: Vec<String>,
+    /// The IANA name of the time zone, kept in its own column instead of the JSONB document.
+    pub timezone: String,
+}
+
+impl Default for UserPreferencesDTO {
+    fn default() -> Self {
+        UserPreferencesDTO {
+            receive_comment_notifications: true,
+            receive_like_notifications: true,
+            receive_newsletter: false,
+            language: String::from("pt"),
+            theme: String::from("system"),
+            notification_opt_outs: Vec::new(),
+            timezone: String::from("UTC"),
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone, Default)]
+pub struct PatchUserPreferencesDTO {
+    #[serde(default, alias = "receiveCommentNotifications")]
+    pub receive_comment_notifications: Option<bool>,
+
+    #[serde(default, alias = "receiveLikeNotifications")]
+    pub receive_like_notifications: Option<bool>,
+
+    #[serde(default, alias = "receiveNewsletter")]
+    pub receive_newsletter: Option<bool>,
+
+    #[validate(custom(function = "language_valid"))]
+    #[serde(default)]
+    pub language: Option<String>,
+
+    #[validate(custom(function = "theme_valid"))]
+    #[serde(default)]
+    pub theme: Option<String>,
+
+    #[validate(custom(function = "timezone_valid"))]
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// Replaces the whole list of notification types the user opted out of.
+    #[validate(custom(function = "notification_opt_outs_valid"))]
+    #[serde(default, alias = "notificationOptOuts")]
+    pub notification_opt_outs: Option<Vec<String>>,
+}
+
+impl PatchUserPreferencesDTO {
+    /// Builds the JSONB document with only the supplied fields, keyed as in `UserPreferencesDTO`.
+    /// The time zone is left out, since it is stored in its own column.
+    pub fn to_patch(&self) -> serde_json::Value {
+        let mut patch = serde_json::Map::new();
+        if let Some(x) = self.receive_comment_notifications {
+            patch.insert("receiveCommentNotifications".into(), x.into());
+        }
+        if let Some(x) = self.receive_like_notifications {
+            patch.insert("receiveLikeNotifications".into(), x.into());
+        }
+        if let Some(x) = self.receive_newsletter {
+            patch.insert("receiveNewsletter".into(), x.into());
+        }
+        if let Some(x) = &self.language {
+            patch.insert("language".into(), x.clone().into());
+        }
+        if let Some(x) = &self.theme {
+            patch.insert("theme".into(), x.clone().into());
+        }
+        if let Some(x) = &self.notification_opt_outs {
+            patch.insert("notificationOptOuts".into(), x.clone().into());
+        }
+        serde_json::Value::Object(patch)
+    }
+}
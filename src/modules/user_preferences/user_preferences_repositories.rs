@@ -0,0 +1,58 @@
+use super::user_preferences_dtos::UserPreferencesDTO;
+use crate::utils::query_constructor_executor::query_executor;
+use actix_web::{web::Data, HttpResponse};
+use tokio_postgres::Row;
+
+const DETAIL_USER_PREFERENCES_SQL: &str =
+    "SELECT preferences::TEXT AS preferences, timezone FROM user_preferences WHERE user_id = $1";
+
+const PATCH_USER_PREFERENCES_SQL: &str = "
+    INSERT INTO user_preferences (user_id, preferences, timezone)
+    VALUES ($1, $2::TEXT::JSONB, COALESCE($3, 'UTC'))
+    ON CONFLICT (user_id) DO UPDATE
+        SET preferences = user_preferences.preferences || EXCLUDED.preferences,
+            timezone = COALESCE($3, user_preferences.timezone)
+    RETURNING preferences::TEXT AS preferences, timezone
+";
+
+fn user_preferences_dto_constructor(row: &Row) -> UserPreferencesDTO {
+    let preferences: String = row.get("preferences");
+    UserPreferencesDTO {
+        timezone: row.get("timezone"),
+        ..serde_json::from_str(&preferences).unwrap_or_default()
+    }
+}
+
+/// Returns the preferences of the user, or the defaults if they never changed any.
+pub async fn detail_user_preferences_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+) -> Result<UserPreferencesDTO, HttpResponse> {
+    match query_executor(pg_pool, DETAIL_USER_PREFERENCES_SQL, &[&user_id]).await {
+        Ok(rows) => Ok(rows
+            .first()
+            .map(user_preferences_dto_constructor)
+            .unwrap_or_default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Merges `patch` into the stored preferences, keeping the fields it does not contain, and
+/// replaces the time zone when one is given.
+pub async fn patch_user_preferences_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    patch: serde_json::Value,
+    timezone: Option<String>,
+) -> Result<UserPreferencesDTO, HttpResponse> {
+    match query_executor(
+        pg_pool,
+        PATCH_USER_PREFERENCES_SQL,
+        &[&user_id, &patch.to_string(), &timezone],
+    )
+    .await
+    {
+        Ok(rows) => Ok(user_preferences_dto_constructor(&rows[0])),
+        Err(e) => Err(e),
+    }
+}
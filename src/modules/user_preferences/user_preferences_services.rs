@@ -0,0 +1,87 @@
+use super::{
+    user_preferences_dtos::{PatchUserPreferencesDTO, UserPreferencesDTO},
+    user_preferences_repositories::*,
+};
+use crate::{infra::redis::Redis, middlewares::etag_middleware::etag_invalidate};
+use actix_web::{web::Data, HttpResponse};
+use chrono_tz::Tz;
+use std::str::FromStr;
+
+const USER_PREFERENCES_TTL_SECONDS: u64 = 86400;
+
+fn user_preferences_key(user_id: uuid::Uuid) -> String {
+    format!("preferences:{}", user_id)
+}
+
+async fn cache_user_preferences(
+    redis_pool: &deadpool_redis::Pool,
+    user_id: uuid::Uuid,
+    preferences: &UserPreferencesDTO,
+) {
+    if let Err(e) = Redis::set_ex(
+        redis_pool,
+        &user_preferences_key(user_id),
+        &serde_json::to_string(preferences).unwrap_or_default(),
+        USER_PREFERENCES_TTL_SECONDS,
+    )
+    .await
+    {
+        log::warn!("Failed to cache the preferences of user {}: {}", user_id, e);
+    }
+}
+
+pub async fn detail_user_preferences_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    user_id: String,
+) -> Result<UserPreferencesDTO, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    if let Ok(cached) = Redis::get(&redis_pool, &user_preferences_key(user_id)).await {
+        if let Ok(preferences) = serde_json::from_str::<UserPreferencesDTO>(&cached) {
+            return Ok(preferences);
+        }
+    }
+
+    let preferences = match detail_user_preferences_repository(pg_pool, user_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    cache_user_preferences(&redis_pool, user_id, &preferences).await;
+    Ok(preferences)
+}
+
+/// Updates only the supplied fields of the preferences and refreshes the Redis cache. A new time
+/// zone also invalidates the ETag of the user, whose detail shows `created_at` in it.
+pub async fn patch_user_preferences_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    user_id: String,
+    body: PatchUserPreferencesDTO,
+) -> Result<UserPreferencesDTO, HttpResponse> {
+    let user_id = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let preferences = match patch_user_preferences_repository(
+        pg_pool,
+        user_id,
+        body.to_patch(),
+        body.timezone.clone(),
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    cache_user_preferences(&redis_pool, user_id, &preferences).await;
+    if body.timezone.is_some() {
+        etag_invalidate(&redis_pool, "user", &user_id.to_string()).await;
+    }
+    Ok(preferences)
+}
+
+/// Converts a UTC timestamp, as stored in `UserDTO::created_at`, to RFC 3339 in `timezone`.
+///
+/// Returns `None` when the timestamp or the time zone can not be parsed.
+pub fn local_time(utc: &str, timezone: &str) -> Option<String> {
+    let timezone = Tz::from_str(timezone).ok()?;
+    let naive = chrono::NaiveDateTime::parse_from_str(utc, "%Y-%m-%d %H:%M:%S%.f UTC").ok()?;
+    Some(naive.and_utc().with_timezone(&timezone).to_rfc3339())
+}
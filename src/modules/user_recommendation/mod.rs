@@ -0,0 +1,3 @@
+pub mod user_recommendation_controllers;
+pub mod user_recommendation_repositories;
+pub mod user_recommendation_services;
@@ -0,0 +1,72 @@
+use super::user_recommendation_services::user_recommendations_service;
+use crate::middlewares::auth_middleware::auth_middleware;
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder};
+
+pub fn user_recommendation_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(user_recommendations);
+}
+
+#[utoipa::path(
+    tag = "user",
+    path = "/user/{user_id}/recommendations",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Até 10 posts ainda não lidos dos usuários com curtidas mais parecidas com as do usuário (OK)",
+        body = Vec<PostDTO>
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "user_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "O token informado não pertence ao usuário.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[get("{user_id}/recommendations")]
+async fn user_recommendations(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    redis_pool: web::Data<deadpool_redis::Pool>,
+    user_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match auth_middleware(user_id.clone(), req, "user_id").await {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match user_recommendations_service(pg_pool, redis_pool, user_id.into_inner()).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    modules::post::{post_dto::PostDTO, post_repositories::post_dto_constructor},
+    utils::query_constructor_executor::query_executor,
+};
+use actix_web::{web::Data, HttpResponse};
+
+const USER_RECOMMENDATIONS_SQL: &str = "
+    WITH user_likes AS (
+        SELECT post_id FROM users_posts_likes WHERE user_id = $1
+    ), similar_users AS (
+        SELECT l.user_id,
+            COUNT(*)::FLOAT8 / (
+                (SELECT COUNT(*) FROM user_likes)
+                + (SELECT COUNT(*) FROM users_posts_likes o WHERE o.user_id = l.user_id)
+                - COUNT(*)
+            ) AS similarity
+        FROM users_posts_likes l
+        JOIN user_likes u ON u.post_id = l.post_id
+        WHERE l.user_id <> $1
+        GROUP BY l.user_id
+        ORDER BY similarity DESC, l.user_id
+        LIMIT 5
+    )
+    SELECT p.id, p.slug, p.title, p.subtitle, NULL::TEXT AS body, p.summary, p.author_id, p.created_at,
+        p.published_at, p.sponsored_by, p.sponsor_url, p.version, p.language, p.canonical_url,
+        p.word_count, p.character_count, p.scheduled_at, p.cover_image_url,
+        p.cover_image_blurhash, p.code_languages
+    FROM posts p
+    JOIN similar_users s ON s.user_id = p.author_id
+    WHERE p.published_at IS NOT NULL AND p.published_at <= NOW() AND p.deleted_at IS NULL
+        AND p.tenant_id = (SELECT tenant_id FROM users WHERE id = $1)
+        AND NOT EXISTS (SELECT 1 FROM post_views v WHERE v.user_id = $1 AND v.post_id = p.id)
+        AND NOT EXISTS (SELECT 1 FROM user_likes u WHERE u.post_id = p.id)
+    ORDER BY p.published_at DESC, p.id DESC
+    LIMIT $2
+";
+
+/// Recommends the latest posts, not yet read by the user, of the 5 users whose likes most overlap
+/// with theirs (by Jaccard similarity of the liked posts).
+pub async fn user_recommendations_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    user_id: uuid::Uuid,
+    limit: i64,
+) -> Result<Vec<PostDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, USER_RECOMMENDATIONS_SQL, &[&user_id, &limit]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.iter().map(post_dto_constructor).collect())
+}
@@ -0,0 +1,39 @@
+use super::user_recommendation_repositories::user_recommendations_repository;
+use crate::{infra::redis::Redis, modules::post::post_dto::PostDTO};
+use actix_web::{web::Data, HttpResponse};
+
+const USER_RECOMMENDATIONS_LIMIT: i64 = 10;
+const USER_RECOMMENDATIONS_EXPIRATION_SECONDS: u64 = 600;
+
+pub fn user_recommendations_key(user_id: &str) -> String {
+    format!("user:recs:{}", user_id)
+}
+
+pub async fn user_recommendations_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    redis_pool: Data<deadpool_redis::Pool>,
+    user_id: String,
+) -> Result<Vec<PostDTO>, HttpResponse> {
+    let key = user_recommendations_key(&user_id);
+    if let Ok(cached) = Redis::get(&redis_pool, &key).await {
+        if let Ok(posts) = serde_json::from_str(&cached) {
+            return Ok(posts);
+        }
+    }
+
+    let user_uuid = uuid::Uuid::parse_str(&user_id).unwrap_or_default();
+    let posts =
+        match user_recommendations_repository(pg_pool, user_uuid, USER_RECOMMENDATIONS_LIMIT).await
+        {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+    let _ = Redis::set_ex(
+        &redis_pool,
+        &key,
+        &serde_json::to_string(&posts).unwrap_or_default(),
+        USER_RECOMMENDATIONS_EXPIRATION_SECONDS,
+    )
+    .await;
+    Ok(posts)
+}
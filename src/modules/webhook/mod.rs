@@ -0,0 +1,4 @@
+pub mod webhook_controllers;
+pub mod webhook_dtos;
+pub mod webhook_repositories;
+pub mod webhook_services;
@@ -0,0 +1,191 @@
+use super::{
+    webhook_dtos::InsertWebhookDTO,
+    webhook_services::{insert_webhook_service, test_webhook_service},
+};
+use crate::middlewares::{
+    admin_middleware::admin_middleware, uuid_path_middleware::uuid_path_middleware,
+};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use validator::Validate;
+
+pub fn webhook_controllers_module() -> actix_web::Scope {
+    web::scope("/webhook").service(test_webhook)
+}
+
+pub fn webhook_admin_controllers_module(cfg: &mut web::ServiceConfig) {
+    cfg.service(insert_webhook);
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/admin/webhook",
+    security(("bearer_auth" = [])),
+    request_body = InsertWebhookDTO,
+    responses((
+        status = 201, description = "Webhook cadastrado; o segredo de assinatura só é exibido nesta resposta (Created)",
+        body = WebhookDTO
+    ), (
+		status = 400, description = "Erro do usuário por URL ou eventos inválidos (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "events": [{
+                "code": "events",
+                "message": "Informe ao menos um evento válido: post.published.",
+                "params": {
+                    "value": ["post.updated"]
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/webhook")]
+async fn insert_webhook(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    body: web::Json<InsertWebhookDTO>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    match body.validate() {
+        Ok(_) => (),
+        Err(e) => return HttpResponse::BadRequest().json(e),
+    };
+    match insert_webhook_service(pg_pool, body.into_inner()).await {
+        Ok(webhook) => HttpResponse::Created().json(webhook),
+        Err(e) => e,
+    }
+}
+
+#[utoipa::path(
+    tag = "admin",
+    path = "/webhook/{webhook_id}/test",
+    security(("bearer_auth" = [])),
+    responses((
+        status = 200, description = "Resultado da entrega do evento de teste (OK)",
+        body = WebhookDeliveryDTO
+    ), (
+		status = 400, description = "Erro do usuário por id inválido (Bad Request)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "webhook_id": [{
+                "code": "bad request",
+                "message": "Por favor, envie um valor de UUID válido na URL da requisição.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 401, description = "Credenciais de autenticação inválidas (Unauthorized)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "unauthorized",
+                "message": "Acesso negado por token de autorização.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 403, description = "Acesso restrito a administradores (Forbidden)",
+		body = ErrorStruct, content_type = "application/json", example = json ! ({
+            "bearer token": [{
+                "code": "forbidden",
+                "message": "Acesso restrito a administradores.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 404, description = "Webhook não encontrado (Not Found)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "webhook": [{
+                "code": "not found",
+                "message": "Não foi encontrado um webhook com este id.",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null
+                }
+		    }]
+        })
+	), (
+		status = 503, description = "Serviço Indisponível (Service Unavailable)", body = ErrorStruct,
+		content_type = "application/json", example = json ! ({
+            "database": [{
+                "code": "service unavailable",
+                "message": "Error occurred while creating a new object: db error: FATAL: password authentication failed for user \"postgres\"",
+                "params": {
+                    "min": null,
+                    "value": null,
+                    "max": null,
+                }
+		    }]
+        })
+	))
+)]
+#[post("/{webhook_id}/test")]
+async fn test_webhook(
+    pg_pool: web::Data<deadpool_postgres::Pool>,
+    webhook_id: web::Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    match admin_middleware(req.headers()) {
+        Ok(_) => (),
+        Err(e) => return e,
+    };
+    let webhook_id = match uuid_path_middleware(webhook_id.into_inner(), "webhook_id") {
+        Ok(webhook_id) => webhook_id,
+        Err(e) => return e,
+    };
+    match test_webhook_service(pg_pool, webhook_id).await {
+        Ok(delivery) => HttpResponse::Ok().json(delivery),
+        Err(e) => e,
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+pub const POST_PUBLISHED_EVENT: &str = "post.published";
+pub const WEBHOOK_TEST_EVENT: &str = "webhook.test";
+const WEBHOOK_EVENTS: [&str; 1] = [POST_PUBLISHED_EVENT];
+
+fn webhook_events_valid(events: &[String]) -> Result<(), ValidationError> {
+    if !events.is_empty()
+        && events
+            .iter()
+            .all(|event| WEBHOOK_EVENTS.contains(&event.as_str()))
+    {
+        return Ok(());
+    }
+    Err(ValidationError::new("events").with_message(Cow::from(
+        "Informe ao menos um evento válido: post.published.",
+    )))
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Validate, Clone)]
+pub struct InsertWebhookDTO {
+    #[validate(url(message = "A URL do webhook deve ser um endereço válido."))]
+    pub url: String,
+
+    #[validate(custom(function = "webhook_events_valid"))]
+    pub events: Vec<String>,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDTO {
+    pub id: String,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub created_at: String,
+}
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveryDTO {
+    pub delivered: bool,
+    pub attempts: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,121 @@
+use super::webhook_dtos::{WebhookDTO, WebhookDeliveryDTO};
+use crate::utils::{error_construct::error_construct, query_constructor_executor::query_executor};
+use actix_web::{web::Data, HttpResponse};
+use chrono::{DateTime, Utc};
+
+const INSERT_WEBHOOK_SQL: &str = "
+    INSERT INTO webhook_subscriptions (id, url, secret, events, active, created_at)
+    VALUES ($1, $2, $3, $4, TRUE, NOW())
+    RETURNING id, url, secret, events, active, created_at
+";
+
+const DETAIL_WEBHOOK_SQL: &str = "
+    SELECT id, url, secret, events, active, created_at FROM webhook_subscriptions WHERE id = $1
+";
+
+const ACTIVE_WEBHOOKS_BY_EVENT_SQL: &str = "
+    SELECT id, url, secret, events, active, created_at FROM webhook_subscriptions
+    WHERE active = TRUE AND $1 = ANY(events)
+";
+
+const INSERT_DELIVERY_LOG_SQL: &str = "
+    INSERT INTO webhook_delivery_logs
+        (id, subscription_id, event, payload, status_code, error, attempts, created_at)
+    VALUES ($1, $2, $3, $4::TEXT::JSONB, $5, $6, $7, NOW())
+";
+
+fn webhook_dto_constructor(row: &postgres::Row) -> WebhookDTO {
+    let id: uuid::Uuid = row.get("id");
+    let created_at: DateTime<Utc> = row.get("created_at");
+
+    WebhookDTO {
+        id: id.to_string(),
+        url: row.get("url"),
+        secret: row.get("secret"),
+        events: row.get("events"),
+        active: row.get("active"),
+        created_at: created_at.to_string(),
+    }
+}
+
+pub async fn insert_webhook_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    url: String,
+    secret: String,
+    events: Vec<String>,
+) -> Result<WebhookDTO, HttpResponse> {
+    let rows = match query_executor(
+        pg_pool,
+        INSERT_WEBHOOK_SQL,
+        &[&uuid::Uuid::new_v4(), &url, &secret, &events],
+    )
+    .await
+    {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(webhook_dto_constructor(&rows[0]))
+}
+
+pub async fn detail_webhook_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    webhook_id: uuid::Uuid,
+) -> Result<WebhookDTO, HttpResponse> {
+    let rows = match query_executor(pg_pool, DETAIL_WEBHOOK_SQL, &[&webhook_id]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    match rows.first() {
+        Some(row) => Ok(webhook_dto_constructor(row)),
+        None => Err(HttpResponse::NotFound().json(error_construct(
+            String::from("webhook"),
+            String::from("not found"),
+            String::from("Não foi encontrado um webhook com este id."),
+            Some(webhook_id.to_string()),
+            None,
+            None,
+        ))),
+    }
+}
+
+pub async fn active_webhooks_by_event_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    event: &str,
+) -> Result<Vec<WebhookDTO>, HttpResponse> {
+    let rows = match query_executor(pg_pool, ACTIVE_WEBHOOKS_BY_EVENT_SQL, &[&event]).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(rows.iter().map(webhook_dto_constructor).collect())
+}
+
+pub async fn insert_delivery_log_repository(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    subscription_id: &str,
+    event: &str,
+    payload: &str,
+    delivery: &WebhookDeliveryDTO,
+) -> Result<(), HttpResponse> {
+    let subscription_id = uuid::Uuid::parse_str(subscription_id).unwrap_or_default();
+    match query_executor(
+        pg_pool,
+        INSERT_DELIVERY_LOG_SQL,
+        &[
+            &uuid::Uuid::new_v4(),
+            &subscription_id,
+            &event,
+            &payload,
+            &delivery.status_code,
+            &delivery.error,
+            &delivery.attempts,
+        ],
+    )
+    .await
+    {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,155 @@
+use super::{
+    webhook_dtos::{InsertWebhookDTO, WebhookDTO, WebhookDeliveryDTO, WEBHOOK_TEST_EVENT},
+    webhook_repositories::{
+        active_webhooks_by_event_repository, detail_webhook_repository,
+        insert_delivery_log_repository, insert_webhook_repository,
+    },
+};
+use actix_web::{web::Data, HttpResponse};
+use futures_util::future::join_all;
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use sha2::Sha256;
+use std::time::Duration;
+
+pub const SIGNATURE_HEADER: &str = "X-Signature-256";
+const MAX_RETRIES: i32 = 3;
+const RETRY_BASE_DELAY_MS: u64 = 200;
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap()
+});
+
+/// Signs `body` with HMAC-SHA256, in the `sha256=<hex>` format sent in `X-Signature-256`.
+pub fn webhook_signature(secret: &str, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+pub async fn insert_webhook_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    body: InsertWebhookDTO,
+) -> Result<WebhookDTO, HttpResponse> {
+    let secret = format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    );
+    insert_webhook_repository(pg_pool, body.url, secret, body.events).await
+}
+
+/// Sends a signed payload to a subscription, retrying up to 3 times with exponential backoff.
+///
+/// Any 2xx response counts as delivered. When every attempt fails, the delivery is stored in
+/// `webhook_delivery_logs`.
+pub async fn deliver_webhook_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    webhook: &WebhookDTO,
+    event: &str,
+    data: serde_json::Value,
+) -> WebhookDeliveryDTO {
+    let payload = serde_json::json!({
+        "event": event,
+        "data": data,
+        "sentAt": chrono::Utc::now().to_rfc3339(),
+    })
+    .to_string();
+    let signature = webhook_signature(&webhook.secret, &payload);
+
+    let mut delivery = WebhookDeliveryDTO {
+        delivered: false,
+        attempts: 0,
+        status_code: None,
+        error: None,
+    };
+    while delivery.attempts <= MAX_RETRIES {
+        if delivery.attempts > 0 {
+            let delay = RETRY_BASE_DELAY_MS * 2u64.pow(delivery.attempts as u32 - 1);
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+        }
+        delivery.attempts += 1;
+
+        match HTTP_CLIENT
+            .post(&webhook.url)
+            .header("Content-Type", "application/json")
+            .header(SIGNATURE_HEADER, &signature)
+            .body(payload.clone())
+            .send()
+            .await
+        {
+            Ok(response) => {
+                delivery.status_code = Some(response.status().as_u16() as i32);
+                if response.status().is_success() {
+                    delivery.delivered = true;
+                    delivery.error = None;
+                    return delivery;
+                }
+                delivery.error = Some(format!("HTTP {}", response.status()));
+            }
+            Err(e) => {
+                delivery.status_code = None;
+                delivery.error = Some(e.to_string());
+            }
+        }
+    }
+
+    log::warn!(
+        "Webhook {} failed to deliver {} after {} attempts: {}",
+        webhook.id,
+        event,
+        delivery.attempts,
+        delivery.error.clone().unwrap_or_default()
+    );
+    if let Err(e) =
+        insert_delivery_log_repository(pg_pool, &webhook.id, event, &payload, &delivery).await
+    {
+        log::warn!(
+            "Failed to store the delivery log of webhook {}: {:?}",
+            webhook.id,
+            e.status()
+        );
+    }
+    delivery
+}
+
+/// Delivers `event` to every active subscription listening to it.
+pub async fn fire_webhook_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    event: &str,
+    data: serde_json::Value,
+) -> Result<Vec<WebhookDeliveryDTO>, HttpResponse> {
+    let webhooks = match active_webhooks_by_event_repository(pg_pool.clone(), event).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(join_all(
+        webhooks
+            .iter()
+            .map(|webhook| deliver_webhook_service(pg_pool.clone(), webhook, event, data.clone())),
+    )
+    .await)
+}
+
+pub async fn test_webhook_service(
+    pg_pool: Data<deadpool_postgres::Pool>,
+    webhook_id: String,
+) -> Result<WebhookDeliveryDTO, HttpResponse> {
+    let webhook_id = uuid::Uuid::parse_str(&webhook_id).unwrap_or_default();
+    let webhook = match detail_webhook_repository(pg_pool.clone(), webhook_id).await {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+
+    Ok(deliver_webhook_service(
+        pg_pool,
+        &webhook,
+        WEBHOOK_TEST_EVENT,
+        serde_json::json!({ "webhookId": webhook.id }),
+    )
+    .await)
+}
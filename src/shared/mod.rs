@@ -1,3 +1,4 @@
 pub mod exceptions;
+pub mod parsers;
 pub mod structs;
 pub mod treaties;
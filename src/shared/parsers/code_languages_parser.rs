@@ -0,0 +1,30 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static RE_CODE_FENCE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^[ \t]{0,3}(?:```|~~~)[ \t]*([A-Za-z0-9_+#.-]+)").unwrap());
+
+/// Extracts the languages of the fenced code blocks of a Markdown body, used as syntax
+/// highlighting hints.
+///
+/// Languages are lowercased and deduplicated, in the order they first appear. Fences without a
+/// language, including the closing ones, are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::code_languages_parser::extract_code_languages;
+///
+/// let body = "```rust\nfn main() {}\n```\n\n```Python\nprint()\n```\n\n```rust\nlet x = 1;\n```";
+/// assert_eq!(extract_code_languages(body), vec!["rust", "python"]);
+/// ```
+pub fn extract_code_languages(body: &str) -> Vec<String> {
+    let mut languages: Vec<String> = Vec::new();
+    for captures in RE_CODE_FENCE.captures_iter(body) {
+        let language = captures[1].to_lowercase();
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+    languages
+}
@@ -0,0 +1,19 @@
+use sha2::{Digest, Sha256};
+
+/// Hashes the title and body of a post to detect duplicates, matching the `content_hash` column
+/// generated by Postgres as `encode(sha256(title || body), 'hex')`.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::content_hash_parser::content_hash;
+///
+/// assert_eq!(content_hash("Ol", "á"), content_hash("Olá", ""));
+/// assert_eq!(content_hash("Olá", "").len(), 64);
+/// ```
+pub fn content_hash(title: &str, body: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(title.as_bytes());
+    hasher.update(body.as_bytes());
+    hex::encode(hasher.finalize())
+}
@@ -0,0 +1,29 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static RE_MENTION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:^|[^a-zA-Z0-9_@])@([a-zA-Z0-9_]+)").unwrap());
+
+/// Extracts the `@username` mentions of a text.
+///
+/// Mentions preceded by a word character (like in e-mail addresses) are ignored and repeated
+/// mentions (compared case-insensitively) are returned only once, as they first appear.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::mentions_parser::extract_mentions;
+///
+/// let mentions = extract_mentions("Olá @navarro e @bush1d3v, @navarro!");
+/// assert_eq!(mentions, vec!["navarro", "bush1d3v"]);
+/// ```
+pub fn extract_mentions(body: &str) -> Vec<String> {
+    let mut mentions: Vec<String> = Vec::new();
+    for capture in RE_MENTION.captures_iter(body) {
+        let mention = capture[1].to_string();
+        if !mentions.iter().any(|m| m.eq_ignore_ascii_case(&mention)) {
+            mentions.push(mention);
+        }
+    }
+    mentions
+}
@@ -0,0 +1,8 @@
+pub mod code_languages_parser;
+pub mod content_hash_parser;
+pub mod mentions_parser;
+pub mod preview_parser;
+pub mod slug_parser;
+pub mod summary_parser;
+pub mod text_counts_parser;
+pub mod toc_parser;
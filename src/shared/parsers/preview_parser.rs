@@ -0,0 +1,23 @@
+/// Default length, in characters, of the previews built by [`truncated_preview`].
+pub const PREVIEW_MAX_CHARS: usize = 80;
+
+/// Builds a short excerpt of a text, as shown in notifications.
+///
+/// The text is trimmed and its first `max_chars` characters (not bytes) are kept, followed by `…`
+/// when the text is longer than that.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::preview_parser::truncated_preview;
+///
+/// assert_eq!(truncated_preview("  Ótimo post!  ", 80), "Ótimo post!");
+/// assert_eq!(truncated_preview("Ótimo post!", 5), "Ótimo…");
+/// ```
+pub fn truncated_preview(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}…", text[..cut].trim_end()),
+        None => String::from(text),
+    }
+}
@@ -0,0 +1,33 @@
+/// Builds a URL slug from a text.
+///
+/// Letters are lowercased and stripped of Portuguese accents, and every run of other characters
+/// becomes a single `-`.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::slug_parser::slugify;
+///
+/// assert_eq!(slugify("Rust & Actix Web: APIs Performáticas!"), "rust-actix-web-apis-performaticas");
+/// ```
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.to_lowercase().chars() {
+        let c = match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            c => c,
+        };
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
@@ -0,0 +1,27 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+const SUMMARY_WORDS: usize = 200;
+
+static RE_MARKDOWN: Lazy<Regex> = Lazy::new(|| Regex::new(r"[#*_`]").unwrap());
+
+/// Builds a post summary from its markdown body.
+///
+/// The basic markdown syntax (`#`, `*`, `_` and `` ` ``) is removed and the first 200 words are
+/// kept, followed by `…` when the body is longer than that.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::summary_parser::generate_summary;
+///
+/// assert_eq!(generate_summary("# Título\n\nUm **post** em `Rust`."), "Título Um post em Rust.");
+/// ```
+pub fn generate_summary(body: &str) -> String {
+    let text = RE_MARKDOWN.replace_all(body, "");
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.len() <= SUMMARY_WORDS {
+        return words.join(" ");
+    }
+    format!("{}…", words[..SUMMARY_WORDS].join(" "))
+}
@@ -0,0 +1,18 @@
+/// Counts the words and characters of a post body, as shown by the CMS.
+///
+/// Words are the runs of non-whitespace and characters are counted as `char`s (not bytes), so
+/// accented letters and emojis count as one character each.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::text_counts_parser::count_words_and_characters;
+///
+/// assert_eq!(count_words_and_characters("Olá, mundo!"), (2, 11));
+/// ```
+pub fn count_words_and_characters(text: &str) -> (i32, i32) {
+    (
+        text.split_whitespace().count() as i32,
+        text.chars().count() as i32,
+    )
+}
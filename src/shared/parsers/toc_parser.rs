@@ -0,0 +1,66 @@
+use crate::shared::structs::toc_entry::TocEntryDTO;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+static RE_HEADING: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(#{1,3})\s+(.+?)(?:\s+#+)?\s*$").unwrap());
+
+/// Builds the anchor of a heading: lowercased, with spaces as `-` and the other
+/// non-alphanumeric characters removed.
+fn heading_anchor(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_whitespace() => Some('-'),
+            c if c.is_alphanumeric() || c == '-' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Extracts the table of contents of a markdown body from its `#`, `##` and `###` headings.
+///
+/// Headings inside fenced code blocks are ignored, and repeated anchors get a `-2`, `-3`, ...
+/// suffix in the order they appear.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::shared::parsers::toc_parser::extract_toc;
+///
+/// let toc = extract_toc("# Rust\n\n## Instalação\n\n#### Detalhes\n\n## Instalação");
+/// let anchors: Vec<&str> = toc.iter().map(|entry| entry.anchor.as_str()).collect();
+/// assert_eq!(anchors, vec!["rust", "instalação", "instalação-2"]);
+/// ```
+pub fn extract_toc(body: &str) -> Vec<TocEntryDTO> {
+    let mut toc = Vec::new();
+    let mut anchors: HashMap<String, usize> = HashMap::new();
+    let mut in_code_block = false;
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let capture = match RE_HEADING.captures(line) {
+            Some(capture) => capture,
+            None => continue,
+        };
+        let text = capture[2].trim().to_string();
+        let anchor = heading_anchor(&text);
+        let count = anchors.entry(anchor.clone()).or_insert(0);
+        *count += 1;
+        toc.push(TocEntryDTO {
+            level: capture[1].len() as u8,
+            anchor: match *count {
+                1 => anchor,
+                n => format!("{}-{}", anchor, n),
+            },
+            text,
+        });
+    }
+    toc
+}
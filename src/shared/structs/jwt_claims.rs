@@ -4,5 +4,19 @@ use serde::{Deserialize, Serialize};
 pub struct Claims {
     pub sub: String,
     pub role: String,
+    /// Whether the user verified the e-mail, read from the database when the token is minted.
+    #[serde(default)]
+    pub email_verified: bool,
+    /// The tenant of the user, read from the database when the token is minted. Tokens without it
+    /// are refused by the routes of every tenant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
     pub exp: usize,
+    /// The id of the admin impersonating the user, present only in impersonation tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonated_by: Option<String>,
+    /// The unique id of the token, present only in impersonation tokens and recorded in
+    /// `impersonation_logs`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
 }
@@ -1,3 +1,6 @@
+pub mod cursor_params;
 pub mod error_struct;
 pub mod jwt_claims;
+pub mod page_params;
 pub mod query_params;
+pub mod toc_entry;
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(ToSchema, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TocEntryDTO {
+    pub level: u8,
+    pub text: String,
+    pub anchor: String,
+}
@@ -3,14 +3,25 @@ use actix_web::HttpResponse;
 use jsonwebtoken::TokenData;
 use std::env;
 
+pub const IMPERSONATION_EXPIRATION_MINUTES: i64 = 10;
+
 pub struct Jwt {}
 
 impl Jwt {
-    pub fn refresh_token_constructor(user_id: String) -> Result<String, HttpResponse> {
+    pub fn refresh_token_constructor(
+        user_id: String,
+        role: String,
+        email_verified: bool,
+        tenant_id: String,
+    ) -> Result<String, HttpResponse> {
         let claims = Claims {
             sub: user_id,
-            role: String::from("admin"),
+            role,
+            email_verified,
+            tenant_id: Some(tenant_id),
             exp: (chrono::Utc::now() + chrono::Duration::days(7)).timestamp() as usize,
+            impersonated_by: None,
+            jti: None,
         };
 
         match jsonwebtoken::encode(
@@ -30,11 +41,58 @@ impl Jwt {
         }
     }
 
-    pub fn access_token_constructor(user_id: String) -> Result<String, HttpResponse> {
+    pub fn access_token_constructor(
+        user_id: String,
+        role: String,
+        email_verified: bool,
+        tenant_id: String,
+    ) -> Result<String, HttpResponse> {
         let claims = Claims {
             sub: user_id,
-            role: String::from("admin"),
+            role,
+            email_verified,
+            tenant_id: Some(tenant_id),
             exp: (chrono::Utc::now() + chrono::Duration::minutes(30)).timestamp() as usize,
+            impersonated_by: None,
+            jti: None,
+        };
+
+        match jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(env::var("JWT_ACCESS_KEY").unwrap().as_ref()),
+        ) {
+            Ok(token) => Ok(token),
+            Err(e) => Err(HttpResponse::InternalServerError().json(error_construct(
+                String::from("jsonwebtoken"),
+                String::from("internal server error"),
+                e.to_string(),
+                None,
+                None,
+                None,
+            ))),
+        }
+    }
+
+    /// Creates a short-lived access token for `user_id` carrying the `impersonated_by` claim. It
+    /// has the `user` role, so the admin cannot reach the admin routes through it.
+    ///
+    /// `jti` identifies the token in the impersonation audit trail.
+    pub fn impersonation_token_constructor(
+        user_id: String,
+        admin_id: String,
+        jti: String,
+        tenant_id: String,
+    ) -> Result<String, HttpResponse> {
+        let claims = Claims {
+            sub: user_id,
+            role: String::from("user"),
+            email_verified: false,
+            tenant_id: Some(tenant_id),
+            exp: (chrono::Utc::now() + chrono::Duration::minutes(IMPERSONATION_EXPIRATION_MINUTES))
+                .timestamp() as usize,
+            impersonated_by: Some(admin_id),
+            jti: Some(jti),
         };
 
         match jsonwebtoken::encode(
@@ -74,7 +132,7 @@ impl Jwt {
         }
     }
 
-    pub fn _refresh_token_decode(token: &str) -> Result<TokenData<Claims>, HttpResponse> {
+    pub fn refresh_token_decode(token: &str) -> Result<TokenData<Claims>, HttpResponse> {
         match jsonwebtoken::decode::<Claims>(
             token,
             &jsonwebtoken::DecodingKey::from_secret(
@@ -0,0 +1,76 @@
+use actix_web::{http::header::HeaderMap, HttpRequest};
+use once_cell::sync::Lazy;
+use std::{env, net::IpAddr};
+
+/// The addresses of the reverse proxies allowed to tell the client IP through `X-Forwarded-For`,
+/// read from the comma separated `TRUSTED_PROXIES` env var. Without it no proxy is trusted and
+/// the IP of the connection is always used.
+pub static TRUSTED_PROXIES: Lazy<Vec<IpAddr>> = Lazy::new(|| {
+    env::var("TRUSTED_PROXIES")
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|ip| {
+            let ip = ip.trim();
+            if ip.is_empty() {
+                return None;
+            }
+            match ip.parse::<IpAddr>() {
+                Ok(ip) => Some(ip),
+                Err(_) => {
+                    log::warn!("Ignoring the invalid trusted proxy {}", ip);
+                    None
+                }
+            }
+        })
+        .collect()
+});
+
+/// Resolves the IP of the client from the IP of the connection.
+///
+/// `X-Forwarded-For` is only read when the connection comes from one of the `trusted_proxies`, and
+/// the address taken is the rightmost one not added by a trusted proxy, since everything to the
+/// left of it was sent by the client and can be forged. `Forwarded` and `X-Real-Ip` are never read.
+pub fn resolve_client_ip(
+    peer_ip: Option<IpAddr>,
+    headers: &HeaderMap,
+    trusted_proxies: &[IpAddr],
+) -> Option<IpAddr> {
+    let peer_ip = peer_ip?;
+    if !trusted_proxies.contains(&peer_ip) {
+        return Some(peer_ip);
+    }
+
+    let forwarded_ip = headers
+        .get_all("X-Forwarded-For")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|ip| ip.trim().parse::<IpAddr>().ok())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .find(|ip| !matches!(ip, Some(ip) if trusted_proxies.contains(ip)));
+    match forwarded_ip {
+        Some(Some(ip)) => Some(ip),
+        _ => Some(peer_ip),
+    }
+}
+
+/// Resolves the IP of the client that made the request, trusting only the [`TRUSTED_PROXIES`].
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::utils::client_ip::client_ip;
+/// use actix_web::HttpRequest;
+///
+/// fn example(req: HttpRequest) -> String {
+///     client_ip(&req).map(|ip| ip.to_string()).unwrap_or_default()
+/// }
+/// ```
+pub fn client_ip(req: &HttpRequest) -> Option<IpAddr> {
+    resolve_client_ip(
+        req.peer_addr().map(|addr| addr.ip()),
+        req.headers(),
+        &TRUSTED_PROXIES,
+    )
+}
@@ -0,0 +1,136 @@
+use crate::{
+    shared::structs::cursor_params::CursorParams, utils::error_construct::error_construct,
+};
+use actix_web::HttpResponse;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use sha2::Sha256;
+use std::env;
+
+/// The keyset position `(created_at, id)` of the last row of a page.
+pub type CursorPosition = (DateTime<Utc>, uuid::Uuid);
+
+/// The key of the HMAC that signs the cursors. Without the `CURSOR_SIGNING_KEY` env var a random
+/// key is used, so the cursors stop being valid when the server restarts.
+static CURSOR_SIGNING_KEY: Lazy<Vec<u8>> = Lazy::new(|| {
+    match env::var("CURSOR_SIGNING_KEY")
+        .ok()
+        .filter(|key| !key.is_empty())
+    {
+        Some(key) => key.into_bytes(),
+        None => {
+            log::warn!("CURSOR_SIGNING_KEY is not set, signing the cursors with a random key");
+            format!("{}{}", uuid::Uuid::new_v4(), uuid::Uuid::new_v4()).into_bytes()
+        }
+    }
+});
+
+fn cursor_mac(value: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&CURSOR_SIGNING_KEY).unwrap();
+    mac.update(value.as_bytes());
+    mac
+}
+
+fn cursor_bad_request(cursor: &str) -> HttpResponse {
+    HttpResponse::BadRequest().json(error_construct(
+        String::from("cursor"),
+        String::from("bad request"),
+        String::from("Cursor inválido."),
+        Some(cursor.to_string()),
+        None,
+        None,
+    ))
+}
+
+/// Encodes the keyset position `(created_at, id)` of a row as an opaque, URL safe and signed cursor.
+///
+/// # Parameters
+///
+/// - `created_at`: The timestamp of the last row of the page.
+/// - `id`: The ID of the last row of the page.
+///
+/// # Returns
+///
+/// Returns the URL safe base64 of `{microseconds}_{id}|{hmac}`, where `hmac` is the hex of the
+/// HMAC-SHA256 of `{microseconds}_{id}` with the `CURSOR_SIGNING_KEY` env var.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::utils::cursor::{decode_cursor, encode_cursor};
+///
+/// let created_at = chrono::Utc::now();
+/// let id = uuid::Uuid::new_v4();
+/// let cursor = encode_cursor(created_at, id);
+///
+/// assert_eq!(decode_cursor(&cursor).unwrap().1, id);
+/// ```
+pub fn encode_cursor(created_at: DateTime<Utc>, id: uuid::Uuid) -> String {
+    let value = format!("{}_{}", created_at.timestamp_micros(), id);
+    let signature = hex::encode(cursor_mac(&value).finalize().into_bytes());
+    URL_SAFE_NO_PAD.encode(format!("{}|{}", value, signature))
+}
+
+/// Decodes a cursor created by `encode_cursor`, verifying its signature before parsing it.
+///
+/// # Parameters
+///
+/// - `cursor`: The cursor received in the query string.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains the keyset position `(created_at, id)`. On failure, returns an `HttpResponse` with the `400 Bad Request` status, also sent for tampered cursors.
+pub fn decode_cursor(cursor: &str) -> Result<CursorPosition, HttpResponse> {
+    let decoded = match URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+    {
+        Some(decoded) => decoded,
+        None => return Err(cursor_bad_request(cursor)),
+    };
+    let (value, signature) = match decoded.rsplit_once('|') {
+        Some(x) => x,
+        None => return Err(cursor_bad_request(cursor)),
+    };
+    let signed = hex::decode(signature)
+        .map(|signature| cursor_mac(value).verify_slice(&signature).is_ok())
+        .unwrap_or(false);
+    if !signed {
+        return Err(cursor_bad_request(cursor));
+    }
+
+    let position = value.split_once('_').and_then(|(micros, id)| {
+        let created_at = DateTime::from_timestamp_micros(micros.parse().ok()?)?;
+        let id = uuid::Uuid::parse_str(id).ok()?;
+        Some((created_at, id))
+    });
+    match position {
+        Some(position) => Ok(position),
+        None => Err(cursor_bad_request(cursor)),
+    }
+}
+
+/// Decodes the cursor and normalizes the limit (1 to 100, default 20) of a paginated request.
+///
+/// # Parameters
+///
+/// - `params`: The cursor pagination query parameters.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains the optional keyset position and the limit. On failure, returns an `HttpResponse` with the `400 Bad Request` status.
+pub fn cursor_page_params(
+    params: &CursorParams,
+) -> Result<(Option<CursorPosition>, i64), HttpResponse> {
+    let cursor = match &params.cursor {
+        Some(cursor) => match decode_cursor(cursor) {
+            Ok(cursor) => Some(cursor),
+            Err(e) => return Err(e),
+        },
+        None => None,
+    };
+    Ok((cursor, params.limit.unwrap_or(20).clamp(1, 100)))
+}
@@ -0,0 +1,74 @@
+use crate::middlewares::in_flight_middleware::in_flight_requests;
+use actix_web::dev::ServerHandle;
+use once_cell::sync::Lazy;
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+use tokio::signal::unix::{signal, SignalKind};
+
+/// Seconds the workers have to finish the in-flight requests, from `SHUTDOWN_TIMEOUT_SECS` (default 30).
+pub static SHUTDOWN_TIMEOUT_SECS: Lazy<u64> = Lazy::new(|| {
+    env::var("SHUTDOWN_TIMEOUT_SECS")
+        .ok()
+        .and_then(|timeout| timeout.parse().ok())
+        .unwrap_or(30)
+});
+
+/// How long the queues may take to be flushed on exit, from `QUEUE_DRAIN_TIMEOUT_SECS` (default 10).
+pub static QUEUE_DRAIN_TIMEOUT: Lazy<Duration> = Lazy::new(|| {
+    Duration::from_secs(
+        env::var("QUEUE_DRAIN_TIMEOUT_SECS")
+            .ok()
+            .and_then(|timeout| timeout.parse().ok())
+            .unwrap_or(10),
+    )
+});
+
+/// Waits for `SIGTERM` or `SIGINT` and stops the server gracefully.
+///
+/// The server stops accepting new connections right away, while the in-flight requests, counted
+/// by `in_flight_middleware`, have up to `SHUTDOWN_TIMEOUT_SECS` to complete. Only then the workers
+/// are stopped: a graceful `stop` alone may race with the accept thread and drop the connections.
+/// The server must be started with `disable_signals` to keep the actix handlers out of the way.
+///
+/// # Parameters
+///
+/// - `server_handle`: The handle of the running `HttpServer`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use navarro_blog_api::utils::graceful_shutdown::{graceful_shutdown, SHUTDOWN_TIMEOUT_SECS};
+/// use navarro_blog_api::middlewares::in_flight_middleware::in_flight_middleware;
+/// use actix_web::{middleware::from_fn, App, HttpServer};
+///
+/// async fn example() -> std::io::Result<()> {
+///     let server = HttpServer::new(|| App::new().wrap(from_fn(in_flight_middleware)))
+///         .disable_signals()
+///         .shutdown_timeout(*SHUTDOWN_TIMEOUT_SECS)
+///         .bind(("127.0.0.1", 8080))?
+///         .run();
+///     tokio::spawn(graceful_shutdown(server.handle()));
+///     server.await
+/// }
+/// ```
+pub async fn graceful_shutdown(server_handle: ServerHandle) {
+    let mut sigterm = signal(SignalKind::terminate()).unwrap();
+    let mut sigint = signal(SignalKind::interrupt()).unwrap();
+
+    tokio::select! {
+        _ = sigterm.recv() => (),
+        _ = sigint.recv() => (),
+    }
+
+    log::info!("Shutting down gracefully...");
+    server_handle.pause().await;
+
+    let deadline = Instant::now() + Duration::from_secs(*SHUTDOWN_TIMEOUT_SECS);
+    while in_flight_requests() > 0 && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    server_handle.stop(true).await;
+}
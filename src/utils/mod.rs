@@ -1,3 +1,8 @@
+pub mod client_ip;
+pub mod cursor;
 pub mod error_construct;
+pub mod graceful_shutdown;
+pub mod password_strength;
 pub mod password_verifier;
 pub mod query_constructor_executor;
+pub mod query_timeout;
@@ -0,0 +1,86 @@
+/// The score a password needs to be accepted on the registration.
+pub const MIN_PASSWORD_STRENGTH: u8 = 2;
+
+/// Passwords refused regardless of their composition, compared ignoring the case.
+const COMMON_PASSWORDS: [&str; 20] = [
+    "123456",
+    "12345678",
+    "123456789",
+    "1234567890",
+    "password",
+    "password1",
+    "password123",
+    "qwerty",
+    "qwerty123",
+    "abc123",
+    "111111",
+    "123123",
+    "iloveyou",
+    "admin",
+    "admin123",
+    "welcome",
+    "letmein",
+    "senha",
+    "senha123",
+    "mudar123",
+];
+
+/// Returns whether the three characters are repeated (`aaa`) or sequential (`abc`, `321`).
+fn is_pattern(window: &[char]) -> bool {
+    let first = window[1] as i64 - window[0] as i64;
+    let second = window[2] as i64 - window[1] as i64;
+    first == second && first.abs() <= 1
+}
+
+/// Scores the quality of a password from 0 (very weak) to 4 (very strong), like zxcvbn.
+///
+/// The length gives up to 3 points (8, 12 and 16 characters) and each character class, among
+/// uppercase, lowercase, digits and specials, gives 1 more. Repeated or sequential characters
+/// take 1 point, or 2 when they make up half of the password. A password of the common list
+/// always scores 0.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::utils::password_strength::score_password;
+///
+/// assert_eq!(score_password("password"), 0);
+/// assert_eq!(score_password("Qwzx plm%9Rt!"), 4);
+/// ```
+pub fn score_password(password: &str) -> u8 {
+    let chars: Vec<char> = password.chars().collect();
+    if COMMON_PASSWORDS.contains(&password.to_lowercase().as_str()) {
+        return 0;
+    }
+
+    let length = match chars.len() {
+        0..=7 => 0,
+        8..=11 => 1,
+        12..=15 => 2,
+        _ => 3,
+    };
+    let classes = [
+        chars.iter().any(|c| c.is_uppercase()),
+        chars.iter().any(|c| c.is_lowercase()),
+        chars.iter().any(|c| c.is_numeric()),
+        chars.iter().any(|c| !c.is_alphanumeric()),
+    ]
+    .iter()
+    .filter(|class| **class)
+    .count();
+    let windows = chars.len().saturating_sub(2);
+    let patterns = chars.windows(3).filter(|window| is_pattern(window)).count();
+    let penalty = match patterns {
+        0 => 0,
+        _ if patterns * 2 >= windows => 2,
+        _ => 1,
+    };
+
+    match (length + classes).saturating_sub(penalty) {
+        0..=1 => 0,
+        2..=3 => 1,
+        4 => 2,
+        5 => 3,
+        _ => 4,
+    }
+}
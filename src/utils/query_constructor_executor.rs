@@ -1,13 +1,17 @@
-use crate::shared::exceptions::custom_error_to_io_error_kind::{
-    custom_error_to_io_error_kind, CustomError,
+use crate::{
+    shared::exceptions::custom_error_to_io_error_kind::{
+        custom_error_to_io_error_kind, CustomError,
+    },
+    utils::query_timeout::with_query_timeout,
 };
 use actix_web::HttpResponse;
 use sql_builder::SqlBuilder;
-use tokio_postgres::Row;
+use tokio_postgres::{types::ToSql, Row};
 
 /// Construct and execute the query.
 ///
 /// This function constructs and executes the query, based on the provided `SqlBuilder` and `Pool`.
+/// The query is cancelled if it exceeds the timeout configured in `POSTGRES_QUERY_TIMEOUT_MS`.
 ///
 /// # Parameters
 ///
@@ -26,6 +30,7 @@ use tokio_postgres::Row;
 /// - The transaction fails.
 /// - The conversion from `sql_builder::SqlBuilder` to `String` fails.
 /// - The query fails.
+/// - The query exceeds the configured timeout.
 /// - The commit fails.
 ///
 /// # Example
@@ -46,6 +51,57 @@ use tokio_postgres::Row;
 pub async fn query_constructor_executor(
     pg_pool: actix_web::web::Data<deadpool_postgres::Pool>,
     sql_builder: SqlBuilder,
+) -> Result<Vec<Row>, HttpResponse> {
+    let sql = match sql_builder.sql() {
+        Ok(x) => x,
+        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::AnyhowError(e))),
+    };
+    query_executor(pg_pool, &sql, &[]).await
+}
+
+/// Execute a raw SQL query with positional parameters (`$1`, `$2`, ...).
+///
+/// It is meant for the queries that `SqlBuilder` can not express, such as CTEs, keeping the same
+/// transaction and timeout handling of `query_constructor_executor`.
+///
+/// # Parameters
+///
+/// - `pg_pool`: A connection pool for the database.
+/// - `sql`: The SQL query.
+/// - `params`: The values bound to the query parameters.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains the result of the query. On failure, returns an `HttpResponse` with the corresponding error.
+///
+/// # Errors
+///
+/// This function may return an error if:
+///
+/// - It is not possible to obtain a connection from the pool.
+/// - The transaction fails.
+/// - The query fails.
+/// - The query exceeds the configured timeout.
+/// - The commit fails.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::utils::query_constructor_executor::query_executor;
+/// use actix_web::{web::Data, HttpResponse};
+/// use deadpool_postgres::Pool;
+///
+/// pub async fn example(pg_pool: Data<Pool>, email: String) -> Result<Vec<postgres::Row>, HttpResponse> {
+///     match query_executor(pg_pool, "SELECT id FROM users WHERE email = $1", &[&email]).await {
+///         Ok(x) => Ok(x),
+///         Err(e) => return Err(e),
+///     }
+/// }
+/// ```
+pub async fn query_executor(
+    pg_pool: actix_web::web::Data<deadpool_postgres::Pool>,
+    sql: &str,
+    params: &[&(dyn ToSql + Sync)],
 ) -> Result<Vec<Row>, HttpResponse> {
     let mut conn = match pg_pool.get().await {
         Ok(x) => x,
@@ -55,13 +111,11 @@ pub async fn query_constructor_executor(
         Ok(x) => x,
         Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
     };
-    let sql = match sql_builder.sql() {
+    let rows = match with_query_timeout(transaction.query(sql, params), transaction.cancel_token())
+        .await
+    {
         Ok(x) => x,
-        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::AnyhowError(e))),
-    };
-    let rows = match transaction.query(&sql, &[]).await {
-        Ok(x) => x,
-        Err(e) => return Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
+        Err(e) => return Err(e),
     };
     match transaction.commit().await {
         Ok(_) => Ok(rows),
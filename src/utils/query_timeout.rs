@@ -0,0 +1,88 @@
+use crate::{
+    shared::exceptions::custom_error_to_io_error_kind::{
+        custom_error_to_io_error_kind, CustomError,
+    },
+    utils::error_construct::error_construct,
+};
+use actix_web::HttpResponse;
+use once_cell::sync::Lazy;
+use std::{env, future::Future, panic::Location, time::Duration};
+use tokio_postgres::{CancelToken, NoTls};
+
+static QUERY_TIMEOUT: Lazy<Duration> = Lazy::new(|| {
+    Duration::from_millis(
+        env::var("POSTGRES_QUERY_TIMEOUT_MS")
+            .ok()
+            .and_then(|timeout| timeout.parse().ok())
+            .unwrap_or(5000),
+    )
+});
+
+/// Runs a Postgres query with the timeout configured in `POSTGRES_QUERY_TIMEOUT_MS` (default 5000).
+///
+/// If the timeout elapses, the query location is logged and the running query is cancelled in the
+/// backend (the same as `pg_cancel_backend`), so the connection can go back to the pool.
+///
+/// # Parameters
+///
+/// - `future`: The query future.
+/// - `cancel_token`: The cancel token of the client running the query.
+///
+/// # Returns
+///
+/// Returns a `Result` which, on success, contains the result of the query. On failure, returns an `HttpResponse` with the corresponding error.
+///
+/// # Errors
+///
+/// This function may return an error if:
+///
+/// - The query fails.
+/// - The query exceeds the configured timeout.
+///
+/// # Example
+///
+/// ```rust
+/// use navarro_blog_api::utils::query_timeout::with_query_timeout;
+/// use actix_web::HttpResponse;
+/// use tokio_postgres::{Row, Transaction};
+///
+/// pub async fn example(transaction: &Transaction<'_>, sql: &str) -> Result<Vec<Row>, HttpResponse> {
+///     match with_query_timeout(transaction.query(sql, &[]), transaction.cancel_token()).await {
+///         Ok(x) => Ok(x),
+///         Err(e) => return Err(e),
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn with_query_timeout<F, T>(
+    future: F,
+    cancel_token: CancelToken,
+) -> impl Future<Output = Result<T, HttpResponse>>
+where
+    F: Future<Output = Result<T, tokio_postgres::Error>>,
+{
+    let location = Location::caller();
+
+    async move {
+        match tokio::time::timeout(*QUERY_TIMEOUT, future).await {
+            Ok(Ok(x)) => Ok(x),
+            Ok(Err(e)) => Err(custom_error_to_io_error_kind(CustomError::TokioPostgres(e))),
+            Err(_) => {
+                log::warn!(
+                    "Query at {} exceeded the timeout of {}ms and will be cancelled",
+                    location,
+                    QUERY_TIMEOUT.as_millis()
+                );
+                let _ = cancel_token.cancel_query(NoTls).await;
+                Err(HttpResponse::ServiceUnavailable().json(error_construct(
+                    String::from("database"),
+                    String::from("service unavailable"),
+                    String::from("A consulta ao banco de dados excedeu o tempo limite."),
+                    None,
+                    None,
+                    None,
+                )))
+            }
+        }
+    }
+}
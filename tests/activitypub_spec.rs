@@ -0,0 +1,207 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod activitypub_specs {
+    use crate::mocks::models::{postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{http::header::CONTENT_TYPE, test, web, App};
+    use navarro_blog_api::modules::{
+        activitypub::activitypub_controllers::activitypub_controllers_module,
+        user::user_controllers::user_controllers_module,
+    };
+
+    #[test]
+    async fn _activitypub_outbox_and_webfinger() {
+        dotenv::dotenv().ok();
+        std::env::set_var("BASE_URL", "http://localhost");
+        std::env::set_var("HTTP_PORT", "8080");
+        std::env::set_var("SITE_BASE_URL", "https://navarro.blog/");
+
+        let pg_pool = PostgresModels::postgres_success(None);
+        let client = pg_pool.get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        let username = format!("ap_{}", &user_id.simple().to_string()[..12]);
+        client
+            .execute(
+                "INSERT INTO users (id, name, username, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, $3, 'x', NOW())",
+                &[&user_id, &username, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+
+        let mut post_ids = Vec::new();
+        for (title, published_at, deleted) in [
+            ("Primeiro", Some("3 days"), false),
+            ("Segundo", Some("2 days"), false),
+            ("Terceiro", Some("1 day"), false),
+            ("Rascunho", None, false),
+            ("Removido", Some("1 hour"), true),
+        ] {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at, deleted_at)
+                    VALUES ($1, $2, 'sub', 'body', $3, NOW(), NOW() - $4::TEXT::INTERVAL, CASE WHEN $5 THEN NOW() END)",
+                    &[&post_id, &title, &user_id, &published_at, &deleted],
+                )
+                .await
+                .unwrap();
+            post_ids.push(post_id);
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pg_pool.clone()))
+                .app_data(web::Data::new(RedisModels::pool_success(None).await))
+                .service(user_controllers_module())
+                .configure(activitypub_controllers_module),
+        )
+        .await;
+        let outbox_url = format!("http://localhost:8080/user/{}/outbox", username);
+        let actor_url = format!("http://localhost:8080/user/@{}", username);
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!("/user/{}/outbox", username))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers().get(CONTENT_TYPE).unwrap(),
+            "application/activity+json"
+        );
+        let collection: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(
+            collection,
+            serde_json::json!({
+                "@context": "https://www.w3.org/ns/activitystreams",
+                "id": outbox_url,
+                "type": "OrderedCollection",
+                "totalItems": 3,
+                "first": format!("{}?page=1", outbox_url),
+                "last": format!("{}?page=1", outbox_url),
+            })
+        );
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!("/user/{}/outbox?page=1&per_page=2", username))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), 200);
+        let page: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(page["@context"], "https://www.w3.org/ns/activitystreams");
+        assert_eq!(page["type"], "OrderedCollectionPage");
+        assert_eq!(page["partOf"], outbox_url.as_str());
+        assert_eq!(page["totalItems"], 3);
+        assert_eq!(
+            page["next"],
+            format!("{}?page=2&per_page=2", outbox_url).as_str()
+        );
+        assert!(page.get("prev").is_none());
+        let items = page["orderedItems"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        let post_url = format!("https://navarro.blog/post/{}", post_ids[2]);
+        assert_eq!(items[0]["id"], format!("{}#create", post_url).as_str());
+        assert_eq!(items[0]["type"], "Create");
+        assert_eq!(items[0]["actor"], actor_url.as_str());
+        assert_eq!(
+            items[0]["to"],
+            serde_json::json!(["https://www.w3.org/ns/activitystreams#Public"])
+        );
+        let article = &items[0]["object"];
+        assert_eq!(article["id"], post_url.as_str());
+        assert_eq!(article["type"], "Article");
+        assert_eq!(article["name"], "Terceiro");
+        assert_eq!(article["summary"], "sub");
+        assert_eq!(article["attributedTo"], actor_url.as_str());
+        assert_eq!(article["published"], items[0]["published"]);
+        assert_eq!(items[1]["object"]["name"], "Segundo");
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!("/user/{}/outbox?page=2&per_page=2", username))
+                .to_request(),
+        )
+        .await;
+        let page: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(page["orderedItems"].as_array().unwrap().len(), 1);
+        assert_eq!(page["orderedItems"][0]["object"]["name"], "Primeiro");
+        assert_eq!(
+            page["prev"],
+            format!("{}?page=1&per_page=2", outbox_url).as_str()
+        );
+        assert!(page.get("next").is_none());
+
+        for (uri, status) in [
+            (format!("/user/{}/outbox?per_page=100", username), 400),
+            (format!("/user/{}/outbox?page=0", username), 400),
+            (String::from("/user/ninguem_aqui_123/outbox"), 404),
+        ] {
+            let resp =
+                test::call_service(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+            assert_eq!(resp.status(), status, "{}", uri);
+        }
+
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri(&format!(
+                    "/.well-known/webfinger?resource=acct:{}@localhost",
+                    username
+                ))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers().get(CONTENT_TYPE).unwrap(),
+            "application/jrd+json"
+        );
+        let jrd: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(
+            jrd,
+            serde_json::json!({
+                "subject": format!("acct:{}@localhost", username),
+                "aliases": [actor_url],
+                "links": [{
+                    "rel": "self",
+                    "type": "application/activity+json",
+                    "href": actor_url,
+                }, {
+                    "rel": "https://www.w3.org/ns/activitystreams#outbox",
+                    "type": "application/activity+json",
+                    "href": outbox_url,
+                }],
+            })
+        );
+
+        for (resource, status) in [
+            (username.clone(), 400),
+            (format!("acct:{}@mastodon.social", username), 404),
+            (String::from("acct:ninguem_aqui_123@localhost"), 404),
+        ] {
+            let resp = test::call_service(
+                &app,
+                test::TestRequest::get()
+                    .uri(&format!("/.well-known/webfinger?resource={}", resource))
+                    .to_request(),
+            )
+            .await;
+            assert_eq!(resp.status(), status, "{}", resource);
+        }
+
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+}
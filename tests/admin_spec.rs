@@ -0,0 +1,866 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod admin_specs {
+    use crate::mocks::{
+        models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels, user::UserModels},
+        test_context::setup_test_context,
+    };
+    use actix_web::{
+        dev::ServiceResponse,
+        test,
+        web::{self, Data},
+        App,
+    };
+    use navarro_blog_api::{
+        middlewares::tenant_middleware::{DEFAULT_TENANT_ID, TENANT_HEADER},
+        modules::{
+            admin::{
+                admin_controllers::admin_controllers_module,
+                admin_dtos::{MergeUsersSummaryDTO, PlatformStatsDTO},
+                admin_repositories::{admin_statistics_repository, impersonation_logs_repository},
+                admin_services::impersonate_user_service,
+            },
+            user::{
+                user_controllers::user_controllers_module,
+                user_dtos::DetailUserDTO,
+                user_queues::{DeleteUserAppQueue, PutUserAppQueue},
+            },
+        },
+        shared::treaties::jwt_treated::Jwt,
+    };
+    use serde_json::json;
+    use std::sync::Arc;
+    use tokio_postgres::error::SqlState;
+
+    const SEED_SQL: &str = "
+        INSERT INTO users (id, name, email, password, created_at) VALUES
+            ('00000000-0000-0000-0000-000000000001', 'recent user', 'recent@gmail.com', 'x', NOW()),
+            ('00000000-0000-0000-0000-000000000002', 'old user', 'old@gmail.com', 'x', NOW() - INTERVAL '90 days'),
+            ('00000000-0000-0000-0000-000000000003', 'commenter', 'commenter@gmail.com', 'x', NOW() - INTERVAL '90 days');
+        INSERT INTO posts (id, title, subtitle, body, created_at, published_at) VALUES
+            ('00000000-0000-0000-0000-000000000011', 'published', 'sub', 'body', NOW(), NOW() - INTERVAL '1 day'),
+            ('00000000-0000-0000-0000-000000000012', 'draft', 'sub', 'body', NOW(), NULL),
+            ('00000000-0000-0000-0000-000000000013', 'scheduled', 'sub', 'body', NOW(), NOW() + INTERVAL '1 day');
+        INSERT INTO comments (id, post_id, user_id, body, created_at) VALUES
+            ('00000000-0000-0000-0000-000000000021', '00000000-0000-0000-0000-000000000011',
+                '00000000-0000-0000-0000-000000000003', 'recent comment', NOW()),
+            ('00000000-0000-0000-0000-000000000022', '00000000-0000-0000-0000-000000000011',
+                '00000000-0000-0000-0000-000000000002', 'old comment', NOW() - INTERVAL '60 days');
+        INSERT INTO users_posts_likes (user_id, post_id) VALUES
+            ('00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000011'),
+            ('00000000-0000-0000-0000-000000000002', '00000000-0000-0000-0000-000000000011');
+        INSERT INTO users_comments_likes (user_id, comment_id) VALUES
+            ('00000000-0000-0000-0000-000000000001', '00000000-0000-0000-0000-000000000021');
+    ";
+
+    #[test]
+    async fn _admin_statistics() {
+        let ctx = setup_test_context().await;
+
+        PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap()
+            .batch_execute(SEED_SQL)
+            .await
+            .unwrap();
+
+        let stats = admin_statistics_repository(
+            Data::new(PostgresModels::postgres_success(Some(&ctx))),
+            uuid::Uuid::parse_str(DEFAULT_TENANT_ID).unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            stats,
+            PlatformStatsDTO {
+                total_users: 3,
+                active_users_last_30_days: 2,
+                total_posts: 3,
+                published_posts: 1,
+                total_comments: 2,
+                total_likes: 3,
+            }
+        );
+    }
+
+    #[test]
+    async fn _ban_and_unban_user() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+
+        let user = UserModels::complete_user_model_hashed();
+        let user_id = uuid::Uuid::parse_str(&user.id).unwrap();
+        let salt = uuid::Uuid::new_v4();
+        let client = PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, $2, $3, $4, NOW())",
+                &[&user_id, &user.name, &user.email, &format!("{}{}", user.password, salt)],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO salt (salt, user_id) VALUES ($1, $2)",
+                &[&salt, &user_id],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(user_controllers_module())
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let admin_jwt = JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string());
+
+        let login = || {
+            test::TestRequest::post()
+                .uri("/user/login")
+                .set_json(UserModels::login_user_model())
+                .to_request()
+        };
+        let admin_request = |action: &str| {
+            test::TestRequest::post()
+                .uri(&format!("/admin/user/{}/{}", user.id, action))
+                .append_header(("Authorization", format!("Bearer {}", admin_jwt)))
+                .set_json(json!({ "reason": "spam em comentários" }))
+                .to_request()
+        };
+
+        let resp: ServiceResponse = test::call_service(&app, admin_request("ban")).await;
+        assert_eq!(resp.status(), 204);
+
+        let resp = test::call_service(&app, login()).await;
+        assert_eq!(resp.status(), 403);
+
+        let bytes = test::read_body(resp).await;
+        assert!(String::from_utf8(bytes.to_vec())
+            .unwrap()
+            .contains("Esta conta foi suspensa."));
+
+        let rows = client
+            .query(
+                "SELECT action, reason FROM audit_logs WHERE target_id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get::<_, String>("action"), "ban_user");
+        assert_eq!(rows[0].get::<_, String>("reason"), "spam em comentários");
+
+        let resp = test::call_service(&app, admin_request("unban")).await;
+        assert_eq!(resp.status(), 204);
+
+        let resp = test::call_service(&app, login()).await;
+        assert_eq!(resp.status(), 200);
+
+        let row = client
+            .query_one("SELECT banned_at FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+        assert!(row
+            .get::<_, Option<chrono::DateTime<chrono::Utc>>>("banned_at")
+            .is_none());
+    }
+
+    #[test]
+    async fn _ban_user_error_forbidden() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let jwt = JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string());
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/admin/user/{}/ban", uuid::Uuid::new_v4()))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .set_json(json!({ "reason": "spam em comentários" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 403);
+    }
+
+    async fn seed_user() -> uuid::Uuid {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        user_id
+    }
+
+    async fn delete_seeded_user(user_id: uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM audit_logs WHERE target_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _impersonate_user_service() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let user_id = seed_user().await;
+        let admin_id = uuid::Uuid::new_v4();
+
+        let impersonation = impersonate_user_service(
+            pg_pool.clone(),
+            admin_id.to_string(),
+            user_id.to_string(),
+            String::from("203.0.113.7"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(impersonation.user_id, user_id.to_string());
+        assert_eq!(impersonation.impersonated_by, admin_id.to_string());
+        assert_eq!(impersonation.access_expires_in, 600);
+
+        let token = Jwt::access_token_decode(&impersonation.access_token).unwrap();
+        assert_eq!(token.claims.sub, user_id.to_string());
+        assert_eq!(token.claims.role, "user");
+        assert_eq!(token.claims.impersonated_by, Some(admin_id.to_string()));
+        let expires_in = token.claims.exp as i64 - chrono::Utc::now().timestamp();
+        assert!(expires_in > 590 && expires_in <= 600);
+        assert!(uuid::Uuid::parse_str(&token.claims.jti.unwrap()).is_ok());
+
+        let rows = pg_pool
+            .get()
+            .await
+            .unwrap()
+            .query(
+                "SELECT actor_id, action FROM audit_logs WHERE target_id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get::<_, uuid::Uuid>("actor_id"), admin_id);
+        assert_eq!(rows[0].get::<_, String>("action"), "impersonate_user");
+
+        let resp = impersonate_user_service(
+            pg_pool.clone(),
+            admin_id.to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            String::from("203.0.113.7"),
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(resp.status(), 404);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri(&format!("/admin/impersonate/{}", user_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(admin_id.to_string())
+                ),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        delete_seeded_user(user_id).await;
+    }
+
+    /// A delete the application could attempt on the trail; there is no repository for it.
+    async fn delete_impersonation_log(
+        client: &deadpool_postgres::Object,
+        token_jti: uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(
+                "DELETE FROM impersonation_logs WHERE token_jti = $1",
+                &[&token_jti],
+            )
+            .await
+    }
+
+    #[test]
+    async fn _impersonation_logs_are_append_only() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let user_id = seed_user().await;
+        let admin_id = uuid::Uuid::new_v4();
+        let impersonation = impersonate_user_service(
+            pg_pool.clone(),
+            admin_id.to_string(),
+            user_id.to_string(),
+            String::from("203.0.113.7"),
+        )
+        .await
+        .unwrap();
+        let token = Jwt::access_token_decode(&impersonation.access_token).unwrap();
+        let token_jti = uuid::Uuid::parse_str(&token.claims.jti.unwrap()).unwrap();
+
+        let logs = impersonation_logs_repository(pg_pool.clone())
+            .await
+            .unwrap();
+        let log = logs
+            .iter()
+            .find(|log| log.token_jti == token_jti.to_string())
+            .unwrap();
+        assert_eq!(log.admin_id, admin_id.to_string());
+        assert_eq!(log.target_user_id, user_id.to_string());
+        assert_eq!(log.used_from_ip, "203.0.113.7");
+
+        let client = pg_pool.get().await.unwrap();
+        let e = delete_impersonation_log(&client, token_jti)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(e.code(), Some(&SqlState::INSUFFICIENT_PRIVILEGE));
+        for sql in [
+            "UPDATE impersonation_logs SET used_from_ip = '127.0.0.1' WHERE token_jti = $1",
+            "TRUNCATE impersonation_logs",
+        ] {
+            let params: &[&(dyn tokio_postgres::types::ToSql + Sync)] = if sql.contains("$1") {
+                &[&token_jti]
+            } else {
+                &[]
+            };
+            let e = client.execute(sql, params).await.err().unwrap();
+            assert_eq!(e.code(), Some(&SqlState::INSUFFICIENT_PRIVILEGE), "{}", sql);
+        }
+        let rows = client
+            .query(
+                "SELECT used_from_ip FROM impersonation_logs WHERE token_jti = $1",
+                &[&token_jti],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows[0].get::<_, String>("used_from_ip"), "203.0.113.7");
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri("/admin/impersonation-logs")
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(admin_id.to_string())
+                ),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        delete_seeded_user(user_id).await;
+    }
+
+    #[test]
+    async fn _impersonation_blocks_destructive_actions() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let user_id = seed_user().await;
+        let impersonation = impersonate_user_service(
+            pg_pool.clone(),
+            uuid::Uuid::new_v4().to_string(),
+            user_id.to_string(),
+            String::from("203.0.113.7"),
+        )
+        .await
+        .unwrap();
+        let authorization = format!("Bearer {}", impersonation.access_token);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .app_data(Data::new(Arc::new(DeleteUserAppQueue::new())))
+                .app_data(Data::new(Arc::new(PutUserAppQueue::new())))
+                .service(user_controllers_module()),
+        )
+        .await;
+
+        for req in [
+            test::TestRequest::delete()
+                .uri(&format!("/user/{}", user_id))
+                .set_json(json!({ "password": "Senha@123" })),
+            test::TestRequest::put()
+                .uri(&format!("/user/{}", user_id))
+                .set_json(json!({
+                    "password": "Senha@123",
+                    "newPassword": "Senha@456",
+                    "email": "impersonated@gmail.com"
+                })),
+            test::TestRequest::delete().uri(&format!("/user/{}/account", user_id)),
+            test::TestRequest::post()
+                .uri(&format!("/user/{}/api-key", user_id))
+                .set_json(json!({ "name": "Deploy do blog", "scopes": ["post:read"] })),
+            test::TestRequest::post().uri(&format!("/user/{}/deactivate", user_id)),
+            test::TestRequest::post().uri(&format!("/user/{}/reactivate", user_id)),
+            test::TestRequest::patch()
+                .uri(&format!("/user/{}/username", user_id))
+                .set_json(json!({ "username": "impersonated_user" })),
+            test::TestRequest::patch()
+                .uri(&format!("/user/{}/name", user_id))
+                .set_json(json!({ "name": "Impersonated User" })),
+        ] {
+            let req = req
+                .append_header(("Authorization", authorization.clone()))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), 403);
+            let bytes = test::read_body(resp).await;
+            assert!(String::from_utf8(bytes.to_vec())
+                .unwrap()
+                .contains("Ações destrutivas não são permitidas em modo de representação."));
+        }
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}", user_id))
+            .append_header(("Authorization", authorization.clone()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let row = pg_pool
+            .get()
+            .await
+            .unwrap()
+            .query_one("SELECT deleted_at FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+        assert!(row
+            .get::<_, Option<chrono::DateTime<chrono::Utc>>>("deleted_at")
+            .is_none());
+        let api_keys = pg_pool
+            .get()
+            .await
+            .unwrap()
+            .query("SELECT id FROM api_keys WHERE user_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        assert!(api_keys.is_empty());
+
+        delete_seeded_user(user_id).await;
+    }
+
+    #[test]
+    async fn _admin_list_users_filters() {
+        dotenv::dotenv().ok();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let tenant_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        let user_sql = "INSERT INTO users (id, name, email, password, created_at, role, banned_at, tenant_id) VALUES ($1, 'filtered user', $2, 'x', NOW() - $3::TEXT::INTERVAL, $4, $5, $6)";
+        let mut user_ids = Vec::new();
+        for (age, role, banned_at) in [
+            ("10 days", "admin", None),
+            ("0 days", "user", None),
+            ("40 days", "user", Some(chrono::Utc::now())),
+            ("0 days", "user", None),
+        ] {
+            let user_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    user_sql,
+                    &[
+                        &user_id,
+                        &format!("{}@gmail.com", user_id),
+                        &age,
+                        &role,
+                        &banned_at,
+                        &tenant_id,
+                    ],
+                )
+                .await
+                .unwrap();
+            user_ids.push(user_id);
+        }
+        client
+            .execute(
+                "INSERT INTO user_onboarding (user_id, email_verified) VALUES ($1, true)",
+                &[&user_ids[1]],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let jwt = JwtModels::tenant_access_jwt_model(
+            uuid::Uuid::new_v4().to_string(),
+            tenant_id.to_string(),
+        );
+        let twenty_days_ago = (chrono::Utc::now() - chrono::Duration::days(20))
+            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+        for (query, expected) in [
+            (String::from("role=admin"), vec![0]),
+            (String::from("role=user&banned=false"), vec![1, 3]),
+            (String::from("email_verified=true"), vec![1]),
+            (String::from("email_verified=false"), vec![0, 2, 3]),
+            (String::from("banned=true"), vec![2]),
+            (format!("created_after={}", twenty_days_ago), vec![0, 1, 3]),
+            (format!("created_before={}", twenty_days_ago), vec![2]),
+        ] {
+            let req = test::TestRequest::get()
+                .uri(&format!("/admin/user?{}", query))
+                .insert_header(("Authorization", format!("Bearer {}", jwt)))
+                .insert_header((TENANT_HEADER, tenant_id.to_string()))
+                .to_request();
+            let users: Vec<DetailUserDTO> = test::call_and_read_body_json(&app, req).await;
+            let mut ids: Vec<String> = users.into_iter().map(|user| user.id).collect();
+            ids.sort();
+            let mut expected: Vec<String> = expected
+                .into_iter()
+                .map(|index| user_ids[index].to_string())
+                .collect();
+            expected.sort();
+            assert_eq!(ids, expected, "{}", query);
+        }
+
+        let req = test::TestRequest::get()
+            .uri("/admin/user?created_after=ontem")
+            .insert_header(("Authorization", format!("Bearer {}", jwt)))
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 400);
+
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tenants WHERE id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _admin_list_posts_filters() {
+        dotenv::dotenv().ok();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let tenant_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        let author_ids = [uuid::Uuid::new_v4(), uuid::Uuid::new_v4()];
+        for author_id in &author_ids {
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at, tenant_id) VALUES ($1, 'post author', $2, 'x', NOW(), $3)",
+                    &[author_id, &format!("{}@gmail.com", author_id), &tenant_id],
+                )
+                .await
+                .unwrap();
+        }
+        let post_sql = "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at, deleted_at, cover_image_url, tenant_id) VALUES ($1, 'filtered post', 'sub', 'body', $2, NOW() - $3::TEXT::INTERVAL, NOW() - $4::TEXT::INTERVAL, $5, $6, $7)";
+        let mut post_ids = Vec::new();
+        for (author, created, published, deleted_at, cover) in [
+            (
+                0,
+                "1 minute",
+                Some("10 days"),
+                None,
+                Some("https://cdn.com/capa.png"),
+            ),
+            (0, "2 minutes", None, None, None),
+            (1, "3 minutes", Some("1 day"), None, None),
+            (
+                1,
+                "4 minutes",
+                Some("40 days"),
+                Some(chrono::Utc::now()),
+                Some("https://cdn.com/capa.png"),
+            ),
+        ] {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    post_sql,
+                    &[
+                        &post_id,
+                        &author_ids[author],
+                        &created,
+                        &published,
+                        &deleted_at,
+                        &cover,
+                        &tenant_id,
+                    ],
+                )
+                .await
+                .unwrap();
+            post_ids.push(post_id.to_string());
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let jwt = JwtModels::tenant_access_jwt_model(
+            uuid::Uuid::new_v4().to_string(),
+            tenant_id.to_string(),
+        );
+        let list = |query: &str| {
+            test::TestRequest::get()
+                .uri(&format!("/admin/post?{}", query))
+                .insert_header(("Authorization", format!("Bearer {}", jwt)))
+                .insert_header((TENANT_HEADER, tenant_id.to_string()))
+                .to_request()
+        };
+        let days_ago = |days: i64| {
+            (chrono::Utc::now() - chrono::Duration::days(days))
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        };
+
+        for (query, expected) in [
+            (String::new(), vec![0, 1, 2]),
+            (String::from("status=draft"), vec![1]),
+            (String::from("status=published"), vec![0, 2]),
+            (String::from("status=deleted"), vec![3]),
+            (format!("author_id={}", author_ids[0]), vec![0, 1]),
+            (format!("published_after={}", days_ago(20)), vec![0, 2]),
+            (format!("published_before={}", days_ago(5)), vec![0]),
+            (String::from("has_cover=true"), vec![0]),
+            (String::from("has_cover=false"), vec![1, 2]),
+            (
+                format!(
+                    "status=published&author_id={}&published_after={}&has_cover=false",
+                    author_ids[1],
+                    days_ago(20)
+                ),
+                vec![2],
+            ),
+            (String::from("status=deleted&has_cover=true"), vec![3]),
+        ] {
+            let page: serde_json::Value = test::call_and_read_body_json(&app, list(&query)).await;
+            let ids: Vec<&str> = page["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|post| post["id"].as_str().unwrap())
+                .collect();
+            let expected: Vec<&str> = expected
+                .into_iter()
+                .map(|index| post_ids[index].as_str())
+                .collect();
+            assert_eq!(ids, expected, "{}", query);
+            assert_eq!(page["total"], expected.len(), "{}", query);
+        }
+
+        let page: serde_json::Value =
+            test::call_and_read_body_json(&app, list("per_page=2&page=2")).await;
+        assert_eq!(page["data"].as_array().unwrap().len(), 1);
+        assert_eq!(page["data"][0]["id"], post_ids[2]);
+        assert_eq!(page["page"], 2);
+        assert_eq!(page["perPage"], 2);
+        assert_eq!(page["total"], 3);
+        assert_eq!(page["totalPages"], 2);
+
+        for query in ["status=archived", "author_id=1", "published_after=ontem"] {
+            assert_eq!(
+                test::call_service(&app, list(query)).await.status(),
+                400,
+                "{}",
+                query
+            );
+        }
+
+        client
+            .execute("DELETE FROM posts WHERE tenant_id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE tenant_id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tenants WHERE id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _merge_users() {
+        dotenv::dotenv().ok();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let (source_id, target_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        for user_id in [source_id, target_id] {
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'merged user', $2, 'x', NOW())",
+                    &[&user_id, &format!("{}@gmail.com", user_id)],
+                )
+                .await
+                .unwrap();
+        }
+        let (source_post_id, target_post_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        let comment_id = uuid::Uuid::new_v4();
+        client
+            .batch_execute(&format!(
+                "
+                INSERT INTO posts (id, title, subtitle, body, author_id, created_at, likes) VALUES
+                    ('{source_post_id}', 'source post', 'sub', 'body', '{source_id}', NOW(), 1),
+                    ('{target_post_id}', 'target post', 'sub', 'body', '{target_id}', NOW(), 2);
+                INSERT INTO post_authors (post_id, user_id, role) VALUES
+                    ('{source_post_id}', '{source_id}', 'primary'),
+                    ('{target_post_id}', '{target_id}', 'primary'),
+                    ('{target_post_id}', '{source_id}', 'co-author');
+                INSERT INTO comments (id, post_id, user_id, body, created_at, likes) VALUES
+                    ('{comment_id}', '{target_post_id}', '{source_id}', 'comment', NOW(), 1);
+                INSERT INTO users_posts_likes (user_id, post_id) VALUES
+                    ('{source_id}', '{source_post_id}'),
+                    ('{source_id}', '{target_post_id}'),
+                    ('{target_id}', '{target_post_id}');
+                INSERT INTO users_comments_likes (user_id, comment_id) VALUES
+                    ('{source_id}', '{comment_id}');
+                INSERT INTO reading_lists (id, user_id, name, created_at) VALUES
+                    ('{}', '{source_id}', 'lista', NOW());
+                ",
+                uuid::Uuid::new_v4()
+            ))
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let admin_id = uuid::Uuid::new_v4();
+        let jwt = JwtModels::access_jwt_model(admin_id.to_string());
+        let merge = |source_id: uuid::Uuid, target_id: uuid::Uuid| {
+            test::TestRequest::post()
+                .uri(&format!(
+                    "/admin/user/{}/merge-into/{}",
+                    source_id, target_id
+                ))
+                .insert_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+
+        let summary: MergeUsersSummaryDTO =
+            test::call_and_read_body_json(&app, merge(source_id, target_id)).await;
+        assert_eq!(
+            summary,
+            MergeUsersSummaryDTO {
+                posts_moved: 1,
+                co_authorships_moved: 1,
+                comments_moved: 1,
+                post_likes_moved: 1,
+                comment_likes_moved: 1,
+                reading_lists_moved: 1,
+                drafts_moved: 0,
+                login_history_moved: 0,
+            }
+        );
+
+        let count = |sql: &'static str, user_id: uuid::Uuid| {
+            let client = &client;
+            async move {
+                client
+                    .query_one(sql, &[&user_id])
+                    .await
+                    .unwrap()
+                    .get::<_, i64>(0)
+            }
+        };
+        for (sql, user_id, expected) in [
+            ("SELECT COUNT(*) FROM posts WHERE author_id = $1", target_id, 2),
+            (
+                "SELECT COUNT(*) FROM post_authors WHERE user_id = $1 AND role = 'primary'",
+                target_id,
+                2,
+            ),
+            ("SELECT COUNT(*) FROM post_authors WHERE user_id = $1", source_id, 0),
+            ("SELECT COUNT(*) FROM comments WHERE user_id = $1", target_id, 1),
+            ("SELECT COUNT(*) FROM users_posts_likes WHERE user_id = $1", target_id, 2),
+            ("SELECT SUM(likes)::BIGINT FROM posts WHERE author_id = $1", target_id, 2),
+            ("SELECT COUNT(*) FROM users_comments_likes WHERE user_id = $1", target_id, 1),
+            ("SELECT COUNT(*) FROM reading_lists WHERE user_id = $1", target_id, 1),
+            (
+                "SELECT COUNT(*) FROM users WHERE id = $1 AND deleted_at IS NOT NULL AND name = 'Deleted User' AND email LIKE 'deleted_%'",
+                source_id,
+                1,
+            ),
+            (
+                "SELECT COUNT(*) FROM audit_logs WHERE action = 'merge_user' AND target_id = $1",
+                source_id,
+                1,
+            ),
+        ] {
+            assert_eq!(count(sql, user_id).await, expected, "{}", sql);
+        }
+
+        for (source, target, status) in [
+            (source_id, target_id, 409),
+            (target_id, target_id, 409),
+            (target_id, uuid::Uuid::new_v4(), 404),
+        ] {
+            let resp = test::call_service(&app, merge(source, target)).await;
+            assert_eq!(resp.status(), status);
+        }
+
+        client
+            .batch_execute(&format!(
+                "
+                DELETE FROM users_comments_likes WHERE comment_id = '{comment_id}';
+                DELETE FROM comments WHERE id = '{comment_id}';
+                DELETE FROM users_posts_likes WHERE post_id IN ('{source_post_id}', '{target_post_id}');
+                DELETE FROM posts WHERE id IN ('{source_post_id}', '{target_post_id}');
+                DELETE FROM audit_logs WHERE target_id = '{source_id}';
+                DELETE FROM users WHERE id IN ('{source_id}', '{target_id}');
+                "
+            ))
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod api_key_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{http::StatusCode, test, web::Data};
+    use navarro_blog_api::{
+        middlewares::api_key_middleware::authorize_api_key,
+        modules::api_key::{
+            api_key_dtos::{InsertApiKeyDTO, POST_READ_SCOPE, POST_WRITE_SCOPE},
+            api_key_repositories::find_api_key_repository,
+            api_key_services::{hash_api_key, insert_api_key_service},
+        },
+    };
+
+    #[test]
+    async fn _api_key_authorization() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+
+        let body = InsertApiKeyDTO {
+            name: String::from("Deploy do blog"),
+            scopes: vec![String::from(POST_READ_SCOPE)],
+            expires_at: None,
+        };
+        let api_key = insert_api_key_service(pg_pool.clone(), user_id.to_string(), body)
+            .await
+            .unwrap();
+        let key = api_key.key.unwrap();
+        let key_hash = hash_api_key(&key);
+
+        let found = find_api_key_repository(pg_pool.clone(), &key_hash)
+            .await
+            .unwrap();
+        let authorized = authorize_api_key(found.clone(), POST_READ_SCOPE).unwrap();
+        assert_eq!(authorized.user_id, user_id.to_string());
+        assert_eq!(
+            authorize_api_key(found, POST_WRITE_SCOPE)
+                .unwrap_err()
+                .status(),
+            StatusCode::FORBIDDEN
+        );
+
+        let unknown = find_api_key_repository(pg_pool.clone(), &hash_api_key("wrong"))
+            .await
+            .unwrap();
+        assert_eq!(
+            authorize_api_key(unknown, POST_READ_SCOPE)
+                .unwrap_err()
+                .status(),
+            StatusCode::UNAUTHORIZED
+        );
+
+        client
+            .execute(
+                "UPDATE users SET deactivated_at = NOW() WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        let deactivated = find_api_key_repository(pg_pool.clone(), &key_hash)
+            .await
+            .unwrap();
+        assert_eq!(
+            authorize_api_key(deactivated, POST_READ_SCOPE)
+                .unwrap_err()
+                .status(),
+            StatusCode::UNAUTHORIZED
+        );
+        client
+            .execute(
+                "UPDATE users SET deactivated_at = NULL WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+
+        client
+            .execute(
+                "UPDATE api_keys SET expires_at = NOW() - INTERVAL '1 minute' WHERE key_hash = $1",
+                &[&key_hash],
+            )
+            .await
+            .unwrap();
+        let expired = find_api_key_repository(pg_pool.clone(), &key_hash)
+            .await
+            .unwrap();
+        assert_eq!(
+            authorize_api_key(expired, POST_READ_SCOPE)
+                .unwrap_err()
+                .status(),
+            StatusCode::UNAUTHORIZED
+        );
+
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _insert_api_key_past_expiration() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let body = InsertApiKeyDTO {
+            name: String::from("Deploy do blog"),
+            scopes: vec![String::from(POST_WRITE_SCOPE)],
+            expires_at: Some(String::from("2000-01-01T00:00:00Z")),
+        };
+        let resp = insert_api_key_service(pg_pool, uuid::Uuid::new_v4().to_string(), body).await;
+        assert_eq!(resp.err().unwrap().status(), StatusCode::BAD_REQUEST);
+    }
+}
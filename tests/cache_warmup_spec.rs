@@ -0,0 +1,76 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod cache_warmup_specs {
+    use crate::mocks::models::{postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::{
+        infra::redis::Redis,
+        middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+        modules::{
+            admin::admin_services::ADMIN_STATS_KEY,
+            cache_warmup::{
+                cache_warmup_repositories::top_post_slugs_repository,
+                cache_warmup_services::{warm_cache_service, CACHE_WARMUP_POSTS_LIMIT},
+            },
+            post::post_services::{ARCHIVE_KEY, EXCERPT_KEY, TRENDING_POSTS_KEY},
+        },
+    };
+
+    #[test]
+    async fn _warm_cache_service() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = Data::new(RedisModels::pool_success(None).await);
+        let client = pg_pool.get().await.unwrap();
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, slug, title, subtitle, body, created_at, published_at) VALUES ($1, $2, 'Warmup', 'sub', 'body', NOW(), NOW())",
+                &[&post_id, &format!("warmup-{}", post_id)],
+            )
+            .await
+            .unwrap();
+
+        let slugs = top_post_slugs_repository(
+            pg_pool.clone(),
+            CACHE_WARMUP_POSTS_LIMIT,
+            uuid::Uuid::parse_str(DEFAULT_TENANT_ID).unwrap(),
+        )
+        .await
+        .unwrap();
+        assert!(!slugs.is_empty());
+        assert!(slugs.len() <= CACHE_WARMUP_POSTS_LIMIT as usize);
+        let excerpt_keys: Vec<String> = slugs
+            .iter()
+            .map(|slug| format!("{}:{}:{}", EXCERPT_KEY, DEFAULT_TENANT_ID, slug))
+            .collect();
+        let keys: Vec<String> = [
+            format!("{}:{}", TRENDING_POSTS_KEY, DEFAULT_TENANT_ID),
+            format!("{}:{}", ARCHIVE_KEY, DEFAULT_TENANT_ID),
+            format!("{}:{}", ADMIN_STATS_KEY, DEFAULT_TENANT_ID),
+        ]
+        .into_iter()
+        .chain(excerpt_keys)
+        .collect();
+        for key in &keys {
+            let _ = Redis::delete(&redis_pool, key).await;
+        }
+
+        let loaded = warm_cache_service(pg_pool.clone(), redis_pool.clone()).await;
+        assert_eq!(loaded, slugs.len());
+        for key in &keys {
+            let cached = Redis::get(&redis_pool, key).await.unwrap();
+            assert!(
+                serde_json::from_str::<serde_json::Value>(&cached).is_ok(),
+                "{}",
+                key
+            );
+        }
+
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod comment_like_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels};
+    use actix_web::{test, web::Data, App};
+    use navarro_blog_api::modules::{
+        comment_like::comment_like_repositories::{
+            like_comment_repository, reconcile_comment_likes_repository, unlike_comment_repository,
+        },
+        post::post_controllers::post_controllers_module,
+    };
+    use serde_json::Value;
+
+    #[test]
+    async fn _comment_likes() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let user_ids: Vec<uuid::Uuid> = (0..3).map(|_| uuid::Uuid::new_v4()).collect();
+        for user_id in &user_ids {
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                    &[user_id, &format!("{}@gmail.com", user_id)],
+                )
+                .await
+                .unwrap();
+        }
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW())",
+                &[&post_id, &user_ids[0]],
+            )
+            .await
+            .unwrap();
+        let (old_id, new_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        for (comment_id, minutes) in [(old_id, 10), (new_id, 1)] {
+            client
+                .execute(
+                    "INSERT INTO comments (id, post_id, user_id, body, created_at) VALUES ($1, $2, $3, 'Comentário', NOW() - make_interval(mins => $4))",
+                    &[&comment_id, &post_id, &user_ids[0], &minutes],
+                )
+                .await
+                .unwrap();
+        }
+
+        for user_id in &user_ids {
+            let liked = like_comment_repository(pg_pool.clone(), *user_id, new_id)
+                .await
+                .unwrap();
+            assert_eq!(liked, Some(true));
+        }
+        let liked = like_comment_repository(pg_pool.clone(), user_ids[0], new_id)
+            .await
+            .unwrap();
+        assert_eq!(liked, Some(false));
+        let unliked = unlike_comment_repository(pg_pool.clone(), user_ids[2], new_id)
+            .await
+            .unwrap();
+        assert_eq!(unliked, Some(true));
+        let missing = like_comment_repository(pg_pool.clone(), user_ids[0], uuid::Uuid::new_v4())
+            .await
+            .unwrap();
+        assert_eq!(missing, None);
+        reconcile_comment_likes_repository(pg_pool.clone(), vec![old_id, new_id])
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/comment?sort=top", post_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(user_ids[1].to_string())
+                ),
+            ))
+            .to_request();
+        let comments: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(comments[0]["id"], new_id.to_string());
+        assert_eq!(comments[0]["likeCount"], 2);
+        assert_eq!(comments[0]["likedByMe"], true);
+        assert_eq!(comments[1]["likeCount"], 0);
+        assert_eq!(comments[1]["likedByMe"], false);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/comment", post_id))
+            .to_request();
+        let comments: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(comments[0]["id"], old_id.to_string());
+        assert!(comments[0].get("likedByMe").is_none());
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/comment?sort=likes", post_id))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+
+        client
+            .execute(
+                "DELETE FROM users_comments_likes WHERE comment_id = ANY($1)",
+                &[&vec![old_id, new_id]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM comments WHERE post_id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,759 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod comment_specs {
+    use crate::mocks::{
+        models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels},
+        test_context::setup_test_context,
+    };
+    use actix_web::{body, test, web, App};
+    use navarro_blog_api::{
+        infra::redis::Redis,
+        modules::{
+            comment::comment_services::{anonymous_comment_rate_key, comment_count_key},
+            comment_like::comment_like_controllers::comment_like_controllers_module,
+            post::post_controllers::post_controllers_module,
+            user::user_controllers::user_controllers_module,
+        },
+    };
+    use serde_json::{json, Value};
+
+    async fn insert_user(client: &deadpool_postgres::Object, name: &str) -> uuid::Uuid {
+        let id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, $2, $3, 'x', NOW())",
+                &[&id, &name, &format!("{}@gmail.com", name)],
+            )
+            .await
+            .unwrap();
+        id
+    }
+
+    #[test]
+    async fn _insert_comment_with_mentions() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+        let client = PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap();
+
+        let author = insert_user(&client, "author").await;
+        let mentioned = insert_user(&client, "navarro").await;
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, created_at) VALUES ($1, 'Rust', 'sub', 'body', NOW())",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/comment", post_id))
+            .append_header((
+                "Authorization",
+                format!("Bearer {}", JwtModels::access_jwt_model(author.to_string())),
+            ))
+            .set_json(json!({ "body": "@navarro @Navarro @ghost @author, veja isso!" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        let comment: Value = test::read_body_json(resp).await;
+
+        let rows = client
+            .query(
+                "SELECT user_id, actor_id, type, payload->>'commentId' AS comment_id FROM notifications",
+                &[],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        let user_id: uuid::Uuid = rows[0].get("user_id");
+        let actor_id: Option<uuid::Uuid> = rows[0].get("actor_id");
+        let notification_type: String = rows[0].get("type");
+        let comment_id: String = rows[0].get("comment_id");
+        assert_eq!(user_id, mentioned);
+        assert_eq!(actor_id, Some(author));
+        assert_eq!(notification_type, "mention");
+        assert_eq!(comment_id, comment["id"].as_str().unwrap());
+    }
+
+    #[test]
+    async fn _insert_comment_error_not_found() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+        let client = PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap();
+        let author = insert_user(&client, "author").await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/comment", uuid::Uuid::new_v4()))
+            .append_header((
+                "Authorization",
+                format!("Bearer {}", JwtModels::access_jwt_model(author.to_string())),
+            ))
+            .set_json(json!({ "body": "Comentário" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+    }
+
+    #[test]
+    async fn _pin_comment() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let client = pg_pool.get().await.unwrap();
+        let author = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let reader = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let (post_id, other_post_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        for id in [post_id, other_post_id] {
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, author_id, created_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW())",
+                    &[&id, &author],
+                )
+                .await
+                .unwrap();
+        }
+        let comment_ids: Vec<uuid::Uuid> = (0..3).map(|_| uuid::Uuid::new_v4()).collect();
+        for (i, comment_id) in comment_ids.iter().enumerate() {
+            client
+                .execute(
+                    "INSERT INTO comments (id, post_id, user_id, body, created_at) VALUES ($1, $2, $3, 'Comentário', NOW() - make_interval(mins => $4))",
+                    &[comment_id, &post_id, &reader, &(3 - i as i32)],
+                )
+                .await
+                .unwrap();
+        }
+        let other_comment_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO comments (id, post_id, user_id, body, created_at) VALUES ($1, $2, $3, 'Comentário', NOW())",
+                &[&other_comment_id, &other_post_id, &reader],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pg_pool.clone()))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let bearer = |user_id: uuid::Uuid| {
+            (
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(user_id.to_string())
+                ),
+            )
+        };
+        let pin_request = |user_id: uuid::Uuid, comment_id: uuid::Uuid| {
+            test::TestRequest::patch()
+                .uri(&format!("/post/{}/pin-comment", post_id))
+                .append_header(bearer(user_id))
+                .set_json(json!({ "comment_id": comment_id.to_string() }))
+                .to_request()
+        };
+        let list_comment_ids = || async {
+            let req = test::TestRequest::get()
+                .uri(&format!("/post/{}/comment", post_id))
+                .to_request();
+            let comments: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+            comments
+                .iter()
+                .map(|comment| {
+                    (
+                        comment["id"].as_str().unwrap().to_string(),
+                        comment["isPinned"].as_bool().unwrap(),
+                    )
+                })
+                .collect::<Vec<(String, bool)>>()
+        };
+
+        let resp = test::call_service(&app, pin_request(reader, comment_ids[1])).await;
+        assert_eq!(resp.status(), 403);
+        let resp = test::call_service(&app, pin_request(author, other_comment_id)).await;
+        assert_eq!(resp.status(), 404);
+
+        let resp = test::call_service(&app, pin_request(author, comment_ids[1])).await;
+        assert_eq!(resp.status(), 204);
+        assert_eq!(
+            list_comment_ids().await,
+            vec![
+                (comment_ids[1].to_string(), true),
+                (comment_ids[0].to_string(), false),
+                (comment_ids[2].to_string(), false),
+            ]
+        );
+
+        let resp = test::call_service(&app, pin_request(author, comment_ids[2])).await;
+        assert_eq!(resp.status(), 204);
+        assert_eq!(
+            list_comment_ids().await,
+            vec![
+                (comment_ids[2].to_string(), true),
+                (comment_ids[0].to_string(), false),
+                (comment_ids[1].to_string(), false),
+            ]
+        );
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/post/{}/pin-comment", post_id))
+            .append_header(bearer(author))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 204);
+        assert_eq!(
+            list_comment_ids().await,
+            comment_ids
+                .iter()
+                .map(|id| (id.to_string(), false))
+                .collect::<Vec<(String, bool)>>()
+        );
+
+        let post_ids = vec![post_id, other_post_id];
+        client
+            .execute("DELETE FROM comments WHERE post_id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM users WHERE id = ANY($1)",
+                &[&vec![author, reader]],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _insert_anonymous_comment() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let redis_pool = RedisModels::pool_success(None).await;
+        let client = pg_pool.get().await.unwrap();
+        let author = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW())",
+                &[&post_id, &author],
+            )
+            .await
+            .unwrap();
+        let octets = post_id.as_bytes();
+        let (ip, other_ip) = (
+            format!("10.{}.{}.1", octets[0], octets[1]),
+            format!("10.{}.{}.2", octets[0], octets[1]),
+        );
+        for ip in [&ip, &other_ip] {
+            let _ = Redis::delete(&redis_pool, &anonymous_comment_rate_key(ip)).await;
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pg_pool.clone()))
+                .app_data(web::Data::new(redis_pool.clone()))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let comment_request = |ip: &str, body: Value| {
+            test::TestRequest::post()
+                .uri(&format!("/post/{}/comment", post_id))
+                .peer_addr(format!("{}:4321", ip).parse().unwrap())
+                .set_json(body)
+                .to_request()
+        };
+        let guest = json!({
+            "body": "Ótimo post!",
+            "guestName": "Maria",
+            "guestEmail": "maria@gmail.com"
+        });
+        let count_comments = || async {
+            client
+                .query_one(
+                    "SELECT COUNT(*) AS count FROM comments WHERE post_id = $1",
+                    &[&post_id],
+                )
+                .await
+                .unwrap()
+                .get::<_, i64>("count")
+        };
+
+        let resp = test::call_service(
+            &app,
+            comment_request(&ip, json!({ "body": "Sem nome", "guestName": "M" })),
+        )
+        .await;
+        assert_eq!(resp.status(), 400);
+        let resp =
+            test::call_service(&app, comment_request(&ip, json!({ "body": "Sem nome" }))).await;
+        assert_eq!(resp.status(), 400);
+
+        let mut spam = guest.clone();
+        spam["website"] = json!("https://spam.example");
+        let resp = test::call_service(&app, comment_request(&ip, spam)).await;
+        assert_eq!(resp.status(), 201);
+        assert!(body::to_bytes(resp.into_body()).await.unwrap().is_empty());
+        assert_eq!(count_comments().await, 0);
+
+        let resp = test::call_service(&app, comment_request(&ip, guest.clone())).await;
+        assert_eq!(resp.status(), 201);
+        let comment: Value = test::read_body_json(resp).await;
+        assert!(comment["userId"].is_null());
+        assert_eq!(comment["guestName"], "Maria");
+        assert!(comment.get("guestEmail").is_none());
+
+        for _ in 0..4 {
+            let resp = test::call_service(&app, comment_request(&ip, guest.clone())).await;
+            assert_eq!(resp.status(), 201);
+        }
+        let resp = test::call_service(&app, comment_request(&ip, guest.clone())).await;
+        assert_eq!(resp.status(), 429);
+        assert_eq!(count_comments().await, 5);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/comment", post_id))
+            .to_request();
+        let comments: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        assert!(comments
+            .iter()
+            .all(|comment| comment["guestName"] == "Maria" && comment["userId"].is_null()));
+
+        client
+            .execute(
+                "UPDATE posts SET allow_anonymous_comments = FALSE WHERE id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        let resp = test::call_service(&app, comment_request(&other_ip, guest.clone())).await;
+        assert_eq!(resp.status(), 403);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/comment", post_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(author.to_string())
+                ),
+            ))
+            .set_json(json!({ "body": "Comentário do autor", "website": "https://navarro.tech" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        assert_eq!(count_comments().await, 6);
+
+        for ip in [&ip, &other_ip] {
+            let _ = Redis::delete(&redis_pool, &anonymous_comment_rate_key(ip)).await;
+        }
+        client
+            .execute("DELETE FROM comments WHERE post_id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&author])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _count_comments() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let redis_pool = RedisModels::pool_success(None).await;
+        let client = pg_pool.get().await.unwrap();
+        let author = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let post_ids = [uuid::Uuid::new_v4(), uuid::Uuid::new_v4()];
+        let slugs: Vec<String> = post_ids
+            .iter()
+            .map(|id| format!("rust-{}", id.simple()))
+            .collect();
+        for (id, slug) in post_ids.iter().zip(&slugs) {
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, author_id, slug, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', $2, $3, NOW(), NOW())",
+                    &[id, &author, slug],
+                )
+                .await
+                .unwrap();
+        }
+        for deleted_at in [None, None, Some(chrono::Utc::now())] {
+            client
+                .execute(
+                    "INSERT INTO comments (id, post_id, user_id, body, created_at, deleted_at) VALUES ($1, $2, $3, 'Comentário', NOW(), $4)",
+                    &[&uuid::Uuid::new_v4(), &post_ids[0], &author, &deleted_at],
+                )
+                .await
+                .unwrap();
+        }
+        let key = comment_count_key(&post_ids[0].to_string());
+        let _ = Redis::delete(&redis_pool, &key).await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pg_pool.clone()))
+                .app_data(web::Data::new(redis_pool.clone()))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let count_request = || {
+            test::TestRequest::get()
+                .uri(&format!("/post/{}/comments/count", slugs[0]))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, count_request()).await;
+        assert_eq!(resp.status(), 200);
+        let count: Value = test::read_body_json(resp).await;
+        assert_eq!(count, json!({ "count": 2 }));
+        assert_eq!(Redis::get(&redis_pool, &key).await.unwrap(), "2");
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/comment", post_ids[0]))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(author.to_string())
+                ),
+            ))
+            .set_json(json!({ "body": "Mais um comentário" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        assert_eq!(Redis::get(&redis_pool, &key).await.unwrap(), "3");
+        let resp = test::call_service(&app, count_request()).await;
+        let count: Value = test::read_body_json(resp).await;
+        assert_eq!(count, json!({ "count": 3 }));
+
+        let req = test::TestRequest::get()
+            .uri("/post/slug-inexistente/comments/count")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+
+        let req = test::TestRequest::post()
+            .uri("/post/comments/count")
+            .set_json(json!({ "slugs": [slugs[0], slugs[1], "slug-inexistente"] }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let counts: Value = test::read_body_json(resp).await;
+        assert_eq!(counts, json!({ slugs[0].clone(): 3, slugs[1].clone(): 0 }));
+
+        for slugs in [vec![], vec![slugs[0].clone(); 51]] {
+            let req = test::TestRequest::post()
+                .uri("/post/comments/count")
+                .set_json(json!({ "slugs": slugs }))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), 400);
+        }
+
+        let _ = Redis::delete(&redis_pool, &key).await;
+        client
+            .execute(
+                "DELETE FROM comments WHERE post_id = ANY($1)",
+                &[&post_ids.to_vec()],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM posts WHERE id = ANY($1)",
+                &[&post_ids.to_vec()],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&author])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _comment_moderation() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let redis_pool = RedisModels::pool_success(None).await;
+        let client = pg_pool.get().await.unwrap();
+        let author = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let reader = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let admin = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, moderation_mode, created_at) VALUES ($1, 'Rust', 'sub', 'body', $2, 'require_approval', NOW())",
+                &[&post_id, &author],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pg_pool.clone()))
+                .app_data(web::Data::new(redis_pool.clone()))
+                .service(post_controllers_module())
+                .service(comment_like_controllers_module()),
+        )
+        .await;
+        let bearer = |user_id: uuid::Uuid| {
+            let token = match user_id == admin {
+                true => JwtModels::access_jwt_model(user_id.to_string()),
+                false => JwtModels::user_access_jwt_model(user_id.to_string()),
+            };
+            ("Authorization", format!("Bearer {}", token))
+        };
+        let insert_comment = |body: &str| {
+            test::TestRequest::post()
+                .uri(&format!("/post/{}/comment", post_id))
+                .append_header(bearer(reader))
+                .set_json(json!({ "body": body }))
+                .to_request()
+        };
+        let moderate_request = |user_id: uuid::Uuid, comment_id: &str, action: &str| {
+            test::TestRequest::patch()
+                .uri(&format!("/comment/{}/{}", comment_id, action))
+                .append_header(bearer(user_id))
+                .to_request()
+        };
+        let list_comment_ids = || async {
+            let req = test::TestRequest::get()
+                .uri(&format!("/post/{}/comment", post_id))
+                .to_request();
+            let comments: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+            comments
+                .iter()
+                .map(|comment| comment["id"].as_str().unwrap().to_string())
+                .collect::<Vec<String>>()
+        };
+        let pending_request = |user_id: uuid::Uuid| {
+            test::TestRequest::get()
+                .uri(&format!("/post/{}/comments/pending", post_id))
+                .append_header(bearer(user_id))
+                .to_request()
+        };
+
+        let admin_username: String = client
+            .query_one("SELECT username FROM users WHERE id = $1", &[&admin])
+            .await
+            .unwrap()
+            .get("username");
+        let count_mentions = || async {
+            client
+                .query_one(
+                    "SELECT COUNT(*) AS count FROM notifications WHERE user_id = $1 AND actor_id = $2 AND type = 'mention'",
+                    &[&admin, &reader],
+                )
+                .await
+                .unwrap()
+                .get::<_, i64>("count")
+        };
+
+        let resp = test::call_service(
+            &app,
+            insert_comment(&format!("Aguardando aprovação @{}", admin_username)),
+        )
+        .await;
+        assert_eq!(resp.status(), 201);
+        let comment: Value = test::read_body_json(resp).await;
+        let comment_id = comment["id"].as_str().unwrap().to_string();
+        assert_eq!(comment["approvedAt"], Value::Null);
+        assert!(list_comment_ids().await.is_empty());
+        assert_eq!(count_mentions().await, 0);
+
+        let resp = test::call_service(&app, pending_request(reader)).await;
+        assert_eq!(resp.status(), 403);
+        let resp = test::call_service(&app, pending_request(author)).await;
+        assert_eq!(resp.status(), 200);
+        let pending: Vec<Value> = test::read_body_json(resp).await;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0]["id"], comment_id.as_str());
+
+        let resp = test::call_service(&app, moderate_request(reader, &comment_id, "approve")).await;
+        assert_eq!(resp.status(), 403);
+        let resp = test::call_service(&app, moderate_request(author, &comment_id, "approve")).await;
+        assert_eq!(resp.status(), 200);
+        let approved: Value = test::read_body_json(resp).await;
+        assert!(approved["approvedAt"].is_string());
+        assert_eq!(list_comment_ids().await, vec![comment_id.clone()]);
+        assert_eq!(count_mentions().await, 1);
+        let resp = test::call_service(&app, moderate_request(author, &comment_id, "approve")).await;
+        assert_eq!(resp.status(), 409);
+
+        let rows = client
+            .query(
+                "SELECT actor_id, type, payload->>'commentId' AS comment_id FROM notifications WHERE user_id = $1",
+                &[&reader],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        let actor_id: Option<uuid::Uuid> = rows[0].get("actor_id");
+        let notification_type: String = rows[0].get("type");
+        let notified_comment_id: String = rows[0].get("comment_id");
+        assert_eq!(actor_id, Some(author));
+        assert_eq!(notification_type, "comment_approved");
+        assert_eq!(notified_comment_id, comment_id);
+
+        let resp =
+            test::call_service(&app, insert_comment(&format!("Spam @{}", admin_username))).await;
+        let rejected: Value = test::read_body_json(resp).await;
+        let rejected_id = rejected["id"].as_str().unwrap().to_string();
+        let resp = test::call_service(&app, moderate_request(admin, &rejected_id, "reject")).await;
+        assert_eq!(resp.status(), 204);
+        let resp = test::call_service(&app, moderate_request(admin, &rejected_id, "reject")).await;
+        assert_eq!(resp.status(), 404);
+        let resp = test::call_service(&app, pending_request(author)).await;
+        let pending: Vec<Value> = test::read_body_json(resp).await;
+        assert!(pending.is_empty());
+        assert_eq!(list_comment_ids().await, vec![comment_id]);
+        assert_eq!(count_mentions().await, 1);
+
+        client
+            .execute(
+                "DELETE FROM notifications WHERE user_id = ANY($1)",
+                &[&vec![reader, admin]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM comments WHERE post_id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM users WHERE id = ANY($1)",
+                &[&vec![author, reader, admin]],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _insert_comment_mentions_autocompleted_username() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let redis_pool = RedisModels::pool_success(None).await;
+        let client = pg_pool.get().await.unwrap();
+        let prefix = format!("m{}", &uuid::Uuid::new_v4().simple().to_string()[..12]);
+        let author = insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        let mentioned = insert_user(&client, &format!("{}_fulano_name", prefix)).await;
+        client
+            .execute(
+                "UPDATE users SET username = $2 WHERE id = $1",
+                &[&mentioned, &format!("{}_fulano", prefix)],
+            )
+            .await
+            .unwrap();
+        // A user named like the mention, but with another username, is not the one mentioned.
+        insert_user(&client, &format!("{}_named", prefix)).await;
+        // A user of another tenant is never mentioned from a post of the default tenant.
+        let tenant_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        let other_tenant_user =
+            insert_user(&client, &uuid::Uuid::new_v4().simple().to_string()).await;
+        client
+            .execute(
+                "UPDATE users SET username = $2, tenant_id = $3 WHERE id = $1",
+                &[&other_tenant_user, &format!("{}_other", prefix), &tenant_id],
+            )
+            .await
+            .unwrap();
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, created_at) VALUES ($1, 'Rust', 'sub', 'body', NOW())",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pg_pool.clone()))
+                .app_data(web::Data::new(redis_pool.clone()))
+                .service(user_controllers_module())
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/autocomplete?q={}", prefix))
+            .to_request();
+        let suggestions: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        let usernames = suggestions
+            .iter()
+            .map(|suggestion| suggestion["username"].as_str().unwrap().to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(usernames, vec![format!("{}_fulano", prefix)]);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/comment", post_id))
+            .append_header((
+                "Authorization",
+                format!("Bearer {}", JwtModels::user_access_jwt_model(author.to_string())),
+            ))
+            .set_json(json!({
+                "body": format!("@{} @{}_named @{}_other", usernames[0].to_uppercase(), prefix, prefix)
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        let comment: Value = test::read_body_json(resp).await;
+
+        let rows = client
+            .query(
+                "SELECT user_id FROM notifications WHERE type = 'mention' AND payload->>'commentId' = $1",
+                &[&comment["id"].as_str().unwrap()],
+            )
+            .await
+            .unwrap();
+        let user_ids = rows
+            .iter()
+            .map(|row| row.get("user_id"))
+            .collect::<Vec<uuid::Uuid>>();
+        assert_eq!(user_ids, vec![mentioned]);
+    }
+}
@@ -0,0 +1,460 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod content_report_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{body, test, web::Data, App};
+    use navarro_blog_api::{
+        infra::redis::Redis,
+        modules::{
+            admin::admin_controllers::admin_controllers_module,
+            comment_like::comment_like_controllers::comment_like_controllers_module,
+            content_report::{
+                content_report_dtos::ReportedAuthorDTO,
+                content_report_repositories::{
+                    content_report_stats_repository, insert_content_report_repository,
+                    resolve_content_report_repository,
+                },
+                content_report_services::{
+                    report_content_service, report_rate_key, CONTENT_REPORT_STATS_KEY,
+                    MAX_REPORTS_PER_DAY,
+                },
+            },
+            metrics::metrics_controllers::metrics_controllers_module,
+            post::post_controllers::post_controllers_module,
+        },
+    };
+    use serde_json::{json, Value};
+
+    async fn seed() -> (uuid::Uuid, uuid::Uuid, uuid::Uuid, uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let (author_id, reporter_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        for user_id in [author_id, reporter_id] {
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                    &[&user_id, &format!("{}@gmail.com", user_id)],
+                )
+                .await
+                .unwrap();
+        }
+        let (post_id, comment_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), NOW())",
+                &[&post_id, &author_id],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO comments (id, post_id, user_id, body, created_at) VALUES ($1, $2, $3, 'Comentário', NOW())",
+                &[&comment_id, &post_id, &author_id],
+            )
+            .await
+            .unwrap();
+        (author_id, reporter_id, post_id, comment_id)
+    }
+
+    async fn cleanup(author_id: uuid::Uuid, reporter_id: uuid::Uuid, post_id: uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM comments WHERE post_id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM users WHERE id = $1 OR id = $2",
+                &[&author_id, &reporter_id],
+            )
+            .await
+            .unwrap();
+    }
+
+    fn bearer(jwt: String) -> (&'static str, String) {
+        ("Authorization", format!("Bearer {}", jwt))
+    }
+
+    #[test]
+    async fn _report_content() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, reporter_id, post_id, comment_id) = seed().await;
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module())
+                .service(comment_like_controllers_module())
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let reporter_jwt = JwtModels::user_access_jwt_model(reporter_id.to_string());
+        let reason = json!({ "reason": "Conteúdo ofensivo contra outros leitores." });
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/report", post_id))
+            .append_header(bearer(reporter_jwt.clone()))
+            .set_json(json!({ "reason": "Spam" }))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 400);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/report", post_id))
+            .append_header(bearer(reporter_jwt.clone()))
+            .set_json(&reason)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        let report: Value =
+            serde_json::from_slice(&body::to_bytes(resp.into_body()).await.unwrap()).unwrap();
+        assert_eq!(report["entityType"], "post");
+        assert_eq!(report["status"], "pending");
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/report", post_id))
+            .append_header(bearer(reporter_jwt.clone()))
+            .set_json(&reason)
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 409);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/comment/{}/report", comment_id))
+            .append_header(bearer(reporter_jwt.clone()))
+            .set_json(&reason)
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 201);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/comment/{}/report", uuid::Uuid::new_v4()))
+            .append_header(bearer(reporter_jwt.clone()))
+            .set_json(&reason)
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 404);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/reports?status=pending")
+            .append_header(bearer(reporter_jwt))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 403);
+
+        let admin_jwt = JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let req = test::TestRequest::get()
+            .uri("/admin/reports?status=archived")
+            .append_header(bearer(admin_jwt.clone()))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 400);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/reports?status=pending")
+            .append_header(bearer(admin_jwt.clone()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let reports: Vec<Value> =
+            serde_json::from_slice(&body::to_bytes(resp.into_body()).await.unwrap()).unwrap();
+        assert!(reports
+            .iter()
+            .any(|report| report["entityId"] == comment_id.to_string()));
+        assert!(reports.iter().all(|report| report["status"] == "pending"));
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/admin/report/{}/resolve", uuid::Uuid::new_v4()))
+            .append_header(bearer(admin_jwt))
+            .set_json(json!({ "deleteContent": true }))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 404);
+
+        cleanup(author_id, reporter_id, post_id).await;
+    }
+
+    #[test]
+    async fn _resolve_content_report() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, reporter_id, post_id, comment_id) = seed().await;
+        let client = pg_pool.get().await.unwrap();
+        let (post_report_id, comment_report_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        for (report_id, entity_type, entity_id) in [
+            (post_report_id, "post", post_id),
+            (comment_report_id, "comment", comment_id),
+        ] {
+            client
+                .execute(
+                    "INSERT INTO content_reports (id, reporter_id, entity_type, entity_id, reason) VALUES ($1, $2, $3, $4, 'Conteúdo ofensivo')",
+                    &[&report_id, &reporter_id, &entity_type, &entity_id],
+                )
+                .await
+                .unwrap();
+        }
+        let admin_id = uuid::Uuid::new_v4();
+
+        let report =
+            resolve_content_report_repository(pg_pool.clone(), comment_report_id, admin_id, true)
+                .await
+                .unwrap();
+        assert_eq!(report.status, "resolved");
+        assert_eq!(report.resolved_by, Some(admin_id.to_string()));
+        assert!(report.resolved_at.is_some());
+        let row = client
+            .query_one(
+                "SELECT deleted_at IS NOT NULL AS deleted FROM comments WHERE id = $1",
+                &[&comment_id],
+            )
+            .await
+            .unwrap();
+        assert!(row.get::<_, bool>("deleted"));
+
+        resolve_content_report_repository(pg_pool.clone(), post_report_id, admin_id, false)
+            .await
+            .unwrap();
+        let row = client
+            .query_one(
+                "SELECT deleted_at IS NOT NULL AS deleted FROM posts WHERE id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        assert!(!row.get::<_, bool>("deleted"));
+
+        let resp =
+            resolve_content_report_repository(pg_pool.clone(), post_report_id, admin_id, true)
+                .await;
+        assert_eq!(resp.err().unwrap().status(), 409);
+
+        cleanup(author_id, reporter_id, post_id).await;
+    }
+
+    #[test]
+    async fn _report_content_entity_window() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, reporter_id, post_id, comment_id) = seed().await;
+        let client = pg_pool.get().await.unwrap();
+        let reason = || String::from("Conteúdo ofensivo contra outros leitores.");
+
+        let report = insert_content_report_repository(
+            pg_pool.clone(),
+            reporter_id,
+            "post",
+            post_id,
+            reason(),
+        )
+        .await
+        .unwrap();
+        let resp = insert_content_report_repository(
+            pg_pool.clone(),
+            reporter_id,
+            "post",
+            post_id,
+            reason(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 409);
+
+        resolve_content_report_repository(
+            pg_pool.clone(),
+            uuid::Uuid::parse_str(&report.id).unwrap(),
+            uuid::Uuid::new_v4(),
+            false,
+        )
+        .await
+        .unwrap();
+        assert!(insert_content_report_repository(
+            pg_pool.clone(),
+            reporter_id,
+            "post",
+            post_id,
+            reason()
+        )
+        .await
+        .is_ok());
+
+        client
+            .execute(
+                "INSERT INTO content_reports (id, reporter_id, entity_type, entity_id, reason, created_at) VALUES ($1, $2, 'comment', $3, 'Conteúdo ofensivo', NOW() - INTERVAL '8 days')",
+                &[&uuid::Uuid::new_v4(), &reporter_id, &comment_id],
+            )
+            .await
+            .unwrap();
+        assert!(insert_content_report_repository(
+            pg_pool.clone(),
+            reporter_id,
+            "comment",
+            comment_id,
+            reason()
+        )
+        .await
+        .is_ok());
+
+        cleanup(author_id, reporter_id, post_id).await;
+    }
+
+    #[test]
+    async fn _report_content_rate_limit() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = RedisModels::pool_success(None).await;
+        let (author_id, reporter_id, post_id, comment_id) = seed().await;
+        let key = report_rate_key(&reporter_id.to_string());
+        let reason = || String::from("Conteúdo ofensivo contra outros leitores.");
+
+        assert!(report_content_service(
+            pg_pool.clone(),
+            &redis_pool,
+            reporter_id.to_string(),
+            "post",
+            post_id.to_string(),
+            reason(),
+        )
+        .await
+        .is_ok());
+        assert_eq!(Redis::get(&redis_pool, &key).await.unwrap(), "1");
+
+        Redis::set(&redis_pool, &key, &MAX_REPORTS_PER_DAY.to_string())
+            .await
+            .unwrap();
+        let resp = report_content_service(
+            pg_pool.clone(),
+            &redis_pool,
+            reporter_id.to_string(),
+            "comment",
+            comment_id.to_string(),
+            reason(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 429);
+
+        Redis::delete(&redis_pool, &key).await.unwrap();
+        cleanup(author_id, reporter_id, post_id).await;
+    }
+
+    #[test]
+    async fn _content_report_stats() {
+        dotenv::dotenv().ok();
+        std::env::set_var("METRICS_TOKEN", "prometheus-scrape-token");
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = RedisModels::pool_success(None).await;
+        let (author_id, reporter_id, post_id, comment_id) = seed().await;
+        let client = pg_pool.get().await.unwrap();
+        let before = content_report_stats_repository(pg_pool.clone())
+            .await
+            .unwrap();
+
+        for (entity_type, entity_id, status, hours) in [
+            ("post", post_id, "pending", 1),
+            ("post", post_id, "pending", 2),
+            ("comment", comment_id, "pending", 3),
+            ("comment", comment_id, "resolved", 10),
+        ] {
+            client
+                .execute(
+                    "INSERT INTO content_reports (id, reporter_id, entity_type, entity_id, reason, status, created_at, resolved_at) VALUES ($1, $2, $3, $4, 'Conteúdo ofensivo', $5, NOW() - make_interval(hours => $6), CASE WHEN $5 = 'resolved' THEN NOW() END)",
+                    &[&uuid::Uuid::new_v4(), &reporter_id, &entity_type, &entity_id, &status, &hours],
+                )
+                .await
+                .unwrap();
+        }
+
+        let stats = content_report_stats_repository(pg_pool.clone())
+            .await
+            .unwrap();
+        assert!(stats.total_pending >= before.total_pending + 3);
+        assert!(stats.reports_by_type.post >= before.reports_by_type.post + 2);
+        assert!(stats.reports_by_type.comment >= before.reports_by_type.comment + 2);
+        assert!(stats.avg_resolution_time_hours > 0.0);
+        assert!(stats.most_reported_authors.contains(&ReportedAuthorDTO {
+            author_id: author_id.to_string(),
+            report_count: 4,
+        }));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(redis_pool.clone()))
+                .service(admin_controllers_module())
+                .configure(metrics_controllers_module),
+        )
+        .await;
+        let _ = Redis::delete(&redis_pool, CONTENT_REPORT_STATS_KEY).await;
+        let req = test::TestRequest::get()
+            .uri("/admin/reports/stats")
+            .append_header(bearer(JwtModels::user_access_jwt_model(
+                reporter_id.to_string(),
+            )))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/reports/stats")
+            .append_header(bearer(JwtModels::access_jwt_model(reporter_id.to_string())))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let body: Value = test::read_body_json(resp).await;
+        assert!(body["totalPending"].as_i64().unwrap() >= 3);
+        assert!(body["reportsByType"]["post"].as_i64().unwrap() >= 2);
+        assert!(body["mostReportedAuthors"]
+            .as_array()
+            .unwrap()
+            .contains(&json!({ "authorId": author_id.to_string(), "reportCount": 4 })));
+        let cached = Redis::get(&redis_pool, CONTENT_REPORT_STATS_KEY)
+            .await
+            .unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&cached).unwrap(), body);
+
+        let req = test::TestRequest::get().uri("/metrics").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+
+        let req = test::TestRequest::get()
+            .uri("/metrics")
+            .append_header(bearer(String::from("wrong-scrape-token")))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 401);
+
+        let req = test::TestRequest::get()
+            .uri("/metrics")
+            .append_header(bearer(JwtModels::user_access_jwt_model(
+                reporter_id.to_string(),
+            )))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        let req = test::TestRequest::get()
+            .uri("/metrics")
+            .append_header(bearer(JwtModels::access_jwt_model(reporter_id.to_string())))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let req = test::TestRequest::get()
+            .uri("/metrics")
+            .append_header(bearer(String::from("prometheus-scrape-token")))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let metrics = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&metrics).contains(&format!(
+            "moderation_pending_reports {}\n",
+            body["totalPending"]
+        )));
+
+        let _ = Redis::delete(&redis_pool, CONTENT_REPORT_STATS_KEY).await;
+        client
+            .execute(
+                "DELETE FROM content_reports WHERE reporter_id = $1",
+                &[&reporter_id],
+            )
+            .await
+            .unwrap();
+        cleanup(author_id, reporter_id, post_id).await;
+    }
+}
@@ -0,0 +1,180 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod dead_letter_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{test, web::Data, web::Json, App};
+    use navarro_blog_api::{
+        infra::redis::Redis,
+        middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+        modules::{
+            admin::admin_controllers::admin_controllers_module,
+            dead_letter::{
+                dead_letter_dtos::FailedQueueItemDTO,
+                dead_letter_services::reprocess_dead_letter_items,
+            },
+            user::{
+                user_dtos::InsertUserDTO,
+                user_queues::{drain_insert_user_queue, InsertUserAppQueue},
+            },
+        },
+    };
+    use std::{sync::Arc, time::Duration};
+
+    #[test]
+    async fn _reprocess_dead_letter_items() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = Data::new(RedisModels::pool_success(None).await);
+        let client = pg_pool.get().await.unwrap();
+        let taken_id = uuid::Uuid::new_v4();
+        let email = format!("{}@gmail.com", taken_id.simple());
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'taken', $2, 'x', NOW())",
+                &[&taken_id, &email],
+            )
+            .await
+            .unwrap();
+
+        let user_id = uuid::Uuid::new_v4();
+        let queue = Arc::new(InsertUserAppQueue::new());
+        queue.push((
+            user_id.to_string(),
+            Json(InsertUserDTO {
+                name: String::from("Dead Letter"),
+                username: format!("dead_letter_{}", &user_id.simple().to_string()[..8]),
+                email,
+                password: String::from("hashed"),
+                invite_code: None,
+            }),
+            chrono::Utc::now().to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            String::from(DEFAULT_TENANT_ID),
+        ));
+        drain_insert_user_queue(
+            pg_pool.get_ref().clone(),
+            queue.clone(),
+            Duration::from_secs(5),
+        )
+        .await;
+        let row = client
+            .query_one(
+                "SELECT queue, attempts, permanently_failed FROM failed_queue_items WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(row.get::<_, String>("queue"), "insert_user");
+        assert_eq!(row.get::<_, i32>("attempts"), 0);
+
+        let exhausted_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO failed_queue_items (id, queue, payload, error, attempts) VALUES ($1, 'insert_user', '{}'::JSONB, 'erro', 5)",
+                &[&exhausted_id],
+            )
+            .await
+            .unwrap();
+
+        let _ = Redis::delete(&redis_pool, "queue:dead-letter:lock").await;
+        let requeued =
+            reprocess_dead_letter_items(pg_pool.clone(), redis_pool.clone(), queue.clone())
+                .await
+                .unwrap();
+        assert!(requeued >= 1);
+        assert!(!queue.is_empty());
+        let row = client
+            .query_one(
+                "SELECT attempts, last_attempt_at IS NOT NULL AS attempted FROM failed_queue_items WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(row.get::<_, i32>("attempts"), 1);
+        assert!(row.get::<_, bool>("attempted"));
+        let requeued =
+            reprocess_dead_letter_items(pg_pool.clone(), redis_pool.clone(), queue.clone())
+                .await
+                .unwrap();
+        assert_eq!(requeued, 0);
+
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&taken_id])
+            .await
+            .unwrap();
+        let only_ours = Arc::new(InsertUserAppQueue::new());
+        while !queue.is_empty() {
+            let event = queue.pop().await;
+            if event.0 == user_id.to_string() {
+                only_ours.push(event);
+            }
+        }
+        drain_insert_user_queue(pg_pool.get_ref().clone(), only_ours, Duration::from_secs(5)).await;
+        let rows = client
+            .query(
+                "SELECT id FROM failed_queue_items WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert!(rows.is_empty());
+        let rows = client
+            .query("SELECT id FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(redis_pool.clone())
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let jwt = JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let req = test::TestRequest::get()
+            .uri("/admin/queue/dead-letter")
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let items: Vec<FailedQueueItemDTO> = test::call_and_read_body_json(&app, req).await;
+        let exhausted = items
+            .iter()
+            .find(|item| item.id == exhausted_id.to_string())
+            .unwrap();
+        assert!(exhausted.permanently_failed);
+        assert_eq!(exhausted.attempts, 5);
+        assert!(items.iter().all(|item| item.id != user_id.to_string()));
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/admin/queue/dead-letter/{}", exhausted_id))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 204);
+        let req = test::TestRequest::delete()
+            .uri(&format!("/admin/queue/dead-letter/{}", exhausted_id))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 404);
+        let req = test::TestRequest::get()
+            .uri("/admin/queue/dead-letter")
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string())
+                ),
+            ))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 403);
+
+        client
+            .execute("DELETE FROM salt WHERE user_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod draft_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{body, test, web::Data};
+    use navarro_blog_api::modules::draft::{
+        draft_dtos::PutDraftDTO,
+        draft_services::{detail_draft_service, promote_draft_service, put_draft_service},
+    };
+
+    async fn seed_user() -> uuid::Uuid {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        user_id
+    }
+
+    async fn cleanup(user_ids: &[uuid::Uuid]) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM posts WHERE author_id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+    }
+
+    fn draft_body(title: &str, body: &str) -> PutDraftDTO {
+        PutDraftDTO {
+            post_id: None,
+            title: String::from(title),
+            body: String::from(body),
+            summary: None,
+            structured_data: None,
+        }
+    }
+
+    #[test]
+    async fn _put_draft() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let author_id = seed_user().await;
+        let draft_id = uuid::Uuid::new_v4().to_string();
+
+        let draft = put_draft_service(
+            pg_pool.clone(),
+            draft_id.clone(),
+            author_id.to_string(),
+            draft_body("Ru", "first version"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(draft.title, "Ru");
+
+        put_draft_service(
+            pg_pool.clone(),
+            draft_id.clone(),
+            author_id.to_string(),
+            draft_body("Rust", "second version"),
+        )
+        .await
+        .unwrap();
+        let draft = detail_draft_service(pg_pool, draft_id, author_id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(draft.title, "Rust");
+        assert_eq!(draft.body, "second version");
+
+        cleanup(&[author_id]).await;
+    }
+
+    #[test]
+    async fn _draft_error_forbidden() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let author_id = seed_user().await;
+        let other_id = seed_user().await;
+        let draft_id = uuid::Uuid::new_v4().to_string();
+
+        put_draft_service(
+            pg_pool.clone(),
+            draft_id.clone(),
+            author_id.to_string(),
+            draft_body("Rust", "body"),
+        )
+        .await
+        .unwrap();
+
+        let put = put_draft_service(
+            pg_pool.clone(),
+            draft_id.clone(),
+            other_id.to_string(),
+            draft_body("Hijack", "body"),
+        )
+        .await;
+        assert_eq!(put.err().unwrap().status(), 403);
+
+        let detail = detail_draft_service(pg_pool, draft_id, other_id.to_string()).await;
+        assert_eq!(detail.err().unwrap().status(), 403);
+
+        cleanup(&[author_id, other_id]).await;
+    }
+
+    #[test]
+    async fn _promote_draft() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let author_id = seed_user().await;
+        let draft_id = uuid::Uuid::new_v4().to_string();
+
+        put_draft_service(
+            pg_pool.clone(),
+            draft_id.clone(),
+            author_id.to_string(),
+            draft_body("Ru", "short"),
+        )
+        .await
+        .unwrap();
+        let promote =
+            promote_draft_service(pg_pool.clone(), draft_id.clone(), author_id.to_string()).await;
+        assert_eq!(promote.err().unwrap().status(), 400);
+
+        put_draft_service(
+            pg_pool.clone(),
+            draft_id.clone(),
+            author_id.to_string(),
+            draft_body("Rust e Actix Web", "Um corpo grande o suficiente"),
+        )
+        .await
+        .unwrap();
+        let promoted =
+            promote_draft_service(pg_pool.clone(), draft_id.clone(), author_id.to_string())
+                .await
+                .unwrap();
+        assert!(promoted.warnings.is_empty());
+        let post_id = promoted.id;
+        assert!(uuid::Uuid::parse_str(&post_id).is_ok());
+
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let rows = client
+            .query(
+                "SELECT slug, summary FROM posts WHERE id = $1::TEXT::UUID",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        let slug: String = rows[0].get("slug");
+        assert_eq!(slug, format!("rust-e-actix-web-{}", &post_id[..8]));
+        let summary: Option<String> = rows[0].get("summary");
+        assert_eq!(summary.unwrap(), "Um corpo grande o suficiente");
+
+        let detail = detail_draft_service(pg_pool, draft_id, author_id.to_string()).await;
+        assert_eq!(detail.err().unwrap().status(), 404);
+
+        cleanup(&[author_id]).await;
+    }
+
+    #[test]
+    async fn _promote_draft_error_conflict() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let author_id = seed_user().await;
+        let other_id = seed_user().await;
+
+        let mut post_ids = Vec::with_capacity(3);
+        for user_id in [author_id, author_id, other_id] {
+            let draft_id = uuid::Uuid::new_v4().to_string();
+            put_draft_service(
+                pg_pool.clone(),
+                draft_id.clone(),
+                user_id.to_string(),
+                draft_body("Rust e Actix Web", "Um corpo grande o suficiente"),
+            )
+            .await
+            .unwrap();
+            post_ids
+                .push(promote_draft_service(pg_pool.clone(), draft_id, user_id.to_string()).await);
+        }
+
+        let first_id = post_ids.remove(0).unwrap().id;
+        let conflict = post_ids.remove(0).err().unwrap();
+        assert_eq!(conflict.status(), 409);
+        let bytes = body::to_bytes(conflict.into_body()).await.unwrap();
+        let error: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(error["post"][0]["params"]["value"], first_id);
+        assert!(post_ids.remove(0).is_ok());
+
+        cleanup(&[author_id, other_id]).await;
+    }
+}
@@ -0,0 +1,109 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod feature_flag_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels};
+    use actix_web::{test, web::Data, App};
+    use navarro_blog_api::modules::{
+        admin::admin_controllers::admin_controllers_module,
+        feature_flag::{
+            feature_flag_dtos::{FeatureFlags, SharedFeatureFlags, INVITE_CODES_FLAG},
+            feature_flag_queues::refresh_feature_flags,
+        },
+    };
+    use serde_json::{json, Value};
+
+    #[test]
+    async fn _feature_flags_default_enabled() {
+        let mut flags = FeatureFlags::default();
+        assert!(flags.enabled("unknown_feature"));
+
+        flags.set("unknown_feature", false);
+        assert!(!flags.enabled("unknown_feature"));
+    }
+
+    #[test]
+    async fn _toggle_feature_flag() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let admin_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&admin_id, &format!("{}@gmail.com", admin_id)],
+            )
+            .await
+            .unwrap();
+        let token = format!(
+            "Bearer {}",
+            JwtModels::access_jwt_model(admin_id.to_string())
+        );
+
+        let feature_flags = SharedFeatureFlags::default();
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(feature_flags.clone()))
+                .service(admin_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/admin/flags/invite_codes")
+            .append_header(("Authorization", token.clone()))
+            .set_json(json!({ "enabled": false }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        assert!(!feature_flags.read().unwrap().enabled(INVITE_CODES_FLAG));
+
+        let req = test::TestRequest::get()
+            .uri("/admin/flags")
+            .append_header(("Authorization", token.clone()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let flags: Vec<Value> = test::read_body_json(resp).await;
+        let invite_codes = flags
+            .iter()
+            .find(|flag| flag["name"] == INVITE_CODES_FLAG)
+            .unwrap();
+        assert_eq!(invite_codes["enabled"], false);
+
+        let req = test::TestRequest::post()
+            .uri("/admin/invite")
+            .append_header(("Authorization", token.clone()))
+            .set_json(json!({ "count": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 501);
+
+        let other_instance = SharedFeatureFlags::default();
+        refresh_feature_flags(pg_pool.get_ref().clone(), &other_instance)
+            .await
+            .unwrap();
+        assert!(!other_instance.read().unwrap().enabled(INVITE_CODES_FLAG));
+
+        let req = test::TestRequest::patch()
+            .uri("/admin/flags/invite_codes")
+            .append_header(("Authorization", token.clone()))
+            .set_json(json!({ "enabled": true }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let req = test::TestRequest::patch()
+            .uri("/admin/flags/unknown_feature")
+            .append_header(("Authorization", token))
+            .set_json(json!({ "enabled": true }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&admin_id])
+            .await
+            .unwrap();
+    }
+}
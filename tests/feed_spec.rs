@@ -0,0 +1,126 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod feed_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::{
+        middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+        modules::feed::{
+            feed_repositories::{rss_feed_repository, sitemap_repository},
+            feed_services::{rss_feed_xml, sitemap_xml},
+        },
+    };
+    use regex::Regex;
+
+    #[test]
+    async fn _rss_feed() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, created_at, published_at) VALUES ($1, 'Rust & Actix', 'APIs <rápidas>', 'body', NOW(), NOW())",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        let other_tenant_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&other_tenant_id, &other_tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        let other_post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, created_at, published_at, tenant_id) VALUES ($1, 'Outro blog', 'sub', 'body', NOW(), NOW() + INTERVAL '1 second', $2)",
+                &[&other_post_id, &other_tenant_id],
+            )
+            .await
+            .unwrap();
+        let tenant_id = uuid::Uuid::parse_str(DEFAULT_TENANT_ID).unwrap();
+
+        let items = rss_feed_repository(pg_pool.clone(), 20, tenant_id)
+            .await
+            .unwrap();
+        assert!(items.len() <= 20);
+        assert_eq!(items[0].id, post_id.to_string());
+        assert!(items
+            .iter()
+            .all(|item| item.id != other_post_id.to_string()));
+
+        let xml = rss_feed_xml(&items);
+        assert!(Regex::new(r#"<rss version="2\.0">"#)
+            .unwrap()
+            .is_match(&xml));
+        assert!(Regex::new(r"(?s)<item>.*</item>").unwrap().is_match(&xml));
+        assert!(xml.contains("<title>Rust &amp; Actix</title>"));
+        assert!(xml.contains("<description>APIs &lt;rápidas&gt;</description>"));
+        assert!(Regex::new(
+            r"<pubDate>\w{3}, \d{1,2} \w{3} \d{4} \d{2}:\d{2}:\d{2} \+0000</pubDate>"
+        )
+        .unwrap()
+        .is_match(&xml));
+
+        client
+            .execute(
+                "DELETE FROM posts WHERE id = ANY($1)",
+                &[&vec![post_id, other_post_id]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tenants WHERE id = $1", &[&other_tenant_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _sitemap() {
+        dotenv::dotenv().ok();
+        std::env::set_var("SITE_BASE_URL", "https://navarro.blog/");
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let mut post_ids = Vec::new();
+        for slug in ["rust-e-actix", "postgres-e-redis"] {
+            let post_id = uuid::Uuid::new_v4();
+            let slug = format!("{}-{}", slug, &post_id.simple().to_string()[..8]);
+            client
+                .execute(
+                    "INSERT INTO posts (id, slug, title, subtitle, body, created_at, published_at) VALUES ($1, $2, 'title', 'sub', 'body', NOW(), NOW())",
+                    &[&post_id, &slug],
+                )
+                .await
+                .unwrap();
+            post_ids.push((post_id, slug));
+        }
+
+        let tenant_id = uuid::Uuid::parse_str(DEFAULT_TENANT_ID).unwrap();
+        let urls = sitemap_repository(pg_pool.clone(), tenant_id)
+            .await
+            .unwrap();
+        let xml = sitemap_xml(&urls);
+
+        assert!(xml.contains(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#));
+        assert!(xml.contains("<loc>https://navarro.blog/</loc>"));
+        assert!(xml.contains("<loc>https://navarro.blog/post</loc>"));
+        for (_, slug) in &post_ids {
+            assert!(xml.contains(&format!("<loc>https://navarro.blog/post/{}</loc>", slug)));
+        }
+        assert!(
+            Regex::new(r"<lastmod>\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z</lastmod>")
+                .unwrap()
+                .is_match(&xml)
+        );
+
+        let ids: Vec<uuid::Uuid> = post_ids.iter().map(|(id, _)| *id).collect();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&ids])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,340 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod follower_specs {
+    use crate::mocks::{
+        models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels},
+        test_context::setup_test_context,
+    };
+    use actix_web::{test, web, App};
+    use navarro_blog_api::{
+        infra::redis::Redis,
+        modules::{
+            follower::follower_services::invalidate_activity_feeds_service,
+            user::user_controllers::user_controllers_module,
+        },
+    };
+    use serde_json::Value;
+
+    async fn insert_user(client: &deadpool_postgres::Object, email: &str) -> uuid::Uuid {
+        let id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&id, &email],
+            )
+            .await
+            .unwrap();
+        id
+    }
+
+    #[test]
+    async fn _follow_user() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+        let client = PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap();
+
+        let followee = insert_user(&client, "followee@gmail.com").await;
+        let mut followers = Vec::new();
+        for i in 0..3 {
+            followers.push(insert_user(&client, &format!("follower{}@gmail.com", i)).await);
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+
+        for follower in &followers {
+            let req = test::TestRequest::post()
+                .uri(&format!("/user/{}/follow", followee))
+                .append_header((
+                    "Authorization",
+                    format!(
+                        "Bearer {}",
+                        JwtModels::access_jwt_model(follower.to_string())
+                    ),
+                ))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), 204);
+        }
+
+        let jwt = JwtModels::access_jwt_model(followee.to_string());
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}", followee))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["followerCount"], 3);
+        assert_eq!(body["followingCount"], 0);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}/followers?limit=2", followee))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 2);
+        let cursor = body["nextCursor"].as_str().unwrap().to_string();
+
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/user/{}/followers?limit=2&cursor={}",
+                followee, cursor
+            ))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert!(body["nextCursor"].is_null());
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/user/{}/follow", followee))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(followers[0].to_string())
+                ),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 204);
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}", followee))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(body["followerCount"], 2);
+    }
+
+    #[test]
+    async fn _follow_user_error_self_follow() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+        let client = PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap();
+        let user = insert_user(&client, "self@gmail.com").await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/user/{}/follow", user))
+            .append_header((
+                "Authorization",
+                format!("Bearer {}", JwtModels::access_jwt_model(user.to_string())),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 400);
+    }
+
+    #[test]
+    async fn _feed() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+        let client = PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap();
+
+        let reader = insert_user(&client, "reader@gmail.com").await;
+        let author = insert_user(&client, "author@gmail.com").await;
+        let stranger = insert_user(&client, "stranger@gmail.com").await;
+        client
+            .batch_execute(&format!(
+                "INSERT INTO user_follows (follower_id, followee_id, created_at) VALUES ('{reader}', '{author}', NOW());
+                INSERT INTO posts (id, title, subtitle, body, created_at, published_at, author_id) VALUES
+                    (gen_random_uuid(), 'followed', 'sub', 'body', NOW(), NOW(), '{author}'),
+                    (gen_random_uuid(), 'draft', 'sub', 'body', NOW(), NULL, '{author}'),
+                    (gen_random_uuid(), 'stranger', 'sub', 'body', NOW(), NOW(), '{stranger}');"
+            ))
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}/feed", reader))
+            .append_header((
+                "Authorization",
+                format!("Bearer {}", JwtModels::access_jwt_model(reader.to_string())),
+            ))
+            .to_request();
+        let body: Value = test::call_and_read_body_json(&app, req).await;
+        let posts = body["data"].as_array().unwrap();
+
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0]["title"], "followed");
+        assert_eq!(posts[0]["authorId"], author.to_string());
+    }
+
+    #[test]
+    async fn _activity_feed() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let redis_pool = RedisModels::pool_success(None).await;
+        let client = pg_pool.get().await.unwrap();
+
+        let mut users = Vec::new();
+        for _ in 0..4 {
+            let email = format!("{}@gmail.com", uuid::Uuid::new_v4().simple());
+            users.push(insert_user(&client, &email).await);
+        }
+        let (reader, first_author, second_author, stranger) =
+            (users[0], users[1], users[2], users[3]);
+        let post_ids: Vec<uuid::Uuid> = (0..5).map(|_| uuid::Uuid::new_v4()).collect();
+        let comment_ids: Vec<uuid::Uuid> = (0..2).map(|_| uuid::Uuid::new_v4()).collect();
+        client
+            .batch_execute(&format!(
+                "INSERT INTO user_follows (follower_id, followee_id, created_at) VALUES
+                    ('{reader}', '{first_author}', NOW()), ('{reader}', '{second_author}', NOW());
+                INSERT INTO posts (id, title, subtitle, body, created_at, published_at, author_id) VALUES
+                    ('{}', 'first', 'sub', 'body', NOW(), NOW() - INTERVAL '1 day', '{first_author}'),
+                    ('{}', 'second', 'sub', 'body', NOW(), NOW() - INTERVAL '2 days', '{second_author}'),
+                    ('{}', 'old', 'sub', 'body', NOW(), NOW() - INTERVAL '60 days', '{first_author}'),
+                    ('{}', 'stranger', 'sub', 'body', NOW(), NOW(), '{stranger}');
+                INSERT INTO comments (id, post_id, user_id, body, created_at, approved_at) VALUES
+                    ('{}', '{}', '{second_author}', 'Ótimo post!', NOW() - INTERVAL '1 hour', NOW()),
+                    ('{}', '{}', '{second_author}', 'Antigo', NOW() - INTERVAL '10 days', NOW());",
+                post_ids[0], post_ids[1], post_ids[2], post_ids[3],
+                comment_ids[0], post_ids[0], comment_ids[1], post_ids[0],
+            ))
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(pg_pool.clone()))
+                .app_data(web::Data::new(redis_pool.clone()))
+                .service(user_controllers_module()),
+        )
+        .await;
+        let feed_request = |query: &str| {
+            test::TestRequest::get()
+                .uri(&format!("/user/{}/activity-feed{}", reader, query))
+                .append_header((
+                    "Authorization",
+                    format!("Bearer {}", JwtModels::access_jwt_model(reader.to_string())),
+                ))
+                .to_request()
+        };
+        let event_ids = |body: &Value| {
+            body["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|event| {
+                    (
+                        event["type"].as_str().unwrap().to_string(),
+                        event["id"].as_str().unwrap().to_string(),
+                    )
+                })
+                .collect::<Vec<(String, String)>>()
+        };
+
+        let body: Value = test::call_and_read_body_json(&app, feed_request("")).await;
+        assert_eq!(
+            event_ids(&body),
+            vec![
+                (String::from("comment"), comment_ids[0].to_string()),
+                (String::from("post"), post_ids[0].to_string()),
+                (String::from("post"), post_ids[1].to_string()),
+            ]
+        );
+        assert_eq!(body["data"][0]["actorId"], second_author.to_string());
+        assert_eq!(body["data"][0]["postTitle"], "first");
+        assert!(body["nextCursor"].is_null());
+
+        let body: Value = test::call_and_read_body_json(&app, feed_request("?limit=2")).await;
+        assert_eq!(event_ids(&body).len(), 2);
+        let cursor = body["nextCursor"].as_str().unwrap().to_string();
+        let body: Value = test::call_and_read_body_json(
+            &app,
+            feed_request(&format!("?limit=2&cursor={}", cursor)),
+        )
+        .await;
+        assert_eq!(
+            event_ids(&body),
+            vec![(String::from("post"), post_ids[1].to_string())]
+        );
+
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, created_at, published_at, author_id) VALUES ($1, 'new', 'sub', 'body', NOW(), NOW(), $2)",
+                &[&post_ids[4], &first_author],
+            )
+            .await
+            .unwrap();
+        let body: Value = test::call_and_read_body_json(&app, feed_request("")).await;
+        assert_eq!(event_ids(&body).len(), 3);
+
+        invalidate_activity_feeds_service(
+            web::Data::new(pg_pool.clone()),
+            &redis_pool,
+            post_ids[4],
+        )
+        .await;
+        let body: Value = test::call_and_read_body_json(&app, feed_request("")).await;
+        assert_eq!(
+            event_ids(&body)[0],
+            (String::from("post"), post_ids[4].to_string())
+        );
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}/activity-feed", reader))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(stranger.to_string())
+                ),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 401);
+
+        let _ = Redis::delete_by_pattern(&redis_pool, &format!("activity_feed:{}:*", reader)).await;
+        client
+            .execute("DELETE FROM comments WHERE id = ANY($1)", &[&comment_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM user_follows WHERE follower_id = $1",
+                &[&reader],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&users])
+            .await
+            .unwrap();
+    }
+}
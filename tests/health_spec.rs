@@ -0,0 +1,52 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod health_specs {
+    use crate::mocks::models::{postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{test, web::Data, App};
+    use navarro_blog_api::modules::health::{
+        health_controllers::health_controllers_module, health_dtos::HealthDTO,
+    };
+
+    #[test]
+    async fn _health() {
+        dotenv::dotenv().ok();
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .configure(health_controllers_module),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/health").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let health: HealthDTO = test::read_body_json(resp).await;
+        assert_eq!(health.postgres_read, "ok");
+        assert_eq!(health.postgres_write, "ok");
+        assert_eq!(health.redis_write, "ok");
+    }
+
+    #[test]
+    async fn _health_error_service_unavailable() {
+        dotenv::dotenv().ok();
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_error()))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .configure(health_controllers_module),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/health").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 503);
+
+        let health: HealthDTO = test::read_body_json(resp).await;
+        assert_eq!(health.postgres_read, "fail");
+        assert_eq!(health.postgres_write, "fail");
+        assert_eq!(health.redis_write, "ok");
+    }
+}
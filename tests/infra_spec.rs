@@ -0,0 +1,83 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod infra_specs {
+    use crate::mocks::{models::postgres::PostgresModels, test_context::setup_test_context};
+    use actix_web::test;
+    use navarro_blog_api::infra::{postgres::Postgres, redis::Redis};
+
+    #[test]
+    async fn _run_migrations() {
+        dotenv::dotenv().ok();
+
+        let pool = PostgresModels::postgres_success(None);
+
+        Postgres::run_migrations(&pool).await.unwrap();
+
+        let client = pool.get().await.unwrap();
+        let rows = client
+            .query(
+                "SELECT table_name::TEXT FROM information_schema.tables WHERE table_schema = 'public'",
+                &[],
+            )
+            .await
+            .unwrap();
+        let tables: Vec<String> = rows.iter().map(|row| row.get("table_name")).collect();
+
+        assert!(tables.contains(&String::from("users")));
+        assert!(tables.contains(&String::from("salt")));
+        assert!(tables.contains(&String::from("refinery_schema_history")));
+    }
+
+    #[test]
+    async fn _run_migrations_twice() {
+        dotenv::dotenv().ok();
+
+        let pool = PostgresModels::postgres_success(None);
+
+        Postgres::run_migrations(&pool).await.unwrap();
+        Postgres::run_migrations(&pool).await.unwrap();
+    }
+
+    #[test]
+    async fn _run_migrations_error_service_unavailable() {
+        dotenv::dotenv().ok();
+
+        let resp = Postgres::run_migrations(&PostgresModels::postgres_error()).await;
+
+        assert!(resp.is_err());
+    }
+
+    #[test]
+    async fn _setup_test_context() {
+        let ctx = setup_test_context().await;
+
+        let client = ctx.postgres_pool.get().await.unwrap();
+        let rows = client
+            .query(
+                "SELECT table_name::TEXT FROM information_schema.tables WHERE table_schema = 'public'",
+                &[],
+            )
+            .await
+            .unwrap();
+        let tables: Vec<String> = rows.iter().map(|row| row.get("table_name")).collect();
+
+        assert!(tables.contains(&String::from("users")));
+        assert!(tables.contains(&String::from("salt")));
+
+        Redis::set(&ctx.redis_pool, "key", "value").await.unwrap();
+        assert_eq!(Redis::get(&ctx.redis_pool, "key").await.unwrap(), "value");
+    }
+
+    #[test]
+    async fn _setup_test_context_isolated() {
+        let first_ctx = setup_test_context().await;
+        let second_ctx = setup_test_context().await;
+
+        Redis::set(&first_ctx.redis_pool, "key", "value")
+            .await
+            .unwrap();
+
+        assert!(Redis::get(&second_ctx.redis_pool, "key").await.is_err());
+    }
+}
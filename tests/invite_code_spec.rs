@@ -0,0 +1,129 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod invite_code_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels};
+    use actix_web::{test, web::Data, App};
+    use navarro_blog_api::modules::{
+        admin::admin_controllers::admin_controllers_module,
+        feature_flag::feature_flag_dtos::SharedFeatureFlags,
+        invite_code::{
+            invite_code_providers::validate_invite_code_provider,
+            invite_code_repositories::use_invite_code_repository,
+        },
+    };
+    use serde_json::{json, Value};
+
+    #[test]
+    async fn _invite_codes() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let admin_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&admin_id, &format!("{}@gmail.com", admin_id)],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(SharedFeatureFlags::default()))
+                .service(admin_controllers_module()),
+        )
+        .await;
+        let req = test::TestRequest::post()
+            .uri("/admin/invite")
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(admin_id.to_string())
+                ),
+            ))
+            .set_json(json!({ "count": 3 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        let codes: Vec<Value> = test::read_body_json(resp).await;
+        assert_eq!(codes.len(), 3);
+
+        let req = test::TestRequest::post()
+            .uri("/admin/invite")
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(admin_id.to_string())
+                ),
+            ))
+            .set_json(json!({ "count": 3 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        let code = codes[0]["code"].as_str().unwrap().to_string();
+        let parsed = validate_invite_code_provider(pg_pool.clone(), Some(code.clone()))
+            .await
+            .unwrap();
+        let new_user_id = uuid::Uuid::new_v4();
+        assert!(
+            use_invite_code_repository(pg_pool.clone(), parsed, new_user_id)
+                .await
+                .unwrap()
+        );
+        let rows = client
+            .query(
+                "SELECT used_by FROM invite_codes WHERE code = $1",
+                &[&parsed],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            rows[0].get::<_, Option<uuid::Uuid>>("used_by"),
+            Some(new_user_id)
+        );
+        let resp = validate_invite_code_provider(pg_pool.clone(), Some(code)).await;
+        assert_eq!(resp.err().unwrap().status(), 400);
+
+        let expired = uuid::Uuid::parse_str(codes[1]["code"].as_str().unwrap()).unwrap();
+        client
+            .execute(
+                "UPDATE invite_codes SET expires_at = NOW() - INTERVAL '1 day' WHERE code = $1",
+                &[&expired],
+            )
+            .await
+            .unwrap();
+        let resp = validate_invite_code_provider(pg_pool.clone(), Some(expired.to_string())).await;
+        assert_eq!(resp.err().unwrap().status(), 410);
+        assert!(
+            !use_invite_code_repository(pg_pool.clone(), expired, new_user_id)
+                .await
+                .unwrap()
+        );
+
+        for code in [
+            None,
+            Some(String::from("convite")),
+            Some(uuid::Uuid::new_v4().to_string()),
+        ] {
+            let resp = validate_invite_code_provider(pg_pool.clone(), code).await;
+            assert_eq!(resp.err().unwrap().status(), 400);
+        }
+
+        client
+            .execute(
+                "DELETE FROM invite_codes WHERE created_by = $1",
+                &[&admin_id],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&admin_id])
+            .await
+            .unwrap();
+    }
+}
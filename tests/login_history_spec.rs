@@ -0,0 +1,138 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod login_history_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data, HttpResponse};
+    use navarro_blog_api::modules::login_history::{
+        login_history_dtos::{LoginClient, LoginHistoryParams},
+        login_history_repositories::*,
+        login_history_services::{login_failure_reason, record_login_attempt_service},
+    };
+    use validator::Validate;
+
+    async fn seed_user() -> (uuid::Uuid, String) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        let email = format!("{}@gmail.com", user_id);
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &email],
+            )
+            .await
+            .unwrap();
+        (user_id, email)
+    }
+
+    async fn cleanup(user_id: uuid::Uuid, email: &str) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM login_history WHERE email = $1", &[&email])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _login_failure_reason() {
+        assert_eq!(
+            login_failure_reason(&HttpResponse::Unauthorized().finish()),
+            "invalid credentials"
+        );
+        assert_eq!(
+            login_failure_reason(&HttpResponse::Forbidden().finish()),
+            "account suspended"
+        );
+        assert_eq!(
+            login_failure_reason(&HttpResponse::NotFound().finish()),
+            "user not found"
+        );
+        assert_eq!(
+            login_failure_reason(&HttpResponse::ServiceUnavailable().finish()),
+            "service unavailable"
+        );
+    }
+
+    #[test]
+    async fn _login_history() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (user_id, email) = seed_user().await;
+        let client = LoginClient {
+            ip: String::from("203.0.113.7"),
+            user_agent: String::from("Mozilla/5.0"),
+        };
+
+        record_login_attempt_service(
+            pg_pool.clone(),
+            email.clone(),
+            &client,
+            Err(&HttpResponse::Unauthorized().finish()),
+        )
+        .await;
+        record_login_attempt_service(
+            pg_pool.clone(),
+            email.clone(),
+            &client,
+            Ok(&user_id.to_string()),
+        )
+        .await;
+
+        let history = user_login_history_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(history[0].success);
+        assert_eq!(history[0].user_id, Some(user_id.to_string()));
+        assert_eq!(history[0].failure_reason, None);
+        assert_eq!(history[0].ip, "203.0.113.7");
+        assert_eq!(history[0].user_agent, "Mozilla/5.0");
+        assert!(!history[1].success);
+        assert_eq!(history[1].user_id, None);
+        assert_eq!(history[1].email, email);
+        assert_eq!(
+            history[1].failure_reason.as_deref(),
+            Some("invalid credentials")
+        );
+
+        let history = email_login_history_repository(pg_pool.clone(), email.clone())
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 2);
+
+        for _ in 0..LOGIN_HISTORY_LIMIT {
+            insert_login_history_repository(
+                pg_pool.clone(),
+                None,
+                email.clone(),
+                &client,
+                Some(String::from("user not found")),
+            )
+            .await
+            .unwrap();
+        }
+        let history = email_login_history_repository(pg_pool.clone(), email.clone())
+            .await
+            .unwrap();
+        assert_eq!(history.len() as i64, LOGIN_HISTORY_LIMIT);
+
+        cleanup(user_id, &email).await;
+    }
+
+    #[test]
+    async fn _login_history_params() {
+        let params = LoginHistoryParams {
+            email: String::from("lightning@gmail.com"),
+        };
+        assert!(params.validate().is_ok());
+
+        let params = LoginHistoryParams {
+            email: String::new(),
+        };
+        assert!(params.validate().is_err());
+    }
+}
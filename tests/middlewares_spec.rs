@@ -3,17 +3,60 @@ pub mod mocks;
 #[cfg(test)]
 
 mod middlewares_specs {
-    use crate::mocks::models::jwt::JwtModels;
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels};
     use actix_web::{
         body,
-        http::header::{HeaderMap, HeaderName, HeaderValue},
+        http::header::{
+            HeaderMap, HeaderName, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH,
+            CONTENT_TYPE, VARY,
+        },
+        middleware::from_fn,
         test,
-        web::Path,
+        web::{self, Path},
+        App, HttpResponse,
     };
-    use navarro_blog_api::middlewares::{
-        auth_middleware::auth_middleware, jwt_token_middleware::jwt_token_middleware,
-        uuid_path_middleware::uuid_path_middleware,
+    use flate2::read::GzDecoder;
+    use navarro_blog_api::{
+        config::{cors::cors, extractors::path_config},
+        infra::redis::Redis,
+        middlewares::{
+            admin_middleware::{admin_middleware, admin_role_validation_middleware},
+            auth_middleware::auth_middleware,
+            body_size_limit_middleware::{
+                body_size_limit_middleware, json_config, BODY_SIZE_LIMIT_BYTES,
+                UPLOAD_BODY_SIZE_LIMIT_BYTES,
+            },
+            geo_restriction_middleware::{
+                geo_restriction_middleware, CountryLookup, GeoRestriction,
+            },
+            gzip_compress_middleware::gzip_compress_middleware,
+            idempotency_middleware::IDEMPOTENCY_KEY_HEADER,
+            json_charset_middleware::json_charset_middleware,
+            jwt_token_middleware::jwt_token_middleware,
+            maintenance_mode_middleware::{
+                maintenance_mode_middleware, MAINTENANCE_RETRY_AFTER_SECS,
+            },
+            security_headers_middleware::security_headers_middleware,
+            tenant_middleware::{tenant_validation_middleware, DEFAULT_TENANT_ID, TENANT_HEADER},
+            uuid_path_middleware::uuid_path_middleware,
+        },
+        modules::{
+            admin::admin_controllers::admin_controllers_module,
+            user::{user_controllers::user_controllers_module, user_queues::InsertUserAppQueue},
+        },
     };
+    use std::{io::Read, net::IpAddr, sync::Arc};
+
+    struct MockCountryLookup;
+
+    impl CountryLookup for MockCountryLookup {
+        fn country_code(&self, ip: IpAddr) -> Option<String> {
+            match ip.to_string().as_str() {
+                "203.0.113.7" => Some(String::from("KP")),
+                _ => Some(String::from("BR")),
+            }
+        }
+    }
 
     #[test]
     async fn _jwt_token() {
@@ -204,4 +247,673 @@ mod middlewares_specs {
         assert!(bytes.contains("bad request"));
         assert!(bytes.contains("O valor do cabeçalho 'Authorization' deve ser informado."));
     }
+
+    #[test]
+    async fn _json_charset() {
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(json_charset_middleware))
+                .route(
+                    "/json",
+                    web::get().to(|| async { HttpResponse::Ok().json("ok") }),
+                )
+                .route(
+                    "/text",
+                    web::get()
+                        .to(|| async { HttpResponse::Ok().content_type("text/plain").body("ok") }),
+                ),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/json").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(
+            resp.headers().get(CONTENT_TYPE).unwrap(),
+            "application/json; charset=utf-8"
+        );
+
+        let req = test::TestRequest::get().uri("/text").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.headers().get(CONTENT_TYPE).unwrap(), "text/plain");
+    }
+
+    #[test]
+    async fn _gzip_compress() {
+        let long_body = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(40);
+        let long_body_async = long_body.clone();
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(gzip_compress_middleware))
+                .route(
+                    "/long",
+                    web::get().to(move || {
+                        let body = long_body_async.clone();
+                        async move { HttpResponse::Ok().body(body) }
+                    }),
+                )
+                .route(
+                    "/short",
+                    web::get().to(|| async { HttpResponse::Ok().body("ok") }),
+                )
+                .route(
+                    "/zip",
+                    web::get().to(|| async {
+                        HttpResponse::Ok()
+                            .content_type("application/zip")
+                            .body(vec![0u8; 4096])
+                    }),
+                )
+                .route(
+                    "/stream",
+                    web::get().to(|| async {
+                        let chunk: Result<web::Bytes, actix_web::Error> =
+                            Ok(web::Bytes::from(vec![b'a'; 4096]));
+                        HttpResponse::Ok().streaming(futures_util::stream::iter(vec![chunk]))
+                    }),
+                ),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/long")
+            .append_header((ACCEPT_ENCODING, "gzip, deflate"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        let mut decompressed = String::new();
+        GzDecoder::new(&bytes[..])
+            .read_to_string(&mut decompressed)
+            .unwrap();
+
+        assert!(bytes.len() < long_body.len());
+        assert_eq!(decompressed, long_body);
+
+        let req = test::TestRequest::get().uri("/long").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert!(resp.headers().get(CONTENT_ENCODING).is_none());
+
+        let req = test::TestRequest::get()
+            .uri("/short")
+            .append_header((ACCEPT_ENCODING, "gzip"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert!(resp.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(body::to_bytes(resp.into_body()).await.unwrap(), "ok");
+
+        for uri in ["/zip", "/stream"] {
+            let req = test::TestRequest::get()
+                .uri(uri)
+                .append_header((ACCEPT_ENCODING, "gzip"))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+
+            assert!(resp.headers().get(CONTENT_ENCODING).is_none());
+            assert_eq!(body::to_bytes(resp.into_body()).await.unwrap().len(), 4096);
+        }
+    }
+
+    #[test]
+    async fn _gzip_compress_keeps_cors_vary() {
+        dotenv::dotenv().ok();
+        let origin = format!(
+            "{}:{}",
+            std::env::var("BASE_URL").unwrap(),
+            std::env::var("HTTP_PORT").unwrap()
+        );
+        let app = test::init_service(
+            App::new()
+                .wrap(cors())
+                .wrap(from_fn(gzip_compress_middleware))
+                .route(
+                    "/long",
+                    web::get().to(|| async { HttpResponse::Ok().body("a".repeat(4096)) }),
+                ),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/long")
+            .append_header((ACCEPT_ENCODING, "gzip"))
+            .append_header(("Origin", origin))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+        let vary = resp
+            .headers()
+            .get_all(VARY)
+            .map(|value| value.to_str().unwrap().to_lowercase())
+            .collect::<Vec<_>>()
+            .join(", ");
+        assert!(vary.contains("origin"));
+        assert!(vary.contains("accept-encoding"));
+    }
+
+    #[test]
+    async fn _admin() {
+        dotenv::dotenv().ok();
+
+        let mut header_map_mock: HeaderMap = HeaderMap::new();
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let jwt = JwtModels::access_jwt_model(id.clone());
+        header_map_mock.insert(
+            HeaderName::from_static("authorization"),
+            HeaderValue::from_str(&format!("Bearer {}", jwt)).unwrap(),
+        );
+
+        let token = admin_middleware(&header_map_mock).unwrap();
+
+        assert_eq!(token.claims.sub, id);
+    }
+
+    #[test]
+    async fn _admin_error_forbidden() {
+        dotenv::dotenv().ok();
+
+        let mut header_map_mock: HeaderMap = HeaderMap::new();
+
+        let jwt = JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string());
+        header_map_mock.insert(
+            HeaderName::from_static("authorization"),
+            HeaderValue::from_str(&format!("Bearer {}", jwt)).unwrap(),
+        );
+
+        let resp = admin_middleware(&header_map_mock).err().unwrap();
+        assert_eq!(resp.status(), 403);
+
+        let bytes =
+            String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec()).unwrap();
+
+        assert!(bytes.contains("forbidden"));
+        assert!(bytes.contains("Acesso restrito a administradores."));
+    }
+
+    #[test]
+    async fn _admin_error_impersonation_token() {
+        dotenv::dotenv().ok();
+
+        let mut header_map_mock: HeaderMap = HeaderMap::new();
+
+        let jwt = JwtModels::impersonation_jwt_model(
+            uuid::Uuid::new_v4().to_string(),
+            uuid::Uuid::new_v4().to_string(),
+        );
+        header_map_mock.insert(
+            HeaderName::from_static("authorization"),
+            HeaderValue::from_str(&format!("Bearer {}", jwt)).unwrap(),
+        );
+
+        let resp = admin_middleware(&header_map_mock).err().unwrap();
+        assert_eq!(resp.status(), 403);
+    }
+
+    #[test]
+    async fn _admin_role_validation() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let client = pg_pool.get().await.unwrap();
+        let admin_id = uuid::Uuid::new_v4();
+        let user_id = uuid::Uuid::new_v4();
+        for (id, role) in [(admin_id, "admin"), (user_id, "user")] {
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at, role) VALUES ($1, 'Victor Navarro', $2, 'x', NOW(), $3)",
+                    &[&id, &format!("{}@gmail.com", id), &role],
+                )
+                .await
+                .unwrap();
+        }
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(admin_role_validation_middleware))
+                .app_data(web::Data::new(pg_pool.clone()))
+                .route("/", web::get().to(|| async { HttpResponse::Ok().finish() })),
+        )
+        .await;
+        let request = |jwt: Option<String>| {
+            let mut req = test::TestRequest::get().uri("/");
+            if let Some(jwt) = jwt {
+                req = req.insert_header(("Authorization", format!("Bearer {}", jwt)));
+            }
+            req.to_request()
+        };
+
+        for (jwt, status) in [
+            (None, 200),
+            (Some(String::from("invalid")), 200),
+            (Some(JwtModels::access_jwt_model(admin_id.to_string())), 200),
+            (
+                Some(JwtModels::user_access_jwt_model(user_id.to_string())),
+                200,
+            ),
+            (Some(JwtModels::access_jwt_model(user_id.to_string())), 403),
+            (
+                Some(JwtModels::access_jwt_model(
+                    uuid::Uuid::new_v4().to_string(),
+                )),
+                403,
+            ),
+        ] {
+            let resp = test::call_service(&app, request(jwt)).await;
+            assert_eq!(resp.status(), status);
+        }
+
+        client
+            .execute(
+                "DELETE FROM users WHERE id = ANY($1)",
+                &[&vec![admin_id, user_id]],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _security_headers() {
+        std::env::set_var("CSP_REPORT_URI", "https://navarro.tech/csp-report");
+        let app = test::init_service(App::new().wrap(from_fn(security_headers_middleware)).route(
+            "/health",
+            web::get().to(|| async { HttpResponse::Ok().body("ok") }),
+        ))
+        .await;
+
+        let req = test::TestRequest::get().uri("/health").to_request();
+        let resp = test::call_service(&app, req).await;
+        let headers = resp.headers();
+
+        assert_eq!(headers.get("x-content-type-options").unwrap(), "nosniff");
+        assert_eq!(headers.get("x-frame-options").unwrap(), "DENY");
+        assert_eq!(
+            headers.get("strict-transport-security").unwrap(),
+            "max-age=31536000; includeSubDomains"
+        );
+        assert_eq!(headers.get("referrer-policy").unwrap(), "no-referrer");
+        assert_eq!(
+            headers.get("permissions-policy").unwrap(),
+            "camera=(), microphone=()"
+        );
+        assert_eq!(
+            headers.get("content-security-policy").unwrap(),
+            "default-src 'none'; report-uri https://navarro.tech/csp-report"
+        );
+    }
+
+    #[test]
+    async fn _body_size_limit() {
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(body_size_limit_middleware))
+                .app_data(json_config())
+                .route(
+                    "/json",
+                    web::post().to(|body: web::Json<String>| async move {
+                        HttpResponse::Ok().json(body.len())
+                    }),
+                )
+                .route(
+                    "/admin/post/import",
+                    web::post().to(|| async { HttpResponse::Ok().finish() }),
+                ),
+        )
+        .await;
+
+        let request = |uri: &str, size: usize| {
+            test::TestRequest::post()
+                .uri(uri)
+                .insert_header((CONTENT_TYPE, "application/json"))
+                .insert_header((CONTENT_LENGTH, size))
+                .set_payload(format!("\"{}\"", "a".repeat(size - 2)))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, request("/json", *BODY_SIZE_LIMIT_BYTES)).await;
+        assert_eq!(resp.status(), 200);
+
+        let resp = test::call_service(&app, request("/json", *BODY_SIZE_LIMIT_BYTES + 1)).await;
+        assert_eq!(resp.status(), 413);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("payload too large"));
+
+        let resp = test::call_service(
+            &app,
+            request("/admin/post/import", *BODY_SIZE_LIMIT_BYTES + 1),
+        )
+        .await;
+        assert_eq!(resp.status(), 200);
+
+        let resp = test::call_service(
+            &app,
+            request("/admin/post/import", *UPLOAD_BODY_SIZE_LIMIT_BYTES + 1),
+        )
+        .await;
+        assert_eq!(resp.status(), 413);
+    }
+
+    #[test]
+    async fn _json_config_overflow() {
+        let app =
+            test::init_service(App::new().app_data(json_config()).route(
+                "/json",
+                web::post().to(|body: web::Json<String>| async move {
+                    HttpResponse::Ok().json(body.len())
+                }),
+            ))
+            .await;
+
+        let req = test::TestRequest::post()
+            .uri("/json")
+            .insert_header((CONTENT_TYPE, "application/json"))
+            .set_payload(format!("\"{}\"", "a".repeat(*BODY_SIZE_LIMIT_BYTES - 1)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 413);
+    }
+
+    #[test]
+    async fn _path_config() {
+        let app = test::init_service(App::new().app_data(path_config()).route(
+            "/page/{page}",
+            web::get().to(|page: Path<u32>| async move { HttpResponse::Ok().json(*page) }),
+        ))
+        .await;
+
+        let req = test::TestRequest::get().uri("/page/2").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let req = test::TestRequest::get().uri("/page/dois").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        let bytes = String::from_utf8_lossy(&bytes);
+        assert!(bytes.contains("\"path\""));
+        assert!(bytes.contains("bad request"));
+    }
+
+    #[test]
+    async fn _geo_restriction() {
+        let geo_app = |lookup: Option<Arc<dyn CountryLookup>>| {
+            test::init_service(
+                App::new()
+                    .wrap(from_fn(geo_restriction_middleware))
+                    .app_data(web::Data::new(GeoRestriction {
+                        lookup,
+                        blocked_countries: vec![String::from("KP")],
+                    }))
+                    .route("/", web::get().to(|| async { HttpResponse::Ok().finish() })),
+            )
+        };
+        let request = |ip: &str| {
+            test::TestRequest::get()
+                .uri("/")
+                .peer_addr(format!("{}:4321", ip).parse().unwrap())
+                .to_request()
+        };
+
+        let app = geo_app(Some(Arc::new(MockCountryLookup))).await;
+        let resp = test::call_service(&app, request("203.0.113.7")).await;
+        assert_eq!(resp.status(), 451);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("KP"));
+        let resp = test::call_service(&app, request("198.51.100.1")).await;
+        assert_eq!(resp.status(), 200);
+
+        // The connection does not come from a trusted proxy, so the forged headers are ignored.
+        for (header, value) in [
+            ("X-Forwarded-For", "198.51.100.1"),
+            ("Forwarded", "for=198.51.100.1"),
+            ("X-Real-Ip", "198.51.100.1"),
+        ] {
+            let forged_request = test::TestRequest::get()
+                .uri("/")
+                .peer_addr("203.0.113.7:4321".parse().unwrap())
+                .insert_header((header, value))
+                .to_request();
+            let resp = test::call_service(&app, forged_request).await;
+            assert_eq!(resp.status(), 451);
+        }
+        let forged_request = test::TestRequest::get()
+            .uri("/")
+            .peer_addr("198.51.100.1:4321".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "203.0.113.7"))
+            .to_request();
+        let resp = test::call_service(&app, forged_request).await;
+        assert_eq!(resp.status(), 200);
+
+        let app = geo_app(None).await;
+        let resp = test::call_service(&app, request("203.0.113.7")).await;
+        assert_eq!(resp.status(), 200);
+    }
+
+    #[test]
+    async fn _tenant_validation() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let tenant_id = uuid::Uuid::new_v4();
+        pg_pool
+            .get()
+            .await
+            .unwrap()
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(tenant_validation_middleware))
+                .app_data(web::Data::new(pg_pool.clone()))
+                .app_data(web::Data::new(RedisModels::pool_success(None).await))
+                .route("/", web::get().to(|| async { HttpResponse::Ok().finish() })),
+        )
+        .await;
+        let request = |tenant: Option<String>, jwt: Option<String>| {
+            let mut req = test::TestRequest::get().uri("/");
+            if let Some(tenant) = tenant {
+                req = req.insert_header((TENANT_HEADER, tenant));
+            }
+            if let Some(jwt) = jwt {
+                req = req.insert_header(("Authorization", format!("Bearer {}", jwt)));
+            }
+            req.to_request()
+        };
+        let user_id = uuid::Uuid::new_v4().to_string();
+
+        for (tenant, jwt, status) in [
+            (None, None, 200),
+            (Some(String::from("not-a-uuid")), None, 400),
+            (Some(uuid::Uuid::new_v4().to_string()), None, 404),
+            (Some(tenant_id.to_string()), None, 200),
+            (
+                None,
+                Some(JwtModels::user_access_jwt_model(user_id.clone())),
+                200,
+            ),
+            (
+                Some(tenant_id.to_string()),
+                Some(JwtModels::user_access_jwt_model(user_id.clone())),
+                403,
+            ),
+            (
+                Some(tenant_id.to_string()),
+                Some(JwtModels::tenant_access_jwt_model(
+                    user_id.clone(),
+                    tenant_id.to_string(),
+                )),
+                200,
+            ),
+            (
+                None,
+                Some(JwtModels::tenant_access_jwt_model(
+                    user_id.clone(),
+                    tenant_id.to_string(),
+                )),
+                403,
+            ),
+        ] {
+            let resp = test::call_service(&app, request(tenant.clone(), jwt)).await;
+            assert_eq!(resp.status(), status, "{:?}", tenant);
+        }
+
+        pg_pool
+            .get()
+            .await
+            .unwrap()
+            .execute("DELETE FROM tenants WHERE id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _maintenance_mode() {
+        dotenv::dotenv().ok();
+        let jwt = JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string());
+
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(maintenance_mode_middleware))
+                .app_data(web::Data::new(RedisModels::pool_success(None).await))
+                .service(admin_controllers_module())
+                .route("/", web::get().to(|| async { HttpResponse::Ok().finish() }))
+                .route(
+                    "/health",
+                    web::get().to(|| async { HttpResponse::Ok().finish() }),
+                )
+                .route(
+                    "/metrics",
+                    web::get().to(|| async { HttpResponse::Ok().finish() }),
+                ),
+        )
+        .await;
+        let toggle = |req: test::TestRequest| {
+            req.uri("/admin/maintenance/enable")
+                .insert_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, toggle(test::TestRequest::post())).await;
+        assert_eq!(resp.status(), 204);
+
+        let req = test::TestRequest::get().uri("/").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 503);
+        assert_eq!(
+            resp.headers().get("retry-after").unwrap(),
+            &MAINTENANCE_RETRY_AFTER_SECS.to_string()
+        );
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("Manutenção em andamento."));
+
+        for uri in ["/health", "/metrics"] {
+            let req = test::TestRequest::get().uri(uri).to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), 200);
+        }
+
+        let resp = test::call_service(&app, toggle(test::TestRequest::delete())).await;
+        assert_eq!(resp.status(), 204);
+
+        let req = test::TestRequest::get().uri("/").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+    }
+
+    #[test]
+    async fn _idempotency() {
+        dotenv::dotenv().ok();
+        let redis_pool = RedisModels::pool_success(None).await;
+        let user_app = |pg_pool: deadpool_postgres::Pool| {
+            App::new()
+                .app_data(web::Data::new(pg_pool))
+                .app_data(web::Data::new(redis_pool.clone()))
+                .app_data(web::Data::new(Arc::new(InsertUserAppQueue::new())))
+                .service(user_controllers_module())
+        };
+        let key = uuid::Uuid::new_v4().to_string();
+        let email = format!("{}@gmail.com", key);
+        let username = format!("user_{}", &key.replace('-', "")[..20]);
+        let insert_user = |key: &str| {
+            test::TestRequest::post()
+                .uri("/user")
+                .insert_header((IDEMPOTENCY_KEY_HEADER, key))
+                .set_json(serde_json::json!({
+                    "name": "Victor Navarro",
+                    "username": username,
+                    "email": email,
+                    "password": "Navarro@2024"
+                }))
+                .to_request()
+        };
+
+        let app = test::init_service(user_app(PostgresModels::postgres_success(None))).await;
+        let resp = test::call_service(&app, insert_user(&key)).await;
+        assert_eq!(resp.status(), 201);
+        assert!(resp.headers().get("idempotent-replayed").is_none());
+        let location = resp.headers().get("location").unwrap().clone();
+
+        let app = test::init_service(user_app(PostgresModels::postgres_error())).await;
+        let resp = test::call_service(&app, insert_user(&key)).await;
+        assert_eq!(resp.status(), 201);
+        assert_eq!(resp.headers().get("location").unwrap(), &location);
+        assert_eq!(resp.headers().get("idempotent-replayed").unwrap(), "true");
+
+        let req = test::TestRequest::post()
+            .uri("/user")
+            .insert_header((IDEMPOTENCY_KEY_HEADER, key.as_str()))
+            .set_json(serde_json::json!({ "name": "Outro corpo" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 422);
+
+        let running_key = uuid::Uuid::new_v4().to_string();
+        let lock_key = format!(
+            "idempotent:anonymous:{}:POST:/user:{}:lock",
+            DEFAULT_TENANT_ID, running_key
+        );
+        Redis::set_ex(&redis_pool, &lock_key, "1", 60)
+            .await
+            .unwrap();
+        let resp = test::call_service(&app, insert_user(&running_key)).await;
+        assert_eq!(resp.status(), 409);
+        let _ = Redis::delete(&redis_pool, &lock_key).await;
+
+        let req = test::TestRequest::post()
+            .uri("/user/login")
+            .insert_header((IDEMPOTENCY_KEY_HEADER, key.as_str()))
+            .set_json(serde_json::json!({ "email": email, "password": "Navarro@2024" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.headers().get("idempotent-replayed").is_none());
+
+        let resp = test::call_service(&app, insert_user("not-a-uuid")).await;
+        assert_eq!(resp.status(), 400);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("Idempotency-Key"));
+
+        let req = test::TestRequest::get()
+            .uri("/user")
+            .insert_header((IDEMPOTENCY_KEY_HEADER, "not-a-uuid"))
+            .to_request();
+        let bytes = body::to_bytes(test::call_service(&app, req).await.into_body())
+            .await
+            .unwrap();
+        assert!(!String::from_utf8_lossy(&bytes).contains("Idempotency-Key"));
+
+        let user_id = location.to_str().unwrap().trim_start_matches("/user/");
+        let idempotent_key = format!(
+            "idempotent:anonymous:{}:POST:/user:{}",
+            DEFAULT_TENANT_ID, key
+        );
+        for key in [idempotent_key, email, user_id.to_string()] {
+            let _ = Redis::delete(&redis_pool, &key).await;
+        }
+    }
 }
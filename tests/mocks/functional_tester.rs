@@ -42,7 +42,7 @@ impl FunctionalTester {
     }
 
     pub async fn delete_from_database(db_table: TablesEnum, conditions: Option<Vec<(&str, &str)>>) {
-        let client = PostgresModels::postgres_success().get().await.unwrap();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
         let mut sql = SqlBuilder::delete_from(FunctionalTester::construct_table(db_table).db_table);
 
         if let Some(conditions) = conditions {
@@ -56,7 +56,7 @@ impl FunctionalTester {
         client.prepare(&stmt).await.unwrap();
         client.execute(&stmt, &[]).await.unwrap();
 
-        let mut redis_conn = RedisModels::pool_success().await.get().await.unwrap();
+        let mut redis_conn = RedisModels::pool_success(None).await.get().await.unwrap();
         let _: () = deadpool_redis::redis::cmd("FLUSHDB")
             .query_async(&mut redis_conn)
             .await
@@ -68,7 +68,7 @@ impl FunctionalTester {
         field: &str,
         conditions: Option<Vec<(&str, &str)>>,
     ) -> bool {
-        let mut conn = PostgresModels::postgres_success().get().await.unwrap();
+        let mut conn = PostgresModels::postgres_success(None).get().await.unwrap();
         let transaction = conn.transaction().await.unwrap();
 
         let mut sql_builder =
@@ -93,7 +93,7 @@ impl FunctionalTester {
         field: &str,
         conditions: Option<Vec<(&str, &str)>>,
     ) -> bool {
-        let mut conn = PostgresModels::postgres_success().get().await.unwrap();
+        let mut conn = PostgresModels::postgres_success(None).get().await.unwrap();
         let transaction = conn.transaction().await.unwrap();
 
         let mut sql_builder =
@@ -114,7 +114,7 @@ impl FunctionalTester {
     }
 
     pub async fn insert_in_db_salt(user_id: String, salt: String) -> String {
-        let client = PostgresModels::postgres_success().get().await.unwrap();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
 
         let user_id2 = uuid::Uuid::parse_str(&user_id).unwrap();
         let salt2 = uuid::Uuid::parse_str(&salt).unwrap();
@@ -135,7 +135,7 @@ impl FunctionalTester {
     }
 
     pub async fn get_salt_from_db(conditions: Option<Vec<(&str, &str)>>) -> SaltDTO {
-        let mut conn = PostgresModels::postgres_success().get().await.unwrap();
+        let mut conn = PostgresModels::postgres_success(None).get().await.unwrap();
         let transaction = conn.transaction().await.unwrap();
 
         let mut sql_builder = SqlBuilder::select_from("salt");
@@ -159,10 +159,11 @@ impl FunctionalTester {
         }
     }
 
-    pub async fn insert_in_db_users(user_body: MockUserDTO) -> MockUserDTO {
+    pub async fn insert_in_db_users(user_body: MockUserDTO, tenant_id: &str) -> MockUserDTO {
         let mut pg_user = MockUserDTO {
             id: user_body.id.clone(),
             name: user_body.name.clone(),
+            username: user_body.username.clone(),
             email: user_body.email.clone(),
             password: user_body.password.clone(),
             created_at: user_body.created_at.clone(),
@@ -185,19 +186,20 @@ impl FunctionalTester {
             pg_user.created_at = UserModels::complete_user_model_hashed().created_at;
         }
 
-        let client = PostgresModels::postgres_success().get().await.unwrap();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
 
         let stmt = client
             .prepare(
                 "INSERT INTO users
-                (id, name, email, password, created_at)
+                (id, name, email, password, created_at, tenant_id)
                 values
-                ($1, $2, $3, $4, $5)",
+                ($1, $2, $3, $4, $5, $6)",
             )
             .await
             .unwrap();
 
         let uuid_id = uuid::Uuid::parse_str(&pg_user.id).unwrap();
+        let tenant_id = uuid::Uuid::parse_str(tenant_id).unwrap();
 
         client
             .query(
@@ -208,6 +210,7 @@ impl FunctionalTester {
                     &pg_user.email,
                     &pg_user.password,
                     &chrono::Utc::now(),
+                    &tenant_id,
                 ],
             )
             .await
@@ -217,7 +220,7 @@ impl FunctionalTester {
     }
 
     pub async fn get_user_from_db() -> String {
-        let client = PostgresModels::postgres_success().get().await.unwrap();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
         let stmt = client.prepare("SELECT salt FROM salt").await.unwrap();
         let rows = client.query(&stmt, &[]).await.unwrap();
         rows[0].get("salt")
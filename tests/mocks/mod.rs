@@ -2,3 +2,4 @@ pub mod enums;
 pub mod functional_tester;
 pub mod models;
 pub mod structs;
+pub mod test_context;
@@ -1,4 +1,6 @@
-use navarro_blog_api::shared::structs::jwt_claims::Claims;
+use navarro_blog_api::{
+    middlewares::tenant_middleware::DEFAULT_TENANT_ID, shared::structs::jwt_claims::Claims,
+};
 
 pub struct JwtModels {}
 
@@ -9,7 +11,71 @@ impl JwtModels {
             &Claims {
                 sub: id,
                 role: String::from("admin"),
+                email_verified: true,
+                tenant_id: Some(String::from(DEFAULT_TENANT_ID)),
                 exp: (chrono::Utc::now() + chrono::Duration::days(7)).timestamp() as usize,
+                impersonated_by: None,
+                jti: None,
+            },
+            &jsonwebtoken::EncodingKey::from_secret(
+                std::env::var("JWT_ACCESS_KEY").unwrap().as_ref(),
+            ),
+        )
+        .unwrap()
+    }
+
+    /// An admin access token for a user of the tenant.
+    pub fn tenant_access_jwt_model(id: String, tenant_id: String) -> String {
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &Claims {
+                sub: id,
+                role: String::from("admin"),
+                email_verified: true,
+                tenant_id: Some(tenant_id),
+                exp: (chrono::Utc::now() + chrono::Duration::days(7)).timestamp() as usize,
+                impersonated_by: None,
+                jti: None,
+            },
+            &jsonwebtoken::EncodingKey::from_secret(
+                std::env::var("JWT_ACCESS_KEY").unwrap().as_ref(),
+            ),
+        )
+        .unwrap()
+    }
+
+    pub fn user_access_jwt_model(id: String) -> String {
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &Claims {
+                sub: id,
+                role: String::from("user"),
+                email_verified: true,
+                tenant_id: Some(String::from(DEFAULT_TENANT_ID)),
+                exp: (chrono::Utc::now() + chrono::Duration::days(7)).timestamp() as usize,
+                impersonated_by: None,
+                jti: None,
+            },
+            &jsonwebtoken::EncodingKey::from_secret(
+                std::env::var("JWT_ACCESS_KEY").unwrap().as_ref(),
+            ),
+        )
+        .unwrap()
+    }
+
+    /// An impersonation token that still claims the `admin` role, to check it is never accepted
+    /// as an admin token.
+    pub fn impersonation_jwt_model(id: String, admin_id: String) -> String {
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &Claims {
+                sub: id,
+                role: String::from("admin"),
+                email_verified: true,
+                tenant_id: Some(String::from(DEFAULT_TENANT_ID)),
+                exp: (chrono::Utc::now() + chrono::Duration::minutes(10)).timestamp() as usize,
+                impersonated_by: Some(admin_id),
+                jti: Some(uuid::Uuid::new_v4().to_string()),
             },
             &jsonwebtoken::EncodingKey::from_secret(
                 std::env::var("JWT_ACCESS_KEY").unwrap().as_ref(),
@@ -24,7 +90,11 @@ impl JwtModels {
             &Claims {
                 sub: id,
                 role: String::from("admin"),
+                email_verified: true,
+                tenant_id: Some(String::from(DEFAULT_TENANT_ID)),
                 exp: (chrono::Utc::now() + chrono::Duration::days(7)).timestamp() as usize,
+                impersonated_by: None,
+                jti: None,
             },
             &jsonwebtoken::EncodingKey::from_secret(
                 std::env::var("JWT_REFRESH_KEY").unwrap().as_ref(),
@@ -1,3 +1,4 @@
+use crate::mocks::test_context::TestContext;
 use deadpool_postgres::{Config, Pool, PoolConfig, Runtime};
 use std::env;
 use tokio_postgres::NoTls;
@@ -5,7 +6,13 @@ use tokio_postgres::NoTls;
 pub struct PostgresModels {}
 
 impl PostgresModels {
-    pub fn postgres_success() -> Pool {
+    /// The Postgres of the test context when one is given, otherwise the shared one configured in
+    /// the env vars.
+    pub fn postgres_success(context: Option<&TestContext>) -> Pool {
+        if let Some(context) = context {
+            return context.postgres_pool.clone();
+        }
+
         let mut cfg: Config = Config::new();
         cfg.host = Some(env::var("DB_HOST").unwrap());
         cfg.port = Some(env::var("DB_PORT").unwrap().parse().unwrap());
@@ -1,3 +1,4 @@
+use crate::mocks::test_context::TestContext;
 use deadpool_redis::{
     Config, ConnectionAddr, ConnectionInfo, Pool, PoolConfig, RedisConnectionInfo, Runtime,
     Timeouts,
@@ -7,7 +8,13 @@ use std::{env, time::Duration};
 pub struct RedisModels {}
 
 impl RedisModels {
-    pub async fn pool_success() -> Pool {
+    /// The Redis of the test context when one is given, otherwise the shared one configured in the
+    /// env vars.
+    pub async fn pool_success(context: Option<&TestContext>) -> Pool {
+        if let Some(context) = context {
+            return context.redis_pool.clone();
+        }
+
         let mut cfg = Config::default();
         cfg.connection = Some(ConnectionInfo {
             addr: ConnectionAddr::Tcp(
@@ -35,10 +42,7 @@ impl RedisModels {
     pub async fn pool_error() -> Pool {
         let mut cfg = Config::default();
         cfg.connection = Some(ConnectionInfo {
-            addr: ConnectionAddr::Tcp(
-                env::var("6380").unwrap(),
-                env::var("1.1.1.1").unwrap().parse().unwrap(),
-            ),
+            addr: ConnectionAddr::Tcp(String::from("127.0.0.1"), 1),
             redis: RedisConnectionInfo {
                 db: env::var("REDIS_NUMBER").unwrap().parse().unwrap(),
                 username: Some(env::var("REDIS_USER").unwrap()),
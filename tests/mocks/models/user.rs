@@ -60,8 +60,9 @@ impl UserModels {
         MockUserDTO {
             id: uuid::Uuid::new_v4().to_string(),
             name: String::from("Victor Navarro"),
+            username: String::from("victor_navarro"),
             email: String::from("bush1d3v@gmail.com"),
-            password: String::from("12345678%"),
+            password: String::from("Navarro@2024"),
             created_at: chrono::Utc::now().to_string(),
             updated_at: None,
         }
@@ -71,8 +72,9 @@ impl UserModels {
         MockUserDTO {
             id: uuid::Uuid::new_v4().to_string(),
             name: String::from("Victor Navarro"),
+            username: String::from("victor_navarro"),
             email: String::from("bush1d3v@gmail.com"),
-            password: hash("12345678%", 4).unwrap().to_string(),
+            password: hash("Navarro@2024", 4).unwrap().to_string(),
             created_at: chrono::Utc::now().to_string(),
             updated_at: None,
         }
@@ -81,23 +83,25 @@ impl UserModels {
     pub fn simple_user_model() -> MockInsertUserDTO {
         MockInsertUserDTO {
             name: String::from("Victor Navarro"),
+            username: String::from("victor_navarro"),
             email: String::from("bush1d3v@gmail.com"),
-            password: String::from("12345678%"),
+            password: String::from("Navarro@2024"),
         }
     }
 
     pub fn simple_user_model_hashed() -> MockInsertUserDTO {
         MockInsertUserDTO {
             name: String::from("Victor Navarro"),
+            username: String::from("victor_navarro"),
             email: String::from("bush1d3v@gmail.com"),
-            password: hash("12345678%", 4).unwrap().to_string(),
+            password: hash("Navarro@2024", 4).unwrap().to_string(),
         }
     }
 
     pub fn login_user_model() -> MockLoginUserDTO {
         MockLoginUserDTO {
             email: String::from("bush1d3v@gmail.com"),
-            password: String::from("12345678%"),
+            password: String::from("Navarro@2024"),
         }
     }
 
@@ -2,9 +2,12 @@ use navarro_blog_api::modules::user::user_dtos::{InsertUserDTO, LoginUserDTO, Pu
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct MockUserDTO {
     pub id: String,
     pub name: String,
+    #[serde(default)]
+    pub username: String,
     pub email: String,
     pub password: String,
     pub created_at: String,
@@ -14,6 +17,7 @@ pub struct MockUserDTO {
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MockInsertUserDTO {
     pub name: String,
+    pub username: String,
     pub email: String,
     pub password: String,
 }
@@ -22,8 +26,10 @@ impl Into<InsertUserDTO> for MockInsertUserDTO {
     fn into(self) -> InsertUserDTO {
         InsertUserDTO {
             name: self.name,
+            username: self.username,
             email: self.email,
             password: self.password,
+            invite_code: None,
         }
     }
 }
@@ -44,6 +50,7 @@ impl Into<LoginUserDTO> for MockLoginUserDTO {
 }
 
 #[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct MockDetailUserDTO {
     pub id: String,
     pub name: String,
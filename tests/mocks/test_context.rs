@@ -0,0 +1,62 @@
+use deadpool_redis::{ConnectionAddr, ConnectionInfo, RedisConnectionInfo};
+use navarro_blog_api::infra::postgres::Postgres;
+use once_cell::sync::Lazy;
+use testcontainers::{clients::Cli, Container, RunnableImage};
+use testcontainers_modules::{postgres::Postgres as PostgresImage, redis::Redis as RedisImage};
+use tokio_postgres::NoTls;
+
+static DOCKER: Lazy<Cli> = Lazy::new(Cli::default);
+
+/// Isolated Postgres and Redis instances for a single test.
+///
+/// The containers are stopped and removed when the context is dropped, so the tests that use it
+/// never share state. It needs a running Docker daemon. Pass it to `PostgresModels` and
+/// `RedisModels` to get its pools; with `None` they return the shared Postgres and Redis configured
+/// in the env vars, and the test must clean up the rows it creates.
+pub struct TestContext {
+    pub postgres_pool: deadpool_postgres::Pool,
+    pub redis_pool: deadpool_redis::Pool,
+    _postgres_container: Container<'static, PostgresImage>,
+    _redis_container: Container<'static, RedisImage>,
+}
+
+/// Starts a `postgres:16-alpine` and a `redis:7-alpine` container and applies the migrations.
+pub async fn setup_test_context() -> TestContext {
+    let postgres_container =
+        DOCKER.run(RunnableImage::from(PostgresImage::default()).with_tag("16-alpine"));
+    let redis_container = DOCKER.run(RunnableImage::from(RedisImage).with_tag("7-alpine"));
+
+    let mut postgres_cfg = deadpool_postgres::Config::new();
+    postgres_cfg.host = Some(String::from("127.0.0.1"));
+    postgres_cfg.port = Some(postgres_container.get_host_port_ipv4(5432));
+    postgres_cfg.dbname = Some(String::from("postgres"));
+    postgres_cfg.user = Some(String::from("postgres"));
+    postgres_cfg.password = Some(String::from("postgres"));
+    let postgres_pool = postgres_cfg
+        .create_pool(Some(deadpool_postgres::Runtime::Tokio1), NoTls)
+        .unwrap();
+
+    let redis_cfg = deadpool_redis::Config::from_connection_info(ConnectionInfo {
+        addr: ConnectionAddr::Tcp(
+            String::from("127.0.0.1"),
+            redis_container.get_host_port_ipv4(6379),
+        ),
+        redis: RedisConnectionInfo {
+            db: 0,
+            username: None,
+            password: None,
+        },
+    });
+    let redis_pool = redis_cfg
+        .create_pool(Some(deadpool_redis::Runtime::Tokio1))
+        .unwrap();
+
+    Postgres::run_migrations(&postgres_pool).await.unwrap();
+
+    TestContext {
+        postgres_pool,
+        redis_pool,
+        _postgres_container: postgres_container,
+        _redis_container: redis_container,
+    }
+}
@@ -0,0 +1,219 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod newsletter_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{test, web::Data, App};
+    use chrono::{TimeZone, Utc};
+    use navarro_blog_api::modules::{
+        admin::admin_controllers::admin_controllers_module,
+        newsletter::{
+            newsletter_dtos::DigestPostDTO,
+            newsletter_services::{digest_html, next_digest_run, send_digest_service},
+        },
+    };
+
+    #[test]
+    async fn _next_digest_run() {
+        let wednesday = Utc.with_ymd_and_hms(2026, 10, 14, 15, 30, 0).unwrap();
+        assert_eq!(
+            next_digest_run(wednesday),
+            Utc.with_ymd_and_hms(2026, 10, 19, 8, 0, 0).unwrap()
+        );
+        let monday_morning = Utc.with_ymd_and_hms(2026, 10, 19, 7, 59, 59).unwrap();
+        assert_eq!(
+            next_digest_run(monday_morning),
+            Utc.with_ymd_and_hms(2026, 10, 19, 8, 0, 0).unwrap()
+        );
+        let monday_run = Utc.with_ymd_and_hms(2026, 10, 19, 8, 0, 0).unwrap();
+        assert_eq!(
+            next_digest_run(monday_run),
+            Utc.with_ymd_and_hms(2026, 10, 26, 8, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    async fn _digest_html() {
+        let html = digest_html(&[
+            DigestPostDTO {
+                id: uuid::Uuid::new_v4(),
+                title: String::from("Rust & <Actix>"),
+                summary: Some(String::from("Um resumo")),
+                url: String::from("https://navarro.blog/post/rust"),
+            },
+            DigestPostDTO {
+                id: uuid::Uuid::new_v4(),
+                title: String::from("Go"),
+                summary: None,
+                url: String::from("https://navarro.blog/post/go"),
+            },
+        ]);
+        assert!(html.contains(
+            "<li><a href=\"https://navarro.blog/post/rust\">Rust &amp; &lt;Actix&gt;</a><p>Um resumo</p></li>"
+        ));
+        assert!(html.contains("<li><a href=\"https://navarro.blog/post/go\">Go</a></li>"));
+    }
+
+    #[test]
+    async fn _send_and_trigger_digest() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let tenant_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        let user_sql = "INSERT INTO users (id, name, email, password, created_at, tenant_id) VALUES ($1, 'digest user', $2, 'x', NOW(), $3)";
+        let subscriber_id = uuid::Uuid::new_v4();
+        let unverified_id = uuid::Uuid::new_v4();
+        for (user_id, email_verified) in [(subscriber_id, true), (unverified_id, false)] {
+            client
+                .execute(
+                    user_sql,
+                    &[&user_id, &format!("{}@gmail.com", user_id), &tenant_id],
+                )
+                .await
+                .unwrap();
+            client
+                .execute(
+                    "INSERT INTO user_preferences (user_id, preferences) VALUES ($1, '{\"receiveNewsletter\": true}'::JSONB)",
+                    &[&user_id],
+                )
+                .await
+                .unwrap();
+            client
+                .execute(
+                    "INSERT INTO user_onboarding (user_id, email_verified) VALUES ($1, $2)",
+                    &[&user_id, &email_verified],
+                )
+                .await
+                .unwrap();
+        }
+        let post_sql = "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at, tenant_id) VALUES ($1, $2, 'sub', 'body', $3, NOW(), NOW() - $4::TEXT::INTERVAL, $5)";
+        let mut post_ids = Vec::new();
+        for (title, age) in [
+            ("Popular", "1 day"),
+            ("Recente", "2 days"),
+            ("Antigo", "10 days"),
+        ] {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    post_sql,
+                    &[&post_id, &title, &subscriber_id, &age, &tenant_id],
+                )
+                .await
+                .unwrap();
+            post_ids.push(post_id);
+        }
+        client
+            .execute(
+                "INSERT INTO users_posts_likes (user_id, post_id) VALUES ($1, $2)",
+                &[&unverified_id, &post_ids[1]],
+            )
+            .await
+            .unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        let email = server
+            .mock("POST", "/send")
+            .match_header("Authorization", "Bearer digest-key")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "to": format!("{}@gmail.com", subscriber_id),
+                "subject": "Os destaques da semana no Navarro Blog",
+            })))
+            .with_status(200)
+            .create_async()
+            .await;
+        std::env::set_var("EMAIL_API_URL", format!("{}/send", server.url()));
+        std::env::set_var("EMAIL_API_KEY", "digest-key");
+
+        let summary = send_digest_service(
+            pg_pool.clone(),
+            Data::new(RedisModels::pool_success(None).await),
+        )
+        .await
+        .unwrap();
+        assert!(summary.sent >= 1);
+        email.assert_async().await;
+
+        let rows = client
+            .query(
+                "SELECT subscriber_id, post_ids FROM digest_deliveries WHERE subscriber_id = ANY($1)",
+                &[&vec![subscriber_id, unverified_id]],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        let delivered_to: uuid::Uuid = rows[0].get("subscriber_id");
+        let delivered_posts: Vec<uuid::Uuid> = rows[0].get("post_ids");
+        assert_eq!(delivered_to, subscriber_id);
+        assert_eq!(delivered_posts, vec![post_ids[1], post_ids[0]]);
+
+        client
+            .execute(
+                "DELETE FROM users_posts_likes WHERE post_id = ANY($1)",
+                &[&post_ids],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM user_preferences WHERE user_id = ANY($1)",
+                &[&vec![subscriber_id, unverified_id]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM users WHERE id = ANY($1)",
+                &[&vec![subscriber_id, unverified_id]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tenants WHERE id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(admin_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/admin/digest/trigger")
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string())
+                ),
+            ))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 403);
+
+        let req = test::TestRequest::post()
+            .uri("/admin/digest/trigger")
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string())
+                ),
+            ))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 202);
+    }
+}
@@ -0,0 +1,96 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod pending_email_specs {
+    use crate::mocks::models::{postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::{
+        infra::redis::Redis,
+        modules::newsletter::newsletter_services::{
+            flush_pending_emails_service, list_pending_emails_service, RateLimitedEmailSender,
+        },
+    };
+
+    #[test]
+    async fn _rate_limited_email_sender_queues_over_the_limit() {
+        dotenv::dotenv().ok();
+        std::env::set_var("EMAIL_MAX_PER_HOUR", "1");
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = Data::new(RedisModels::pool_success(None).await);
+        let domain = format!("{}.com", uuid::Uuid::new_v4().simple());
+        let (first, second) = (format!("first@{}", domain), format!("second@{}", domain));
+
+        let mut server = mockito::Server::new_async().await;
+        let mut emails = Vec::new();
+        for to in [&first, &second] {
+            emails.push(
+                server
+                    .mock("POST", "/send")
+                    .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                        "to": to,
+                        "subject": "Assunto",
+                    })))
+                    .with_status(200)
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+        std::env::set_var("EMAIL_API_URL", format!("{}/send", server.url()));
+        std::env::set_var("EMAIL_API_KEY", "email-key");
+
+        let sender = RateLimitedEmailSender::new(pg_pool.clone(), redis_pool.clone());
+        assert_eq!(sender.max_per_hour, 1);
+        sender.send(&first, "Assunto", "<p>1</p>").await.unwrap();
+        sender.send(&second, "Assunto", "<p>2</p>").await.unwrap();
+        emails[0].assert_async().await;
+
+        let pending = list_pending_emails_service(pg_pool.clone()).await.unwrap();
+        let queued: Vec<_> = pending
+            .iter()
+            .filter(|email| email.to.ends_with(&domain))
+            .collect();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].to, second);
+        assert_eq!(queued[0].subject, "Assunto");
+
+        flush_pending_emails_service(pg_pool.clone(), redis_pool.clone())
+            .await
+            .unwrap();
+        let client = pg_pool.get().await.unwrap();
+        let unsent_sql =
+            "SELECT COUNT(*) FROM pending_emails WHERE \"to\" LIKE $1 AND sent_at IS NULL";
+        let recipients = format!("%@{}", domain);
+        let unsent: i64 = client
+            .query_one(unsent_sql, &[&recipients])
+            .await
+            .unwrap()
+            .get(0);
+        assert_eq!(unsent, 1);
+
+        Redis::delete(&redis_pool, &format!("email:rate:{}", domain))
+            .await
+            .unwrap();
+        flush_pending_emails_service(pg_pool.clone(), redis_pool.clone())
+            .await
+            .unwrap();
+        emails[1].assert_async().await;
+        let unsent: i64 = client
+            .query_one(unsent_sql, &[&recipients])
+            .await
+            .unwrap()
+            .get(0);
+        assert_eq!(unsent, 0);
+        let pending = list_pending_emails_service(pg_pool.clone()).await.unwrap();
+        assert!(!pending.iter().any(|email| email.to.ends_with(&domain)));
+
+        client
+            .execute(
+                "DELETE FROM pending_emails WHERE \"to\" LIKE $1",
+                &[&recipients],
+            )
+            .await
+            .unwrap();
+        let _ = Redis::delete(&redis_pool, &format!("email:rate:{}", domain)).await;
+    }
+}
@@ -0,0 +1,207 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod poll_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::{
+        middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+        modules::{
+            poll::{
+                poll_dtos::InsertPollDTO,
+                poll_services::{insert_poll_service, vote_poll_service},
+            },
+            post::post_services::detail_post_service,
+        },
+    };
+
+    async fn seed_user() -> uuid::Uuid {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        user_id
+    }
+
+    async fn cleanup(user_ids: &[uuid::Uuid]) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM posts WHERE author_id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+    }
+
+    fn poll_body() -> InsertPollDTO {
+        InsertPollDTO {
+            question: String::from("Qual framework você prefere?"),
+            options: vec![String::from("Actix Web"), String::from(" Axum ")],
+            ends_at: Some((chrono::Utc::now() + chrono::Duration::days(7)).to_rfc3339()),
+        }
+    }
+
+    #[test]
+    async fn _poll() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let author_id = seed_user().await;
+        let voter_id = seed_user().await;
+        let post_id = uuid::Uuid::new_v4();
+        let slug = format!("enquete-{}", &post_id.simple().to_string()[..8]);
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "INSERT INTO posts (id, slug, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, $2, 'Rust', 'sub', 'body', $3, NOW(), NOW())",
+                &[&post_id, &slug, &author_id],
+            )
+            .await
+            .unwrap();
+
+        let post = detail_post_service(
+            pg_pool.clone(),
+            slug.clone(),
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert!(post.poll.is_none());
+
+        let resp = insert_poll_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            voter_id.to_string(),
+            poll_body(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+
+        let poll = insert_poll_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            poll_body(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(poll.options.len(), 2);
+        assert_eq!(poll.options[1].text, "Axum");
+        assert_eq!(poll.options[1].position, 2);
+        assert!(!poll.closed);
+
+        let resp = insert_poll_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            poll_body(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 409);
+
+        let resp = vote_poll_service(
+            pg_pool.clone(),
+            poll.id.clone(),
+            voter_id.to_string(),
+            uuid::Uuid::new_v4().to_string(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 400);
+
+        let results = vote_poll_service(
+            pg_pool.clone(),
+            poll.id.clone(),
+            voter_id.to_string(),
+            poll.options[0].id.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(results.options[0].vote_count, 1);
+        assert_eq!(results.options[1].vote_count, 0);
+
+        let resp = vote_poll_service(
+            pg_pool.clone(),
+            poll.id.clone(),
+            voter_id.to_string(),
+            poll.options[1].id.clone(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 409);
+
+        client
+            .execute(
+                "UPDATE polls SET ends_at = NOW() - INTERVAL '1 minute' WHERE id = $1::TEXT::UUID",
+                &[&poll.id],
+            )
+            .await
+            .unwrap();
+        let resp = vote_poll_service(
+            pg_pool.clone(),
+            poll.id.clone(),
+            author_id.to_string(),
+            poll.options[1].id.clone(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 410);
+
+        let post = detail_post_service(pg_pool.clone(), slug, String::from(DEFAULT_TENANT_ID))
+            .await
+            .unwrap()
+            .unwrap();
+        let post_poll = post.poll.unwrap();
+        assert!(post_poll.closed);
+        assert_eq!(post_poll.options[0].vote_count, 1);
+
+        cleanup(&[author_id, voter_id]).await;
+    }
+
+    #[test]
+    async fn _poll_error() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+
+        let resp = detail_post_service(
+            pg_pool.clone(),
+            String::from("nao-existe"),
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        let mut body = poll_body();
+        body.ends_at = Some(String::from("2020-01-01T00:00:00Z"));
+        let resp = insert_poll_service(
+            pg_pool.clone(),
+            uuid::Uuid::new_v4().to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            body,
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 400);
+
+        let resp = insert_poll_service(
+            pg_pool.clone(),
+            uuid::Uuid::new_v4().to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            poll_body(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        let resp = vote_poll_service(
+            pg_pool,
+            uuid::Uuid::new_v4().to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            uuid::Uuid::new_v4().to_string(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+    }
+}
@@ -0,0 +1,179 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod post_attachment_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::{
+        middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+        modules::{
+            post::post_services::detail_post_service,
+            post_attachment::{
+                post_attachment_dtos::InsertAttachmentDTO,
+                post_attachment_providers::url_reachable_provider,
+                post_attachment_services::{delete_attachment_service, insert_attachment_service},
+            },
+        },
+    };
+    use validator::Validate;
+
+    fn attachment_body(mime_type: &str) -> InsertAttachmentDTO {
+        InsertAttachmentDTO {
+            url: String::from("https://cdn.navarro.tech/exemplos.zip"),
+            filename: String::from("exemplos.zip"),
+            size_bytes: 20480,
+            mime_type: String::from(mime_type),
+        }
+    }
+
+    #[test]
+    async fn _attachment_mime_type() {
+        for mime_type in [
+            "application/pdf",
+            "application/zip",
+            "text/plain",
+            "image/png",
+        ] {
+            assert!(attachment_body(mime_type).validate().is_ok());
+        }
+        for mime_type in ["application/x-msdownload", "text/html", "image/"] {
+            assert!(attachment_body(mime_type).validate().is_err());
+        }
+    }
+
+    #[test]
+    async fn _url_reachable() {
+        let mut server = mockito::Server::new_async().await;
+        let ok = server
+            .mock("HEAD", "/exemplos.zip")
+            .with_status(200)
+            .create_async()
+            .await;
+        let missing = server
+            .mock("HEAD", "/perdido.zip")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        assert!(
+            url_reachable_provider(&format!("{}/exemplos.zip", server.url()))
+                .await
+                .is_ok()
+        );
+        let resp = url_reachable_provider(&format!("{}/perdido.zip", server.url())).await;
+        assert_eq!(resp.err().unwrap().status(), 400);
+        ok.assert_async().await;
+        missing.assert_async().await;
+    }
+
+    #[test]
+    async fn _post_attachment() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let (author_id, other_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        for user_id in [author_id, other_id] {
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                    &[&user_id, &format!("{}@gmail.com", user_id)],
+                )
+                .await
+                .unwrap();
+        }
+        let post_id = uuid::Uuid::new_v4();
+        let slug = format!("anexo-{}", &post_id.simple().to_string()[..8]);
+        client
+            .execute(
+                "INSERT INTO posts (id, slug, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, $2, 'Rust', 'sub', 'body', $3, NOW(), NOW())",
+                &[&post_id, &slug, &author_id],
+            )
+            .await
+            .unwrap();
+
+        let resp = insert_attachment_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            other_id.to_string(),
+            attachment_body("application/zip"),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+
+        let resp = insert_attachment_service(
+            pg_pool.clone(),
+            uuid::Uuid::new_v4().to_string(),
+            author_id.to_string(),
+            attachment_body("application/zip"),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        let attachment = insert_attachment_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            attachment_body("application/zip"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(attachment.post_id, post_id.to_string());
+        assert_eq!(attachment.size_bytes, 20480);
+
+        let post = detail_post_service(
+            pg_pool.clone(),
+            slug.clone(),
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let attachments = post.attachments.unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].id, attachment.id);
+
+        let resp = delete_attachment_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            attachment.id.clone(),
+            other_id.to_string(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+
+        delete_attachment_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            attachment.id.clone(),
+            author_id.to_string(),
+        )
+        .await
+        .unwrap();
+        let resp = delete_attachment_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            attachment.id,
+            author_id.to_string(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        let post = detail_post_service(pg_pool.clone(), slug, String::from(DEFAULT_TENANT_ID))
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(post.attachments.unwrap().is_empty());
+
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM users WHERE id = ANY($1)",
+                &[&vec![author_id, other_id]],
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,279 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod post_import_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::modules::post_import::post_import_services::{
+        bulk_import_posts_service, import_markdown_post_service, parse_import_file,
+        parse_markdown_file, parse_rss_feed,
+    };
+
+    #[test]
+    async fn _parse_import_file() {
+        let csv = "title,slug,body,author_email,published_at\n\
+            Rust,rust,Corpo do post,a@gmail.com,2024-06-18T22:03:54Z\n\
+            Actix,actix,Corpo do post,a@gmail.com,\n";
+        let rows = parse_import_file("text/csv", csv.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].0, 2);
+        assert_eq!(rows[1].1.as_ref().unwrap().slug, "actix");
+
+        let ndjson = "{\"title\":\"Rust\",\"slug\":\"rust\",\"body\":\"Corpo do post\",\"author_email\":\"a@gmail.com\"}\n\nnot json\n";
+        let rows = parse_import_file("application/x-ndjson", ndjson.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].1.is_ok());
+        assert!(rows[1].1.is_err());
+    }
+
+    #[test]
+    async fn _parse_import_file_error() {
+        let ndjson = "{}\n".repeat(1001);
+        let resp = parse_import_file("application/x-ndjson", ndjson.as_bytes());
+        assert_eq!(resp.err().unwrap().status(), 413);
+
+        let resp = parse_import_file("application/pdf", b"%PDF");
+        assert_eq!(resp.err().unwrap().status(), 415);
+    }
+
+    #[test]
+    async fn _bulk_import_posts() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let author_id = uuid::Uuid::new_v4();
+        let email = format!("{}@gmail.com", author_id);
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&author_id, &email],
+            )
+            .await
+            .unwrap();
+        let existing_slug = format!("existing-{}", &author_id.simple().to_string()[..8]);
+        let new_slug = format!("new-{}", &author_id.simple().to_string()[..8]);
+        client
+            .execute(
+                "INSERT INTO posts (id, slug, title, subtitle, body, created_at) VALUES ($1, $2, 'Old', '', 'Old body', NOW())",
+                &[&uuid::Uuid::new_v4(), &existing_slug],
+            )
+            .await
+            .unwrap();
+
+        let csv = format!(
+            "title,slug,body,author_email,published_at\n\
+            Novo post,{new},Corpo do novo post,{email},2024-06-18T22:03:54Z\n\
+            Post atualizado,{existing},Corpo atualizado,{email},\n\
+            Sem autor,orphan-post,Corpo do post,ghost@gmail.com,\n\
+            Slug inválido,Slug Inválido,Corpo do post,{email},\n\
+            Repetido,{new},Corpo do post,{email},\n\
+            Data inválida,bad-date,Corpo do post,{email},ontem\n",
+            new = new_slug,
+            existing = existing_slug,
+            email = email
+        );
+        let rows = parse_import_file("text/csv", csv.as_bytes()).unwrap();
+        let result = bulk_import_posts_service(pg_pool.clone(), rows)
+            .await
+            .unwrap();
+
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.updated, 1);
+        let error_rows: Vec<usize> = result.errors.iter().map(|error| error.row).collect();
+        assert_eq!(error_rows, vec![3, 4, 5, 6]);
+
+        let updated = client
+            .query_one(
+                "SELECT title, author_id, language FROM posts WHERE slug = $1",
+                &[&existing_slug],
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.get::<_, String>("language"), "pt");
+        assert_eq!(updated.get::<_, String>("title"), "Post atualizado");
+        assert_eq!(
+            updated.get::<_, Option<uuid::Uuid>>("author_id"),
+            Some(author_id)
+        );
+
+        client
+            .execute(
+                "DELETE FROM posts WHERE slug = ANY($1)",
+                &[&vec![existing_slug, new_slug]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&author_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _parse_rss_feed() {
+        let rss = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+            <channel>
+                <title>Blog antigo</title>
+                <link>https://blog.antigo.com</link>
+                <description>Posts do blog antigo</description>
+                <item>
+                    <title>Rust e Actix Web</title>
+                    <link>https://blog.antigo.com/2024/06/rust-e-actix-web.html</link>
+                    <description>Resumo do post</description>
+                    <content:encoded><![CDATA[<p>Corpo completo do post</p>]]></content:encoded>
+                    <pubDate>Tue, 18 Jun 2024 22:03:54 GMT</pubDate>
+                </item>
+                <item>
+                    <title>Sem link na URL</title>
+                    <link>https://blog.antigo.com/</link>
+                    <description>Corpo do segundo post</description>
+                </item>
+                <item>
+                    <description>Item sem título</description>
+                </item>
+                <item>
+                    <title>Data inválida</title>
+                    <description>Corpo do post</description>
+                    <pubDate>ontem</pubDate>
+                </item>
+            </channel>
+            </rss>"#;
+        let rows = parse_rss_feed(rss.as_bytes(), "a@gmail.com").unwrap();
+        assert_eq!(rows.len(), 4);
+
+        let post = rows[0].1.as_ref().unwrap();
+        assert_eq!(post.slug, "rust-e-actix-web");
+        assert_eq!(post.body, "<p>Corpo completo do post</p>");
+        assert_eq!(post.author_email, "a@gmail.com");
+        assert_eq!(
+            post.published_at.as_deref(),
+            Some("2024-06-18T22:03:54+00:00")
+        );
+
+        let post = rows[1].1.as_ref().unwrap();
+        assert_eq!(post.slug, "sem-link-na-url");
+        assert_eq!(post.body, "Corpo do segundo post");
+        assert!(rows[2].1.is_err());
+        assert!(rows[3].1.is_err());
+
+        assert!(parse_rss_feed(b"<html></html>", "a@gmail.com").is_err());
+    }
+
+    #[test]
+    async fn _parse_markdown_file() {
+        let markdown = "---\ntitle: Rust e Actix Web\nslug: rust-e-actix-web\nauthor_email: a@gmail.com\ntags: [rust, web]\n---\n\n# Introdução\n\nCorpo do post\n";
+        let (front_matter, body) = parse_markdown_file(markdown.as_bytes()).unwrap();
+        assert_eq!(front_matter.title, "Rust e Actix Web");
+        assert_eq!(front_matter.tags, vec!["rust", "web"]);
+        assert!(front_matter.categories.is_empty());
+        assert_eq!(body, "# Introdução\n\nCorpo do post");
+
+        assert!(parse_markdown_file(b"# Sem front-matter").is_err());
+        assert!(parse_markdown_file(b"---\ntitle: Sem fim\n").is_err());
+        assert!(parse_markdown_file(b"---\ntitle: [\n---\nCorpo").is_err());
+        assert!(parse_markdown_file(b"---\ntitle: Sem slug\n---\nCorpo").is_err());
+    }
+
+    #[test]
+    async fn _import_markdown_post() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let author_id = uuid::Uuid::new_v4();
+        let email = format!("{}@gmail.com", author_id);
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&author_id, &email],
+            )
+            .await
+            .unwrap();
+        let suffix = &author_id.simple().to_string()[..8];
+        let slug = format!("markdown-{}", suffix);
+        let tag = format!("tag-{}", suffix);
+        let category = format!("category-{}", suffix);
+        let markdown = format!(
+            "---\ntitle: Rust e Actix Web\nslug: {slug}\nauthor_email: {email}\npublished_at: 2024-06-18T22:03:54Z\ntags: [{tag}, {tag}]\ncategories: [{category}]\n---\nThis is a post about Rust and the way it is growing.\n"
+        );
+
+        let result = import_markdown_post_service(
+            pg_pool.clone(),
+            String::from("post.md"),
+            markdown.as_bytes(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.status, 201);
+        let post_id = uuid::Uuid::parse_str(&result.post_id.unwrap()).unwrap();
+        let post = client
+            .query_one(
+                "SELECT author_id, language, published_at IS NOT NULL AS published, (SELECT COUNT(*) FROM posts_tags WHERE post_id = $1) AS tags, (SELECT COUNT(*) FROM posts_categories WHERE post_id = $1) AS categories FROM posts WHERE id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            post.get::<_, Option<uuid::Uuid>>("author_id"),
+            Some(author_id)
+        );
+        assert_eq!(post.get::<_, String>("language"), "en");
+        assert!(post.get::<_, bool>("published"));
+        assert_eq!(post.get::<_, i64>("tags"), 1);
+        assert_eq!(post.get::<_, i64>("categories"), 1);
+
+        let duplicate = import_markdown_post_service(
+            pg_pool.clone(),
+            String::from("post.md"),
+            markdown.as_bytes(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(duplicate.status, 409);
+        let invalid = import_markdown_post_service(
+            pg_pool.clone(),
+            String::from("invalid.md"),
+            b"# Sem front-matter",
+        )
+        .await
+        .unwrap();
+        assert_eq!(invalid.status, 400);
+        let short_body = import_markdown_post_service(
+            pg_pool.clone(),
+            String::from("short.md"),
+            format!("---\ntitle: Curto\nslug: curto-{suffix}\nauthor_email: {email}\n---\nCurto")
+                .as_bytes(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(short_body.status, 400);
+
+        client
+            .execute("DELETE FROM posts_tags WHERE post_id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM posts_categories WHERE post_id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tags WHERE name = $1", &[&tag])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM categories WHERE name = $1", &[&category])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&author_id])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,2135 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod post_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{
+        body,
+        http::header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH, LOCATION},
+        test,
+        web::Data,
+        App,
+    };
+    use navarro_blog_api::{
+        middlewares::tenant_middleware::{DEFAULT_TENANT_ID, TENANT_HEADER},
+        modules::post::{
+            post_controllers::post_controllers_module,
+            post_dto::{
+                ArchiveMonthDTO, CopiedPostDTO, CoverImageDTO, DiffChunkDTO, EmbedMetaDTO,
+                ExcerptPostDTO, LintWarningDTO, ListPostsParams, PostDTO, PostLinkDTO, PostLockDTO,
+                PostLockedDTO, SponsorshipDTO, UpdatePostDTO,
+            },
+            post_providers::{
+                detect_language_provider, image_url_provider, lint_markdown_provider,
+                validate_structured_data_provider, DEFAULT_OG_IMAGE_URL,
+            },
+            post_queues::publish_scheduled_posts,
+            post_repositories::{expire_post_locks_repository, trending_posts_repository},
+            post_services::{
+                compute_character_diff, compute_diff, copy_slug, detail_post_service,
+                invalidate_archive_service, list_posts_service, put_sponsorship_service,
+                update_post_service,
+            },
+        },
+        shared::structs::cursor_params::CursorPage,
+    };
+    use validator::Validate;
+
+    async fn seed() -> (uuid::Uuid, uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let author_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&author_id, &format!("{}@gmail.com", author_id)],
+            )
+            .await
+            .unwrap();
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), NOW())",
+                &[&post_id, &author_id],
+            )
+            .await
+            .unwrap();
+        (author_id, post_id)
+    }
+
+    async fn cleanup(author_id: uuid::Uuid, post_id: uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&author_id])
+            .await
+            .unwrap();
+    }
+
+    fn sponsorship(sponsored_by: Option<&str>, sponsor_url: Option<&str>) -> SponsorshipDTO {
+        SponsorshipDTO {
+            sponsored_by: sponsored_by.map(String::from),
+            sponsor_url: sponsor_url.map(String::from),
+        }
+    }
+
+    async fn sponsored_ids(pg_pool: Data<deadpool_postgres::Pool>) -> Vec<String> {
+        let page = list_posts_service(
+            pg_pool,
+            ListPostsParams {
+                cursor: None,
+                limit: Some(100),
+                sponsored: Some(true),
+                lang: None,
+                code_language: None,
+            },
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await
+        .unwrap();
+        page.data.into_iter().map(|post| post.id).collect()
+    }
+
+    #[test]
+    async fn _put_sponsorship() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+
+        let post = put_sponsorship_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            false,
+            sponsorship(Some("Navarro Tech"), Some("https://navarro.tech")),
+        )
+        .await
+        .unwrap();
+        assert!(post.is_sponsored);
+        assert_eq!(post.sponsor_url.as_deref(), Some("https://navarro.tech"));
+        assert!(sponsored_ids(pg_pool.clone())
+            .await
+            .contains(&post_id.to_string()));
+
+        let post = put_sponsorship_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            true,
+            sponsorship(Some("Bush Labs"), None),
+        )
+        .await
+        .unwrap();
+        assert_eq!(post.sponsored_by.as_deref(), Some("Bush Labs"));
+        assert!(post.sponsor_url.is_none());
+
+        let post = put_sponsorship_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            false,
+            sponsorship(None, None),
+        )
+        .await
+        .unwrap();
+        assert!(!post.is_sponsored);
+        let body = serde_json::to_value(&post).unwrap();
+        assert!(body.get("sponsoredBy").is_none());
+        assert!(!sponsored_ids(pg_pool).await.contains(&post_id.to_string()));
+
+        cleanup(author_id, post_id).await;
+    }
+
+    fn update(title: &str, version: i32) -> UpdatePostDTO {
+        UpdatePostDTO {
+            title: String::from(title),
+            subtitle: String::from("sub"),
+            body: String::from("Conteúdo atualizado do post"),
+            summary: None,
+            version,
+            language: None,
+            slug: None,
+            canonical_url: None,
+            scheduled_at: None,
+            allow_anonymous_comments: None,
+            moderation_mode: None,
+            structured_data: None,
+        }
+    }
+
+    #[test]
+    async fn _update_post_duplicate_content() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let duplicate_id = uuid::Uuid::new_v4();
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, 'Duplicado', 'sub', 'Conteúdo atualizado do post', $2, NOW(), NOW())",
+                &[&duplicate_id, &author_id],
+            )
+            .await
+            .unwrap();
+
+        let resp = update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            update("Duplicado", 0),
+        )
+        .await;
+        let resp = resp.err().unwrap();
+        assert_eq!(resp.status(), 409);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        let error: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            error["post"][0]["params"]["value"],
+            duplicate_id.to_string()
+        );
+
+        let post = update_post_service(
+            pg_pool,
+            duplicate_id.to_string(),
+            author_id.to_string(),
+            update("Duplicado", 0),
+        )
+        .await
+        .unwrap();
+        assert_eq!(post.version, 1);
+        assert_eq!(post.warnings, Some(Vec::new()));
+
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&duplicate_id])
+            .await
+            .unwrap();
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _update_post_concurrent_edit() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+
+        let (first, second) = futures_util::join!(
+            update_post_service(
+                pg_pool.clone(),
+                post_id.to_string(),
+                author_id.to_string(),
+                update("Primeira sessão", 0),
+            ),
+            update_post_service(
+                pg_pool.clone(),
+                post_id.to_string(),
+                author_id.to_string(),
+                update("Segunda sessão", 0),
+            )
+        );
+        let (updated, conflict) = match (first, second) {
+            (Ok(post), Err(e)) | (Err(e), Ok(post)) => (post, e),
+            _ => panic!("exactly one of the concurrent updates must succeed"),
+        };
+        assert_eq!(updated.version, 1);
+        assert_eq!((updated.word_count, updated.character_count), (4, 27));
+        assert_eq!(conflict.status(), 409);
+
+        let post = update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            update("Terceira sessão", updated.version),
+        )
+        .await
+        .unwrap();
+        assert_eq!(post.title, "Terceira sessão");
+        assert_eq!(post.version, 2);
+
+        let resp = update_post_service(
+            pg_pool,
+            post_id.to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            update("Outro autor", post.version),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _detect_language_provider() {
+        let cases = [
+            ("Este é um post sobre Rust, que não para de crescer.", "pt"),
+            ("This is a post about Rust and the way it is growing.", "en"),
+            (
+                "Este es un artículo sobre Rust y la forma en que crece.",
+                "es",
+            ),
+            (
+                "Ceci est un article sur Rust et les outils pour le web.",
+                "fr",
+            ),
+            (
+                "Das ist ein Artikel über Rust und die Sprache ist schnell.",
+                "de",
+            ),
+            ("Rust 1.80", "pt"),
+        ];
+        for (body, language) in cases {
+            assert_eq!(detect_language_provider(body), language, "{}", body);
+        }
+    }
+
+    fn lint_rules(body: &str) -> Vec<(usize, usize, String)> {
+        lint_markdown_provider(body)
+            .into_iter()
+            .map(|warning: LintWarningDTO| (warning.line, warning.column, warning.rule))
+            .collect()
+    }
+
+    #[test]
+    async fn _lint_markdown_provider_unclosed_code_fence() {
+        let body =
+            "# Rust\n\n```rust\nfn main() {}\n```\n\nTexto\n\n  ```\n[](https://navarro.dev)";
+        assert_eq!(
+            lint_rules(body),
+            vec![(9, 3, String::from("unclosed_code_fence"))]
+        );
+        assert!(lint_markdown_provider("```rust\nfn main() {}\n```").is_empty());
+    }
+
+    #[test]
+    async fn _lint_markdown_provider_empty_link_text() {
+        let body = "Veja [o repositório](https://github.com) e [](https://navarro.dev).\n[ ](https://x.com)";
+        assert_eq!(
+            lint_rules(body),
+            vec![
+                (1, 44, String::from("empty_link_text")),
+                (2, 1, String::from("empty_link_text")),
+            ]
+        );
+    }
+
+    #[test]
+    async fn _lint_markdown_provider_image_missing_alt() {
+        let body = "![Logo do Rust](rust.png)\nÍcone: ![](ferris.png)";
+        let warnings = lint_markdown_provider(body);
+        assert_eq!(
+            warnings,
+            vec![LintWarningDTO {
+                line: 2,
+                column: 8,
+                rule: String::from("image_missing_alt"),
+                message: String::from("A imagem não tem texto alternativo."),
+            }]
+        );
+    }
+
+    #[test]
+    async fn _lint_markdown_provider_heading_level_skip() {
+        let body =
+            "# Rust\n### Instalação\n## Uso\n### Exemplos\n# Conclusão\n```\n#### Código\n```";
+        assert_eq!(
+            lint_rules(body),
+            vec![(2, 1, String::from("heading_level_skip"))]
+        );
+        assert!(lint_markdown_provider("## Rust\n### Instalação\n#hashtag").is_empty());
+    }
+
+    #[test]
+    async fn _validate_structured_data_provider() {
+        let cells = |total: usize| vec![String::from("Rust"); total];
+        let table = serde_json::json!({
+            "headers": ["Linguagem", "Ano"],
+            "rows": [["Rust", "2015"], ["Go", "2009"]],
+        });
+        assert!(validate_structured_data_provider(&table).is_ok());
+        assert!(validate_structured_data_provider(&serde_json::Value::Null).is_ok());
+
+        let invalid = [
+            serde_json::json!({ "rows": [["Rust"]] }),
+            serde_json::json!({ "headers": [], "rows": [] }),
+            serde_json::json!({ "headers": ["Linguagem", "Ano"], "rows": [["Rust"]] }),
+            serde_json::json!({ "headers": ["Linguagem"], "rows": [[2015]] }),
+            serde_json::json!({ "headers": ["Linguagem"], "rows": [], "extra": true }),
+            serde_json::json!({ "headers": cells(11), "rows": [] }),
+            serde_json::json!({ "headers": ["Linguagem"], "rows": vec![cells(1); 101] }),
+            serde_json::json!(["Linguagem", "Ano"]),
+        ];
+        for data in invalid {
+            let e = validate_structured_data_provider(&data).unwrap_err();
+            assert_eq!(e.code, "structured_data", "{}", data);
+        }
+
+        let body = UpdatePostDTO {
+            structured_data: Some(serde_json::json!({ "headers": ["Linguagem"] })),
+            ..update("Rust", 0)
+        };
+        assert!(body.validate().is_err());
+    }
+
+    #[test]
+    async fn _list_posts_by_language() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "UPDATE posts SET language = 'en' WHERE id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+
+        let list = |lang: &str| {
+            list_posts_service(
+                pg_pool.clone(),
+                ListPostsParams {
+                    cursor: None,
+                    limit: Some(100),
+                    sponsored: None,
+                    lang: Some(String::from(lang)),
+                    code_language: None,
+                },
+                String::from(DEFAULT_TENANT_ID),
+            )
+        };
+        let english = list("en").await.unwrap();
+        assert!(english.data.iter().all(|post| post.language == "en"));
+        assert!(english
+            .data
+            .iter()
+            .any(|post| post.id == post_id.to_string()));
+        let portuguese = list("pt").await.unwrap();
+        assert!(!portuguese
+            .data
+            .iter()
+            .any(|post| post.id == post_id.to_string()));
+        assert_eq!(list("xx").await.err().unwrap().status(), 400);
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _list_posts_by_code_language() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+
+        let mut body = update("Rust e Python", 0);
+        body.body = String::from(
+            "Exemplos:\n\n```rust\nfn main() {}\n```\n\n```Python\nprint()\n```\n\n```rust\nlet x = 1;\n```",
+        );
+        let post = update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            body,
+        )
+        .await
+        .unwrap();
+        assert_eq!(post.code_languages, vec!["rust", "python"]);
+
+        let list = |code_language: &str| {
+            list_posts_service(
+                pg_pool.clone(),
+                ListPostsParams {
+                    cursor: None,
+                    limit: Some(100),
+                    sponsored: None,
+                    lang: None,
+                    code_language: Some(String::from(code_language)),
+                },
+                String::from(DEFAULT_TENANT_ID),
+            )
+        };
+        let rust = list("Rust").await.unwrap();
+        assert!(rust
+            .data
+            .iter()
+            .all(|post| post.code_languages.contains(&String::from("rust"))));
+        assert!(rust.data.iter().any(|post| post.id == post_id.to_string()));
+        let go = list("go").await.unwrap();
+        assert!(!go.data.iter().any(|post| post.id == post_id.to_string()));
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _put_sponsorship_error_forbidden() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+
+        let resp = put_sponsorship_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            uuid::Uuid::new_v4().to_string(),
+            false,
+            sponsorship(Some("Navarro Tech"), None),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+
+        let resp = put_sponsorship_service(
+            pg_pool,
+            uuid::Uuid::new_v4().to_string(),
+            author_id.to_string(),
+            false,
+            sponsorship(Some("Navarro Tech"), None),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _sponsorship_url_error_bad_request() {
+        assert!(
+            sponsorship(Some("Navarro Tech"), Some("http://navarro.tech"))
+                .validate()
+                .is_err()
+        );
+        assert!(sponsorship(Some("Navarro Tech"), Some("https://"))
+            .validate()
+            .is_err());
+        assert!(
+            sponsorship(Some("Navarro Tech"), Some("https://navarro.tech/post?id=1"))
+                .validate()
+                .is_ok()
+        );
+    }
+
+    fn cover(url: &str, blurhash: Option<&str>) -> CoverImageDTO {
+        CoverImageDTO {
+            cover_image_url: String::from(url),
+            cover_image_blurhash: blurhash.map(String::from),
+        }
+    }
+
+    #[test]
+    async fn _cover_image() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let token = format!(
+            "Bearer {}",
+            JwtModels::user_access_jwt_model(author_id.to_string())
+        );
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/post/{}/cover", post_id))
+            .append_header(("Authorization", token.clone()))
+            .set_json(serde_json::json!({
+                "coverImageUrl": "https://cdn.navarro.tech/rust.webp",
+                "coverImageBlurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let post: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(post["coverImageUrl"], "https://cdn.navarro.tech/rust.webp");
+        assert_eq!(post["coverImageBlurhash"], "LEHV6nWB2yk8pyo0adR*.7kCMdnj");
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/post/{}/cover", post_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string())
+                ),
+            ))
+            .set_json(serde_json::json!({ "coverImageUrl": "https://cdn.navarro.tech/go.webp" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 403);
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/post/{}/cover", post_id))
+            .append_header(("Authorization", token))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 204);
+
+        let client = pg_pool.get().await.unwrap();
+        let row = client
+            .query_one(
+                "SELECT cover_image_url, cover_image_blurhash FROM posts WHERE id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        assert!(row.get::<_, Option<String>>("cover_image_url").is_none());
+        assert!(row
+            .get::<_, Option<String>>("cover_image_blurhash")
+            .is_none());
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _detail_post_etag() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let slug = format!("rust-{}", post_id.simple());
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "UPDATE posts SET slug = $2 WHERE id = $1",
+                &[&post_id, &slug],
+            )
+            .await
+            .unwrap();
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let token = format!(
+            "Bearer {}",
+            JwtModels::user_access_jwt_model(author_id.to_string())
+        );
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}", slug))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let etag = resp
+            .headers()
+            .get(ETAG)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_owned();
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}", slug))
+            .append_header((IF_NONE_MATCH, etag.clone()))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 304);
+        assert_eq!(resp.headers().get(ETAG).unwrap().to_str().unwrap(), etag);
+
+        let changes = [
+            (
+                format!("/post/{}/sponsorship", post_id),
+                serde_json::json!({ "sponsoredBy": "Navarro Tech" }),
+            ),
+            (
+                format!("/post/{}/cover", post_id),
+                serde_json::json!({ "coverImageUrl": "https://cdn.navarro.tech/rust.webp" }),
+            ),
+        ];
+        let mut etag = etag;
+        for (uri, body) in changes {
+            let req = test::TestRequest::patch()
+                .uri(&uri)
+                .append_header(("Authorization", token.clone()))
+                .set_json(body)
+                .to_request();
+            assert_eq!(test::call_service(&app, req).await.status(), 200);
+
+            let req = test::TestRequest::get()
+                .uri(&format!("/post/{}", slug))
+                .append_header((IF_NONE_MATCH, etag.clone()))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), 200);
+            let new_etag = resp
+                .headers()
+                .get(ETAG)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_owned();
+            assert_ne!(new_etag, etag);
+            etag = new_etag;
+        }
+
+        cleanup(author_id, post_id).await;
+    }
+
+    async fn seed_embed_meta(post_id: uuid::Uuid, cover_image_url: Option<&str>) -> String {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let slug = format!("rust-{}", post_id.simple());
+        client
+            .execute(
+                "UPDATE posts SET slug = $2, summary = $3, cover_image_url = $4 WHERE id = $1",
+                &[
+                    &post_id,
+                    &slug,
+                    &format!("# Rust & **Actix** {}", "palavra ".repeat(40)),
+                    &cover_image_url,
+                ],
+            )
+            .await
+            .unwrap();
+        slug
+    }
+
+    #[test]
+    async fn _embed_meta() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let slug = seed_embed_meta(post_id, Some("https://cdn.navarro.tech/rust.webp")).await;
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/embed-meta", slug))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let meta: EmbedMetaDTO = test::read_body_json(resp).await;
+        assert_eq!(meta.og_title, "Rust");
+        assert!(meta.og_description.starts_with("Rust & Actix palavra"));
+        assert!(meta.og_description.ends_with('…'));
+        assert_eq!(meta.og_description.chars().count(), 161);
+        assert_eq!(meta.og_image, "https://cdn.navarro.tech/rust.webp");
+        assert!(meta.og_url.ends_with(&format!("/post/{}", slug)));
+        assert_eq!(meta.twitter_card, "summary_large_image");
+
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute("UPDATE posts SET title = 'Go' WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/embed-meta", slug))
+            .to_request();
+        let meta: EmbedMetaDTO = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(meta.og_title, "Rust");
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _embed_meta_html() {
+        dotenv::dotenv().ok();
+        let (author_id, post_id) = seed().await;
+        let slug = seed_embed_meta(post_id, None).await;
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/embed-meta", slug))
+            .append_header(("Accept", "text/html,application/xhtml+xml"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "text/html; charset=utf-8"
+        );
+        let html =
+            String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec()).unwrap();
+        assert!(html.contains("<meta property=\"og:title\" content=\"Rust\">"));
+        assert!(html.contains("<meta property=\"og:description\" content=\"Rust &amp; Actix"));
+        assert!(html.contains(&format!(
+            "<meta property=\"og:image\" content=\"{}\">",
+            *DEFAULT_OG_IMAGE_URL
+        )));
+        assert!(html.contains(&format!("/post/{}\">", slug)));
+        assert!(html.contains("<meta name=\"twitter:card\""));
+
+        let req = test::TestRequest::get()
+            .uri("/post/slug-inexistente/embed-meta")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _excerpt_post() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let slug = seed_embed_meta(post_id, None).await;
+        let tag_id = uuid::Uuid::new_v4();
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "INSERT INTO tags (id, name, created_at) VALUES ($1, $2, NOW())",
+                &[&tag_id, &format!("rust-{}", tag_id.simple())],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO posts_tags (post_id, tag_id) VALUES ($1, $2)",
+                &[&post_id, &tag_id],
+            )
+            .await
+            .unwrap();
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/excerpt", slug))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers().get(CACHE_CONTROL).unwrap(),
+            "public, max-age=3600"
+        );
+        let excerpt: serde_json::Value =
+            serde_json::from_slice(&body::to_bytes(resp.into_body()).await.unwrap()).unwrap();
+        assert!(excerpt.get("body").is_none());
+        assert_eq!(excerpt["title"], "Rust");
+        assert_eq!(excerpt["slug"], slug.as_str());
+        assert_eq!(excerpt["tagNames"][0], format!("rust-{}", tag_id.simple()));
+        assert_eq!(excerpt["likeCount"], 0);
+        assert_eq!(excerpt["readingTime"], 1);
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/post/{}", post_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(author_id.to_string())
+                ),
+            ))
+            .set_json(update("Rust e Actix Web", 0))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 200);
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}/excerpt", slug))
+            .to_request();
+        let excerpt: ExcerptPostDTO = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(excerpt.title, "Rust e Actix Web");
+
+        let req = test::TestRequest::get()
+            .uri("/post/slug-inexistente/excerpt")
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 404);
+
+        client
+            .execute("DELETE FROM posts_tags WHERE post_id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tags WHERE id = $1", &[&tag_id])
+            .await
+            .unwrap();
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _cover_image_error_bad_request() {
+        assert!(cover(
+            "https://cdn.navarro.tech/rust.webp",
+            Some("LEHV6nWB2yk8pyo0adR*.7kCMdnj")
+        )
+        .validate()
+        .is_ok());
+        assert!(cover("http://cdn.navarro.tech/rust.webp", None)
+            .validate()
+            .is_err());
+        assert!(cover("capa.webp", None).validate().is_err());
+        assert!(
+            cover("https://cdn.navarro.tech/rust.webp", Some(&"L".repeat(51)))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    async fn _image_url_provider() {
+        let mut server = mockito::Server::new_async().await;
+        let image = server
+            .mock("HEAD", "/rust.webp")
+            .with_status(200)
+            .with_header("Content-Type", "image/webp")
+            .create_async()
+            .await;
+        let page = server
+            .mock("HEAD", "/rust.html")
+            .with_status(200)
+            .with_header("Content-Type", "text/html; charset=utf-8")
+            .create_async()
+            .await;
+
+        assert!(image_url_provider(&format!("{}/rust.webp", server.url()))
+            .await
+            .is_ok());
+        let resp = image_url_provider(&format!("{}/rust.html", server.url())).await;
+        assert_eq!(resp.err().unwrap().status(), 400);
+        let resp = image_url_provider(&format!("{}/perdida.webp", server.url())).await;
+        assert_eq!(resp.err().unwrap().status(), 400);
+        image.assert_async().await;
+        page.assert_async().await;
+    }
+
+    #[test]
+    async fn _trending_posts() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let mut user_ids = Vec::new();
+        for _ in 0..10 {
+            let user_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                    &[&user_id, &format!("{}@gmail.com", user_id)],
+                )
+                .await
+                .unwrap();
+            user_ids.push(user_id);
+        }
+        let (old_id, new_id, expired_id) = (
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+        );
+        for (post_id, age) in [
+            (old_id, "5 days"),
+            (new_id, "1 hour"),
+            (expired_id, "31 days"),
+        ] {
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW() - $3::TEXT::INTERVAL, NOW() - $3::TEXT::INTERVAL)",
+                    &[&post_id, &user_ids[0], &age],
+                )
+                .await
+                .unwrap();
+        }
+        let like_sql = "INSERT INTO users_posts_likes (user_id, post_id) VALUES ($1, $2)";
+        for user_id in &user_ids {
+            client.execute(like_sql, &[user_id, &old_id]).await.unwrap();
+            client
+                .execute(like_sql, &[user_id, &expired_id])
+                .await
+                .unwrap();
+        }
+        for user_id in &user_ids[..2] {
+            client.execute(like_sql, &[user_id, &new_id]).await.unwrap();
+        }
+        client
+            .execute(
+                "INSERT INTO comments (id, post_id, user_id, body, created_at) VALUES ($1, $2, $3, 'Comentário', NOW())",
+                &[&uuid::Uuid::new_v4(), &new_id, &user_ids[1]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO post_views (user_id, post_id, viewed_at) VALUES ($1, $2, NOW())",
+                &[&user_ids[2], &new_id],
+            )
+            .await
+            .unwrap();
+
+        let posts = trending_posts_repository(pg_pool.clone(), 1000, uuid::Uuid::nil())
+            .await
+            .unwrap();
+        let position = |id: uuid::Uuid| posts.iter().position(|post| post.id == id.to_string());
+        assert!(position(new_id).unwrap() < position(old_id).unwrap());
+        assert!(position(expired_id).is_none());
+        assert!(posts.iter().all(|post| post.body.is_none()));
+
+        let post_ids = vec![old_id, new_id, expired_id];
+        client
+            .execute(
+                "DELETE FROM users_posts_likes WHERE post_id = ANY($1)",
+                &[&post_ids],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM comments WHERE post_id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _update_post_slug_redirect() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let suffix = &post_id.simple().to_string()[..8];
+        let old_slug = format!("rust-{}", suffix);
+        let new_slug = format!("rust-e-actix-web-{}", suffix);
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "UPDATE posts SET slug = $2 WHERE id = $1",
+                &[&post_id, &old_slug],
+            )
+            .await
+            .unwrap();
+
+        let other = seed().await;
+        let mut body = update("Rust e Actix Web", 0);
+        body.slug = Some(old_slug.clone());
+        let resp = update_post_service(
+            pg_pool.clone(),
+            other.1.to_string(),
+            other.0.to_string(),
+            body,
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 409);
+        cleanup(other.0, other.1).await;
+
+        let mut body = update("Rust e Actix Web", 0);
+        body.slug = Some(new_slug.clone());
+        body.canonical_url = Some(String::from("https://dev.to/bush1d3v/rust-e-actix-web"));
+        let post = update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            body,
+        )
+        .await
+        .unwrap();
+        assert_eq!(post.slug.as_deref(), Some(new_slug.as_str()));
+        assert_eq!(
+            post.canonical_url.as_deref(),
+            Some("https://dev.to/bush1d3v/rust-e-actix-web")
+        );
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}", old_slug))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 301);
+        let location = resp.headers().get(LOCATION).unwrap().to_str().unwrap();
+        assert_eq!(location, format!("/post/{}", new_slug));
+        let redirect = detail_post_service(
+            pg_pool.clone(),
+            old_slug.clone(),
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await
+        .unwrap();
+        assert_eq!(redirect.err().unwrap().new_slug, new_slug);
+
+        let req = test::TestRequest::get().uri(location).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let post: serde_json::Value =
+            serde_json::from_slice(&body::to_bytes(resp.into_body()).await.unwrap()).unwrap();
+        assert_eq!(post["slug"], new_slug.as_str());
+        assert_eq!(
+            post["canonicalUrl"],
+            "https://dev.to/bush1d3v/rust-e-actix-web"
+        );
+
+        let mut body = update("Rust", 1);
+        body.slug = Some(old_slug.clone());
+        update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            body,
+        )
+        .await
+        .unwrap();
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}", old_slug))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 200);
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}", new_slug))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 301);
+        assert_eq!(
+            resp.headers().get(LOCATION).unwrap(),
+            format!("/post/{}", old_slug).as_str()
+        );
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _recompute_post_counts() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "UPDATE posts SET body = 'Olá, mundo! Ação 😀', word_count = 0, character_count = 0 WHERE id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/recompute-counts", post_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(author_id.to_string())
+                ),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let post: serde_json::Value =
+            serde_json::from_slice(&body::to_bytes(resp.into_body()).await.unwrap()).unwrap();
+        assert_eq!(post["wordCount"], 4);
+        assert_eq!(post["characterCount"], 18);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/recompute-counts", uuid::Uuid::new_v4()))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::access_jwt_model(author_id.to_string())
+                ),
+            ))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 404);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/recompute-counts", post_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(author_id.to_string())
+                ),
+            ))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 403);
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _publish_scheduled_posts() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "UPDATE posts SET published_at = NULL WHERE id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+
+        let mut body = update("Rust agendado", 0);
+        body.scheduled_at = Some(String::from("2024-06-18T22:03:54Z"));
+        assert!(body.validate().is_err());
+        body.scheduled_at = Some((chrono::Utc::now() + chrono::Duration::seconds(2)).to_rfc3339());
+        assert!(body.validate().is_ok());
+        let post = update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            body,
+        )
+        .await
+        .unwrap();
+        assert!(post.scheduled_at.is_some());
+        assert!(post.published_at.is_none());
+
+        let list = || {
+            list_posts_service(
+                pg_pool.clone(),
+                ListPostsParams {
+                    cursor: None,
+                    limit: Some(100),
+                    sponsored: None,
+                    lang: None,
+                    code_language: None,
+                },
+                String::from(DEFAULT_TENANT_ID),
+            )
+        };
+        let published = publish_scheduled_posts(pg_pool.get_ref().clone())
+            .await
+            .unwrap();
+        assert!(!published.contains(&post_id));
+        let page = list().await.unwrap();
+        assert!(!page.data.iter().any(|post| post.id == post_id.to_string()));
+
+        tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+        let published = publish_scheduled_posts(pg_pool.get_ref().clone())
+            .await
+            .unwrap();
+        assert!(published.contains(&post_id));
+        let page = list().await.unwrap();
+        assert!(page.data.iter().any(|post| post.id == post_id.to_string()));
+
+        let published = publish_scheduled_posts(pg_pool.get_ref().clone())
+            .await
+            .unwrap();
+        assert!(!published.contains(&post_id));
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _archive() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = RedisModels::pool_success(None).await;
+        let client = pg_pool.get().await.unwrap();
+        let tenant_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        let (author_id, seeded_post_id) = seed().await;
+        let post_sql = "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at, tenant_id) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), $3::TEXT::TIMESTAMPTZ, $4)";
+        let mut post_ids = Vec::new();
+        for published_at in [
+            "2024-12-03T10:00:00Z",
+            "2024-12-20T10:00:00Z",
+            "2024-11-15T10:00:00Z",
+            "2023-01-31T23:59:59Z",
+        ] {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(post_sql, &[&post_id, &author_id, &published_at, &tenant_id])
+                .await
+                .unwrap();
+            post_ids.push(post_id);
+        }
+        let draft_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, tenant_id) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), $3)",
+                &[&draft_id, &author_id, &tenant_id],
+            )
+            .await
+            .unwrap();
+        post_ids.push(draft_id);
+
+        invalidate_archive_service(&redis_pool).await;
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(redis_pool.clone()))
+                .service(post_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/post/archive")
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .to_request();
+        let months: Vec<ArchiveMonthDTO> = test::call_and_read_body_json(&app, req).await;
+        let months: Vec<(i32, u8, i64)> = months
+            .iter()
+            .map(|month| (month.year, month.month, month.count))
+            .collect();
+        assert_eq!(months, vec![(2024, 12, 2), (2024, 11, 1), (2023, 1, 1)]);
+
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                post_sql,
+                &[&post_id, &author_id, &"2024-11-01T00:00:00Z", &tenant_id],
+            )
+            .await
+            .unwrap();
+        post_ids.push(post_id);
+        let req = test::TestRequest::get()
+            .uri("/post/archive")
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .to_request();
+        let months: Vec<ArchiveMonthDTO> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(months[1].count, 1);
+
+        invalidate_archive_service(&redis_pool).await;
+        let req = test::TestRequest::get()
+            .uri("/post/archive")
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .to_request();
+        let months: Vec<ArchiveMonthDTO> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(months[1].count, 2);
+
+        let req = test::TestRequest::get()
+            .uri("/post/archive/2024/12?limit=1")
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .to_request();
+        let page: CursorPage<PostDTO> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].id, post_ids[1].to_string());
+        let req = test::TestRequest::get()
+            .uri(&format!(
+                "/post/archive/2024/12?limit=1&cursor={}",
+                page.next_cursor.unwrap()
+            ))
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .to_request();
+        let page: CursorPage<PostDTO> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].id, post_ids[0].to_string());
+        assert!(page.next_cursor.is_none());
+
+        let req = test::TestRequest::get()
+            .uri("/post/archive/2023/1")
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .to_request();
+        let page: CursorPage<PostDTO> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(page.data.len(), 1);
+        assert!(page.data[0].body.is_none());
+
+        let req = test::TestRequest::get()
+            .uri("/post/archive/2024/13")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tenants WHERE id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+        invalidate_archive_service(&redis_pool).await;
+        cleanup(author_id, seeded_post_id).await;
+    }
+
+    #[test]
+    async fn _copy_slug() {
+        let taken = vec![String::from("rust-copy"), String::from("rust-copy-2")];
+        assert_eq!(copy_slug("rust", &[]), "rust-copy");
+        assert_eq!(copy_slug("rust", &taken[..1]), "rust-copy-2");
+        assert_eq!(copy_slug("rust", &taken), "rust-copy-3");
+    }
+
+    #[test]
+    async fn _copy_post() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let (author_id, post_id) = seed().await;
+        let slug = format!("rust-{}", &post_id.simple().to_string()[..8]);
+        let (category_id, tag_id) = (uuid::Uuid::new_v4(), uuid::Uuid::new_v4());
+        client
+            .execute(
+                "UPDATE posts SET slug = $2, cover_image_url = 'https://cdn.navarro.tech/rust.webp' WHERE id = $1",
+                &[&post_id, &slug],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO categories (id, name, created_at) VALUES ($1, $2, NOW())",
+                &[&category_id, &category_id.to_string()],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO tags (id, name, created_at) VALUES ($1, $2, NOW())",
+                &[&tag_id, &tag_id.to_string()],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO posts_categories (post_id, category_id) VALUES ($1, $2)",
+                &[&post_id, &category_id],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO posts_tags (post_id, tag_id) VALUES ($1, $2)",
+                &[&post_id, &tag_id],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let copy = |jwt: String, post_id: uuid::Uuid| {
+            test::TestRequest::post()
+                .uri(&format!("/post/{}/copy", post_id))
+                .append_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+        let author_jwt = JwtModels::user_access_jwt_model(author_id.to_string());
+
+        let mut copy_ids = Vec::new();
+        for expected_slug in [format!("{}-copy", slug), format!("{}-copy-2", slug)] {
+            let resp = test::call_service(&app, copy(author_jwt.clone(), post_id)).await;
+            assert_eq!(resp.status(), 201);
+            let copied: CopiedPostDTO = test::read_body_json(resp).await;
+            let copy_id = uuid::Uuid::parse_str(&copied.post_id).unwrap();
+            copy_ids.push(copy_id);
+
+            let row = client
+                .query_one(
+                    "SELECT title, body, slug, cover_image_url, published_at, author_id FROM posts WHERE id = $1",
+                    &[&copy_id],
+                )
+                .await
+                .unwrap();
+            assert_eq!(row.get::<_, String>("title"), "Rust");
+            assert_eq!(row.get::<_, String>("body"), "body");
+            assert_eq!(row.get::<_, String>("slug"), expected_slug);
+            assert_eq!(
+                row.get::<_, Option<String>>("cover_image_url").unwrap(),
+                "https://cdn.navarro.tech/rust.webp"
+            );
+            assert!(row
+                .get::<_, Option<chrono::DateTime<chrono::Utc>>>("published_at")
+                .is_none());
+            assert_eq!(
+                row.get::<_, Option<uuid::Uuid>>("author_id"),
+                Some(author_id)
+            );
+
+            let row = client
+                .query_one(
+                    "SELECT (SELECT category_id FROM posts_categories WHERE post_id = $1) AS category_id, (SELECT tag_id FROM posts_tags WHERE post_id = $1) AS tag_id",
+                    &[&copy_id],
+                )
+                .await
+                .unwrap();
+            assert_eq!(
+                row.get::<_, Option<uuid::Uuid>>("category_id"),
+                Some(category_id)
+            );
+            assert_eq!(row.get::<_, Option<uuid::Uuid>>("tag_id"), Some(tag_id));
+        }
+
+        let stranger_jwt = JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let resp = test::call_service(&app, copy(stranger_jwt, post_id)).await;
+        assert_eq!(resp.status(), 403);
+        let resp = test::call_service(&app, copy(author_jwt, uuid::Uuid::new_v4())).await;
+        assert_eq!(resp.status(), 404);
+
+        let post_ids: Vec<uuid::Uuid> = copy_ids.iter().chain([&post_id]).copied().collect();
+        client
+            .execute(
+                "DELETE FROM posts_categories WHERE post_id = ANY($1)",
+                &[&post_ids],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM posts_tags WHERE post_id = ANY($1)",
+                &[&post_ids],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&copy_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM categories WHERE id = $1", &[&category_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tags WHERE id = $1", &[&tag_id])
+            .await
+            .unwrap();
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _patch_post_authors() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let (author_id, post_id) = seed().await;
+        let slug = format!("co-authors-{}", post_id.simple());
+        client
+            .execute(
+                "UPDATE posts SET slug = $2 WHERE id = $1",
+                &[&post_id, &slug],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO post_authors (post_id, user_id, role, added_at) VALUES ($1, $2, 'primary', NOW())",
+                &[&post_id, &author_id],
+            )
+            .await
+            .unwrap();
+        let mut co_author_ids = Vec::new();
+        for _ in 0..6 {
+            let user_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Maria Silva', $2, 'x', NOW())",
+                    &[&user_id, &format!("{}@gmail.com", user_id)],
+                )
+                .await
+                .unwrap();
+            co_author_ids.push(user_id);
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let patch_authors = |user_id: uuid::Uuid, body: serde_json::Value| {
+            test::TestRequest::patch()
+                .uri(&format!("/post/{}/authors", post_id))
+                .append_header((
+                    "Authorization",
+                    format!(
+                        "Bearer {}",
+                        JwtModels::user_access_jwt_model(user_id.to_string())
+                    ),
+                ))
+                .set_json(body)
+                .to_request()
+        };
+        let ids =
+            |ids: &[uuid::Uuid]| -> Vec<String> { ids.iter().map(|id| id.to_string()).collect() };
+
+        let resp = test::call_service(
+            &app,
+            patch_authors(
+                author_id,
+                serde_json::json!({"add": ids(&co_author_ids[..2])}),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), 200);
+        let authors: Vec<serde_json::Value> = test::read_body_json(resp).await;
+        let roles: Vec<(&str, &str)> = authors
+            .iter()
+            .map(|author| {
+                (
+                    author["userId"].as_str().unwrap(),
+                    author["role"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        let (first, second) = (co_author_ids[0].to_string(), co_author_ids[1].to_string());
+        assert_eq!(roles[0], (author_id.to_string().as_str(), "primary"));
+        assert_eq!(roles.len(), 3);
+        assert!(roles.contains(&(first.as_str(), "co-author")));
+        assert!(roles.contains(&(second.as_str(), "co-author")));
+
+        let resp = test::call_service(
+            &app,
+            patch_authors(
+                author_id,
+                serde_json::json!({"add": ids(&co_author_ids[2..])}),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), 400);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("Um post pode ter no máximo 5 coautores."));
+
+        let resp = test::call_service(
+            &app,
+            patch_authors(
+                author_id,
+                serde_json::json!({"add": [uuid::Uuid::new_v4().to_string()]}),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), 400);
+        let resp = test::call_service(
+            &app,
+            patch_authors(author_id, serde_json::json!({"add": ["not-a-uuid"]})),
+        )
+        .await;
+        assert_eq!(resp.status(), 400);
+
+        let resp = test::call_service(
+            &app,
+            patch_authors(co_author_ids[0], serde_json::json!({"remove": [second]})),
+        )
+        .await;
+        assert_eq!(resp.status(), 403);
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/post/{}/cover", post_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(co_author_ids[0].to_string())
+                ),
+            ))
+            .set_json(serde_json::json!({"coverImageUrl": "https://cdn.navarro.tech/rust.webp"}))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 200);
+
+        let resp = test::call_service(
+            &app,
+            patch_authors(
+                author_id,
+                serde_json::json!({"remove": [first, author_id.to_string()]}),
+            ),
+        )
+        .await;
+        assert_eq!(resp.status(), 200);
+        let authors: Vec<serde_json::Value> = test::read_body_json(resp).await;
+        assert_eq!(authors.len(), 2);
+        assert_eq!(authors[0]["role"], "primary");
+        assert_eq!(authors[1]["userId"], second);
+
+        let post = detail_post_service(pg_pool.clone(), slug, String::from(DEFAULT_TENANT_ID))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(post.authors.unwrap().len(), 2);
+
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&co_author_ids])
+            .await
+            .unwrap();
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _cross_tenant_post_authors_and_copy() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let (author_id, post_id) = seed().await;
+        let slug = format!("tenants-{}", post_id.simple());
+        client
+            .execute(
+                "UPDATE posts SET slug = $2 WHERE id = $1",
+                &[&post_id, &slug],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO post_authors (post_id, user_id, role, added_at) VALUES ($1, $2, 'primary', NOW())",
+                &[&post_id, &author_id],
+            )
+            .await
+            .unwrap();
+        let (tenant_id, other_user_id, other_post_id) = (
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+        );
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at, tenant_id) VALUES ($1, 'Maria Silva', $2, 'x', NOW(), $3)",
+                &[&other_user_id, &format!("{}@gmail.com", other_user_id), &tenant_id],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, slug, tenant_id) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), $3, $4)",
+                &[&other_post_id, &other_user_id, &format!("{}-copy", slug), &tenant_id],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let author_jwt = JwtModels::user_access_jwt_model(author_id.to_string());
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/post/{}/authors", post_id))
+            .append_header(("Authorization", format!("Bearer {}", author_jwt)))
+            .set_json(serde_json::json!({"add": [other_user_id.to_string()]}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 400);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains(&other_user_id.to_string()));
+        let row = client
+            .query_one(
+                "SELECT COUNT(*) AS authors FROM post_authors WHERE post_id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(row.get::<_, i64>("authors"), 1);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/copy", post_id))
+            .insert_header((TENANT_HEADER, tenant_id.to_string()))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::tenant_access_jwt_model(
+                        other_user_id.to_string(),
+                        tenant_id.to_string()
+                    )
+                ),
+            ))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 404);
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/post/{}/copy", post_id))
+            .append_header(("Authorization", format!("Bearer {}", author_jwt)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 201);
+        let copied: CopiedPostDTO = test::read_body_json(resp).await;
+        let copy_id = uuid::Uuid::parse_str(&copied.post_id).unwrap();
+        let row = client
+            .query_one("SELECT slug FROM posts WHERE id = $1", &[&copy_id])
+            .await
+            .unwrap();
+        assert_eq!(row.get::<_, String>("slug"), format!("{}-copy", slug));
+
+        client
+            .execute(
+                "DELETE FROM posts WHERE id = ANY($1)",
+                &[&vec![copy_id, other_post_id]],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&other_user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM tenants WHERE id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+        cleanup(author_id, post_id).await;
+    }
+
+    fn chunk(op: &str, text: &str) -> DiffChunkDTO {
+        DiffChunkDTO {
+            op: String::from(op),
+            text: String::from(text),
+        }
+    }
+
+    #[test]
+    async fn _compute_diff() {
+        assert_eq!(
+            compute_diff("Olá mundo cruel", "Olá mundo gentil"),
+            vec![
+                chunk("equal", "Olá mundo "),
+                chunk("delete", "cruel"),
+                chunk("insert", "gentil"),
+            ]
+        );
+        assert_eq!(compute_diff("", "novo"), vec![chunk("insert", "novo")]);
+        assert_eq!(
+            compute_diff("igual", "igual"),
+            vec![chunk("equal", "igual")]
+        );
+        assert!(compute_diff("", "").is_empty());
+
+        assert_eq!(
+            compute_character_diff("gato", "pato"),
+            vec![
+                chunk("delete", "g"),
+                chunk("insert", "p"),
+                chunk("equal", "ato"),
+            ]
+        );
+        assert_eq!(
+            compute_character_diff("Rust", "Rusty"),
+            vec![chunk("equal", "Rust"), chunk("insert", "y")]
+        );
+    }
+
+    #[test]
+    async fn _revision_diff() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+
+        for (version, body) in [
+            (0, "Primeira versão do post"),
+            (1, "Segunda versão do post"),
+        ] {
+            update_post_service(
+                pg_pool.clone(),
+                post_id.to_string(),
+                author_id.to_string(),
+                UpdatePostDTO {
+                    body: String::from(body),
+                    ..update("Rust", version)
+                },
+            )
+            .await
+            .unwrap();
+        }
+        let client = pg_pool.get().await.unwrap();
+        let revision_ids: Vec<uuid::Uuid> = client
+            .query(
+                "SELECT id FROM post_revisions WHERE post_id = $1 ORDER BY version",
+                &[&post_id],
+            )
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.get("id"))
+            .collect();
+        assert_eq!(revision_ids.len(), 2);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .service(post_controllers_module()),
+        )
+        .await;
+        let jwt = JwtModels::user_access_jwt_model(author_id.to_string());
+        let diff = |revision_id: uuid::Uuid, query: &str, jwt: &String| {
+            test::TestRequest::get()
+                .uri(&format!(
+                    "/post/{}/revisions/{}/diff{}",
+                    post_id, revision_id, query
+                ))
+                .append_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+
+        let chunks: Vec<DiffChunkDTO> =
+            test::call_and_read_body_json(&app, diff(revision_ids[1], "", &jwt)).await;
+        assert_eq!(
+            chunks,
+            vec![
+                chunk("delete", "Primeira"),
+                chunk("insert", "Segunda"),
+                chunk("equal", " versão do post"),
+            ]
+        );
+
+        let chunks: Vec<DiffChunkDTO> =
+            test::call_and_read_body_json(&app, diff(revision_ids[0], "?compare_to=prev", &jwt))
+                .await;
+        assert_eq!(chunks, vec![chunk("insert", "body")]);
+
+        let chunks: Vec<DiffChunkDTO> = test::call_and_read_body_json(
+            &app,
+            diff(
+                revision_ids[1],
+                "?compare_to=prev&granularity=character",
+                &jwt,
+            ),
+        )
+        .await;
+        let text = |op: &str| -> String {
+            chunks
+                .iter()
+                .filter(|chunk| chunk.op == "equal" || chunk.op == op)
+                .map(|chunk| chunk.text.as_str())
+                .collect()
+        };
+        assert_eq!(text("delete"), "body");
+        assert_eq!(text("insert"), "Primeira versão do post");
+
+        let resp = test::call_service(&app, diff(revision_ids[0], "?granularity=line", &jwt)).await;
+        assert_eq!(resp.status(), 400);
+        let other_jwt = JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let resp = test::call_service(&app, diff(revision_ids[0], "", &other_jwt)).await;
+        assert_eq!(resp.status(), 403);
+        let resp = test::call_service(&app, diff(uuid::Uuid::new_v4(), "", &jwt)).await;
+        assert_eq!(resp.status(), 404);
+
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _post_lock() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (author_id, post_id) = seed().await;
+        let admin_id = uuid::Uuid::new_v4();
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at, role) VALUES ($1, 'Admin Navarro', $2, 'x', NOW(), 'admin')",
+                &[&admin_id, &format!("{}@gmail.com", admin_id)],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .service(post_controllers_module()),
+        )
+        .await;
+        let author_jwt = JwtModels::user_access_jwt_model(author_id.to_string());
+        let admin_jwt = JwtModels::access_jwt_model(admin_id.to_string());
+        let lock = |jwt: &String| {
+            test::TestRequest::post()
+                .uri(&format!("/post/{}/lock", post_id))
+                .append_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+        let release = |jwt: &String| {
+            test::TestRequest::delete()
+                .uri(&format!("/post/{}/lock", post_id))
+                .append_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+
+        let (author_resp, admin_resp) = tokio::join!(
+            test::call_service(&app, lock(&author_jwt)),
+            test::call_service(&app, lock(&admin_jwt))
+        );
+        let (winner, winner_name, loser_resp) = match author_resp.status().as_u16() {
+            200 => (author_id, "Victor Navarro", admin_resp),
+            _ => (admin_id, "Admin Navarro", author_resp),
+        };
+        assert_eq!(loser_resp.status(), 423);
+        let locked: PostLockedDTO = test::read_body_json(loser_resp).await;
+        assert_eq!(locked.locked_by, winner.to_string());
+        assert_eq!(locked.locked_by_name, winner_name);
+
+        let resp = test::call_service(&app, release(&admin_jwt)).await;
+        assert_eq!(resp.status(), 204);
+        let lock_dto: PostLockDTO = test::call_and_read_body_json(&app, lock(&admin_jwt)).await;
+        assert_eq!(lock_dto.locked_by, admin_id.to_string());
+        let resp = test::call_service(&app, lock(&admin_jwt)).await;
+        assert_eq!(resp.status(), 200);
+
+        let resp = test::call_service(&app, release(&author_jwt)).await;
+        assert_eq!(resp.status(), 423);
+        let resp = update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            update("Rust", 0),
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(resp.status(), 423);
+
+        client
+            .execute(
+                "UPDATE post_locks SET expires_at = NOW() - INTERVAL '1 second' WHERE post_id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        update_post_service(
+            pg_pool.clone(),
+            post_id.to_string(),
+            author_id.to_string(),
+            update("Rust", 0),
+        )
+        .await
+        .unwrap();
+        assert!(expire_post_locks_repository(pg_pool.clone()).await.unwrap() >= 1);
+        let resp = test::call_service(&app, lock(&author_jwt)).await;
+        assert_eq!(resp.status(), 200);
+
+        let other_jwt = JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let resp = test::call_service(&app, lock(&other_jwt)).await;
+        assert_eq!(resp.status(), 403);
+        let resp = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri(&format!("/post/{}/lock", uuid::Uuid::new_v4()))
+                .append_header(("Authorization", format!("Bearer {}", admin_jwt)))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(resp.status(), 404);
+
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&admin_id])
+            .await
+            .unwrap();
+        cleanup(author_id, post_id).await;
+    }
+
+    #[test]
+    async fn _series_navigation() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let (author_id, standalone_id) = seed().await;
+        let series_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO series (id, title) VALUES ($1, 'Rust do zero')",
+                &[&series_id],
+            )
+            .await
+            .unwrap();
+        let mut slugs = Vec::new();
+        for position in 1..=3 {
+            let post_id = uuid::Uuid::new_v4();
+            let slug = format!("rust-parte-{}-{}", position, post_id.simple());
+            client
+                .execute(
+                    "INSERT INTO posts (id, slug, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, $2, $3, 'sub', 'body', $4, NOW(), NOW())",
+                    &[&post_id, &slug, &format!("Parte {}", position), &author_id],
+                )
+                .await
+                .unwrap();
+            client
+                .execute(
+                    "INSERT INTO series_posts (series_id, post_id, position) VALUES ($1, $2, $3)",
+                    &[&series_id, &post_id, &position],
+                )
+                .await
+                .unwrap();
+            slugs.push(slug);
+        }
+        let link = |position: usize| {
+            Some(PostLinkDTO {
+                title: format!("Parte {}", position + 1),
+                slug: slugs[position].clone(),
+            })
+        };
+
+        let mut navigation = Vec::new();
+        for slug in &slugs {
+            let post = detail_post_service(
+                pg_pool.clone(),
+                slug.clone(),
+                String::from(DEFAULT_TENANT_ID),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+            navigation.push((post.prev_in_series, post.next_in_series));
+        }
+        assert_eq!(
+            navigation,
+            vec![(None, link(1)), (link(0), link(2)), (link(1), None)]
+        );
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(post_controllers_module()),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri(&format!("/post/{}", slugs[0]))
+            .to_request();
+        let post: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+        assert!(post["prevInSeries"].is_null());
+        assert_eq!(
+            post["nextInSeries"],
+            serde_json::json!({ "title": "Parte 2", "slug": slugs[1] })
+        );
+
+        client
+            .execute(
+                "UPDATE posts SET slug = id::TEXT WHERE id = $1",
+                &[&standalone_id],
+            )
+            .await
+            .unwrap();
+        let standalone = detail_post_service(
+            pg_pool.clone(),
+            standalone_id.to_string(),
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(standalone.prev_in_series, None);
+        assert_eq!(standalone.next_in_series, None);
+
+        client
+            .execute("DELETE FROM series WHERE id = $1", &[&series_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE author_id = $1", &[&author_id])
+            .await
+            .unwrap();
+        cleanup(author_id, standalone_id).await;
+    }
+}
@@ -0,0 +1,142 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod post_view_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels};
+    use actix_web::{
+        http::header::{HeaderMap, HeaderValue, AUTHORIZATION},
+        test,
+        web::Data,
+    };
+    use navarro_blog_api::{
+        modules::post_view::post_view_services::{_record_post_view_service, read_history_service},
+        shared::structs::cursor_params::CursorParams,
+    };
+
+    async fn seed() -> (uuid::Uuid, Vec<uuid::Uuid>) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+
+        let mut post_ids = Vec::new();
+        for title in ["first", "second"] {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, created_at) VALUES ($1, $2, 'sub', 'body', NOW())",
+                    &[&post_id, &title],
+                )
+                .await
+                .unwrap();
+            post_ids.push(post_id);
+        }
+        (user_id, post_ids)
+    }
+
+    async fn cleanup(user_id: uuid::Uuid, post_ids: Vec<uuid::Uuid>) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM post_views WHERE user_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _record_post_view() {
+        dotenv::dotenv().ok();
+        let pool = Data::new(PostgresModels::postgres_success(None));
+        let (user_id, post_ids) = seed().await;
+
+        let resp = _record_post_view_service(pool.clone(), &HeaderMap::new(), post_ids[0])
+            .await
+            .unwrap();
+        assert_eq!(resp, None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!(
+                "Bearer {}",
+                JwtModels::access_jwt_model(user_id.to_string())
+            ))
+            .unwrap(),
+        );
+
+        let resp = _record_post_view_service(pool.clone(), &headers, post_ids[0])
+            .await
+            .unwrap();
+        assert_eq!(resp, Some(false));
+
+        let resp = _record_post_view_service(pool.clone(), &headers, post_ids[0])
+            .await
+            .unwrap();
+        assert_eq!(resp, Some(true));
+
+        cleanup(user_id, post_ids).await;
+    }
+
+    #[test]
+    async fn _read_history() {
+        dotenv::dotenv().ok();
+        let pool = Data::new(PostgresModels::postgres_success(None));
+        let (user_id, post_ids) = seed().await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!(
+                "Bearer {}",
+                JwtModels::access_jwt_model(user_id.to_string())
+            ))
+            .unwrap(),
+        );
+        for post_id in &post_ids {
+            _record_post_view_service(pool.clone(), &headers, *post_id)
+                .await
+                .unwrap();
+        }
+
+        let page = read_history_service(
+            pool.clone(),
+            user_id.to_string(),
+            CursorParams {
+                cursor: None,
+                limit: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].title, "second");
+
+        let page = read_history_service(
+            pool.clone(),
+            user_id.to_string(),
+            CursorParams {
+                cursor: page.next_cursor,
+                limit: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].title, "first");
+        assert!(page.next_cursor.is_none());
+
+        cleanup(user_id, post_ids).await;
+    }
+}
@@ -0,0 +1,252 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod reading_list_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels};
+    use actix_web::{
+        http::header::{HeaderMap, HeaderName, HeaderValue},
+        test,
+        web::Data,
+    };
+    use navarro_blog_api::{
+        modules::reading_list::{
+            reading_list_dtos::{InsertReadingListDTO, PutReadingListDTO},
+            reading_list_services::*,
+        },
+        shared::structs::cursor_params::CursorParams,
+    };
+
+    async fn seed_user_with_posts(posts: usize) -> (uuid::Uuid, Vec<String>) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        let mut post_ids = Vec::with_capacity(posts);
+        for _ in 0..posts {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, summary, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', 'Resumo', $2, NOW(), NOW())",
+                    &[&post_id, &user_id],
+                )
+                .await
+                .unwrap();
+            post_ids.push(post_id.to_string());
+        }
+        (user_id, post_ids)
+    }
+
+    async fn cleanup(user_ids: &[uuid::Uuid]) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM posts WHERE author_id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+    }
+
+    fn bearer(user_id: Option<uuid::Uuid>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Some(user_id) = user_id {
+            headers.insert(
+                HeaderName::from_static("authorization"),
+                HeaderValue::from_str(&format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(user_id.to_string())
+                ))
+                .unwrap(),
+            );
+        }
+        headers
+    }
+
+    fn page(cursor: Option<String>, limit: i64) -> CursorParams {
+        CursorParams {
+            cursor,
+            limit: Some(limit),
+        }
+    }
+
+    #[test]
+    async fn _reading_list_items() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (user_id, post_ids) = seed_user_with_posts(3).await;
+
+        let list = insert_reading_list_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            InsertReadingListDTO {
+                name: String::from("Para ler depois"),
+                public: false,
+            },
+        )
+        .await
+        .unwrap();
+        for post_id in &post_ids {
+            insert_reading_list_item_service(
+                pg_pool.clone(),
+                list.id.clone(),
+                user_id.to_string(),
+                post_id.clone(),
+            )
+            .await
+            .unwrap();
+        }
+        insert_reading_list_item_service(
+            pg_pool.clone(),
+            list.id.clone(),
+            user_id.to_string(),
+            post_ids[0].clone(),
+        )
+        .await
+        .unwrap();
+
+        let lists = list_user_reading_lists_service(pg_pool.clone(), user_id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(lists.len(), 1);
+        assert_eq!(lists[0].item_count, 3);
+
+        let first = detail_reading_list_service(
+            pg_pool.clone(),
+            &bearer(Some(user_id)),
+            list.id.clone(),
+            page(None, 2),
+        )
+        .await
+        .unwrap();
+        assert_eq!(first.items.len(), 2);
+        assert_eq!(first.items[0].summary.as_deref(), Some("Resumo"));
+        let second = detail_reading_list_service(
+            pg_pool.clone(),
+            &bearer(Some(user_id)),
+            list.id.clone(),
+            page(first.next_cursor, 2),
+        )
+        .await
+        .unwrap();
+        assert_eq!(second.items.len(), 1);
+        assert!(second.next_cursor.is_none());
+
+        delete_reading_list_item_service(
+            pg_pool.clone(),
+            list.id.clone(),
+            user_id.to_string(),
+            post_ids[0].clone(),
+        )
+        .await
+        .unwrap();
+        let resp = delete_reading_list_item_service(
+            pg_pool.clone(),
+            list.id.clone(),
+            user_id.to_string(),
+            post_ids[0].clone(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        let resp = insert_reading_list_item_service(
+            pg_pool.clone(),
+            list.id.clone(),
+            user_id.to_string(),
+            uuid::Uuid::new_v4().to_string(),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        delete_reading_list_service(pg_pool.clone(), list.id.clone(), user_id.to_string())
+            .await
+            .unwrap();
+        let resp =
+            detail_reading_list_service(pg_pool, &bearer(Some(user_id)), list.id, page(None, 2))
+                .await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        cleanup(&[user_id]).await;
+    }
+
+    #[test]
+    async fn _reading_list_visibility() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (owner_id, _) = seed_user_with_posts(0).await;
+        let (other_id, _) = seed_user_with_posts(0).await;
+
+        let list = insert_reading_list_service(
+            pg_pool.clone(),
+            owner_id.to_string(),
+            InsertReadingListDTO {
+                name: String::from("Favoritos"),
+                public: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        let resp = detail_reading_list_service(
+            pg_pool.clone(),
+            &bearer(None),
+            list.id.clone(),
+            page(None, 20),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 400);
+        let resp = detail_reading_list_service(
+            pg_pool.clone(),
+            &bearer(Some(other_id)),
+            list.id.clone(),
+            page(None, 20),
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+
+        let resp = put_reading_list_service(
+            pg_pool.clone(),
+            list.id.clone(),
+            other_id.to_string(),
+            PutReadingListDTO {
+                name: None,
+                public: Some(true),
+            },
+        )
+        .await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+        let updated = put_reading_list_service(
+            pg_pool.clone(),
+            list.id.clone(),
+            owner_id.to_string(),
+            PutReadingListDTO {
+                name: None,
+                public: Some(true),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(updated.public);
+        assert_eq!(updated.name, "Favoritos");
+
+        let detail = detail_reading_list_service(
+            pg_pool.clone(),
+            &bearer(None),
+            list.id.clone(),
+            page(None, 20),
+        )
+        .await
+        .unwrap();
+        assert_eq!(detail.list.item_count, 0);
+
+        let resp = delete_reading_list_service(pg_pool, list.id, other_id.to_string()).await;
+        assert_eq!(resp.err().unwrap().status(), 403);
+
+        cleanup(&[owner_id, other_id]).await;
+    }
+}
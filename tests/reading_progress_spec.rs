@@ -0,0 +1,182 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod reading_progress_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels};
+    use actix_web::{
+        http::header::{HeaderMap, HeaderName, HeaderValue},
+        test,
+        web::Data,
+    };
+    use navarro_blog_api::{
+        modules::{
+            reading_list::{
+                reading_list_dtos::InsertReadingListDTO,
+                reading_list_services::{
+                    detail_reading_list_service, insert_reading_list_item_service,
+                    insert_reading_list_service,
+                },
+            },
+            reading_progress::{reading_progress_repositories::*, reading_progress_services::*},
+        },
+        shared::structs::cursor_params::CursorParams,
+    };
+
+    async fn seed_user_with_posts(posts: usize) -> (uuid::Uuid, Vec<uuid::Uuid>) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        let mut post_ids = Vec::with_capacity(posts);
+        for _ in 0..posts {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, summary, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', 'Resumo', $2, NOW(), NOW())",
+                    &[&post_id, &user_id],
+                )
+                .await
+                .unwrap();
+            post_ids.push(post_id);
+        }
+        (user_id, post_ids)
+    }
+
+    async fn cleanup(user_id: uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM reading_lists WHERE user_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE author_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    fn page() -> CursorParams {
+        CursorParams {
+            cursor: None,
+            limit: Some(20),
+        }
+    }
+
+    #[test]
+    async fn _put_reading_progress() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (user_id, post_ids) = seed_user_with_posts(1).await;
+
+        let progress = put_reading_progress_repository(pg_pool.clone(), user_id, post_ids[0], 30)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(progress.progress_percent, 30);
+        let progress = put_reading_progress_repository(pg_pool.clone(), user_id, post_ids[0], 80)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(progress.progress_percent, 80);
+        let progress = detail_reading_progress_repository(pg_pool.clone(), user_id, post_ids[0])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(progress.progress_percent, 80);
+
+        let unknown_post =
+            put_reading_progress_repository(pg_pool.clone(), user_id, uuid::Uuid::new_v4(), 10)
+                .await
+                .unwrap();
+        assert!(unknown_post.is_none());
+
+        cleanup(user_id).await;
+    }
+
+    #[test]
+    async fn _list_in_progress() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (user_id, post_ids) = seed_user_with_posts(3).await;
+
+        for (post_id, percent) in post_ids.iter().zip([10, 100, 50]) {
+            put_reading_progress_repository(pg_pool.clone(), user_id, *post_id, percent)
+                .await
+                .unwrap();
+        }
+
+        let in_progress = list_in_progress_service(pg_pool.clone(), user_id.to_string(), page())
+            .await
+            .unwrap();
+        let ids: Vec<String> = in_progress.data.iter().map(|p| p.post_id.clone()).collect();
+        assert_eq!(ids, vec![post_ids[2].to_string(), post_ids[0].to_string()]);
+        assert_eq!(in_progress.data[0].progress_percent, 50);
+        assert!(in_progress.next_cursor.is_none());
+
+        cleanup(user_id).await;
+    }
+
+    #[test]
+    async fn _reading_list_progress() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let (user_id, post_ids) = seed_user_with_posts(1).await;
+
+        let list = insert_reading_list_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            InsertReadingListDTO {
+                name: String::from("Para ler depois"),
+                public: true,
+            },
+        )
+        .await
+        .unwrap();
+        insert_reading_list_item_service(
+            pg_pool.clone(),
+            list.id.clone(),
+            user_id.to_string(),
+            post_ids[0].to_string(),
+        )
+        .await
+        .unwrap();
+        put_reading_progress_repository(pg_pool.clone(), user_id, post_ids[0], 42)
+            .await
+            .unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("authorization"),
+            HeaderValue::from_str(&format!(
+                "Bearer {}",
+                JwtModels::user_access_jwt_model(user_id.to_string())
+            ))
+            .unwrap(),
+        );
+        let detail =
+            detail_reading_list_service(pg_pool.clone(), &headers, list.id.clone(), page())
+                .await
+                .unwrap();
+        assert_eq!(detail.items[0].progress_percent, Some(42));
+
+        let detail = detail_reading_list_service(
+            pg_pool.clone(),
+            &HeaderMap::new(),
+            list.id.clone(),
+            page(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(detail.items[0].progress_percent, None);
+
+        cleanup(user_id).await;
+    }
+}
@@ -0,0 +1,153 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod shared_specs {
+    use actix_web::test;
+    use navarro_blog_api::shared::parsers::{
+        code_languages_parser::extract_code_languages,
+        mentions_parser::extract_mentions,
+        preview_parser::{truncated_preview, PREVIEW_MAX_CHARS},
+        slug_parser::slugify,
+        summary_parser::generate_summary,
+        text_counts_parser::count_words_and_characters,
+        toc_parser::extract_toc,
+    };
+
+    #[test]
+    async fn _extract_mentions() {
+        let mentions = extract_mentions("@navarro, veja o comentário do @bush1d3v e do @dev_2");
+        assert_eq!(mentions, vec!["navarro", "bush1d3v", "dev_2"]);
+    }
+
+    #[test]
+    async fn _extract_mentions_deduplicated() {
+        let mentions = extract_mentions("@navarro @bush1d3v @navarro @Navarro!");
+        assert_eq!(mentions, vec!["navarro", "bush1d3v"]);
+    }
+
+    #[test]
+    async fn _extract_mentions_empty() {
+        assert!(extract_mentions("Sem menções por aqui.").is_empty());
+        assert!(extract_mentions("Envie para navarro@gmail.com ou @@").is_empty());
+    }
+
+    #[test]
+    async fn _slugify() {
+        assert_eq!(slugify("Rust e Actix Web"), "rust-e-actix-web");
+        assert_eq!(slugify("  Ação & Reação!  "), "acao-reacao");
+        assert_eq!(slugify("---"), "");
+    }
+
+    #[test]
+    async fn _generate_summary_markdown() {
+        let body = "# Título\n\n## Subtítulo\n\nUm **post** em _Rust_ com `código` e *ênfase*.";
+        assert_eq!(
+            generate_summary(body),
+            "Título Subtítulo Um post em Rust com código e ênfase."
+        );
+
+        let body = "**palavra** ".repeat(250);
+        let summary = generate_summary(&body);
+        assert!(summary.ends_with("palavra…"));
+        assert_eq!(summary.split_whitespace().count(), 200);
+    }
+
+    #[test]
+    async fn _generate_summary_short() {
+        assert_eq!(generate_summary("Um post curto."), "Um post curto.");
+        assert_eq!(
+            generate_summary(&"palavra ".repeat(200)),
+            "palavra ".repeat(200).trim_end()
+        );
+    }
+
+    #[test]
+    async fn _generate_summary_empty() {
+        assert_eq!(generate_summary(""), "");
+        assert_eq!(generate_summary("  # ** _ `  "), "");
+    }
+
+    #[test]
+    async fn _truncated_preview() {
+        assert_eq!(
+            truncated_preview("Ótimo post!", PREVIEW_MAX_CHARS),
+            "Ótimo post!"
+        );
+        assert_eq!(truncated_preview("", PREVIEW_MAX_CHARS), "");
+
+        let exact = "a".repeat(PREVIEW_MAX_CHARS);
+        assert_eq!(truncated_preview(&exact, PREVIEW_MAX_CHARS), exact);
+        assert_eq!(
+            truncated_preview(&format!("{}b", exact), PREVIEW_MAX_CHARS),
+            format!("{}…", exact)
+        );
+
+        assert_eq!(truncated_preview("  \n Ótimo post!  ", 80), "Ótimo post!");
+        assert_eq!(truncated_preview("   abcdef", 3), "abc…");
+        assert_eq!(truncated_preview("abc   def", 5), "abc…");
+
+        assert_eq!(truncated_preview("ãéîõü😀ç", 5), "ãéîõü…");
+        assert_eq!(truncated_preview("abcd😀ef", 5), "abcd😀…");
+        assert_eq!(truncated_preview("😀😀😀", 3), "😀😀😀");
+    }
+
+    #[test]
+    async fn _count_words_and_characters() {
+        assert_eq!(count_words_and_characters(""), (0, 0));
+        assert_eq!(count_words_and_characters("  \n\t "), (0, 5));
+        assert_eq!(count_words_and_characters("Rust e Actix Web"), (4, 16));
+        assert_eq!(
+            count_words_and_characters("Olá, mundo!\n\nAção   em 日本語 😀"),
+            (6, 28)
+        );
+        assert_eq!(count_words_and_characters("naïve\u{00a0}café"), (2, 10));
+    }
+
+    #[test]
+    async fn _extract_code_languages() {
+        let body = "# Exemplos\n\n```rust\nfn main() {}\n```\n\n~~~ Python\nprint()\n~~~\n\n```javascript\nlet x = 1;\n```\n\n```RUST\nlet y = 2;\n```\n\n```\nsem linguagem\n```";
+        assert_eq!(
+            extract_code_languages(body),
+            vec!["rust", "python", "javascript"]
+        );
+        assert_eq!(
+            extract_code_languages("```c++\nint x;\n```\n```c#\nint y;\n```"),
+            vec!["c++", "c#"]
+        );
+        assert!(extract_code_languages("Post sem blocos de código, só `inline`.").is_empty());
+        assert!(extract_code_languages("").is_empty());
+    }
+
+    #[test]
+    async fn _extract_toc() {
+        let body = "# Rust e Actix\n\nIntrodução.\n\n## Instalação & Setup\n\n### Passo 1\n\n#### Nível quatro\n\n## Instalação & Setup\n\n###Sem espaço\n\n## Instalação & Setup ##";
+        let toc = extract_toc(body);
+        let entries: Vec<(u8, &str, &str)> = toc
+            .iter()
+            .map(|entry| (entry.level, entry.text.as_str(), entry.anchor.as_str()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (1, "Rust e Actix", "rust-e-actix"),
+                (2, "Instalação & Setup", "instalação--setup"),
+                (3, "Passo 1", "passo-1"),
+                (2, "Instalação & Setup", "instalação--setup-2"),
+                (2, "Instalação & Setup", "instalação--setup-3"),
+            ]
+        );
+    }
+
+    #[test]
+    async fn _extract_toc_code_block() {
+        let toc = extract_toc("```bash\n# comentário\n```\n\n## Uso");
+        assert_eq!(toc.len(), 1);
+        assert_eq!(toc[0].anchor, "uso");
+    }
+
+    #[test]
+    async fn _extract_toc_empty() {
+        assert!(extract_toc("Um post sem títulos.\n\n#hashtag").is_empty());
+        assert!(extract_toc("").is_empty());
+    }
+}
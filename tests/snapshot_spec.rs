@@ -0,0 +1,107 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod snapshot_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use navarro_blog_api::modules::snapshot::{
+        snapshot_providers::s3_authorization,
+        snapshot_repositories::list_snapshots_repository,
+        snapshot_services::{content_snapshot_service, snapshot_file_key},
+    };
+
+    #[test]
+    async fn _s3_authorization() {
+        let empty_payload_hash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        let authorization = s3_authorization(
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "us-east-1",
+            "examplebucket.s3.amazonaws.com",
+            "/snapshots/2024-06-18/posts.jsonl",
+            empty_payload_hash,
+            Utc.with_ymd_and_hms(2024, 6, 18, 3, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20240618/us-east-1/s3/aws4_request, \
+            SignedHeaders=host;x-amz-content-sha256;x-amz-date, \
+            Signature=4d2122d919bafed878f52b449f56569c698b4b0e9011be7f2f4d81e3c5b4516d"
+        );
+    }
+
+    #[test]
+    async fn _content_snapshot_service() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, created_at, published_at) VALUES ($1, 'Snapshot', 'sub', 'body', NOW(), NOW())",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+
+        let snapshot_date = NaiveDate::from_ymd_opt(2024, 6, 18).unwrap();
+        assert_eq!(
+            snapshot_file_key(snapshot_date),
+            "snapshots/2024-06-18/posts.jsonl"
+        );
+
+        let mut server = mockito::Server::new_async().await;
+        let upload = server
+            .mock("PUT", "/blog-backups/snapshots/2024-06-18/posts.jsonl")
+            .match_header(
+                "Authorization",
+                mockito::Matcher::Regex(String::from(
+                    "^AWS4-HMAC-SHA256 Credential=snapshot-key/[0-9]{8}/sa-east-1/s3/aws4_request, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature=[0-9a-f]{64}$",
+                )),
+            )
+            .match_header("Content-Type", "application/x-ndjson")
+            .match_header(
+                "x-amz-content-sha256",
+                mockito::Matcher::Regex(String::from("^[0-9a-f]{64}$")),
+            )
+            .match_body(mockito::Matcher::Regex(format!(
+                "(?m)^\\{{.*\"id\":\"{}\".*\\}}$",
+                post_id
+            )))
+            .with_status(200)
+            .create_async()
+            .await;
+        std::env::set_var("AWS_S3_ENDPOINT", server.url());
+        std::env::set_var("AWS_S3_BUCKET", "blog-backups");
+        std::env::set_var("AWS_S3_REGION", "sa-east-1");
+        std::env::set_var("AWS_ACCESS_KEY_ID", "snapshot-key");
+        std::env::set_var("AWS_SECRET_ACCESS_KEY", "snapshot-secret");
+
+        let snapshot = content_snapshot_service(pg_pool.clone(), snapshot_date)
+            .await
+            .unwrap();
+        upload.assert_async().await;
+        assert_eq!(snapshot.snapshot_date, "2024-06-18");
+        assert_eq!(snapshot.file_key, "snapshots/2024-06-18/posts.jsonl");
+        assert!(snapshot.post_count >= 1);
+        assert!(snapshot.size_bytes > 0);
+
+        let snapshots = list_snapshots_repository(pg_pool.clone(), 30)
+            .await
+            .unwrap();
+        assert!(snapshots.iter().any(|x| x.id == snapshot.id));
+
+        client
+            .execute(
+                "DELETE FROM snapshots WHERE id = $1",
+                &[&uuid::Uuid::parse_str(&snapshot.id).unwrap()],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+    }
+}
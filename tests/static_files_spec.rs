@@ -0,0 +1,46 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod static_files_specs {
+    use actix_web::{body, http::header::CACHE_CONTROL, test, App};
+    use navarro_blog_api::modules::static_files::static_files_controllers::static_files_controllers_module;
+
+    #[test]
+    async fn _favicon() {
+        let app = test::init_service(App::new().configure(static_files_controllers_module)).await;
+
+        let req = test::TestRequest::get().uri("/favicon.ico").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 200);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "image/x-icon");
+        assert_eq!(resp.headers().get(CACHE_CONTROL).unwrap(), "max-age=86400");
+
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(&bytes[..6], &[0, 0, 1, 0, 1, 0]);
+        assert_eq!((bytes[6], bytes[7]), (16, 16));
+    }
+
+    #[test]
+    async fn _robots_txt() {
+        let app = test::init_service(App::new().configure(static_files_controllers_module)).await;
+
+        let req = test::TestRequest::get().uri("/robots.txt").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(resp.headers().get(CACHE_CONTROL).unwrap(), "max-age=86400");
+
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        let robots = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(robots.starts_with("User-agent: *\n"));
+        assert!(robots.contains("Disallow: /admin\n"));
+        assert!(robots.contains("Disallow: /user\n"));
+        assert!(robots.contains("Allow: /post\n"));
+        assert!(robots.contains("Allow: /feed.rss\n"));
+    }
+}
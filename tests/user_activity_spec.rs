@@ -0,0 +1,87 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod user_activity_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::modules::user_activity::{
+        user_activity_dtos::ActivityDayDTO,
+        user_activity_repositories::user_activity_heatmap_repository,
+    };
+
+    #[test]
+    async fn _user_activity_heatmap() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+
+        let mut post_ids = Vec::new();
+        for created_at in [
+            "2023-03-01 10:00:00+00",
+            "2023-03-01 18:00:00+00",
+            "2023-07-15 09:00:00+00",
+            "2022-12-31 12:00:00+00",
+        ] {
+            let post_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO posts (id, title, subtitle, body, author_id, created_at) VALUES ($1, 'Rust', 'sub', 'body', $2, $3::TEXT::TIMESTAMPTZ)",
+                    &[&post_id, &user_id, &created_at],
+                )
+                .await
+                .unwrap();
+            post_ids.push(post_id);
+        }
+        for created_at in ["2023-03-01 12:00:00+00", "2023-09-02 12:00:00+00"] {
+            client
+                .execute(
+                    "INSERT INTO comments (id, post_id, user_id, body, created_at) VALUES ($1, $2, $3, 'Comentário', $4::TEXT::TIMESTAMPTZ)",
+                    &[&uuid::Uuid::new_v4(), &post_ids[0], &user_id, &created_at],
+                )
+                .await
+                .unwrap();
+        }
+
+        let activity = user_activity_heatmap_repository(pg_pool.clone(), user_id, 2023)
+            .await
+            .unwrap();
+        let day = |date: &str, count: i64| ActivityDayDTO {
+            date: String::from(date),
+            count,
+        };
+        assert_eq!(
+            activity,
+            vec![
+                day("2023-03-01", 3),
+                day("2023-07-15", 1),
+                day("2023-09-02", 1)
+            ]
+        );
+
+        let activity = user_activity_heatmap_repository(pg_pool.clone(), user_id, 2021)
+            .await
+            .unwrap();
+        assert!(activity.is_empty());
+
+        client
+            .execute("DELETE FROM comments WHERE user_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE author_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod user_badge_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::modules::user_badge::{
+        user_badge_dtos::BadgeSummaryDTO, user_badge_repositories::*,
+    };
+
+    #[test]
+    async fn _evaluate_badges() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW() - INTERVAL '2 years')",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            evaluate_badges_repository(pg_pool.clone(), user_id)
+                .await
+                .unwrap(),
+            1
+        );
+        let slugs = |badges: Vec<BadgeSummaryDTO>| -> Vec<String> {
+            badges.into_iter().map(|badge| badge.slug).collect()
+        };
+        let badges = list_user_badges_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(
+            slugs(badges.into_iter().map(BadgeSummaryDTO::from).collect()),
+            vec!["veteran"]
+        );
+
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), NOW())",
+                &[&post_id, &user_id],
+            )
+            .await
+            .unwrap();
+        let liker_ids: Vec<uuid::Uuid> = client
+            .query(
+                "INSERT INTO users (id, name, email, password, created_at)
+                SELECT gen_random_uuid(), 'Leitor', gen_random_uuid() || '@gmail.com', 'x', NOW()
+                FROM generate_series(1, 100) RETURNING id",
+                &[],
+            )
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.get("id"))
+            .collect();
+        client
+            .execute(
+                "INSERT INTO users_posts_likes (user_id, post_id) SELECT UNNEST($1::UUID[]), $2",
+                &[&liker_ids, &post_id],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            evaluate_badges_repository(pg_pool.clone(), user_id)
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            evaluate_badges_repository(pg_pool.clone(), user_id)
+                .await
+                .unwrap(),
+            0
+        );
+        let badges = list_user_badges_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        let mut earned = slugs(badges.into_iter().map(BadgeSummaryDTO::from).collect());
+        earned.sort();
+        assert_eq!(earned, vec!["first_post", "popular", "veteran"]);
+
+        client
+            .execute(
+                "DELETE FROM users_posts_likes WHERE post_id = $1",
+                &[&post_id],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&liker_ids])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+}
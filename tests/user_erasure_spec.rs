@@ -0,0 +1,92 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod user_erasure_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::modules::user_erasure::user_erasure_repositories::delete_all_user_data_repository;
+
+    #[test]
+    async fn _delete_all_user_data() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        let email = format!("{}@gmail.com", user_id);
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &email],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO salt (salt, user_id) VALUES ($1, $2)",
+                &[&uuid::Uuid::new_v4(), &user_id],
+            )
+            .await
+            .unwrap();
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), NOW())",
+                &[&post_id, &user_id],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO notifications (id, user_id, type, created_at) VALUES ($1, $2, 'mention', NOW())",
+                &[&uuid::Uuid::new_v4(), &user_id],
+            )
+            .await
+            .unwrap();
+
+        let erased_email = delete_all_user_data_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(erased_email, email);
+
+        let user = client
+            .query_one(
+                "SELECT name, email, password, deleted_at IS NOT NULL AS deleted FROM users WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(user.get::<_, String>("name"), "Deleted User");
+        assert_eq!(
+            user.get::<_, String>("email"),
+            format!("deleted_{}@deleted.invalid", user_id)
+        );
+        assert_eq!(user.get::<_, String>("password"), "");
+        assert!(user.get::<_, bool>("deleted"));
+
+        let counts = client
+            .query_one(
+                "SELECT
+                    (SELECT COUNT(*) FROM salt WHERE user_id = $1) AS salts,
+                    (SELECT COUNT(*) FROM notifications WHERE user_id = $1) AS notifications,
+                    (SELECT COUNT(*) FROM posts WHERE author_id = $1 AND deleted_at IS NULL) AS posts",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(counts.get::<_, i64>("salts"), 0);
+        assert_eq!(counts.get::<_, i64>("notifications"), 0);
+        assert_eq!(counts.get::<_, i64>("posts"), 0);
+
+        let resp = delete_all_user_data_repository(pg_pool, user_id).await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+}
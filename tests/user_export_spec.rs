@@ -0,0 +1,126 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod user_export_specs {
+    use crate::mocks::{
+        models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels},
+        test_context::setup_test_context,
+    };
+    use actix_web::{http::header::CONTENT_DISPOSITION, test, web, App};
+    use navarro_blog_api::modules::{
+        user::user_controllers::user_controllers_module,
+        user_export::{
+            user_export_repositories::export_user_data_repository, user_export_services::export_zip,
+        },
+    };
+    use serde_json::Value;
+    use std::io::{Cursor, Read};
+
+    async fn insert_user(client: &deadpool_postgres::Object) -> uuid::Uuid {
+        let id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&id, &format!("{}@gmail.com", id)],
+            )
+            .await
+            .unwrap();
+        id
+    }
+
+    #[test]
+    async fn _export_user_data() {
+        dotenv::dotenv().ok();
+        let pg_pool = web::Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let user_id = insert_user(&client).await;
+        let post_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO posts (id, title, subtitle, body, author_id, created_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW())",
+                &[&post_id, &user_id],
+            )
+            .await
+            .unwrap();
+
+        let files = export_user_data_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        let archive = export_zip(&files).unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert_eq!(zip.len(), 6);
+
+        let mut profile = String::new();
+        zip.by_name("profile.json")
+            .unwrap()
+            .read_to_string(&mut profile)
+            .unwrap();
+        let profile: Value = serde_json::from_str(&profile).unwrap();
+        assert_eq!(profile["id"], user_id.to_string());
+        assert!(profile.get("password").is_none());
+
+        let mut posts = String::new();
+        zip.by_name("posts.json")
+            .unwrap()
+            .read_to_string(&mut posts)
+            .unwrap();
+        let posts: Value = serde_json::from_str(&posts).unwrap();
+        assert_eq!(posts[0]["id"], post_id.to_string());
+
+        let mut comments = String::new();
+        zip.by_name("comments.json")
+            .unwrap()
+            .read_to_string(&mut comments)
+            .unwrap();
+        assert_eq!(comments, "[]");
+
+        client
+            .execute("DELETE FROM posts WHERE id = $1", &[&post_id])
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _export_user_data_error_too_many_requests() {
+        dotenv::dotenv().ok();
+        let ctx = setup_test_context().await;
+        let client = PostgresModels::postgres_success(Some(&ctx))
+            .get()
+            .await
+            .unwrap();
+        let user_id = insert_user(&client).await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(PostgresModels::postgres_success(Some(&ctx))))
+                .app_data(web::Data::new(RedisModels::pool_success(Some(&ctx)).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+
+        let jwt = JwtModels::access_jwt_model(user_id.to_string());
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}/export", user_id))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers().get(CONTENT_DISPOSITION).unwrap(),
+            &format!("attachment; filename=\"export_{}.zip\"", user_id)
+        );
+        let archive = test::read_body(resp).await;
+        assert!(zip::ZipArchive::new(Cursor::new(archive.to_vec())).is_ok());
+
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}/export", user_id))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 429);
+    }
+}
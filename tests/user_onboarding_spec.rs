@@ -0,0 +1,105 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod user_onboarding_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::modules::user_onboarding::{
+        user_onboarding_dtos::{OnboardingStep, UserOnboardingDTO},
+        user_onboarding_repositories::*,
+    };
+
+    async fn seed_user() -> uuid::Uuid {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        user_id
+    }
+
+    async fn cleanup(user_id: uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    fn steps(onboarding: &UserOnboardingDTO) -> [bool; 4] {
+        [
+            onboarding.email_verified,
+            onboarding.profile_picture_set,
+            onboarding.first_post_published,
+            onboarding.biography_set,
+        ]
+    }
+
+    #[test]
+    async fn _complete_onboarding_steps() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+
+        for (i, step) in OnboardingStep::ALL.into_iter().enumerate() {
+            let user_id = seed_user().await;
+            let onboarding = complete_onboarding_step_repository(pg_pool.clone(), user_id, step)
+                .await
+                .unwrap();
+            let mut expected = [false; 4];
+            expected[i] = true;
+            assert_eq!(steps(&onboarding), expected, "step {}", step.column());
+            assert!(!onboarding.onboarding_complete);
+
+            let onboarding = complete_onboarding_step_repository(pg_pool.clone(), user_id, step)
+                .await
+                .unwrap();
+            assert_eq!(steps(&onboarding), expected, "step {}", step.column());
+            cleanup(user_id).await;
+        }
+    }
+
+    #[test]
+    async fn _onboarding_complete_and_skip() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let user_id = seed_user().await;
+
+        let onboarding = detail_user_onboarding_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(steps(&onboarding), [false; 4]);
+        assert!(!onboarding.skipped);
+        assert!(!onboarding.onboarding_complete);
+
+        complete_onboarding_step_repository(
+            pg_pool.clone(),
+            user_id,
+            OnboardingStep::FirstPostPublished,
+        )
+        .await
+        .unwrap();
+        let onboarding = skip_user_onboarding_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert!(onboarding.skipped);
+        assert!(onboarding.first_post_published);
+        assert!(!onboarding.onboarding_complete);
+
+        for step in OnboardingStep::ALL {
+            complete_onboarding_step_repository(pg_pool.clone(), user_id, step)
+                .await
+                .unwrap();
+        }
+        let onboarding = detail_user_onboarding_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(steps(&onboarding), [true; 4]);
+        assert!(onboarding.onboarding_complete);
+
+        cleanup(user_id).await;
+    }
+}
@@ -0,0 +1,236 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod user_preferences_specs {
+    use crate::mocks::models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels};
+    use actix_web::{test, web::Data, App};
+    use navarro_blog_api::modules::{
+        notification::notification_services::insert_notification_service,
+        user::user_controllers::user_controllers_module,
+        user_preferences::{
+            user_preferences_dtos::{PatchUserPreferencesDTO, UserPreferencesDTO},
+            user_preferences_repositories::*,
+            user_preferences_services::local_time,
+        },
+    };
+    use validator::Validate;
+
+    async fn seed_user() -> uuid::Uuid {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        user_id
+    }
+
+    async fn cleanup(user_id: uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _patch_user_preferences() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let user_id = seed_user().await;
+
+        let preferences = detail_user_preferences_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(preferences, UserPreferencesDTO::default());
+
+        let patch = PatchUserPreferencesDTO {
+            theme: Some(String::from("dark")),
+            receive_newsletter: Some(true),
+            ..Default::default()
+        };
+        patch_user_preferences_repository(pg_pool.clone(), user_id, patch.to_patch(), None)
+            .await
+            .unwrap();
+        let patch = PatchUserPreferencesDTO {
+            language: Some(String::from("en")),
+            ..Default::default()
+        };
+        let preferences =
+            patch_user_preferences_repository(pg_pool.clone(), user_id, patch.to_patch(), None)
+                .await
+                .unwrap();
+        assert_eq!(preferences.language, "en");
+        assert_eq!(preferences.theme, "dark");
+        assert!(preferences.receive_newsletter);
+        assert!(preferences.receive_comment_notifications);
+        assert_eq!(
+            detail_user_preferences_repository(pg_pool.clone(), user_id)
+                .await
+                .unwrap(),
+            preferences
+        );
+
+        cleanup(user_id).await;
+    }
+
+    #[test]
+    async fn _patch_user_preferences_validation() {
+        let patch: PatchUserPreferencesDTO =
+            serde_json::from_str(r#"{"receiveLikeNotifications": false, "language": "es"}"#)
+                .unwrap();
+        assert!(patch.validate().is_ok());
+        assert_eq!(
+            patch.to_patch(),
+            serde_json::json!({"receiveLikeNotifications": false, "language": "es"})
+        );
+
+        let patch: PatchUserPreferencesDTO = serde_json::from_str(r#"{"language": "fr"}"#).unwrap();
+        assert!(patch.validate().is_err());
+        let patch: PatchUserPreferencesDTO = serde_json::from_str(r#"{"theme": "blue"}"#).unwrap();
+        assert!(patch.validate().is_err());
+        let patch: PatchUserPreferencesDTO =
+            serde_json::from_str(r#"{"timezone": "America/Sao_Paulo"}"#).unwrap();
+        assert!(patch.validate().is_ok());
+        assert_eq!(patch.to_patch(), serde_json::json!({}));
+        let patch: PatchUserPreferencesDTO =
+            serde_json::from_str(r#"{"timezone": "Brasilia"}"#).unwrap();
+        assert!(patch.validate().is_err());
+        let patch: PatchUserPreferencesDTO =
+            serde_json::from_str(r#"{"notificationOptOuts": ["like_on_post", "follow"]}"#).unwrap();
+        assert!(patch.validate().is_ok());
+        assert_eq!(
+            patch.to_patch(),
+            serde_json::json!({"notificationOptOuts": ["like_on_post", "follow"]})
+        );
+        let patch: PatchUserPreferencesDTO =
+            serde_json::from_str(r#"{"notificationOptOuts": ["newsletter"]}"#).unwrap();
+        assert!(patch.validate().is_err());
+    }
+
+    #[test]
+    async fn _notification_opt_out() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = RedisModels::pool_success(None).await;
+        let user_id = seed_user().await;
+        let patch = PatchUserPreferencesDTO {
+            notification_opt_outs: Some(vec![String::from("like_on_post")]),
+            ..Default::default()
+        };
+        let preferences =
+            patch_user_preferences_repository(pg_pool.clone(), user_id, patch.to_patch(), None)
+                .await
+                .unwrap();
+        assert_eq!(preferences.notification_opt_outs, vec!["like_on_post"]);
+
+        let notification = insert_notification_service(
+            pg_pool.clone(),
+            &redis_pool,
+            user_id,
+            None,
+            "like_on_post",
+            serde_json::json!({ "postId": uuid::Uuid::new_v4().to_string() }),
+        )
+        .await
+        .unwrap();
+        assert!(notification.is_none());
+        let client = pg_pool.get().await.unwrap();
+        let count_notifications = || async {
+            client
+                .query_one(
+                    "SELECT COUNT(*) AS count FROM notifications WHERE user_id = $1",
+                    &[&user_id],
+                )
+                .await
+                .unwrap()
+                .get::<_, i64>("count")
+        };
+        assert_eq!(count_notifications().await, 0);
+
+        let notification = insert_notification_service(
+            pg_pool.clone(),
+            &redis_pool,
+            user_id,
+            None,
+            "mention",
+            serde_json::json!({}),
+        )
+        .await
+        .unwrap();
+        assert!(notification.is_some());
+        assert_eq!(count_notifications().await, 1);
+
+        client
+            .execute("DELETE FROM notifications WHERE user_id = $1", &[&user_id])
+            .await
+            .unwrap();
+        cleanup(user_id).await;
+    }
+
+    #[test]
+    async fn _detail_user_created_at_local() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let user_id = seed_user().await;
+        pg_pool
+            .get()
+            .await
+            .unwrap()
+            .execute(
+                "UPDATE users SET created_at = '2024-01-15 12:00:00+00' WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            local_time("2024-01-15 12:00:00 UTC", "America/Sao_Paulo").unwrap(),
+            "2024-01-15T09:00:00-03:00"
+        );
+        assert!(local_time("2024-01-15 12:00:00 UTC", "Brasilia").is_none());
+
+        let patch = PatchUserPreferencesDTO {
+            timezone: Some(String::from("America/Sao_Paulo")),
+            ..Default::default()
+        };
+        let preferences = patch_user_preferences_repository(
+            pg_pool.clone(),
+            user_id,
+            patch.to_patch(),
+            patch.timezone.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(preferences.timezone, "America/Sao_Paulo");
+        assert_eq!(preferences.theme, "system");
+
+        let app = test::init_service(
+            App::new()
+                .app_data(pg_pool.clone())
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/{}", user_id))
+            .append_header((
+                "Authorization",
+                format!(
+                    "Bearer {}",
+                    JwtModels::user_access_jwt_model(user_id.to_string())
+                ),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let user: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(user["createdAt"], "2024-01-15 12:00:00 UTC");
+        assert_eq!(user["createdAtLocal"], "2024-01-15T09:00:00-03:00");
+
+        cleanup(user_id).await;
+    }
+}
@@ -0,0 +1,107 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod user_recommendation_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::modules::user_recommendation::user_recommendation_repositories::user_recommendations_repository;
+
+    #[test]
+    async fn _user_recommendations() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let client = pg_pool.get().await.unwrap();
+        let (reader_id, similar_id, other_id) = (
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+            uuid::Uuid::new_v4(),
+        );
+        for user_id in [reader_id, similar_id, other_id] {
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                    &[&user_id, &format!("{}@gmail.com", user_id)],
+                )
+                .await
+                .unwrap();
+        }
+
+        let insert_post = "INSERT INTO posts (id, title, subtitle, body, author_id, created_at, published_at) VALUES ($1, 'Rust', 'sub', 'body', $2, NOW(), NOW())";
+        let mut post_ids = Vec::new();
+        let mut new_post = |author_id: uuid::Uuid| {
+            let post_id = uuid::Uuid::new_v4();
+            post_ids.push(post_id);
+            (post_id, author_id)
+        };
+        let liked = [new_post(other_id), new_post(other_id), new_post(other_id)];
+        let (recommended_id, _) = new_post(similar_id);
+        let (read_id, _) = new_post(similar_id);
+        let (unrelated_id, _) = new_post(other_id);
+        for (post_id, author_id) in liked.iter().chain(
+            [
+                (recommended_id, similar_id),
+                (read_id, similar_id),
+                (unrelated_id, other_id),
+            ]
+            .iter(),
+        ) {
+            client
+                .execute(insert_post, &[post_id, author_id])
+                .await
+                .unwrap();
+        }
+
+        let like_sql = "INSERT INTO users_posts_likes (user_id, post_id) VALUES ($1, $2)";
+        for (post_id, _) in &liked[..2] {
+            client
+                .execute(like_sql, &[&reader_id, post_id])
+                .await
+                .unwrap();
+            client
+                .execute(like_sql, &[&similar_id, post_id])
+                .await
+                .unwrap();
+        }
+        client
+            .execute(like_sql, &[&other_id, &liked[2].0])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO post_views (user_id, post_id, viewed_at) VALUES ($1, $2, NOW())",
+                &[&reader_id, &read_id],
+            )
+            .await
+            .unwrap();
+
+        let posts = user_recommendations_repository(pg_pool.clone(), reader_id, 10)
+            .await
+            .unwrap();
+        let ids: Vec<String> = posts.into_iter().map(|post| post.id).collect();
+        assert_eq!(ids, vec![recommended_id.to_string()]);
+
+        let posts = user_recommendations_repository(pg_pool.clone(), uuid::Uuid::new_v4(), 10)
+            .await
+            .unwrap();
+        assert!(posts.is_empty());
+
+        client
+            .execute(
+                "DELETE FROM users_posts_likes WHERE post_id = ANY($1)",
+                &[&post_ids],
+            )
+            .await
+            .unwrap();
+        client
+            .execute("DELETE FROM posts WHERE id = ANY($1)", &[&post_ids])
+            .await
+            .unwrap();
+        client
+            .execute(
+                "DELETE FROM users WHERE id = ANY($1)",
+                &[&vec![reader_id, similar_id, other_id]],
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -7,6 +7,7 @@ mod unitary_specs {
         functional_tester::FunctionalTester,
         models::{
             postgres::PostgresModels,
+            redis::RedisModels,
             user::{QueryParamsModels, UserModels},
         },
         structs::user::{MockPutUserDTO, MockUserDTO},
@@ -17,21 +18,65 @@ mod unitary_specs {
     };
     use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
     use navarro_blog_api::{
-        modules::user::{
-            user_providers::{email_exists, email_not_exists},
-            user_queues::{DeleteUserAppQueue, InsertUserAppQueue, PutUserAppQueue},
-            user_repositories::{
-                delete_user_repository, detail_user_repository, insert_user_repository,
-                list_users_repository, login_user_repository, put_user_repository,
-            },
-            user_services::{
-                delete_user_service, detail_user_service, insert_user_service, list_users_service,
-                login_user_service, put_user_service,
+        infra::redis::Redis,
+        middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+        modules::{
+            login_history::login_history_dtos::LoginClient,
+            tenant::{tenant_dtos::InsertTenantDTO, tenant_services::insert_tenant_service},
+            user::{
+                user_dtos::{
+                    BatchGetUsersDTO, DetailUserDTO, PutUserDTO, UpdateNameDTO, UpdateUsernameDTO,
+                    UserDTO,
+                },
+                user_providers::{
+                    email_exists, email_not_exists, username_exists, username_not_exists,
+                },
+                user_queues::{
+                    drain_insert_user_queue, put_user_flush_queue, DeleteUserAppQueue,
+                    InsertUserAppQueue, PutUserAppQueue,
+                },
+                user_repositories::{
+                    delete_user_repository, detail_user_repository, insert_user_repository,
+                    list_users_repository, login_user_repository, password_history_repository,
+                    put_user_repository, PASSWORD_HISTORY_LIMIT,
+                },
+                user_services::{
+                    batch_get_users_service, delete_user_service, detail_user_service,
+                    insert_user_service, list_name_history_service, list_users_service,
+                    login_user_service, put_user_service, update_name_service,
+                    update_username_service,
+                },
             },
         },
         shared::structs::jwt_claims::Claims,
     };
-    use std::sync::Arc;
+    use std::{sync::Arc, time::Duration};
+    use validator::Validate;
+
+    async fn seed_user_with_username(username: &str) -> uuid::Uuid {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        let user_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO users (id, name, username, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, $3, 'x', NOW())",
+                &[&user_id, &username, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        user_id
+    }
+
+    async fn delete_seeded_user(user_id: uuid::Uuid) {
+        let client = PostgresModels::postgres_success(None).get().await.unwrap();
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    fn unique_username() -> String {
+        format!("u_{}", &uuid::Uuid::new_v4().simple().to_string()[..20])
+    }
 
     #[test]
     async fn _insert_user_service() {
@@ -42,9 +87,10 @@ mod unitary_specs {
 
         let resp = insert_user_service(
             web::Data::new(queue.clone()),
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Json(user.clone().into()),
             String::from(""),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -74,13 +120,18 @@ mod unitary_specs {
         let queue = Arc::new(InsertUserAppQueue::new());
         let user = UserModels::simple_user_model();
 
-        FunctionalTester::insert_in_db_users(UserModels::complete_user_model_hashed()).await;
+        FunctionalTester::insert_in_db_users(
+            UserModels::complete_user_model_hashed(),
+            DEFAULT_TENANT_ID,
+        )
+        .await;
 
         let resp = insert_user_service(
             web::Data::new(queue.clone()),
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Json(user.clone().into()),
             String::from(""),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .err()
@@ -109,13 +160,18 @@ mod unitary_specs {
         let queue = Arc::new(InsertUserAppQueue::new());
         let user = UserModels::simple_user_model();
 
-        FunctionalTester::insert_in_db_users(UserModels::complete_user_model_hashed()).await;
+        FunctionalTester::insert_in_db_users(
+            UserModels::complete_user_model_hashed(),
+            DEFAULT_TENANT_ID,
+        )
+        .await;
 
         let resp = insert_user_service(
             web::Data::new(queue.clone()),
             web::Data::new(PostgresModels::postgres_error()),
             web::Json(user.clone().into()),
             String::from(""),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .err()
@@ -148,6 +204,7 @@ mod unitary_specs {
             web::Json(UserModels::simple_user_model().into()),
             user.id.clone(),
             uuid::Uuid::new_v4().to_string(),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -162,6 +219,63 @@ mod unitary_specs {
         assert!(resp.updated_at.is_none());
     }
 
+    #[test]
+    async fn _drain_insert_user_queue_multiple_users() {
+        dotenv::dotenv().ok();
+
+        let queue = Arc::new(InsertUserAppQueue::new());
+        let mut emails: Vec<String> = Vec::with_capacity(2);
+        let mut user_ids: Vec<String> = Vec::with_capacity(2);
+
+        for i in 0..2 {
+            let mut user = UserModels::simple_user_model();
+            user.email += &i.to_string();
+            user.username += &i.to_string();
+            emails.push(user.email.clone());
+            user_ids.push(uuid::Uuid::new_v4().to_string());
+
+            insert_user_repository(
+                web::Data::new(queue.clone()),
+                web::Json(user.into()),
+                user_ids[i].clone(),
+                uuid::Uuid::new_v4().to_string(),
+                String::from(DEFAULT_TENANT_ID),
+            )
+            .await
+            .unwrap();
+        }
+
+        drain_insert_user_queue(
+            PostgresModels::postgres_success(None),
+            queue.clone(),
+            Duration::from_secs(10),
+        )
+        .await;
+
+        assert_eq!(queue.len(), 0);
+        for email in emails.iter() {
+            assert!(
+                FunctionalTester::can_see_in_database(
+                    TablesEnum::Users,
+                    "email",
+                    Some(vec![("email", email)]),
+                )
+                .await
+            );
+        }
+
+        FunctionalTester::delete_from_database(
+            TablesEnum::Salt,
+            Some(vec![("user_id", &user_ids[0]), ("user_id", &user_ids[1])]),
+        )
+        .await;
+        FunctionalTester::delete_from_database(
+            TablesEnum::Users,
+            Some(vec![("email", &emails[0]), ("email", &emails[1])]),
+        )
+        .await;
+    }
+
     #[test]
     async fn _login_user_service() {
         dotenv::dotenv().ok();
@@ -170,7 +284,7 @@ mod unitary_specs {
 
         let salt = uuid::Uuid::new_v4().to_string();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
 
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
@@ -178,8 +292,11 @@ mod unitary_specs {
 
         let resp = login_user_service(
             login_user.clone().into(),
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
+            web::Data::new(RedisModels::pool_success(None).await),
             String::from(""),
+            LoginClient::default(),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -226,8 +343,11 @@ mod unitary_specs {
 
         let resp = login_user_service(
             UserModels::login_user_model().into(),
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
+            web::Data::new(RedisModels::pool_success(None).await),
             String::from(""),
+            LoginClient::default(),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .err()
@@ -252,7 +372,7 @@ mod unitary_specs {
 
         let salt = uuid::Uuid::new_v4().to_string();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
 
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt).await;
 
@@ -261,8 +381,11 @@ mod unitary_specs {
 
         let resp = login_user_service(
             login_user.clone().into(),
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
+            web::Data::new(RedisModels::pool_success(None).await),
             String::from(""),
+            LoginClient::default(),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .err()
@@ -295,7 +418,7 @@ mod unitary_specs {
 
         let salt = uuid::Uuid::new_v4().to_string();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
 
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt).await;
 
@@ -304,7 +427,10 @@ mod unitary_specs {
         let resp = login_user_service(
             login_user.clone().into(),
             web::Data::new(PostgresModels::postgres_error()),
+            web::Data::new(RedisModels::pool_success(None).await),
             String::from(""),
+            LoginClient::default(),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .err()
@@ -332,11 +458,12 @@ mod unitary_specs {
 
         let user = UserModels::complete_user_model();
 
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
 
         let resp = login_user_repository(
             user.email.clone(),
-            web::Data::new(PostgresModels::postgres_success()),
+            String::from(DEFAULT_TENANT_ID),
+            web::Data::new(PostgresModels::postgres_success(None)),
         )
         .await
         .unwrap();
@@ -374,7 +501,8 @@ mod unitary_specs {
 
         let resp = login_user_repository(
             user.email.clone(),
-            web::Data::new(PostgresModels::postgres_success()),
+            String::from(DEFAULT_TENANT_ID),
+            web::Data::new(PostgresModels::postgres_success(None)),
         )
         .await
         .err()
@@ -407,6 +535,7 @@ mod unitary_specs {
 
         let resp = login_user_repository(
             user.email.clone(),
+            String::from(DEFAULT_TENANT_ID),
             web::Data::new(PostgresModels::postgres_error()),
         )
         .await
@@ -428,10 +557,10 @@ mod unitary_specs {
 
         let user = UserModels::complete_user_model_hashed();
 
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
 
         let resp = detail_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             user.id.clone(),
             String::from(""),
         )
@@ -460,7 +589,7 @@ mod unitary_specs {
         let user = UserModels::complete_user_model_hashed();
 
         let resp = detail_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             user.id.clone(),
             String::from(""),
         )
@@ -515,10 +644,10 @@ mod unitary_specs {
 
         let user = UserModels::complete_user_model_hashed();
 
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
 
         let resp = detail_user_repository(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             user.id.clone(),
         )
         .await
@@ -546,7 +675,7 @@ mod unitary_specs {
         let user = UserModels::complete_user_model();
 
         let resp = detail_user_repository(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             user.id.clone(),
         )
         .await
@@ -599,12 +728,13 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let resp = list_users_service(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::default_query_params_model()),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -628,6 +758,71 @@ mod unitary_specs {
         }
     }
 
+    #[test]
+    async fn _list_users_service_scoped_by_tenant() {
+        dotenv::dotenv().ok();
+
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let mut tenants: Vec<String> = Vec::with_capacity(2);
+
+        for i in 0..2 {
+            let tenant = insert_tenant_service(
+                pg_pool.clone(),
+                InsertTenantDTO {
+                    name: format!("tenant-{}-{}", i, uuid::Uuid::new_v4()),
+                },
+            )
+            .await
+            .unwrap();
+            tenants.push(tenant.id);
+        }
+
+        let mut user_a = UserModels::complete_user_model_hashed();
+        user_a.email += "tenant_a";
+        let user_a = FunctionalTester::insert_in_db_users(user_a, &tenants[0]).await;
+
+        let mut user_b = UserModels::complete_user_model_hashed();
+        user_b.email += "tenant_b";
+        let user_b = FunctionalTester::insert_in_db_users(user_b, &tenants[1]).await;
+
+        let resp_a = list_users_service(
+            pg_pool.clone(),
+            Query(QueryParamsModels::default_query_params_model()),
+            tenants[0].clone(),
+        )
+        .await
+        .unwrap();
+        let resp_b = list_users_service(
+            pg_pool.clone(),
+            Query(QueryParamsModels::default_query_params_model()),
+            tenants[1].clone(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resp_a.len(), 1);
+        assert!(resp_a[0].id == user_a.id);
+        assert_eq!(resp_b.len(), 1);
+        assert!(resp_b[0].id == user_b.id);
+
+        FunctionalTester::delete_from_database(
+            TablesEnum::Users,
+            Some(vec![("email", &user_a.email), ("email", &user_b.email)]),
+        )
+        .await;
+
+        let client = pg_pool.get().await.unwrap();
+        for tenant in tenants {
+            client
+                .execute(
+                    "DELETE FROM tenants WHERE id = $1",
+                    &[&uuid::Uuid::parse_str(&tenant).unwrap()],
+                )
+                .await
+                .unwrap();
+        }
+    }
+
     #[test]
     async fn _list_users_service_offset_query_params() {
         dotenv::dotenv().ok();
@@ -639,12 +834,13 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
         let offset = 2;
         let resp = list_users_service(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::offset_query_params_model(offset)),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -685,12 +881,13 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
         let limit = 2;
         let resp = list_users_service(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::limit_query_params_model(limit)),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -731,12 +928,13 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let resp = list_users_service(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::order_by_query_params_model("created_at")),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -770,12 +968,13 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let resp = list_users_service(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::order_direction_query_params_model("asc")),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .unwrap();
@@ -805,6 +1004,7 @@ mod unitary_specs {
         let resp = list_users_service(
             Data::new(PostgresModels::postgres_error()),
             Query(QueryParamsModels::default_query_params_model()),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .err()
@@ -824,8 +1024,9 @@ mod unitary_specs {
         dotenv::dotenv().ok();
 
         let resp = list_users_service(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::default_query_params_model()),
+            String::from(DEFAULT_TENANT_ID),
         )
         .await
         .err()
@@ -854,12 +1055,14 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let resp = list_users_repository(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::default_query_params_model()),
+            String::from(DEFAULT_TENANT_ID),
+            None,
         )
         .await
         .unwrap();
@@ -894,12 +1097,14 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
         let offset = 2;
         let resp = list_users_repository(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::offset_query_params_model(offset)),
+            String::from(DEFAULT_TENANT_ID),
+            None,
         )
         .await
         .unwrap();
@@ -940,12 +1145,14 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
         let limit = 2;
         let resp = list_users_repository(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::limit_query_params_model(limit)),
+            String::from(DEFAULT_TENANT_ID),
+            None,
         )
         .await
         .unwrap();
@@ -986,12 +1193,14 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let resp = list_users_repository(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::order_by_query_params_model("created_at")),
+            String::from(DEFAULT_TENANT_ID),
+            None,
         )
         .await
         .unwrap();
@@ -1025,12 +1234,14 @@ mod unitary_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let resp = list_users_repository(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::order_direction_query_params_model("asc")),
+            String::from(DEFAULT_TENANT_ID),
+            None,
         )
         .await
         .unwrap();
@@ -1060,6 +1271,8 @@ mod unitary_specs {
         let resp = list_users_repository(
             Data::new(PostgresModels::postgres_error()),
             Query(QueryParamsModels::default_query_params_model()),
+            String::from(DEFAULT_TENANT_ID),
+            None,
         )
         .await
         .err()
@@ -1079,8 +1292,10 @@ mod unitary_specs {
         dotenv::dotenv().ok();
 
         let resp = list_users_repository(
-            Data::new(PostgresModels::postgres_success()),
+            Data::new(PostgresModels::postgres_success(None)),
             Query(QueryParamsModels::default_query_params_model()),
+            String::from(DEFAULT_TENANT_ID),
+            None,
         )
         .await
         .err()
@@ -1107,11 +1322,11 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let email_resp = delete_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Data::new(queue),
             UserModels::complete_user_model().password,
             user.id,
@@ -1145,7 +1360,7 @@ mod unitary_specs {
         );
 
         let resp = delete_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Data::new(queue),
             UserModels::complete_user_model().password,
             user.id,
@@ -1174,13 +1389,13 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let error_password = String::from("123456789%");
 
         let resp = delete_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Data::new(queue),
             error_password.clone(),
             user.id,
@@ -1234,7 +1449,7 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let resp = delete_user_service(
@@ -1290,7 +1505,7 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let resp = delete_user_repository(web::Data::new(queue), user.id)
@@ -1316,7 +1531,7 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -1330,7 +1545,7 @@ mod unitary_specs {
         };
 
         let resp = put_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Data::new(queue),
             put_user_dto.into(),
             user.id.clone(),
@@ -1364,7 +1579,7 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -1378,7 +1593,7 @@ mod unitary_specs {
         };
 
         let resp = put_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Data::new(queue),
             put_user_dto.into(),
             user.id,
@@ -1414,7 +1629,7 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -1428,7 +1643,7 @@ mod unitary_specs {
         };
 
         let resp = put_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Data::new(queue),
             put_user_dto.into(),
             user.id,
@@ -1473,7 +1688,7 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_password = String::from("123456789%");
@@ -1486,7 +1701,7 @@ mod unitary_specs {
         };
 
         let resp = put_user_service(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             web::Data::new(queue),
             put_user_dto.into(),
             user.id,
@@ -1522,6 +1737,117 @@ mod unitary_specs {
         .await;
     }
 
+    #[test]
+    async fn _put_user_service_error_password_reused() {
+        dotenv::dotenv().ok();
+
+        let pg_pool = web::Data::new(PostgresModels::postgres_success(None));
+        let queue = web::Data::new(Arc::new(PutUserAppQueue::new()));
+
+        let salt = uuid::Uuid::new_v4().to_string();
+        let mut user = UserModels::complete_user_model_hashed();
+        user.username = format!("reuse_{}", &user.id[..8]);
+        user.email = format!("reuse_{}@gmail.com", &user.id[..8]);
+        user.password = format!("{}{}", user.password, salt);
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
+        FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
+        let user_id = uuid::Uuid::parse_str(&user.id).unwrap();
+
+        let old_password = String::from("Antiga@2023");
+        let old_hash = format!("{}{}", bcrypt::hash(&old_password, 4).unwrap(), salt);
+        let older_hash = format!("{}{}", bcrypt::hash("Velha@2022", 4).unwrap(), salt);
+        let client = pg_pool.get().await.unwrap();
+        for _ in 1..PASSWORD_HISTORY_LIMIT {
+            client
+                .execute(
+                    "INSERT INTO password_history (id, user_id, password_hash, created_at)
+                    VALUES ($1, $2, $3, NOW() - INTERVAL '1 day')",
+                    &[&uuid::Uuid::new_v4(), &user_id, &older_hash],
+                )
+                .await
+                .unwrap();
+        }
+        client
+            .execute(
+                "INSERT INTO password_history (id, user_id, password_hash, created_at)
+                VALUES ($1, $2, $3, NOW())",
+                &[&uuid::Uuid::new_v4(), &user_id, &old_hash],
+            )
+            .await
+            .unwrap();
+
+        let put_user_dto = |new_password: &str| -> PutUserDTO {
+            MockPutUserDTO {
+                password: UserModels::complete_user_model().password,
+                email: user.email.clone(),
+                new_password: new_password.to_string(),
+                new_email: format!("reuse_new_{}@gmail.com", &user.id[..8]),
+            }
+            .into()
+        };
+
+        for reused_password in [UserModels::complete_user_model().password, old_password] {
+            let resp = put_user_service(
+                pg_pool.clone(),
+                queue.clone(),
+                put_user_dto(&reused_password),
+                user.id.clone(),
+                String::from(""),
+            )
+            .await
+            .err()
+            .unwrap();
+
+            assert_eq!(resp.status(), 400);
+
+            let bytes = String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec())
+                .unwrap();
+
+            assert!(bytes.contains("new_password"));
+            assert!(bytes.contains(
+                "A nova senha não pode ser igual a uma das últimas 5 senhas utilizadas."
+            ));
+        }
+
+        put_user_service(
+            pg_pool.clone(),
+            queue.clone(),
+            put_user_dto("Nova@2025!"),
+            user.id.clone(),
+            String::from(""),
+        )
+        .await
+        .unwrap();
+
+        let history = password_history_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(history[0], old_hash);
+
+        let flush_pool = PostgresModels::postgres_success(None);
+        let flush_queue = queue.get_ref().clone();
+        tokio::spawn(async move { put_user_flush_queue(flush_pool, flush_queue).await });
+        tokio::time::sleep(Duration::from_secs(3)).await;
+
+        let history = password_history_repository(pg_pool.clone(), user_id)
+            .await
+            .unwrap();
+        assert_eq!(history[0], user.password);
+        assert_eq!(history[1], old_hash);
+        let rows = client
+            .query(
+                "SELECT id FROM password_history WHERE user_id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len() as i64, PASSWORD_HISTORY_LIMIT);
+
+        FunctionalTester::delete_from_database(TablesEnum::Salt, Some(vec![("salt", &salt)])).await;
+        FunctionalTester::delete_from_database(TablesEnum::Users, Some(vec![("id", &user.id)]))
+            .await;
+    }
+
     #[test]
     async fn _put_user_repository() {
         dotenv::dotenv().ok();
@@ -1531,7 +1857,7 @@ mod unitary_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -1566,10 +1892,14 @@ mod unitary_specs {
     async fn _email_exists_provider() {
         dotenv::dotenv().ok();
 
-        let user = FunctionalTester::insert_in_db_users(UserModels::complete_user_model()).await;
+        let user = FunctionalTester::insert_in_db_users(
+            UserModels::complete_user_model(),
+            DEFAULT_TENANT_ID,
+        )
+        .await;
 
         let resp = email_exists(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             UserModels::simple_user_model().email,
         )
         .await
@@ -1596,7 +1926,11 @@ mod unitary_specs {
     async fn _email_exists_provider_error_service_unavailable() {
         dotenv::dotenv().ok();
 
-        let user = FunctionalTester::insert_in_db_users(UserModels::complete_user_model()).await;
+        let user = FunctionalTester::insert_in_db_users(
+            UserModels::complete_user_model(),
+            DEFAULT_TENANT_ID,
+        )
+        .await;
 
         let resp = email_exists(
             web::Data::new(PostgresModels::postgres_error()),
@@ -1637,7 +1971,7 @@ mod unitary_specs {
         let user = UserModels::simple_user_model();
 
         let resp = email_not_exists(
-            web::Data::new(PostgresModels::postgres_success()),
+            web::Data::new(PostgresModels::postgres_success(None)),
             user.email.clone(),
         )
         .await
@@ -1685,6 +2019,321 @@ mod unitary_specs {
         assert!(bytes.contains("database"));
         assert!(bytes.contains("service unavailable"));
     }
+
+    #[test]
+    async fn _detail_user_dto_camel_case() {
+        let user = UserModels::detail_user_model();
+
+        let json = serde_json::to_string(&DetailUserDTO {
+            id: user.id,
+            name: user.name,
+            username: String::from("victor_navarro"),
+            email: user.email,
+            created_at: user.created_at,
+        })
+        .unwrap();
+
+        assert!(json.contains("createdAt"));
+        assert!(!json.contains("created_at"));
+    }
+
+    #[test]
+    async fn _put_user_dto_snake_case_and_camel_case() {
+        let snake_case: PutUserDTO = serde_json::from_str(
+            r#"{"email": "a@gmail.com", "password": "1234567%", "new_email": "b@gmail.com", "new_password": "7654321%"}"#,
+        )
+        .unwrap();
+        let camel_case: PutUserDTO = serde_json::from_str(
+            r#"{"email": "a@gmail.com", "password": "1234567%", "newEmail": "b@gmail.com", "newPassword": "7654321%"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(snake_case.new_email, camel_case.new_email);
+        assert_eq!(snake_case.new_password, camel_case.new_password);
+    }
+
+    #[test]
+    async fn _update_username_dto_validation() {
+        let valid = ["abc", "victor_navarro", "user_2024", &"a".repeat(30)];
+        for username in valid {
+            let dto = UpdateUsernameDTO {
+                username: String::from(username),
+            };
+            assert!(dto.validate().is_ok(), "{} should be valid", username);
+        }
+
+        let invalid = [
+            "ab",
+            "Victor",
+            "victor navarro",
+            "victor-navarro",
+            "vítor",
+            &"a".repeat(31),
+        ];
+        for username in invalid {
+            let dto = UpdateUsernameDTO {
+                username: String::from(username),
+            };
+            assert!(dto.validate().is_err(), "{} should be invalid", username);
+        }
+    }
+
+    #[test]
+    async fn _username_providers() {
+        dotenv::dotenv().ok();
+        let pg_pool = web::Data::new(PostgresModels::postgres_success(None));
+        let username = unique_username();
+        let user_id = seed_user_with_username(&username).await;
+
+        let resp = username_exists(pg_pool.clone(), username.clone())
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(resp.status(), 409);
+        assert!(username_not_exists(pg_pool.clone(), username.clone())
+            .await
+            .is_ok());
+
+        let other = unique_username();
+        assert!(username_exists(pg_pool.clone(), other.clone())
+            .await
+            .is_ok());
+        let resp = username_not_exists(pg_pool.clone(), other)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(resp.status(), 404);
+
+        delete_seeded_user(user_id).await;
+    }
+
+    #[test]
+    async fn _update_username_service() {
+        dotenv::dotenv().ok();
+        let pg_pool = web::Data::new(PostgresModels::postgres_success(None));
+        let taken = unique_username();
+        let other_id = seed_user_with_username(&taken).await;
+        let user_id = seed_user_with_username(&unique_username()).await;
+
+        let resp = update_username_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            UpdateUsernameDTO {
+                username: taken.clone(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(resp.status(), 409);
+
+        let username = unique_username();
+        let user = update_username_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            UpdateUsernameDTO {
+                username: username.clone(),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(user.id, user_id.to_string());
+        assert_eq!(user.username, username);
+        assert!(user.updated_at.is_some());
+
+        let resp = update_username_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            UpdateUsernameDTO {
+                username: unique_username(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(resp.status(), 429);
+        let bytes =
+            String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec()).unwrap();
+        assert!(bytes.contains("too many requests"));
+
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "UPDATE users SET username_changed_at = NOW() - INTERVAL '31 days' WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert!(update_username_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            UpdateUsernameDTO {
+                username: unique_username(),
+            },
+        )
+        .await
+        .is_ok());
+
+        delete_seeded_user(user_id).await;
+        delete_seeded_user(other_id).await;
+    }
+
+    #[test]
+    async fn _batch_get_users_service_partial_cache_hits() {
+        dotenv::dotenv().ok();
+        let pg_pool = web::Data::new(PostgresModels::postgres_success(None));
+        let redis_pool = web::Data::new(RedisModels::pool_success(None).await);
+        let cached_id = seed_user_with_username(&unique_username()).await;
+        let uncached_id = seed_user_with_username(&unique_username()).await;
+        let missing_id = uuid::Uuid::new_v4();
+        let other_tenant_id = seed_user_with_username(&unique_username()).await;
+        let client = pg_pool.get().await.unwrap();
+        let tenant_id = uuid::Uuid::new_v4();
+        client
+            .execute(
+                "INSERT INTO tenants (id, name) VALUES ($1, $2)",
+                &[&tenant_id, &tenant_id.to_string()],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "UPDATE users SET tenant_id = $2 WHERE id = $1",
+                &[&other_tenant_id, &tenant_id],
+            )
+            .await
+            .unwrap();
+
+        let cached_user = UserDTO {
+            id: cached_id.to_string(),
+            name: String::from("Nome Em Cache"),
+            username: String::from("cached_user"),
+            email: format!("{}@gmail.com", cached_id),
+            password: String::from("x"),
+            created_at: chrono::Utc::now().to_string(),
+            updated_at: None,
+        };
+        Redis::set(
+            &redis_pool,
+            &cached_user.id,
+            &serde_json::to_string(&cached_user).unwrap(),
+        )
+        .await
+        .unwrap();
+        let _ = Redis::delete(&redis_pool, &uncached_id.to_string()).await;
+
+        let users = batch_get_users_service(
+            pg_pool.clone(),
+            redis_pool.clone(),
+            BatchGetUsersDTO {
+                ids: vec![
+                    cached_id.to_string(),
+                    uncached_id.to_string(),
+                    missing_id.to_string(),
+                    cached_id.to_string(),
+                    other_tenant_id.to_string(),
+                ],
+            },
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .await
+        .unwrap();
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[&cached_id.to_string()].name, "Nome Em Cache");
+        assert_eq!(users[&uncached_id.to_string()].name, "Victor Navarro");
+        assert!(!users.contains_key(&missing_id.to_string()));
+        assert!(!users.contains_key(&other_tenant_id.to_string()));
+
+        let redis_user = Redis::get(&redis_pool, &uncached_id.to_string())
+            .await
+            .unwrap();
+        assert!(redis_user.contains(&uncached_id.to_string()));
+
+        for user_id in [cached_id, uncached_id, other_tenant_id] {
+            let _ = Redis::delete(&redis_pool, &user_id.to_string()).await;
+            let _ = Redis::delete(&redis_pool, &format!("{}@gmail.com", user_id)).await;
+            delete_seeded_user(user_id).await;
+        }
+        client
+            .execute("DELETE FROM tenants WHERE id = $1", &[&tenant_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _batch_get_users_dto_error_too_many_ids() {
+        let body = BatchGetUsersDTO {
+            ids: (0..101).map(|_| uuid::Uuid::new_v4().to_string()).collect(),
+        };
+        assert!(body.validate().is_err());
+
+        let body = BatchGetUsersDTO {
+            ids: vec![String::from("not an uuid")],
+        };
+        assert!(body.validate().is_err());
+    }
+
+    #[test]
+    async fn _update_name_service_limited_to_three_changes() {
+        dotenv::dotenv().ok();
+        let pg_pool = web::Data::new(PostgresModels::postgres_success(None));
+        let user_id = seed_user_with_username(&unique_username()).await;
+
+        for name in ["Primeiro Nome", "Segundo Nome", "Terceiro Nome"] {
+            let user = update_name_service(
+                pg_pool.clone(),
+                user_id.to_string(),
+                UpdateNameDTO {
+                    name: String::from(name),
+                },
+            )
+            .await
+            .unwrap();
+            assert_eq!(user.name, name);
+        }
+
+        let resp = update_name_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            UpdateNameDTO {
+                name: String::from("Quarto Nome"),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+        assert_eq!(resp.status(), 429);
+        let bytes =
+            String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec()).unwrap();
+        assert!(bytes.contains("too many requests"));
+
+        let history = list_name_history_service(pg_pool.clone(), user_id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].old_name, "Segundo Nome");
+        assert_eq!(history[0].new_name, "Terceiro Nome");
+
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "UPDATE user_name_history SET changed_at = NOW() - INTERVAL '31 days' WHERE user_id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert!(update_name_service(
+            pg_pool.clone(),
+            user_id.to_string(),
+            UpdateNameDTO {
+                name: String::from("Quarto Nome"),
+            },
+        )
+        .await
+        .is_ok());
+
+        delete_seeded_user(user_id).await;
+    }
 }
 
 #[cfg(test)]
@@ -1711,6 +2360,10 @@ mod integration_specs {
     };
     use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
     use navarro_blog_api::{
+        config::extractors::path_config,
+        middlewares::{
+            body_size_limit_middleware::json_config, tenant_middleware::DEFAULT_TENANT_ID,
+        },
         modules::user::{
             user_controllers::user_controllers_module,
             user_queues::{
@@ -1718,7 +2371,10 @@ mod integration_specs {
                 DeleteUserAppQueue, InsertUserAppQueue, PutUserAppQueue,
             },
         },
-        shared::structs::{jwt_claims::Claims, query_params::QueryParams},
+        shared::{
+            structs::{jwt_claims::Claims, query_params::QueryParams},
+            treaties::jwt_treated::Jwt,
+        },
     };
     use serde_json::Value;
     use std::sync::Arc;
@@ -1728,6 +2384,7 @@ mod integration_specs {
         InsertUserDTO(MockUserDTO),
         LoginUserDTO(MockLoginUserDTO),
         DetailUserDTO(MockDetailUserDTO, Option<String>),
+        ConditionalDetailUserDTO(MockDetailUserDTO, Option<String>, String),
         ListUsersDTO(Query<QueryParams>, Option<String>),
         DeleteUserDTO(MockDeleteUserDTO, Option<String>, Option<String>),
         PutUserDTO(MockPutUserDTO, Option<String>, Option<String>),
@@ -1735,12 +2392,12 @@ mod integration_specs {
 
     async fn user_call_http_before(user: UserTypes, pool_error: bool) -> ServiceResponse {
         dotenv::dotenv().ok();
-        let redis_pool = RedisModels::pool_success().await;
+        let redis_pool = RedisModels::pool_success(None).await;
         let pool;
         if pool_error {
             pool = PostgresModels::postgres_error();
         } else {
-            pool = PostgresModels::postgres_success();
+            pool = PostgresModels::postgres_success(None);
         }
         let insert_pool_async = pool.clone();
         let insert_user_queue = Arc::new(InsertUserAppQueue::new());
@@ -1814,6 +2471,17 @@ mod integration_specs {
 
                 request.to_request()
             }
+            UserTypes::ConditionalDetailUserDTO(user, jwt, etag) => {
+                let mut request = test::TestRequest::get()
+                    .uri(&format!("/user/{}", user.id))
+                    .append_header(("If-None-Match", etag));
+
+                if let Some(token) = jwt {
+                    request = request.append_header(("Authorization", format!("Bearer {}", token)));
+                }
+
+                request.to_request()
+            }
             UserTypes::DeleteUserDTO(password, user_id, jwt) => {
                 let id = user_id.clone().unwrap_or(String::from("123456"));
                 let mut request = test::TestRequest::delete()
@@ -1992,7 +2660,7 @@ mod integration_specs {
     async fn _insert_user_error_email_conflict_db() {
         dotenv::dotenv().ok();
         let mut user = UserModels::complete_user_model();
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
 
         user.name = String::from("João Navarro");
         let resp = user_call_http_before(UserTypes::InsertUserDTO(user.clone()), false).await;
@@ -2073,6 +2741,31 @@ mod integration_specs {
         );
     }
 
+    #[test]
+    async fn _insert_user_error_password_strength() {
+        let mut user = UserModels::complete_user_model();
+        user.password = String::from("abcdefgh%");
+
+        let resp = user_call_http_before(UserTypes::InsertUserDTO(user.clone()), false).await;
+
+        assert_eq!(resp.status(), 400);
+
+        let value: Value = test::read_body_json(resp).await;
+        let error = &value["password"][0];
+        assert_eq!(error["code"], "password_strength");
+        assert_eq!(error["message"], "A senha é muito fraca.");
+        assert_eq!(error["params"]["password_strength"], 0);
+
+        assert!(
+            FunctionalTester::cant_see_in_database(
+                TablesEnum::Users,
+                "email",
+                Some(vec![("email", &user.email)])
+            )
+            .await
+        );
+    }
+
     #[test]
     async fn _insert_user_error_service_unavailable() {
         dotenv::dotenv().ok();
@@ -2107,7 +2800,7 @@ mod integration_specs {
 
         let salt = uuid::Uuid::new_v4().to_string();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let resp = user_call_http_before(
@@ -2121,12 +2814,14 @@ mod integration_specs {
         let bytes =
             String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec()).unwrap();
 
-        assert!(bytes.contains("access_token"));
-        assert!(bytes.contains("refresh_token"));
+        assert!(bytes.contains("accessToken"));
+        assert!(bytes.contains("refreshToken"));
+        assert!(bytes.contains("accessExpiresIn"));
+        assert!(bytes.contains("refreshExpiresIn"));
 
         let value: Value = serde_json::from_str(&bytes).unwrap();
-        let access_token = value["access_token"].as_str().unwrap();
-        let refresh_token = value["refresh_token"].as_str().unwrap();
+        let access_token = value["accessToken"].as_str().unwrap();
+        let refresh_token = value["refreshToken"].as_str().unwrap();
 
         let token_data = decode::<Claims>(
             &refresh_token,
@@ -2159,7 +2854,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let insert_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(insert_user.clone()).await;
+        FunctionalTester::insert_in_db_users(insert_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut login_user = UserModels::login_user_model();
         login_user.email = String::from("teste@gmailcom");
@@ -2185,7 +2880,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let insert_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(insert_user.clone()).await;
+        FunctionalTester::insert_in_db_users(insert_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut login_user = UserModels::login_user_model();
         login_user.email = String::from("");
@@ -2211,7 +2906,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let insert_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(insert_user.clone()).await;
+        FunctionalTester::insert_in_db_users(insert_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut login_user = UserModels::login_user_model();
         login_user.email = String::from("teste@gmail.com");
@@ -2237,7 +2932,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let insert_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(insert_user.clone()).await;
+        FunctionalTester::insert_in_db_users(insert_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut login_user = UserModels::login_user_model();
         login_user.password = String::from("1234567");
@@ -2263,7 +2958,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let insert_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(insert_user.clone()).await;
+        FunctionalTester::insert_in_db_users(insert_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut login_user = UserModels::login_user_model();
         login_user.password = String::from("12345678");
@@ -2292,7 +2987,7 @@ mod integration_specs {
 
         let salt = uuid::Uuid::new_v4().to_string();
         insert_user.password = format!("{}{}", insert_user.password, salt);
-        FunctionalTester::insert_in_db_users(insert_user.clone()).await;
+        FunctionalTester::insert_in_db_users(insert_user.clone(), DEFAULT_TENANT_ID).await;
 
         FunctionalTester::insert_in_db_salt(insert_user.id.clone(), salt.clone()).await;
 
@@ -2329,7 +3024,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let insert_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(insert_user.clone()).await;
+        FunctionalTester::insert_in_db_users(insert_user.clone(), DEFAULT_TENANT_ID).await;
 
         let login_user = UserModels::login_user_model();
         let resp = user_call_http_before(UserTypes::LoginUserDTO(login_user), true).await;
@@ -2354,7 +3049,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let inserted_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(inserted_user.clone()).await;
+        FunctionalTester::insert_in_db_users(inserted_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut detailed_user = UserModels::detail_user_model();
         detailed_user.id = inserted_user.id.clone();
@@ -2388,12 +3083,57 @@ mod integration_specs {
         .await;
     }
 
+    #[test]
+    async fn _detail_user_not_modified() {
+        dotenv::dotenv().ok();
+
+        let inserted_user = UserModels::complete_user_model_hashed();
+        FunctionalTester::insert_in_db_users(inserted_user.clone(), DEFAULT_TENANT_ID).await;
+
+        let mut detailed_user = UserModels::detail_user_model();
+        detailed_user.id = inserted_user.id.clone();
+        let jwt = JwtModels::access_jwt_model(inserted_user.id);
+        let resp = user_call_http_before(
+            UserTypes::DetailUserDTO(detailed_user.clone(), Some(jwt.clone())),
+            false,
+        )
+        .await;
+
+        assert_eq!(resp.status(), 200);
+        let etag = resp
+            .headers()
+            .get("ETag")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let resp = user_call_http_before(
+            UserTypes::ConditionalDetailUserDTO(detailed_user.clone(), Some(jwt), etag.clone()),
+            false,
+        )
+        .await;
+
+        assert_eq!(resp.status(), 304);
+        assert_eq!(resp.headers().get("ETag").unwrap().to_str().unwrap(), etag);
+
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+
+        assert!(bytes.is_empty());
+
+        FunctionalTester::delete_from_database(
+            TablesEnum::Users,
+            Some(vec![("email", &detailed_user.email)]),
+        )
+        .await;
+    }
+
     #[test]
     async fn _detail_user_error_service_unavailable() {
         dotenv::dotenv().ok();
 
         let inserted_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(inserted_user.clone()).await;
+        FunctionalTester::insert_in_db_users(inserted_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut detailed_user = UserModels::detail_user_model();
         detailed_user.id = inserted_user.id.clone();
@@ -2423,7 +3163,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let inserted_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(inserted_user.clone()).await;
+        FunctionalTester::insert_in_db_users(inserted_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut detailed_user = UserModels::detail_user_model();
         detailed_user.id = inserted_user.id.clone();
@@ -2452,7 +3192,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let inserted_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(inserted_user.clone()).await;
+        FunctionalTester::insert_in_db_users(inserted_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut detailed_user = UserModels::detail_user_model();
         detailed_user.id = inserted_user.id.clone();
@@ -2482,7 +3222,7 @@ mod integration_specs {
         dotenv::dotenv().ok();
 
         let inserted_user = UserModels::complete_user_model_hashed();
-        FunctionalTester::insert_in_db_users(inserted_user.clone()).await;
+        FunctionalTester::insert_in_db_users(inserted_user.clone(), DEFAULT_TENANT_ID).await;
 
         let mut detailed_user = UserModels::detail_user_model();
         detailed_user.id = "123456".to_string();
@@ -2544,7 +3284,7 @@ mod integration_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let jwt = JwtModels::access_jwt_model(users[0].id.clone());
@@ -2586,7 +3326,7 @@ mod integration_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let jwt = JwtModels::access_jwt_model(users[0].id.clone());
@@ -2636,7 +3376,7 @@ mod integration_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let jwt = JwtModels::access_jwt_model(users[0].id.clone());
@@ -2686,7 +3426,7 @@ mod integration_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let jwt = JwtModels::access_jwt_model(users[0].id.clone());
@@ -2732,7 +3472,7 @@ mod integration_specs {
             let mut user = UserModels::complete_user_model_hashed();
             user.email += &i.to_string();
 
-            users.push(FunctionalTester::insert_in_db_users(user).await);
+            users.push(FunctionalTester::insert_in_db_users(user, DEFAULT_TENANT_ID).await);
         }
 
         let jwt = JwtModels::access_jwt_model(users[0].id.clone());
@@ -2771,8 +3511,11 @@ mod integration_specs {
     async fn _list_users_error_service_unavailable() {
         dotenv::dotenv().ok();
 
-        let user =
-            FunctionalTester::insert_in_db_users(UserModels::complete_user_model_hashed()).await;
+        let user = FunctionalTester::insert_in_db_users(
+            UserModels::complete_user_model_hashed(),
+            DEFAULT_TENANT_ID,
+        )
+        .await;
 
         let jwt = JwtModels::access_jwt_model(user.id.clone());
         let resp = user_call_http_before(
@@ -2870,7 +3613,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let jwt = JwtModels::access_jwt_model(user.id.clone());
@@ -2917,7 +3660,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let jwt = JwtModels::access_jwt_model(user.id.clone());
@@ -2969,7 +3712,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let jwt = JwtModels::access_jwt_model(user.id.clone());
@@ -3097,7 +3840,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let jwt = JwtModels::access_jwt_model(user.id.clone());
@@ -3155,7 +3898,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let jwt = JwtModels::access_jwt_model(user.id.clone());
@@ -3208,7 +3951,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3260,7 +4003,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3310,7 +4053,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3360,7 +4103,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3410,7 +4153,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3460,7 +4203,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3509,7 +4252,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3558,7 +4301,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2");
@@ -3608,7 +4351,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmailcom");
@@ -3658,7 +4401,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3709,7 +4452,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3808,7 +4551,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3860,7 +4603,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -3912,7 +4655,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = user.email.clone();
@@ -3964,7 +4707,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -4013,7 +4756,7 @@ mod integration_specs {
         let salt = uuid::Uuid::new_v4().to_string();
         let mut user = UserModels::complete_user_model_hashed();
         user.password = format!("{}{}", user.password, salt);
-        FunctionalTester::insert_in_db_users(user.clone()).await;
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
         FunctionalTester::insert_in_db_salt(user.id.clone(), salt.clone()).await;
 
         let new_email = String::from("bush1d3v2@gmail.com");
@@ -4056,4 +4799,382 @@ mod integration_specs {
         )
         .await;
     }
+
+    #[test]
+    async fn _insert_user_error_malformed_json() {
+        dotenv::dotenv().ok();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(json_config())
+                .app_data(path_config())
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .app_data(Data::new(Arc::new(InsertUserAppQueue::new())))
+                .app_data(Data::new(Arc::new(DeleteUserAppQueue::new())))
+                .app_data(Data::new(Arc::new(PutUserAppQueue::new())))
+                .service(user_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/user")
+            .insert_header(("Content-Type", "application/json"))
+            .set_payload("{\"broken json")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), 400);
+
+        let body: Value =
+            serde_json::from_slice(&body::to_bytes(resp.into_body()).await.unwrap()).unwrap();
+        let error = &body["body"][0];
+        assert_eq!(error["code"], "bad request");
+        assert_eq!(
+            error["message"],
+            "O corpo da requisição não é um JSON válido."
+        );
+        assert!(error["params"].is_object());
+    }
+
+    #[test]
+    async fn _detail_user_by_username() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let user_id = uuid::Uuid::new_v4();
+        let username = format!("u_{}", &user_id.simple().to_string()[..20]);
+        pg_pool
+            .get()
+            .await
+            .unwrap()
+            .execute(
+                "INSERT INTO users (id, name, username, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, $3, 'x', NOW())",
+                &[&user_id, &username, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(pg_pool.clone()))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/user/@nobody_here")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+        let bytes =
+            String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec()).unwrap();
+        assert!(bytes.contains("username"));
+
+        let jwt = JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let req = test::TestRequest::get()
+            .uri(&format!("/user/@{}", username))
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 401);
+
+        pg_pool
+            .get()
+            .await
+            .unwrap()
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _refresh_token_after_role_change() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let user_id = uuid::Uuid::new_v4();
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+        client
+            .execute(
+                "INSERT INTO user_onboarding (user_id, email_verified) VALUES ($1, true)",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(pg_pool.clone()))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+        let refresh = |refresh_token: String| {
+            test::TestRequest::post()
+                .uri("/user/refresh")
+                .set_json(serde_json::json!({ "refreshToken": refresh_token }))
+                .to_request()
+        };
+
+        let refresh_token = Jwt::refresh_token_constructor(
+            user_id.to_string(),
+            String::from("user"),
+            false,
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .unwrap();
+        let tokens: Value = test::call_and_read_body_json(&app, refresh(refresh_token)).await;
+        let claims = Jwt::access_token_decode(tokens["accessToken"].as_str().unwrap())
+            .unwrap()
+            .claims;
+        assert_eq!(claims.role, "user");
+        assert!(claims.email_verified);
+        assert_eq!(claims.tenant_id.as_deref(), Some(DEFAULT_TENANT_ID));
+
+        let user_jwt = JwtModels::user_access_jwt_model(user_id.to_string());
+        let admin_jwt = JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string());
+        for (jwt, role, status) in [
+            (&user_jwt, "admin", 403),
+            (&admin_jwt, "owner", 400),
+            (&admin_jwt, "admin", 204),
+        ] {
+            let req = test::TestRequest::patch()
+                .uri(&format!("/user/{}/role", user_id))
+                .append_header(("Authorization", format!("Bearer {}", jwt)))
+                .set_json(serde_json::json!({ "role": role }))
+                .to_request();
+            assert_eq!(test::call_service(&app, req).await.status(), status);
+        }
+
+        let refresh_token = tokens["refreshToken"].as_str().unwrap().to_string();
+        let tokens: Value = test::call_and_read_body_json(&app, refresh(refresh_token)).await;
+        let claims = Jwt::access_token_decode(tokens["accessToken"].as_str().unwrap())
+            .unwrap()
+            .claims;
+        assert_eq!(claims.sub, user_id.to_string());
+        assert_eq!(claims.role, "admin");
+        let claims = Jwt::refresh_token_decode(tokens["refreshToken"].as_str().unwrap())
+            .unwrap()
+            .claims;
+        assert_eq!(claims.role, "admin");
+
+        let resp = test::call_service(&app, refresh(String::from("invalid"))).await;
+        assert_eq!(resp.status(), 401);
+
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _refresh_token_banned_or_deactivated() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let user_id = uuid::Uuid::new_v4();
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "INSERT INTO users (id, name, email, password, created_at, deactivated_at) VALUES ($1, 'Victor Navarro', $2, 'x', NOW(), NOW())",
+                &[&user_id, &format!("{}@gmail.com", user_id)],
+            )
+            .await
+            .unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(pg_pool.clone()))
+                .app_data(Data::new(RedisModels::pool_error().await))
+                .service(user_controllers_module()),
+        )
+        .await;
+        let refresh_token = Jwt::refresh_token_constructor(
+            user_id.to_string(),
+            String::from("user"),
+            false,
+            String::from(DEFAULT_TENANT_ID),
+        )
+        .unwrap();
+        let refresh = || {
+            test::TestRequest::post()
+                .uri("/user/refresh")
+                .set_json(serde_json::json!({ "refreshToken": refresh_token }))
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, refresh()).await;
+        assert_eq!(resp.status(), 403);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("Sua conta está desativada."));
+
+        client
+            .execute(
+                "UPDATE users SET deactivated_at = NULL, banned_at = NOW() WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        let resp = test::call_service(&app, refresh()).await;
+        assert_eq!(resp.status(), 403);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("Esta conta foi suspensa."));
+
+        client
+            .execute(
+                "UPDATE users SET banned_at = NULL WHERE id = $1",
+                &[&user_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(test::call_service(&app, refresh()).await.status(), 200);
+
+        client
+            .execute("DELETE FROM users WHERE id = $1", &[&user_id])
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _deactivate_and_reactivate_user() {
+        dotenv::dotenv().ok();
+
+        let mut user = UserModels::complete_user_model_hashed();
+        let salt = uuid::Uuid::new_v4().to_string();
+        user.password = format!("{}{}", user.password, salt);
+        user.username = format!("user_{}", &user.id[..8]);
+        user.email = format!("{}@gmail.com", user.id);
+        FunctionalTester::insert_in_db_users(user.clone(), DEFAULT_TENANT_ID).await;
+        FunctionalTester::insert_in_db_salt(user.id.clone(), salt).await;
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+        let user_jwt = JwtModels::user_access_jwt_model(user.id.clone());
+        let other_jwt = JwtModels::user_access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let admin_jwt = JwtModels::access_jwt_model(uuid::Uuid::new_v4().to_string());
+        let post = |action: &str, jwt: &String| {
+            test::TestRequest::post()
+                .uri(&format!("/user/{}/{}", user.id, action))
+                .append_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request()
+        };
+        let detail = || {
+            test::TestRequest::get()
+                .uri(&format!("/user/{}", user.id))
+                .append_header(("Authorization", format!("Bearer {}", user_jwt)))
+                .to_request()
+        };
+        let login = || {
+            test::TestRequest::post()
+                .uri("/user/login")
+                .set_json(MockLoginUserDTO {
+                    email: user.email.clone(),
+                    password: String::from("Navarro@2024"),
+                })
+                .to_request()
+        };
+
+        let resp = test::call_service(&app, post("deactivate", &other_jwt)).await;
+        assert_eq!(resp.status(), 401);
+        let resp = test::call_service(&app, post("deactivate", &user_jwt)).await;
+        assert_eq!(resp.status(), 204);
+
+        let resp = test::call_service(&app, detail()).await;
+        assert_eq!(resp.status(), 410);
+        let value: Value = test::read_body_json(resp).await;
+        assert_eq!(value["deactivated"], true);
+
+        let resp = test::call_service(&app, login()).await;
+        assert_eq!(resp.status(), 403);
+        let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("reactivate"));
+
+        let resp = test::call_service(&app, post("reactivate", &user_jwt)).await;
+        assert_eq!(resp.status(), 204);
+        assert_eq!(test::call_service(&app, detail()).await.status(), 200);
+        assert_eq!(test::call_service(&app, login()).await.status(), 200);
+
+        let resp = test::call_service(&app, post("deactivate", &admin_jwt)).await;
+        assert_eq!(resp.status(), 204);
+        assert_eq!(test::call_service(&app, detail()).await.status(), 410);
+        let resp = test::call_service(&app, post("reactivate", &admin_jwt)).await;
+        assert_eq!(resp.status(), 204);
+        assert_eq!(test::call_service(&app, detail()).await.status(), 200);
+
+        FunctionalTester::delete_from_database(TablesEnum::Salt, Some(vec![("user_id", &user.id)]))
+            .await;
+        FunctionalTester::delete_from_database(TablesEnum::Users, Some(vec![("id", &user.id)]))
+            .await;
+    }
+
+    #[test]
+    async fn _mention_autocomplete() {
+        dotenv::dotenv().ok();
+        let pg_pool = PostgresModels::postgres_success(None);
+        let prefix = format!("ac{}", &uuid::Uuid::new_v4().simple().to_string()[..8]);
+        let usernames = [
+            format!("{}_alpha", prefix),
+            format!("{}_beta", prefix),
+            format!("other_{}", prefix),
+        ];
+        let client = pg_pool.get().await.unwrap();
+        let mut user_ids = Vec::with_capacity(usernames.len());
+        for username in &usernames {
+            let user_id = uuid::Uuid::new_v4();
+            client
+                .execute(
+                    "INSERT INTO users (id, name, email, password, username, created_at) VALUES ($1, 'Victor Navarro', $2, 'x', $3, NOW())",
+                    &[&user_id, &format!("{}@gmail.com", user_id), username],
+                )
+                .await
+                .unwrap();
+            user_ids.push(user_id);
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(pg_pool.clone()))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .service(user_controllers_module()),
+        )
+        .await;
+        let autocomplete = |q: &str| {
+            test::TestRequest::get()
+                .uri(&format!("/user/autocomplete?q={}", q))
+                .to_request()
+        };
+
+        let suggestions: Value =
+            test::call_and_read_body_json(&app, autocomplete(&prefix.to_uppercase())).await;
+        let suggestions = suggestions.as_array().unwrap();
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0]["username"], usernames[0]);
+        assert_eq!(suggestions[0]["name"], "Victor Navarro");
+        assert_eq!(suggestions[1]["username"], usernames[1]);
+
+        let suggestions: Value =
+            test::call_and_read_body_json(&app, autocomplete(&format!("%40{}_b", prefix))).await;
+        assert_eq!(suggestions.as_array().unwrap().len(), 1);
+
+        let suggestions: Value = test::call_and_read_body_json(&app, autocomplete("a")).await;
+        assert!(suggestions.as_array().unwrap().is_empty());
+        let suggestions: Value = test::call_and_read_body_json(&app, autocomplete("%25%25")).await;
+        assert!(suggestions.as_array().unwrap().is_empty());
+
+        client
+            .execute("DELETE FROM users WHERE id = ANY($1)", &[&user_ids])
+            .await
+            .unwrap();
+    }
 }
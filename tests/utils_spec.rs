@@ -0,0 +1,188 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod utils_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{
+        body,
+        http::header::{HeaderMap, HeaderName, HeaderValue},
+        middleware::from_fn,
+        test,
+        web::{self, Data},
+        App, HttpResponse, HttpServer,
+    };
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use navarro_blog_api::{
+        middlewares::in_flight_middleware::in_flight_middleware,
+        utils::{
+            client_ip::resolve_client_ip,
+            cursor::{decode_cursor, encode_cursor},
+            graceful_shutdown::graceful_shutdown,
+            password_strength::score_password,
+            query_constructor_executor::query_constructor_executor,
+        },
+    };
+    use sql_builder::SqlBuilder;
+    use std::{
+        net::IpAddr,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    async fn _query_constructor_executor_error_query_timeout() {
+        dotenv::dotenv().ok();
+
+        let pool = PostgresModels::postgres_success(None);
+        let sql_builder = SqlBuilder::select_values(&["pg_sleep(10)"]);
+
+        let start = Instant::now();
+        let resp = query_constructor_executor(Data::new(pool.clone()), sql_builder)
+            .await
+            .err()
+            .unwrap();
+        assert!(start.elapsed() < Duration::from_secs(7));
+        assert_eq!(resp.status(), 503);
+
+        let bytes =
+            String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec()).unwrap();
+
+        assert!(bytes.contains("database"));
+        assert!(bytes.contains("service unavailable"));
+        assert!(bytes.contains("A consulta ao banco de dados excedeu o tempo limite."));
+
+        let client = pool.get().await.unwrap();
+        let rows = client
+            .query(
+                "SELECT pid FROM pg_stat_activity WHERE query LIKE 'SELECT pg_sleep(10)%'",
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert!(rows.is_empty());
+    }
+
+    #[test]
+    async fn _cursor() {
+        let created_at = chrono::DateTime::from_timestamp_micros(1718759034053147).unwrap();
+        let id = uuid::Uuid::new_v4();
+
+        let cursor = encode_cursor(created_at, id);
+        let decoded = String::from_utf8(URL_SAFE_NO_PAD.decode(&cursor).unwrap()).unwrap();
+
+        assert!(decoded.starts_with(&format!("1718759034053147_{}|", id)));
+        assert_eq!(decode_cursor(&cursor).unwrap(), (created_at, id));
+    }
+
+    #[test]
+    async fn _cursor_error_bad_request() {
+        let id = uuid::Uuid::new_v4();
+        let cursor = encode_cursor(chrono::Utc::now(), id);
+        let decoded = String::from_utf8(URL_SAFE_NO_PAD.decode(&cursor).unwrap()).unwrap();
+        let (_, signature) = decoded.rsplit_once('|').unwrap();
+        let tampered = URL_SAFE_NO_PAD.encode(format!("0_{}|{}", id, signature));
+        let unsigned = URL_SAFE_NO_PAD.encode(format!("0_{}", id));
+
+        for cursor in [
+            String::new(),
+            String::from("abc"),
+            format!("1718759034053147_{}", id),
+            unsigned,
+            tampered,
+            format!("{}A", cursor),
+        ] {
+            let resp = decode_cursor(&cursor).err().unwrap();
+            assert_eq!(resp.status(), 400);
+
+            let bytes = String::from_utf8(body::to_bytes(resp.into_body()).await.unwrap().to_vec())
+                .unwrap();
+
+            assert!(bytes.contains("cursor"));
+            assert!(bytes.contains("Cursor inválido."));
+        }
+    }
+
+    #[test]
+    async fn _graceful_shutdown() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let server = HttpServer::new(|| {
+            App::new().wrap(from_fn(in_flight_middleware)).route(
+                "/slow",
+                web::get().to(|| async {
+                    tokio::time::sleep(Duration::from_millis(500)).await;
+                    HttpResponse::Ok().body("done")
+                }),
+            )
+        })
+        .workers(1)
+        .disable_signals()
+        .shutdown_timeout(5)
+        .listen(listener)
+        .unwrap()
+        .run();
+        tokio::spawn(graceful_shutdown(server.handle()));
+        let server = tokio::spawn(server);
+
+        let slow_url = format!("{}/slow", address);
+        let request = tokio::spawn(async move {
+            let resp = reqwest::get(slow_url).await.unwrap();
+            (resp.status().as_u16(), resp.text().await.unwrap())
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        std::process::Command::new("kill")
+            .args(["-TERM", &std::process::id().to_string()])
+            .status()
+            .unwrap();
+
+        server.await.unwrap().unwrap();
+        let (status, text) = request.await.unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(text, "done");
+
+        assert!(reqwest::get(format!("{}/slow", address)).await.is_err());
+    }
+
+    #[test]
+    async fn _score_password() {
+        assert_eq!(score_password("password"), 0);
+        assert_eq!(score_password("Password"), 0);
+        assert_eq!(score_password("12345678%"), 0);
+        assert_eq!(score_password("abc"), 0);
+        assert_eq!(score_password("qwzxplmk"), 1);
+        assert_eq!(score_password("aaaa%1111"), 1);
+        assert_eq!(score_password("qwzxplm%A"), 2);
+        assert_eq!(score_password("Senha@123"), 2);
+        assert_eq!(score_password("Qwzxplm%9"), 3);
+        assert_eq!(score_password("Qwzx plm%9Rt!"), 4);
+        assert_eq!(score_password("Navarro@2024"), 4);
+    }
+
+    #[test]
+    async fn _resolve_client_ip() {
+        let proxy: IpAddr = "10.0.0.2".parse().unwrap();
+        let client: IpAddr = "198.51.100.1".parse().unwrap();
+        let forged: IpAddr = "203.0.113.7".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("x-forwarded-for"),
+            HeaderValue::from_str(&format!("{}, {}", forged, client)).unwrap(),
+        );
+
+        assert_eq!(resolve_client_ip(Some(client), &headers, &[]), Some(client));
+        assert_eq!(
+            resolve_client_ip(Some(proxy), &headers, &[proxy]),
+            Some(client)
+        );
+        assert_eq!(
+            resolve_client_ip(Some(forged), &headers, &[proxy]),
+            Some(forged)
+        );
+        assert_eq!(
+            resolve_client_ip(Some(proxy), &HeaderMap::new(), &[proxy]),
+            Some(proxy)
+        );
+        assert_eq!(resolve_client_ip(None, &headers, &[proxy]), None);
+    }
+}
@@ -0,0 +1,78 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod version_router_specs {
+    use crate::mocks::{
+        enums::db_table::TablesEnum,
+        functional_tester::FunctionalTester,
+        models::{jwt::JwtModels, postgres::PostgresModels, redis::RedisModels, user::UserModels},
+    };
+    use actix_web::{body, test, web::Data, App};
+    use navarro_blog_api::{
+        config::version_router::version_router, middlewares::tenant_middleware::DEFAULT_TENANT_ID,
+    };
+
+    #[test]
+    async fn _versioned_and_unprefixed_routes() {
+        dotenv::dotenv().ok();
+
+        let user = FunctionalTester::insert_in_db_users(
+            UserModels::complete_user_model_hashed(),
+            DEFAULT_TENANT_ID,
+        )
+        .await;
+        let jwt = JwtModels::access_jwt_model(user.id.clone());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .configure(version_router),
+        )
+        .await;
+
+        let mut responses = Vec::with_capacity(2);
+        for uri in ["/v1/user", "/user"] {
+            let req = test::TestRequest::get()
+                .uri(uri)
+                .append_header(("Authorization", format!("Bearer {}", jwt)))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), 200);
+
+            let deprecation = resp.headers().get("deprecation").cloned();
+            let sunset = resp.headers().get("sunset").cloned();
+            let bytes = body::to_bytes(resp.into_body()).await.unwrap();
+            responses.push((deprecation, sunset, bytes));
+        }
+
+        let (v1_deprecation, v1_sunset, v1_body) = &responses[0];
+        let (deprecation, sunset, body) = &responses[1];
+        assert_eq!(v1_body, body);
+        assert!(v1_deprecation.is_none());
+        assert!(v1_sunset.is_none());
+        assert_eq!(deprecation.as_ref().unwrap(), "true");
+        assert!(sunset.is_some());
+
+        FunctionalTester::delete_from_database(TablesEnum::Users, Some(vec![("id", &user.id)]))
+            .await;
+    }
+
+    #[test]
+    async fn _v2_routes_error_not_found() {
+        dotenv::dotenv().ok();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(PostgresModels::postgres_success(None)))
+                .app_data(Data::new(RedisModels::pool_success(None).await))
+                .configure(version_router),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/v2/user").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 404);
+        assert!(resp.headers().get("deprecation").is_none());
+    }
+}
@@ -0,0 +1,140 @@
+pub mod mocks;
+
+#[cfg(test)]
+mod webhook_specs {
+    use crate::mocks::models::postgres::PostgresModels;
+    use actix_web::{test, web::Data};
+    use navarro_blog_api::modules::webhook::{
+        webhook_dtos::{InsertWebhookDTO, POST_PUBLISHED_EVENT},
+        webhook_services::{
+            fire_webhook_service, insert_webhook_service, test_webhook_service, webhook_signature,
+            SIGNATURE_HEADER,
+        },
+    };
+
+    #[test]
+    async fn _webhook_signature() {
+        assert_eq!(
+            webhook_signature("key", "The quick brown fox jumps over the lazy dog"),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    async fn _fire_webhook() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let mut server = mockito::Server::new_async().await;
+        let path = format!("/hooks/{}", uuid::Uuid::new_v4());
+        let webhook = insert_webhook_service(
+            pg_pool.clone(),
+            InsertWebhookDTO {
+                url: format!("{}{}", server.url(), path),
+                events: vec![String::from(POST_PUBLISHED_EVENT)],
+            },
+        )
+        .await
+        .unwrap();
+
+        let mock = server
+            .mock("POST", path.as_str())
+            .match_header(
+                SIGNATURE_HEADER,
+                mockito::Matcher::Regex(String::from("^sha256=[0-9a-f]{64}$")),
+            )
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "event": "post.published",
+                "data": { "postId": "1" }
+            })))
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let deliveries = fire_webhook_service(
+            pg_pool.clone(),
+            POST_PUBLISHED_EVENT,
+            serde_json::json!({ "postId": "1" }),
+        )
+        .await
+        .unwrap();
+        let delivery = deliveries.iter().find(|delivery| delivery.delivered);
+        assert_eq!(delivery.unwrap().attempts, 1);
+        mock.assert_async().await;
+
+        let client = pg_pool.get().await.unwrap();
+        client
+            .execute(
+                "DELETE FROM webhook_subscriptions WHERE id = $1",
+                &[&uuid::Uuid::parse_str(&webhook.id).unwrap()],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _test_webhook_failure_is_logged() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let mut server = mockito::Server::new_async().await;
+        let webhook = insert_webhook_service(
+            pg_pool.clone(),
+            InsertWebhookDTO {
+                url: format!("{}/hook", server.url()),
+                events: vec![String::from(POST_PUBLISHED_EVENT)],
+            },
+        )
+        .await
+        .unwrap();
+        let webhook_id = uuid::Uuid::parse_str(&webhook.id).unwrap();
+        let client = pg_pool.get().await.unwrap();
+        // Keeps `_fire_webhook`, which may run at the same time, from delivering to this one.
+        client
+            .execute(
+                "UPDATE webhook_subscriptions SET events = '{}' WHERE id = $1",
+                &[&webhook_id],
+            )
+            .await
+            .unwrap();
+        let mock = server
+            .mock("POST", "/hook")
+            .with_status(500)
+            .expect(4)
+            .create_async()
+            .await;
+
+        let delivery = test_webhook_service(pg_pool.clone(), webhook.id.clone())
+            .await
+            .unwrap();
+        assert!(!delivery.delivered);
+        assert_eq!(delivery.attempts, 4);
+        assert_eq!(delivery.status_code, Some(500));
+        mock.assert_async().await;
+
+        let row = client
+            .query_one(
+                "SELECT event, attempts, status_code FROM webhook_delivery_logs WHERE subscription_id = $1",
+                &[&webhook_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(row.get::<_, String>("event"), "webhook.test");
+        assert_eq!(row.get::<_, i32>("attempts"), 4);
+        assert_eq!(row.get::<_, Option<i32>>("status_code"), Some(500));
+
+        client
+            .execute(
+                "DELETE FROM webhook_subscriptions WHERE id = $1",
+                &[&webhook_id],
+            )
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    async fn _test_webhook_not_found() {
+        dotenv::dotenv().ok();
+        let pg_pool = Data::new(PostgresModels::postgres_success(None));
+        let resp = test_webhook_service(pg_pool, uuid::Uuid::new_v4().to_string()).await;
+        assert_eq!(resp.err().unwrap().status(), 404);
+    }
+}